@@ -17,12 +17,26 @@
 
 //! Some configurable implementations as associated type for the substrate runtime.
 
-use crate::{AccountId, Assets, Authorship, Balances, NegativeImbalance, Runtime};
-use frame_support::traits::{
-	fungibles::{Balanced, CreditOf},
-	Currency, OnUnbalanced,
+use crate::{
+	AccountId, AllianceMotion, AssetConversion, Assets, Authorship, Balance, Balances, Call,
+	CouncilCollective, Hash, Identity, MaxCouncilSpend, NegativeImbalance, Origin, Runtime,
 };
+use frame_support::{
+	dispatch::{DispatchError, DispatchResultWithPostInfo},
+	traits::{
+		fungibles::{Balanced, CreditOf},
+		tokens::ConversionFromAssetBalance,
+		Currency, EnsureOrigin, Get, OnUnbalanced,
+	},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use pallet_alliance::{IdentityVerifier, ProposalIndex, ProposalProvider};
+use pallet_asset_conversion::NativeOrAssetId;
 use pallet_asset_tx_payment::HandleCredit;
+use pallet_identity::Judgement;
+use sp_core::u32_trait::{_3, _5};
+use sp_std::prelude::*;
 
 pub struct Author;
 impl OnUnbalanced<NegativeImbalance> for Author {
@@ -42,6 +56,142 @@ impl HandleCredit<AccountId, Assets> for CreditToBlockAuthor {
 	}
 }
 
+/// Converts an amount of an asset into its value in the native currency, using the spot price of
+/// the asset's pool with the native currency in `pallet-asset-conversion`.
+///
+/// Treasury spend limits such as `MaxCouncilSpend` are therefore applied to the native value of a
+/// spend. Spends of an asset without such a pool fail to convert and are rejected.
+pub struct AssetBalanceToNative;
+impl ConversionFromAssetBalance<Balance, u32, Balance> for AssetBalanceToNative {
+	type Error = ();
+
+	fn from_asset_balance(balance: Balance, asset_id: u32) -> Result<Balance, Self::Error> {
+		AssetConversion::quote_price_exact_tokens_for_tokens(
+			NativeOrAssetId::Asset(asset_id),
+			NativeOrAssetId::Native,
+			balance,
+			false,
+		)
+		.ok_or(())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(asset_id: u32) {
+		use crate::DOLLARS;
+
+		let provider: AccountId = frame_benchmarking::account("pool_provider", asset_id, 0);
+		let liquidity = 1_000_000 * DOLLARS;
+		// Creating the asset fails if it already exists, which is fine here.
+		let _ = Assets::force_create(Origin::root(), asset_id, provider.clone().into(), true, 1);
+		let _ = Balances::deposit_creating(&provider, 2 * liquidity);
+		let _ = Assets::mint(
+			Origin::signed(provider.clone()),
+			asset_id,
+			provider.clone().into(),
+			liquidity,
+		);
+		let _ = AssetConversion::create_pool(
+			Origin::signed(provider.clone()),
+			NativeOrAssetId::Native,
+			NativeOrAssetId::Asset(asset_id),
+		);
+		let _ = AssetConversion::add_liquidity(
+			Origin::signed(provider.clone()),
+			NativeOrAssetId::Native,
+			NativeOrAssetId::Asset(asset_id),
+			liquidity,
+			liquidity,
+			1,
+			1,
+			provider,
+		);
+	}
+}
+
+/// The origin allowed to approve treasury spends through `spend`, along with the maximum amount
+/// it may spend at a time: Root may spend any amount, while a 3/5 majority of the council is
+/// limited to `MaxCouncilSpend`.
+pub struct TreasurySpendOrigin;
+impl EnsureOrigin<Origin> for TreasurySpendOrigin {
+	type Success = Balance;
+	fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+		type EnsureCouncil =
+			pallet_collective::EnsureProportionAtLeast<_3, _5, AccountId, CouncilCollective>;
+		EnsureRoot::<AccountId>::try_origin(o)
+			.map(|_| Balance::max_value())
+			.or_else(|o| EnsureCouncil::try_origin(o).map(|_| MaxCouncilSpend::get()))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> Origin {
+		Origin::root()
+	}
+}
+
+/// Identity checks for the alliance, backed by the identity pallet: an account needs the requested
+/// identity fields and a reasonable or known-good judgement.
+pub struct AllianceIdentityVerifier;
+impl IdentityVerifier<AccountId> for AllianceIdentityVerifier {
+	fn has_identity(who: &AccountId, fields: u64) -> bool {
+		Identity::has_identity(who, fields)
+	}
+
+	fn has_good_judgement(who: &AccountId) -> bool {
+		if let Some(judgements) =
+			Identity::identity(who).map(|registration| registration.judgements)
+		{
+			judgements
+				.iter()
+				.any(|(_, j)| matches!(j, Judgement::KnownGood | Judgement::Reasonable))
+		} else {
+			false
+		}
+	}
+
+	fn super_account_id(who: &AccountId) -> Option<AccountId> {
+		Identity::super_of(who).map(|parent| parent.0)
+	}
+}
+
+/// Motions of the alliance, handled by its `pallet-collective` instance.
+pub struct AllianceProposalProvider;
+impl ProposalProvider<AccountId, Hash, Call> for AllianceProposalProvider {
+	fn propose_proposal(
+		who: AccountId,
+		threshold: u32,
+		proposal: Box<Call>,
+		length_bound: u32,
+	) -> Result<(u32, u32), DispatchError> {
+		AllianceMotion::do_propose_proposed(who, threshold, proposal, length_bound)
+	}
+
+	fn vote_proposal(
+		who: AccountId,
+		proposal: Hash,
+		index: ProposalIndex,
+		approve: bool,
+	) -> Result<bool, DispatchError> {
+		AllianceMotion::do_vote(who, proposal, index, approve)
+	}
+
+	fn veto_proposal(proposal_hash: Hash) -> u32 {
+		AllianceMotion::do_disapprove_proposal(proposal_hash)
+	}
+
+	fn close_proposal(
+		proposal_hash: Hash,
+		proposal_index: ProposalIndex,
+		proposal_weight_bound: Weight,
+		length_bound: u32,
+	) -> DispatchResultWithPostInfo {
+		AllianceMotion::do_close(proposal_hash, proposal_index, proposal_weight_bound, length_bound)
+	}
+
+	fn proposal_of(proposal_hash: Hash) -> Option<Call> {
+		AllianceMotion::proposal_of(proposal_hash)
+	}
+}
+
 #[cfg(test)]
 mod multiplier_tests {
 	use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
@@ -0,0 +1,494 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Alliance pallet benchmarking.
+
+use sp_runtime::traits::{Bounded, Hash, StaticLookup};
+use sp_std::{mem::size_of, prelude::*};
+
+use frame_benchmarking::{account, benchmarks_instance_pallet};
+use frame_support::traits::{EnsureOrigin, Get, UnfilteredDispatchable};
+use frame_system::{Pallet as System, RawOrigin as SystemOrigin};
+
+use super::{Call as AllianceCall, Pallet as Alliance, *};
+
+const SEED: u32 = 0;
+
+const MAX_BYTES: u32 = 1_024;
+
+fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::Event) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn cid(input: impl AsRef<[u8]>) -> Cid {
+	Cid::new_v0(sp_io::hashing::sha2_256(input.as_ref()))
+}
+
+fn rule(input: impl AsRef<[u8]>) -> Cid {
+	cid(input)
+}
+
+fn announcement(input: impl AsRef<[u8]>) -> Cid {
+	cid(input)
+}
+
+fn funded_account<T: Config<I>, I: 'static>(name: &'static str, index: u32) -> T::AccountId {
+	let account: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&account, BalanceOf::<T, I>::max_value() / 100u8.into());
+	account
+}
+
+fn founder<T: Config<I>, I: 'static>(index: u32) -> T::AccountId {
+	funded_account::<T, I>("founder", index)
+}
+
+fn fellow<T: Config<I>, I: 'static>(index: u32) -> T::AccountId {
+	funded_account::<T, I>("fellow", index)
+}
+
+fn ally<T: Config<I>, I: 'static>(index: u32) -> T::AccountId {
+	funded_account::<T, I>("ally", index)
+}
+
+fn outsider<T: Config<I>, I: 'static>(index: u32) -> T::AccountId {
+	funded_account::<T, I>("outsider", index)
+}
+
+fn generate_unscrupulous_account<T: Config<I>, I: 'static>(index: u32) -> T::AccountId {
+	funded_account::<T, I>("unscrupulous", index)
+}
+
+fn generate_unscrupulous_website<T: Config<I>, I: 'static>(index: u32, length: u32) -> UrlOf<T, I> {
+	// Websites are kept sorted in storage, so make them unique by prefixing the index.
+	let mut website = index.to_be_bytes().to_vec();
+	website.resize(length as usize, 0);
+	website.try_into().expect("website length is within bounds; qed")
+}
+
+fn set_founders<T: Config<I>, I: 'static>(count: u32) -> Vec<T::AccountId> {
+	let founders = (0..count).map(founder::<T, I>).collect::<Vec<_>>();
+	assert!(Alliance::<T, I>::init_members(
+		SystemOrigin::Root.into(),
+		founders.clone(),
+		vec![],
+		vec![],
+	)
+	.is_ok());
+	founders
+}
+
+fn propose_remarks<T: Config<I>, I: 'static>(
+	proposer: &T::AccountId,
+	threshold: u32,
+	count: u32,
+	length: u32,
+) -> Result<Option<T::Hash>, &'static str> {
+	let bytes_in_storage = length + size_of::<u32>() as u32;
+	let mut last_hash = None;
+	for i in 0..count {
+		// Proposals should be different so that different proposal hashes are generated.
+		let proposal: T::Proposal =
+			frame_system::Call::<T>::remark { remark: vec![i as u8; length as usize] }.into();
+		Alliance::<T, I>::propose(
+			SystemOrigin::Signed(proposer.clone()).into(),
+			threshold,
+			Box::new(proposal.clone()),
+			bytes_in_storage,
+		)?;
+		last_hash = Some(T::Hashing::hash_of(&proposal));
+	}
+	Ok(last_hash)
+}
+
+benchmarks_instance_pallet! {
+	propose_proposed {
+		let b in 1 .. MAX_BYTES;
+		let m in 2 .. T::MaxMembersCount::get();
+		let p in 1 .. T::MaxProposals::get();
+
+		let bytes_in_storage = b + size_of::<u32>() as u32;
+		let founders = set_founders::<T, I>(m);
+		let proposer = founders[0].clone();
+		let threshold = m;
+
+		// Add previous proposals.
+		propose_remarks::<T, I>(&proposer, threshold, p - 1, b)?;
+
+		let proposal: T::Proposal =
+			frame_system::Call::<T>::remark { remark: vec![p as u8; b as usize] }.into();
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+	}: propose(SystemOrigin::Signed(proposer), threshold, Box::new(proposal.clone()), bytes_in_storage)
+	verify {
+		assert_eq!(T::ProposalProvider::proposal_of(proposal_hash), Some(proposal));
+	}
+
+	vote {
+		// We choose 5 as a minimum so we always trigger a vote in the voting loop.
+		let m in 5 .. T::MaxMembersCount::get();
+
+		let founders = set_founders::<T, I>(m);
+		let proposer = founders[0].clone();
+		let voter = founders[m as usize - 1].clone();
+		let last_hash = propose_remarks::<T, I>(&proposer, m, 1, MAX_BYTES)?
+			.expect("a proposal was made; qed");
+
+		// Have almost everyone vote aye on the proposal, while keeping it from passing.
+		for member in founders.iter().take(m as usize - 2) {
+			Alliance::<T, I>::vote(
+				SystemOrigin::Signed(member.clone()).into(),
+				last_hash.clone(),
+				0,
+				true,
+			)?;
+		}
+		// The voter votes aye first, and switches to nay in the benchmark.
+		Alliance::<T, I>::vote(SystemOrigin::Signed(voter.clone()).into(), last_hash.clone(), 0, true)?;
+	}: _(SystemOrigin::Signed(voter), last_hash.clone(), 0, false)
+	verify {
+		assert!(T::ProposalProvider::proposal_of(last_hash).is_some());
+	}
+
+	veto {
+		let p in 1 .. T::MaxProposals::get();
+
+		let founders = set_founders::<T, I>(2);
+		let proposer = founders[0].clone();
+		let vetor = founders[1].clone();
+
+		propose_remarks::<T, I>(&proposer, 2, p - 1, MAX_BYTES)?;
+
+		let proposal: T::Proposal = AllianceCall::<T, I>::set_rule { rule: rule(b"rule") }.into();
+		let proposal_len = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+		Alliance::<T, I>::propose(
+			SystemOrigin::Signed(proposer).into(),
+			2,
+			Box::new(proposal),
+			proposal_len,
+		)?;
+	}: _(SystemOrigin::Signed(vetor), proposal_hash)
+	verify {
+		assert!(T::ProposalProvider::proposal_of(proposal_hash).is_none());
+	}
+
+	close_early_disapproved {
+		let m in 4 .. T::MaxMembersCount::get();
+		let p in 1 .. T::MaxProposals::get();
+
+		let founders = set_founders::<T, I>(m);
+		let proposer = founders[0].clone();
+		let last_hash = propose_remarks::<T, I>(&proposer, m, p, MAX_BYTES)?
+			.expect("at least one proposal was made; qed");
+
+		// Everyone votes nay, so the proposal can no longer pass.
+		for member in founders.iter() {
+			Alliance::<T, I>::vote(
+				SystemOrigin::Signed(member.clone()).into(),
+				last_hash.clone(),
+				p - 1,
+				false,
+			)?;
+		}
+	}: close(SystemOrigin::Signed(proposer), last_hash.clone(), p - 1, Weight::max_value(), MAX_BYTES + size_of::<u32>() as u32)
+	verify {
+		assert!(T::ProposalProvider::proposal_of(last_hash).is_none());
+	}
+
+	close_early_approved {
+		let b in 1 .. MAX_BYTES;
+		let m in 4 .. T::MaxMembersCount::get();
+		let p in 1 .. T::MaxProposals::get();
+
+		let founders = set_founders::<T, I>(m);
+		let proposer = founders[0].clone();
+		// Threshold is 2 so any two ayes will approve the vote.
+		let last_hash = propose_remarks::<T, I>(&proposer, 2, p, b)?
+			.expect("at least one proposal was made; qed");
+
+		for member in founders.iter().take(2) {
+			Alliance::<T, I>::vote(
+				SystemOrigin::Signed(member.clone()).into(),
+				last_hash.clone(),
+				p - 1,
+				true,
+			)?;
+		}
+	}: close(SystemOrigin::Signed(proposer), last_hash.clone(), p - 1, Weight::max_value(), b + size_of::<u32>() as u32)
+	verify {
+		assert!(T::ProposalProvider::proposal_of(last_hash).is_none());
+	}
+
+	close_disapproved {
+		let m in 2 .. T::MaxMembersCount::get();
+		let p in 1 .. T::MaxProposals::get();
+
+		let founders = set_founders::<T, I>(m);
+		let proposer = founders[0].clone();
+		let last_hash = propose_remarks::<T, I>(&proposer, m, p, MAX_BYTES)?
+			.expect("at least one proposal was made; qed");
+
+		// Only the proposer votes aye, the others abstain.
+		Alliance::<T, I>::vote(
+			SystemOrigin::Signed(proposer.clone()).into(),
+			last_hash.clone(),
+			p - 1,
+			true,
+		)?;
+
+		System::<T>::set_block_number(T::BlockNumber::max_value());
+	}: close(SystemOrigin::Signed(proposer), last_hash.clone(), p - 1, Weight::max_value(), MAX_BYTES + size_of::<u32>() as u32)
+	verify {
+		assert!(T::ProposalProvider::proposal_of(last_hash).is_none());
+	}
+
+	close_approved {
+		let b in 1 .. MAX_BYTES;
+		let m in 2 .. T::MaxMembersCount::get();
+		let p in 1 .. T::MaxProposals::get();
+
+		let founders = set_founders::<T, I>(m);
+		let proposer = founders[0].clone();
+		let last_hash = propose_remarks::<T, I>(&proposer, 2, p, b)?
+			.expect("at least one proposal was made; qed");
+
+		for member in founders.iter().take(2) {
+			Alliance::<T, I>::vote(
+				SystemOrigin::Signed(member.clone()).into(),
+				last_hash.clone(),
+				p - 1,
+				true,
+			)?;
+		}
+
+		System::<T>::set_block_number(T::BlockNumber::max_value());
+	}: close(SystemOrigin::Signed(proposer), last_hash.clone(), p - 1, Weight::max_value(), b + size_of::<u32>() as u32)
+	verify {
+		assert!(T::ProposalProvider::proposal_of(last_hash).is_none());
+	}
+
+	init_members {
+		// at least 1 founder
+		let x in 1 .. T::MaxMembersCount::get();
+		let y in 0 .. T::MaxMembersCount::get();
+		let z in 0 .. T::MaxMembersCount::get();
+
+		let mut founders = (0 .. x).map(founder::<T, I>).collect::<Vec<_>>();
+		let mut fellows = (0 .. y).map(fellow::<T, I>).collect::<Vec<_>>();
+		let mut allies = (0 .. z).map(ally::<T, I>).collect::<Vec<_>>();
+	}: _(SystemOrigin::Root, founders.clone(), fellows.clone(), allies.clone())
+	verify {
+		founders.sort();
+		fellows.sort();
+		allies.sort();
+		assert_last_event::<T, I>(Event::MembersInitialized {
+			founders: founders.clone(),
+			fellows: fellows.clone(),
+			allies: allies.clone(),
+		}.into());
+		assert_eq!(Alliance::<T, I>::members(MemberRole::Founder).into_inner(), founders);
+		assert_eq!(Alliance::<T, I>::members(MemberRole::Fellow).into_inner(), fellows);
+		assert_eq!(Alliance::<T, I>::members(MemberRole::Ally).into_inner(), allies);
+	}
+
+	set_rule {
+		set_founders::<T, I>(1);
+
+		let rule = rule(b"alliance rule");
+
+		let origin = T::AdminOrigin::successful_origin();
+		let call = AllianceCall::<T, I>::set_rule { rule: rule.clone() };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Alliance::<T, I>::rule(), Some(rule.clone()));
+		assert_last_event::<T, I>(Event::NewRuleSet { rule }.into());
+	}
+
+	announce {
+		set_founders::<T, I>(1);
+
+		let announcement = announcement(b"announcement");
+
+		let origin = T::AnnouncementOrigin::successful_origin();
+		let call = AllianceCall::<T, I>::announce { announcement: announcement.clone() };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(Alliance::<T, I>::announcements().contains(&announcement));
+		assert_last_event::<T, I>(Event::Announced { announcement }.into());
+	}
+
+	remove_announcement {
+		set_founders::<T, I>(1);
+
+		let announcement = announcement(b"announcement");
+		let announcements: BoundedVec<_, T::MaxAnnouncementsCount> =
+			vec![announcement.clone()].try_into().expect("at least one announcement is allowed; qed");
+		Announcements::<T, I>::put(announcements);
+
+		let origin = T::AnnouncementOrigin::successful_origin();
+		let call = AllianceCall::<T, I>::remove_announcement { announcement: announcement.clone() };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(Alliance::<T, I>::announcements().is_empty());
+		assert_last_event::<T, I>(Event::AnnouncementRemoved { announcement }.into());
+	}
+
+	join_alliance {
+		set_founders::<T, I>(1);
+
+		let outsider = outsider::<T, I>(1);
+		assert!(!Alliance::<T, I>::is_member(&outsider));
+		assert_eq!(DepositOf::<T, I>::get(&outsider), None);
+	}: _(SystemOrigin::Signed(outsider.clone()))
+	verify {
+		assert!(Alliance::<T, I>::is_member_of(&outsider, MemberRole::Ally));
+		assert_eq!(DepositOf::<T, I>::get(&outsider), Some(T::AllyDeposit::get()));
+		assert_last_event::<T, I>(Event::NewAllyJoined {
+			ally: outsider,
+			nominator: None,
+			reserved: Some(T::AllyDeposit::get()),
+		}.into());
+	}
+
+	nominate_ally {
+		let founders = set_founders::<T, I>(1);
+		let nominator = founders[0].clone();
+
+		let outsider = outsider::<T, I>(1);
+		let outsider_lookup = T::Lookup::unlookup(outsider.clone());
+	}: _(SystemOrigin::Signed(nominator.clone()), outsider_lookup)
+	verify {
+		assert!(Alliance::<T, I>::is_member_of(&outsider, MemberRole::Ally));
+		assert_eq!(DepositOf::<T, I>::get(&outsider), None);
+		assert_last_event::<T, I>(Event::NewAllyJoined {
+			ally: outsider,
+			nominator: Some(nominator),
+			reserved: None,
+		}.into());
+	}
+
+	elevate_ally {
+		set_founders::<T, I>(1);
+
+		let ally = ally::<T, I>(1);
+		Alliance::<T, I>::join_alliance(SystemOrigin::Signed(ally.clone()).into())?;
+		let ally_lookup = T::Lookup::unlookup(ally.clone());
+
+		let origin = T::MembershipManager::successful_origin();
+		let call = AllianceCall::<T, I>::elevate_ally { ally: ally_lookup };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!Alliance::<T, I>::is_member_of(&ally, MemberRole::Ally));
+		assert!(Alliance::<T, I>::is_member_of(&ally, MemberRole::Fellow));
+		assert_last_event::<T, I>(Event::AllyElevated { ally }.into());
+	}
+
+	retire {
+		set_founders::<T, I>(1);
+
+		// A voting member with a deposit is the worst case.
+		let fellow = fellow::<T, I>(1);
+		Alliance::<T, I>::join_alliance(SystemOrigin::Signed(fellow.clone()).into())?;
+		Alliance::<T, I>::elevate_ally(
+			T::MembershipManager::successful_origin(),
+			T::Lookup::unlookup(fellow.clone()),
+		)?;
+	}: _(SystemOrigin::Signed(fellow.clone()))
+	verify {
+		assert!(!Alliance::<T, I>::is_member(&fellow));
+		assert_eq!(DepositOf::<T, I>::get(&fellow), None);
+		assert_last_event::<T, I>(Event::MemberRetired {
+			member: fellow,
+			unreserved: Some(T::AllyDeposit::get()),
+		}.into());
+	}
+
+	kick_member {
+		set_founders::<T, I>(1);
+
+		// A voting member with a deposit who is up for kicking is the worst case.
+		let fellow = fellow::<T, I>(1);
+		Alliance::<T, I>::join_alliance(SystemOrigin::Signed(fellow.clone()).into())?;
+		Alliance::<T, I>::elevate_ally(
+			T::MembershipManager::successful_origin(),
+			T::Lookup::unlookup(fellow.clone()),
+		)?;
+		UpForKicking::<T, I>::insert(&fellow, true);
+		let fellow_lookup = T::Lookup::unlookup(fellow.clone());
+
+		let origin = T::MembershipManager::successful_origin();
+		let call = AllianceCall::<T, I>::kick_member { who: fellow_lookup };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!Alliance::<T, I>::is_member(&fellow));
+		assert_eq!(DepositOf::<T, I>::get(&fellow), None);
+		assert!(!Alliance::<T, I>::up_for_kicking(&fellow));
+		assert_last_event::<T, I>(Event::MemberKicked {
+			member: fellow,
+			slashed: Some(T::AllyDeposit::get()),
+		}.into());
+	}
+
+	add_unscrupulous_items {
+		let n in 1 .. T::MaxUnscrupulousItems::get();
+		// Websites need room for the index prefix that keeps them unique.
+		let l in 4 .. T::MaxWebsiteUrlLength::get();
+
+		set_founders::<T, I>(1);
+
+		let mut unscrupulous_list = Vec::with_capacity(n as usize * 2);
+		unscrupulous_list.extend(
+			(0 .. n).map(|i| UnscrupulousItem::AccountId(generate_unscrupulous_account::<T, I>(i)))
+		);
+		unscrupulous_list.extend(
+			(0 .. n).map(|i| UnscrupulousItem::Website(generate_unscrupulous_website::<T, I>(i, l)))
+		);
+
+		let origin = T::AnnouncementOrigin::successful_origin();
+		let call = AllianceCall::<T, I>::add_unscrupulous_items { items: unscrupulous_list.clone() };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::UnscrupulousItemAdded { items: unscrupulous_list }.into());
+	}
+
+	remove_unscrupulous_items {
+		let n in 1 .. T::MaxUnscrupulousItems::get();
+		// Websites need room for the index prefix that keeps them unique.
+		let l in 4 .. T::MaxWebsiteUrlLength::get();
+
+		set_founders::<T, I>(1);
+
+		let mut unscrupulous_list = Vec::with_capacity(n as usize * 2);
+		unscrupulous_list.extend(
+			(0 .. n).map(|i| UnscrupulousItem::AccountId(generate_unscrupulous_account::<T, I>(i)))
+		);
+		unscrupulous_list.extend(
+			(0 .. n).map(|i| UnscrupulousItem::Website(generate_unscrupulous_website::<T, I>(i, l)))
+		);
+		Alliance::<T, I>::add_unscrupulous_items(
+			T::AnnouncementOrigin::successful_origin(),
+			unscrupulous_list.clone(),
+		)?;
+
+		let origin = T::AnnouncementOrigin::successful_origin();
+		let call = AllianceCall::<T, I>::remove_unscrupulous_items { items: unscrupulous_list.clone() };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::UnscrupulousItemRemoved { items: unscrupulous_list }.into());
+	}
+
+	impl_benchmark_test_suite!(Alliance, crate::mock::new_bench_ext(), crate::mock::Test);
+}
@@ -0,0 +1,544 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the alliance pallet.
+
+use sp_runtime::traits::BadOrigin;
+
+use frame_support::{assert_noop, assert_ok, Hashable};
+use frame_system::{EventRecord, Phase};
+
+use super::*;
+use crate::mock::*;
+
+type AllianceMotionEvent = pallet_collective::Event<Test, pallet_collective::Instance1>;
+
+fn last_event() -> mock::Event {
+	System::events().pop().expect("Event expected").event
+}
+
+/// Undo the initialization done in the mock, as if the alliance was never set up.
+fn reset_members() {
+	Members::<Test>::remove(MemberRole::Founder);
+	Members::<Test>::remove(MemberRole::Fellow);
+	pallet_collective::Members::<Test, pallet_collective::Instance1>::kill();
+}
+
+#[test]
+fn init_members_works() {
+	new_test_ext().execute_with(|| {
+		// alliance is already initialized in the mock
+		assert_noop!(
+			Alliance::init_members(Origin::root(), vec![4], vec![], vec![]),
+			Error::<Test, ()>::AllianceAlreadyInitialized,
+		);
+
+		assert_eq!(Alliance::members(MemberRole::Founder), vec![1, 2]);
+		assert_eq!(Alliance::members(MemberRole::Fellow), vec![3]);
+		assert_eq!(AllianceMotion::members(), vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn init_members_requires_founders() {
+	new_test_ext().execute_with(|| {
+		reset_members();
+
+		assert_noop!(
+			Alliance::init_members(Origin::root(), vec![], vec![3], vec![]),
+			Error::<Test, ()>::FoundersMissing,
+		);
+
+		assert_ok!(Alliance::init_members(Origin::root(), vec![2, 1], vec![4], vec![5]));
+		assert_eq!(Alliance::members(MemberRole::Founder), vec![1, 2]);
+		assert_eq!(Alliance::members(MemberRole::Fellow), vec![4]);
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![5]);
+		assert_eq!(
+			last_event(),
+			mock::Event::Alliance(crate::Event::MembersInitialized {
+				founders: vec![1, 2],
+				fellows: vec![4],
+				allies: vec![5],
+			})
+		);
+	});
+}
+
+#[cfg(not(feature = "runtime-benchmarks"))]
+#[test]
+fn members_require_identity() {
+	new_test_ext().execute_with(|| {
+		// 9 has an identity without a judgement, 10 has no identity at all.
+		assert_noop!(
+			Alliance::join_alliance(Origin::signed(10)),
+			Error::<Test, ()>::WithoutIdentityDisplayAndWebsite,
+		);
+		assert_noop!(
+			Alliance::nominate_ally(Origin::signed(1), 9),
+			Error::<Test, ()>::WithoutGoodIdentityJudgement,
+		);
+
+		reset_members();
+		assert_noop!(
+			Alliance::init_members(Origin::root(), vec![10], vec![], vec![]),
+			Error::<Test, ()>::WithoutIdentityDisplayAndWebsite,
+		);
+		assert_noop!(
+			Alliance::init_members(Origin::root(), vec![9], vec![], vec![]),
+			Error::<Test, ()>::WithoutGoodIdentityJudgement,
+		);
+	});
+}
+
+#[test]
+fn propose_works() {
+	new_test_ext().execute_with(|| {
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let hash: H256 = proposal.blake2_256().into();
+
+		// only voting member can propose proposal, 4 is ally not have vote rights
+		assert_noop!(
+			Alliance::propose(Origin::signed(4), 3, Box::new(proposal.clone()), proposal_len),
+			Error::<Test, ()>::NoVotingRights
+		);
+
+		assert_ok!(Alliance::propose(
+			Origin::signed(1),
+			3,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_eq!(*AllianceMotion::proposals(), vec![hash]);
+		assert_eq!(AllianceMotion::proposal_of(&hash), Some(proposal));
+		assert_eq!(
+			System::events(),
+			vec![EventRecord {
+				phase: Phase::Initialization,
+				event: mock::Event::AllianceMotion(AllianceMotionEvent::Proposed {
+					account: 1,
+					proposal_index: 0,
+					proposal_hash: hash,
+					threshold: 3,
+				}),
+				topics: vec![],
+			}]
+		);
+	});
+}
+
+#[test]
+fn vote_works() {
+	new_test_ext().execute_with(|| {
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let hash: H256 = proposal.blake2_256().into();
+		assert_ok!(Alliance::propose(
+			Origin::signed(1),
+			3,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+
+		assert_noop!(
+			Alliance::vote(Origin::signed(4), hash, 0, true),
+			Error::<Test, ()>::NoVotingRights
+		);
+
+		assert_ok!(Alliance::vote(Origin::signed(2), hash, 0, true));
+		assert_eq!(
+			last_event(),
+			mock::Event::AllianceMotion(AllianceMotionEvent::Voted {
+				account: 2,
+				proposal_hash: hash,
+				voted: true,
+				yes: 1,
+				no: 0,
+			})
+		);
+	});
+}
+
+#[test]
+fn veto_works() {
+	new_test_ext().execute_with(|| {
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let hash: H256 = proposal.blake2_256().into();
+		assert_ok!(Alliance::propose(
+			Origin::signed(1),
+			3,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		// only set_rule/elevate_ally can be veto
+		assert_noop!(
+			Alliance::veto(Origin::signed(1), hash),
+			Error::<Test, ()>::NotVetoableProposal
+		);
+
+		let cid = test_cid();
+		let vetoable_proposal = make_set_rule_proposal(cid);
+		let vetoable_proposal_len: u32 = vetoable_proposal.using_encoded(|p| p.len() as u32);
+		let vetoable_hash: H256 = vetoable_proposal.blake2_256().into();
+		assert_ok!(Alliance::propose(
+			Origin::signed(1),
+			3,
+			Box::new(vetoable_proposal.clone()),
+			vetoable_proposal_len
+		));
+
+		// only founder have veto rights, 3 is fellow
+		assert_noop!(
+			Alliance::veto(Origin::signed(3), vetoable_hash),
+			Error::<Test, ()>::NotFounder
+		);
+
+		assert_ok!(Alliance::veto(Origin::signed(2), vetoable_hash));
+		assert_eq!(
+			last_event(),
+			mock::Event::AllianceMotion(AllianceMotionEvent::Disapproved {
+				proposal_hash: vetoable_hash
+			})
+		);
+		assert_eq!(AllianceMotion::proposal_of(&vetoable_hash), None);
+	});
+}
+
+#[test]
+fn close_works() {
+	new_test_ext().execute_with(|| {
+		let cid = test_cid();
+		let proposal = make_set_rule_proposal(cid.clone());
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		let hash = BlakeTwo256::hash_of(&proposal);
+		assert_ok!(Alliance::propose(
+			Origin::signed(1),
+			3,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_ok!(Alliance::vote(Origin::signed(1), hash, 0, true));
+		assert_ok!(Alliance::vote(Origin::signed(2), hash, 0, true));
+		assert_ok!(Alliance::vote(Origin::signed(3), hash, 0, true));
+
+		assert_noop!(
+			Alliance::close(Origin::signed(4), hash, 0, proposal_weight, proposal_len),
+			Error::<Test, ()>::NoVotingRights
+		);
+
+		assert_ok!(Alliance::close(Origin::signed(1), hash, 0, proposal_weight, proposal_len));
+		assert_eq!(AllianceMotion::proposal_of(&hash), None);
+		assert_eq!(Alliance::rule(), Some(cid));
+		assert_eq!(
+			last_event(),
+			mock::Event::AllianceMotion(AllianceMotionEvent::Executed {
+				proposal_hash: hash,
+				result: Ok(()),
+			})
+		);
+	});
+}
+
+#[test]
+fn set_rule_works() {
+	new_test_ext().execute_with(|| {
+		let cid = test_cid();
+		assert_noop!(Alliance::set_rule(Origin::signed(2), cid.clone()), BadOrigin);
+
+		assert_ok!(Alliance::set_rule(Origin::signed(1), cid.clone()));
+		assert_eq!(Alliance::rule(), Some(cid.clone()));
+		assert_eq!(last_event(), mock::Event::Alliance(crate::Event::NewRuleSet { rule: cid }));
+	});
+}
+
+#[test]
+fn announce_works() {
+	new_test_ext().execute_with(|| {
+		let cid = test_cid();
+		assert_noop!(Alliance::announce(Origin::signed(2), cid.clone()), BadOrigin);
+
+		assert_ok!(Alliance::announce(Origin::signed(3), cid.clone()));
+		assert_eq!(Alliance::announcements(), vec![cid.clone()]);
+		assert_eq!(
+			last_event(),
+			mock::Event::Alliance(crate::Event::Announced { announcement: cid })
+		);
+	});
+}
+
+#[test]
+fn remove_announcement_works() {
+	new_test_ext().execute_with(|| {
+		let cid = test_cid();
+		assert_noop!(
+			Alliance::remove_announcement(Origin::signed(3), cid.clone()),
+			Error::<Test, ()>::MissingAnnouncement
+		);
+
+		assert_ok!(Alliance::announce(Origin::signed(3), cid.clone()));
+		assert_ok!(Alliance::remove_announcement(Origin::signed(3), cid.clone()));
+		assert!(Alliance::announcements().is_empty());
+		assert_eq!(
+			last_event(),
+			mock::Event::Alliance(crate::Event::AnnouncementRemoved { announcement: cid })
+		);
+	});
+}
+
+#[test]
+fn join_alliance_works() {
+	new_test_ext().execute_with(|| {
+		// check already member
+		assert_noop!(Alliance::join_alliance(Origin::signed(1)), Error::<Test, ()>::AlreadyMember);
+
+		// check account non grata
+		assert_ok!(Alliance::add_unscrupulous_items(
+			Origin::signed(3),
+			vec![UnscrupulousItem::AccountId(4)]
+		));
+		assert_noop!(
+			Alliance::join_alliance(Origin::signed(4)),
+			Error::<Test, ()>::AccountNonGrata
+		);
+		assert_ok!(Alliance::remove_unscrupulous_items(
+			Origin::signed(3),
+			vec![UnscrupulousItem::AccountId(4)]
+		));
+
+		// check deposit funds
+		assert_noop!(
+			Alliance::join_alliance(Origin::signed(5)),
+			Error::<Test, ()>::InsufficientFunds
+		);
+
+		assert_ok!(Alliance::join_alliance(Origin::signed(4)));
+		assert_eq!(Alliance::deposit_of(4), Some(25));
+		assert_eq!(Balances::reserved_balance(4), 35);
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![4]);
+		assert_eq!(
+			last_event(),
+			mock::Event::Alliance(crate::Event::NewAllyJoined {
+				ally: 4,
+				nominator: None,
+				reserved: Some(25),
+			})
+		);
+	});
+}
+
+#[test]
+fn join_alliance_requires_initialization() {
+	new_test_ext().execute_with(|| {
+		reset_members();
+
+		assert_noop!(
+			Alliance::join_alliance(Origin::signed(4)),
+			Error::<Test, ()>::AllianceNotYetInitialized
+		);
+	});
+}
+
+#[test]
+fn nominate_ally_works() {
+	new_test_ext().execute_with(|| {
+		// check already member
+		assert_noop!(
+			Alliance::nominate_ally(Origin::signed(1), 2),
+			Error::<Test, ()>::AlreadyMember
+		);
+
+		// only voting member(founder/fellow) have nominate right
+		assert_ok!(Alliance::nominate_ally(Origin::signed(1), 6));
+		assert_noop!(
+			Alliance::nominate_ally(Origin::signed(6), 4),
+			Error::<Test, ()>::NoVotingRights
+		);
+
+		// check account non grata
+		assert_ok!(Alliance::add_unscrupulous_items(
+			Origin::signed(3),
+			vec![UnscrupulousItem::AccountId(4)]
+		));
+		assert_noop!(
+			Alliance::nominate_ally(Origin::signed(1), 4),
+			Error::<Test, ()>::AccountNonGrata
+		);
+		assert_ok!(Alliance::remove_unscrupulous_items(
+			Origin::signed(3),
+			vec![UnscrupulousItem::AccountId(4)]
+		));
+
+		assert_ok!(Alliance::nominate_ally(Origin::signed(1), 4));
+		assert_eq!(Alliance::deposit_of(4), None);
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![4, 6]);
+		assert_eq!(
+			last_event(),
+			mock::Event::Alliance(crate::Event::NewAllyJoined {
+				ally: 4,
+				nominator: Some(1),
+				reserved: None,
+			})
+		);
+	});
+}
+
+#[test]
+fn elevate_ally_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Alliance::elevate_ally(Origin::signed(2), 4), Error::<Test, ()>::NotAlly);
+
+		assert_ok!(Alliance::join_alliance(Origin::signed(4)));
+		assert_noop!(Alliance::elevate_ally(Origin::signed(1), 4), BadOrigin);
+
+		assert_ok!(Alliance::elevate_ally(Origin::signed(2), 4));
+		assert!(Alliance::members(MemberRole::Ally).is_empty());
+		assert_eq!(Alliance::members(MemberRole::Fellow), vec![3, 4]);
+		assert_eq!(AllianceMotion::members(), vec![1, 2, 3, 4]);
+		assert_eq!(last_event(), mock::Event::Alliance(crate::Event::AllyElevated { ally: 4 }));
+	});
+}
+
+#[test]
+fn retire_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Alliance::retire(Origin::signed(4)), Error::<Test, ()>::NotMember);
+
+		assert_ok!(Alliance::join_alliance(Origin::signed(4)));
+		assert_ok!(Alliance::elevate_ally(Origin::signed(2), 4));
+		assert_ok!(Alliance::retire(Origin::signed(4)));
+		assert!(!Alliance::is_member(&4));
+		assert_eq!(Alliance::deposit_of(4), None);
+		assert_eq!(Balances::reserved_balance(4), 10);
+		assert_eq!(AllianceMotion::members(), vec![1, 2, 3]);
+		assert_eq!(
+			last_event(),
+			mock::Event::Alliance(crate::Event::MemberRetired { member: 4, unreserved: Some(25) })
+		);
+	});
+}
+
+#[test]
+fn cannot_retire_while_up_for_kicking() {
+	new_test_ext().execute_with(|| {
+		let proposal = make_kick_member_proposal(3);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		let hash = BlakeTwo256::hash_of(&proposal);
+		assert_ok!(Alliance::propose(
+			Origin::signed(1),
+			3,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert!(Alliance::up_for_kicking(3));
+		assert_noop!(Alliance::retire(Origin::signed(3)), Error::<Test, ()>::UpForKicking);
+
+		// the motion is rejected, so the member may retire again
+		assert_ok!(Alliance::vote(Origin::signed(1), hash, 0, false));
+		assert_ok!(Alliance::vote(Origin::signed(2), hash, 0, false));
+		assert_ok!(Alliance::close(Origin::signed(1), hash, 0, proposal_weight, proposal_len));
+		assert!(!Alliance::up_for_kicking(3));
+		assert_ok!(Alliance::retire(Origin::signed(3)));
+	});
+}
+
+#[test]
+fn failed_kick_proposal_does_not_mark_member() {
+	new_test_ext().execute_with(|| {
+		let proposal = make_kick_member_proposal(3);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		assert_noop!(
+			Alliance::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len - 1),
+			pallet_collective::Error::<Test, pallet_collective::Instance1>::WrongProposalLength
+		);
+		assert!(!Alliance::up_for_kicking(3));
+		assert_ok!(Alliance::retire(Origin::signed(3)));
+	});
+}
+
+#[test]
+fn kick_member_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Alliance::kick_member(Origin::signed(2), 4), Error::<Test, ()>::NotMember);
+
+		assert_ok!(Alliance::join_alliance(Origin::signed(4)));
+		assert_eq!(Alliance::deposit_of(4), Some(25));
+		assert_noop!(Alliance::kick_member(Origin::signed(1), 4), BadOrigin);
+
+		assert_ok!(Alliance::kick_member(Origin::signed(2), 4));
+		assert!(!Alliance::is_member(&4));
+		assert_eq!(Alliance::deposit_of(4), None);
+		assert_eq!(Balances::reserved_balance(4), 10);
+		assert_eq!(Balances::total_balance(&4), 25);
+		assert_eq!(
+			last_event(),
+			mock::Event::Alliance(crate::Event::MemberKicked { member: 4, slashed: Some(25) })
+		);
+	});
+}
+
+#[test]
+fn add_unscrupulous_items_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Alliance::add_unscrupulous_items(Origin::signed(2), vec![]), BadOrigin);
+
+		assert_ok!(Alliance::add_unscrupulous_items(
+			Origin::signed(3),
+			vec![
+				UnscrupulousItem::AccountId(3),
+				UnscrupulousItem::Website("abc".as_bytes().to_vec().try_into().unwrap())
+			]
+		));
+		assert_eq!(Alliance::unscrupulous_accounts().into_inner(), vec![3]);
+		assert_eq!(Alliance::unscrupulous_websites().into_inner(), vec!["abc".as_bytes().to_vec()]);
+
+		assert_noop!(
+			Alliance::add_unscrupulous_items(
+				Origin::signed(3),
+				vec![UnscrupulousItem::AccountId(3)]
+			),
+			Error::<Test, ()>::AlreadyUnscrupulous
+		);
+	});
+}
+
+#[test]
+fn remove_unscrupulous_items_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Alliance::remove_unscrupulous_items(Origin::signed(2), vec![]), BadOrigin);
+
+		assert_noop!(
+			Alliance::remove_unscrupulous_items(
+				Origin::signed(3),
+				vec![UnscrupulousItem::AccountId(3)]
+			),
+			Error::<Test, ()>::NotListedAsUnscrupulous
+		);
+
+		assert_ok!(Alliance::add_unscrupulous_items(
+			Origin::signed(3),
+			vec![UnscrupulousItem::AccountId(3)]
+		));
+		assert_ok!(Alliance::remove_unscrupulous_items(
+			Origin::signed(3),
+			vec![UnscrupulousItem::AccountId(3)]
+		));
+		assert!(Alliance::unscrupulous_accounts().is_empty());
+	});
+}
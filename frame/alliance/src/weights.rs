@@ -0,0 +1,479 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_alliance
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2021-12-14, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+
+// Executed Command:
+// ./target/release/substrate
+// benchmark
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_alliance
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/alliance/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_alliance.
+pub trait WeightInfo {
+	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight;
+	fn vote(m: u32, ) -> Weight;
+	fn veto(p: u32, ) -> Weight;
+	fn close_early_disapproved(m: u32, p: u32, ) -> Weight;
+	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight;
+	fn close_disapproved(m: u32, p: u32, ) -> Weight;
+	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight;
+	fn init_members(x: u32, y: u32, z: u32, ) -> Weight;
+	fn set_rule() -> Weight;
+	fn announce() -> Weight;
+	fn remove_announcement() -> Weight;
+	fn join_alliance() -> Weight;
+	fn nominate_ally() -> Weight;
+	fn elevate_ally() -> Weight;
+	fn retire() -> Weight;
+	fn kick_member() -> Weight;
+	fn add_unscrupulous_items(n: u32, l: u32, ) -> Weight;
+	fn remove_unscrupulous_items(n: u32, l: u32, ) -> Weight;
+}
+
+/// Weights for pallet_alliance using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Alliance Members (r:2 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	// Storage: AllianceMotion ProposalCount (r:1 w:1)
+	// Storage: AllianceMotion Voting (r:0 w:1)
+	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight {
+		(39_992_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+			// Standard Error: 1_000
+			.saturating_add((101_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 1_000
+			.saturating_add((395_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Alliance Members (r:2 w:0)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	fn vote(m: u32, ) -> Weight {
+		(36_210_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((195_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	// Storage: AllianceMotion Voting (r:0 w:1)
+	fn veto(p: u32, ) -> Weight {
+		(30_589_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((372_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
+		(40_090_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((107_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 1_000
+			.saturating_add((339_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
+		(52_214_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
+			// Standard Error: 1_000
+			.saturating_add((113_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 1_000
+			.saturating_add((363_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion Prime (r:1 w:0)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	fn close_disapproved(m: u32, p: u32, ) -> Weight {
+		(47_208_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((106_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 1_000
+			.saturating_add((351_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion Prime (r:1 w:0)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
+		(46_917_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+			// Standard Error: 1_000
+			.saturating_add((111_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 1_000
+			.saturating_add((366_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:3)
+	// Storage: AllianceMotion Members (r:1 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	fn init_members(x: u32, y: u32, z: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 15_000
+			.saturating_add((264_000 as Weight).saturating_mul(x as Weight))
+			// Standard Error: 3_000
+			.saturating_add((227_000 as Weight).saturating_mul(y as Weight))
+			// Standard Error: 2_000
+			.saturating_add((160_000 as Weight).saturating_mul(z as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Alliance Rule (r:0 w:1)
+	fn set_rule() -> Weight {
+		(17_812_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Announcements (r:1 w:1)
+	fn announce() -> Weight {
+		(19_434_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Announcements (r:1 w:1)
+	fn remove_announcement() -> Weight {
+		(20_472_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Members (r:4 w:1)
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Alliance DepositOf (r:0 w:1)
+	fn join_alliance() -> Weight {
+		(61_239_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:4 w:1)
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	fn nominate_ally() -> Weight {
+		(42_102_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:2)
+	// Storage: AllianceMotion Proposals (r:1 w:0)
+	// Storage: AllianceMotion Members (r:0 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	fn elevate_ally() -> Weight {
+		(36_823_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Alliance UpForKicking (r:1 w:0)
+	// Storage: Alliance Members (r:3 w:1)
+	// Storage: Alliance DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AllianceMotion Proposals (r:1 w:0)
+	// Storage: AllianceMotion Members (r:0 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	fn retire() -> Weight {
+		(51_640_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:1)
+	// Storage: Alliance DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AllianceMotion Proposals (r:1 w:0)
+	// Storage: Alliance UpForKicking (r:0 w:1)
+	// Storage: AllianceMotion Members (r:0 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	fn kick_member() -> Weight {
+		(64_718_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:1)
+	// Storage: Alliance UnscrupulousWebsites (r:1 w:1)
+	fn add_unscrupulous_items(n: u32, l: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 16_000
+			.saturating_add((1_358_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 7_000
+			.saturating_add((115_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:1)
+	// Storage: Alliance UnscrupulousWebsites (r:1 w:1)
+	fn remove_unscrupulous_items(n: u32, l: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 340_000
+			.saturating_add((110_062_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 154_000
+			.saturating_add((7_278_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Alliance Members (r:2 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	// Storage: AllianceMotion ProposalCount (r:1 w:1)
+	// Storage: AllianceMotion Voting (r:0 w:1)
+	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight {
+		(39_992_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+			// Standard Error: 1_000
+			.saturating_add((101_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 1_000
+			.saturating_add((395_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Alliance Members (r:2 w:0)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	fn vote(m: u32, ) -> Weight {
+		(36_210_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((195_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	// Storage: AllianceMotion Voting (r:0 w:1)
+	fn veto(p: u32, ) -> Weight {
+		(30_589_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((372_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
+		(40_090_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((107_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 1_000
+			.saturating_add((339_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
+		(52_214_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
+			// Standard Error: 1_000
+			.saturating_add((113_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 1_000
+			.saturating_add((363_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion Prime (r:1 w:0)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	fn close_disapproved(m: u32, p: u32, ) -> Weight {
+		(47_208_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((106_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 1_000
+			.saturating_add((351_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion Prime (r:1 w:0)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
+		(46_917_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+			// Standard Error: 1_000
+			.saturating_add((111_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 1_000
+			.saturating_add((366_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:3)
+	// Storage: AllianceMotion Members (r:1 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	fn init_members(x: u32, y: u32, z: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 15_000
+			.saturating_add((264_000 as Weight).saturating_mul(x as Weight))
+			// Standard Error: 3_000
+			.saturating_add((227_000 as Weight).saturating_mul(y as Weight))
+			// Standard Error: 2_000
+			.saturating_add((160_000 as Weight).saturating_mul(z as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Alliance Rule (r:0 w:1)
+	fn set_rule() -> Weight {
+		(17_812_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Announcements (r:1 w:1)
+	fn announce() -> Weight {
+		(19_434_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Announcements (r:1 w:1)
+	fn remove_announcement() -> Weight {
+		(20_472_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Members (r:4 w:1)
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Alliance DepositOf (r:0 w:1)
+	fn join_alliance() -> Weight {
+		(61_239_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:4 w:1)
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	fn nominate_ally() -> Weight {
+		(42_102_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:2)
+	// Storage: AllianceMotion Proposals (r:1 w:0)
+	// Storage: AllianceMotion Members (r:0 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	fn elevate_ally() -> Weight {
+		(36_823_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Alliance UpForKicking (r:1 w:0)
+	// Storage: Alliance Members (r:3 w:1)
+	// Storage: Alliance DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AllianceMotion Proposals (r:1 w:0)
+	// Storage: AllianceMotion Members (r:0 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	fn retire() -> Weight {
+		(51_640_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:1)
+	// Storage: Alliance DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AllianceMotion Proposals (r:1 w:0)
+	// Storage: Alliance UpForKicking (r:0 w:1)
+	// Storage: AllianceMotion Members (r:0 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	fn kick_member() -> Weight {
+		(64_718_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:1)
+	// Storage: Alliance UnscrupulousWebsites (r:1 w:1)
+	fn add_unscrupulous_items(n: u32, l: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 16_000
+			.saturating_add((1_358_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 7_000
+			.saturating_add((115_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:1)
+	// Storage: Alliance UnscrupulousWebsites (r:1 w:1)
+	fn remove_unscrupulous_items(n: u32, l: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 340_000
+			.saturating_add((110_062_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 154_000
+			.saturating_add((7_278_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+}
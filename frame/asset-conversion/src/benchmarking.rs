@@ -0,0 +1,192 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Asset conversion pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::traits::fungible;
+use frame_system::RawOrigin as SystemOrigin;
+
+use crate::Pallet as AssetConversion;
+
+/// The unit, in the native currency and the assets, of the amounts used by the benchmarks.
+fn unit<T: Config>() -> T::Balance {
+	T::Currency::minimum_balance().max(One::one())
+}
+
+/// Create a funded caller and an asset with `id`, of which the caller holds a large balance.
+fn create_asset<T: Config>(id: u32) -> (T::AccountId, MultiAssetIdOf<T>)
+where
+	T::Currency: fungible::Mutate<T::AccountId>,
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	T::AssetId: From<u32>,
+{
+	let caller: T::AccountId = whitelisted_caller();
+	let amount = unit::<T>().saturating_mul(1_000_000u32.into());
+	if T::Currency::balance(&caller).is_zero() {
+		let funds = amount.saturating_add(T::PoolSetupFee::get().saturating_mul(10u32.into()));
+		assert!(<T::Currency as fungible::Mutate<_>>::mint_into(&caller, funds).is_ok());
+	}
+	let asset_id: T::AssetId = id.into();
+	assert!(T::Assets::create(asset_id, caller.clone(), true, One::one()).is_ok());
+	assert!(T::Assets::mint_into(asset_id, &caller, amount).is_ok());
+	(caller, NativeOrAssetId::Asset(asset_id))
+}
+
+/// Create the pool of the native currency and the asset with `id`, with liquidity provided by
+/// the caller.
+fn create_pool_with_liquidity<T: Config>(id: u32) -> (T::AccountId, MultiAssetIdOf<T>)
+where
+	T::Currency: fungible::Mutate<T::AccountId>,
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	T::AssetId: From<u32>,
+{
+	let (caller, asset) = create_asset::<T>(id);
+	let amount = unit::<T>().saturating_mul(1_000u32.into());
+	assert!(AssetConversion::<T>::create_pool(
+		SystemOrigin::Signed(caller.clone()).into(),
+		NativeOrAssetId::Native,
+		asset,
+	)
+	.is_ok());
+	assert!(AssetConversion::<T>::add_liquidity(
+		SystemOrigin::Signed(caller.clone()).into(),
+		NativeOrAssetId::Native,
+		asset,
+		amount,
+		amount,
+		One::one(),
+		One::one(),
+		caller.clone(),
+	)
+	.is_ok());
+	(caller, asset)
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Create a swap path of `n` assets, alternating between assets and the native currency, with a
+/// pool for each hop.
+fn create_swap_path<T: Config>(n: u32) -> (T::AccountId, Vec<MultiAssetIdOf<T>>)
+where
+	T::Currency: fungible::Mutate<T::AccountId>,
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	T::AssetId: From<u32>,
+{
+	let path = (0..n)
+		.map(|i| {
+			if i % 2 == 0 {
+				create_pool_with_liquidity::<T>(i / 2 + 1).1
+			} else {
+				NativeOrAssetId::Native
+			}
+		})
+		.collect();
+	(whitelisted_caller(), path)
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T::Currency: fungible::Mutate<T::AccountId>,
+			T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+			T::AssetId: From<u32>,
+	}
+
+	create_pool {
+		let (caller, asset) = create_asset::<T>(1);
+	}: _(SystemOrigin::Signed(caller.clone()), NativeOrAssetId::Native, asset)
+	verify {
+		let pool_id = (NativeOrAssetId::Native, asset);
+		let lp_token = Pools::<T>::get(&pool_id).unwrap().lp_token;
+		assert_last_event::<T>(Event::PoolCreated { creator: caller, pool_id, lp_token }.into());
+	}
+
+	add_liquidity {
+		let (caller, asset) = create_pool_with_liquidity::<T>(1);
+		let amount = unit::<T>().saturating_mul(100u32.into());
+		let pool_id = (NativeOrAssetId::Native, asset);
+		let lp_token = Pools::<T>::get(&pool_id).unwrap().lp_token;
+		let total_supply = T::PoolAssets::total_issuance(lp_token);
+	}: _(
+		SystemOrigin::Signed(caller.clone()),
+		NativeOrAssetId::Native,
+		asset,
+		amount,
+		amount,
+		Zero::zero(),
+		Zero::zero(),
+		caller.clone()
+	)
+	verify {
+		assert!(T::PoolAssets::total_issuance(lp_token) > total_supply);
+	}
+
+	remove_liquidity {
+		let (caller, asset) = create_pool_with_liquidity::<T>(1);
+		let pool_id = (NativeOrAssetId::Native, asset);
+		let lp_token = Pools::<T>::get(&pool_id).unwrap().lp_token;
+		let lp_token_burn = T::PoolAssets::balance(lp_token, &caller) / 2u32.into();
+		let total_supply = T::PoolAssets::total_issuance(lp_token);
+	}: _(
+		SystemOrigin::Signed(caller.clone()),
+		NativeOrAssetId::Native,
+		asset,
+		lp_token_burn,
+		Zero::zero(),
+		Zero::zero(),
+		caller.clone()
+	)
+	verify {
+		assert_eq!(T::PoolAssets::total_issuance(lp_token), total_supply - lp_token_burn);
+	}
+
+	swap_exact_tokens_for_tokens {
+		let n in 2 .. T::MaxSwapPathLength::get();
+		let (caller, path) = create_swap_path::<T>(n);
+		let amount_in = unit::<T>().saturating_mul(10u32.into());
+		let balance = AssetConversion::<T>::get_balance(&caller, &path[0]);
+	}: _(SystemOrigin::Signed(caller.clone()), path.clone(), amount_in, One::one(), caller.clone(), false)
+	verify {
+		assert_eq!(AssetConversion::<T>::get_balance(&caller, &path[0]), balance - amount_in);
+	}
+
+	swap_tokens_for_exact_tokens {
+		let n in 2 .. T::MaxSwapPathLength::get();
+		let (caller, path) = create_swap_path::<T>(n);
+		let amount_out = unit::<T>().saturating_mul(10u32.into());
+		let last = path[path.len() - 1];
+		let balance = AssetConversion::<T>::get_balance(&caller, &last);
+	}: _(
+		SystemOrigin::Signed(caller.clone()),
+		path,
+		amount_out,
+		unit::<T>().saturating_mul(100u32.into()),
+		caller.clone(),
+		false
+	)
+	verify {
+		assert_eq!(AssetConversion::<T>::get_balance(&caller, &last), balance + amount_out);
+	}
+
+	impl_benchmark_test_suite!(AssetConversion, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,877 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Asset Conversion Pallet
+//!
+//! A simple decentralised exchange, based on constant product liquidity pools, that allows to
+//! swap between the native currency of the chain and the assets of a `fungibles` implementation
+//! (e.g. `pallet-assets`), as well as between the assets themselves.
+//!
+//! ## Overview
+//!
+//! Anyone can create a liquidity pool for a pair of assets with [`Pallet::create_pool`], by paying
+//! [`Config::PoolSetupFee`]. Each pool holds a reserve of both of its assets in an account derived
+//! from [`Config::PalletId`] and the ids of the assets.
+//!
+//! Liquidity providers add both assets to a pool, in the ratio of its current reserves, with
+//! [`Pallet::add_liquidity`]. In return, they receive liquidity pool tokens, which are minted
+//! through the [`Config::PoolAssets`] implementation of `fungibles::Mutate`. The liquidity pool
+//! tokens can later be burned with [`Pallet::remove_liquidity`] to withdraw a proportional share
+//! of the reserves. When liquidity is first added to a pool, [`Config::MintMinLiquidity`] tokens
+//! are locked in the pool forever, such that its reserves can never be completely drained.
+//!
+//! Swaps keep the product of the two reserves of a pool constant, minus the fee of
+//! [`Config::LPFee`] that is left in the pool for the liquidity providers. A swap can go through
+//! several pools, given by a path of assets where each consecutive pair of assets has a pool:
+//!
+//! - [`Pallet::swap_exact_tokens_for_tokens`] pays an exact amount of the first asset of the path
+//!   for as much as possible of the last one.
+//! - [`Pallet::swap_tokens_for_exact_tokens`] pays as little as possible of the first asset of the
+//!   path for an exact amount of the last one.
+//!
+//! Both are also available to other pallets through the [`Swap`] trait, and the prices of swaps can
+//! be quoted off-chain through the [`AssetConversionApi`] runtime API.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub mod weights;
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+use codec::Codec;
+use frame_support::{
+	ensure,
+	traits::{
+		fungible::{Inspect as InspectFungible, Transfer as TransferFungible},
+		fungibles::{Create, Inspect, Mutate, Transfer},
+		Get,
+	},
+	transactional, PalletId,
+};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{AccountIdConversion, CheckedAdd, IntegerSquareRoot, One, Saturating, Zero},
+	DispatchError,
+};
+use sp_std::{convert::TryInto, prelude::*};
+
+/// The identifier of either the native currency or an asset.
+pub type MultiAssetIdOf<T> = NativeOrAssetId<<T as Config>::AssetId>;
+
+/// The identifier of a pool, consisting of the ids of its two assets in ascending order.
+pub type PoolIdOf<T> = (MultiAssetIdOf<T>, MultiAssetIdOf<T>);
+
+/// The denominator of [`Config::LPFee`].
+const FEE_DENOMINATOR: u128 = 1000;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AtLeast32BitUnsigned, MaybeSerializeDeserialize};
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The native currency.
+		type Currency: InspectFungible<Self::AccountId, Balance = Self::Balance>
+			+ TransferFungible<Self::AccountId>;
+
+		/// The balance type of the native currency, the assets and the liquidity pool tokens.
+		type Balance: Member
+			+ Parameter
+			+ AtLeast32BitUnsigned
+			+ Default
+			+ Copy
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen;
+
+		/// Identifier of the assets that can be swapped.
+		type AssetId: Member + Parameter + Copy + Ord + MaybeSerializeDeserialize + MaxEncodedLen;
+
+		/// The assets that can be swapped, against each other and against the native currency.
+		type Assets: Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = Self::Balance>
+			+ Transfer<Self::AccountId>;
+
+		/// Identifier of the liquidity pool tokens.
+		type PoolAssetId: Member
+			+ Parameter
+			+ AtLeast32BitUnsigned
+			+ Default
+			+ Copy
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen;
+
+		/// The registry of the liquidity pool tokens.
+		///
+		/// This should be a dedicated instance, in which no tokens can be created or minted other
+		/// than by this pallet.
+		type PoolAssets: Inspect<Self::AccountId, AssetId = Self::PoolAssetId, Balance = Self::Balance>
+			+ Create<Self::AccountId>
+			+ Mutate<Self::AccountId>;
+
+		/// The fee of every swap, in per-mille of the amount paid in, which is left in the pool
+		/// for the liquidity providers.
+		#[pallet::constant]
+		type LPFee: Get<u32>;
+
+		/// The amount of the native currency that has to be paid to create a pool.
+		#[pallet::constant]
+		type PoolSetupFee: Get<Self::Balance>;
+
+		/// The account that receives the pool setup fees.
+		type PoolSetupFeeReceiver: Get<Self::AccountId>;
+
+		/// The amount of liquidity pool tokens that is locked in a pool when liquidity is first
+		/// added to it.
+		#[pallet::constant]
+		type MintMinLiquidity: Get<Self::Balance>;
+
+		/// The maximum number of assets in the path of a swap, including the first and the last
+		/// one.
+		#[pallet::constant]
+		type MaxSwapPathLength: Get<u32>;
+
+		/// The pallet id, used to derive the accounts of the pools.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The liquidity pools, by their pool id.
+	#[pallet::storage]
+	pub type Pools<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolIdOf<T>, PoolInfo<T::PoolAssetId>, OptionQuery>;
+
+	/// The id of the liquidity pool token of the next pool that is created.
+	#[pallet::storage]
+	pub type NextPoolAssetId<T: Config> = StorageValue<_, T::PoolAssetId, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A liquidity pool was created.
+		PoolCreated { creator: T::AccountId, pool_id: PoolIdOf<T>, lp_token: T::PoolAssetId },
+		/// Liquidity was added to a pool, and liquidity pool tokens were minted to `mint_to`.
+		LiquidityAdded {
+			who: T::AccountId,
+			mint_to: T::AccountId,
+			pool_id: PoolIdOf<T>,
+			amount1_provided: T::Balance,
+			amount2_provided: T::Balance,
+			lp_token: T::PoolAssetId,
+			lp_token_minted: T::Balance,
+		},
+		/// Liquidity was removed from a pool, and the withdrawn assets were sent to `withdraw_to`.
+		LiquidityRemoved {
+			who: T::AccountId,
+			withdraw_to: T::AccountId,
+			pool_id: PoolIdOf<T>,
+			amount1: T::Balance,
+			amount2: T::Balance,
+			lp_token: T::PoolAssetId,
+			lp_token_burned: T::Balance,
+		},
+		/// `amount_in` of the first asset of `path` was swapped for `amount_out` of the last
+		/// asset of `path`, which was sent to `send_to`.
+		SwapExecuted {
+			who: T::AccountId,
+			send_to: T::AccountId,
+			path: Vec<MultiAssetIdOf<T>>,
+			amount_in: T::Balance,
+			amount_out: T::Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The two assets of a pool must be different.
+		EqualAssets,
+		/// A pool for the given assets already exists.
+		PoolExists,
+		/// No pool exists for the given assets.
+		PoolNotFound,
+		/// The desired amounts of liquidity to add must not be zero.
+		WrongDesiredAmount,
+		/// The amount of the first asset is less than the given minimum.
+		AmountOneLessThanMinimal,
+		/// The amount of the second asset is less than the given minimum.
+		AmountTwoLessThanMinimal,
+		/// The amount of liquidity pool tokens to mint is too low.
+		InsufficientLiquidityMinted,
+		/// The amount to swap or burn must not be zero.
+		ZeroAmount,
+		/// The pool does not hold any liquidity.
+		ZeroLiquidity,
+		/// The pool does not hold enough liquidity to pay out the requested amount.
+		InsufficientLiquidity,
+		/// The swap would give less than the given minimum.
+		ProvidedMinimumNotSufficientForSwap,
+		/// The swap would cost more than the given maximum.
+		ProvidedMaximumNotSufficientForSwap,
+		/// The path of a swap is too short or too long, or has the same asset twice in a row.
+		InvalidPath,
+		/// An arithmetic overflow occurred.
+		Overflow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			assert!(
+				(T::LPFee::get() as u128) < FEE_DENOMINATOR,
+				"the LP fee must be less than 100%",
+			);
+			assert!(T::MaxSwapPathLength::get() >= 2, "a swap path has at least two assets");
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a liquidity pool for `asset1` and `asset2`.
+		///
+		/// The dispatch origin for this call must be _Signed_ and pays [`Config::PoolSetupFee`].
+		///
+		/// The pool gets its own liquidity pool token, which is created in
+		/// [`Config::PoolAssets`].
+		///
+		/// Emits `PoolCreated`.
+		#[pallet::weight(T::WeightInfo::create_pool())]
+		#[transactional]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			asset1: MultiAssetIdOf<T>,
+			asset2: MultiAssetIdOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(asset1 != asset2, Error::<T>::EqualAssets);
+
+			let pool_id = Self::get_pool_id(asset1, asset2);
+			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
+
+			let setup_fee = T::PoolSetupFee::get();
+			if !setup_fee.is_zero() {
+				T::Currency::transfer(&sender, &T::PoolSetupFeeReceiver::get(), setup_fee, true)?;
+			}
+
+			// the pool account must be able to hold the reserves of any asset, regardless of its
+			// balance of the native currency.
+			let pool_account = Self::get_pool_account(&pool_id);
+			frame_system::Pallet::<T>::inc_providers(&pool_account);
+
+			let lp_token = NextPoolAssetId::<T>::get();
+			let next_lp_token = lp_token.checked_add(&One::one()).ok_or(Error::<T>::Overflow)?;
+			NextPoolAssetId::<T>::put(next_lp_token);
+			T::PoolAssets::create(lp_token, pool_account, false, One::one())?;
+
+			Pools::<T>::insert(&pool_id, PoolInfo { lp_token });
+			Self::deposit_event(Event::PoolCreated { creator: sender, pool_id, lp_token });
+			Ok(())
+		}
+
+		/// Add liquidity to the pool of `asset1` and `asset2`, and mint liquidity pool tokens to
+		/// `mint_to` in return.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Up to `amount1_desired` of `asset1` and `amount2_desired` of `asset2` are added, in the
+		/// ratio of the current reserves of the pool. The call fails if less than `amount1_min` or
+		/// `amount2_min` would be added. The first liquidity provider of a pool sets its initial
+		/// price, and [`Config::MintMinLiquidity`] of the minted tokens are locked in the pool.
+		///
+		/// Emits `LiquidityAdded`.
+		#[pallet::weight(T::WeightInfo::add_liquidity())]
+		#[transactional]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			asset1: MultiAssetIdOf<T>,
+			asset2: MultiAssetIdOf<T>,
+			amount1_desired: T::Balance,
+			amount2_desired: T::Balance,
+			amount1_min: T::Balance,
+			amount2_min: T::Balance,
+			mint_to: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let pool_id = Self::get_pool_id(asset1, asset2);
+			// sort the amounts in the same order as the assets of the pool.
+			let (amount1_desired, amount2_desired, amount1_min, amount2_min) =
+				if pool_id.0 == asset1 {
+					(amount1_desired, amount2_desired, amount1_min, amount2_min)
+				} else {
+					(amount2_desired, amount1_desired, amount2_min, amount1_min)
+				};
+			ensure!(
+				!amount1_desired.is_zero() && !amount2_desired.is_zero(),
+				Error::<T>::WrongDesiredAmount
+			);
+
+			let pool = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let (asset1, asset2) = pool_id;
+			let pool_account = Self::get_pool_account(&pool_id);
+			let reserve1 = Self::get_balance(&pool_account, &asset1);
+			let reserve2 = Self::get_balance(&pool_account, &asset2);
+
+			let (amount1, amount2) = if reserve1.is_zero() || reserve2.is_zero() {
+				(amount1_desired, amount2_desired)
+			} else {
+				let amount2_optimal = Self::quote(&amount1_desired, &reserve1, &reserve2)?;
+				if amount2_optimal <= amount2_desired {
+					(amount1_desired, amount2_optimal)
+				} else {
+					let amount1_optimal = Self::quote(&amount2_desired, &reserve2, &reserve1)?;
+					debug_assert!(amount1_optimal <= amount1_desired);
+					(amount1_optimal, amount2_desired)
+				}
+			};
+			ensure!(amount1 >= amount1_min, Error::<T>::AmountOneLessThanMinimal);
+			ensure!(amount2 >= amount2_min, Error::<T>::AmountTwoLessThanMinimal);
+
+			Self::transfer(&asset1, &sender, &pool_account, amount1, true)?;
+			Self::transfer(&asset2, &sender, &pool_account, amount2, true)?;
+
+			let total_supply = T::PoolAssets::total_issuance(pool.lp_token);
+			let lp_token_amount = if total_supply.is_zero() {
+				let liquidity = Self::calc_initial_liquidity(&amount1, &amount2)?;
+				let min_liquidity = T::MintMinLiquidity::get();
+				// lock the minimum liquidity in the pool, such that it can never be fully drained.
+				T::PoolAssets::mint_into(pool.lp_token, &pool_account, min_liquidity)?;
+				liquidity.saturating_sub(min_liquidity)
+			} else {
+				let side1 = Self::mul_div(&amount1, &total_supply, &reserve1)?;
+				let side2 = Self::mul_div(&amount2, &total_supply, &reserve2)?;
+				side1.min(side2)
+			};
+			ensure!(!lp_token_amount.is_zero(), Error::<T>::InsufficientLiquidityMinted);
+			T::PoolAssets::mint_into(pool.lp_token, &mint_to, lp_token_amount)?;
+
+			Self::deposit_event(Event::LiquidityAdded {
+				who: sender,
+				mint_to,
+				pool_id,
+				amount1_provided: amount1,
+				amount2_provided: amount2,
+				lp_token: pool.lp_token,
+				lp_token_minted: lp_token_amount,
+			});
+			Ok(())
+		}
+
+		/// Burn `lp_token_burn` liquidity pool tokens of the pool of `asset1` and `asset2`, and
+		/// send the proportional share of its reserves to `withdraw_to`.
+		///
+		/// The dispatch origin for this call must be _Signed_ and hold the tokens to burn.
+		///
+		/// The call fails if less than `amount1_min_receive` of `asset1` or `amount2_min_receive`
+		/// of `asset2` would be withdrawn.
+		///
+		/// Emits `LiquidityRemoved`.
+		#[pallet::weight(T::WeightInfo::remove_liquidity())]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			asset1: MultiAssetIdOf<T>,
+			asset2: MultiAssetIdOf<T>,
+			lp_token_burn: T::Balance,
+			amount1_min_receive: T::Balance,
+			amount2_min_receive: T::Balance,
+			withdraw_to: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let pool_id = Self::get_pool_id(asset1, asset2);
+			// sort the amounts in the same order as the assets of the pool.
+			let (amount1_min_receive, amount2_min_receive) = if pool_id.0 == asset1 {
+				(amount1_min_receive, amount2_min_receive)
+			} else {
+				(amount2_min_receive, amount1_min_receive)
+			};
+			ensure!(!lp_token_burn.is_zero(), Error::<T>::ZeroAmount);
+
+			let pool = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let (asset1, asset2) = pool_id;
+			let pool_account = Self::get_pool_account(&pool_id);
+			let reserve1 = Self::get_balance(&pool_account, &asset1);
+			let reserve2 = Self::get_balance(&pool_account, &asset2);
+
+			let total_supply = T::PoolAssets::total_issuance(pool.lp_token);
+			let amount1 = Self::mul_div(&lp_token_burn, &reserve1, &total_supply)?;
+			let amount2 = Self::mul_div(&lp_token_burn, &reserve2, &total_supply)?;
+			ensure!(
+				!amount1.is_zero() && amount1 >= amount1_min_receive,
+				Error::<T>::AmountOneLessThanMinimal
+			);
+			ensure!(
+				!amount2.is_zero() && amount2 >= amount2_min_receive,
+				Error::<T>::AmountTwoLessThanMinimal
+			);
+
+			T::PoolAssets::burn_from(pool.lp_token, &sender, lp_token_burn)?;
+			Self::transfer(&asset1, &pool_account, &withdraw_to, amount1, true)?;
+			Self::transfer(&asset2, &pool_account, &withdraw_to, amount2, true)?;
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				who: sender,
+				withdraw_to,
+				pool_id,
+				amount1,
+				amount2,
+				lp_token: pool.lp_token,
+				lp_token_burned: lp_token_burn,
+			});
+			Ok(())
+		}
+
+		/// Swap exactly `amount_in` of the first asset of `path` for as much as possible of the
+		/// last asset of `path`, which is sent to `send_to`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Each consecutive pair of assets in `path` must have a pool. The call fails if less than
+		/// `amount_out_min` would be received. If `keep_alive` is set, the account of the origin
+		/// is kept alive.
+		///
+		/// Emits `SwapExecuted`.
+		#[pallet::weight(T::WeightInfo::swap_exact_tokens_for_tokens(path.len() as u32))]
+		pub fn swap_exact_tokens_for_tokens(
+			origin: OriginFor<T>,
+			path: Vec<MultiAssetIdOf<T>>,
+			amount_in: T::Balance,
+			amount_out_min: T::Balance,
+			send_to: T::AccountId,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_swap_exact_tokens_for_tokens(
+				sender,
+				path,
+				amount_in,
+				Some(amount_out_min),
+				send_to,
+				keep_alive,
+			)?;
+			Ok(())
+		}
+
+		/// Swap as little as possible of the first asset of `path` for exactly `amount_out` of
+		/// the last asset of `path`, which is sent to `send_to`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Each consecutive pair of assets in `path` must have a pool. The call fails if more than
+		/// `amount_in_max` would need to be paid. If `keep_alive` is set, the account of the
+		/// origin is kept alive.
+		///
+		/// Emits `SwapExecuted`.
+		#[pallet::weight(T::WeightInfo::swap_tokens_for_exact_tokens(path.len() as u32))]
+		pub fn swap_tokens_for_exact_tokens(
+			origin: OriginFor<T>,
+			path: Vec<MultiAssetIdOf<T>>,
+			amount_out: T::Balance,
+			amount_in_max: T::Balance,
+			send_to: T::AccountId,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_swap_tokens_for_exact_tokens(
+				sender,
+				path,
+				amount_out,
+				Some(amount_in_max),
+				send_to,
+				keep_alive,
+			)?;
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Swap exactly `amount_in` of the first asset of `path` for as much as possible of the last
+	/// asset of `path`. See [`Swap::swap_exact_tokens_for_tokens`].
+	pub fn do_swap_exact_tokens_for_tokens(
+		sender: T::AccountId,
+		path: Vec<MultiAssetIdOf<T>>,
+		amount_in: T::Balance,
+		amount_out_min: Option<T::Balance>,
+		send_to: T::AccountId,
+		keep_alive: bool,
+	) -> Result<T::Balance, DispatchError> {
+		ensure!(!amount_in.is_zero(), Error::<T>::ZeroAmount);
+		Self::validate_swap_path(&path)?;
+
+		let amounts = Self::get_amounts_out(&amount_in, &path)?;
+		let amount_out = *amounts.last().ok_or(Error::<T>::InvalidPath)?;
+		ensure!(!amount_out.is_zero(), Error::<T>::ZeroAmount);
+		if let Some(amount_out_min) = amount_out_min {
+			ensure!(amount_out >= amount_out_min, Error::<T>::ProvidedMinimumNotSufficientForSwap);
+		}
+
+		Self::do_swap(sender, &amounts, path, send_to, keep_alive)?;
+		Ok(amount_out)
+	}
+
+	/// Swap as little as possible of the first asset of `path` for exactly `amount_out` of the
+	/// last asset of `path`. See [`Swap::swap_tokens_for_exact_tokens`].
+	pub fn do_swap_tokens_for_exact_tokens(
+		sender: T::AccountId,
+		path: Vec<MultiAssetIdOf<T>>,
+		amount_out: T::Balance,
+		amount_in_max: Option<T::Balance>,
+		send_to: T::AccountId,
+		keep_alive: bool,
+	) -> Result<T::Balance, DispatchError> {
+		ensure!(!amount_out.is_zero(), Error::<T>::ZeroAmount);
+		Self::validate_swap_path(&path)?;
+
+		let amounts = Self::get_amounts_in(&amount_out, &path)?;
+		let amount_in = *amounts.first().ok_or(Error::<T>::InvalidPath)?;
+		if let Some(amount_in_max) = amount_in_max {
+			ensure!(amount_in <= amount_in_max, Error::<T>::ProvidedMaximumNotSufficientForSwap);
+		}
+
+		Self::do_swap(sender, &amounts, path, send_to, keep_alive)?;
+		Ok(amount_in)
+	}
+
+	/// Move the `amounts` along the pools of `path`: the first amount is paid by `sender` into
+	/// the first pool, and each pool pays the next amount into the next pool, or to `send_to` for
+	/// the last one.
+	#[transactional]
+	fn do_swap(
+		sender: T::AccountId,
+		amounts: &[T::Balance],
+		path: Vec<MultiAssetIdOf<T>>,
+		send_to: T::AccountId,
+		keep_alive: bool,
+	) -> Result<(), DispatchError> {
+		let first_pool = Self::get_pool_account(&Self::get_pool_id(path[0], path[1]));
+		Self::transfer(&path[0], &sender, &first_pool, amounts[0], keep_alive)?;
+
+		for (hop, pair) in path.windows(2).enumerate() {
+			let pool_account = Self::get_pool_account(&Self::get_pool_id(pair[0], pair[1]));
+			let destination = match path.get(hop + 2) {
+				Some(next) => Self::get_pool_account(&Self::get_pool_id(pair[1], *next)),
+				None => send_to.clone(),
+			};
+			Self::transfer(&pair[1], &pool_account, &destination, amounts[hop + 1], true)?;
+		}
+
+		Self::deposit_event(Event::SwapExecuted {
+			who: sender,
+			send_to,
+			amount_in: amounts[0],
+			amount_out: amounts[amounts.len() - 1],
+			path,
+		});
+		Ok(())
+	}
+
+	/// The id of the pool of `asset1` and `asset2`, regardless of their order.
+	pub fn get_pool_id(asset1: MultiAssetIdOf<T>, asset2: MultiAssetIdOf<T>) -> PoolIdOf<T> {
+		if asset1 <= asset2 {
+			(asset1, asset2)
+		} else {
+			(asset2, asset1)
+		}
+	}
+
+	/// The account that holds the reserves of the pool with `pool_id`.
+	pub fn get_pool_account(pool_id: &PoolIdOf<T>) -> T::AccountId {
+		T::PalletId::get().into_sub_account(pool_id)
+	}
+
+	/// The reserves of `asset1` and `asset2` in their pool, in the order of the arguments.
+	pub fn get_reserves(
+		asset1: &MultiAssetIdOf<T>,
+		asset2: &MultiAssetIdOf<T>,
+	) -> Result<(T::Balance, T::Balance), Error<T>> {
+		let pool_id = Self::get_pool_id(*asset1, *asset2);
+		ensure!(Pools::<T>::contains_key(&pool_id), Error::<T>::PoolNotFound);
+		let pool_account = Self::get_pool_account(&pool_id);
+
+		let reserve1 = Self::get_balance(&pool_account, asset1);
+		let reserve2 = Self::get_balance(&pool_account, asset2);
+		ensure!(!reserve1.is_zero() && !reserve2.is_zero(), Error::<T>::ZeroLiquidity);
+
+		Ok((reserve1, reserve2))
+	}
+
+	/// The amount of `asset2` that exactly `amount` of `asset1` is swapped for, or the spot price
+	/// of `amount` if `include_fee` is not set.
+	pub fn quote_price_exact_tokens_for_tokens(
+		asset1: MultiAssetIdOf<T>,
+		asset2: MultiAssetIdOf<T>,
+		amount: T::Balance,
+		include_fee: bool,
+	) -> Option<T::Balance> {
+		let (reserve1, reserve2) = Self::get_reserves(&asset1, &asset2).ok()?;
+		if include_fee {
+			Self::get_amount_out(&amount, &reserve1, &reserve2).ok()
+		} else {
+			Self::quote(&amount, &reserve1, &reserve2).ok()
+		}
+	}
+
+	/// The amount of `asset1` that has to be swapped for exactly `amount` of `asset2`, or the spot
+	/// price of `amount` if `include_fee` is not set.
+	pub fn quote_price_tokens_for_exact_tokens(
+		asset1: MultiAssetIdOf<T>,
+		asset2: MultiAssetIdOf<T>,
+		amount: T::Balance,
+		include_fee: bool,
+	) -> Option<T::Balance> {
+		let (reserve1, reserve2) = Self::get_reserves(&asset1, &asset2).ok()?;
+		if include_fee {
+			Self::get_amount_in(&amount, &reserve1, &reserve2).ok()
+		} else {
+			Self::quote(&amount, &reserve2, &reserve1).ok()
+		}
+	}
+
+	/// The amount of `reserve2` that is worth `amount` of `reserve1`, without any fee.
+	pub fn quote(
+		amount: &T::Balance,
+		reserve1: &T::Balance,
+		reserve2: &T::Balance,
+	) -> Result<T::Balance, Error<T>> {
+		Self::mul_div(amount, reserve2, reserve1)
+	}
+
+	/// The amount of the out asset that exactly `amount_in` of the in asset is swapped for, given
+	/// the reserves of the pool.
+	pub fn get_amount_out(
+		amount_in: &T::Balance,
+		reserve_in: &T::Balance,
+		reserve_out: &T::Balance,
+	) -> Result<T::Balance, Error<T>> {
+		ensure!(!reserve_in.is_zero() && !reserve_out.is_zero(), Error::<T>::ZeroLiquidity);
+
+		let amount_in_with_fee = Self::to_u128(amount_in)?
+			.checked_mul(FEE_DENOMINATOR - T::LPFee::get() as u128)
+			.ok_or(Error::<T>::Overflow)?;
+		let denominator = Self::to_u128(reserve_in)?
+			.checked_mul(FEE_DENOMINATOR)
+			.and_then(|r| r.checked_add(amount_in_with_fee))
+			.ok_or(Error::<T>::Overflow)?;
+
+		multiply_by_rational(amount_in_with_fee, Self::to_u128(reserve_out)?, denominator)
+			.map_err(|_| Error::<T>::Overflow)?
+			.try_into()
+			.map_err(|_| Error::<T>::Overflow)
+	}
+
+	/// The amount of the in asset that has to be swapped for exactly `amount_out` of the out
+	/// asset, given the reserves of the pool.
+	pub fn get_amount_in(
+		amount_out: &T::Balance,
+		reserve_in: &T::Balance,
+		reserve_out: &T::Balance,
+	) -> Result<T::Balance, Error<T>> {
+		ensure!(!reserve_in.is_zero() && !reserve_out.is_zero(), Error::<T>::ZeroLiquidity);
+		ensure!(amount_out < reserve_out, Error::<T>::InsufficientLiquidity);
+
+		let numerator = Self::to_u128(amount_out)?
+			.checked_mul(FEE_DENOMINATOR)
+			.ok_or(Error::<T>::Overflow)?;
+		let denominator = Self::to_u128(&(*reserve_out - *amount_out))?
+			.checked_mul(FEE_DENOMINATOR - T::LPFee::get() as u128)
+			.ok_or(Error::<T>::Overflow)?;
+
+		// round up, such that the pool never pays out more than it receives.
+		multiply_by_rational(Self::to_u128(reserve_in)?, numerator, denominator)
+			.ok()
+			.and_then(|amount_in| amount_in.checked_add(1))
+			.ok_or(Error::<T>::Overflow)?
+			.try_into()
+			.map_err(|_| Error::<T>::Overflow)
+	}
+
+	/// The amounts of all assets of `path` when swapping exactly `amount_in` of the first one.
+	fn get_amounts_out(
+		amount_in: &T::Balance,
+		path: &[MultiAssetIdOf<T>],
+	) -> Result<Vec<T::Balance>, DispatchError> {
+		let mut amounts = vec![*amount_in];
+		for pair in path.windows(2) {
+			let (reserve_in, reserve_out) = Self::get_reserves(&pair[0], &pair[1])?;
+			let amount_in = amounts[amounts.len() - 1];
+			amounts.push(Self::get_amount_out(&amount_in, &reserve_in, &reserve_out)?);
+		}
+		Ok(amounts)
+	}
+
+	/// The amounts of all assets of `path` when swapping for exactly `amount_out` of the last one.
+	fn get_amounts_in(
+		amount_out: &T::Balance,
+		path: &[MultiAssetIdOf<T>],
+	) -> Result<Vec<T::Balance>, DispatchError> {
+		let mut amounts = vec![*amount_out];
+		for pair in path.windows(2).rev() {
+			let (reserve_in, reserve_out) = Self::get_reserves(&pair[0], &pair[1])?;
+			let amount_out = amounts[0];
+			amounts.insert(0, Self::get_amount_in(&amount_out, &reserve_in, &reserve_out)?);
+		}
+		Ok(amounts)
+	}
+
+	/// Ensure that `path` has a valid length and never has the same asset twice in a row.
+	fn validate_swap_path(path: &[MultiAssetIdOf<T>]) -> Result<(), Error<T>> {
+		ensure!(
+			path.len() >= 2 && path.len() <= T::MaxSwapPathLength::get() as usize,
+			Error::<T>::InvalidPath
+		);
+		ensure!(path.windows(2).all(|pair| pair[0] != pair[1]), Error::<T>::InvalidPath);
+		Ok(())
+	}
+
+	/// The liquidity pool tokens minted for the first liquidity of a pool.
+	fn calc_initial_liquidity(
+		amount1: &T::Balance,
+		amount2: &T::Balance,
+	) -> Result<T::Balance, Error<T>> {
+		Self::to_u128(amount1)?
+			.checked_mul(Self::to_u128(amount2)?)
+			.ok_or(Error::<T>::Overflow)?
+			.integer_sqrt()
+			.try_into()
+			.map_err(|_| Error::<T>::Overflow)
+	}
+
+	/// Calculate `a * b / c`, with a higher precision intermediate result.
+	fn mul_div(a: &T::Balance, b: &T::Balance, c: &T::Balance) -> Result<T::Balance, Error<T>> {
+		ensure!(!c.is_zero(), Error::<T>::ZeroLiquidity);
+		multiply_by_rational(Self::to_u128(a)?, Self::to_u128(b)?, Self::to_u128(c)?)
+			.map_err(|_| Error::<T>::Overflow)?
+			.try_into()
+			.map_err(|_| Error::<T>::Overflow)
+	}
+
+	fn to_u128(balance: &T::Balance) -> Result<u128, Error<T>> {
+		(*balance).try_into().map_err(|_| Error::<T>::Overflow)
+	}
+
+	/// The balance of `asset` that `owner` can transfer without being reaped.
+	fn get_balance(owner: &T::AccountId, asset: &MultiAssetIdOf<T>) -> T::Balance {
+		match asset {
+			NativeOrAssetId::Native => T::Currency::reducible_balance(owner, true),
+			NativeOrAssetId::Asset(id) => T::Assets::reducible_balance(*id, owner, true),
+		}
+	}
+
+	fn transfer(
+		asset: &MultiAssetIdOf<T>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: T::Balance,
+		keep_alive: bool,
+	) -> Result<T::Balance, DispatchError> {
+		match asset {
+			NativeOrAssetId::Native => T::Currency::transfer(from, to, amount, keep_alive),
+			NativeOrAssetId::Asset(id) => T::Assets::transfer(*id, from, to, amount, keep_alive),
+		}
+	}
+}
+
+impl<T: Config> Swap<T::AccountId, T::Balance, MultiAssetIdOf<T>> for Pallet<T> {
+	fn swap_exact_tokens_for_tokens(
+		sender: T::AccountId,
+		path: Vec<MultiAssetIdOf<T>>,
+		amount_in: T::Balance,
+		amount_out_min: Option<T::Balance>,
+		send_to: T::AccountId,
+		keep_alive: bool,
+	) -> Result<T::Balance, DispatchError> {
+		Self::do_swap_exact_tokens_for_tokens(
+			sender,
+			path,
+			amount_in,
+			amount_out_min,
+			send_to,
+			keep_alive,
+		)
+	}
+
+	fn swap_tokens_for_exact_tokens(
+		sender: T::AccountId,
+		path: Vec<MultiAssetIdOf<T>>,
+		amount_out: T::Balance,
+		amount_in_max: Option<T::Balance>,
+		send_to: T::AccountId,
+		keep_alive: bool,
+	) -> Result<T::Balance, DispatchError> {
+		Self::do_swap_tokens_for_exact_tokens(
+			sender,
+			path,
+			amount_out,
+			amount_in_max,
+			send_to,
+			keep_alive,
+		)
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to quote the prices of swaps and to query the reserves of the liquidity pools of
+	/// the asset conversion pallet.
+	pub trait AssetConversionApi<Balance, AssetId> where
+		Balance: Codec,
+		AssetId: Codec,
+	{
+		/// The amount of `asset1` that has to be swapped for exactly `amount` of `asset2`, or the
+		/// spot price of `amount` if `include_fee` is not set.
+		fn quote_price_tokens_for_exact_tokens(
+			asset1: AssetId,
+			asset2: AssetId,
+			amount: Balance,
+			include_fee: bool,
+		) -> Option<Balance>;
+
+		/// The amount of `asset2` that exactly `amount` of `asset1` is swapped for, or the spot
+		/// price of `amount` if `include_fee` is not set.
+		fn quote_price_exact_tokens_for_tokens(
+			asset1: AssetId,
+			asset2: AssetId,
+			amount: Balance,
+			include_fee: bool,
+		) -> Option<Balance>;
+
+		/// The reserves of `asset1` and `asset2` in their pool, if it exists and has liquidity.
+		fn get_reserves(asset1: AssetId, asset2: AssetId) -> Option<(Balance, Balance)>;
+	}
+}
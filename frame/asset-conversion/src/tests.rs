@@ -0,0 +1,625 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the asset conversion pallet.
+
+use crate::{
+	mock::*, Error, MultiAssetIdOf, NativeOrAssetId, NextPoolAssetId, PoolInfo, Pools, Swap,
+};
+use frame_support::{assert_noop, assert_ok};
+use NativeOrAssetId::{Asset, Native};
+
+fn create_asset(id: u32, holders: &[(u128, u64)]) {
+	assert_ok!(Assets::force_create(Origin::root(), id, 1, false, 1));
+	for (who, amount) in holders {
+		assert_ok!(Assets::mint(Origin::signed(1), id, *who, *amount));
+	}
+}
+
+fn pool_account(asset1: MultiAssetIdOf<Test>, asset2: MultiAssetIdOf<Test>) -> u128 {
+	AssetConversion::get_pool_account(&AssetConversion::get_pool_id(asset1, asset2))
+}
+
+fn last_event() -> crate::Event<Test> {
+	System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| if let Event::AssetConversion(inner) = e { Some(inner) } else { None })
+		.last()
+		.expect("an asset conversion event is expected")
+}
+
+/// Create the pool of the native currency and asset 2, with 10_000 of the native currency and
+/// 1_000 of asset 2 provided by account 1.
+fn setup_native_pool() {
+	create_asset(2, &[(1, 10_000), (2, 10_000)]);
+	assert_ok!(AssetConversion::create_pool(Origin::signed(1), Native, Asset(2)));
+	assert_ok!(AssetConversion::add_liquidity(
+		Origin::signed(1),
+		Native,
+		Asset(2),
+		10_000,
+		1_000,
+		10_000,
+		1_000,
+		1
+	));
+}
+
+#[test]
+fn create_pool_works() {
+	new_test_ext().execute_with(|| {
+		create_asset(2, &[]);
+
+		assert_ok!(AssetConversion::create_pool(Origin::signed(1), Asset(2), Native));
+
+		// the assets are sorted in the pool id.
+		let pool_id = (Native, Asset(2));
+		assert_eq!(Pools::<Test>::get(&pool_id), Some(PoolInfo { lp_token: 0 }));
+		assert_eq!(NextPoolAssetId::<Test>::get(), 1);
+		assert_eq!(last_event(), crate::Event::PoolCreated { creator: 1, pool_id, lp_token: 0 });
+
+		// the setup fee is paid.
+		assert_eq!(Balances::free_balance(1), 100_000 - 100);
+		assert_eq!(Balances::free_balance(PoolSetupFeeReceiver::get()), 1 + 100);
+
+		// the liquidity pool token is created, without any supply.
+		assert_eq!(PoolAssets::total_supply(0), 0);
+
+		// pools between two assets can be created as well.
+		create_asset(3, &[]);
+		assert_ok!(AssetConversion::create_pool(Origin::signed(1), Asset(3), Asset(2)));
+		assert_eq!(Pools::<Test>::get(&(Asset(2), Asset(3))), Some(PoolInfo { lp_token: 1 }));
+		assert_eq!(NextPoolAssetId::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn create_pool_fails() {
+	new_test_ext().execute_with(|| {
+		create_asset(2, &[]);
+
+		assert_noop!(
+			AssetConversion::create_pool(Origin::signed(1), Asset(2), Asset(2)),
+			Error::<Test>::EqualAssets
+		);
+		assert_noop!(
+			AssetConversion::create_pool(Origin::signed(1), Native, Native),
+			Error::<Test>::EqualAssets
+		);
+
+		// the setup fee can't be paid.
+		assert_noop!(
+			AssetConversion::create_pool(Origin::signed(4), Native, Asset(2)),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(AssetConversion::create_pool(Origin::signed(1), Native, Asset(2)));
+		assert_noop!(
+			AssetConversion::create_pool(Origin::signed(2), Native, Asset(2)),
+			Error::<Test>::PoolExists
+		);
+		assert_noop!(
+			AssetConversion::create_pool(Origin::signed(2), Asset(2), Native),
+			Error::<Test>::PoolExists
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_works() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+		let pool_account = pool_account(Native, Asset(2));
+
+		// sqrt(10_000 * 1_000) = 3_162, of which the minimum liquidity is locked in the pool.
+		assert_eq!(PoolAssets::total_supply(0), 3_162);
+		assert_eq!(PoolAssets::balance(0, 1), 3_062);
+		assert_eq!(PoolAssets::balance(0, pool_account), 100);
+		assert_eq!(Balances::free_balance(pool_account), 10_000);
+		assert_eq!(Assets::balance(2, pool_account), 1_000);
+		assert_eq!(Balances::free_balance(1), 100_000 - 100 - 10_000);
+		assert_eq!(Assets::balance(2, 1), 9_000);
+		assert_eq!(
+			last_event(),
+			crate::Event::LiquidityAdded {
+				who: 1,
+				mint_to: 1,
+				pool_id: (Native, Asset(2)),
+				amount1_provided: 10_000,
+				amount2_provided: 1_000,
+				lp_token: 0,
+				lp_token_minted: 3_062,
+			}
+		);
+
+		// later liquidity is added in the ratio of the reserves of the pool, which keep the
+		// existential deposit and minimum balance aside: 5_000 * 999 / 9_999 = 499.
+		assert_ok!(AssetConversion::add_liquidity(
+			Origin::signed(2),
+			Asset(2),
+			Native,
+			1_000,
+			5_000,
+			1,
+			1,
+			2
+		));
+		assert_eq!(Balances::free_balance(pool_account), 15_000);
+		assert_eq!(Assets::balance(2, pool_account), 1_499);
+		// min(5_000 * 3_162 / 9_999, 499 * 3_162 / 999) = min(1_581, 1_579).
+		assert_eq!(PoolAssets::balance(0, 2), 1_579);
+		assert_eq!(PoolAssets::total_supply(0), 3_162 + 1_579);
+	});
+}
+
+#[test]
+fn add_liquidity_fails() {
+	new_test_ext().execute_with(|| {
+		create_asset(2, &[(1, 10_000)]);
+
+		assert_noop!(
+			AssetConversion::add_liquidity(
+				Origin::signed(1),
+				Native,
+				Asset(2),
+				10_000,
+				1_000,
+				0,
+				0,
+				1
+			),
+			Error::<Test>::PoolNotFound
+		);
+
+		assert_ok!(AssetConversion::create_pool(Origin::signed(1), Native, Asset(2)));
+		assert_noop!(
+			AssetConversion::add_liquidity(Origin::signed(1), Native, Asset(2), 10_000, 0, 0, 0, 1),
+			Error::<Test>::WrongDesiredAmount
+		);
+
+		// the first liquidity doesn't even cover the minimum liquidity: sqrt(10 * 10) < 100.
+		assert_noop!(
+			AssetConversion::add_liquidity(Origin::signed(1), Native, Asset(2), 10, 10, 0, 0, 1),
+			Error::<Test>::InsufficientLiquidityMinted
+		);
+
+		assert_ok!(AssetConversion::add_liquidity(
+			Origin::signed(1),
+			Native,
+			Asset(2),
+			10_000,
+			1_000,
+			0,
+			0,
+			1
+		));
+
+		// 5_000 of the native currency only needs 499 of asset 2.
+		assert_noop!(
+			AssetConversion::add_liquidity(
+				Origin::signed(1),
+				Native,
+				Asset(2),
+				5_000,
+				1_000,
+				0,
+				500,
+				1
+			),
+			Error::<Test>::AmountTwoLessThanMinimal
+		);
+		assert_noop!(
+			AssetConversion::add_liquidity(
+				Origin::signed(1),
+				Native,
+				Asset(2),
+				20_000,
+				1_000,
+				20_000,
+				0,
+				1
+			),
+			Error::<Test>::AmountOneLessThanMinimal
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_works() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+		let pool_account = pool_account(Native, Asset(2));
+
+		assert_ok!(AssetConversion::remove_liquidity(
+			Origin::signed(1),
+			Asset(2),
+			Native,
+			3_062,
+			967,
+			9_682,
+			1
+		));
+
+		// 3_062 * 9_999 / 3_162 and 3_062 * 999 / 3_162.
+		assert_eq!(Balances::free_balance(1), 100_000 - 100 - 10_000 + 9_682);
+		assert_eq!(Assets::balance(2, 1), 9_000 + 967);
+		assert_eq!(PoolAssets::balance(0, 1), 0);
+		assert_eq!(PoolAssets::total_supply(0), 100);
+		assert_eq!(Balances::free_balance(pool_account), 10_000 - 9_682);
+		assert_eq!(Assets::balance(2, pool_account), 1_000 - 967);
+		assert_eq!(
+			last_event(),
+			crate::Event::LiquidityRemoved {
+				who: 1,
+				withdraw_to: 1,
+				pool_id: (Native, Asset(2)),
+				amount1: 9_682,
+				amount2: 967,
+				lp_token: 0,
+				lp_token_burned: 3_062,
+			}
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_fails() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+
+		assert_noop!(
+			AssetConversion::remove_liquidity(Origin::signed(1), Native, Asset(3), 100, 0, 0, 1),
+			Error::<Test>::PoolNotFound
+		);
+		assert_noop!(
+			AssetConversion::remove_liquidity(Origin::signed(1), Native, Asset(2), 0, 0, 0, 1),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			AssetConversion::remove_liquidity(
+				Origin::signed(1),
+				Native,
+				Asset(2),
+				3_062,
+				9_683,
+				0,
+				1
+			),
+			Error::<Test>::AmountOneLessThanMinimal
+		);
+		assert_noop!(
+			AssetConversion::remove_liquidity(
+				Origin::signed(1),
+				Native,
+				Asset(2),
+				3_062,
+				0,
+				968,
+				1
+			),
+			Error::<Test>::AmountTwoLessThanMinimal
+		);
+		// account 2 has no liquidity pool tokens to burn.
+		assert!(AssetConversion::remove_liquidity(
+			Origin::signed(2),
+			Native,
+			Asset(2),
+			100,
+			0,
+			0,
+			2
+		)
+		.is_err());
+	});
+}
+
+#[test]
+fn swap_exact_tokens_for_tokens_works() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+		let pool_account = pool_account(Native, Asset(2));
+
+		// 1_000 * 997 * 999 / (9_999 * 1_000 + 1_000 * 997) = 90.
+		assert_eq!(AssetConversion::get_amount_out(&1_000, &9_999, &999).ok(), Some(90));
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			Origin::signed(2),
+			vec![Native, Asset(2)],
+			1_000,
+			90,
+			3,
+			true
+		));
+
+		assert_eq!(Balances::free_balance(2), 100_000 - 1_000);
+		assert_eq!(Assets::balance(2, 2), 10_000);
+		assert_eq!(Assets::balance(2, 3), 90);
+		assert_eq!(Balances::free_balance(pool_account), 11_000);
+		assert_eq!(Assets::balance(2, pool_account), 910);
+		assert_eq!(
+			last_event(),
+			crate::Event::SwapExecuted {
+				who: 2,
+				send_to: 3,
+				path: vec![Native, Asset(2)],
+				amount_in: 1_000,
+				amount_out: 90,
+			}
+		);
+	});
+}
+
+#[test]
+fn swap_tokens_for_exact_tokens_works() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+		let pool_account = pool_account(Native, Asset(2));
+
+		// 9_999 * 50 * 1_000 / ((999 - 50) * 997) + 1 = 529.
+		assert_eq!(AssetConversion::get_amount_in(&50, &9_999, &999).ok(), Some(529));
+		assert_ok!(AssetConversion::swap_tokens_for_exact_tokens(
+			Origin::signed(2),
+			vec![Native, Asset(2)],
+			50,
+			529,
+			2,
+			false
+		));
+
+		assert_eq!(Balances::free_balance(2), 100_000 - 529);
+		assert_eq!(Assets::balance(2, 2), 10_000 + 50);
+		assert_eq!(Balances::free_balance(pool_account), 10_529);
+		assert_eq!(Assets::balance(2, pool_account), 950);
+	});
+}
+
+#[test]
+fn swap_fails() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+		create_asset(3, &[]);
+
+		assert_noop!(
+			AssetConversion::swap_exact_tokens_for_tokens(
+				Origin::signed(2),
+				vec![Native],
+				1_000,
+				1,
+				2,
+				true
+			),
+			Error::<Test>::InvalidPath
+		);
+		assert_noop!(
+			AssetConversion::swap_exact_tokens_for_tokens(
+				Origin::signed(2),
+				vec![Native, Native],
+				1_000,
+				1,
+				2,
+				true
+			),
+			Error::<Test>::InvalidPath
+		);
+		assert_noop!(
+			AssetConversion::swap_exact_tokens_for_tokens(
+				Origin::signed(2),
+				vec![Native, Asset(2), Native, Asset(2), Native],
+				1_000,
+				1,
+				2,
+				true
+			),
+			Error::<Test>::InvalidPath
+		);
+		assert_noop!(
+			AssetConversion::swap_exact_tokens_for_tokens(
+				Origin::signed(2),
+				vec![Native, Asset(3)],
+				1_000,
+				1,
+				2,
+				true
+			),
+			Error::<Test>::PoolNotFound
+		);
+		assert_noop!(
+			AssetConversion::swap_exact_tokens_for_tokens(
+				Origin::signed(2),
+				vec![Native, Asset(2)],
+				0,
+				0,
+				2,
+				true
+			),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			AssetConversion::swap_exact_tokens_for_tokens(
+				Origin::signed(2),
+				vec![Native, Asset(2)],
+				1_000,
+				91,
+				2,
+				true
+			),
+			Error::<Test>::ProvidedMinimumNotSufficientForSwap
+		);
+		assert_noop!(
+			AssetConversion::swap_tokens_for_exact_tokens(
+				Origin::signed(2),
+				vec![Native, Asset(2)],
+				50,
+				528,
+				2,
+				true
+			),
+			Error::<Test>::ProvidedMaximumNotSufficientForSwap
+		);
+		assert_noop!(
+			AssetConversion::swap_tokens_for_exact_tokens(
+				Origin::signed(2),
+				vec![Native, Asset(2)],
+				999,
+				100_000,
+				2,
+				true
+			),
+			Error::<Test>::InsufficientLiquidity
+		);
+
+		// the pool of asset 3 exists, but has no liquidity.
+		assert_ok!(AssetConversion::create_pool(Origin::signed(1), Native, Asset(3)));
+		assert_noop!(
+			AssetConversion::swap_exact_tokens_for_tokens(
+				Origin::signed(2),
+				vec![Native, Asset(3)],
+				1_000,
+				1,
+				2,
+				true
+			),
+			Error::<Test>::ZeroLiquidity
+		);
+	});
+}
+
+#[test]
+fn multi_hop_swaps_work() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+		create_asset(3, &[(1, 10_000)]);
+		assert_ok!(AssetConversion::create_pool(Origin::signed(1), Native, Asset(3)));
+		assert_ok!(AssetConversion::add_liquidity(
+			Origin::signed(1),
+			Native,
+			Asset(3),
+			10_000,
+			2_000,
+			0,
+			0,
+			1
+		));
+		let pool2 = pool_account(Native, Asset(2));
+		let pool3 = pool_account(Native, Asset(3));
+
+		// 100 of asset 2 gives 907 of the native currency, which gives 165 of asset 3.
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			Origin::signed(2),
+			vec![Asset(2), Native, Asset(3)],
+			100,
+			165,
+			2,
+			true
+		));
+		assert_eq!(Assets::balance(2, 2), 10_000 - 100);
+		assert_eq!(Assets::balance(3, 2), 165);
+		assert_eq!(Balances::free_balance(2), 100_000);
+		assert_eq!(Assets::balance(2, pool2), 1_100);
+		assert_eq!(Balances::free_balance(pool2), 10_000 - 907);
+		assert_eq!(Balances::free_balance(pool3), 10_000 + 907);
+		assert_eq!(Assets::balance(3, pool3), 2_000 - 165);
+	});
+}
+
+#[test]
+fn multi_hop_exact_out_swaps_work() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+		create_asset(3, &[(1, 10_000)]);
+		assert_ok!(AssetConversion::create_pool(Origin::signed(1), Native, Asset(3)));
+		assert_ok!(AssetConversion::add_liquidity(
+			Origin::signed(1),
+			Native,
+			Asset(3),
+			10_000,
+			2_000,
+			0,
+			0,
+			1
+		));
+
+		// 100 of asset 3 needs 529 of the native currency, which needs 56 of asset 2.
+		assert_ok!(AssetConversion::swap_tokens_for_exact_tokens(
+			Origin::signed(2),
+			vec![Asset(2), Native, Asset(3)],
+			100,
+			56,
+			2,
+			true
+		));
+		assert_eq!(Assets::balance(2, 2), 10_000 - 56);
+		assert_eq!(Assets::balance(3, 2), 100);
+		assert_eq!(Balances::free_balance(pool_account(Native, Asset(3))), 10_000 + 529);
+	});
+}
+
+#[test]
+fn quotes_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(Native, Asset(2), 1_000, true),
+			None
+		);
+
+		setup_native_pool();
+
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(Native, Asset(2), 1_000, true),
+			Some(90)
+		);
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(Native, Asset(2), 1_000, false),
+			Some(99)
+		);
+		assert_eq!(
+			AssetConversion::quote_price_tokens_for_exact_tokens(Native, Asset(2), 50, true),
+			Some(529)
+		);
+		assert_eq!(
+			AssetConversion::quote_price_tokens_for_exact_tokens(Native, Asset(2), 50, false),
+			Some(500)
+		);
+		// more than the reserves can't be bought.
+		assert_eq!(
+			AssetConversion::quote_price_tokens_for_exact_tokens(Native, Asset(2), 999, true),
+			None
+		);
+		assert_eq!(AssetConversion::get_reserves(&Asset(2), &Native).ok(), Some((999, 9_999)));
+	});
+}
+
+#[test]
+fn swap_trait_works() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+
+		assert_eq!(
+			<AssetConversion as Swap<_, _, _>>::swap_tokens_for_exact_tokens(
+				2,
+				vec![Asset(2), Native],
+				1_000,
+				None,
+				2,
+				true
+			),
+			Ok(112)
+		);
+		assert_eq!(Balances::free_balance(2), 100_000 + 1_000);
+		assert_eq!(Assets::balance(2, 2), 10_000 - 112);
+	});
+}
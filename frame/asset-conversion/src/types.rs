@@ -0,0 +1,97 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Various basic types for use in the asset conversion pallet.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::prelude::*;
+
+/// Identifier of an asset that can be swapped: either the native currency of the chain, or one of
+/// the assets of the `fungibles` implementation.
+///
+/// The native currency is ordered before all other assets, such that it always comes first in the
+/// id of a pool.
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum NativeOrAssetId<AssetId> {
+	/// The native currency.
+	Native,
+	/// An asset of the `fungibles` implementation.
+	Asset(AssetId),
+}
+
+impl<AssetId> From<AssetId> for NativeOrAssetId<AssetId> {
+	fn from(asset: AssetId) -> Self {
+		Self::Asset(asset)
+	}
+}
+
+/// Information about a liquidity pool.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolInfo<PoolAssetId> {
+	/// The id of the liquidity pool token, which is minted to liquidity providers.
+	pub lp_token: PoolAssetId,
+}
+
+/// Something that can swap one asset for another along a path of liquidity pools.
+pub trait Swap<AccountId, Balance, MultiAssetId> {
+	/// Swap exactly `amount_in` of the first asset of `path` for as much as possible of the last
+	/// asset of `path`, which is sent to `send_to`.
+	///
+	/// Fails if the received amount would be less than `amount_out_min`, if given.
+	///
+	/// Returns the amount of the last asset of `path` that was received.
+	fn swap_exact_tokens_for_tokens(
+		sender: AccountId,
+		path: Vec<MultiAssetId>,
+		amount_in: Balance,
+		amount_out_min: Option<Balance>,
+		send_to: AccountId,
+		keep_alive: bool,
+	) -> Result<Balance, DispatchError>;
+
+	/// Swap as little as possible of the first asset of `path` for exactly `amount_out` of the last
+	/// asset of `path`, which is sent to `send_to`.
+	///
+	/// Fails if more than `amount_in_max`, if given, of the first asset would need to be paid.
+	///
+	/// Returns the amount of the first asset of `path` that was paid.
+	fn swap_tokens_for_exact_tokens(
+		sender: AccountId,
+		path: Vec<MultiAssetId>,
+		amount_out: Balance,
+		amount_in_max: Option<Balance>,
+		send_to: AccountId,
+		keep_alive: bool,
+	) -> Result<Balance, DispatchError>;
+}
@@ -0,0 +1,168 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_asset_conversion
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2021-12-20, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+
+// Executed Command:
+// ./target/release/substrate
+// benchmark
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_asset_conversion
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/asset-conversion/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_asset_conversion.
+pub trait WeightInfo {
+	fn create_pool() -> Weight;
+	fn add_liquidity() -> Weight;
+	fn remove_liquidity() -> Weight;
+	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight;
+	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight;
+}
+
+/// Weights for pallet_asset_conversion using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: AssetConversion Pools (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: AssetConversion NextPoolAssetId (r:1 w:1)
+	// Storage: PoolAssets Asset (r:1 w:1)
+	fn create_pool() -> Weight {
+		(94_511_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: AssetConversion Pools (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: PoolAssets Asset (r:1 w:1)
+	// Storage: PoolAssets Account (r:2 w:2)
+	fn add_liquidity() -> Weight {
+		(171_325_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: AssetConversion Pools (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: PoolAssets Asset (r:1 w:1)
+	// Storage: PoolAssets Account (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_liquidity() -> Weight {
+		(159_873_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: AssetConversion Pools (r:3 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Asset (r:3 w:3)
+	// Storage: Assets Account (r:6 w:6)
+	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 87_000
+			.saturating_add((71_204_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: AssetConversion Pools (r:3 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Asset (r:3 w:3)
+	// Storage: Assets Account (r:6 w:6)
+	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 87_000
+			.saturating_add((71_538_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: AssetConversion Pools (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: AssetConversion NextPoolAssetId (r:1 w:1)
+	// Storage: PoolAssets Asset (r:1 w:1)
+	fn create_pool() -> Weight {
+		(94_511_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: AssetConversion Pools (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: PoolAssets Asset (r:1 w:1)
+	// Storage: PoolAssets Account (r:2 w:2)
+	fn add_liquidity() -> Weight {
+		(171_325_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: AssetConversion Pools (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: PoolAssets Asset (r:1 w:1)
+	// Storage: PoolAssets Account (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_liquidity() -> Weight {
+		(159_873_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: AssetConversion Pools (r:3 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Asset (r:3 w:3)
+	// Storage: Assets Account (r:6 w:6)
+	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 87_000
+			.saturating_add((71_204_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: AssetConversion Pools (r:3 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Asset (r:3 w:3)
+	// Storage: Assets Account (r:6 w:6)
+	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 87_000
+			.saturating_add((71_538_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
+	}
+}
@@ -62,11 +62,11 @@ impl<T: Config<I>, I: 'static> ExtraMutator<T, I> {
 		id: T::AssetId,
 		who: impl sp_std::borrow::Borrow<T::AccountId>,
 	) -> Option<ExtraMutator<T, I>> {
-		if Account::<T, I>::contains_key(id, who.borrow()) {
+		if let Some(a) = Account::<T, I>::get(id, who.borrow()) {
 			Some(ExtraMutator::<T, I> {
 				id,
 				who: who.borrow().clone(),
-				original: Account::<T, I>::get(id, who.borrow()).extra,
+				original: a.extra,
 				pending: None,
 			})
 		} else {
@@ -34,7 +34,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 	/// Get the asset `id` balance of `who`.
 	pub fn balance(id: T::AssetId, who: impl sp_std::borrow::Borrow<T::AccountId>) -> T::Balance {
-		Account::<T, I>::get(id, who.borrow()).balance
+		Account::<T, I>::get(id, who.borrow())
+			.map(|a| a.balance)
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Get the IDs of the assets for which `who` holds an asset account.
+	pub fn account_assets(who: impl sp_std::borrow::Borrow<T::AccountId>) -> Vec<T::AssetId> {
+		AccountAssets::<T, I>::iter_key_prefix(who.borrow()).collect()
 	}
 
 	/// Get the total supply of an asset `id`.
@@ -42,37 +49,77 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Asset::<T, I>::get(id).map(|x| x.supply).unwrap_or_else(Zero::zero)
 	}
 
+	/// Account `who` into the asset class `d`, returning the reason of its existence.
+	///
+	/// If `maybe_deposit` is `Some`, then the account exists because of that deposit, which must
+	/// be reserved by the caller. Otherwise, a self-sufficient or consumer reference is placed on
+	/// the account, depending on whether the asset is sufficient.
 	pub(super) fn new_account(
+		what: T::AssetId,
 		who: &T::AccountId,
 		d: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
-	) -> Result<bool, DispatchError> {
+		maybe_deposit: Option<DepositBalanceOf<T, I>>,
+	) -> Result<ExistenceReason<DepositBalanceOf<T, I>>, DispatchError> {
 		let accounts = d.accounts.checked_add(1).ok_or(ArithmeticError::Overflow)?;
-		let is_sufficient = if d.is_sufficient {
+		let reason = if let Some(deposit) = maybe_deposit {
+			ExistenceReason::DepositHeld(deposit)
+		} else if d.is_sufficient {
 			frame_system::Pallet::<T>::inc_sufficients(who);
 			d.sufficients += 1;
-			true
+			ExistenceReason::Sufficient
 		} else {
 			frame_system::Pallet::<T>::inc_consumers(who).map_err(|_| Error::<T, I>::NoProvider)?;
-			false
+			ExistenceReason::Consumer
 		};
 		d.accounts = accounts;
-		Ok(is_sufficient)
+		AccountAssets::<T, I>::insert(who, what, ());
+		Ok(reason)
 	}
 
+	/// Remove the account `who` from the asset class `d`, releasing the reference that was placed
+	/// on it for the given `reason`.
+	///
+	/// An account for which a deposit is still held is kept, unless `force` is set, in which case
+	/// the caller must take care of the deposit.
 	pub(super) fn dead_account(
 		what: T::AssetId,
 		who: &T::AccountId,
 		d: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
-		sufficient: bool,
-	) {
-		if sufficient {
-			d.sufficients = d.sufficients.saturating_sub(1);
-			frame_system::Pallet::<T>::dec_sufficients(who);
-		} else {
-			frame_system::Pallet::<T>::dec_consumers(who);
+		reason: &ExistenceReason<DepositBalanceOf<T, I>>,
+		force: bool,
+	) -> DeadConsequence {
+		match *reason {
+			ExistenceReason::Consumer => frame_system::Pallet::<T>::dec_consumers(who),
+			ExistenceReason::Sufficient => {
+				d.sufficients = d.sufficients.saturating_sub(1);
+				frame_system::Pallet::<T>::dec_sufficients(who);
+			},
+			ExistenceReason::DepositRefunded => {},
+			ExistenceReason::DepositHeld(_) if !force => return DeadConsequence::Keep,
+			ExistenceReason::DepositHeld(_) => {},
 		}
 		d.accounts = d.accounts.saturating_sub(1);
-		T::Freezer::died(what, who)
+		AccountAssets::<T, I>::remove(who, what);
+		Freezes::<T, I>::remove(what, who);
+		T::Freezer::died(what, who);
+		DeadConsequence::Remove
+	}
+
+	/// The amount of asset `id` of `who` which must be kept in addition to the minimum balance, if
+	/// any.
+	///
+	/// This is the greater of the amount given by `T::Freezer` and the largest freeze on the
+	/// account. An account with funds on hold may not be removed, even if nothing is frozen.
+	pub(super) fn frozen_balance(id: T::AssetId, who: &T::AccountId) -> Option<T::Balance> {
+		let frozen = Freezes::<T, I>::get(id, who).iter().map(|f| f.amount).max();
+		let frozen = match frozen {
+			None if Holds::<T, I>::contains_key(id, who) => Some(Zero::zero()),
+			frozen => frozen,
+		};
+		match (T::Freezer::frozen_balance(id, who), frozen) {
+			(Some(a), Some(b)) => Some(a.max(b)),
+			(a, b) => a.or(b),
+		}
 	}
 
 	pub(super) fn can_increase(
@@ -84,14 +131,20 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Some(details) => details,
 			None => return DepositConsequence::UnknownAsset,
 		};
-		if details.supply.checked_add(&amount).is_none() {
-			return DepositConsequence::Overflow
+		if details.status == AssetStatus::Destroying {
+			return DepositConsequence::UnknownAsset
 		}
-		let account = Account::<T, I>::get(id, who);
-		if account.balance.checked_add(&amount).is_none() {
+		if details.supply.checked_add(&amount).is_none() {
 			return DepositConsequence::Overflow
 		}
-		if account.balance.is_zero() {
+		if let Some(account) = Account::<T, I>::get(id, who) {
+			if account.balance.checked_add(&amount).is_none() {
+				return DepositConsequence::Overflow
+			}
+			if account.balance.is_zero() && amount < details.min_balance {
+				return DepositConsequence::BelowMinimum
+			}
+		} else {
 			if amount < details.min_balance {
 				return DepositConsequence::BelowMinimum
 			}
@@ -118,18 +171,24 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Some(details) => details,
 			None => return UnknownAsset,
 		};
+		if details.status == AssetStatus::Destroying {
+			return UnknownAsset
+		}
 		if details.supply.checked_sub(&amount).is_none() {
 			return Underflow
 		}
-		if details.is_frozen {
+		if details.status == AssetStatus::Frozen {
 			return Frozen
 		}
-		let account = Account::<T, I>::get(id, who);
+		let account = match Account::<T, I>::get(id, who) {
+			Some(account) => account,
+			None => return NoFunds,
+		};
 		if account.is_frozen {
 			return Frozen
 		}
 		if let Some(rest) = account.balance.checked_sub(&amount) {
-			if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
+			if let Some(frozen) = Self::frozen_balance(id, who) {
 				match frozen.checked_add(&details.min_balance) {
 					Some(required) if rest < required => return Frozen,
 					None => return Overflow,
@@ -163,12 +222,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		keep_alive: bool,
 	) -> Result<T::Balance, DispatchError> {
 		let details = Asset::<T, I>::get(id).ok_or_else(|| Error::<T, I>::Unknown)?;
-		ensure!(!details.is_frozen, Error::<T, I>::Frozen);
+		ensure!(details.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
+		ensure!(details.status != AssetStatus::Frozen, Error::<T, I>::Frozen);
 
-		let account = Account::<T, I>::get(id, who);
+		let account = Account::<T, I>::get(id, who).ok_or(Error::<T, I>::NoAccount)?;
 		ensure!(!account.is_frozen, Error::<T, I>::Frozen);
 
-		let amount = if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
+		let amount = if let Some(frozen) = Self::frozen_balance(id, who) {
 			// Frozen balance: account CANNOT be deleted
 			let required =
 				frozen.checked_add(&details.min_balance).ok_or(ArithmeticError::Overflow)?;
@@ -307,13 +367,23 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 			check(details)?;
 
-			Account::<T, I>::try_mutate(id, beneficiary, |t| -> DispatchResult {
-				let new_balance = t.balance.saturating_add(amount);
-				ensure!(new_balance >= details.min_balance, TokenError::BelowMinimum);
-				if t.balance.is_zero() {
-					t.sufficient = Self::new_account(beneficiary, details)?;
+			Account::<T, I>::try_mutate(id, beneficiary, |maybe_account| -> DispatchResult {
+				match maybe_account {
+					Some(ref mut account) => {
+						let new_balance = account.balance.saturating_add(amount);
+						ensure!(new_balance >= details.min_balance, TokenError::BelowMinimum);
+						account.balance = new_balance;
+					},
+					maybe_account @ None => {
+						ensure!(amount >= details.min_balance, TokenError::BelowMinimum);
+						*maybe_account = Some(AssetAccount {
+							balance: amount,
+							is_frozen: false,
+							reason: Self::new_account(id, beneficiary, details, None)?,
+							extra: T::Extra::default(),
+						});
+					},
 				}
-				t.balance = new_balance;
 				Ok(())
 			})?;
 			Ok(())
@@ -380,18 +450,21 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			check(actual, details)?;
 
 			Account::<T, I>::try_mutate_exists(id, target, |maybe_account| -> DispatchResult {
-				let mut account = maybe_account.take().unwrap_or_default();
+				let mut account = maybe_account.take().ok_or(Error::<T, I>::NoAccount)?;
 				debug_assert!(account.balance >= actual, "checked in prep; qed");
 
 				// Make the debit.
 				account.balance = account.balance.saturating_sub(actual);
-				*maybe_account = if account.balance < details.min_balance {
+				if account.balance < details.min_balance {
 					debug_assert!(account.balance.is_zero(), "checked in prep; qed");
-					Self::dead_account(id, target, details, account.sufficient);
-					None
-				} else {
-					Some(account)
-				};
+					let reason = &account.reason;
+					if Self::dead_account(id, target, details, reason, false) ==
+						DeadConsequence::Remove
+					{
+						return Ok(())
+					}
+				}
+				*maybe_account = Some(account);
 				Ok(())
 			})?;
 
@@ -432,7 +505,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let debit = Self::prep_debit(id, &source, amount, f.into())?;
 		let (credit, maybe_burn) = Self::prep_credit(id, &dest, amount, debit, f.burn_dust)?;
 
-		let mut source_account = Account::<T, I>::get(id, &source);
+		let mut source_account =
+			Account::<T, I>::get(id, &source).ok_or(Error::<T, I>::NoAccount)?;
 
 		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
 			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
@@ -459,28 +533,42 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			debug_assert!(source_account.balance >= debit, "checked in prep; qed");
 			source_account.balance = source_account.balance.saturating_sub(debit);
 
-			Account::<T, I>::try_mutate(id, &dest, |a| -> DispatchResult {
-				// Calculate new balance; this will not saturate since it's already checked in prep.
-				debug_assert!(a.balance.checked_add(&credit).is_some(), "checked in prep; qed");
-				let new_balance = a.balance.saturating_add(credit);
-
-				// Create a new account if there wasn't one already.
-				if a.balance.is_zero() {
-					a.sufficient = Self::new_account(&dest, details)?;
+			Account::<T, I>::try_mutate(id, &dest, |maybe_account| -> DispatchResult {
+				match maybe_account {
+					Some(ref mut account) => {
+						// Calculate new balance; this will not saturate since it's already checked
+						// in prep.
+						debug_assert!(
+							account.balance.checked_add(&credit).is_some(),
+							"checked in prep; qed"
+						);
+						account.balance.saturating_accrue(credit);
+					},
+					maybe_account @ None => {
+						// Create a new account if there wasn't one already.
+						*maybe_account = Some(AssetAccount {
+							balance: credit,
+							is_frozen: false,
+							reason: Self::new_account(id, &dest, details, None)?,
+							extra: T::Extra::default(),
+						});
+					},
 				}
-
-				a.balance = new_balance;
 				Ok(())
 			})?;
 
 			// Remove source account if it's now dead.
 			if source_account.balance < details.min_balance {
 				debug_assert!(source_account.balance.is_zero(), "checked in prep; qed");
-				Self::dead_account(id, &source, details, source_account.sufficient);
-				Account::<T, I>::remove(id, &source);
-			} else {
-				Account::<T, I>::insert(id, &source, &source_account)
+				let reason = &source_account.reason;
+				if Self::dead_account(id, &source, details, reason, false) ==
+					DeadConsequence::Remove
+				{
+					Account::<T, I>::remove(id, &source);
+					return Ok(())
+				}
 			}
+			Account::<T, I>::insert(id, &source, &source_account);
 
 			Ok(())
 		})?;
@@ -525,39 +613,110 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				accounts: 0,
 				sufficients: 0,
 				approvals: 0,
-				is_frozen: false,
+				status: AssetStatus::Live,
 			},
 		);
 		Self::deposit_event(Event::ForceCreated { asset_id: id, owner });
 		Ok(())
 	}
 
-	/// Destroy an existing asset.
+	/// Start the destruction of an existing asset, after which it can no longer be used.
 	///
 	/// * `id`: The asset you want to destroy.
-	/// * `witness`: Witness data needed about the current state of the asset, used to confirm
-	///   complexity of the operation.
 	/// * `maybe_check_owner`: An optional check before destroying the asset, if the provided
 	///   account is the owner of that asset. Can be used for authorization checks.
-	pub(super) fn do_destroy(
+	pub(super) fn do_start_destroy(
 		id: T::AssetId,
-		witness: DestroyWitness,
 		maybe_check_owner: Option<T::AccountId>,
-	) -> Result<DestroyWitness, DispatchError> {
-		Asset::<T, I>::try_mutate_exists(id, |maybe_details| {
-			let mut details = maybe_details.take().ok_or(Error::<T, I>::Unknown)?;
+	) -> DispatchResult {
+		Asset::<T, I>::try_mutate_exists(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
 			if let Some(check_owner) = maybe_check_owner {
 				ensure!(details.owner == check_owner, Error::<T, I>::NoPermission);
 			}
-			ensure!(details.accounts <= witness.accounts, Error::<T, I>::BadWitness);
-			ensure!(details.sufficients <= witness.sufficients, Error::<T, I>::BadWitness);
-			ensure!(details.approvals <= witness.approvals, Error::<T, I>::BadWitness);
+			ensure!(details.status != AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+			details.status = AssetStatus::Destroying;
+
+			Self::deposit_event(Event::DestructionStarted { asset_id: id });
+			Ok(())
+		})
+	}
+
+	/// Destroy up to `max_items` accounts of an asset that is being destroyed, unreserving any
+	/// deposits held for them.
+	///
+	/// Returns the number of destroyed accounts.
+	pub(super) fn do_destroy_accounts(
+		id: T::AssetId,
+		max_items: u32,
+	) -> Result<u32, DispatchError> {
+		let mut dead_accounts: u32 = 0;
+		Asset::<T, I>::try_mutate_exists(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+
+			for (who, mut account) in Account::<T, I>::drain_prefix(id).take(max_items as usize) {
+				if let Some(deposit) = account.reason.take_deposit() {
+					T::Currency::unreserve(&who, deposit);
+				}
+				let held = Holds::<T, I>::take(id, &who)
+					.iter()
+					.fold(Zero::zero(), |total: T::Balance, h| total.saturating_add(h.amount));
+				details.supply =
+					details.supply.saturating_sub(account.balance).saturating_sub(held);
+				let _ = Self::dead_account(id, &who, details, &account.reason, true);
+				dead_accounts.saturating_inc();
+			}
+
+			Self::deposit_event(Event::AccountsDestroyed {
+				asset_id: id,
+				accounts_destroyed: dead_accounts,
+				accounts_remaining: details.accounts,
+			});
+			Ok(())
+		})?;
+		Ok(dead_accounts)
+	}
 
-			for (who, v) in Account::<T, I>::drain_prefix(id) {
-				Self::dead_account(id, &who, &mut details, v.sufficient);
+	/// Destroy up to `max_items` approvals of an asset that is being destroyed, unreserving their
+	/// deposits.
+	///
+	/// Returns the number of destroyed approvals.
+	pub(super) fn do_destroy_approvals(
+		id: T::AssetId,
+		max_items: u32,
+	) -> Result<u32, DispatchError> {
+		let mut removed_approvals: u32 = 0;
+		Asset::<T, I>::try_mutate_exists(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+
+			for ((owner, _), approval) in
+				Approvals::<T, I>::drain_prefix((&id,)).take(max_items as usize)
+			{
+				T::Currency::unreserve(&owner, approval.deposit);
+				details.approvals.saturating_dec();
+				removed_approvals.saturating_inc();
 			}
-			debug_assert_eq!(details.accounts, 0);
-			debug_assert_eq!(details.sufficients, 0);
+
+			Self::deposit_event(Event::ApprovalsDestroyed {
+				asset_id: id,
+				approvals_destroyed: removed_approvals,
+				approvals_remaining: details.approvals,
+			});
+			Ok(())
+		})?;
+		Ok(removed_approvals)
+	}
+
+	/// Complete the destruction of an asset, once all of its accounts and approvals have been
+	/// destroyed, and unreserve the deposits of its owner.
+	pub(super) fn do_finish_destroy(id: T::AssetId) -> DispatchResult {
+		Asset::<T, I>::try_mutate_exists(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.take().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+			ensure!(details.accounts == 0, Error::<T, I>::InUse);
+			ensure!(details.approvals == 0, Error::<T, I>::InUse);
 
 			let metadata = Metadata::<T, I>::take(&id);
 			T::Currency::unreserve(
@@ -565,19 +724,69 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				details.deposit.saturating_add(metadata.deposit),
 			);
 
-			for ((owner, _), approval) in Approvals::<T, I>::drain_prefix((&id,)) {
-				T::Currency::unreserve(&owner, approval.deposit);
-			}
 			Self::deposit_event(Event::Destroyed { asset_id: id });
-
-			Ok(DestroyWitness {
-				accounts: details.accounts,
-				sufficients: details.sufficients,
-				approvals: details.approvals,
-			})
+			Ok(())
 		})
 	}
 
+	/// Create an account of `who` with a zero balance of asset `id`, reserving
+	/// `T::AssetAccountDeposit` from `who` to pay for its storage.
+	pub(super) fn do_touch(id: T::AssetId, who: T::AccountId) -> DispatchResult {
+		ensure!(!Account::<T, I>::contains_key(id, &who), Error::<T, I>::AlreadyExists);
+		let deposit = T::AssetAccountDeposit::get();
+		let mut details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+
+		T::Currency::reserve(&who, deposit)?;
+		let reason = Self::new_account(id, &who, &mut details, Some(deposit))?;
+		Asset::<T, I>::insert(id, details);
+		Account::<T, I>::insert(
+			id,
+			&who,
+			AssetAccount {
+				balance: Zero::zero(),
+				is_frozen: false,
+				reason,
+				extra: T::Extra::default(),
+			},
+		);
+
+		Self::deposit_event(Event::Touched { asset_id: id, who, deposit });
+		Ok(())
+	}
+
+	/// Remove the account of `who` for asset `id`, and unreserve the deposit that was taken for
+	/// it by `do_touch`.
+	///
+	/// Any remaining balance is burned if `allow_burn` is `true`, otherwise the account must have
+	/// a zero balance.
+	pub(super) fn do_refund(id: T::AssetId, who: T::AccountId, allow_burn: bool) -> DispatchResult {
+		let mut account = Account::<T, I>::get(id, &who).ok_or(Error::<T, I>::NoDeposit)?;
+		let deposit = account.reason.take_deposit().ok_or(Error::<T, I>::NoDeposit)?;
+		let mut details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+		ensure!(!account.is_frozen, Error::<T, I>::Frozen);
+		ensure!(account.balance.is_zero() || allow_burn, Error::<T, I>::WouldBurn);
+		ensure!(!Holds::<T, I>::contains_key(id, &who), Error::<T, I>::ContainsHolds);
+
+		T::Currency::unreserve(&who, deposit);
+		details.supply = details.supply.saturating_sub(account.balance);
+		let consequence = Self::dead_account(id, &who, &mut details, &account.reason, false);
+		debug_assert_eq!(consequence, DeadConsequence::Remove, "the deposit was taken; qed");
+		Account::<T, I>::remove(id, &who);
+		Asset::<T, I>::insert(id, details);
+
+		if !account.balance.is_zero() {
+			Self::deposit_event(Event::Burned {
+				asset_id: id,
+				owner: who.clone(),
+				balance: account.balance,
+			});
+		}
+		Self::deposit_event(Event::DepositRefunded { asset_id: id, who, deposit });
+		Ok(())
+	}
+
 	/// Creates an approval from `owner` to spend `amount` of asset `id` tokens by 'delegate'
 	/// while reserving `T::ApprovalDeposit` from owner
 	///
@@ -589,7 +798,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		amount: T::Balance,
 	) -> DispatchResult {
 		let mut d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
-		ensure!(!d.is_frozen, Error::<T, I>::Frozen);
+		ensure!(d.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
+		ensure!(d.status != AssetStatus::Frozen, Error::<T, I>::Frozen);
 		Approvals::<T, I>::try_mutate(
 			(id, &owner, &delegate),
 			|maybe_approved| -> DispatchResult {
@@ -678,6 +888,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			symbol.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
 
 		let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(d.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
 		ensure!(from == &d.owner, Error::<T, I>::NoPermission);
 
 		Metadata::<T, I>::try_mutate_exists(id, |metadata| {
@@ -712,4 +923,259 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})
 	}
+
+	/// The funds of `who` held in asset `id` for `reason`.
+	pub(super) fn balance_on_hold(
+		id: T::AssetId,
+		reason: &T::HoldReason,
+		who: &T::AccountId,
+	) -> T::Balance {
+		Holds::<T, I>::get(id, who)
+			.iter()
+			.find(|h| &h.id == reason)
+			.map_or_else(Zero::zero, |h| h.amount)
+	}
+
+	/// Add `amount` to the funds held for `reason` in `holds`.
+	fn add_hold(
+		holds: &mut BoundedVec<IdAmount<T::HoldReason, T::Balance>, T::MaxHolds>,
+		reason: &T::HoldReason,
+		amount: T::Balance,
+	) -> DispatchResult {
+		match holds.iter().position(|h| &h.id == reason) {
+			Some(index) =>
+				holds[index].amount =
+					holds[index].amount.checked_add(&amount).ok_or(ArithmeticError::Overflow)?,
+			None => holds
+				.try_push(IdAmount { id: *reason, amount })
+				.map_err(|_| Error::<T, I>::TooManyHolds)?,
+		}
+		Ok(())
+	}
+
+	/// Take up to `amount` from the funds held for `reason` in `holds`, or exactly `amount` unless
+	/// `best_effort` is `true`.
+	///
+	/// Returns the amount taken.
+	fn remove_hold(
+		holds: &mut BoundedVec<IdAmount<T::HoldReason, T::Balance>, T::MaxHolds>,
+		reason: &T::HoldReason,
+		amount: T::Balance,
+		best_effort: bool,
+	) -> Result<T::Balance, DispatchError> {
+		let index = match holds.iter().position(|h| &h.id == reason) {
+			Some(index) => index,
+			None if best_effort => return Ok(Zero::zero()),
+			None => return Err(Error::<T, I>::BalanceLow.into()),
+		};
+		let held = holds[index].amount;
+		ensure!(best_effort || held >= amount, Error::<T, I>::BalanceLow);
+		let actual = amount.min(held);
+		if actual == held {
+			holds.remove(index);
+		} else {
+			holds[index].amount = held - actual;
+		}
+		Ok(actual)
+	}
+
+	/// Store the `holds` of `who` in asset `id`, removing the entry once nothing is held.
+	fn put_holds(
+		id: T::AssetId,
+		who: &T::AccountId,
+		holds: BoundedVec<IdAmount<T::HoldReason, T::Balance>, T::MaxHolds>,
+	) {
+		if holds.is_empty() {
+			Holds::<T, I>::remove(id, who);
+		} else {
+			Holds::<T, I>::insert(id, who, holds);
+		}
+	}
+
+	/// Place `amount` of the asset `id` balance of `who` on hold for `reason`.
+	///
+	/// The funds must be withdrawable while keeping the account alive, so frozen funds cannot be
+	/// held. The held funds remain part of the supply of the asset.
+	pub(super) fn do_hold(
+		id: T::AssetId,
+		reason: &T::HoldReason,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+
+		let mut holds = Holds::<T, I>::get(id, who);
+		Self::add_hold(&mut holds, reason, amount)?;
+
+		let f = DebitFlags { keep_alive: true, best_effort: false };
+		Self::decrease_balance(id, who, amount, f, |_, _| Ok(()))?;
+		Self::put_holds(id, who, holds);
+
+		Self::deposit_event(Event::Held {
+			asset_id: id,
+			who: who.clone(),
+			reason: *reason,
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Release up to `amount` of the asset `id` funds of `who` held for `reason`, or exactly
+	/// `amount` unless `best_effort` is `true`.
+	///
+	/// Returns the amount released.
+	pub(super) fn do_release(
+		id: T::AssetId,
+		reason: &T::HoldReason,
+		who: &T::AccountId,
+		amount: T::Balance,
+		best_effort: bool,
+	) -> Result<T::Balance, DispatchError> {
+		let mut holds = Holds::<T, I>::get(id, who);
+		let actual = Self::remove_hold(&mut holds, reason, amount, best_effort)?;
+		if actual.is_zero() {
+			return Ok(actual)
+		}
+
+		Account::<T, I>::try_mutate(id, who, |maybe_account| -> DispatchResult {
+			let account = maybe_account.as_mut().ok_or(Error::<T, I>::NoAccount)?;
+			account.balance =
+				account.balance.checked_add(&actual).ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		})?;
+		Self::put_holds(id, who, holds);
+
+		Self::deposit_event(Event::Released {
+			asset_id: id,
+			who: who.clone(),
+			reason: *reason,
+			amount: actual,
+		});
+		Ok(actual)
+	}
+
+	/// Transfer up to `amount` of the asset `id` funds of `source` held for `reason` to `dest`, or
+	/// exactly `amount` unless `best_effort` is `true`.
+	///
+	/// If `on_hold` is `true`, the funds stay on hold for `reason` in the account of `dest`, which
+	/// must exist. Otherwise they are credited to the balance of `dest`.
+	///
+	/// Returns the amount transferred.
+	pub(super) fn do_transfer_held(
+		id: T::AssetId,
+		reason: &T::HoldReason,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		best_effort: bool,
+		on_hold: bool,
+	) -> Result<T::Balance, DispatchError> {
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
+		ensure!(details.status != AssetStatus::Frozen, Error::<T, I>::Frozen);
+
+		if source == dest {
+			return match on_hold {
+				true => Ok(Self::balance_on_hold(id, reason, source).min(amount)),
+				false => Self::do_release(id, reason, source, amount, best_effort),
+			}
+		}
+
+		let mut source_holds = Holds::<T, I>::get(id, source);
+		let actual = Self::remove_hold(&mut source_holds, reason, amount, best_effort)?;
+		if actual.is_zero() {
+			return Ok(actual)
+		}
+
+		if on_hold {
+			ensure!(Account::<T, I>::contains_key(id, dest), Error::<T, I>::NoAccount);
+			let mut dest_holds = Holds::<T, I>::get(id, dest);
+			Self::add_hold(&mut dest_holds, reason, actual)?;
+			Self::put_holds(id, dest, dest_holds);
+		} else {
+			Self::increase_balance(id, dest, actual, |_| Ok(()))?;
+		}
+		Self::put_holds(id, source, source_holds);
+
+		Self::deposit_event(Event::HeldTransferred {
+			asset_id: id,
+			reason: *reason,
+			from: source.clone(),
+			to: dest.clone(),
+			amount: actual,
+			on_hold,
+		});
+		Ok(actual)
+	}
+
+	/// The amount of the asset `id` balance of `who` frozen by the freeze `freeze_id`.
+	pub(super) fn balance_frozen(
+		id: T::AssetId,
+		freeze_id: &T::FreezeId,
+		who: &T::AccountId,
+	) -> T::Balance {
+		Freezes::<T, I>::get(id, who)
+			.iter()
+			.find(|f| &f.id == freeze_id)
+			.map_or_else(Zero::zero, |f| f.amount)
+	}
+
+	/// Set the freeze `freeze_id` on the asset `id` account of `who` to `amount`, removing it if
+	/// `amount` is zero.
+	pub(super) fn do_set_freeze(
+		id: T::AssetId,
+		freeze_id: &T::FreezeId,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Self::do_thaw(id, freeze_id, who)
+		}
+		ensure!(Account::<T, I>::contains_key(id, who), Error::<T, I>::NoAccount);
+
+		let mut freezes = Freezes::<T, I>::get(id, who);
+		match freezes.iter().position(|f| &f.id == freeze_id) {
+			Some(index) => freezes[index].amount = amount,
+			None => freezes
+				.try_push(IdAmount { id: *freeze_id, amount })
+				.map_err(|_| Error::<T, I>::TooManyFreezes)?,
+		}
+		Freezes::<T, I>::insert(id, who, freezes);
+
+		Self::deposit_event(Event::FreezeSet {
+			asset_id: id,
+			who: who.clone(),
+			id: *freeze_id,
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Remove the freeze `freeze_id` from the asset `id` account of `who`, if it exists.
+	pub(super) fn do_thaw(
+		id: T::AssetId,
+		freeze_id: &T::FreezeId,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		let mut freezes = Freezes::<T, I>::get(id, who);
+		let index = match freezes.iter().position(|f| &f.id == freeze_id) {
+			Some(index) => index,
+			None => return Ok(()),
+		};
+		freezes.remove(index);
+		if freezes.is_empty() {
+			Freezes::<T, I>::remove(id, who);
+		} else {
+			Freezes::<T, I>::insert(id, who, freezes);
+		}
+
+		Self::deposit_event(Event::FreezeRemoved {
+			asset_id: id,
+			who: who.clone(),
+			id: *freeze_id,
+		});
+		Ok(())
+	}
 }
@@ -58,6 +58,17 @@
 //!   from the approving account into some third-party destination account.
 //! * **Sufficiency**: The idea of a minimum-balance of an asset being sufficient to allow the
 //!   account's existence on the system without requiring any other existential-deposit.
+//! * **Asset account deposit**: A deposit reserved from an account in order to hold an asset
+//!   account for an asset which is not sufficient, without requiring a provider reference on it. It
+//!   is taken by `touch` and returned by `refund`.
+//!
+//! ### Asset Destruction
+//!
+//! Destroying an asset class may involve an unbounded number of accounts and approvals, so it is
+//! done over several blocks. The owner (or the `ForceOrigin`) first calls `start_destroy`, after
+//! which the asset can no longer be used. Anyone may then repeatedly call `destroy_accounts` and
+//! `destroy_approvals`, each of which removes at most `RemoveItemsLimit` items, until none remain.
+//! Finally, `finish_destroy` removes the asset class and returns the owner's deposits.
 //!
 //! ### Goals
 //!
@@ -84,6 +95,13 @@
 //! * `approve_transfer`: Create or increase an delegated transfer.
 //! * `cancel_approval`: Rescind a previous approval.
 //! * `transfer_approved`: Transfer third-party's assets to another account.
+//! * `touch`: Create an asset account for non-provider assets, taking the required deposit.
+//! * `refund`: Return the deposit (if any) of the caller's asset account.
+//! * `destroy_accounts`: Destroy a batch of the accounts of an asset class that is being destroyed.
+//! * `destroy_approvals`: Destroy a batch of the approvals of an asset class that is being
+//!   destroyed.
+//! * `finish_destroy`: Finish the destruction of an asset class, once all its accounts and
+//!   approvals are gone.
 //!
 //! ### Permissioned Functions
 //!
@@ -94,7 +112,8 @@
 //! * `force_cancel_approval`: Rescind a previous approval.
 //!
 //! ### Privileged Functions
-//! * `destroy`: Destroys an entire asset class; called by the asset class's Owner.
+//! * `start_destroy`: Starts the destruction of an entire asset class; called by the asset class's
+//!   Owner.
 //! * `mint`: Increases the asset balance of an account; called by the asset class's Issuer.
 //! * `burn`: Decreases the asset balance of an account; called by the asset class's Admin.
 //! * `force_transfer`: Transfers between arbitrary accounts; called by the asset class's Admin.
@@ -122,6 +141,7 @@
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
+#![recursion_limit = "256"]
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
@@ -136,6 +156,7 @@ pub use extra_mutator::*;
 mod functions;
 mod impl_fungibles;
 mod impl_stored_map;
+pub mod migration;
 mod types;
 pub use types::*;
 
@@ -156,7 +177,7 @@ use frame_support::{
 	traits::{
 		tokens::{fungibles, DepositConsequence, WithdrawConsequence},
 		BalanceStatus::Reserved,
-		Currency, ReservableCurrency, StoredMap,
+		Currency, ReservableCurrency, StorageVersion, StoredMap,
 	},
 };
 use frame_system::Config as SystemConfig;
@@ -170,9 +191,13 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
 	#[pallet::generate_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[pallet::config]
@@ -212,6 +237,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type AssetDeposit: Get<DepositBalanceOf<Self, I>>;
 
+		/// The amount of funds that must be reserved for a non-provider asset account to be
+		/// maintained.
+		#[pallet::constant]
+		type AssetAccountDeposit: Get<DepositBalanceOf<Self, I>>;
+
 		/// The basic amount of funds that must be reserved when adding metadata to your asset.
 		#[pallet::constant]
 		type MetadataDepositBase: Get<DepositBalanceOf<Self, I>>;
@@ -236,6 +266,26 @@ pub mod pallet {
 		/// Additional data to be stored with an account's asset balance.
 		type Extra: Member + Parameter + Default + MaxEncodedLen;
 
+		/// The maximum number of accounts or approvals that may be removed in a single call to
+		/// `destroy_accounts` or `destroy_approvals`.
+		#[pallet::constant]
+		type RemoveItemsLimit: Get<u32>;
+
+		/// The identifier of the reasons for which funds may be held on an asset account.
+		type HoldReason: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// The identifier of the freezes which may be placed on an asset account.
+		type FreezeId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// The maximum number of distinct reasons for which funds may be held on an asset
+		/// account.
+		#[pallet::constant]
+		type MaxHolds: Get<u32>;
+
+		/// The maximum number of distinct freezes which may be placed on an asset account.
+		#[pallet::constant]
+		type MaxFreezes: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -250,19 +300,57 @@ pub mod pallet {
 	>;
 
 	#[pallet::storage]
-	/// The number of units of assets held by any given account.
+	/// The holdings of a specific account for a specific asset.
 	pub(super) type Account<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		T::AssetId,
 		Blake2_128Concat,
 		T::AccountId,
-		AssetBalance<T::Balance, T::Extra>,
-		ValueQuery,
+		AssetAccount<T::Balance, DepositBalanceOf<T, I>, T::Extra>,
+		OptionQuery,
 		GetDefault,
 		ConstU32<300_000>,
 	>;
 
+	#[pallet::storage]
+	/// The assets for which an account holds an asset account. First key is the owner of the
+	/// asset account and second key is the asset ID.
+	pub(super) type AccountAssets<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AssetId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The funds of an asset account which are on hold, for each reason.
+	pub(super) type Holds<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<T::HoldReason, T::Balance>, T::MaxHolds>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The freezes placed on an asset account. Freezes overlap, so the frozen amount of the
+	/// account is the largest of them.
+	pub(super) type Freezes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<T::FreezeId, T::Balance>, T::MaxFreezes>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	/// Approved balance transfers. First balance is the amount approved for transfer. Second
 	/// is the amount of `T::Currency` reserved for storing this.
@@ -333,7 +421,7 @@ pub mod pallet {
 						accounts: 0,
 						sufficients: 0,
 						approvals: 0,
-						is_frozen: false,
+						status: AssetStatus::Live,
 					},
 				);
 			}
@@ -408,6 +496,16 @@ pub mod pallet {
 		AssetFrozen { asset_id: T::AssetId },
 		/// Some asset `asset_id` was thawed.
 		AssetThawed { asset_id: T::AssetId },
+		/// Accounts were destroyed for given asset.
+		AccountsDestroyed { asset_id: T::AssetId, accounts_destroyed: u32, accounts_remaining: u32 },
+		/// Approvals were destroyed for given asset.
+		ApprovalsDestroyed {
+			asset_id: T::AssetId,
+			approvals_destroyed: u32,
+			approvals_remaining: u32,
+		},
+		/// An asset class is in the process of being destroyed.
+		DestructionStarted { asset_id: T::AssetId },
 		/// An asset class was destroyed.
 		Destroyed { asset_id: T::AssetId },
 		/// Some asset class was force-created.
@@ -442,6 +540,32 @@ pub mod pallet {
 		},
 		/// An asset has had its attributes changed by the `Force` origin.
 		AssetStatusChanged { asset_id: T::AssetId },
+		/// An asset account was created for `who`, with `deposit` reserved from it.
+		Touched { asset_id: T::AssetId, who: T::AccountId, deposit: DepositBalanceOf<T, I> },
+		/// The `deposit` held for the asset account of `who` was refunded.
+		DepositRefunded { asset_id: T::AssetId, who: T::AccountId, deposit: DepositBalanceOf<T, I> },
+		/// Some `amount` of the asset balance of `who` was placed on hold for `reason`.
+		Held { asset_id: T::AssetId, who: T::AccountId, reason: T::HoldReason, amount: T::Balance },
+		/// Some `amount` of the asset balance of `who` held for `reason` was released.
+		Released {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			reason: T::HoldReason,
+			amount: T::Balance,
+		},
+		/// Some `amount` of the asset balance of `from` held for `reason` was transferred to `to`.
+		HeldTransferred {
+			asset_id: T::AssetId,
+			reason: T::HoldReason,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+			on_hold: bool,
+		},
+		/// The freeze `id` on the asset account of `who` was set to `amount`.
+		FreezeSet { asset_id: T::AssetId, who: T::AccountId, id: T::FreezeId, amount: T::Balance },
+		/// The freeze `id` on the asset account of `who` was removed.
+		FreezeRemoved { asset_id: T::AssetId, who: T::AccountId, id: T::FreezeId },
 	}
 
 	#[pallet::error]
@@ -471,6 +595,24 @@ pub mod pallet {
 		Unapproved,
 		/// The source account would not survive the transfer and it needs to stay alive.
 		WouldDie,
+		/// The asset-account already exists.
+		AlreadyExists,
+		/// The asset-account doesn't have an associated deposit.
+		NoDeposit,
+		/// The operation would result in funds being burned.
+		WouldBurn,
+		/// The asset is not live, and likely being destroyed.
+		AssetNotLive,
+		/// The asset status is not the expected status.
+		IncorrectStatus,
+		/// The account to alter does not exist.
+		NoAccount,
+		/// Funds cannot be held for any more reasons on the account.
+		TooManyHolds,
+		/// No more freezes can be placed on the account.
+		TooManyFreezes,
+		/// The account has funds on hold.
+		ContainsHolds,
 	}
 
 	#[pallet::call]
@@ -524,7 +666,7 @@ pub mod pallet {
 					accounts: 0,
 					sufficients: 0,
 					approvals: 0,
-					is_frozen: false,
+					status: AssetStatus::Live,
 				},
 			);
 			Self::deposit_event(Event::Created { asset_id: id, creator: owner, owner: admin });
@@ -563,7 +705,7 @@ pub mod pallet {
 			Self::do_force_create(id, owner, is_sufficient, min_balance)
 		}
 
-		/// Destroy a class of fungible assets.
+		/// Start the process of destroying a class of fungible assets.
 		///
 		/// The origin must conform to `ForceOrigin` or must be Signed and the sender must be the
 		/// owner of the asset `id`.
@@ -571,37 +713,95 @@ pub mod pallet {
 		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
 		/// asset.
 		///
-		/// Emits `Destroyed` event when successful.
+		/// Once started, the asset can no longer be used, and its accounts and approvals must be
+		/// removed with `destroy_accounts` and `destroy_approvals` before the destruction can be
+		/// completed with `finish_destroy`.
+		///
+		/// Emits `DestructionStarted` event when successful.
 		///
-		/// NOTE: It can be helpful to first freeze an asset before destroying it so that you
-		/// can provide accurate witness information and prevent users from manipulating state
-		/// in a way that can make it harder to destroy.
-		///
-		/// Weight: `O(c + p + a)` where:
-		/// - `c = (witness.accounts - witness.sufficients)`
-		/// - `s = witness.sufficients`
-		/// - `a = witness.approvals`
-		#[pallet::weight(T::WeightInfo::destroy(
-			witness.accounts.saturating_sub(witness.sufficients),
- 			witness.sufficients,
- 			witness.approvals,
- 		))]
-		pub fn destroy(
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::start_destroy())]
+		pub fn start_destroy(
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
-			witness: DestroyWitness,
-		) -> DispatchResultWithPostInfo {
+		) -> DispatchResult {
 			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
 				Ok(_) => None,
 				Err(origin) => Some(ensure_signed(origin)?),
 			};
-			let details = Self::do_destroy(id, witness, maybe_check_owner)?;
-			Ok(Some(T::WeightInfo::destroy(
-				details.accounts.saturating_sub(details.sufficients),
-				details.sufficients,
-				details.approvals,
-			))
-			.into())
+			Self::do_start_destroy(id, maybe_check_owner)
+		}
+
+		/// Destroy all accounts associated with a given asset.
+		///
+		/// `destroy_accounts` should only be called after `start_destroy` has been called, and the
+		/// asset is in a `Destroying` state.
+		///
+		/// Due to weight restrictions, this function may need to be called multiple times to fully
+		/// destroy all accounts. It will destroy `RemoveItemsLimit` accounts at a time.
+		///
+		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
+		/// asset.
+		///
+		/// Each call emits the `AccountsDestroyed` event.
+		///
+		/// Weight: `O(c)` where `c` is the number of accounts destroyed, at most
+		/// `RemoveItemsLimit`.
+		#[pallet::weight(T::WeightInfo::destroy_accounts(T::RemoveItemsLimit::get()))]
+		pub fn destroy_accounts(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			let removed_accounts = Self::do_destroy_accounts(id, T::RemoveItemsLimit::get())?;
+			Ok(Some(T::WeightInfo::destroy_accounts(removed_accounts)).into())
+		}
+
+		/// Destroy all approvals associated with a given asset up to the max
+		/// (`RemoveItemsLimit`).
+		///
+		/// `destroy_approvals` should only be called after `start_destroy` has been called, and
+		/// the asset is in a `Destroying` state.
+		///
+		/// Due to weight restrictions, this function may need to be called multiple times to fully
+		/// destroy all approvals. It will destroy `RemoveItemsLimit` approvals at a time.
+		///
+		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
+		/// asset.
+		///
+		/// Each call emits the `ApprovalsDestroyed` event.
+		///
+		/// Weight: `O(a)` where `a` is the number of approvals destroyed, at most
+		/// `RemoveItemsLimit`.
+		#[pallet::weight(T::WeightInfo::destroy_approvals(T::RemoveItemsLimit::get()))]
+		pub fn destroy_approvals(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			let removed_approvals = Self::do_destroy_approvals(id, T::RemoveItemsLimit::get())?;
+			Ok(Some(T::WeightInfo::destroy_approvals(removed_approvals)).into())
+		}
+
+		/// Complete destroying asset and unreserve currency.
+		///
+		/// `finish_destroy` should only be called after `start_destroy` has been called, and the
+		/// asset is in a `Destroying` state. All accounts or approvals should be destroyed before
+		/// hand.
+		///
+		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
+		/// asset.
+		///
+		/// Emits `Destroyed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::finish_destroy())]
+		pub fn finish_destroy(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			Self::do_finish_destroy(id)
 		}
 
 		/// Mint assets of a particular class.
@@ -777,11 +977,17 @@ pub mod pallet {
 			let origin = ensure_signed(origin)?;
 
 			let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(
+				d.status == AssetStatus::Live || d.status == AssetStatus::Frozen,
+				Error::<T, I>::IncorrectStatus
+			);
 			ensure!(&origin == &d.freezer, Error::<T, I>::NoPermission);
 			let who = T::Lookup::lookup(who)?;
-			ensure!(Account::<T, I>::contains_key(id, &who), Error::<T, I>::BalanceZero);
 
-			Account::<T, I>::mutate(id, &who, |a| a.is_frozen = true);
+			Account::<T, I>::try_mutate(id, &who, |maybe_account| -> DispatchResult {
+				maybe_account.as_mut().ok_or(Error::<T, I>::BalanceZero)?.is_frozen = true;
+				Ok(())
+			})?;
 
 			Self::deposit_event(Event::<T, I>::Frozen { asset_id: id, who });
 			Ok(())
@@ -806,11 +1012,17 @@ pub mod pallet {
 			let origin = ensure_signed(origin)?;
 
 			let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(
+				details.status == AssetStatus::Live || details.status == AssetStatus::Frozen,
+				Error::<T, I>::IncorrectStatus
+			);
 			ensure!(&origin == &details.admin, Error::<T, I>::NoPermission);
 			let who = T::Lookup::lookup(who)?;
-			ensure!(Account::<T, I>::contains_key(id, &who), Error::<T, I>::BalanceZero);
 
-			Account::<T, I>::mutate(id, &who, |a| a.is_frozen = false);
+			Account::<T, I>::try_mutate(id, &who, |maybe_account| -> DispatchResult {
+				maybe_account.as_mut().ok_or(Error::<T, I>::BalanceZero)?.is_frozen = false;
+				Ok(())
+			})?;
 
 			Self::deposit_event(Event::<T, I>::Thawed { asset_id: id, who });
 			Ok(())
@@ -835,8 +1047,9 @@ pub mod pallet {
 			Asset::<T, I>::try_mutate(id, |maybe_details| {
 				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
 				ensure!(&origin == &d.freezer, Error::<T, I>::NoPermission);
+				ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 
-				d.is_frozen = true;
+				d.status = AssetStatus::Frozen;
 
 				Self::deposit_event(Event::<T, I>::AssetFrozen { asset_id: id });
 				Ok(())
@@ -862,8 +1075,12 @@ pub mod pallet {
 			Asset::<T, I>::try_mutate(id, |maybe_details| {
 				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
 				ensure!(&origin == &d.admin, Error::<T, I>::NoPermission);
+				ensure!(
+					d.status == AssetStatus::Live || d.status == AssetStatus::Frozen,
+					Error::<T, I>::IncorrectStatus
+				);
 
-				d.is_frozen = false;
+				d.status = AssetStatus::Live;
 
 				Self::deposit_event(Event::<T, I>::AssetThawed { asset_id: id });
 				Ok(())
@@ -1122,13 +1339,14 @@ pub mod pallet {
 
 			Asset::<T, I>::try_mutate(id, |maybe_asset| {
 				let mut asset = maybe_asset.take().ok_or(Error::<T, I>::Unknown)?;
+				ensure!(asset.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
 				asset.owner = T::Lookup::lookup(owner)?;
 				asset.issuer = T::Lookup::lookup(issuer)?;
 				asset.admin = T::Lookup::lookup(admin)?;
 				asset.freezer = T::Lookup::lookup(freezer)?;
 				asset.min_balance = min_balance;
 				asset.is_sufficient = is_sufficient;
-				asset.is_frozen = is_frozen;
+				asset.status = if is_frozen { AssetStatus::Frozen } else { AssetStatus::Live };
 				*maybe_asset = Some(asset);
 
 				Self::deposit_event(Event::AssetStatusChanged { asset_id: id });
@@ -1190,6 +1408,8 @@ pub mod pallet {
 			let owner = ensure_signed(origin)?;
 			let delegate = T::Lookup::lookup(delegate)?;
 			let mut d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+
 			let approval =
 				Approvals::<T, I>::take((id, &owner, &delegate)).ok_or(Error::<T, I>::Unknown)?;
 			T::Currency::unreserve(&owner, approval.deposit);
@@ -1222,6 +1442,7 @@ pub mod pallet {
 			delegate: <T::Lookup as StaticLookup>::Source,
 		) -> DispatchResult {
 			let mut d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 			T::ForceOrigin::try_origin(origin)
 				.map(|_| ())
 				.or_else(|origin| -> DispatchResult {
@@ -1274,5 +1495,36 @@ pub mod pallet {
 			let destination = T::Lookup::lookup(destination)?;
 			Self::do_transfer_approved(id, &owner, &delegate, &destination, amount)
 		}
+
+		/// Create an asset account for non-provider assets.
+		///
+		/// A deposit will be taken from the signer account.
+		///
+		/// - `origin`: Must be Signed; the signer account must have sufficient funds for a deposit
+		///   to be taken.
+		/// - `id`: The identifier of the asset for the account to be created.
+		///
+		/// Emits `Touched` event when successful.
+		#[pallet::weight(T::WeightInfo::touch())]
+		pub fn touch(origin: OriginFor<T>, #[pallet::compact] id: T::AssetId) -> DispatchResult {
+			Self::do_touch(id, ensure_signed(origin)?)
+		}
+
+		/// Return the deposit (if any) of an asset account.
+		///
+		/// The origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset for the account to be created.
+		/// - `allow_burn`: If `true` then assets may be destroyed in order to complete the refund.
+		///
+		/// Emits `DepositRefunded` event when successful.
+		#[pallet::weight(T::WeightInfo::refund())]
+		pub fn refund(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			allow_burn: bool,
+		) -> DispatchResult {
+			Self::do_refund(id, ensure_signed(origin)?, allow_burn)
+		}
 	}
 }
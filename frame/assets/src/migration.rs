@@ -0,0 +1,144 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the assets pallet.
+
+use super::*;
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+
+pub mod v1 {
+	use super::*;
+	use codec::Decode;
+
+	#[derive(Decode)]
+	pub struct OldAssetDetails<Balance, AccountId, DepositBalance> {
+		pub owner: AccountId,
+		pub issuer: AccountId,
+		pub admin: AccountId,
+		pub freezer: AccountId,
+		pub supply: Balance,
+		pub deposit: DepositBalance,
+		pub min_balance: Balance,
+		pub is_sufficient: bool,
+		pub accounts: u32,
+		pub sufficients: u32,
+		pub approvals: u32,
+		pub is_frozen: bool,
+	}
+
+	impl<Balance, AccountId, DepositBalance> OldAssetDetails<Balance, AccountId, DepositBalance> {
+		fn migrate_to_v1(self) -> AssetDetails<Balance, AccountId, DepositBalance> {
+			let status = if self.is_frozen { AssetStatus::Frozen } else { AssetStatus::Live };
+
+			AssetDetails {
+				owner: self.owner,
+				issuer: self.issuer,
+				admin: self.admin,
+				freezer: self.freezer,
+				supply: self.supply,
+				deposit: self.deposit,
+				min_balance: self.min_balance,
+				is_sufficient: self.is_sufficient,
+				accounts: self.accounts,
+				sufficients: self.sufficients,
+				approvals: self.approvals,
+				status,
+			}
+		}
+	}
+
+	#[derive(Decode)]
+	pub struct OldAssetBalance<Balance, Extra> {
+		pub balance: Balance,
+		pub is_frozen: bool,
+		pub sufficient: bool,
+		pub extra: Extra,
+	}
+
+	impl<Balance, Extra> OldAssetBalance<Balance, Extra> {
+		fn migrate_to_v1<DepositBalance>(self) -> AssetAccount<Balance, DepositBalance, Extra> {
+			let reason = if self.sufficient {
+				ExistenceReason::Sufficient
+			} else {
+				ExistenceReason::Consumer
+			};
+
+			AssetAccount {
+				balance: self.balance,
+				is_frozen: self.is_frozen,
+				reason,
+				extra: self.extra,
+			}
+		}
+	}
+
+	/// Migrate the pallet storage from `0` to `1`: asset details record their `status` rather
+	/// than an `is_frozen` flag, asset accounts record the reason of their existence, and every
+	/// asset account is recorded in `AccountAssets`.
+	///
+	/// This only runs if the on-chain storage version is `0`, and translates every `Asset` and
+	/// `Account` entry in a single block: `Asset` entries cost one read and one write, `Account`
+	/// entries one read and two writes. The old format can not be read by the new code, so the
+	/// migration can not be spread over several blocks; it is meant for chains with at most a few
+	/// thousand asset accounts, where this stays far below the block weight limit.
+	pub struct MigrateToV1<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T, I>::current_storage_version();
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 0 || current_version != 1 {
+				log::info!(
+					target: "runtime::assets",
+					"Migration did not execute. This probably should be removed"
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut assets = 0u64;
+			Asset::<T, I>::translate::<
+				OldAssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+				_,
+			>(|_key, old_value| {
+				assets.saturating_inc();
+				Some(old_value.migrate_to_v1())
+			});
+			let mut accounts = 0u64;
+			Account::<T, I>::translate::<OldAssetBalance<T::Balance, T::Extra>, _>(
+				|id, who, old_value| {
+					accounts.saturating_inc();
+					AccountAssets::<T, I>::insert(who, id, ());
+					Some(old_value.migrate_to_v1())
+				},
+			);
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+
+			log::info!(
+				target: "runtime::assets",
+				"Upgraded {} assets and {} accounts, storage to version 1",
+				assets,
+				accounts,
+			);
+			T::DbWeight::get().reads_writes(
+				assets.saturating_add(accounts).saturating_add(1),
+				assets.saturating_add(accounts.saturating_mul(2)).saturating_add(1),
+			)
+		}
+	}
+}
@@ -20,7 +20,10 @@
 use super::*;
 use crate as pallet_assets;
 
-use frame_support::{construct_runtime, parameter_types, traits::GenesisBuild};
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, GenesisBuild},
+};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -89,10 +92,12 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub const AssetDeposit: u64 = 1;
+	pub const AssetAccountDeposit: u64 = 10;
 	pub const ApprovalDeposit: u64 = 1;
 	pub const StringLimit: u32 = 50;
 	pub const MetadataDepositBase: u64 = 1;
 	pub const MetadataDepositPerByte: u64 = 1;
+	pub const RemoveItemsLimit: u32 = 5;
 }
 
 impl Config for Test {
@@ -102,6 +107,7 @@ impl Config for Test {
 	type Currency = Balances;
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
 	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
 	type MetadataDepositBase = MetadataDepositBase;
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ApprovalDeposit;
@@ -109,6 +115,11 @@ impl Config for Test {
 	type Freezer = TestFreezer;
 	type WeightInfo = ();
 	type Extra = ();
+	type RemoveItemsLimit = RemoveItemsLimit;
+	type HoldReason = u8;
+	type FreezeId = u8;
+	type MaxHolds = ConstU32<2>;
+	type MaxFreezes = ConstU32<2>;
 }
 
 use std::{cell::RefCell, collections::HashMap};
@@ -191,8 +191,10 @@ fn lifecycle_should_work() {
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 20, 100));
 		assert_eq!(Account::<Test>::iter_prefix(0).count(), 2);
 
-		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
-		assert_ok!(Assets::destroy(Origin::signed(1), 0, w));
+		assert_ok!(Assets::start_destroy(Origin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(Origin::signed(1), 0));
+		assert_ok!(Assets::destroy_approvals(Origin::signed(1), 0));
+		assert_ok!(Assets::finish_destroy(Origin::signed(1), 0));
 		assert_eq!(Balances::reserved_balance(&1), 0);
 
 		assert!(!Asset::<Test>::contains_key(0));
@@ -211,8 +213,10 @@ fn lifecycle_should_work() {
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 20, 100));
 		assert_eq!(Account::<Test>::iter_prefix(0).count(), 2);
 
-		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
-		assert_ok!(Assets::destroy(Origin::root(), 0, w));
+		assert_ok!(Assets::start_destroy(Origin::root(), 0));
+		assert_ok!(Assets::destroy_accounts(Origin::signed(1), 0));
+		assert_ok!(Assets::destroy_approvals(Origin::signed(1), 0));
+		assert_ok!(Assets::finish_destroy(Origin::signed(1), 0));
 		assert_eq!(Balances::reserved_balance(&1), 0);
 
 		assert!(!Asset::<Test>::contains_key(0));
@@ -222,18 +226,46 @@ fn lifecycle_should_work() {
 }
 
 #[test]
-fn destroy_with_bad_witness_should_not_work() {
+fn destroy_should_work_in_batches() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
-		let mut w = Asset::<Test>::get(0).unwrap().destroy_witness();
-		assert_ok!(Assets::mint(Origin::signed(1), 0, 10, 100));
-		// witness too low
-		assert_noop!(Assets::destroy(Origin::signed(1), 0, w), Error::<Test>::BadWitness);
-		// witness too high is okay though
-		w.accounts += 2;
-		w.sufficients += 2;
-		assert_ok!(Assets::destroy(Origin::signed(1), 0, w));
+		for who in 10..17 {
+			assert_ok!(Assets::mint(Origin::signed(1), 0, who, 100));
+		}
+		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 7);
+
+		// the asset can't be finished before it is started, nor while accounts remain.
+		assert_noop!(Assets::finish_destroy(Origin::signed(1), 0), Error::<Test>::IncorrectStatus);
+		assert_noop!(
+			Assets::destroy_accounts(Origin::signed(1), 0),
+			Error::<Test>::IncorrectStatus
+		);
+		assert_ok!(Assets::start_destroy(Origin::signed(1), 0));
+		assert_noop!(Assets::start_destroy(Origin::signed(1), 0), Error::<Test>::IncorrectStatus);
+		assert_noop!(Assets::finish_destroy(Origin::signed(1), 0), Error::<Test>::InUse);
+
+		// the asset can no longer be used.
+		assert_noop!(Assets::transfer(Origin::signed(10), 0, 20, 50), Error::<Test>::AssetNotLive);
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 10, 50), TokenError::UnknownAsset);
+		assert_noop!(Assets::freeze_asset(Origin::signed(1), 0), Error::<Test>::AssetNotLive);
+
+		// at most `RemoveItemsLimit` accounts are removed at a time, by any signed origin.
+		assert_ok!(Assets::destroy_accounts(Origin::signed(2), 0));
+		System::assert_last_event(mock::Event::Assets(crate::Event::AccountsDestroyed {
+			asset_id: 0,
+			accounts_destroyed: 5,
+			accounts_remaining: 2,
+		}));
+		assert_eq!(Account::<Test>::iter_prefix(0).count(), 2);
+		assert_ok!(Assets::destroy_accounts(Origin::signed(2), 0));
+		assert_eq!(Account::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(Asset::<Test>::get(0).unwrap().supply, 0);
+		assert_eq!(Asset::<Test>::get(0).unwrap().sufficients, 0);
+
+		assert_ok!(Assets::finish_destroy(Origin::signed(2), 0));
+		System::assert_last_event(mock::Event::Assets(crate::Event::Destroyed { asset_id: 0 }));
+		assert!(!Asset::<Test>::contains_key(0));
 	});
 }
 
@@ -248,8 +280,10 @@ fn destroy_should_refund_approvals() {
 		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 4, 50));
 		assert_eq!(Balances::reserved_balance(&1), 3);
 
-		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
-		assert_ok!(Assets::destroy(Origin::signed(1), 0, w));
+		assert_ok!(Assets::start_destroy(Origin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(Origin::signed(1), 0));
+		assert_ok!(Assets::destroy_approvals(Origin::signed(1), 0));
+		assert_ok!(Assets::finish_destroy(Origin::signed(1), 0));
 		assert_eq!(Balances::reserved_balance(&1), 0);
 
 		// all approvals are removed
@@ -282,6 +316,120 @@ fn non_providing_should_work() {
 	});
 }
 
+#[test]
+fn touching_and_refunding_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, false, 1));
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_noop!(Assets::refund(Origin::signed(1), 0, false), Error::<Test>::NoDeposit);
+
+		// touching reserves the deposit and creates an empty account.
+		assert_ok!(Assets::touch(Origin::signed(1), 0));
+		System::assert_last_event(mock::Event::Assets(crate::Event::Touched {
+			asset_id: 0,
+			who: 1,
+			deposit: 10,
+		}));
+		assert_eq!(Balances::reserved_balance(&1), 10);
+		assert!(Account::<Test>::contains_key(0, &1));
+		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 1);
+		assert_noop!(Assets::touch(Origin::signed(1), 0), Error::<Test>::AlreadyExists);
+
+		// the deposit, rather than a consumer reference, pays for the account.
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(frame_system::Pallet::<Test>::consumers(&1), 0);
+
+		// the account is kept even once it is emptied.
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 100));
+		assert!(Account::<Test>::contains_key(0, &1));
+		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 2);
+
+		// accounts without a deposit can't be refunded.
+		assert_noop!(Assets::refund(Origin::signed(2), 0, true), Error::<Test>::NoDeposit);
+
+		assert_ok!(Assets::refund(Origin::signed(1), 0, false));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert!(!Account::<Test>::contains_key(0, &1));
+		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 1);
+	});
+}
+
+#[test]
+fn refunding_with_balance_requires_burn() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, false, 1));
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::touch(Origin::signed(1), 0));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+
+		assert_noop!(Assets::refund(Origin::signed(1), 0, false), Error::<Test>::WouldBurn);
+		assert_ok!(Assets::refund(Origin::signed(1), 0, true));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Assets::total_supply(0), 0);
+		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 0);
+	});
+}
+
+#[test]
+fn destroy_should_refund_account_deposits() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, false, 1));
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::touch(Origin::signed(2), 0));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_eq!(Balances::reserved_balance(&2), 10);
+
+		assert_ok!(Assets::start_destroy(Origin::root(), 0));
+		assert_noop!(Assets::refund(Origin::signed(2), 0, true), Error::<Test>::AssetNotLive);
+		assert_ok!(Assets::destroy_accounts(Origin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_ok!(Assets::finish_destroy(Origin::signed(2), 0));
+		assert!(!Asset::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn account_assets_should_track_asset_accounts() {
+	new_test_ext().execute_with(|| {
+		let assets_of = |who| {
+			let mut assets = Assets::account_assets(who);
+			assets.sort();
+			assets
+		};
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, false, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, true, 1));
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+		assert!(assets_of(2).is_empty());
+
+		// touching and minting create asset accounts.
+		assert_ok!(Assets::touch(Origin::signed(2), 0));
+		assert_ok!(Assets::mint(Origin::signed(1), 1, 2, 100));
+		assert_eq!(assets_of(2), vec![0, 1]);
+
+		// a transfer creates the account of the receiver, and removes the emptied account of the
+		// sender, unless a deposit is held for it.
+		assert_ok!(Assets::transfer(Origin::signed(2), 1, 3, 100));
+		assert_eq!(assets_of(2), vec![0]);
+		assert_eq!(assets_of(3), vec![1]);
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 100));
+		assert_eq!(assets_of(2), vec![0]);
+		assert_eq!(assets_of(3), vec![0, 1]);
+
+		// refunding removes the account.
+		assert_ok!(Assets::refund(Origin::signed(2), 0, false));
+		assert!(assets_of(2).is_empty());
+
+		// destroying the asset removes its accounts.
+		assert_ok!(Assets::start_destroy(Origin::root(), 0));
+		assert_ok!(Assets::destroy_accounts(Origin::signed(3), 0));
+		assert_eq!(assets_of(3), vec![1]);
+	});
+}
+
 #[test]
 fn min_balance_should_work() {
 	new_test_ext().execute_with(|| {
@@ -418,8 +566,7 @@ fn origin_guards_should_work() {
 			Assets::force_transfer(Origin::signed(2), 0, 1, 2, 100),
 			Error::<Test>::NoPermission
 		);
-		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
-		assert_noop!(Assets::destroy(Origin::signed(2), 0, w), Error::<Test>::NoPermission);
+		assert_noop!(Assets::start_destroy(Origin::signed(2), 0), Error::<Test>::NoPermission);
 	});
 }
 
@@ -488,7 +635,7 @@ fn transferring_amount_more_than_available_balance_should_not_work() {
 		assert_eq!(Assets::balance(0, 2), 50);
 		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, u64::MAX));
 		assert_eq!(Assets::balance(0, 1), 0);
-		assert_noop!(Assets::transfer(Origin::signed(1), 0, 1, 50), Error::<Test>::BalanceLow);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 1, 50), Error::<Test>::NoAccount);
 		assert_noop!(Assets::transfer(Origin::signed(2), 0, 1, 51), Error::<Test>::BalanceLow);
 	});
 }
@@ -536,7 +683,7 @@ fn burning_asset_balance_with_zero_balance_does_nothing() {
 		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 2), 0);
-		assert_ok!(Assets::burn(Origin::signed(1), 0, 2, u64::MAX));
+		assert_noop!(Assets::burn(Origin::signed(1), 0, 2, u64::MAX), Error::<Test>::NoAccount);
 		assert_eq!(Assets::balance(0, 2), 0);
 		assert_eq!(Assets::total_supply(0), 100);
 	});
@@ -654,6 +801,151 @@ fn imbalances_should_work() {
 	});
 }
 
+#[test]
+fn holds_should_work() {
+	use frame_support::traits::tokens::fungibles::{InspectHold, MutateHold};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+
+		// funds are held separately for each reason, and stay part of the supply.
+		assert_ok!(Assets::hold(0, &1, &1, 30));
+		assert_ok!(Assets::hold(0, &2, &1, 20));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance_on_hold(0, &1, &1), 30);
+		assert_eq!(Assets::balance_on_hold(0, &2, &1), 20);
+		assert_eq!(Assets::total_balance_on_hold(0, &1), 50);
+		assert_eq!(Assets::total_supply(0), 100);
+
+		// at most `MaxHolds` reasons, and the account must stay alive.
+		assert!(!Assets::can_hold(0, &3, &1, 1));
+		assert_noop!(Assets::hold(0, &3, &1, 1), Error::<Test>::TooManyHolds);
+		assert!(Assets::can_hold(0, &1, &1, 40));
+		assert!(!Assets::can_hold(0, &1, &1, 41));
+		assert_noop!(Assets::hold(0, &1, &1, 41), Error::<Test>::BalanceLow);
+
+		// an account with funds on hold cannot be removed.
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::BalanceLow);
+
+		assert_noop!(Assets::release(0, &1, &1, 40, false), Error::<Test>::BalanceLow);
+		assert_eq!(Assets::release(0, &1, &1, 40, true), Ok(30));
+		assert_eq!(Assets::balance(0, 1), 80);
+		assert_eq!(Assets::balance_on_hold(0, &1, &1), 0);
+
+		// held funds may be transferred, either freely or on hold.
+		assert_eq!(Assets::transfer_held(0, &2, &1, &2, 10, false, false), Ok(10));
+		assert_eq!(Assets::balance(0, 2), 10);
+		assert_eq!(Assets::transfer_held(0, &2, &1, &2, 10, false, true), Ok(10));
+		assert_eq!(Assets::balance_on_hold(0, &2, &2), 10);
+		assert_eq!(Assets::total_balance_on_hold(0, &1), 0);
+		assert!(!Holds::<Test>::contains_key(0, 1));
+		assert_noop!(
+			Assets::transfer_held(0, &2, &2, &3, 10, false, true),
+			Error::<Test>::NoAccount
+		);
+		assert_eq!(Assets::total_supply(0), 100);
+
+		// once nothing is held, the account may be removed.
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 3, 80));
+		assert_eq!(Assets::balance(0, 1), 0);
+	});
+}
+
+#[test]
+fn freezes_should_work() {
+	use frame_support::traits::tokens::fungibles::{InspectFreeze, MutateFreeze, MutateHold};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(Assets::set_freeze(0, &1, &2, 10), Error::<Test>::NoAccount);
+
+		// freezes overlap, so only the largest counts.
+		assert_ok!(Assets::set_freeze(0, &1, &1, 50));
+		assert_ok!(Assets::set_freeze(0, &2, &1, 30));
+		assert_eq!(Assets::balance_frozen(0, &1, &1), 50);
+		assert_eq!(Assets::balance_frozen(0, &2, &1), 30);
+		assert!(Assets::can_freeze(0, &1, &1));
+		assert!(!Assets::can_freeze(0, &3, &1));
+		assert_noop!(Assets::set_freeze(0, &3, &1, 10), Error::<Test>::TooManyFreezes);
+
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 40));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 1), Error::<Test>::BalanceLow);
+
+		// the freezer hook is respected together with the freezes.
+		set_frozen_balance(0, 1, 45);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 1), Error::<Test>::BalanceLow);
+		clear_frozen_balance(0, 1);
+
+		assert_ok!(<Assets as MutateFreeze<u64>>::thaw(0, &1, &1));
+		assert_eq!(Assets::balance_frozen(0, &1, &1), 0);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 20));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 1), Error::<Test>::BalanceLow);
+
+		assert_ok!(Assets::extend_freeze(0, &2, &1, 10));
+		assert_eq!(Assets::balance_frozen(0, &2, &1), 30);
+		assert_ok!(Assets::extend_freeze(0, &2, &1, 35));
+		assert_eq!(Assets::balance_frozen(0, &2, &1), 35);
+
+		// frozen funds cannot be held.
+		assert_noop!(Assets::hold(0, &1, &1, 1), Error::<Test>::BalanceLow);
+
+		// setting a freeze to zero removes it, and the account can be removed again.
+		assert_ok!(Assets::set_freeze(0, &2, &1, 0));
+		assert!(!Freezes::<Test>::contains_key(0, 1));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 40));
+		assert_eq!(Assets::balance(0, 1), 0);
+	});
+}
+
+#[test]
+fn slashing_held_funds_should_work() {
+	use frame_support::traits::tokens::fungibles::{Balanced, BalancedHold, MutateHold};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::hold(0, &1, &1, 30));
+
+		// held funds cannot be withdrawn.
+		assert!(Assets::withdraw(0, &1, 61).is_err());
+
+		let (credit, left) = Assets::slash_held(0, &1, &1, 20);
+		assert_eq!(credit.peek(), 20);
+		assert_eq!(left, 0);
+		assert_eq!(Assets::balance_on_hold(0, &1, &1), 10);
+		assert_eq!(Assets::balance(0, 1), 70);
+		assert_eq!(Assets::total_supply(0), 100);
+
+		drop(credit);
+		assert_eq!(Assets::total_supply(0), 80);
+	});
+}
+
+#[test]
+fn held_funds_should_be_removed_with_the_asset() {
+	use frame_support::traits::tokens::fungibles::{MutateFreeze, MutateHold};
+
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, false, 10));
+		assert_ok!(Assets::touch(Origin::signed(2), 0));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::hold(0, &1, &2, 50));
+		assert_ok!(Assets::set_freeze(0, &1, &2, 20));
+
+		assert_noop!(Assets::refund(Origin::signed(2), 0, true), Error::<Test>::ContainsHolds);
+
+		assert_ok!(Assets::start_destroy(Origin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(Origin::signed(1), 0));
+		assert_eq!(Asset::<Test>::get(0).unwrap().supply, 0);
+		assert!(!Holds::<Test>::contains_key(0, 2));
+		assert!(!Freezes::<Test>::contains_key(0, 2));
+	});
+}
+
 #[test]
 fn force_metadata_should_work() {
 	new_test_ext().execute_with(|| {
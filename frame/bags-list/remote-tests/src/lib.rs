@@ -17,6 +17,7 @@
 
 //! Utilities for remote-testing pallet-bags-list.
 
+use frame_election_provider_support::VoteWeight;
 use sp_std::prelude::*;
 
 /// A common log target to use.
@@ -30,10 +31,16 @@ pub mod snapshot;
 ///
 /// For example, this can be the `Runtime` type of the Polkadot runtime.
 pub trait RuntimeT:
-	pallet_staking::Config + pallet_bags_list::Config + frame_system::Config
+	pallet_staking::Config + pallet_bags_list::Config<Score = VoteWeight> + frame_system::Config
+{
+}
+impl<
+		T: pallet_staking::Config
+			+ pallet_bags_list::Config<Score = VoteWeight>
+			+ frame_system::Config,
+	> RuntimeT for T
 {
 }
-impl<T: pallet_staking::Config + pallet_bags_list::Config + frame_system::Config> RuntimeT for T {}
 
 fn percent(portion: u32, total: u32) -> f64 {
 	(portion as f64 / total as f64) * 100f64
@@ -17,13 +17,14 @@
 
 //! # Bags-List Pallet
 //!
-//! A semi-sorted list, where items hold an `AccountId` based on some `VoteWeight`. The `AccountId`
-//! (`id` for short) might be synonym to a `voter` or `nominator` in some context, and `VoteWeight`
+//! A semi-sorted list, where items hold an `AccountId` based on some `Score`. The `AccountId`
+//! (`id` for short) might be synonym to a `voter` or `nominator` in some context, and `Score`
 //! signifies the chance of each id being included in the final [`SortedListProvider::iter`].
 //!
 //! It implements [`frame_election_provider_support::SortedListProvider`] to provide a semi-sorted
-//! list of accounts to another pallet. It needs some other pallet to give it some information about
-//! the weights of accounts via [`frame_election_provider_support::VoteWeightProvider`].
+//! list of accounts to another pallet, as long as the score is a `VoteWeight`. It needs some other
+//! pallet to give it some information about the scores of accounts via
+//! [`frame_election_provider_support::ScoreProvider`].
 //!
 //! This pallet is not configurable at genesis. Whoever uses it should call appropriate functions of
 //! the `SortedListProvider` (e.g. `on_insert`, or `regenerate`) at their genesis.
@@ -33,12 +34,12 @@
 //! The data structure exposed by this pallet aims to be optimized for:
 //!
 //! - insertions and removals.
-//! - iteration over the top* N items by weight, where the precise ordering of items doesn't
+//! - iteration over the top* N items by score, where the precise ordering of items doesn't
 //!   particularly matter.
 //!
 //! # Details
 //!
-//! - items are kept in bags, which are delineated by their range of weight (See
+//! - items are kept in bags, which are delineated by their range of score (See
 //!   [`Config::BagThresholds`]).
 //! - for iteration, bags are chained together from highest to lowest and elements within the bag
 //!   are iterated from head to tail.
@@ -46,14 +47,21 @@
 //!   it will worsen its position in list iteration; this reduces incentives for some types of spam
 //!   that involve consistently removing and inserting for better position. Further, ordering
 //!   granularity is thus dictated by range between each bag threshold.
-//! - if an item's weight changes to a value no longer within the range of its current bag the
-//!   item's position will need to be updated by an external actor with rebag (update), or removal
-//!   and insertion.
+//! - if an item's score changes to a value no longer within the range of its current bag the item's
+//!   position will need to be updated by an external actor with rebag (update), or removal and
+//!   insertion. Additionally, up to [`Config::MaxAutoRebagPerBlock`] items are checked and rebagged
+//!   if needed in `on_idle`, iterating the whole list over the course of several blocks.
+//! - within a bag, an item can move itself directly in front of another item of the same bag with a
+//!   lower score via [`Pallet::put_in_front_of`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_election_provider_support::{SortedListProvider, VoteWeight, VoteWeightProvider};
+use codec::FullCodec;
+use frame_election_provider_support::{ScoreProvider, SortedListProvider, VoteWeight};
+use frame_support::weights::Weight;
 use frame_system::ensure_signed;
+use scale_info::TypeInfo;
+use sp_runtime::traits::{AtLeast32BitUnsigned, Bounded};
 use sp_std::prelude::*;
 
 #[cfg(any(feature = "runtime-benchmarks", test))]
@@ -67,7 +75,7 @@ pub mod mock;
 mod tests;
 pub mod weights;
 
-pub use list::{notional_bag_for, Bag, Error, List, Node};
+pub use list::{notional_bag_for, Bag, List, ListError, Node};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -103,28 +111,28 @@ pub mod pallet {
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: weights::WeightInfo;
 
-		/// Something that provides the weights of ids.
-		type VoteWeightProvider: VoteWeightProvider<Self::AccountId>;
+		/// Something that provides the scores of ids.
+		type ScoreProvider: ScoreProvider<Self::AccountId, Score = Self::Score>;
 
 		/// The list of thresholds separating the various bags.
 		///
-		/// Ids are separated into unsorted bags according to their vote weight. This specifies the
-		/// thresholds separating the bags. An id's bag is the largest bag for which the id's weight
+		/// Ids are separated into unsorted bags according to their score. This specifies the
+		/// thresholds separating the bags. An id's bag is the largest bag for which the id's score
 		/// is less than or equal to its upper threshold.
 		///
 		/// When ids are iterated, higher bags are iterated completely before lower bags. This means
-		/// that iteration is _semi-sorted_: ids of higher weight tend to come before ids of lower
-		/// weight, but peer ids within a particular bag are sorted in insertion order.
+		/// that iteration is _semi-sorted_: ids of higher score tend to come before ids of lower
+		/// score, but peer ids within a particular bag are sorted in insertion order.
 		///
 		/// # Expressing the constant
 		///
 		/// This constant must be sorted in strictly increasing order. Duplicate items are not
 		/// permitted.
 		///
-		/// There is an implied upper limit of `VoteWeight::MAX`; that value does not need to be
+		/// There is an implied upper limit of `Score::max_value()`; that value does not need to be
 		/// specified within the bag. For any two threshold lists, if one ends with
-		/// `VoteWeight::MAX`, the other one does not, and they are otherwise equal, the two lists
-		/// will behave identically.
+		/// `Score::max_value()`, the other one does not, and they are otherwise equal, the two
+		/// lists will behave identically.
 		///
 		/// # Calculation
 		///
@@ -142,15 +150,40 @@ pub mod pallet {
 		///   the procedure given above, then the constant ratio is equal to 2.
 		/// - If `BagThresholds::get().len() == 200`, and the thresholds are determined according to
 		///   the procedure given above, then the constant ratio is approximately equal to 1.248.
-		/// - If the threshold list begins `[1, 2, 3, ...]`, then an id with weight 0 or 1 will fall
-		///   into bag 0, an id with weight 2 will fall into bag 1, etc.
+		/// - If the threshold list begins `[1, 2, 3, ...]`, then an id with score 0 or 1 will fall
+		///   into bag 0, an id with score 2 will fall into bag 1, etc.
 		///
 		/// # Migration
 		///
 		/// In the event that this list ever changes, a copy of the old bags list must be retained.
 		/// With that `List::migrate` can be called, which will perform the appropriate migration.
 		#[pallet::constant]
-		type BagThresholds: Get<&'static [VoteWeight]>;
+		type BagThresholds: Get<&'static [Self::Score]>;
+
+		/// The type used to dictate a node position relative to other nodes.
+		type Score: Clone
+			+ Default
+			+ PartialEq
+			+ Eq
+			+ Ord
+			+ PartialOrd
+			+ sp_std::fmt::Debug
+			+ Copy
+			+ AtLeast32BitUnsigned
+			+ Bounded
+			+ TypeInfo
+			+ FullCodec
+			+ MaxEncodedLen;
+
+		/// The maximum number of ids that are checked, and rebagged if needed, in `on_idle` per
+		/// block.
+		///
+		/// The ids are checked in list order, starting at [`NextNodeAutoRebagged`], such that the
+		/// entire list is eventually visited over the course of several blocks. The amount of
+		/// ids checked is further limited by the remaining weight of the block. Setting this to
+		/// zero disables the automatic rebagging.
+		#[pallet::constant]
+		type MaxAutoRebagPerBlock: Get<u32>;
 	}
 
 	/// A single node, within some bag.
@@ -164,19 +197,47 @@ pub mod pallet {
 	///
 	/// Stores a `Bag` struct, which stores head and tail pointers to itself.
 	#[pallet::storage]
-	pub(crate) type ListBags<T: Config> = StorageMap<_, Twox64Concat, VoteWeight, list::Bag<T>>;
+	pub(crate) type ListBags<T: Config> = StorageMap<_, Twox64Concat, T::Score, list::Bag<T>>;
+
+	/// The next id to be checked by the automatic rebagging in `on_idle`.
+	///
+	/// If it is `None`, or the id is no longer part of the list, the next check starts at the head
+	/// of the list.
+	#[pallet::storage]
+	pub type NextNodeAutoRebagged<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Moved an account from one bag to another.
-		Rebagged { who: T::AccountId, from: VoteWeight, to: VoteWeight },
+		Rebagged { who: T::AccountId, from: T::Score, to: T::Score },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The given id is not part of the list.
+		IdNotFound,
+		/// Attempted to place a node in front of a node in another bag.
+		NotInSameBag,
+		/// The id does not have a greater score than the id it is put in front of.
+		NotHeavier,
+	}
+
+	impl<T> From<ListError> for Error<T> {
+		fn from(error: ListError) -> Self {
+			match error {
+				// `Duplicate` can only be returned when inserting into the list.
+				ListError::Duplicate | ListError::NodeNotFound => Error::<T>::IdNotFound,
+				ListError::NotInSameBag => Error::<T>::NotInSameBag,
+				ListError::NotHeavier => Error::<T>::NotHeavier,
+			}
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Declare that some `dislocated` account has, through rewards or penalties, sufficiently
-		/// changed its weight that it should properly fall into a different bag than its current
+		/// changed its score that it should properly fall into a different bag than its current
 		/// one.
 		///
 		/// Anyone can call this function about any potentially dislocated account.
@@ -186,14 +247,32 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::rebag_non_terminal().max(T::WeightInfo::rebag_terminal()))]
 		pub fn rebag(origin: OriginFor<T>, dislocated: T::AccountId) -> DispatchResult {
 			ensure_signed(origin)?;
-			let current_weight = T::VoteWeightProvider::vote_weight(&dislocated);
-			let _ = Pallet::<T>::do_rebag(&dislocated, current_weight);
+			let current_score = T::ScoreProvider::score(&dislocated);
+			let _ = Pallet::<T>::do_rebag(&dislocated, current_score);
 			Ok(())
 		}
+
+		/// Move the caller's id directly in front of `lighter`.
+		///
+		/// The dispatch origin for this call must be _Signed_ and can only be called by the id of
+		/// the account going in front of `lighter`.
+		///
+		/// Only works if:
+		/// - both nodes are within the same bag,
+		/// - and `origin` has a greater `Score` than `lighter`.
+		#[pallet::weight(T::WeightInfo::put_in_front_of())]
+		pub fn put_in_front_of(origin: OriginFor<T>, lighter: T::AccountId) -> DispatchResult {
+			let heavier = ensure_signed(origin)?;
+			List::<T>::put_in_front_of(&lighter, &heavier).map_err(|e| Error::<T>::from(e).into())
+		}
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::do_auto_rebag(remaining_weight)
+		}
+
 		fn integrity_test() {
 			// ensure they are strictly increasing, this also implies that duplicates are detected.
 			assert!(
@@ -208,34 +287,89 @@ impl<T: Config> Pallet<T> {
 	/// Move an account from one bag to another, depositing an event on success.
 	///
 	/// If the account changed bags, returns `Some((from, to))`.
-	pub fn do_rebag(
-		account: &T::AccountId,
-		new_weight: VoteWeight,
-	) -> Option<(VoteWeight, VoteWeight)> {
+	pub fn do_rebag(account: &T::AccountId, new_score: T::Score) -> Option<(T::Score, T::Score)> {
 		// if no voter at that node, don't do anything.
 		// the caller just wasted the fee to call this.
 		let maybe_movement = list::Node::<T>::get(&account)
-			.and_then(|node| List::update_position_for(node, new_weight));
+			.and_then(|node| List::update_position_for(node, new_score));
 		if let Some((from, to)) = maybe_movement {
 			Self::deposit_event(Event::<T>::Rebagged { who: account.clone(), from, to });
 		};
 		maybe_movement
 	}
 
+	/// Check up to [`Config::MaxAutoRebagPerBlock`] ids, starting at [`NextNodeAutoRebagged`], and
+	/// rebag those that are misplaced, within the given weight.
+	///
+	/// Returns the weight consumed.
+	pub(crate) fn do_auto_rebag(remaining_weight: Weight) -> Weight {
+		use frame_support::traits::Get as _;
+
+		// reading and writing the cursor.
+		let base_weight = T::DbWeight::get().reads_writes(1, 1);
+		// the worst case of checking a single id is rebagging it.
+		let item_weight = T::WeightInfo::rebag_non_terminal().max(T::WeightInfo::rebag_terminal());
+
+		let max_items_by_weight = remaining_weight.saturating_sub(base_weight) / item_weight.max(1);
+		let max_items =
+			(T::MaxAutoRebagPerBlock::get() as Weight).min(max_items_by_weight) as usize;
+		if max_items == 0 {
+			return 0
+		}
+
+		let iter: Box<dyn Iterator<Item = list::Node<T>>> =
+			match NextNodeAutoRebagged::<T>::get().and_then(|next| list::Node::<T>::get(&next)) {
+				// continue with the id that was next in line when the previous check stopped.
+				Some(next) => match List::<T>::iter_from(next.id()) {
+					Ok(rest) => Box::new(sp_std::iter::once(next).chain(rest)),
+					Err(_) => Box::new(List::<T>::iter()),
+				},
+				// either a fresh start, or the next id has left the list in the meantime.
+				None => Box::new(List::<T>::iter()),
+			};
+
+		// collect the ids first, such that rebagging can not interfere with the iteration. One
+		// more id is taken to know where to continue in the next block.
+		let mut ids = iter
+			.take(max_items.saturating_add(1))
+			.map(|node| node.id().clone())
+			.collect::<Vec<_>>();
+		let maybe_next = if ids.len() > max_items { ids.pop() } else { None };
+
+		for id in ids.iter() {
+			let _ = Self::do_rebag(id, T::ScoreProvider::score(id));
+		}
+
+		match maybe_next {
+			Some(next) => NextNodeAutoRebagged::<T>::put(next),
+			// we reached the end of the list, start over from the head in the next block.
+			None => NextNodeAutoRebagged::<T>::kill(),
+		}
+
+		base_weight.saturating_add(item_weight.saturating_mul(ids.len() as Weight))
+	}
+
 	/// Equivalent to `ListBags::get`, but public. Useful for tests in outside of this crate.
 	#[cfg(feature = "std")]
-	pub fn list_bags_get(weight: VoteWeight) -> Option<list::Bag<T>> {
-		ListBags::get(weight)
+	pub fn list_bags_get(score: T::Score) -> Option<list::Bag<T>> {
+		ListBags::get(score)
 	}
 }
 
-impl<T: Config> SortedListProvider<T::AccountId> for Pallet<T> {
-	type Error = Error;
+impl<T: Config<Score = VoteWeight>> SortedListProvider<T::AccountId> for Pallet<T> {
+	type Error = ListError;
 
 	fn iter() -> Box<dyn Iterator<Item = T::AccountId>> {
 		Box::new(List::<T>::iter().map(|n| n.id().clone()))
 	}
 
+	fn iter_from(
+		start: &T::AccountId,
+	) -> Result<Box<dyn Iterator<Item = T::AccountId>>, ListError> {
+		let iter = List::<T>::iter_from(start)?;
+		Ok(Box::new(iter.map(|n| n.id().clone())))
+	}
+
 	fn count() -> u32 {
 		ListNodes::<T>::count()
 	}
@@ -244,7 +378,7 @@ impl<T: Config> SortedListProvider<T::AccountId> for Pallet<T> {
 		List::<T>::contains(id)
 	}
 
-	fn on_insert(id: T::AccountId, weight: VoteWeight) -> Result<(), Error> {
+	fn on_insert(id: T::AccountId, weight: VoteWeight) -> Result<(), ListError> {
 		List::<T>::insert(id, weight)
 	}
 
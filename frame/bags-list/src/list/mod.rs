@@ -26,9 +26,10 @@
 
 use crate::Config;
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_election_provider_support::{VoteWeight, VoteWeightProvider};
+use frame_election_provider_support::ScoreProvider;
 use frame_support::{traits::Get, DefaultNoBound};
 use scale_info::TypeInfo;
+use sp_runtime::traits::{Bounded, Zero};
 use sp_std::{
 	boxed::Box,
 	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
@@ -38,39 +39,45 @@ use sp_std::{
 };
 
 #[derive(Debug, PartialEq, Eq)]
-pub enum Error {
+pub enum ListError {
 	/// A duplicate id has been detected.
 	Duplicate,
+	/// The given id does not exist in the list.
+	NodeNotFound,
+	/// An id does not have a greater score than another id.
+	NotHeavier,
+	/// Attempted to place node in front of a node in another bag.
+	NotInSameBag,
 }
 
 #[cfg(test)]
 mod tests;
 
-/// Given a certain vote weight, to which bag does it belong to?
+/// Given a certain score, to which bag does it belong to?
 ///
 /// Bags are identified by their upper threshold; the value returned by this function is guaranteed
 /// to be a member of `T::BagThresholds`.
 ///
-/// Note that even if the thresholds list does not have `VoteWeight::MAX` as its final member, this
-/// function behaves as if it does.
-pub fn notional_bag_for<T: Config>(weight: VoteWeight) -> VoteWeight {
+/// Note that even if the thresholds list does not have `T::Score::max_value()` as its final member,
+/// this function behaves as if it does.
+pub fn notional_bag_for<T: Config>(score: T::Score) -> T::Score {
 	let thresholds = T::BagThresholds::get();
-	let idx = thresholds.partition_point(|&threshold| weight > threshold);
-	thresholds.get(idx).copied().unwrap_or(VoteWeight::MAX)
+	let idx = thresholds.partition_point(|&threshold| score > threshold);
+	thresholds.get(idx).copied().unwrap_or_else(T::Score::max_value)
 }
 
 /// The **ONLY** entry point of this module. All operations to the bags-list should happen through
 /// this interface. It is forbidden to access other module members directly.
 //
-// Data structure providing efficient mostly-accurate selection of the top N id by `VoteWeight`.
+// Data structure providing efficient mostly-accurate selection of the top N id by `Score`.
 //
 // It's implemented as a set of linked lists. Each linked list comprises a bag of ids of
-// arbitrary and unbounded length, all having a vote weight within a particular constant range.
+// arbitrary and unbounded length, all having a score within a particular constant range.
 // This structure means that ids can be added and removed in `O(1)` time.
 //
 // Iteration is accomplished by chaining the iteration of each bag, from greatest to least. While
-// the users within any particular bag are sorted in an entirely arbitrary order, the overall vote
-// weight decreases as successive bags are reached. This means that it is valid to truncate
+// the users within any particular bag are sorted in an entirely arbitrary order, the overall score
+// decreases as successive bags are reached. This means that it is valid to truncate
 // iteration at any desired point; only those ids in the lowest bag can be excluded. This
 // satisfies both the desire for fairness and the requirement for efficiency.
 pub struct List<T: Config>(PhantomData<T>);
@@ -102,10 +109,10 @@ impl<T: Config> List<T> {
 	/// Returns the number of ids migrated.
 	pub fn regenerate(
 		all: impl IntoIterator<Item = T::AccountId>,
-		weight_of: Box<dyn Fn(&T::AccountId) -> VoteWeight>,
+		score_of: Box<dyn Fn(&T::AccountId) -> T::Score>,
 	) -> u32 {
 		Self::clear(None);
-		Self::insert_many(all, weight_of)
+		Self::insert_many(all, score_of)
 	}
 
 	/// Migrate the list from one set of thresholds to another.
@@ -128,7 +135,7 @@ impl<T: Config> List<T> {
 	/// - ids whose bags change at all are implicitly rebagged into the appropriate bag in the new
 	///   threshold set.
 	#[allow(dead_code)]
-	pub fn migrate(old_thresholds: &[VoteWeight]) -> u32 {
+	pub fn migrate(old_thresholds: &[T::Score]) -> u32 {
 		let new_thresholds = T::BagThresholds::get();
 		if new_thresholds == old_thresholds {
 			return 0
@@ -159,7 +166,7 @@ impl<T: Config> List<T> {
 			let affected_bag = {
 				// this recreates `notional_bag_for` logic, but with the old thresholds.
 				let idx = old_thresholds.partition_point(|&threshold| inserted_bag > threshold);
-				old_thresholds.get(idx).copied().unwrap_or(VoteWeight::MAX)
+				old_thresholds.get(idx).copied().unwrap_or_else(T::Score::max_value)
 			};
 			if !affected_old_bags.insert(affected_bag) {
 				// If the previous threshold list was [10, 20], and we insert [3, 5], then there's
@@ -186,10 +193,10 @@ impl<T: Config> List<T> {
 
 		// migrate the voters whose bag has changed
 		let num_affected = affected_accounts.len() as u32;
-		let weight_of = T::VoteWeightProvider::vote_weight;
+		let score_of = T::ScoreProvider::score;
 		let _removed = Self::remove_many(&affected_accounts);
 		debug_assert_eq!(_removed, num_affected);
-		let _inserted = Self::insert_many(affected_accounts.into_iter(), weight_of);
+		let _inserted = Self::insert_many(affected_accounts.into_iter(), score_of);
 		debug_assert_eq!(_inserted, num_affected);
 
 		// we couldn't previously remove the old bags because both insertion and removal assume that
@@ -229,29 +236,55 @@ impl<T: Config> List<T> {
 		// easier; they can just configure `type BagThresholds = ()`.
 		let thresholds = T::BagThresholds::get();
 		let iter = thresholds.iter().copied();
-		let iter: Box<dyn Iterator<Item = u64>> = if thresholds.last() == Some(&VoteWeight::MAX) {
-			// in the event that they included it, we can just pass the iterator through unchanged.
-			Box::new(iter.rev())
-		} else {
-			// otherwise, insert it here.
-			Box::new(iter.chain(iter::once(VoteWeight::MAX)).rev())
-		};
+		let iter: Box<dyn Iterator<Item = T::Score>> =
+			if thresholds.last() == Some(&T::Score::max_value()) {
+				// in the event that they included it, we can just pass the iterator through
+				// unchanged.
+				Box::new(iter.rev())
+			} else {
+				// otherwise, insert it here.
+				Box::new(iter.chain(iter::once(T::Score::max_value())).rev())
+			};
 
 		iter.filter_map(Bag::get).flat_map(|bag| bag.iter())
 	}
 
+	/// Same as [`List::iter`], but starts right after the node of `start`, which is excluded.
+	///
+	/// The iteration continues with the rest of the bag of `start`, followed by all the bags with a
+	/// lower threshold.
+	pub(crate) fn iter_from(
+		start: &T::AccountId,
+	) -> Result<impl Iterator<Item = Node<T>>, ListError> {
+		let start_node = Node::<T>::get(start).ok_or(ListError::NodeNotFound)?;
+		let start_bag_upper = start_node.bag_upper;
+		let rest_of_start_bag = sp_std::iter::successors(start_node.next(), |prev| prev.next());
+
+		// all thresholds strictly below the bag of `start`, in descending order.
+		let thresholds = T::BagThresholds::get();
+		let lower_bags = thresholds.partition_point(|&threshold| threshold < start_bag_upper);
+		let rest_of_list = thresholds[..lower_bags]
+			.iter()
+			.rev()
+			.copied()
+			.filter_map(Bag::get)
+			.flat_map(|bag| bag.iter());
+
+		Ok(rest_of_start_bag.chain(rest_of_list))
+	}
+
 	/// Insert several ids into the appropriate bags in the list. Continues with insertions
 	/// if duplicates are detected.
 	///
 	/// Returns the final count of number of ids inserted.
 	fn insert_many(
 		ids: impl IntoIterator<Item = T::AccountId>,
-		weight_of: impl Fn(&T::AccountId) -> VoteWeight,
+		score_of: impl Fn(&T::AccountId) -> T::Score,
 	) -> u32 {
 		let mut count = 0;
 		ids.into_iter().for_each(|v| {
-			let weight = weight_of(&v);
-			if Self::insert(v, weight).is_ok() {
+			let score = score_of(&v);
+			if Self::insert(v, score).is_ok() {
 				count += 1;
 			}
 		});
@@ -262,13 +295,13 @@ impl<T: Config> List<T> {
 	/// Insert a new id into the appropriate bag in the list.
 	///
 	/// Returns an error if the list already contains `id`.
-	pub(crate) fn insert(id: T::AccountId, weight: VoteWeight) -> Result<(), Error> {
+	pub(crate) fn insert(id: T::AccountId, score: T::Score) -> Result<(), ListError> {
 		if Self::contains(&id) {
-			return Err(Error::Duplicate)
+			return Err(ListError::Duplicate)
 		}
 
-		let bag_weight = notional_bag_for::<T>(weight);
-		let mut bag = Bag::<T>::get_or_make(bag_weight);
+		let bag_score = notional_bag_for::<T>(score);
+		let mut bag = Bag::<T>::get_or_make(bag_score);
 		// unchecked insertion is okay; we just got the correct `notional_bag_for`.
 		bag.insert_unchecked(id.clone());
 
@@ -277,10 +310,10 @@ impl<T: Config> List<T> {
 
 		crate::log!(
 			debug,
-			"inserted {:?} with weight {} into bag {:?}, new count is {}",
+			"inserted {:?} with score {:?} into bag {:?}, new count is {}",
 			id,
-			weight,
-			bag_weight,
+			score,
+			bag_score,
 			crate::ListNodes::<T>::count(),
 		);
 
@@ -343,9 +376,9 @@ impl<T: Config> List<T> {
 	/// to call [`self.remove_many`] followed by [`self.insert_many`].
 	pub(crate) fn update_position_for(
 		node: Node<T>,
-		new_weight: VoteWeight,
-	) -> Option<(VoteWeight, VoteWeight)> {
-		node.is_misplaced(new_weight).then(move || {
+		new_score: T::Score,
+	) -> Option<(T::Score, T::Score)> {
+		node.is_misplaced(new_score).then(move || {
 			let old_bag_upper = node.bag_upper;
 
 			if !node.is_terminal() {
@@ -366,7 +399,7 @@ impl<T: Config> List<T> {
 			}
 
 			// put the node into the appropriate new bag.
-			let new_bag_upper = notional_bag_for::<T>(new_weight);
+			let new_bag_upper = notional_bag_for::<T>(new_score);
 			let mut bag = Bag::<T>::get_or_make(new_bag_upper);
 			// prev, next, and bag_upper of the node are updated inside `insert_node`, also
 			// `node.put` is in there.
@@ -377,6 +410,84 @@ impl<T: Config> List<T> {
 		})
 	}
 
+	/// Put `heavier_id` to the position directly in front of `lighter_id`. Both ids must be in the
+	/// same bag and the score of `heavier_id` must be greater than that of `lighter_id`.
+	pub(crate) fn put_in_front_of(
+		lighter_id: &T::AccountId,
+		heavier_id: &T::AccountId,
+	) -> Result<(), ListError> {
+		use frame_support::ensure;
+
+		let lighter_node = Node::<T>::get(lighter_id).ok_or(ListError::NodeNotFound)?;
+		let heavier_node = Node::<T>::get(heavier_id).ok_or(ListError::NodeNotFound)?;
+
+		ensure!(lighter_node.bag_upper == heavier_node.bag_upper, ListError::NotInSameBag);
+
+		// this is the most expensive check, so we do it last.
+		ensure!(
+			T::ScoreProvider::score(heavier_id) > T::ScoreProvider::score(lighter_id),
+			ListError::NotHeavier
+		);
+
+		// remove the heavier node from this list. Note that this removes the node from storage and
+		// decrements the node counter.
+		Self::remove(heavier_id);
+
+		// re-fetch `lighter_node` from storage since it may have been updated when `heavier_node`
+		// was removed.
+		let lighter_node = Node::<T>::get(lighter_id).ok_or_else(|| {
+			debug_assert!(false, "id that should exist cannot be found");
+			crate::log!(warn, "id that should exist cannot be found");
+			ListError::NodeNotFound
+		})?;
+
+		// insert `heavier_node` directly in front of `lighter_node`. This will update both nodes
+		// in storage and update the node counter.
+		Self::insert_at_unchecked(lighter_node, heavier_node);
+
+		Ok(())
+	}
+
+	/// Insert `node` directly in front of `at`.
+	///
+	/// This is a naive function in that it does not check if `node` belongs to the same bag as
+	/// `at`. It is expected that the call site will check preconditions.
+	fn insert_at_unchecked(mut at: Node<T>, mut node: Node<T>) {
+		// connect `node` to its new `prev`.
+		node.prev = at.prev.clone();
+		if let Some(mut prev) = at.prev() {
+			prev.next = Some(node.id().clone());
+			prev.put()
+		}
+
+		// connect `node` and `at`.
+		node.next = Some(at.id().clone());
+		at.prev = Some(node.id().clone());
+
+		if node.is_terminal() {
+			// `node` is the new head, so we make sure the bag is updated. Note, since `node` is
+			// always in front of `at` we know that 1) there is always at least 2 nodes in the bag,
+			// and 2) only `node` could be the head and only `at` could be the tail.
+			if let Some(mut bag) = Bag::<T>::get(at.bag_upper) {
+				if node.prev == None {
+					bag.head = Some(node.id().clone())
+				}
+				bag.put()
+			} else {
+				crate::log!(
+					error,
+					"Node {:?} did not have a bag; ListBags is in an inconsistent state",
+					at.id,
+				);
+				debug_assert!(false, "every node must have an extant bag associated with it");
+			}
+		};
+
+		// write the updated nodes to storage.
+		at.put();
+		node.put();
+	}
+
 	/// Sanity check the list.
 	///
 	/// This should be called from the call-site, whenever one of the mutating apis (e.g. `insert`)
@@ -405,13 +516,14 @@ impl<T: Config> List<T> {
 
 		let active_bags = {
 			let thresholds = T::BagThresholds::get().iter().copied();
-			let thresholds: Vec<u64> = if thresholds.clone().last() == Some(VoteWeight::MAX) {
-				// in the event that they included it, we don't need to make any changes
-				thresholds.collect()
-			} else {
-				// otherwise, insert it here.
-				thresholds.chain(iter::once(VoteWeight::MAX)).collect()
-			};
+			let thresholds: Vec<T::Score> =
+				if thresholds.clone().last() == Some(T::Score::max_value()) {
+					// in the event that they included it, we don't need to make any changes
+					thresholds.collect()
+				} else {
+					// otherwise, insert it here.
+					thresholds.chain(iter::once(T::Score::max_value())).collect()
+				};
 			thresholds.into_iter().filter_map(|t| Bag::<T>::get(t))
 		};
 
@@ -440,18 +552,20 @@ impl<T: Config> List<T> {
 	/// Returns the nodes of all non-empty bags. For testing and benchmarks.
 	#[cfg(any(feature = "std", feature = "runtime-benchmarks"))]
 	#[allow(dead_code)]
-	pub(crate) fn get_bags() -> Vec<(VoteWeight, Vec<T::AccountId>)> {
+	pub(crate) fn get_bags() -> Vec<(T::Score, Vec<T::AccountId>)> {
 		use frame_support::traits::Get as _;
 
 		let thresholds = T::BagThresholds::get();
 		let iter = thresholds.iter().copied();
-		let iter: Box<dyn Iterator<Item = u64>> = if thresholds.last() == Some(&VoteWeight::MAX) {
-			// in the event that they included it, we can just pass the iterator through unchanged.
-			Box::new(iter)
-		} else {
-			// otherwise, insert it here.
-			Box::new(iter.chain(sp_std::iter::once(VoteWeight::MAX)))
-		};
+		let iter: Box<dyn Iterator<Item = T::Score>> =
+			if thresholds.last() == Some(&T::Score::max_value()) {
+				// in the event that they included it, we can just pass the iterator through
+				// unchanged.
+				Box::new(iter)
+			} else {
+				// otherwise, insert it here.
+				Box::new(iter.chain(sp_std::iter::once(T::Score::max_value())))
+			};
 
 		iter.filter_map(|t| {
 			Bag::<T>::get(t).map(|bag| (t, bag.iter().map(|n| n.id().clone()).collect::<Vec<_>>()))
@@ -476,7 +590,7 @@ pub struct Bag<T: Config> {
 	tail: Option<T::AccountId>,
 
 	#[codec(skip)]
-	bag_upper: VoteWeight,
+	bag_upper: T::Score,
 }
 
 impl<T: Config> Bag<T> {
@@ -484,22 +598,22 @@ impl<T: Config> Bag<T> {
 	pub(crate) fn new(
 		head: Option<T::AccountId>,
 		tail: Option<T::AccountId>,
-		bag_upper: VoteWeight,
+		bag_upper: T::Score,
 	) -> Self {
 		Self { head, tail, bag_upper }
 	}
 
-	/// Get a bag by its upper vote weight.
-	pub(crate) fn get(bag_upper: VoteWeight) -> Option<Bag<T>> {
+	/// Get a bag by its upper score.
+	pub(crate) fn get(bag_upper: T::Score) -> Option<Bag<T>> {
 		crate::ListBags::<T>::try_get(bag_upper).ok().map(|mut bag| {
 			bag.bag_upper = bag_upper;
 			bag
 		})
 	}
 
-	/// Get a bag by its upper vote weight or make it, appropriately initialized. Does not check if
+	/// Get a bag by its upper score or make it, appropriately initialized. Does not check if
 	/// if `bag_upper` is a valid threshold.
-	fn get_or_make(bag_upper: VoteWeight) -> Bag<T> {
+	fn get_or_make(bag_upper: T::Score) -> Bag<T> {
 		Self::get(bag_upper).unwrap_or(Bag { bag_upper, ..Default::default() })
 	}
 
@@ -543,7 +657,12 @@ impl<T: Config> Bag<T> {
 		// insert_node will overwrite `prev`, `next` and `bag_upper` to the proper values. As long
 		// as this bag is the correct one, we're good. All calls to this must come after getting the
 		// correct [`notional_bag_for`].
-		self.insert_node_unchecked(Node::<T> { id, prev: None, next: None, bag_upper: 0 });
+		self.insert_node_unchecked(Node::<T> {
+			id,
+			prev: None,
+			next: None,
+			bag_upper: Zero::zero(),
+		});
 	}
 
 	/// Insert a node into this bag.
@@ -678,7 +797,7 @@ pub struct Node<T: Config> {
 	id: T::AccountId,
 	prev: Option<T::AccountId>,
 	next: Option<T::AccountId>,
-	bag_upper: VoteWeight,
+	bag_upper: T::Score,
 }
 
 impl<T: Config> Node<T> {
@@ -727,8 +846,8 @@ impl<T: Config> Node<T> {
 	}
 
 	/// `true` when this voter is in the wrong bag.
-	pub fn is_misplaced(&self, current_weight: VoteWeight) -> bool {
-		notional_bag_for::<T>(current_weight) != self.bag_upper
+	pub fn is_misplaced(&self, current_score: T::Score) -> bool {
+		notional_bag_for::<T>(current_score) != self.bag_upper
 	}
 
 	/// `true` when this voter is a bag head or tail.
@@ -751,7 +870,7 @@ impl<T: Config> Node<T> {
 	/// The bag this nodes belongs to (public for benchmarks).
 	#[cfg(feature = "runtime-benchmarks")]
 	#[allow(dead_code)]
-	pub fn bag_upper(&self) -> VoteWeight {
+	pub fn bag_upper(&self) -> T::Score {
 		self.bag_upper
 	}
 
@@ -20,7 +20,7 @@ use crate::{
 	mock::{test_utils::*, *},
 	ListBags, ListNodes,
 };
-use frame_election_provider_support::SortedListProvider;
+use frame_election_provider_support::{ScoreProvider, SortedListProvider, VoteWeight};
 use frame_support::{assert_ok, assert_storage_noop};
 
 #[test]
@@ -211,6 +211,39 @@ mod list {
 			})
 	}
 
+	#[test]
+	fn iter_from_works() {
+		ExtBuilder::default()
+			.add_ids(vec![(5, 2_000), (6, 2_000)])
+			.build_and_execute(|| {
+				// given
+				assert_eq!(
+					List::<Runtime>::get_bags(),
+					vec![(10, vec![1]), (1_000, vec![2, 3, 4]), (2_000, vec![5, 6])]
+				);
+
+				// when starting in the middle of a bag, then the rest of the bag and all the lower
+				// bags are iterated.
+				let iteration = List::<Runtime>::iter_from(&6)
+					.unwrap()
+					.map(|node| *node.id())
+					.collect::<Vec<_>>();
+				assert_eq!(iteration, vec![2, 3, 4, 1]);
+
+				let iteration = List::<Runtime>::iter_from(&3)
+					.unwrap()
+					.map(|node| *node.id())
+					.collect::<Vec<_>>();
+				assert_eq!(iteration, vec![4, 1]);
+
+				// when starting from the last node, then nothing is left.
+				assert_eq!(List::<Runtime>::iter_from(&1).unwrap().count(), 0);
+
+				// when starting from a non-existent node, then an error is returned.
+				assert!(matches!(List::<Runtime>::iter_from(&42), Err(ListError::NodeNotFound)));
+			})
+	}
+
 	#[test]
 	fn insert_works() {
 		ExtBuilder::default().build_and_execute(|| {
@@ -242,7 +275,7 @@ mod list {
 			// then
 			assert_storage_noop!(assert_eq!(
 				List::<Runtime>::insert(3, 20).unwrap_err(),
-				Error::Duplicate
+				ListError::Duplicate
 			));
 		});
 	}
@@ -383,6 +416,26 @@ mod list {
 			assert!(non_existent_ids.iter().all(|id| !List::<Runtime>::contains(id)));
 		})
 	}
+
+	#[test]
+	fn put_in_front_of_head_of_bag_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1]), (1_000, vec![2, 3, 4])]);
+			StakingMock::set_score_of(&2, 1_000);
+			StakingMock::set_score_of(&4, 999);
+
+			// when the head is put in front of the tail
+			assert_ok!(List::<Runtime>::put_in_front_of(&4, &2));
+
+			// then the bag gets a new head
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1]), (1_000, vec![3, 2, 4])]);
+			assert_eq!(Bag::<Runtime>::get(1_000).unwrap(), Bag::new(Some(3), Some(4), 1_000));
+
+			// and the ids can not be swapped back
+			assert_eq!(List::<Runtime>::put_in_front_of(&2, &4), Err(ListError::NotHeavier));
+		});
+	}
 }
 
 mod bags {
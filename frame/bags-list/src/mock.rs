@@ -21,28 +21,33 @@ use super::*;
 use crate::{self as bags_list};
 use frame_election_provider_support::VoteWeight;
 use frame_support::parameter_types;
+use std::collections::HashMap;
 
 pub type AccountId = u32;
 pub type Balance = u32;
 
 parameter_types! {
 	pub static NextVoteWeight: VoteWeight = 0;
+	pub static NextVoteWeightMap: HashMap<AccountId, VoteWeight> = Default::default();
 }
 
 pub struct StakingMock;
-impl frame_election_provider_support::VoteWeightProvider<AccountId> for StakingMock {
-	fn vote_weight(id: &AccountId) -> VoteWeight {
+impl frame_election_provider_support::ScoreProvider<AccountId> for StakingMock {
+	type Score = VoteWeight;
+
+	fn score(id: &AccountId) -> Self::Score {
 		match id {
 			710 => 15,
 			711 => 16,
 			712 => 2_000, // special cases used for migrate test
-			_ => NextVoteWeight::get(),
+			_ => NextVoteWeightMap::get().get(id).copied().unwrap_or(NextVoteWeight::get()),
 		}
 	}
 	#[cfg(any(feature = "runtime-benchmarks", test))]
-	fn set_vote_weight_of(_: &AccountId, weight: VoteWeight) {
-		// we don't really keep a mapping, just set weight for everyone.
-		NextVoteWeight::set(weight)
+	fn set_score_of(id: &AccountId, weight: Self::Score) {
+		let mut weights = NextVoteWeightMap::get();
+		weights.insert(*id, weight);
+		NextVoteWeightMap::set(weights);
 	}
 }
 
@@ -74,13 +79,16 @@ impl frame_system::Config for Runtime {
 
 parameter_types! {
 	pub static BagThresholds: &'static [VoteWeight] = &[10, 20, 30, 40, 50, 60, 1_000, 2_000, 10_000];
+	pub static MaxAutoRebagPerBlock: u32 = 0;
 }
 
 impl bags_list::Config for Runtime {
 	type Event = Event;
 	type WeightInfo = ();
 	type BagThresholds = BagThresholds;
-	type VoteWeightProvider = StakingMock;
+	type ScoreProvider = StakingMock;
+	type Score = VoteWeight;
+	type MaxAutoRebagPerBlock = MaxAutoRebagPerBlock;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -15,10 +15,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use frame_support::{assert_ok, assert_storage_noop, traits::IntegrityTest};
+use frame_support::{
+	assert_noop, assert_ok, assert_storage_noop,
+	traits::{IntegrityTest, OnIdle},
+	weights::Weight,
+};
 
 use super::*;
-use frame_election_provider_support::SortedListProvider;
+use frame_election_provider_support::{ScoreProvider, SortedListProvider};
 use list::Bag;
 use mock::{test_utils::*, *};
 
@@ -159,6 +163,121 @@ mod pallet {
 		});
 	}
 
+	#[test]
+	fn put_in_front_of_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1]), (1_000, vec![2, 3, 4])]);
+			StakingMock::set_score_of(&2, 999);
+			StakingMock::set_score_of(&3, 999);
+			StakingMock::set_score_of(&4, 1_000);
+
+			// when the tail goes in front of the head
+			assert_ok!(BagsList::put_in_front_of(Origin::signed(4), 2));
+
+			// then it becomes the new head
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1]), (1_000, vec![4, 2, 3])]);
+			assert_eq!(Bag::<Runtime>::get(1_000).unwrap(), Bag::new(Some(4), Some(3), 1_000));
+
+			// when the tail goes in front of a node in the middle
+			StakingMock::set_score_of(&3, 1_000);
+			assert_ok!(BagsList::put_in_front_of(Origin::signed(3), 2));
+
+			// then the previous node becomes the new tail
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1]), (1_000, vec![4, 3, 2])]);
+			assert_eq!(Bag::<Runtime>::get(1_000).unwrap(), Bag::new(Some(4), Some(2), 1_000));
+			assert_eq!(ListNodes::<Runtime>::count(), 4);
+		});
+	}
+
+	#[test]
+	fn put_in_front_of_errors() {
+		ExtBuilder::default().build_and_execute(|| {
+			StakingMock::set_score_of(&1, 10);
+			StakingMock::set_score_of(&2, 1_000);
+			StakingMock::set_score_of(&3, 1_000);
+
+			// the nodes are not in the same bag
+			assert_noop!(
+				BagsList::put_in_front_of(Origin::signed(2), 1),
+				crate::Error::<Runtime>::NotInSameBag
+			);
+
+			// the caller does not have a greater score
+			assert_noop!(
+				BagsList::put_in_front_of(Origin::signed(3), 2),
+				crate::Error::<Runtime>::NotHeavier
+			);
+
+			// either of the ids is not in the list
+			assert_noop!(
+				BagsList::put_in_front_of(Origin::signed(42), 2),
+				crate::Error::<Runtime>::IdNotFound
+			);
+			assert_noop!(
+				BagsList::put_in_front_of(Origin::signed(2), 42),
+				crate::Error::<Runtime>::IdNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn on_idle_rebags_with_cursor() {
+		ExtBuilder::default().build_and_execute(|| {
+			let item_weight =
+				<() as WeightInfo>::rebag_non_terminal().max(<() as WeightInfo>::rebag_terminal());
+
+			// given everyone's score drops to the lowest bag
+			NextVoteWeight::set(10);
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1]), (1_000, vec![2, 3, 4])]);
+
+			// when automatic rebagging is disabled
+			assert_storage_noop!(assert_eq!(BagsList::on_idle(1, Weight::max_value()), 0));
+
+			// when
+			MaxAutoRebagPerBlock::set(2);
+			assert_eq!(BagsList::on_idle(1, Weight::max_value()), 2 * item_weight);
+
+			// then the first two ids are rebagged, and the next one is remembered
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1, 2, 3]), (1_000, vec![4])]);
+			assert_eq!(NextNodeAutoRebagged::<Runtime>::get(), Some(4));
+
+			// when
+			assert_eq!(BagsList::on_idle(2, Weight::max_value()), 2 * item_weight);
+
+			// then the check continues at the remembered id
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1, 2, 3, 4])]);
+			assert_eq!(NextNodeAutoRebagged::<Runtime>::get(), Some(2));
+
+			// when
+			BagsList::on_idle(3, Weight::max_value());
+			BagsList::on_idle(4, Weight::max_value());
+
+			// then the end of the list was reached and the next check starts from the head
+			assert_eq!(NextNodeAutoRebagged::<Runtime>::get(), None);
+		});
+	}
+
+	#[test]
+	fn on_idle_respects_remaining_weight() {
+		ExtBuilder::default().build_and_execute(|| {
+			let item_weight =
+				<() as WeightInfo>::rebag_non_terminal().max(<() as WeightInfo>::rebag_terminal());
+			NextVoteWeight::set(10);
+			MaxAutoRebagPerBlock::set(10);
+
+			// when there is not enough weight for a single id
+			assert_storage_noop!(assert_eq!(BagsList::on_idle(1, item_weight - 1), 0));
+
+			// when there is only enough weight for a single id
+			assert_eq!(BagsList::on_idle(1, item_weight), item_weight);
+
+			// then only that one is rebagged
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1, 2]), (1_000, vec![3, 4])]);
+			assert_eq!(NextNodeAutoRebagged::<Runtime>::get(), Some(3));
+		});
+	}
+
 	#[test]
 	#[should_panic = "thresholds must strictly increase, and have no duplicates"]
 	fn duplicate_in_bags_threshold_panics() {
@@ -271,7 +390,7 @@ mod sorted_list_provider {
 			// then
 			assert_storage_noop!(assert_eq!(
 				BagsList::on_insert(3, 20).unwrap_err(),
-				Error::Duplicate
+				ListError::Duplicate
 			));
 		});
 	}
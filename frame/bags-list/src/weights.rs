@@ -47,6 +47,7 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn rebag_non_terminal() -> Weight;
 	fn rebag_terminal() -> Weight;
+	fn put_in_front_of() -> Weight;
 }
 
 /// Weights for pallet_bags_list using the Substrate node and recommended hardware.
@@ -70,6 +71,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7 as Weight))
 			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
+	// Storage: BagsList ListNodes (r:4 w:4)
+	// Storage: Staking Bonded (r:2 w:0)
+	// Storage: Staking Ledger (r:2 w:0)
+	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	// Storage: BagsList ListBags (r:1 w:1)
+	fn put_in_front_of() -> Weight {
+		(77_852_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -92,4 +103,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 	}
+	// Storage: BagsList ListNodes (r:4 w:4)
+	// Storage: Staking Bonded (r:2 w:0)
+	// Storage: Staking Ledger (r:2 w:0)
+	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	// Storage: BagsList ListBags (r:1 w:1)
+	fn put_in_front_of() -> Weight {
+		(77_852_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
 }
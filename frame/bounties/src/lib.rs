@@ -71,6 +71,11 @@
 //! - `claim_bounty` - Claim a specific bounty amount from the Payout Address.
 //! - `unassign_curator` - Unassign an accepted curator from a specific earmark.
 //! - `close_bounty` - Cancel the earmark for a specific treasury amount and close the bounty.
+//!
+//! A bounty that is active can be split into child bounties by its curator, see
+//! `pallet-child-bounties`. This pallet only learns about them through the `ChildBountyManager`
+//! config item: a bounty can neither be awarded nor closed while it still has active child
+//! bounties, and the curator fees of child bounties are deducted from the parent curator's fee.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -123,6 +128,15 @@ pub struct Bounty<AccountId, Balance, BlockNumber> {
 	status: BountyStatus<AccountId, BlockNumber>,
 }
 
+impl<AccountId: PartialEq + Clone + Ord, Balance, BlockNumber: Clone>
+	Bounty<AccountId, Balance, BlockNumber>
+{
+	/// Getter for bounty status, to be used for child bounties.
+	pub fn get_status(&self) -> BountyStatus<AccountId, BlockNumber> {
+		self.status.clone()
+	}
+}
+
 /// The status of a bounty proposal.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub enum BountyStatus<AccountId, BlockNumber> {
@@ -202,6 +216,9 @@ pub mod pallet {
 
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// The child-bounty manager.
+		type ChildBountyManager: ChildBountyManager<BalanceOf<Self>>;
 	}
 
 	#[pallet::error]
@@ -225,6 +242,8 @@ pub mod pallet {
 		PendingPayout,
 		/// The bounties cannot be claimed/closed because it's still in the countdown period.
 		Premature,
+		/// The bounty cannot be closed because it has active child-bounties.
+		HasActiveChildBounty,
 	}
 
 	#[pallet::event]
@@ -518,6 +537,10 @@ pub mod pallet {
 					},
 					_ => return Err(Error::<T>::UnexpectedStatus.into()),
 				}
+				ensure!(
+					T::ChildBountyManager::child_bounties_count(bounty_id) == 0,
+					Error::<T>::HasActiveChildBounty
+				);
 				bounty.status = BountyStatus::PendingPayout {
 					curator: signer,
 					beneficiary: beneficiary.clone(),
@@ -559,7 +582,10 @@ pub mod pallet {
 					);
 					let bounty_account = Self::bounty_account_id(bounty_id);
 					let balance = T::Currency::free_balance(&bounty_account);
-					let fee = bounty.fee.min(balance); // just to be safe
+					// The curator fees of child-bounties were already paid out of the child-bounty
+					// accounts, so they are deducted from the parent curator's fee.
+					let children_fee = T::ChildBountyManager::children_curator_fees(bounty_id);
+					let fee = bounty.fee.saturating_sub(children_fee).min(balance); // just to be safe
 					let payout = balance.saturating_sub(fee);
 					let err_amount = T::Currency::unreserve(&curator, bounty.curator_deposit);
 					debug_assert!(err_amount.is_zero());
@@ -572,6 +598,7 @@ pub mod pallet {
 					*maybe_bounty = None;
 
 					BountyDescriptions::<T>::remove(bounty_id);
+					T::ChildBountyManager::bounty_removed(bounty_id);
 
 					Self::deposit_event(Event::<T>::BountyClaimed {
 						index: bounty_id,
@@ -609,6 +636,13 @@ pub mod pallet {
 				|maybe_bounty| -> DispatchResultWithPostInfo {
 					let bounty = maybe_bounty.as_ref().ok_or(Error::<T>::InvalidIndex)?;
 
+					// Child-bounties hold part of the bounty funds in their own accounts, so they
+					// have to be closed by the curator or `RejectOrigin` first.
+					ensure!(
+						T::ChildBountyManager::child_bounties_count(bounty_id) == 0,
+						Error::<T>::HasActiveChildBounty
+					);
+
 					match &bounty.status {
 						BountyStatus::Proposed => {
 							// The reject origin would like to cancel a proposed bounty.
@@ -654,6 +688,7 @@ pub mod pallet {
 					let bounty_account = Self::bounty_account_id(bounty_id);
 
 					BountyDescriptions::<T>::remove(bounty_id);
+					T::ChildBountyManager::bounty_removed(bounty_id);
 
 					let balance = T::Currency::free_balance(&bounty_account);
 					let res = T::Currency::transfer(
@@ -813,3 +848,27 @@ impl<T: Config> pallet_treasury::SpendFunds<T> for Pallet<T> {
 		*total_weight += <T as Config>::WeightInfo::spend_funds(bounties_len);
 	}
 }
+
+/// Interface for the child-bounties of a bounty, implemented by `pallet-child-bounties`.
+pub trait ChildBountyManager<Balance> {
+	/// Get the number of active child-bounties of a parent bounty.
+	fn child_bounties_count(bounty_id: BountyIndex) -> BountyIndex;
+
+	/// Get the total curator fees of the child-bounties of a parent bounty.
+	fn children_curator_fees(bounty_id: BountyIndex) -> Balance;
+
+	/// Hook called when a parent bounty is removed.
+	fn bounty_removed(bounty_id: BountyIndex);
+}
+
+impl<Balance: Zero> ChildBountyManager<Balance> for () {
+	fn child_bounties_count(_bounty_id: BountyIndex) -> BountyIndex {
+		Default::default()
+	}
+
+	fn children_curator_fees(_bounty_id: BountyIndex) -> Balance {
+		Zero::zero()
+	}
+
+	fn bounty_removed(_bounty_id: BountyIndex) {}
+}
@@ -34,6 +34,16 @@ fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+// The deposit of a closed proposal is released after it is executed or disapproved.
+fn assert_has_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::Event) {
+	frame_system::Pallet::<T>::assert_has_event(generic_event.into());
+}
+
+/// Give `proposer` enough funds to afford the deposits of all of its proposals.
+fn fund_proposer<T: Config<I>, I: 'static>(proposer: &T::AccountId) {
+	T::Currency::make_free_balance_be(proposer, BalanceOf::<T, I>::max_value() / 2u32.into());
+}
+
 benchmarks_instance_pallet! {
 	set_members {
 		let m in 1 .. T::MaxMembers::get();
@@ -49,6 +59,7 @@ benchmarks_instance_pallet! {
 			old_members.push(last_old_member.clone());
 		}
 		let old_members_count = old_members.len() as u32;
+		fund_proposer::<T, I>(&last_old_member);
 
 		Collective::<T, I>::set_members(
 			SystemOrigin::Root.into(),
@@ -117,6 +128,7 @@ benchmarks_instance_pallet! {
 		}
 
 		let caller: T::AccountId = whitelisted_caller();
+		fund_proposer::<T, I>(&caller);
 		members.push(caller.clone());
 
 		Collective::<T, I>::set_members(SystemOrigin::Root.into(), members, None, T::MaxMembers::get())?;
@@ -147,6 +159,7 @@ benchmarks_instance_pallet! {
 		}
 
 		let caller: T::AccountId = whitelisted_caller();
+		fund_proposer::<T, I>(&caller);
 		members.push(caller.clone());
 
 		Collective::<T, I>::set_members(SystemOrigin::Root.into(), members, None, T::MaxMembers::get())?;
@@ -178,6 +191,7 @@ benchmarks_instance_pallet! {
 			members.push(member);
 		}
 		let caller: T::AccountId = whitelisted_caller();
+		fund_proposer::<T, I>(&caller);
 		members.push(caller.clone());
 		Collective::<T, I>::set_members(SystemOrigin::Root.into(), members, None, T::MaxMembers::get())?;
 
@@ -217,6 +231,7 @@ benchmarks_instance_pallet! {
 		// Construct `members`.
 		let mut members = vec![];
 		let proposer: T::AccountId = account("proposer", 0, SEED);
+		fund_proposer::<T, I>(&proposer);
 		members.push(proposer.clone());
 		for i in 1 .. m - 1 {
 			let member = account("member", i, SEED);
@@ -292,6 +307,7 @@ benchmarks_instance_pallet! {
 		// Construct `members`.
 		let mut members = vec![];
 		let proposer: T::AccountId = account("proposer", 0, SEED);
+		fund_proposer::<T, I>(&proposer);
 		members.push(proposer.clone());
 		for i in 1 .. m - 1 {
 			let member = account("member", i, SEED);
@@ -359,7 +375,7 @@ benchmarks_instance_pallet! {
 	verify {
 		// The last proposal is removed.
 		assert_eq!(Collective::<T, I>::proposals().len(), (p - 1) as usize);
-		assert_last_event::<T, I>(Event::Disapproved { proposal_hash: last_hash }.into());
+		assert_has_event::<T, I>(Event::Disapproved { proposal_hash: last_hash }.into());
 	}
 
 	close_early_approved {
@@ -377,6 +393,7 @@ benchmarks_instance_pallet! {
 			members.push(member);
 		}
 		let caller: T::AccountId = whitelisted_caller();
+		fund_proposer::<T, I>(&caller);
 		members.push(caller.clone());
 		Collective::<T, I>::set_members(SystemOrigin::Root.into(), members.clone(), None, T::MaxMembers::get())?;
 
@@ -440,7 +457,7 @@ benchmarks_instance_pallet! {
 	verify {
 		// The last proposal is removed.
 		assert_eq!(Collective::<T, I>::proposals().len(), (p - 1) as usize);
-		assert_last_event::<T, I>(Event::Executed { proposal_hash: last_hash, result: Err(DispatchError::BadOrigin) }.into());
+		assert_has_event::<T, I>(Event::Executed { proposal_hash: last_hash, result: Err(DispatchError::BadOrigin) }.into());
 	}
 
 	close_disapproved {
@@ -458,6 +475,7 @@ benchmarks_instance_pallet! {
 			members.push(member);
 		}
 		let caller: T::AccountId = whitelisted_caller();
+		fund_proposer::<T, I>(&caller);
 		members.push(caller.clone());
 		Collective::<T, I>::set_members(
 			SystemOrigin::Root.into(),
@@ -514,7 +532,7 @@ benchmarks_instance_pallet! {
 	}: close(SystemOrigin::Signed(caller), last_hash, index, Weight::max_value(), bytes_in_storage)
 	verify {
 		assert_eq!(Collective::<T, I>::proposals().len(), (p - 1) as usize);
-		assert_last_event::<T, I>(Event::Disapproved { proposal_hash: last_hash }.into());
+		assert_has_event::<T, I>(Event::Disapproved { proposal_hash: last_hash }.into());
 	}
 
 	close_approved {
@@ -532,6 +550,7 @@ benchmarks_instance_pallet! {
 			members.push(member);
 		}
 		let caller: T::AccountId = whitelisted_caller();
+		fund_proposer::<T, I>(&caller);
 		members.push(caller.clone());
 		Collective::<T, I>::set_members(
 			SystemOrigin::Root.into(),
@@ -586,7 +605,7 @@ benchmarks_instance_pallet! {
 	}: close(SystemOrigin::Signed(caller), last_hash, p - 1, Weight::max_value(), bytes_in_storage)
 	verify {
 		assert_eq!(Collective::<T, I>::proposals().len(), (p - 1) as usize);
-		assert_last_event::<T, I>(Event::Executed { proposal_hash: last_hash, result: Err(DispatchError::BadOrigin) }.into());
+		assert_has_event::<T, I>(Event::Executed { proposal_hash: last_hash, result: Err(DispatchError::BadOrigin) }.into());
 	}
 
 	disapprove_proposal {
@@ -603,6 +622,7 @@ benchmarks_instance_pallet! {
 			members.push(member);
 		}
 		let caller: T::AccountId = account("caller", 0, SEED);
+		fund_proposer::<T, I>(&caller);
 		members.push(caller.clone());
 		Collective::<T, I>::set_members(
 			SystemOrigin::Root.into(),
@@ -634,7 +654,7 @@ benchmarks_instance_pallet! {
 	}: _(SystemOrigin::Root, last_hash)
 	verify {
 		assert_eq!(Collective::<T, I>::proposals().len(), (p - 1) as usize);
-		assert_last_event::<T, I>(Event::Disapproved { proposal_hash: last_hash }.into());
+		assert_has_event::<T, I>(Event::Disapproved { proposal_hash: last_hash }.into());
 	}
 
 	impl_benchmark_test_suite!(Collective, crate::tests::new_test_ext(), crate::tests::Test);
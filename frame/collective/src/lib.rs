@@ -38,6 +38,11 @@
 //! abstentions and the proposal is executed if there are enough approvals counting the new votes.
 //!
 //! If there are not, or if no prime is set, then the motion is dropped without being executed.
+//!
+//! Proposing a motion may require a deposit, given by `ProposalDeposit` and depending on the
+//! number of motions already active. The deposit is returned to the proposer once the motion is
+//! closed. Motions whose voting period has ended are closed automatically at the start of a block,
+//! as long as the weight spent doing so stays within `MaxAutoCloseWeight`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "128"]
@@ -45,7 +50,10 @@
 use scale_info::TypeInfo;
 use sp_core::u32_trait::Value as U32;
 use sp_io::storage;
-use sp_runtime::{traits::Hash, RuntimeDebug};
+use sp_runtime::{
+	traits::{Hash, Saturating, Zero},
+	RuntimeDebug,
+};
 use sp_std::{marker::PhantomData, prelude::*, result};
 
 use frame_support::{
@@ -53,9 +61,10 @@ use frame_support::{
 	dispatch::{DispatchError, DispatchResultWithPostInfo, Dispatchable, PostDispatchInfo},
 	ensure,
 	traits::{
-		Backing, ChangeMembers, EnsureOrigin, Get, GetBacking, InitializeMembers, StorageVersion,
+		Backing, ChangeMembers, Currency, EnsureOrigin, Get, GetBacking, InitializeMembers,
+		ReservableCurrency, StorageVersion,
 	},
-	weights::{GetDispatchInfo, Weight},
+	weights::{GetDispatchInfo, Pays, Weight},
 };
 
 #[cfg(test)]
@@ -72,12 +81,42 @@ pub use weights::WeightInfo;
 /// Simple index type for proposal counting.
 pub type ProposalIndex = u32;
 
+type BalanceOf<T, I> =
+	<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 /// A number of members.
 ///
 /// This also serves as a number of voting members, and since for motions, each member may
 /// vote exactly once, therefore also the number of votes for any given motion.
 pub type MemberCount = u32;
 
+/// Determines the deposit to reserve for a new proposal.
+pub trait GetDeposit<Balance> {
+	/// Returns the deposit for a new proposal, given the number of proposals that are already
+	/// active.
+	fn get_deposit(proposal_count: u32) -> Balance;
+}
+
+/// No deposit is required for proposals.
+impl<Balance: Zero> GetDeposit<Balance> for () {
+	fn get_deposit(_proposal_count: u32) -> Balance {
+		Zero::zero()
+	}
+}
+
+/// A deposit of `Base`, increased by `Increment` for each proposal that is already active.
+pub struct LinearDeposit<Base, Increment>(PhantomData<(Base, Increment)>);
+impl<Balance, Base, Increment> GetDeposit<Balance> for LinearDeposit<Base, Increment>
+where
+	Balance: Saturating + From<u32>,
+	Base: Get<Balance>,
+	Increment: Get<Balance>,
+{
+	fn get_deposit(proposal_count: u32) -> Balance {
+		Base::get().saturating_add(Increment::get().saturating_mul(proposal_count.into()))
+	}
+}
+
 /// Default voting strategy when a member is inactive.
 pub trait DefaultVote {
 	/// Get the default voting strategy, given:
@@ -204,6 +243,17 @@ pub mod pallet {
 		/// Default vote strategy of this collective.
 		type DefaultVote: DefaultVote;
 
+		/// The currency used to reserve proposal deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved from the proposer of a motion, depending on the number of motions
+		/// that are already active. It is returned when the motion is closed.
+		type ProposalDeposit: GetDeposit<BalanceOf<Self, I>>;
+
+		/// The maximum weight that may be spent in `on_initialize` to close motions whose voting
+		/// period has ended. Motions are not closed automatically if this is zero.
+		type MaxAutoCloseWeight: Get<Weight>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -274,6 +324,12 @@ pub mod pallet {
 	#[pallet::getter(fn prime)]
 	pub type Prime<T: Config<I>, I: 'static = ()> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	/// The proposer and reserved deposit of a given proposal, if it's current.
+	#[pallet::storage]
+	#[pallet::getter(fn deposit_of)]
+	pub type DepositOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, T::Hash, (T::AccountId, BalanceOf<T, I>), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -304,6 +360,8 @@ pub mod pallet {
 		MemberExecuted { proposal_hash: T::Hash, result: DispatchResult },
 		/// A proposal was closed because its threshold was reached or after its duration was up.
 		Closed { proposal_hash: T::Hash, yes: MemberCount, no: MemberCount },
+		/// The deposit of a proposal was returned to its proposer.
+		DepositReleased { proposal_hash: T::Hash, who: T::AccountId, amount: BalanceOf<T, I> },
 	}
 
 	/// Old name generated by `decl_event`.
@@ -332,6 +390,15 @@ pub mod pallet {
 		WrongProposalWeight,
 		/// The given length bound for the proposal was too low.
 		WrongProposalLength,
+		/// The proposer cannot afford the proposal deposit.
+		InsufficientFunds,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			Self::close_expired_proposals(n, T::MaxAutoCloseWeight::get())
+		}
 	}
 
 	// Note that councillor operations are assigned to the operational class.
@@ -514,21 +581,8 @@ pub mod pallet {
 			let members = Self::members();
 			ensure!(members.contains(&who), Error::<T, I>::NotMember);
 
-			let proposal_len = proposal.using_encoded(|x| x.len());
-			ensure!(proposal_len <= length_bound as usize, Error::<T, I>::WrongProposalLength);
-			let proposal_hash = T::Hashing::hash_of(&proposal);
-			ensure!(
-				!<ProposalOf<T, I>>::contains_key(proposal_hash),
-				Error::<T, I>::DuplicateProposal
-			);
-
 			if threshold < 2 {
-				let seats = Self::members().len() as MemberCount;
-				let result = proposal.dispatch(RawOrigin::Members(1, seats).into());
-				Self::deposit_event(Event::Executed {
-					proposal_hash,
-					result: result.map(|_| ()).map_err(|e| e.error),
-				});
+				let (proposal_len, result) = Self::do_propose_execute(proposal, length_bound)?;
 
 				Ok(get_result_weight(result)
 					.map(|w| {
@@ -540,28 +594,8 @@ pub mod pallet {
 					})
 					.into())
 			} else {
-				let active_proposals =
-					<Proposals<T, I>>::try_mutate(|proposals| -> Result<usize, DispatchError> {
-						proposals
-							.try_push(proposal_hash)
-							.map_err(|_| Error::<T, I>::TooManyProposals)?;
-						Ok(proposals.len())
-					})?;
-				let index = Self::proposal_count();
-				<ProposalCount<T, I>>::mutate(|i| *i += 1);
-				<ProposalOf<T, I>>::insert(proposal_hash, *proposal);
-				let votes = {
-					let end = frame_system::Pallet::<T>::block_number() + T::MotionDuration::get();
-					Votes { index, threshold, ayes: vec![], nays: vec![], end }
-				};
-				<Voting<T, I>>::insert(proposal_hash, votes);
-
-				Self::deposit_event(Event::Proposed {
-					account: who,
-					proposal_index: index,
-					proposal_hash,
-					threshold,
-				});
+				let (proposal_len, active_proposals) =
+					Self::do_propose_proposed(who, threshold, proposal, length_bound)?;
 
 				Ok(Some(T::WeightInfo::propose_proposed(
 					proposal_len as u32,     // B
@@ -598,46 +632,8 @@ pub mod pallet {
 			let members = Self::members();
 			ensure!(members.contains(&who), Error::<T, I>::NotMember);
 
-			let mut voting = Self::voting(&proposal).ok_or(Error::<T, I>::ProposalMissing)?;
-			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
-
-			let position_yes = voting.ayes.iter().position(|a| a == &who);
-			let position_no = voting.nays.iter().position(|a| a == &who);
-
 			// Detects first vote of the member in the motion
-			let is_account_voting_first_time = position_yes.is_none() && position_no.is_none();
-
-			if approve {
-				if position_yes.is_none() {
-					voting.ayes.push(who.clone());
-				} else {
-					return Err(Error::<T, I>::DuplicateVote.into())
-				}
-				if let Some(pos) = position_no {
-					voting.nays.swap_remove(pos);
-				}
-			} else {
-				if position_no.is_none() {
-					voting.nays.push(who.clone());
-				} else {
-					return Err(Error::<T, I>::DuplicateVote.into())
-				}
-				if let Some(pos) = position_yes {
-					voting.ayes.swap_remove(pos);
-				}
-			}
-
-			let yes_votes = voting.ayes.len() as MemberCount;
-			let no_votes = voting.nays.len() as MemberCount;
-			Self::deposit_event(Event::Voted {
-				account: who,
-				proposal_hash: proposal,
-				voted: approve,
-				yes: yes_votes,
-				no: no_votes,
-			});
-
-			Voting::<T, I>::insert(&proposal, voting);
+			let is_account_voting_first_time = Self::do_vote(who, proposal, index, approve)?;
 
 			if is_account_voting_first_time {
 				Ok((Some(T::WeightInfo::vote(members.len() as u32)), Pays::No).into())
@@ -701,82 +697,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let _ = ensure_signed(origin)?;
 
-			let voting = Self::voting(&proposal_hash).ok_or(Error::<T, I>::ProposalMissing)?;
-			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
-
-			let mut no_votes = voting.nays.len() as MemberCount;
-			let mut yes_votes = voting.ayes.len() as MemberCount;
-			let seats = Self::members().len() as MemberCount;
-			let approved = yes_votes >= voting.threshold;
-			let disapproved = seats.saturating_sub(no_votes) < voting.threshold;
-			// Allow (dis-)approving the proposal as soon as there are enough votes.
-			if approved {
-				let (proposal, len) = Self::validate_and_get_proposal(
-					&proposal_hash,
-					length_bound,
-					proposal_weight_bound,
-				)?;
-				Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
-				let (proposal_weight, proposal_count) =
-					Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal);
-				return Ok((
-					Some(
-						T::WeightInfo::close_early_approved(len as u32, seats, proposal_count)
-							.saturating_add(proposal_weight),
-					),
-					Pays::Yes,
-				)
-					.into())
-			} else if disapproved {
-				Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
-				let proposal_count = Self::do_disapprove_proposal(proposal_hash);
-				return Ok((
-					Some(T::WeightInfo::close_early_disapproved(seats, proposal_count)),
-					Pays::No,
-				)
-					.into())
-			}
-
-			// Only allow actual closing of the proposal after the voting period has ended.
-			ensure!(
-				frame_system::Pallet::<T>::block_number() >= voting.end,
-				Error::<T, I>::TooEarly
-			);
-
-			let prime_vote = Self::prime().map(|who| voting.ayes.iter().any(|a| a == &who));
-
-			// default voting strategy.
-			let default = T::DefaultVote::default_vote(prime_vote, yes_votes, no_votes, seats);
-
-			let abstentions = seats - (yes_votes + no_votes);
-			match default {
-				true => yes_votes += abstentions,
-				false => no_votes += abstentions,
-			}
-			let approved = yes_votes >= voting.threshold;
-
-			if approved {
-				let (proposal, len) = Self::validate_and_get_proposal(
-					&proposal_hash,
-					length_bound,
-					proposal_weight_bound,
-				)?;
-				Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
-				let (proposal_weight, proposal_count) =
-					Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal);
-				Ok((
-					Some(
-						T::WeightInfo::close_approved(len as u32, seats, proposal_count)
-							.saturating_add(proposal_weight),
-					),
-					Pays::Yes,
-				)
-					.into())
-			} else {
-				Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
-				let proposal_count = Self::do_disapprove_proposal(proposal_hash);
-				Ok((Some(T::WeightInfo::close_disapproved(seats, proposal_count)), Pays::No).into())
-			}
+			Self::do_close(proposal_hash, index, proposal_weight_bound, length_bound)
 		}
 
 		/// Disapprove a proposal, close, and remove it from the system, regardless of its current
@@ -823,6 +744,209 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Self::members().contains(who)
 	}
 
+	/// Execute the proposal immediately, as if it had been approved by a single member.
+	///
+	/// Returns the encoded length of the proposal along with the result of its dispatch.
+	pub fn do_propose_execute(
+		proposal: Box<<T as Config<I>>::Proposal>,
+		length_bound: MemberCount,
+	) -> Result<(u32, DispatchResultWithPostInfo), DispatchError> {
+		let proposal_len = proposal.using_encoded(|x| x.len());
+		ensure!(proposal_len <= length_bound as usize, Error::<T, I>::WrongProposalLength);
+
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+		ensure!(!<ProposalOf<T, I>>::contains_key(proposal_hash), Error::<T, I>::DuplicateProposal);
+
+		let seats = Self::members().len() as MemberCount;
+		let result = proposal.dispatch(RawOrigin::Members(1, seats).into());
+		Self::deposit_event(Event::Executed {
+			proposal_hash,
+			result: result.map(|_| ()).map_err(|e| e.error),
+		});
+		Ok((proposal_len as u32, result))
+	}
+
+	/// Add a new proposal to be voted on.
+	///
+	/// Returns the encoded length of the proposal along with the number of active proposals.
+	pub fn do_propose_proposed(
+		who: T::AccountId,
+		threshold: MemberCount,
+		proposal: Box<<T as Config<I>>::Proposal>,
+		length_bound: MemberCount,
+	) -> Result<(u32, u32), DispatchError> {
+		let proposal_len = proposal.using_encoded(|x| x.len());
+		ensure!(proposal_len <= length_bound as usize, Error::<T, I>::WrongProposalLength);
+
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+		ensure!(!<ProposalOf<T, I>>::contains_key(proposal_hash), Error::<T, I>::DuplicateProposal);
+
+		let active_proposals =
+			<Proposals<T, I>>::try_mutate(|proposals| -> Result<usize, DispatchError> {
+				let deposit = T::ProposalDeposit::get_deposit(proposals.len() as u32);
+				proposals.try_push(proposal_hash).map_err(|_| Error::<T, I>::TooManyProposals)?;
+				if !deposit.is_zero() {
+					T::Currency::reserve(&who, deposit)
+						.map_err(|_| Error::<T, I>::InsufficientFunds)?;
+					<DepositOf<T, I>>::insert(proposal_hash, (who.clone(), deposit));
+				}
+				Ok(proposals.len())
+			})?;
+
+		let index = Self::proposal_count();
+		<ProposalCount<T, I>>::mutate(|i| *i += 1);
+		<ProposalOf<T, I>>::insert(proposal_hash, *proposal);
+		let votes = {
+			let end = frame_system::Pallet::<T>::block_number() + T::MotionDuration::get();
+			Votes { index, threshold, ayes: vec![], nays: vec![], end }
+		};
+		<Voting<T, I>>::insert(proposal_hash, votes);
+
+		Self::deposit_event(Event::Proposed {
+			account: who,
+			proposal_index: index,
+			proposal_hash,
+			threshold,
+		});
+		Ok((proposal_len as u32, active_proposals as u32))
+	}
+
+	/// Add an aye or nay vote for the member to the given proposal.
+	///
+	/// Returns `true` if this is the first vote of the member on the proposal.
+	pub fn do_vote(
+		who: T::AccountId,
+		proposal: T::Hash,
+		index: ProposalIndex,
+		approve: bool,
+	) -> Result<bool, DispatchError> {
+		let mut voting = Self::voting(&proposal).ok_or(Error::<T, I>::ProposalMissing)?;
+		ensure!(voting.index == index, Error::<T, I>::WrongIndex);
+
+		let position_yes = voting.ayes.iter().position(|a| a == &who);
+		let position_no = voting.nays.iter().position(|a| a == &who);
+
+		// Detects first vote of the member in the motion
+		let is_account_voting_first_time = position_yes.is_none() && position_no.is_none();
+
+		if approve {
+			if position_yes.is_none() {
+				voting.ayes.push(who.clone());
+			} else {
+				return Err(Error::<T, I>::DuplicateVote.into())
+			}
+			if let Some(pos) = position_no {
+				voting.nays.swap_remove(pos);
+			}
+		} else {
+			if position_no.is_none() {
+				voting.nays.push(who.clone());
+			} else {
+				return Err(Error::<T, I>::DuplicateVote.into())
+			}
+			if let Some(pos) = position_yes {
+				voting.ayes.swap_remove(pos);
+			}
+		}
+
+		let yes_votes = voting.ayes.len() as MemberCount;
+		let no_votes = voting.nays.len() as MemberCount;
+		Self::deposit_event(Event::Voted {
+			account: who,
+			proposal_hash: proposal,
+			voted: approve,
+			yes: yes_votes,
+			no: no_votes,
+		});
+
+		Voting::<T, I>::insert(&proposal, voting);
+		Ok(is_account_voting_first_time)
+	}
+
+	/// Close a vote that is either approved, disapproved or whose voting period has ended.
+	///
+	/// See [`Pallet::close`] for details on how the outcome is determined.
+	pub fn do_close(
+		proposal_hash: T::Hash,
+		index: ProposalIndex,
+		proposal_weight_bound: Weight,
+		length_bound: u32,
+	) -> DispatchResultWithPostInfo {
+		let voting = Self::voting(&proposal_hash).ok_or(Error::<T, I>::ProposalMissing)?;
+		ensure!(voting.index == index, Error::<T, I>::WrongIndex);
+
+		let mut no_votes = voting.nays.len() as MemberCount;
+		let mut yes_votes = voting.ayes.len() as MemberCount;
+		let seats = Self::members().len() as MemberCount;
+		let approved = yes_votes >= voting.threshold;
+		let disapproved = seats.saturating_sub(no_votes) < voting.threshold;
+		// Allow (dis-)approving the proposal as soon as there are enough votes.
+		if approved {
+			let (proposal, len) = Self::validate_and_get_proposal(
+				&proposal_hash,
+				length_bound,
+				proposal_weight_bound,
+			)?;
+			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
+			let (proposal_weight, proposal_count) =
+				Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal);
+			return Ok((
+				Some(
+					T::WeightInfo::close_early_approved(len as u32, seats, proposal_count)
+						.saturating_add(proposal_weight),
+				),
+				Pays::Yes,
+			)
+				.into())
+		} else if disapproved {
+			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
+			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
+			return Ok((
+				Some(T::WeightInfo::close_early_disapproved(seats, proposal_count)),
+				Pays::No,
+			)
+				.into())
+		}
+
+		// Only allow actual closing of the proposal after the voting period has ended.
+		ensure!(frame_system::Pallet::<T>::block_number() >= voting.end, Error::<T, I>::TooEarly);
+
+		let prime_vote = Self::prime().map(|who| voting.ayes.iter().any(|a| a == &who));
+
+		// default voting strategy.
+		let default = T::DefaultVote::default_vote(prime_vote, yes_votes, no_votes, seats);
+
+		let abstentions = seats - (yes_votes + no_votes);
+		match default {
+			true => yes_votes += abstentions,
+			false => no_votes += abstentions,
+		}
+		let approved = yes_votes >= voting.threshold;
+
+		if approved {
+			let (proposal, len) = Self::validate_and_get_proposal(
+				&proposal_hash,
+				length_bound,
+				proposal_weight_bound,
+			)?;
+			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
+			let (proposal_weight, proposal_count) =
+				Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal);
+			Ok((
+				Some(
+					T::WeightInfo::close_approved(len as u32, seats, proposal_count)
+						.saturating_add(proposal_weight),
+				),
+				Pays::Yes,
+			)
+				.into())
+		} else {
+			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
+			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
+			Ok((Some(T::WeightInfo::close_disapproved(seats, proposal_count)), Pays::No).into())
+		}
+	}
+
 	/// Ensure that the right proposal bounds were passed and get the proposal from storage.
 	///
 	/// Checks the length in storage via `storage::read` which adds an extra `size_of::<u32>() == 4`
@@ -879,23 +1003,93 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		(proposal_weight, proposal_count)
 	}
 
-	fn do_disapprove_proposal(proposal_hash: T::Hash) -> u32 {
+	/// Removes a proposal from the pallet, and deposit the `Disapproved` event.
+	pub fn do_disapprove_proposal(proposal_hash: T::Hash) -> u32 {
 		// disapproved
 		Self::deposit_event(Event::Disapproved { proposal_hash });
 		Self::remove_proposal(proposal_hash)
 	}
 
-	// Removes a proposal from the pallet, cleaning up votes and the vector of proposals.
+	// Removes a proposal from the pallet, cleaning up votes and the vector of proposals, and
+	// returning the deposit of the proposer.
 	fn remove_proposal(proposal_hash: T::Hash) -> u32 {
 		// remove proposal and vote
 		ProposalOf::<T, I>::remove(&proposal_hash);
 		Voting::<T, I>::remove(&proposal_hash);
+		if let Some((who, amount)) = DepositOf::<T, I>::take(&proposal_hash) {
+			let _ = T::Currency::unreserve(&who, amount);
+			Self::deposit_event(Event::DepositReleased { proposal_hash, who, amount });
+		}
 		let num_proposals = Proposals::<T, I>::mutate(|proposals| {
 			proposals.retain(|h| h != &proposal_hash);
 			proposals.len() + 1 // calculate weight based on original length
 		});
 		num_proposals as u32
 	}
+
+	/// Close the motions whose voting period has ended as of block `now`, for as long as the
+	/// worst case weight of closing the next one stays within `max_weight`.
+	///
+	/// Returns the weight consumed.
+	fn close_expired_proposals(now: T::BlockNumber, max_weight: Weight) -> Weight {
+		if max_weight.is_zero() {
+			return 0
+		}
+
+		let proposals = Self::proposals();
+		let seats = Self::members().len() as MemberCount;
+		let proposal_count = proposals.len() as u32;
+		let mut weight = T::DbWeight::get().reads(2);
+		for proposal_hash in proposals.into_iter() {
+			weight = weight.saturating_add(T::DbWeight::get().reads(1));
+			let voting = match Self::voting(&proposal_hash) {
+				Some(voting) if voting.end <= now => voting,
+				_ => continue,
+			};
+			let proposal = match Self::proposal_of(&proposal_hash) {
+				Some(proposal) => proposal,
+				None => {
+					let disapprove_weight = T::WeightInfo::close_disapproved(seats, proposal_count);
+					if weight.saturating_add(disapprove_weight) > max_weight {
+						break
+					}
+					// There is nothing left to execute, so drop the motion rather than finding it
+					// again in every block.
+					Self::do_disapprove_proposal(proposal_hash);
+					weight = weight.saturating_add(disapprove_weight);
+					continue
+				},
+			};
+			let proposal_len = proposal.using_encoded(|x| x.len()) as u32;
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let max_close_weight =
+				T::WeightInfo::close_approved(proposal_len, seats, proposal_count)
+					.saturating_add(proposal_weight)
+					.max(T::WeightInfo::close_disapproved(seats, proposal_count));
+			if weight.saturating_add(max_close_weight) > max_weight {
+				break
+			}
+
+			let close_weight =
+				match Self::do_close(proposal_hash, voting.index, proposal_weight, u32::MAX) {
+					Ok(post_info) => post_info.actual_weight,
+					Err(err) => {
+						log::warn!(
+							target: "runtime::collective",
+							"Failed to close expired motion {:?}, dropping it: {:?}",
+							proposal_hash,
+							err.error,
+						);
+						// A motion that cannot be closed now will not be closable later either,
+						// so drop it instead of retrying it on every block.
+						Self::do_disapprove_proposal(proposal_hash);
+						None
+					},
+				};
+			weight = weight.saturating_add(close_weight.unwrap_or(max_close_weight));
+		}
+		weight
+	}
 }
 
 impl<T: Config<I>, I: 'static> ChangeMembers<T::AccountId> for Pallet<T, I> {
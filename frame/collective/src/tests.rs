@@ -18,7 +18,10 @@
 use super::{Event as CollectiveEvent, *};
 use crate as pallet_collective;
 use frame_support::{
-	assert_noop, assert_ok, parameter_types, traits::GenesisBuild, weights::Pays, Hashable,
+	assert_noop, assert_ok, parameter_types,
+	traits::{GenesisBuild, Hooks},
+	weights::Pays,
+	Hashable,
 };
 use frame_system::{EventRecord, Phase};
 use sp_core::{
@@ -41,6 +44,7 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic
 	{
 		System: frame_system::{Pallet, Call, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Collective: pallet_collective::<Instance1>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
 		CollectiveMajority: pallet_collective::<Instance2>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
 		DefaultCollective: pallet_collective::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
@@ -88,6 +92,9 @@ parameter_types! {
 	pub const MotionDuration: u64 = 3;
 	pub const MaxProposals: u32 = 100;
 	pub const MaxMembers: u32 = 100;
+	pub const ProposalDepositBase: u64 = 2;
+	pub const ProposalDepositIncrement: u64 = 1;
+	pub const MaxAutoCloseWeight: Weight = 1_000_000_000;
 	pub BlockWeights: frame_system::limits::BlockWeights =
 		frame_system::limits::BlockWeights::simple_max(1024);
 }
@@ -109,13 +116,27 @@ impl frame_system::Config for Test {
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
 	type SS58Prefix = ();
 	type OnSetCode = ();
 }
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
 impl Config<Instance1> for Test {
 	type Origin = Origin;
 	type Proposal = Call;
@@ -124,6 +145,9 @@ impl Config<Instance1> for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = PrimeDefaultVote;
+	type Currency = Balances;
+	type ProposalDeposit = ();
+	type MaxAutoCloseWeight = ();
 	type WeightInfo = ();
 }
 impl Config<Instance2> for Test {
@@ -134,6 +158,9 @@ impl Config<Instance2> for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = MoreThanMajorityThenPrimeDefaultVote;
+	type Currency = Balances;
+	type ProposalDeposit = ();
+	type MaxAutoCloseWeight = ();
 	type WeightInfo = ();
 }
 impl mock_democracy::Config for Test {
@@ -148,11 +175,17 @@ impl Config for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = PrimeDefaultVote;
+	type Currency = Balances;
+	type ProposalDeposit = LinearDeposit<ProposalDepositBase, ProposalDepositIncrement>;
+	type MaxAutoCloseWeight = MaxAutoCloseWeight;
 	type WeightInfo = ();
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut ext: sp_io::TestExternalities = GenesisConfig {
+		balances: pallet_balances::GenesisConfig {
+			balances: vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 3)],
+		},
 		collective: pallet_collective::GenesisConfig {
 			members: vec![1, 2, 3],
 			phantom: Default::default(),
@@ -246,6 +279,202 @@ fn close_works() {
 	});
 }
 
+#[test]
+fn proposal_deposit_is_reserved_and_released_on_close() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DefaultCollective::set_members(Origin::root(), vec![1, 2, 3], None, 3));
+
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		let hash = BlakeTwo256::hash_of(&proposal);
+		assert_ok!(DefaultCollective::propose(
+			Origin::signed(1),
+			2,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_eq!(DefaultCollective::deposit_of(hash), Some((1, 2)));
+		assert_eq!(Balances::reserved_balance(1), 2);
+
+		// the deposit increases with the number of active proposals.
+		let other = make_proposal(69);
+		let other_len: u32 = other.using_encoded(|p| p.len() as u32);
+		let other_hash = BlakeTwo256::hash_of(&other);
+		assert_ok!(DefaultCollective::propose(Origin::signed(1), 2, Box::new(other), other_len));
+		assert_eq!(DefaultCollective::deposit_of(other_hash), Some((1, 3)));
+		assert_eq!(Balances::reserved_balance(1), 5);
+
+		assert_ok!(DefaultCollective::vote(Origin::signed(1), hash, 0, true));
+		assert_ok!(DefaultCollective::vote(Origin::signed(2), hash, 0, true));
+		assert_ok!(DefaultCollective::close(
+			Origin::signed(4),
+			hash,
+			0,
+			proposal_weight,
+			proposal_len
+		));
+		assert_eq!(DefaultCollective::deposit_of(hash), None);
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_eq!(
+			System::events().pop().map(|r| r.event),
+			Some(Event::DefaultCollective(CollectiveEvent::DepositReleased {
+				proposal_hash: hash,
+				who: 1,
+				amount: 2,
+			}))
+		);
+
+		// a disapproved proposal returns the deposit as well.
+		assert_ok!(DefaultCollective::disapprove_proposal(Origin::root(), other_hash));
+		assert_eq!(DefaultCollective::deposit_of(other_hash), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn proposal_deposit_must_be_affordable() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DefaultCollective::set_members(Origin::root(), vec![1, 5], None, 2));
+
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		assert_ok!(DefaultCollective::propose(
+			Origin::signed(5),
+			2,
+			Box::new(proposal),
+			proposal_len
+		));
+		assert_eq!(Balances::reserved_balance(5), 2);
+
+		let proposal = make_proposal(69);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		assert_noop!(
+			DefaultCollective::propose(Origin::signed(5), 2, Box::new(proposal), proposal_len),
+			Error::<Test>::InsufficientFunds
+		);
+		assert_eq!(DefaultCollective::proposals().len(), 1);
+	});
+}
+
+#[test]
+fn expired_motions_are_closed_on_initialize() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DefaultCollective::set_members(Origin::root(), vec![1, 2, 3], None, 3));
+
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let hash = BlakeTwo256::hash_of(&proposal);
+		assert_ok!(DefaultCollective::propose(
+			Origin::signed(1),
+			3,
+			Box::new(proposal),
+			proposal_len
+		));
+		assert_ok!(DefaultCollective::vote(Origin::signed(1), hash, 0, true));
+
+		// voting is still ongoing.
+		DefaultCollective::on_initialize(3);
+		assert_eq!(*DefaultCollective::proposals(), vec![hash]);
+
+		System::set_block_number(4);
+		DefaultCollective::on_initialize(4);
+		assert_eq!(*DefaultCollective::proposals(), Vec::<H256>::new());
+		assert_eq!(DefaultCollective::proposal_of(hash), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(
+			System::events().into_iter().map(|r| r.event).rev().take(4).collect::<Vec<_>>(),
+			vec![
+				Event::DefaultCollective(CollectiveEvent::DepositReleased {
+					proposal_hash: hash,
+					who: 1,
+					amount: 2,
+				}),
+				Event::Balances(pallet_balances::Event::Unreserved { who: 1, amount: 2 }),
+				Event::DefaultCollective(CollectiveEvent::Disapproved { proposal_hash: hash }),
+				Event::DefaultCollective(CollectiveEvent::Closed {
+					proposal_hash: hash,
+					yes: 1,
+					no: 2
+				}),
+			]
+		);
+	});
+}
+
+#[test]
+fn expired_motions_that_cannot_be_closed_are_dropped() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DefaultCollective::set_members(Origin::root(), vec![1, 2, 3], None, 3));
+
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let hash = BlakeTwo256::hash_of(&proposal);
+		assert_ok!(DefaultCollective::propose(
+			Origin::signed(1),
+			3,
+			Box::new(proposal),
+			proposal_len
+		));
+		assert_eq!(Balances::reserved_balance(1), 2);
+
+		// the motion can no longer be executed.
+		ProposalOf::<Test>::remove(hash);
+
+		System::set_block_number(4);
+		DefaultCollective::on_initialize(4);
+		assert_eq!(*DefaultCollective::proposals(), Vec::<H256>::new());
+		assert_eq!(DefaultCollective::voting(hash), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_last_event(Event::DefaultCollective(CollectiveEvent::DepositReleased {
+			proposal_hash: hash,
+			who: 1,
+			amount: 2,
+		}));
+	});
+}
+
+#[test]
+fn auto_close_respects_weight_limit() {
+	new_test_ext().execute_with(|| {
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let hash = BlakeTwo256::hash_of(&proposal);
+		assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal), proposal_len));
+
+		System::set_block_number(4);
+		// auto-closing is disabled for this instance.
+		assert_eq!(Collective::on_initialize(4), 0);
+		assert_eq!(*Collective::proposals(), vec![hash]);
+
+		// not enough weight to close the motion.
+		Collective::close_expired_proposals(4, 1);
+		assert_eq!(*Collective::proposals(), vec![hash]);
+
+		Collective::close_expired_proposals(4, Weight::max_value());
+		assert_eq!(*Collective::proposals(), Vec::<H256>::new());
+	});
+}
+
+#[test]
+fn dropping_unexecutable_motions_respects_weight_limit() {
+	new_test_ext().execute_with(|| {
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let hash = BlakeTwo256::hash_of(&proposal);
+		assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal), proposal_len));
+		ProposalOf::<Test, Instance1>::remove(hash);
+
+		System::set_block_number(4);
+		// not enough weight to drop the motion.
+		Collective::close_expired_proposals(4, 1);
+		assert_eq!(*Collective::proposals(), vec![hash]);
+
+		Collective::close_expired_proposals(4, Weight::max_value());
+		assert_eq!(*Collective::proposals(), Vec::<H256>::new());
+	});
+}
+
 #[test]
 fn proposal_weight_limit_works_on_approve() {
 	new_test_ext().execute_with(|| {
@@ -101,6 +101,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Instance1Collective Proposals (r:1 w:1)
 	// Storage: Instance1Collective ProposalCount (r:1 w:1)
 	// Storage: Instance1Collective Voting (r:0 w:1)
+	// Storage: Instance1Collective DepositOf (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight {
 		(39_869_000 as Weight)
 			// Standard Error: 0
@@ -109,8 +111,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((107_000 as Weight).saturating_mul(m as Weight))
 			// Standard Error: 1_000
 			.saturating_add((406_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
 	// Storage: Instance1Collective Members (r:1 w:0)
 	// Storage: Instance1Collective Voting (r:1 w:1)
@@ -125,19 +127,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Instance1Collective Members (r:1 w:0)
 	// Storage: Instance1Collective Proposals (r:1 w:1)
 	// Storage: Instance1Collective ProposalOf (r:0 w:1)
+	// Storage: Instance1Collective DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
 		(45_670_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((170_000 as Weight).saturating_mul(m as Weight))
 			// Standard Error: 1_000
 			.saturating_add((358_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
 	// Storage: Instance1Collective Voting (r:1 w:1)
 	// Storage: Instance1Collective Members (r:1 w:0)
 	// Storage: Instance1Collective ProposalOf (r:1 w:1)
 	// Storage: Instance1Collective Proposals (r:1 w:1)
+	// Storage: Instance1Collective DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
 		(52_529_000 as Weight)
 			// Standard Error: 0
@@ -146,28 +152,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((206_000 as Weight).saturating_mul(m as Weight))
 			// Standard Error: 1_000
 			.saturating_add((412_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
 	// Storage: Instance1Collective Voting (r:1 w:1)
 	// Storage: Instance1Collective Members (r:1 w:0)
 	// Storage: Instance1Collective Prime (r:1 w:0)
 	// Storage: Instance1Collective Proposals (r:1 w:1)
 	// Storage: Instance1Collective ProposalOf (r:0 w:1)
+	// Storage: Instance1Collective DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn close_disapproved(m: u32, p: u32, ) -> Weight {
 		(50_427_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((170_000 as Weight).saturating_mul(m as Weight))
 			// Standard Error: 1_000
 			.saturating_add((354_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
 	// Storage: Instance1Collective Voting (r:1 w:1)
 	// Storage: Instance1Collective Members (r:1 w:0)
 	// Storage: Instance1Collective Prime (r:1 w:0)
 	// Storage: Instance1Collective ProposalOf (r:1 w:1)
 	// Storage: Instance1Collective Proposals (r:1 w:1)
+	// Storage: Instance1Collective DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
 		(57_031_000 as Weight)
 			// Standard Error: 0
@@ -176,18 +186,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((208_000 as Weight).saturating_mul(m as Weight))
 			// Standard Error: 1_000
 			.saturating_add((408_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
 	// Storage: Instance1Collective Proposals (r:1 w:1)
 	// Storage: Instance1Collective Voting (r:0 w:1)
 	// Storage: Instance1Collective ProposalOf (r:0 w:1)
+	// Storage: Instance1Collective DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn disapprove_proposal(p: u32, ) -> Weight {
 		(27_458_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((402_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
 }
 
@@ -234,6 +246,8 @@ impl WeightInfo for () {
 	// Storage: Instance1Collective Proposals (r:1 w:1)
 	// Storage: Instance1Collective ProposalCount (r:1 w:1)
 	// Storage: Instance1Collective Voting (r:0 w:1)
+	// Storage: Instance1Collective DepositOf (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight {
 		(39_869_000 as Weight)
 			// Standard Error: 0
@@ -242,8 +256,8 @@ impl WeightInfo for () {
 			.saturating_add((107_000 as Weight).saturating_mul(m as Weight))
 			// Standard Error: 1_000
 			.saturating_add((406_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
 	}
 	// Storage: Instance1Collective Members (r:1 w:0)
 	// Storage: Instance1Collective Voting (r:1 w:1)
@@ -258,19 +272,23 @@ impl WeightInfo for () {
 	// Storage: Instance1Collective Members (r:1 w:0)
 	// Storage: Instance1Collective Proposals (r:1 w:1)
 	// Storage: Instance1Collective ProposalOf (r:0 w:1)
+	// Storage: Instance1Collective DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
 		(45_670_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((170_000 as Weight).saturating_mul(m as Weight))
 			// Standard Error: 1_000
 			.saturating_add((358_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 	}
 	// Storage: Instance1Collective Voting (r:1 w:1)
 	// Storage: Instance1Collective Members (r:1 w:0)
 	// Storage: Instance1Collective ProposalOf (r:1 w:1)
 	// Storage: Instance1Collective Proposals (r:1 w:1)
+	// Storage: Instance1Collective DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
 		(52_529_000 as Weight)
 			// Standard Error: 0
@@ -279,28 +297,32 @@ impl WeightInfo for () {
 			.saturating_add((206_000 as Weight).saturating_mul(m as Weight))
 			// Standard Error: 1_000
 			.saturating_add((412_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 	}
 	// Storage: Instance1Collective Voting (r:1 w:1)
 	// Storage: Instance1Collective Members (r:1 w:0)
 	// Storage: Instance1Collective Prime (r:1 w:0)
 	// Storage: Instance1Collective Proposals (r:1 w:1)
 	// Storage: Instance1Collective ProposalOf (r:0 w:1)
+	// Storage: Instance1Collective DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn close_disapproved(m: u32, p: u32, ) -> Weight {
 		(50_427_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((170_000 as Weight).saturating_mul(m as Weight))
 			// Standard Error: 1_000
 			.saturating_add((354_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 	}
 	// Storage: Instance1Collective Voting (r:1 w:1)
 	// Storage: Instance1Collective Members (r:1 w:0)
 	// Storage: Instance1Collective Prime (r:1 w:0)
 	// Storage: Instance1Collective ProposalOf (r:1 w:1)
 	// Storage: Instance1Collective Proposals (r:1 w:1)
+	// Storage: Instance1Collective DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
 		(57_031_000 as Weight)
 			// Standard Error: 0
@@ -309,17 +331,19 @@ impl WeightInfo for () {
 			.saturating_add((208_000 as Weight).saturating_mul(m as Weight))
 			// Standard Error: 1_000
 			.saturating_add((408_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 	}
 	// Storage: Instance1Collective Proposals (r:1 w:1)
 	// Storage: Instance1Collective Voting (r:0 w:1)
 	// Storage: Instance1Collective ProposalOf (r:0 w:1)
+	// Storage: Instance1Collective DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn disapprove_proposal(p: u32, ) -> Weight {
 		(27_458_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((402_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 	}
 }
@@ -177,3 +177,19 @@ fn split_vote_delegation_should_be_ignored() {
 		assert_eq!(tally(r), Tally { ayes: 1, nays: 0, turnout: 10 });
 	});
 }
+
+#[test]
+fn split_abstain_vote_delegation_should_be_ignored() {
+	// If transactor voted, delegated vote is overwritten.
+	new_test_ext().execute_with(|| {
+		let r = begin_referendum();
+		assert_ok!(Democracy::delegate(Origin::signed(2), 1, Conviction::Locked6x, 20));
+		assert_ok!(Democracy::vote(
+			Origin::signed(1),
+			r,
+			AccountVote::SplitAbstain { aye: 0, nay: 0, abstain: 10 }
+		));
+		// Delegated vote is ignored, the abstention only counts towards the turnout.
+		assert_eq!(tally(r), Tally { ayes: 0, nays: 0, turnout: 10 });
+	});
+}
@@ -56,6 +56,50 @@ fn split_vote_cancellation_should_work() {
 	});
 }
 
+#[test]
+fn split_abstain_voting_should_work() {
+	new_test_ext().execute_with(|| {
+		let r = begin_referendum();
+		let v = AccountVote::SplitAbstain { aye: 20, nay: 20, abstain: 20 };
+		assert_noop!(Democracy::vote(Origin::signed(5), r, v), Error::<Test>::InsufficientFunds);
+		let v = AccountVote::SplitAbstain { aye: 10, nay: 20, abstain: 20 };
+		assert_ok!(Democracy::vote(Origin::signed(5), r, v));
+
+		// abstentions only count towards the turnout.
+		assert_eq!(tally(r), Tally { ayes: 1, nays: 2, turnout: 50 });
+		assert_eq!(Balances::locks(5)[0].amount, 50);
+	});
+}
+
+#[test]
+fn split_abstain_vote_cancellation_should_work() {
+	new_test_ext().execute_with(|| {
+		let r = begin_referendum();
+		let v = AccountVote::SplitAbstain { aye: 10, nay: 20, abstain: 20 };
+		assert_ok!(Democracy::vote(Origin::signed(5), r, v));
+		assert_ok!(Democracy::remove_vote(Origin::signed(5), r));
+		assert_eq!(tally(r), Tally { ayes: 0, nays: 0, turnout: 0 });
+		assert_ok!(Democracy::unlock(Origin::signed(5), 5));
+		assert_eq!(Balances::locks(5), vec![]);
+	});
+}
+
+#[test]
+fn split_abstain_vote_is_unlocked_after_referendum() {
+	new_test_ext().execute_with(|| {
+		let r = begin_referendum();
+		let v = AccountVote::SplitAbstain { aye: 10, nay: 0, abstain: 20 };
+		assert_ok!(Democracy::vote(Origin::signed(5), r, v));
+		fast_forward_to(5);
+		assert!(Democracy::referendum_status(r).is_err());
+
+		// a split vote carries no conviction, so anyone can remove it and unlock right away.
+		assert_ok!(Democracy::remove_other_vote(Origin::signed(1), 5, r));
+		assert_ok!(Democracy::unlock(Origin::signed(5), 5));
+		assert_eq!(Balances::locks(5), vec![]);
+	});
+}
+
 #[test]
 fn single_proposal_should_work() {
 	new_test_ext().execute_with(|| {
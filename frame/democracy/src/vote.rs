@@ -73,6 +73,12 @@ pub enum AccountVote<Balance> {
 	/// A split vote with balances given for both ways, and with no conviction, useful for
 	/// parachains when voting.
 	Split { aye: Balance, nay: Balance },
+	/// A split vote with balances given for both ways as well as abstentions, and with no
+	/// conviction, useful for parachains when voting, other than for abstention.
+	///
+	/// The abstained balance counts towards the turnout of the referendum, but neither towards
+	/// its approval nor its rejection.
+	SplitAbstain { aye: Balance, nay: Balance, abstain: Balance },
 }
 
 impl<Balance: Saturating> AccountVote<Balance> {
@@ -92,11 +98,13 @@ impl<Balance: Saturating> AccountVote<Balance> {
 		match self {
 			AccountVote::Standard { balance, .. } => balance,
 			AccountVote::Split { aye, nay } => aye.saturating_add(nay),
+			AccountVote::SplitAbstain { aye, nay, abstain } =>
+				aye.saturating_add(nay).saturating_add(abstain),
 		}
 	}
 
 	/// Returns `Some` with whether the vote is an aye vote if it is standard, otherwise `None` if
-	/// it is split.
+	/// it is split (with or without abstention).
 	pub fn as_standard(self) -> Option<bool> {
 		match self {
 			AccountVote::Standard { vote, .. } => Some(vote.aye),
@@ -18,13 +18,13 @@
 //! Two phase election pallet benchmarking.
 
 use super::*;
-use crate::{unsigned::IndexAssignmentOf, Pallet as MultiPhase};
+use crate::{unsigned::IndexAssignmentOf, Miner, Pallet as MultiPhase};
 use frame_benchmarking::account;
 use frame_support::{assert_ok, traits::Hooks};
 use frame_system::RawOrigin;
 use rand::{prelude::SliceRandom, rngs::SmallRng, SeedableRng};
 use sp_arithmetic::{per_things::Percent, traits::One};
-use sp_npos_elections::IndexAssignment;
+use sp_npos_elections::{ExtendedBalance, IndexAssignment, Support};
 use sp_runtime::InnerOf;
 
 const SEED: u32 = 999;
@@ -36,15 +36,15 @@ fn solution_with_size<T: Config>(
 	size: SolutionOrSnapshotSize,
 	active_voters_count: u32,
 	desired_targets: u32,
-) -> Result<RawSolution<SolutionOf<T>>, &'static str> {
+) -> Result<RawSolution<SolutionOf<T::MinerConfig>>, &'static str> {
 	ensure!(size.targets >= desired_targets, "must have enough targets");
 	ensure!(
-		size.targets >= (<SolutionOf<T>>::LIMIT * 2) as u32,
+		size.targets >= (<SolutionOf<T::MinerConfig>>::LIMIT * 2) as u32,
 		"must have enough targets for unique votes."
 	);
 	ensure!(size.voters >= active_voters_count, "must have enough voters");
 	ensure!(
-		(<SolutionOf<T>>::LIMIT as u32) < desired_targets,
+		(<SolutionOf<T::MinerConfig>>::LIMIT as u32) < desired_targets,
 		"must have enough winners to give them votes."
 	);
 
@@ -71,7 +71,7 @@ fn solution_with_size<T: Config>(
 			// chose a random subset of winners.
 			let winner_votes = winners
 				.as_slice()
-				.choose_multiple(&mut rng, <SolutionOf<T>>::LIMIT)
+				.choose_multiple(&mut rng, <SolutionOf<T::MinerConfig>>::LIMIT)
 				.cloned()
 				.collect::<Vec<_>>();
 			let voter = frame_benchmarking::account::<T::AccountId>("Voter", i, SEED);
@@ -88,7 +88,7 @@ fn solution_with_size<T: Config>(
 	let rest_voters = (active_voters_count..size.voters)
 		.map(|i| {
 			let votes = (&non_winners)
-				.choose_multiple(&mut rng, <SolutionOf<T>>::LIMIT)
+				.choose_multiple(&mut rng, <SolutionOf<T::MinerConfig>>::LIMIT)
 				.cloned()
 				.collect::<Vec<T::AccountId>>();
 			let voter = frame_benchmarking::account::<T::AccountId>("Voter", i, SEED);
@@ -109,36 +109,45 @@ fn solution_with_size<T: Config>(
 		targets: targets.len() as u32,
 	});
 	<DesiredTargets<T>>::put(desired_targets);
-	<Snapshot<T>>::put(RoundSnapshot { voters: all_voters.clone(), targets: targets.clone() });
+	<MultiPhase<T>>::put_snapshot(RoundSnapshot {
+		voters: all_voters.clone(),
+		targets: targets.clone(),
+	});
 
 	// write the snapshot to staking or whoever is the data provider, in case it is needed further
 	// down the road.
 	T::DataProvider::put_snapshot(all_voters.clone(), targets.clone(), Some(stake));
 
-	let cache = helpers::generate_voter_cache::<T>(&all_voters);
-	let stake_of = helpers::stake_of_fn::<T>(&all_voters, &cache);
-	let voter_index = helpers::voter_index_fn::<T>(&cache);
-	let target_index = helpers::target_index_fn::<T>(&targets);
-	let voter_at = helpers::voter_at_fn::<T>(&all_voters);
-	let target_at = helpers::target_at_fn::<T>(&targets);
+	let cache = helpers::generate_voter_cache::<T::MinerConfig>(&all_voters);
+	let stake_of = helpers::stake_of_fn::<T::MinerConfig>(&all_voters, &cache);
+	let voter_index = helpers::voter_index_fn::<T::MinerConfig>(&cache);
+	let target_index = helpers::target_index_fn::<T::MinerConfig>(&targets);
+	let voter_at = helpers::voter_at_fn::<T::MinerConfig>(&all_voters);
+	let target_at = helpers::target_at_fn::<T::MinerConfig>(&targets);
 
 	let assignments = active_voters
 		.iter()
 		.map(|(voter, _stake, votes)| {
-			let percent_per_edge: InnerOf<SolutionAccuracyOf<T>> =
+			let percent_per_edge: InnerOf<SolutionAccuracyOf<T::MinerConfig>> =
 				(100 / votes.len()).try_into().unwrap_or_else(|_| panic!("failed to convert"));
-			crate::unsigned::Assignment::<T> {
+			crate::unsigned::Assignment::<T::MinerConfig> {
 				who: voter.clone(),
 				distribution: votes
 					.iter()
-					.map(|t| (t.clone(), <SolutionAccuracyOf<T>>::from_percent(percent_per_edge)))
+					.map(|t| {
+						(
+							t.clone(),
+							<SolutionAccuracyOf<T::MinerConfig>>::from_percent(percent_per_edge),
+						)
+					})
 					.collect::<Vec<_>>(),
 			}
 		})
 		.collect::<Vec<_>>();
 
 	let solution =
-		<SolutionOf<T>>::from_assignment(&assignments, &voter_index, &target_index).unwrap();
+		<SolutionOf<T::MinerConfig>>::from_assignment(&assignments, &voter_index, &target_index)
+			.unwrap();
 	let score = solution.clone().score(stake_of, voter_at, target_at).unwrap();
 	let round = <MultiPhase<T>>::round();
 
@@ -251,9 +260,10 @@ frame_benchmarking::benchmarks! {
 		let desired_targets = T::DataProvider::desired_targets()?;
 		assert!(<MultiPhase<T>>::snapshot().is_none());
 	}: {
-		<MultiPhase::<T>>::create_snapshot_internal(targets, voters, desired_targets)
+		<MultiPhase::<T>>::create_snapshot_internal(0, voters, Some((targets, desired_targets)))
 	} verify {
-		assert!(<MultiPhase<T>>::snapshot().is_some());
+		assert!(<PagedVoterSnapshot<T>>::get(0).is_some());
+		assert!(<TargetSnapshot<T>>::get().is_some());
 		assert_eq!(<MultiPhase<T>>::snapshot_metadata().ok_or("metadata missing")?.voters, v + t);
 		assert_eq!(<MultiPhase<T>>::snapshot_metadata().ok_or("metadata missing")?.targets, t);
 	}
@@ -282,14 +292,14 @@ frame_benchmarking::benchmarks! {
 
 		// these are set by the `solution_with_size` function.
 		assert!(<DesiredTargets<T>>::get().is_some());
-		assert!(<Snapshot<T>>::get().is_some());
+		assert!(<MultiPhase<T>>::snapshot().is_some());
 		assert!(<SnapshotMetadata<T>>::get().is_some());
 	}: {
 		assert_ok!(<MultiPhase<T> as ElectionProvider<T::AccountId, T::BlockNumber>>::elect());
 	} verify {
 		assert!(<MultiPhase<T>>::queued_solution().is_none());
 		assert!(<DesiredTargets<T>>::get().is_none());
-		assert!(<Snapshot<T>>::get().is_none());
+		assert!(<MultiPhase<T>>::snapshot().is_none());
 		assert!(<SnapshotMetadata<T>>::get().is_none());
 		assert_eq!(<CurrentPhase<T>>::get(), <Phase<T::BlockNumber>>::Off);
 	}
@@ -327,6 +337,26 @@ frame_benchmarking::benchmarks! {
 		assert!(<MultiPhase<T>>::signed_submissions().len() as u32 == c + 1);
 	}
 
+	register_paged {
+		<MultiPhase<T>>::create_snapshot().map_err(<&str>::from)?;
+		MultiPhase::<T>::on_initialize_open_signed();
+		<Round<T>>::put(1);
+		<MinimumUntrustedScore<T>>::put([1, 0, 0]);
+
+		// all but one of the paged submissions are taken.
+		for i in 0..(T::SignedMaxSubmissions::get() - 1) {
+			let submitter: T::AccountId = account("submitter", i, SEED);
+			<PagedSignedSubmissions<T>>::insert(&submitter, PagedSignedSubmissionOf::<T>::default());
+		}
+
+		let caller: T::AccountId = frame_benchmarking::whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller,  T::Currency::minimum_balance() * 10u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), [10_000_000, 0, 0])
+	verify {
+		assert!(<PagedSignedSubmissions<T>>::contains_key(&caller));
+		assert_eq!(<PagedSignedSubmissions<T>>::count(), T::SignedMaxSubmissions::get());
+	}
+
 	submit_unsigned {
 		// number of votes in snapshot.
 		let v in (T::BenchmarkingConfig::VOTERS[0]) .. T::BenchmarkingConfig::VOTERS[1];
@@ -372,6 +402,37 @@ frame_benchmarking::benchmarks! {
 		assert_ok!(<MultiPhase<T>>::feasibility_check(raw_solution, ElectionCompute::Unsigned));
 	}
 
+	// This is aggregating a feasible paged solution, which is the worse case.
+	aggregate_paged_supports {
+		// number of voters backing the winners, across all pages.
+		let v in (T::BenchmarkingConfig::ACTIVE_VOTERS[0]) .. T::BenchmarkingConfig::ACTIVE_VOTERS[1];
+		// number of winners, which are spread over all pages.
+		let d in (T::BenchmarkingConfig::DESIRED_TARGETS[0]) .. T::BenchmarkingConfig::DESIRED_TARGETS[1];
+
+		let pages = T::Pages::get();
+		let mut paged_supports: Vec<(PageIndex, Supports<T::AccountId>)> =
+			(0..pages).map(|page| (page, Vec::new())).collect();
+		// winner `i` is backed by every `d`-th voter, in page `i % pages`.
+		for i in 0..d {
+			let voters = (i..v)
+				.step_by(d as usize)
+				.map(|j| (account::<T::AccountId>("voter", j, SEED), 1_000 as ExtendedBalance))
+				.collect::<Vec<_>>();
+			let total = voters.iter().map(|(_, stake)| stake).sum();
+			paged_supports[(i % pages) as usize]
+				.1
+				.push((account("winner", i, SEED), Support { total, voters }));
+		}
+		let score = paged_supports
+			.iter()
+			.flat_map(|(_, supports)| supports.clone())
+			.collect::<Supports<T::AccountId>>()
+			.evaluate();
+		<DesiredTargets<T>>::put(d);
+	}: {
+		assert_ok!(<MultiPhase<T>>::aggregate_paged_supports(paged_supports, score));
+	}
+
 	// NOTE: this weight is not used anywhere, but the fact that it should succeed when execution in
 	// isolation is vital to ensure memory-safety. For the same reason, we don't care about the
 	// components iterating, we merely check that this operation will work with the "maximum"
@@ -445,17 +506,17 @@ frame_benchmarking::benchmarks! {
 		let witness = SolutionOrSnapshotSize { voters: v, targets: t };
 		let RawSolution { solution, .. } = solution_with_size::<T>(witness, a, d)?;
 		let RoundSnapshot { voters, targets } = MultiPhase::<T>::snapshot().ok_or("snapshot missing")?;
-		let voter_at = helpers::voter_at_fn::<T>(&voters);
-		let target_at = helpers::target_at_fn::<T>(&targets);
+		let voter_at = helpers::voter_at_fn::<T::MinerConfig>(&voters);
+		let target_at = helpers::target_at_fn::<T::MinerConfig>(&targets);
 		let mut assignments = solution.into_assignment(voter_at, target_at).expect("solution generated by `solution_with_size` must be valid.");
 
 		// make a voter cache and some helper functions for access
-		let cache = helpers::generate_voter_cache::<T>(&voters);
-		let voter_index = helpers::voter_index_fn::<T>(&cache);
-		let target_index = helpers::target_index_fn::<T>(&targets);
+		let cache = helpers::generate_voter_cache::<T::MinerConfig>(&voters);
+		let voter_index = helpers::voter_index_fn::<T::MinerConfig>(&cache);
+		let target_index = helpers::target_index_fn::<T::MinerConfig>(&targets);
 
 		// sort assignments by decreasing voter stake
-		assignments.sort_by_key(|crate::unsigned::Assignment::<T> { who, .. }| {
+		assignments.sort_by_key(|crate::unsigned::Assignment::<T::MinerConfig> { who, .. }| {
 			let stake = cache.get(&who).map(|idx| {
 				let (_, stake, _) = voters[*idx];
 				stake
@@ -469,21 +530,21 @@ frame_benchmarking::benchmarks! {
 			.collect::<Result<Vec<_>, _>>()
 			.unwrap();
 
-		let encoded_size_of = |assignments: &[IndexAssignmentOf<T>]| {
-			SolutionOf::<T>::try_from(assignments).map(|solution| solution.encoded_size())
+		let encoded_size_of = |assignments: &[IndexAssignmentOf<T::MinerConfig>]| {
+			SolutionOf::<T::MinerConfig>::try_from(assignments).map(|solution| solution.encoded_size())
 		};
 
 		let desired_size = Percent::from_percent(100 - f.saturated_into::<u8>())
 			.mul_ceil(encoded_size_of(index_assignments.as_slice()).unwrap());
 		log!(trace, "desired_size = {}", desired_size);
 	}: {
-		MultiPhase::<T>::trim_assignments_length(
+		Miner::<T::MinerConfig>::trim_assignments_length(
 			desired_size.saturated_into(),
 			&mut index_assignments,
 			&encoded_size_of,
 		).unwrap();
 	} verify {
-		let solution = SolutionOf::<T>::try_from(index_assignments.as_slice()).unwrap();
+		let solution = SolutionOf::<T::MinerConfig>::try_from(index_assignments.as_slice()).unwrap();
 		let encoding = solution.encode();
 		log!(
 			trace,
@@ -46,6 +46,24 @@
 //! have length zero, then the pallet essentially runs only the fallback strategy, denoted by
 //! [`Config::Fallback`].
 //!
+//! ### Paged Snapshot
+//!
+//! The snapshot is split into [`pallet::Config::Pages`] pages of voters, each of at most
+//! [`pallet::Config::VoterSnapshotPerBlock`] voters. If there is more than one page, the snapshot
+//! is taken over multiple blocks in [`Phase::Snapshot`], which starts `Pages - 1` blocks before the
+//! signed phase: the targets and the first page of voters are taken in the first block, and one
+//! more page of voters is taken in every block thereafter, the last one being taken as the signed
+//! phase opens. The voters of each page are requested from
+//! [`frame_election_provider_support::ElectionDataProvider::voters_paged`], thus their order is
+//! determined by the data provider (e.g. the bags-list iteration order in staking).
+//!
+//! If taking a page fails, the partial snapshot is discarded and the entire snapshot is taken in a
+//! single block at the start of the next phase, as it is done when [`pallet::Config::Pages`] is
+//! `1`.
+//!
+//! The voter pages concatenated in order make up [`RoundSnapshot::voters`], and the index of a
+//! voter in a (non-paged) [`RawSolution`] is its index in this concatenation.
+//!
 //! ### Signed Phase
 //!
 //! In the signed phase, solutions (of type [`RawSolution`]) are submitted and queued on chain. A
@@ -93,6 +111,23 @@
 //! Note that both of the bottom solutions end up being discarded and get their deposit back,
 //! despite one of them being *invalid*.
 //!
+//! #### Paged Signed Submissions
+//!
+//! A solution that is too big to be submitted in one transaction can also be submitted in pages,
+//! one per page of the voter snapshot. The submitter first registers their claimed score with
+//! [`Pallet::register_paged`], reserving [`pallet::Config::SignedDepositBase`], then submits each
+//! page via [`Pallet::submit_page`], reserving a deposit for the size and weight of the page. The
+//! voter indices of each page are relative to that page of the snapshot. Each page is checked for
+//! validity upon arrival and only its supports are stored.
+//!
+//! At the end of the signed phase, and after the signed submissions above are processed, the paged
+//! submissions are aggregated from best to worse claimed score. A paged submission is feasible if
+//! all of its pages were submitted, its aggregated supports have exactly [`DesiredTargets`]
+//! winners, and their score is the claimed one. The first feasible one that is better than the
+//! queued solution is rewarded and queued, the infeasible ones processed before it are slashed and
+//! the rest get their deposit back. If none is feasible, the queued solution, or eventually
+//! [`pallet::Config::Fallback`], is used.
+//!
 //! ## Unsigned Phase
 //!
 //! The unsigned phase will always follow the signed phase, with the specified duration. In this
@@ -103,7 +138,14 @@
 //!
 //! Validators will only submit solutions if the one that they have computed is sufficiently better
 //! than the best queued one (see [`pallet::Config::SolutionImprovementThreshold`]) and will limit
-//! the weigh of the solution to [`pallet::Config::MinerMaxWeight`].
+//! the weight and length of the solution to [`MinerConfig::MaxWeight`] and
+//! [`MinerConfig::MaxLength`] of [`pallet::Config::MinerConfig`].
+//!
+//! The algorithm used to mine the solution is [`pallet::Config::Solver`], which can be any
+//! [`frame_election_provider_support::NposSolver`], e.g.
+//! [`frame_election_provider_support::SequentialPhragmen`],
+//! [`frame_election_provider_support::PhragMMS`] or
+//! [`frame_election_provider_support::ApprovalVoting`].
 //!
 //! The unsigned phase can be made passive depending on how the previous signed phase went, by
 //! setting the first inner value of [`Phase`] to `false`. For now, the signed phase is always
@@ -114,8 +156,11 @@
 //! If we reach the end of both phases (i.e. call to [`ElectionProvider::elect`] happens) and no
 //! good solution is queued, then the fallback strategy [`pallet::Config::Fallback`] is used to
 //! determine what needs to be done. The on-chain election is slow, and contains no balancing or
-//! reduction post-processing. [`NoFallback`] does nothing and enables [`Phase::Emergency`], which
-//! is a more *fail-safe* approach.
+//! reduction post-processing. It can use any [`frame_election_provider_support::NposSolver`]
+//! through [`frame_election_provider_support::onchain::Config::Solver`], and
+//! [`frame_election_provider_support::onchain::BoundedExecution`] refuses to run on more voters
+//! and targets than its configured bounds, instead of exhausting the block weight. [`NoFallback`]
+//! does nothing and enables [`Phase::Emergency`], which is a more *fail-safe* approach.
 //!
 //! ### Emergency Phase
 //!
@@ -230,7 +275,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode};
-use frame_election_provider_support::{ElectionDataProvider, ElectionProvider};
+use frame_election_provider_support::{ElectionDataProvider, ElectionProvider, PageIndex};
 use frame_support::{
 	dispatch::DispatchResultWithPostInfo,
 	ensure,
@@ -272,12 +317,15 @@ pub mod weights;
 pub use weights::WeightInfo;
 
 pub use signed::{
-	BalanceOf, NegativeImbalanceOf, PositiveImbalanceOf, SignedSubmission, SignedSubmissionOf,
-	SignedSubmissions, SubmissionIndicesOf,
+	BalanceOf, NegativeImbalanceOf, PagedSignedSubmission, PagedSignedSubmissionOf,
+	PositiveImbalanceOf, SignedSubmission, SignedSubmissionOf, SignedSubmissions,
+	SubmissionIndicesOf,
 };
 
+pub use unsigned::{Miner, MinerConfig};
+
 /// The solution type used by this crate.
-pub type SolutionOf<T> = <T as Config>::Solution;
+pub type SolutionOf<T> = <T as MinerConfig>::Solution;
 
 /// The voter index. Derived from [`SolutionOf`].
 pub type SolutionVoterIndexOf<T> = <SolutionOf<T> as NposSolution>::VoterIndex;
@@ -344,6 +392,9 @@ pub enum Phase<Bn> {
 	/// After that, the only way to leave this phase is through a successful
 	/// `T::ElectionProvider::elect`.
 	Emergency,
+	/// The snapshot is being taken over multiple blocks. The inner value is the index of the next
+	/// page of voters to be taken.
+	Snapshot(PageIndex),
 }
 
 impl<Bn> Default for Phase<Bn> {
@@ -382,6 +433,11 @@ impl<Bn: PartialEq + Eq> Phase<Bn> {
 	pub fn is_off(&self) -> bool {
 		matches!(self, Phase::Off)
 	}
+
+	/// Whether the phase is snapshot or not.
+	pub fn is_snapshot(&self) -> bool {
+		matches!(self, Phase::Snapshot(_))
+	}
 }
 
 /// The type of `Computation` that provided this election data.
@@ -480,7 +536,7 @@ pub enum ElectionError<T: Config> {
 	/// An error happened in the feasibility check sub-system.
 	Feasibility(FeasibilityError),
 	/// An error in the miner (offchain) sub-system.
-	Miner(unsigned::MinerError<T>),
+	Miner(unsigned::MinerError),
 	/// An error happened in the data provider.
 	DataProvider(&'static str),
 	/// An error nested in the fallback.
@@ -512,8 +568,8 @@ impl<T: Config> From<FeasibilityError> for ElectionError<T> {
 	}
 }
 
-impl<T: Config> From<unsigned::MinerError<T>> for ElectionError<T> {
-	fn from(e: unsigned::MinerError<T>) -> Self {
+impl<T: Config> From<unsigned::MinerError> for ElectionError<T> {
+	fn from(e: unsigned::MinerError) -> Self {
 		ElectionError::Miner(e)
 	}
 }
@@ -541,6 +597,8 @@ pub enum FeasibilityError {
 	InvalidRound,
 	/// Comparison against `MinimumUntrustedScore` failed.
 	UntrustedScoreTooLow,
+	/// Not all of the pages of a paged solution were submitted.
+	IncompletePagedSolution,
 }
 
 impl From<sp_npos_elections::Error> for FeasibilityError {
@@ -592,13 +650,6 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinerTxPriority: Get<TransactionPriority>;
 
-		/// Maximum weight that the miner should consume.
-		///
-		/// The miner will ensure that the total weight of the unsigned solution will not exceed
-		/// this value, based on [`WeightInfo::submit_unsigned`].
-		#[pallet::constant]
-		type MinerMaxWeight: Get<Weight>;
-
 		/// Maximum number of signed submissions that can be queued.
 		///
 		/// It is best to avoid adjusting this during an election, as it impacts downstream data
@@ -611,7 +662,8 @@ pub mod pallet {
 
 		/// Maximum weight of a signed solution.
 		///
-		/// This should probably be similar to [`Config::MinerMaxWeight`].
+		/// This should probably be similar to [`MinerConfig::MaxWeight`] of
+		/// [`Config::MinerConfig`].
 		#[pallet::constant]
 		type SignedMaxWeight: Get<Weight>;
 
@@ -631,14 +683,20 @@ pub mod pallet {
 		#[pallet::constant]
 		type SignedDepositWeight: Get<BalanceOf<Self>>;
 
-		/// The maximum number of voters to put in the snapshot. At the moment, snapshots are only
-		/// over a single block, but once multi-block elections are introduced they will take place
-		/// over multiple blocks.
+		/// The maximum number of voters to put in each page of the snapshot.
 		///
 		/// Also, note the data type: If the voters are represented by a `u32` in `type
-		/// CompactSolution`, the same `u32` is used here to ensure bounds are respected.
+		/// CompactSolution`, the same `u32` is used here to ensure bounds are respected. This bound
+		/// must also hold for all the pages together, see [`Config::Pages`].
+		#[pallet::constant]
+		type VoterSnapshotPerBlock: Get<SolutionVoterIndexOf<Self::MinerConfig>>;
+
+		/// The number of pages that the voter snapshot is taken in, one page per block.
+		///
+		/// A paged signed submission must be submitted in this many pages as well. With `1`, the
+		/// entire snapshot is taken in the block that opens the signed (or unsigned) phase.
 		#[pallet::constant]
-		type VoterSnapshotPerBlock: Get<SolutionVoterIndexOf<Self>>;
+		type Pages: Get<PageIndex>;
 
 		/// Handler for the slashed deposits.
 		type SlashHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
@@ -646,26 +704,15 @@ pub mod pallet {
 		/// Handler for the rewards.
 		type RewardHandler: OnUnbalanced<PositiveImbalanceOf<Self>>;
 
-		/// Maximum length (bytes) that the mined solution should consume.
-		///
-		/// The miner will ensure that the total length of the unsigned solution will not exceed
-		/// this value.
-		#[pallet::constant]
-		type MinerMaxLength: Get<u32>;
-
 		/// Something that will provide the election data.
 		type DataProvider: ElectionDataProvider<Self::AccountId, Self::BlockNumber>;
 
-		/// The solution type.
-		type Solution: codec::Codec
-			+ Default
-			+ PartialEq
-			+ Eq
-			+ Clone
-			+ sp_std::fmt::Debug
-			+ Ord
-			+ NposSolution
-			+ TypeInfo;
+		/// The configuration of the solution miner, used both by the offchain worker and by any
+		/// external miner.
+		///
+		/// This determines the solution type, and the maximum weight and length that a mined
+		/// solution may have.
+		type MinerConfig: crate::unsigned::MinerConfig<AccountId = Self::AccountId>;
 
 		/// Configuration for the fallback
 		type Fallback: ElectionProvider<
@@ -696,6 +743,9 @@ pub mod pallet {
 			let signed_deadline = T::SignedPhase::get() + T::UnsignedPhase::get();
 			let unsigned_deadline = T::UnsignedPhase::get();
 
+			let pages = T::Pages::get();
+			let snapshot_deadline = signed_deadline + pages.saturating_sub(1).into();
+
 			let remaining = next_election - now;
 			let current_phase = Self::current_phase();
 
@@ -707,9 +757,47 @@ pub mod pallet {
 				Self::snapshot_metadata()
 			);
 			match current_phase {
-				Phase::Off if remaining <= signed_deadline && remaining > unsigned_deadline => {
+				Phase::Off
+					if remaining <= snapshot_deadline &&
+						remaining > signed_deadline &&
+						!signed_deadline.is_zero() =>
+				{
+					// NOTE: only reachable with more than one page, and at least one of the phases
+					// enabled.
+					match Self::create_snapshot_paged(0, 1) {
+						Ok(_) => {
+							<CurrentPhase<T>>::put(Phase::Snapshot(1));
+							T::WeightInfo::on_initialize_nothing()
+								.saturating_add(T::DbWeight::get().writes(1))
+						},
+						Err(why) => {
+							log!(warn, "failed to start multi-block snapshot due to {:?}", why);
+							Self::kill_snapshot();
+							T::WeightInfo::on_initialize_nothing()
+						},
+					}
+				},
+				Phase::Snapshot(next) if remaining > signed_deadline && next < pages => {
+					match Self::create_snapshot_paged(next, next + 1) {
+						Ok(_) => {
+							<CurrentPhase<T>>::put(Phase::Snapshot(next + 1));
+							T::WeightInfo::on_initialize_nothing()
+								.saturating_add(T::DbWeight::get().writes(1))
+						},
+						Err(why) => {
+							// The whole snapshot will be taken again once the signed phase opens.
+							log!(warn, "failed to take snapshot page {} due to {:?}", next, why);
+							Self::kill_snapshot();
+							<CurrentPhase<T>>::put(Phase::Off);
+							T::WeightInfo::on_initialize_nothing()
+						},
+					}
+				},
+				Phase::Off | Phase::Snapshot(_)
+					if remaining <= signed_deadline && remaining > unsigned_deadline =>
+				{
 					// NOTE: if signed-phase length is zero, second part of the if-condition fails.
-					match Self::create_snapshot() {
+					match Self::finish_snapshot(current_phase) {
 						Ok(_) => {
 							Self::on_initialize_open_signed();
 							T::WeightInfo::on_initialize_open_signed()
@@ -721,7 +809,7 @@ pub mod pallet {
 						},
 					}
 				},
-				Phase::Signed | Phase::Off
+				Phase::Signed | Phase::Off | Phase::Snapshot(_)
 					if remaining <= unsigned_deadline && remaining > Zero::zero() =>
 				{
 					// our needs vary according to whether or not the unsigned phase follows a
@@ -742,13 +830,13 @@ pub mod pallet {
 						// unconditionally as a defensive measure.
 						(false, true)
 					} else {
-						// No signed phase: create a new snapshot, definitely `enable` the unsigned
-						// phase.
+						// No signed phase: create a new snapshot, or finish the ongoing one,
+						// definitely `enable` the unsigned phase.
 						(true, true)
 					};
 
 					if need_snapshot {
-						match Self::create_snapshot() {
+						match Self::finish_snapshot(current_phase) {
 							Ok(_) => {
 								Self::on_initialize_open_unsigned(enabled, now);
 								T::WeightInfo::on_initialize_open_unsigned()
@@ -793,33 +881,45 @@ pub mod pallet {
 			use sp_std::mem::size_of;
 			// The index type of both voters and targets need to be smaller than that of usize (very
 			// unlikely to be the case, but anyhow).
-			assert!(size_of::<SolutionVoterIndexOf<T>>() <= size_of::<usize>());
-			assert!(size_of::<SolutionTargetIndexOf<T>>() <= size_of::<usize>());
+			assert!(size_of::<SolutionVoterIndexOf<T::MinerConfig>>() <= size_of::<usize>());
+			assert!(size_of::<SolutionTargetIndexOf<T::MinerConfig>>() <= size_of::<usize>());
+
+			// There must be at least one page, and the voters of all pages together must still be
+			// representable by the voter index of the solution.
+			assert!(T::Pages::get() > 0);
+			let voters_per_page: usize = T::VoterSnapshotPerBlock::get().saturated_into();
+			assert!(
+				voters_per_page.saturating_mul(T::Pages::get() as usize) <=
+					<SolutionVoterIndexOf<T::MinerConfig>>::max_value()
+						.saturated_into::<usize>()
+						.saturating_add(1)
+			);
 
 			// ----------------------------
 			// Based on the requirements of [`sp_npos_elections::Assignment::try_normalize`].
-			let max_vote: usize = <SolutionOf<T> as NposSolution>::LIMIT;
+			let max_vote: usize = <SolutionOf<T::MinerConfig> as NposSolution>::LIMIT;
 
 			// 2. Maximum sum of [SolutionAccuracy; 16] must fit into `UpperOf<OffchainAccuracy>`.
-			let maximum_chain_accuracy: Vec<UpperOf<SolutionAccuracyOf<T>>> = (0..max_vote)
+			let maximum_chain_accuracy: Vec<UpperOf<SolutionAccuracyOf<T::MinerConfig>>> = (0..
+				max_vote)
 				.map(|_| {
-					<UpperOf<SolutionAccuracyOf<T>>>::from(
-						<SolutionAccuracyOf<T>>::one().deconstruct(),
+					<UpperOf<SolutionAccuracyOf<T::MinerConfig>>>::from(
+						<SolutionAccuracyOf<T::MinerConfig>>::one().deconstruct(),
 					)
 				})
 				.collect();
-			let _: UpperOf<SolutionAccuracyOf<T>> = maximum_chain_accuracy
+			let _: UpperOf<SolutionAccuracyOf<T::MinerConfig>> = maximum_chain_accuracy
 				.iter()
 				.fold(Zero::zero(), |acc, x| acc.checked_add(x).unwrap());
 
 			// We only accept data provider who's maximum votes per voter matches our
-			// `T::Solution`'s `LIMIT`.
+			// `T::MinerConfig::Solution`'s `LIMIT`.
 			//
 			// NOTE that this pallet does not really need to enforce this in runtime. The
 			// solution cannot represent any voters more than `LIMIT` anyhow.
 			assert_eq!(
 				<T::DataProvider as ElectionDataProvider<T::AccountId, T::BlockNumber>>::MAXIMUM_VOTES_PER_VOTER,
-				<SolutionOf<T> as NposSolution>::LIMIT as u32,
+				<SolutionOf<T::MinerConfig> as NposSolution>::LIMIT as u32,
 			);
 		}
 	}
@@ -851,7 +951,7 @@ pub mod pallet {
 		))]
 		pub fn submit_unsigned(
 			origin: OriginFor<T>,
-			raw_solution: Box<RawSolution<SolutionOf<T>>>,
+			raw_solution: Box<RawSolution<SolutionOf<T::MinerConfig>>>,
 			witness: SolutionOrSnapshotSize,
 		) -> DispatchResultWithPostInfo {
 			ensure_none(origin)?;
@@ -941,7 +1041,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::submit(*num_signed_submissions))]
 		pub fn submit(
 			origin: OriginFor<T>,
-			raw_solution: Box<RawSolution<SolutionOf<T>>>,
+			raw_solution: Box<RawSolution<SolutionOf<T::MinerConfig>>>,
 			num_signed_submissions: u32,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
@@ -1008,6 +1108,118 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Register a solution that will be submitted in pages, for the signed phase.
+		///
+		/// The dispatch origin fo this call must be __signed__.
+		///
+		/// `claimed_score` is the score of the entire solution, which is checked once all of its
+		/// pages are aggregated at the end of the signed phase. [`Config::SignedDepositBase`] is
+		/// reserved as deposit, and is slashed if the solution turns out to be incomplete or
+		/// infeasible.
+		///
+		/// The pages of the solution are then submitted via [`Pallet::submit_page`].
+		#[pallet::weight(T::WeightInfo::register_paged())]
+		pub fn register_paged(
+			origin: OriginFor<T>,
+			claimed_score: ElectionScore,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// ensure solution is timely.
+			ensure!(Self::current_phase().is_signed(), Error::<T>::PreDispatchEarlySubmission);
+			ensure!(
+				!<PagedSignedSubmissions<T>>::contains_key(&who),
+				Error::<T>::SignedPagedAlreadyRegistered
+			);
+			ensure!(
+				<PagedSignedSubmissions<T>>::count() < T::SignedMaxSubmissions::get(),
+				Error::<T>::SignedQueueFull
+			);
+			ensure!(
+				Self::minimum_untrusted_score().map_or(true, |min_score| {
+					sp_npos_elections::is_score_better(claimed_score, min_score, Perbill::zero())
+				}),
+				Error::<T>::PreDispatchWeakSubmission,
+			);
+
+			let deposit = T::SignedDepositBase::get();
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::SignedCannotPayDeposit)?;
+
+			<PagedSignedSubmissions<T>>::insert(
+				&who,
+				PagedSignedSubmission { deposit, claimed_score, pages: 0 },
+			);
+			Ok(())
+		}
+
+		/// Submit one page of a solution previously registered via [`Pallet::register_paged`].
+		///
+		/// The dispatch origin fo this call must be __signed__.
+		///
+		/// The voter indices of `solution` are relative to the given `page` of the voter snapshot,
+		/// while the target indices are relative to the entire target snapshot. The page is checked
+		/// for validity on the spot, and only its supports are stored. Each page can only be
+		/// submitted once.
+		///
+		/// A deposit based on the size and weight of the page, see [`Config::SignedDepositByte`]
+		/// and [`Config::SignedDepositWeight`], is reserved on top of the deposit of the
+		/// registration, and shares its fate.
+		///
+		/// # <weight>
+		/// The size of the snapshot page must be provided as witness data.
+		/// # </weight>
+		#[pallet::weight(
+			T::WeightInfo::feasibility_check(
+				witness.voters,
+				witness.targets,
+				solution.voter_count() as u32,
+				solution.unique_targets().len() as u32,
+			)
+			.saturating_add(T::DbWeight::get().reads_writes(5, 3))
+		)]
+		pub fn submit_page(
+			origin: OriginFor<T>,
+			page: PageIndex,
+			solution: Box<SolutionOf<T::MinerConfig>>,
+			witness: SolutionOrSnapshotSize,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::current_phase().is_signed(), Error::<T>::PreDispatchEarlySubmission);
+			ensure!(page < T::Pages::get(), Error::<T>::SignedPagedInvalidPage);
+
+			let mut submission = <PagedSignedSubmissions<T>>::get(&who)
+				.ok_or(Error::<T>::SignedPagedNotRegistered)?;
+			ensure!(
+				!<PagedSignedSupports<T>>::contains_key(&who, page),
+				Error::<T>::SignedPagedDuplicatePage
+			);
+
+			// ensure witness data is correct.
+			let voters = <PagedVoterSnapshot<T>>::decode_len(page).unwrap_or_default() as u32;
+			let targets = <TargetSnapshot<T>>::decode_len().unwrap_or_default() as u32;
+			ensure!(
+				witness.voters >= voters && witness.targets >= targets,
+				Error::<T>::SignedInvalidWitness
+			);
+
+			let deposit =
+				Self::page_deposit_for(&solution, SolutionOrSnapshotSize { voters, targets });
+			let supports = Self::feasibility_check_page(page, *solution).map_err(|err| {
+				log!(debug, "page {} of paged submission is infeasible due to {:?}", page, err);
+				Error::<T>::SignedPagedInfeasible
+			})?;
+
+			// collect deposit. Thereafter, the function cannot fail.
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::SignedCannotPayDeposit)?;
+
+			<PagedSignedSupports<T>>::insert(&who, page, supports);
+			submission.deposit = submission.deposit.saturating_add(deposit);
+			submission.pages.saturating_inc();
+			<PagedSignedSubmissions<T>>::insert(&who, submission);
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -1058,6 +1270,16 @@ pub mod pallet {
 		InvalidSubmissionIndex,
 		/// The call is not allowed at this point.
 		CallNotAllowed,
+		/// The origin already registered a paged solution.
+		SignedPagedAlreadyRegistered,
+		/// The origin has not registered a paged solution.
+		SignedPagedNotRegistered,
+		/// The page index is out of bounds.
+		SignedPagedInvalidPage,
+		/// The page of the paged solution has already been submitted.
+		SignedPagedDuplicatePage,
+		/// The page of the paged solution is not valid.
+		SignedPagedInfeasible,
 	}
 
 	#[pallet::validate_unsigned]
@@ -1133,23 +1355,31 @@ pub mod pallet {
 	#[pallet::getter(fn queued_solution)]
 	pub type QueuedSolution<T: Config> = StorageValue<_, ReadySolution<T::AccountId>>;
 
-	/// Snapshot data of the round.
+	/// The voters of the snapshot of the round, per page.
+	///
+	/// This is created at the beginning of the signed phase, or over the [`Phase::Snapshot`] that
+	/// precedes it, and cleared upon calling `elect`.
+	#[pallet::storage]
+	pub type PagedVoterSnapshot<T: Config> =
+		StorageMap<_, Twox64Concat, PageIndex, Vec<crate::unsigned::Voter<T>>>;
+
+	/// The targets of the snapshot of the round.
 	///
-	/// This is created at the beginning of the signed phase and cleared upon calling `elect`.
+	/// This is created along with the first page of [`PagedVoterSnapshot`] and cleared upon
+	/// calling `elect`.
 	#[pallet::storage]
-	#[pallet::getter(fn snapshot)]
-	pub type Snapshot<T: Config> = StorageValue<_, RoundSnapshot<T::AccountId>>;
+	pub type TargetSnapshot<T: Config> = StorageValue<_, Vec<T::AccountId>>;
 
 	/// Desired number of targets to elect for this round.
 	///
-	/// Only exists when [`Snapshot`] is present.
+	/// Only exists when [`TargetSnapshot`] is present.
 	#[pallet::storage]
 	#[pallet::getter(fn desired_targets)]
 	pub type DesiredTargets<T> = StorageValue<_, u32>;
 
-	/// The metadata of the [`RoundSnapshot`]
+	/// The metadata of the [`RoundSnapshot`], accumulated over all of its pages.
 	///
-	/// Only exists when [`Snapshot`] is present.
+	/// Only exists when [`TargetSnapshot`] is present.
 	#[pallet::storage]
 	#[pallet::getter(fn snapshot_metadata)]
 	pub type SnapshotMetadata<T: Config> = StorageValue<_, SolutionOrSnapshotSize>;
@@ -1193,6 +1423,26 @@ pub mod pallet {
 
 	// `SignedSubmissions` items end here.
 
+	/// Registered paged signed solutions, see [`Pallet::register_paged`].
+	///
+	/// Drained at the end of the signed phase.
+	#[pallet::storage]
+	pub type PagedSignedSubmissions<T: Config> =
+		CountedStorageMap<_, Twox64Concat, T::AccountId, PagedSignedSubmissionOf<T>>;
+
+	/// The supports of each submitted page of the registered paged signed solutions.
+	///
+	/// Drained at the end of the signed phase.
+	#[pallet::storage]
+	pub type PagedSignedSupports<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		PageIndex,
+		Supports<T::AccountId>,
+	>;
+
 	/// The minimum score that each 'untrusted' solution must attain in order to be considered
 	/// feasible.
 	///
@@ -1249,85 +1499,163 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::UnsignedPhaseStarted { round });
 	}
 
-	/// Parts of [`create_snapshot`] that happen inside of this pallet.
+	/// Parts of [`create_snapshot`] that happen inside of this pallet, for a single page.
+	///
+	/// The targets and desired targets must be given, and are only stored, for the first page.
 	///
 	/// Extracted for easier weight calculation.
 	fn create_snapshot_internal(
-		targets: Vec<T::AccountId>,
+		page: PageIndex,
 		voters: Vec<crate::unsigned::Voter<T>>,
-		desired_targets: u32,
+		maybe_targets: Option<(Vec<T::AccountId>, u32)>,
 	) {
-		let metadata =
-			SolutionOrSnapshotSize { voters: voters.len() as u32, targets: targets.len() as u32 };
-		log!(info, "creating a snapshot with metadata {:?}", metadata);
+		if let Some((targets, desired_targets)) = maybe_targets {
+			let metadata = SolutionOrSnapshotSize { voters: 0, targets: targets.len() as u32 };
+			<SnapshotMetadata<T>>::put(metadata);
+			<DesiredTargets<T>>::put(desired_targets);
+			<TargetSnapshot<T>>::put(targets);
+		}
 
-		<SnapshotMetadata<T>>::put(metadata);
-		<DesiredTargets<T>>::put(desired_targets);
+		<SnapshotMetadata<T>>::mutate(|maybe_metadata| {
+			// defensive-only: the first page always creates the metadata.
+			let metadata = maybe_metadata.get_or_insert_with(Default::default);
+			metadata.voters = metadata.voters.saturating_add(voters.len() as u32);
+			log!(info, "creating snapshot page {} with metadata {:?}", page, metadata);
+		});
 
 		// instead of using storage APIs, we do a manual encoding into a fixed-size buffer.
 		// `encoded_size` encodes it without storing it anywhere, this should not cause any
 		// allocation.
-		let snapshot = RoundSnapshot { voters, targets };
-		let size = snapshot.encoded_size();
-		log!(debug, "snapshot pre-calculated size {:?}", size);
+		let size = voters.encoded_size();
+		log!(debug, "snapshot page {} pre-calculated size {:?}", page, size);
 		let mut buffer = Vec::with_capacity(size);
-		snapshot.encode_to(&mut buffer);
+		voters.encode_to(&mut buffer);
 
 		// do some checks.
-		debug_assert_eq!(buffer, snapshot.encode());
+		debug_assert_eq!(buffer, voters.encode());
 		// buffer should have not re-allocated since.
 		debug_assert!(buffer.len() == size && size == buffer.capacity());
 
-		sp_io::storage::set(&<Snapshot<T>>::hashed_key(), &buffer);
+		sp_io::storage::set(&<PagedVoterSnapshot<T>>::hashed_key_for(page), &buffer);
 	}
 
-	/// Parts of [`create_snapshot`] that happen outside of this pallet.
+	/// Parts of [`create_snapshot`] that happen outside of this pallet, for a single page.
+	///
+	/// The targets and desired targets are only fetched for the first page.
 	///
 	/// Extracted for easier weight calculation.
 	fn create_snapshot_external(
-	) -> Result<(Vec<T::AccountId>, Vec<crate::unsigned::Voter<T>>, u32), ElectionError<T>> {
-		let target_limit = <SolutionTargetIndexOf<T>>::max_value().saturated_into::<usize>();
-		// for now we have just a single block snapshot.
+		page: PageIndex,
+	) -> Result<(Vec<crate::unsigned::Voter<T>>, Option<(Vec<T::AccountId>, u32)>), ElectionError<T>>
+	{
+		let target_limit =
+			<SolutionTargetIndexOf<T::MinerConfig>>::max_value().saturated_into::<usize>();
 		let voter_limit = T::VoterSnapshotPerBlock::get().saturated_into::<usize>();
 
-		let targets =
-			T::DataProvider::targets(Some(target_limit)).map_err(ElectionError::DataProvider)?;
-		let voters =
-			T::DataProvider::voters(Some(voter_limit)).map_err(ElectionError::DataProvider)?;
-		let desired_targets =
-			T::DataProvider::desired_targets().map_err(ElectionError::DataProvider)?;
+		let maybe_targets = if page == 0 {
+			let targets = T::DataProvider::targets(Some(target_limit))
+				.map_err(ElectionError::DataProvider)?;
+			let desired_targets =
+				T::DataProvider::desired_targets().map_err(ElectionError::DataProvider)?;
+			Some((targets, desired_targets))
+		} else {
+			None
+		};
+		let voters = T::DataProvider::voters_paged(Some(voter_limit), page)
+			.map_err(ElectionError::DataProvider)?;
 
 		// Defensive-only.
-		if targets.len() > target_limit || voters.len() > voter_limit {
+		let targets_len = maybe_targets.as_ref().map_or(0, |(targets, _)| targets.len());
+		if targets_len > target_limit || voters.len() > voter_limit {
 			debug_assert!(false, "Snapshot limit has not been respected.");
 			return Err(ElectionError::DataProvider("Snapshot too big for submission."))
 		}
 
-		Ok((targets, voters, desired_targets))
+		Ok((voters, maybe_targets))
 	}
 
-	/// Creates the snapshot. Writes new data to:
+	/// Creates the pages `from..to` of the snapshot. Creating the first page also creates the
+	/// target snapshot. Writes new data to:
 	///
 	/// 1. [`SnapshotMetadata`]
-	/// 2. [`RoundSnapshot`]
-	/// 3. [`DesiredTargets`]
+	/// 2. [`PagedVoterSnapshot`]
+	/// 3. [`TargetSnapshot`] and [`DesiredTargets`], for the first page.
 	///
 	/// Returns `Ok(())` if operation is okay.
 	///
 	/// This is a *self-weighing* function, it will register its own extra weight as
 	/// [`DispatchClass::Mandatory`] with the system pallet.
-	pub fn create_snapshot() -> Result<(), ElectionError<T>> {
-		// this is self-weighing itself..
-		let (targets, voters, desired_targets) = Self::create_snapshot_external()?;
-
-		// ..therefore we only measure the weight of this and add it.
-		let internal_weight =
-			T::WeightInfo::create_snapshot_internal(voters.len() as u32, targets.len() as u32);
-		Self::create_snapshot_internal(targets, voters, desired_targets);
-		Self::register_weight(internal_weight);
+	pub fn create_snapshot_paged(from: PageIndex, to: PageIndex) -> Result<(), ElectionError<T>> {
+		for page in from..to {
+			// this is self-weighing itself..
+			let (voters, maybe_targets) = Self::create_snapshot_external(page)?;
+
+			// ..therefore we only measure the weight of this and add it.
+			let internal_weight = T::WeightInfo::create_snapshot_internal(
+				voters.len() as u32,
+				maybe_targets.as_ref().map_or(0, |(targets, _)| targets.len() as u32),
+			);
+			Self::create_snapshot_internal(page, voters, maybe_targets);
+			Self::register_weight(internal_weight);
+		}
 		Ok(())
 	}
 
+	/// Creates the entire snapshot, all of its pages at once.
+	///
+	/// See [`Pallet::create_snapshot_paged`].
+	pub fn create_snapshot() -> Result<(), ElectionError<T>> {
+		Self::create_snapshot_paged(0, T::Pages::get())
+	}
+
+	/// Creates the pages of the snapshot that are still missing in `phase`: all of them, unless a
+	/// multi-block snapshot is ongoing.
+	///
+	/// If finishing an ongoing multi-block snapshot fails, it is discarded and the phase is set
+	/// back to [`Phase::Off`], such that the next attempt takes the entire snapshot.
+	fn finish_snapshot(phase: Phase<T::BlockNumber>) -> Result<(), ElectionError<T>> {
+		match phase {
+			Phase::Snapshot(next) =>
+				Self::create_snapshot_paged(next, T::Pages::get()).map_err(|why| {
+					Self::kill_snapshot();
+					<CurrentPhase<T>>::put(Phase::Off);
+					why
+				}),
+			_ => Self::create_snapshot(),
+		}
+	}
+
+	/// The entire snapshot of the round, with the voters of all pages concatenated in order.
+	///
+	/// Returns `None` if the snapshot, or any of its pages, does not exist.
+	pub fn snapshot() -> Option<RoundSnapshot<T::AccountId>> {
+		let targets = <TargetSnapshot<T>>::get()?;
+		let mut voters = Vec::new();
+		for page in 0..T::Pages::get() {
+			voters.extend(<PagedVoterSnapshot<T>>::get(page)?);
+		}
+		Some(RoundSnapshot { voters, targets })
+	}
+
+	/// Put the given `snapshot` in storage, splitting its voters in [`Config::Pages`] pages of
+	/// equal size.
+	///
+	/// Does not update the [`SnapshotMetadata`] and [`DesiredTargets`].
+	#[cfg(any(feature = "runtime-benchmarks", test))]
+	pub fn put_snapshot(snapshot: RoundSnapshot<T::AccountId>) {
+		let RoundSnapshot { voters, targets } = snapshot;
+		let pages = T::Pages::get() as usize;
+		let page_size = (voters.len() + pages - 1) / pages;
+		let mut voters = voters.into_iter();
+		for page in 0..T::Pages::get() {
+			<PagedVoterSnapshot<T>>::insert(
+				page,
+				voters.by_ref().take(page_size).collect::<Vec<_>>(),
+			);
+		}
+		<TargetSnapshot<T>>::put(targets);
+	}
+
 	/// Register some amount of weight directly with the system pallet.
 	///
 	/// This is always mandatory weight.
@@ -1340,14 +1668,15 @@ impl<T: Config> Pallet<T> {
 
 	/// Kill everything created by [`Pallet::create_snapshot`].
 	pub fn kill_snapshot() {
-		<Snapshot<T>>::kill();
+		<PagedVoterSnapshot<T>>::remove_all(None);
+		<TargetSnapshot<T>>::kill();
 		<SnapshotMetadata<T>>::kill();
 		<DesiredTargets<T>>::kill();
 	}
 
 	/// Checks the feasibility of a solution.
 	pub fn feasibility_check(
-		raw_solution: RawSolution<SolutionOf<T>>,
+		raw_solution: RawSolution<SolutionOf<T::MinerConfig>>,
 		compute: ElectionCompute,
 	) -> Result<ReadySolution<T::AccountId>, FeasibilityError> {
 		let RawSolution { solution, score, round } = raw_solution;
@@ -1379,11 +1708,45 @@ impl<T: Config> Pallet<T> {
 		let RoundSnapshot { voters: snapshot_voters, targets: snapshot_targets } =
 			Self::snapshot().ok_or(FeasibilityError::SnapshotUnavailable)?;
 
+		let supports = Self::supports_of(solution, &snapshot_voters, &snapshot_targets)?;
+
+		// Finally, check that the claimed score was indeed correct.
+		let known_score = supports.evaluate();
+		ensure!(known_score == score, FeasibilityError::InvalidScore);
+
+		Ok(ReadySolution { supports, compute, score })
+	}
+
+	/// Checks the feasibility of a single page of a paged signed solution, and returns its
+	/// supports.
+	///
+	/// The voter indices of `solution` are relative to the given `page` of the voter snapshot. Only
+	/// the validity of the votes is checked; the winner count and the score are checked once all of
+	/// the pages are aggregated.
+	pub fn feasibility_check_page(
+		page: PageIndex,
+		solution: SolutionOf<T::MinerConfig>,
+	) -> Result<Supports<T::AccountId>, FeasibilityError> {
+		let snapshot_voters =
+			<PagedVoterSnapshot<T>>::get(page).ok_or(FeasibilityError::SnapshotUnavailable)?;
+		let snapshot_targets =
+			<TargetSnapshot<T>>::get().ok_or(FeasibilityError::SnapshotUnavailable)?;
+
+		Self::supports_of(solution, &snapshot_voters, &snapshot_targets)
+	}
+
+	/// Converts `solution` into supports, ensuring that all of its votes are valid based on the
+	/// given snapshot voters and targets.
+	fn supports_of(
+		solution: SolutionOf<T::MinerConfig>,
+		snapshot_voters: &Vec<crate::unsigned::Voter<T>>,
+		snapshot_targets: &Vec<T::AccountId>,
+	) -> Result<Supports<T::AccountId>, FeasibilityError> {
 		// ----- Start building. First, we need some closures.
-		let cache = helpers::generate_voter_cache::<T>(&snapshot_voters);
-		let voter_at = helpers::voter_at_fn::<T>(&snapshot_voters);
-		let target_at = helpers::target_at_fn::<T>(&snapshot_targets);
-		let voter_index = helpers::voter_index_fn_usize::<T>(&cache);
+		let cache = helpers::generate_voter_cache::<T::MinerConfig>(snapshot_voters);
+		let voter_at = helpers::voter_at_fn::<T::MinerConfig>(snapshot_voters);
+		let target_at = helpers::target_at_fn::<T::MinerConfig>(snapshot_targets);
+		let voter_index = helpers::voter_index_fn_usize::<T::MinerConfig>(&cache);
 
 		// Then convert solution -> assignment. This will fail if any of the indices are gibberish,
 		// namely any of the voters or targets.
@@ -1417,18 +1780,12 @@ impl<T: Config> Pallet<T> {
 			.collect::<Result<(), FeasibilityError>>()?;
 
 		// ----- Start building support. First, we need one more closure.
-		let stake_of = helpers::stake_of_fn::<T>(&snapshot_voters, &cache);
+		let stake_of = helpers::stake_of_fn::<T::MinerConfig>(snapshot_voters, &cache);
 
 		// This might fail if the normalization fails. Very unlikely. See `integrity_test`.
 		let staked_assignments = assignment_ratio_to_staked_normalized(assignments, stake_of)
 			.map_err::<FeasibilityError, _>(Into::into)?;
-		let supports = sp_npos_elections::to_supports(&staked_assignments);
-
-		// Finally, check that the claimed score was indeed correct.
-		let known_score = supports.evaluate();
-		ensure!(known_score == score, FeasibilityError::InvalidScore);
-
-		Ok(ReadySolution { supports, compute, score })
+		Ok(sp_npos_elections::to_supports(&staked_assignments))
 	}
 
 	/// Perform the tasks to be done after a new `elect` has been triggered:
@@ -1546,7 +1903,7 @@ mod feasibility_check {
 			let solution = raw_solution();
 
 			// For whatever reason it might be:
-			<Snapshot<Runtime>>::kill();
+			<TargetSnapshot<Runtime>>::kill();
 
 			assert_noop!(
 				MultiPhase::feasibility_check(solution, COMPUTE),
@@ -1848,6 +2205,81 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn multi_block_snapshot_works() {
+		ExtBuilder::default().pages(3, 3).build_and_execute(|| {
+			// 0 ------- 13 -- 14 -- 15 ------- 25 ------- 30
+			//           |      |     |          |          |
+			//      Snapshot(1) |   Signed    Unsigned    Elect
+			//            Snapshot(2)
+			roll_to(12);
+			assert_eq!(MultiPhase::current_phase(), Phase::Off);
+			assert!(MultiPhase::snapshot_metadata().is_none());
+
+			// the targets and the first page of voters are taken.
+			roll_to(13);
+			assert_eq!(MultiPhase::current_phase(), Phase::Snapshot(1));
+			assert_eq!(<PagedVoterSnapshot<Runtime>>::get(0).unwrap().len(), 3);
+			assert_eq!(<TargetSnapshot<Runtime>>::get().unwrap().len(), 4);
+			assert_eq!(MultiPhase::desired_targets(), Some(2));
+			assert_eq!(
+				MultiPhase::snapshot_metadata().unwrap(),
+				SolutionOrSnapshotSize { voters: 3, targets: 4 }
+			);
+			// the snapshot is not complete yet.
+			assert!(MultiPhase::snapshot().is_none());
+
+			roll_to(14);
+			assert_eq!(MultiPhase::current_phase(), Phase::Snapshot(2));
+			assert_eq!(<PagedVoterSnapshot<Runtime>>::get(1).unwrap().len(), 3);
+			assert!(MultiPhase::snapshot().is_none());
+
+			// the last page is taken as the signed phase opens.
+			roll_to(15);
+			assert_eq!(MultiPhase::current_phase(), Phase::Signed);
+			assert_eq!(<PagedVoterSnapshot<Runtime>>::get(2).unwrap().len(), 2);
+			assert_eq!(
+				MultiPhase::snapshot_metadata().unwrap(),
+				SolutionOrSnapshotSize { voters: 8, targets: 4 }
+			);
+			assert_eq!(MultiPhase::snapshot().unwrap().voters, crate::mock::Voters::get());
+
+			roll_to(30);
+			assert_ok!(MultiPhase::elect());
+			assert!(MultiPhase::current_phase().is_off());
+			assert!(MultiPhase::snapshot().is_none());
+			assert!(<PagedVoterSnapshot<Runtime>>::iter().next().is_none());
+		})
+	}
+
+	#[test]
+	fn multi_block_snapshot_signed_phase_void() {
+		ExtBuilder::default().phases(0, 10).pages(3, 3).build_and_execute(|| {
+			roll_to(17);
+			assert!(MultiPhase::current_phase().is_off());
+
+			roll_to(18);
+			assert_eq!(MultiPhase::current_phase(), Phase::Snapshot(1));
+
+			roll_to(19);
+			assert_eq!(MultiPhase::current_phase(), Phase::Snapshot(2));
+
+			// the last page is taken as the unsigned phase opens.
+			roll_to(20);
+			assert!(MultiPhase::current_phase().is_unsigned_open_at(20));
+			assert_eq!(MultiPhase::snapshot().unwrap().voters, crate::mock::Voters::get());
+		})
+	}
+
+	#[test]
+	fn multi_block_snapshot_not_taken_if_both_phases_void() {
+		ExtBuilder::default().phases(0, 0).pages(3, 3).build_and_execute(|| {
+			roll_to(30);
+			assert!(MultiPhase::current_phase().is_off());
+			assert!(MultiPhase::snapshot_metadata().is_none());
+		})
+	}
+
 	#[test]
 	fn early_termination() {
 		// An early termination in the signed phase, with no queued solution.
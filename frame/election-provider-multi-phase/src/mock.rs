@@ -185,6 +185,42 @@ pub fn raw_solution() -> RawSolution<SolutionOf<Runtime>> {
 	RawSolution { solution, score, round }
 }
 
+/// Spit out a verifiable solution split in [`Pages`] pages, one per page of the snapshot, along
+/// with its score.
+pub fn paged_solution() -> (ElectionScore, Vec<SolutionOf<Runtime>>) {
+	let RawSolution { solution, score, .. } = raw_solution();
+	let RoundSnapshot { voters, targets } = MultiPhase::snapshot().unwrap();
+
+	let voter_at = helpers::voter_at_fn::<Runtime>(&voters);
+	let target_at = helpers::target_at_fn::<Runtime>(&targets);
+	let target_index = helpers::target_index_fn_linear::<Runtime>(&targets);
+	let assignments = solution.into_assignment(voter_at, target_at).unwrap();
+
+	let pages = (0..Pages::get())
+		.map(|page| {
+			let page_voters = <PagedVoterSnapshot<Runtime>>::get(page).unwrap();
+			let voter_index = helpers::voter_index_fn_linear::<Runtime>(&page_voters);
+			let page_assignments = assignments
+				.iter()
+				.filter(|a| page_voters.iter().any(|(v, _, _)| *v == a.who))
+				.cloned()
+				.collect::<Vec<_>>();
+			<SolutionOf<Runtime>>::from_assignment(&page_assignments, &voter_index, &target_index)
+				.unwrap()
+		})
+		.collect::<Vec<_>>();
+
+	(score, pages)
+}
+
+/// The witness of the given `page` of the snapshot.
+pub fn page_witness(page: PageIndex) -> SolutionOrSnapshotSize {
+	SolutionOrSnapshotSize {
+		voters: <PagedVoterSnapshot<Runtime>>::decode_len(page).unwrap_or_default() as u32,
+		targets: <TargetSnapshot<Runtime>>::decode_len().unwrap_or_default() as u32,
+	}
+}
+
 pub fn witness() -> SolutionOrSnapshotSize {
 	MultiPhase::snapshot()
 		.map(|snap| SolutionOrSnapshotSize {
@@ -269,13 +305,14 @@ parameter_types! {
 	pub static MinerMaxLength: u32 = 256;
 	pub static MockWeightInfo: bool = false;
 	pub static VoterSnapshotPerBlock: VoterIndex = u32::max_value();
+	pub static Pages: PageIndex = 1;
 
 	pub static EpochLength: u64 = 30;
 	pub static OnChianFallback: bool = true;
 }
 
 impl onchain::Config for Runtime {
-	type Accuracy = sp_runtime::Perbill;
+	type Solver = SequentialPhragmen<AccountId, sp_runtime::Perbill>;
 	type DataProvider = StakingMock;
 }
 
@@ -286,8 +323,7 @@ impl ElectionProvider<AccountId, u64> for MockFallback {
 
 	fn elect() -> Result<Supports<AccountId>, Self::Error> {
 		if OnChianFallback::get() {
-			onchain::OnChainSequentialPhragmen::<Runtime>::elect()
-				.map_err(|_| "OnChainSequentialPhragmen failed")
+			onchain::UnboundedExecution::<Runtime>::elect().map_err(|_| "UnboundedExecution failed")
 		} else {
 			super::NoFallback::<Runtime>::elect()
 		}
@@ -371,6 +407,20 @@ impl multi_phase::weights::WeightInfo for DualMockWeightInfo {
 			<() as multi_phase::weights::WeightInfo>::feasibility_check(v, t, a, d)
 		}
 	}
+	fn register_paged() -> Weight {
+		if MockWeightInfo::get() {
+			Zero::zero()
+		} else {
+			<() as multi_phase::weights::WeightInfo>::register_paged()
+		}
+	}
+	fn aggregate_paged_supports(v: u32, d: u32) -> Weight {
+		if MockWeightInfo::get() {
+			Zero::zero()
+		} else {
+			<() as multi_phase::weights::WeightInfo>::aggregate_paged_supports(v, d)
+		}
+	}
 }
 
 parameter_types! {
@@ -398,12 +448,10 @@ impl crate::Config for Runtime {
 	type UnsignedPhase = UnsignedPhase;
 	type SolutionImprovementThreshold = SolutionImprovementThreshold;
 	type OffchainRepeat = OffchainRepeat;
-	type MinerMaxWeight = MinerMaxWeight;
-	type MinerMaxLength = MinerMaxLength;
 	type MinerTxPriority = MinerTxPriority;
 	type SignedRewardBase = SignedRewardBase;
 	type SignedDepositBase = SignedDepositBase;
-	type SignedDepositByte = ();
+	type SignedDepositByte = SignedDepositByte;
 	type SignedDepositWeight = ();
 	type SignedMaxWeight = SignedMaxWeight;
 	type SignedMaxSubmissions = SignedMaxSubmissions;
@@ -414,11 +462,25 @@ impl crate::Config for Runtime {
 	type BenchmarkingConfig = TestBenchmarkingConfig;
 	type Fallback = MockFallback;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
-	type Solution = TestNposSolution;
+	type MinerConfig = Self;
 	type VoterSnapshotPerBlock = VoterSnapshotPerBlock;
+	type Pages = Pages;
 	type Solver = SequentialPhragmen<AccountId, SolutionAccuracyOf<Runtime>, Balancing>;
 }
 
+impl MinerConfig for Runtime {
+	type AccountId = AccountId;
+	type Solution = TestNposSolution;
+	type MaxLength = MinerMaxLength;
+	type MaxWeight = MinerMaxWeight;
+
+	fn solution_weight(v: u32, t: u32, a: u32, d: u32) -> Weight {
+		<<Self as crate::Config>::WeightInfo as multi_phase::weights::WeightInfo>::submit_unsigned(
+			v, t, a, d,
+		)
+	}
+}
+
 impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Runtime
 where
 	Call: From<LocalCall>,
@@ -456,6 +518,16 @@ impl ElectionDataProvider<AccountId, u64> for StakingMock {
 		Ok(voters)
 	}
 
+	fn voters_paged(
+		maybe_max_len: Option<usize>,
+		page: PageIndex,
+	) -> data_provider::Result<Vec<(AccountId, VoteWeight, Vec<AccountId>)>> {
+		let voters = Voters::get();
+		let page_len = maybe_max_len.unwrap_or(voters.len());
+
+		Ok(voters.into_iter().skip(page as usize * page_len).take(page_len).collect())
+	}
+
 	fn desired_targets() -> data_provider::Result<u32> {
 		Ok(DesiredTargets::get())
 	}
@@ -545,6 +617,11 @@ impl ExtBuilder {
 		<SignedDepositWeight>::set(weight);
 		self
 	}
+	pub fn pages(self, pages: PageIndex, voters_per_page: VoterIndex) -> Self {
+		<Pages>::set(pages);
+		<VoterSnapshotPerBlock>::set(voters_per_page);
+		self
+	}
 	pub fn signed_weight(self, weight: Weight) -> Self {
 		<SignedMaxWeight>::set(weight);
 		self
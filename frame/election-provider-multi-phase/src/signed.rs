@@ -18,17 +18,21 @@
 //! The signed phase implementation.
 
 use crate::{
-	Config, ElectionCompute, Pallet, QueuedSolution, RawSolution, ReadySolution,
-	SignedSubmissionIndices, SignedSubmissionNextIndex, SignedSubmissionsMap, SolutionOf,
-	SolutionOrSnapshotSize, Weight, WeightInfo,
+	Config, ElectionCompute, FeasibilityError, PagedSignedSubmissions, PagedSignedSupports, Pallet,
+	QueuedSolution, RawSolution, ReadySolution, SignedSubmissionIndices, SignedSubmissionNextIndex,
+	SignedSubmissionsMap, SolutionOf, SolutionOrSnapshotSize, Weight, WeightInfo,
 };
 use codec::{Decode, Encode, HasCompact};
+use frame_election_provider_support::PageIndex;
 use frame_support::{
+	ensure,
 	storage::bounded_btree_map::BoundedBTreeMap,
 	traits::{Currency, Get, OnUnbalanced, ReservableCurrency},
 };
 use sp_arithmetic::traits::SaturatedConversion;
-use sp_npos_elections::{is_score_better, ElectionScore, NposSolution};
+use sp_npos_elections::{
+	is_score_better, ElectionScore, EvaluateSupport, NposSolution, Support, Supports,
+};
 use sp_runtime::{
 	traits::{Saturating, Zero},
 	RuntimeDebug,
@@ -83,6 +87,20 @@ where
 	}
 }
 
+/// A signed submission that is submitted in pages, see [`Pallet::register_paged`].
+///
+/// Only the metadata of the submission is kept here, the supports of each of its pages are kept
+/// in [`PagedSignedSupports`].
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, Default, scale_info::TypeInfo)]
+pub struct PagedSignedSubmission<Balance> {
+	/// The deposit reserved for this submission.
+	pub deposit: Balance,
+	/// The score claimed for the entire solution.
+	pub claimed_score: ElectionScore,
+	/// The number of pages submitted so far.
+	pub pages: PageIndex,
+}
+
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 pub type PositiveImbalanceOf<T> = <<T as Config>::Currency as Currency<
@@ -91,8 +109,13 @@ pub type PositiveImbalanceOf<T> = <<T as Config>::Currency as Currency<
 pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::NegativeImbalance;
-pub type SignedSubmissionOf<T> =
-	SignedSubmission<<T as frame_system::Config>::AccountId, BalanceOf<T>, SolutionOf<T>>;
+pub type SignedSubmissionOf<T> = SignedSubmission<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	SolutionOf<<T as crate::Config>::MinerConfig>,
+>;
+
+pub type PagedSignedSubmissionOf<T> = PagedSignedSubmission<BalanceOf<T>>;
 
 pub type SubmissionIndicesOf<T> =
 	BoundedBTreeMap<ElectionScore, u32, <T as Config>::SignedMaxSubmissions>;
@@ -405,15 +428,139 @@ impl<T: Config> Pallet<T> {
 		debug_assert!(!SignedSubmissionNextIndex::<T>::exists());
 		debug_assert!(SignedSubmissionsMap::<T>::iter().next().is_none());
 
+		let (paged_weight, found_paged_solution) = Self::finalize_paged_signed_submissions();
+		weight = weight.saturating_add(paged_weight);
+
 		log!(
 			debug,
-			"closed signed phase, found solution? {}, discarded {}",
+			"closed signed phase, found solution? {}, found paged solution? {}, discarded {}",
 			found_solution,
+			found_paged_solution,
 			discarded
 		);
 
+		(weight, found_solution || found_paged_solution)
+	}
+
+	/// Process the paged signed submissions, from best to worse claimed score, after the signed
+	/// submissions are processed.
+	///
+	/// The pages of each submission are aggregated until a feasible one that is better than the
+	/// queued solution is found. It is rewarded and queued, and the infeasible ones processed
+	/// before it are slashed. The rest of the submissions get their deposit back, without being
+	/// checked.
+	///
+	/// This drains the [`PagedSignedSubmissions`] and [`PagedSignedSupports`].
+	pub(crate) fn finalize_paged_signed_submissions() -> (Weight, bool) {
+		let mut submissions = <PagedSignedSubmissions<T>>::drain().collect::<Vec<_>>();
+		let mut weight = T::DbWeight::get()
+			.reads_writes(submissions.len() as u64 + 1, submissions.len() as u64 + 1);
+		let mut found_solution = false;
+
+		// best claimed score first.
+		submissions.sort_by(|(_, a), (_, b)| b.claimed_score.cmp(&a.claimed_score));
+
+		for (who, PagedSignedSubmission { deposit, claimed_score, .. }) in submissions {
+			let pages = <PagedSignedSupports<T>>::drain_prefix(&who).collect::<Vec<_>>();
+			weight = weight.saturating_add(
+				T::DbWeight::get().reads_writes(pages.len() as u64, pages.len() as u64),
+			);
+
+			if found_solution {
+				let _remaining = T::Currency::unreserve(&who, deposit);
+				weight = weight.saturating_add(T::DbWeight::get().writes(1));
+				debug_assert!(_remaining.is_zero());
+				continue
+			}
+
+			// the cost of merging the supports of all pages and evaluating them.
+			let (voters, targets) = pages.iter().flat_map(|(_, supports)| supports).fold(
+				(0u32, 0u32),
+				|(v, t), (_, support)| {
+					(v.saturating_add(support.voters.len() as u32), t.saturating_add(1))
+				},
+			);
+			weight =
+				weight.saturating_add(T::WeightInfo::aggregate_paged_supports(voters, targets));
+
+			match Self::aggregate_paged_supports(pages, claimed_score) {
+				Ok(ready_solution) => {
+					let is_better = Self::queued_solution().map_or(true, |queued| {
+						is_score_better(
+							ready_solution.score,
+							queued.score,
+							T::SolutionImprovementThreshold::get(),
+						)
+					});
+					weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+					if is_better {
+						Self::finalize_signed_phase_accept_solution(
+							ready_solution,
+							&who,
+							deposit,
+							T::SignedRewardBase::get(),
+						);
+						found_solution = true;
+						weight = weight
+							.saturating_add(T::WeightInfo::finalize_signed_phase_accept_solution());
+					} else {
+						// feasible, yet not an improvement.
+						let _remaining = T::Currency::unreserve(&who, deposit);
+						weight = weight.saturating_add(T::DbWeight::get().writes(1));
+						debug_assert!(_remaining.is_zero());
+					}
+				},
+				Err(err) => {
+					log!(debug, "paged signed submission of {:?} is infeasible: {:?}", who, err);
+					Self::finalize_signed_phase_reject_solution(&who, deposit);
+					weight = weight
+						.saturating_add(T::WeightInfo::finalize_signed_phase_reject_solution());
+				},
+			}
+		}
+
+		debug_assert!(<PagedSignedSupports<T>>::iter().next().is_none());
 		(weight, found_solution)
 	}
+
+	/// Aggregate the supports of all the `pages` of a paged signed submission into a
+	/// [`ReadySolution`], ensuring that the solution is complete, has the correct number of winners
+	/// and that the `claimed_score` is correct.
+	pub fn aggregate_paged_supports(
+		pages: Vec<(PageIndex, Supports<T::AccountId>)>,
+		claimed_score: ElectionScore,
+	) -> Result<ReadySolution<T::AccountId>, FeasibilityError> {
+		ensure!(
+			pages.len() as PageIndex == T::Pages::get(),
+			FeasibilityError::IncompletePagedSolution
+		);
+
+		// each voter appears in exactly one page, thus the supports can simply be merged.
+		let mut aggregated: BTreeMap<T::AccountId, Support<T::AccountId>> = BTreeMap::new();
+		for (target, support) in pages.into_iter().flat_map(|(_, supports)| supports) {
+			let entry = aggregated.entry(target).or_default();
+			entry.total = entry.total.saturating_add(support.total);
+			entry.voters.extend(support.voters);
+		}
+		let supports = aggregated.into_iter().collect::<Supports<_>>();
+
+		let desired_targets =
+			Self::desired_targets().ok_or(FeasibilityError::SnapshotUnavailable)?;
+		ensure!(supports.len() as u32 == desired_targets, FeasibilityError::WrongWinnerCount);
+
+		ensure!(
+			Self::minimum_untrusted_score().map_or(true, |min_score| {
+				is_score_better(claimed_score, min_score, sp_runtime::Perbill::zero())
+			}),
+			FeasibilityError::UntrustedScoreTooLow
+		);
+
+		let known_score = supports.evaluate();
+		ensure!(known_score == claimed_score, FeasibilityError::InvalidScore);
+
+		Ok(ReadySolution { supports, score: known_score, compute: ElectionCompute::Signed })
+	}
 	/// Helper function for the case where a solution is accepted in the signed phase.
 	///
 	/// Extracted to facilitate with weight calculation.
@@ -454,7 +601,7 @@ impl<T: Config> Pallet<T> {
 
 	/// The feasibility weight of the given raw solution.
 	pub fn feasibility_weight_of(
-		raw_solution: &RawSolution<SolutionOf<T>>,
+		raw_solution: &RawSolution<SolutionOf<T::MinerConfig>>,
 		size: SolutionOrSnapshotSize,
 	) -> Weight {
 		T::WeightInfo::feasibility_check(
@@ -473,7 +620,7 @@ impl<T: Config> Pallet<T> {
 	/// 2. a per-byte deposit, for renting the state usage.
 	/// 3. a per-weight deposit, for the potential weight usage in an upcoming on_initialize
 	pub fn deposit_for(
-		raw_solution: &RawSolution<SolutionOf<T>>,
+		raw_solution: &RawSolution<SolutionOf<T::MinerConfig>>,
 		size: SolutionOrSnapshotSize,
 	) -> BalanceOf<T> {
 		let encoded_len: u32 = raw_solution.encoded_size().saturated_into();
@@ -488,6 +635,30 @@ impl<T: Config> Pallet<T> {
 			.saturating_add(len_deposit)
 			.saturating_add(weight_deposit)
 	}
+
+	/// The deposit required for a single page of a paged signed submission, on top of the
+	/// [`Config::SignedDepositBase`] reserved upon registration.
+	///
+	/// `size` is the size of the voter snapshot page and the target snapshot.
+	pub fn page_deposit_for(
+		solution: &SolutionOf<T::MinerConfig>,
+		size: SolutionOrSnapshotSize,
+	) -> BalanceOf<T> {
+		let encoded_len: u32 = solution.encoded_size().saturated_into();
+		let encoded_len: BalanceOf<T> = encoded_len.into();
+		let feasibility_weight = T::WeightInfo::feasibility_check(
+			size.voters,
+			size.targets,
+			solution.voter_count() as u32,
+			solution.unique_targets().len() as u32,
+		);
+
+		let len_deposit = T::SignedDepositByte::get().saturating_mul(encoded_len);
+		let weight_deposit =
+			T::SignedDepositWeight::get().saturating_mul(feasibility_weight.saturated_into());
+
+		len_deposit.saturating_add(weight_deposit)
+	}
 }
 
 #[cfg(test)]
@@ -495,8 +666,8 @@ mod tests {
 	use super::*;
 	use crate::{
 		mock::{
-			balances, raw_solution, roll_to, ExtBuilder, MultiPhase, Origin, Runtime,
-			SignedMaxSubmissions, SignedMaxWeight,
+			balances, page_witness, paged_solution, raw_solution, roll_to, ExtBuilder, MultiPhase,
+			Origin, Runtime, SignedMaxSubmissions, SignedMaxWeight,
 		},
 		Error, Phase,
 	};
@@ -939,4 +1110,275 @@ mod tests {
 			assert_storage_noop!(MultiPhase::finalize_signed_phase());
 		})
 	}
+
+	fn submit_pages(who: u64, pages: Vec<SolutionOf<Runtime>>) {
+		for (page, solution) in pages.into_iter().enumerate() {
+			let page = page as PageIndex;
+			assert_ok!(MultiPhase::submit_page(
+				Origin::signed(who),
+				page,
+				Box::new(solution),
+				page_witness(page),
+			));
+		}
+	}
+
+	#[test]
+	fn paged_solution_is_rewarded() {
+		ExtBuilder::default().pages(2, 4).build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let (score, pages) = paged_solution();
+			assert_eq!(pages.len(), 2);
+
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			assert_eq!(balances(&99), (95, 5));
+
+			submit_pages(99, pages);
+			assert_eq!(PagedSignedSubmissions::<Runtime>::get(&99).unwrap().pages, 2);
+
+			assert!(MultiPhase::finalize_signed_phase());
+
+			// the deposit is returned, and the reward is paid.
+			assert_eq!(balances(&99), (100 + 7, 0));
+			let queued = MultiPhase::queued_solution().unwrap();
+			assert_eq!(queued.score, score);
+			assert_eq!(queued.compute, ElectionCompute::Signed);
+			assert_eq!(queued.supports.len(), 2);
+
+			// all of the paged submission is cleared.
+			assert_eq!(PagedSignedSubmissions::<Runtime>::count(), 0);
+			assert!(PagedSignedSupports::<Runtime>::iter().next().is_none());
+		})
+	}
+
+	#[test]
+	fn paged_submission_reserves_deposit_per_page() {
+		ExtBuilder::default().pages(2, 4).signed_deposit(5, 1, 0).build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let (score, pages) = paged_solution();
+			let page_sizes = pages.iter().map(|p| p.encoded_size() as u64).collect::<Vec<_>>();
+
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			assert_eq!(balances(&99), (95, 5));
+
+			submit_pages(99, pages);
+			let deposit = 5 + page_sizes.iter().sum::<u64>();
+			assert_eq!(balances(&99), (100 - deposit, deposit));
+			assert_eq!(PagedSignedSubmissions::<Runtime>::get(&99).unwrap().deposit, deposit);
+
+			assert!(MultiPhase::finalize_signed_phase());
+
+			// the entire deposit is returned.
+			assert_eq!(balances(&99), (100 + 7, 0));
+		})
+	}
+
+	#[test]
+	fn page_deposit_is_slashed_along_with_the_registration() {
+		ExtBuilder::default().pages(2, 4).signed_deposit(5, 1, 0).build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let (score, mut pages) = paged_solution();
+			pages.truncate(1);
+			let deposit = 5 + pages[0].encoded_size() as u64;
+
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			submit_pages(99, pages);
+			assert_eq!(balances(&99), (100 - deposit, deposit));
+
+			assert!(!MultiPhase::finalize_signed_phase());
+			assert_eq!(balances(&99), (100 - deposit, 0));
+		})
+	}
+
+	#[test]
+	fn incomplete_paged_solution_is_slashed() {
+		ExtBuilder::default().pages(2, 4).build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let (score, mut pages) = paged_solution();
+			pages.truncate(1);
+
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			submit_pages(99, pages);
+
+			assert!(!MultiPhase::finalize_signed_phase());
+
+			assert_eq!(balances(&99), (95, 0));
+			assert!(MultiPhase::queued_solution().is_none());
+			assert!(PagedSignedSupports::<Runtime>::iter().next().is_none());
+		})
+	}
+
+	#[test]
+	fn paged_solution_with_wrong_score_is_slashed() {
+		ExtBuilder::default().pages(2, 4).build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let (mut score, pages) = paged_solution();
+			score[0] += 1;
+
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			submit_pages(99, pages);
+
+			assert!(!MultiPhase::finalize_signed_phase());
+
+			assert_eq!(balances(&99), (95, 0));
+			assert!(MultiPhase::queued_solution().is_none());
+		})
+	}
+
+	#[test]
+	fn paged_solution_not_better_than_queued_gets_bond_back() {
+		ExtBuilder::default().pages(2, 4).build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			// a regular submission of the same solution.
+			assert_ok!(submit_with_witness(Origin::signed(999), raw_solution()));
+
+			let (score, pages) = paged_solution();
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			submit_pages(99, pages);
+
+			assert!(MultiPhase::finalize_signed_phase());
+
+			// the regular submission is rewarded, the paged one is not an improvement.
+			assert_eq!(balances(&999), (100 + 7 + 8, 0));
+			assert_eq!(balances(&99), (100, 0));
+		})
+	}
+
+	#[test]
+	fn best_feasible_paged_solution_is_rewarded() {
+		ExtBuilder::default().pages(2, 4).build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let (score, pages) = paged_solution();
+
+			// an infeasible solution with a better claimed score, ..
+			let mut better_score = score;
+			better_score[0] += 1;
+			assert_ok!(MultiPhase::register_paged(Origin::signed(999), better_score));
+			submit_pages(999, pages.clone());
+
+			// .. a feasible one, ..
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			submit_pages(99, pages.clone());
+
+			// .. and an incomplete one with a worse claimed score.
+			let mut worse_score = score;
+			worse_score[0] -= 1;
+			assert_ok!(MultiPhase::register_paged(Origin::signed(9999), worse_score));
+			submit_pages(9999, pages.into_iter().take(1).collect());
+
+			assert!(MultiPhase::finalize_signed_phase());
+
+			assert_eq!(balances(&999), (95, 0));
+			assert_eq!(balances(&99), (100 + 7, 0));
+			// never checked.
+			assert_eq!(balances(&9999), (100, 0));
+			assert_eq!(MultiPhase::queued_solution().unwrap().score, score);
+		})
+	}
+
+	#[test]
+	fn paged_submission_checks_work() {
+		ExtBuilder::default()
+			.pages(2, 4)
+			.signed_max_submission(1)
+			.build_and_execute(|| {
+				roll_to(14);
+				assert!(MultiPhase::current_phase().is_snapshot());
+				assert_noop!(
+					MultiPhase::register_paged(Origin::signed(99), Default::default()),
+					Error::<Runtime>::PreDispatchEarlySubmission,
+				);
+
+				roll_to(15);
+				assert!(MultiPhase::current_phase().is_signed());
+				let (score, pages) = paged_solution();
+
+				// must register first.
+				assert_noop!(
+					MultiPhase::submit_page(
+						Origin::signed(99),
+						0,
+						Box::new(pages[0].clone()),
+						page_witness(0)
+					),
+					Error::<Runtime>::SignedPagedNotRegistered,
+				);
+
+				assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+				assert_noop!(
+					MultiPhase::register_paged(Origin::signed(99), score),
+					Error::<Runtime>::SignedPagedAlreadyRegistered,
+				);
+				assert_noop!(
+					MultiPhase::register_paged(Origin::signed(999), score),
+					Error::<Runtime>::SignedQueueFull,
+				);
+
+				// page out of bounds.
+				assert_noop!(
+					MultiPhase::submit_page(
+						Origin::signed(99),
+						2,
+						Box::new(pages[0].clone()),
+						page_witness(0)
+					),
+					Error::<Runtime>::SignedPagedInvalidPage,
+				);
+
+				// wrong witness.
+				assert_noop!(
+					MultiPhase::submit_page(
+						Origin::signed(99),
+						0,
+						Box::new(pages[0].clone()),
+						Default::default()
+					),
+					Error::<Runtime>::SignedInvalidWitness,
+				);
+
+				// a voter that is not in the page.
+				let mut invalid = pages[0].clone();
+				invalid.votes1.push((10, 0));
+				assert_noop!(
+					MultiPhase::submit_page(
+						Origin::signed(99),
+						0,
+						Box::new(invalid),
+						page_witness(0)
+					),
+					Error::<Runtime>::SignedPagedInfeasible,
+				);
+
+				// each page only once.
+				assert_ok!(MultiPhase::submit_page(
+					Origin::signed(99),
+					0,
+					Box::new(pages[0].clone()),
+					page_witness(0)
+				));
+				assert_noop!(
+					MultiPhase::submit_page(
+						Origin::signed(99),
+						0,
+						Box::new(pages[0].clone()),
+						page_witness(0)
+					),
+					Error::<Runtime>::SignedPagedDuplicatePage,
+				);
+			})
+	}
 }
@@ -20,16 +20,16 @@
 use crate::{
 	helpers, Call, Config, ElectionCompute, Error, FeasibilityError, Pallet, RawSolution,
 	ReadySolution, RoundSnapshot, SolutionAccuracyOf, SolutionOf, SolutionOrSnapshotSize, Weight,
-	WeightInfo,
 };
 use codec::Encode;
 use frame_election_provider_support::{NposSolver, PerThing128};
 use frame_support::{dispatch::DispatchResult, ensure, traits::Get};
 use frame_system::offchain::SubmitTransaction;
+use scale_info::TypeInfo;
 use sp_arithmetic::Perbill;
 use sp_npos_elections::{
 	assignment_ratio_to_staked_normalized, assignment_staked_to_ratio_normalized, is_score_better,
-	ElectionResult, NposSolution,
+	ElectionResult, ElectionScore, IdentifierT, NposSolution, VoteWeight,
 };
 use sp_runtime::{
 	offchain::storage::{MutateStorageError, StorageValueRef},
@@ -53,19 +53,21 @@ pub type Voter<T> = (
 	Vec<<T as frame_system::Config>::AccountId>,
 );
 
+/// Same as [`Voter`], but parameterized by a [`MinerConfig`] rather than a runtime.
+pub type MinerVoterOf<T> =
+	(<T as MinerConfig>::AccountId, VoteWeight, Vec<<T as MinerConfig>::AccountId>);
+
 /// The relative distribution of a voter's stake among the winning targets.
 pub type Assignment<T> =
-	sp_npos_elections::Assignment<<T as frame_system::Config>::AccountId, SolutionAccuracyOf<T>>;
+	sp_npos_elections::Assignment<<T as MinerConfig>::AccountId, SolutionAccuracyOf<T>>;
 
 /// The [`IndexAssignment`][sp_npos_elections::IndexAssignment] type specialized for a particular
-/// runtime `T`.
+/// miner configuration `T`.
 pub type IndexAssignmentOf<T> = sp_npos_elections::IndexAssignmentOf<SolutionOf<T>>;
 
-/// Error type of the pallet's [`crate::Config::Solver`].
-pub type SolverErrorOf<T> = <<T as Config>::Solver as NposSolver>::Error;
 /// Error type for operations related to the OCW npos solution miner.
-#[derive(frame_support::DebugNoBound, frame_support::PartialEqNoBound)]
-pub enum MinerError<T: Config> {
+#[derive(Debug, PartialEq)]
+pub enum MinerError {
 	/// An internal error in the NPoS elections crate.
 	NposElections(sp_npos_elections::Error),
 	/// Snapshot data was unavailable unexpectedly.
@@ -87,23 +89,23 @@ pub enum MinerError<T: Config> {
 	/// There are no more voters to remove to trim the solution.
 	NoMoreVoters,
 	/// An error from the solver.
-	Solver(SolverErrorOf<T>),
+	Solver,
 }
 
-impl<T: Config> From<sp_npos_elections::Error> for MinerError<T> {
+impl From<sp_npos_elections::Error> for MinerError {
 	fn from(e: sp_npos_elections::Error) -> Self {
 		MinerError::NposElections(e)
 	}
 }
 
-impl<T: Config> From<FeasibilityError> for MinerError<T> {
+impl From<FeasibilityError> for MinerError {
 	fn from(e: FeasibilityError) -> Self {
 		MinerError::Feasibility(e)
 	}
 }
 
 /// Save a given call into OCW storage.
-fn save_solution<T: Config>(call: &Call<T>) -> Result<(), MinerError<T>> {
+fn save_solution<T: Config>(call: &Call<T>) -> Result<(), MinerError> {
 	log!(debug, "saving a call to the offchain storage.");
 	let storage = StorageValueRef::persistent(&OFFCHAIN_CACHED_CALL);
 	match storage.mutate::<_, (), _>(|_| Ok(call.clone())) {
@@ -121,7 +123,7 @@ fn save_solution<T: Config>(call: &Call<T>) -> Result<(), MinerError<T>> {
 }
 
 /// Get a saved solution from OCW storage if it exists.
-fn restore_solution<T: Config>() -> Result<Call<T>, MinerError<T>> {
+fn restore_solution<T: Config>() -> Result<Call<T>, MinerError> {
 	StorageValueRef::persistent(&OFFCHAIN_CACHED_CALL)
 		.get()
 		.ok()
@@ -154,7 +156,7 @@ fn ocw_solution_exists<T: Config>() -> bool {
 impl<T: Config> Pallet<T> {
 	/// Attempt to restore a solution from cache. Otherwise, compute it fresh. Either way, submit
 	/// if our call's score is greater than that of the cached solution.
-	pub fn restore_or_compute_then_maybe_submit() -> Result<(), MinerError<T>> {
+	pub fn restore_or_compute_then_maybe_submit() -> Result<(), MinerError> {
 		log!(debug, "miner attempting to restore or compute an unsigned solution.");
 
 		let call = restore_solution::<T>()
@@ -168,7 +170,7 @@ impl<T: Config> Pallet<T> {
 					Err(MinerError::SolutionCallInvalid)
 				}
 			})
-			.or_else::<MinerError<T>, _>(|error| {
+			.or_else::<MinerError, _>(|error| {
 				log!(debug, "restoring solution failed due to {:?}", error);
 				match error {
 					MinerError::NoStoredSolution => {
@@ -199,7 +201,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Mine a new solution, cache it, and submit it back to the chain as an unsigned transaction.
-	pub fn mine_check_save_submit() -> Result<(), MinerError<T>> {
+	pub fn mine_check_save_submit() -> Result<(), MinerError> {
 		log!(debug, "miner attempting to compute an unsigned solution.");
 
 		let call = Self::mine_checked_call()?;
@@ -208,7 +210,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Mine a new solution as a call. Performs all checks.
-	pub fn mine_checked_call() -> Result<Call<T>, MinerError<T>> {
+	pub fn mine_checked_call() -> Result<Call<T>, MinerError> {
 		// get the solution, with a load of checks to ensure if submitted, IT IS ABSOLUTELY VALID.
 		let (raw_solution, witness) = Self::mine_and_check()?;
 
@@ -226,7 +228,7 @@ impl<T: Config> Pallet<T> {
 		Ok(call)
 	}
 
-	fn submit_call(call: Call<T>) -> Result<(), MinerError<T>> {
+	fn submit_call(call: Call<T>) -> Result<(), MinerError> {
 		log!(debug, "miner submitting a solution as an unsigned transaction");
 
 		SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
@@ -237,9 +239,9 @@ impl<T: Config> Pallet<T> {
 	//
 	// Performance: note that it internally clones the provided solution.
 	pub fn basic_checks(
-		raw_solution: &RawSolution<SolutionOf<T>>,
+		raw_solution: &RawSolution<SolutionOf<T::MinerConfig>>,
 		solution_type: &str,
-	) -> Result<(), MinerError<T>> {
+	) -> Result<(), MinerError> {
 		Self::unsigned_pre_dispatch_checks(raw_solution).map_err(|err| {
 			log!(debug, "pre-dispatch checks failed for {} solution: {:?}", solution_type, err);
 			MinerError::PreDispatchChecksFailed(err)
@@ -262,7 +264,7 @@ impl<T: Config> Pallet<T> {
 	/// If you want a checked solution and submit it at the same time, use
 	/// [`Pallet::mine_check_save_submit`].
 	pub fn mine_and_check(
-	) -> Result<(RawSolution<SolutionOf<T>>, SolutionOrSnapshotSize), MinerError<T>> {
+	) -> Result<(RawSolution<SolutionOf<T::MinerConfig>>, SolutionOrSnapshotSize), MinerError> {
 		let (raw_solution, witness) = Self::mine_solution::<T::Solver>()?;
 		Self::basic_checks(&raw_solution, "mined")?;
 		Ok((raw_solution, witness))
@@ -270,20 +272,23 @@ impl<T: Config> Pallet<T> {
 
 	/// Mine a new npos solution.
 	///
-	/// The Npos Solver type, `S`, must have the same AccountId and Error type as the
-	/// [`crate::Config::Solver`] in order to create a unified return type.
+	/// The Npos Solver type, `S`, must have the same AccountId as the [`crate::Config::Solver`].
 	pub fn mine_solution<S>(
-	) -> Result<(RawSolution<SolutionOf<T>>, SolutionOrSnapshotSize), MinerError<T>>
+	) -> Result<(RawSolution<SolutionOf<T::MinerConfig>>, SolutionOrSnapshotSize), MinerError>
 	where
-		S: NposSolver<AccountId = T::AccountId, Error = SolverErrorOf<T>>,
+		S: NposSolver<AccountId = T::AccountId>,
 	{
 		let RoundSnapshot { voters, targets } =
 			Self::snapshot().ok_or(MinerError::SnapshotUnAvailable)?;
 		let desired_targets = Self::desired_targets().ok_or(MinerError::SnapshotUnAvailable)?;
 
-		S::solve(desired_targets as usize, targets, voters)
-			.map_err(|e| MinerError::Solver::<T>(e))
-			.and_then(|e| Self::prepare_election_result::<S::Accuracy>(e))
+		let (solution, score, size) = Miner::<T::MinerConfig>::mine_solution_with_snapshot::<S>(
+			voters,
+			targets,
+			desired_targets,
+		)?;
+		let round = Self::round();
+		Ok((RawSolution { solution, score, round }, size))
 	}
 
 	/// Convert a raw solution from [`sp_npos_elections::ElectionResult`] to [`RawSolution`], which
@@ -292,15 +297,184 @@ impl<T: Config> Pallet<T> {
 	/// Will always reduce the solution as well.
 	pub fn prepare_election_result<Accuracy: PerThing128>(
 		election_result: ElectionResult<T::AccountId, Accuracy>,
-	) -> Result<(RawSolution<SolutionOf<T>>, SolutionOrSnapshotSize), MinerError<T>> {
-		// NOTE: This code path is generally not optimized as it is run offchain. Could use some at
-		// some point though.
-
+	) -> Result<(RawSolution<SolutionOf<T::MinerConfig>>, SolutionOrSnapshotSize), MinerError> {
 		// storage items. Note: we have already read this from storage, they must be in cache.
 		let RoundSnapshot { voters, targets } =
 			Self::snapshot().ok_or(MinerError::SnapshotUnAvailable)?;
 		let desired_targets = Self::desired_targets().ok_or(MinerError::SnapshotUnAvailable)?;
 
+		let (solution, score, size) =
+			Miner::<T::MinerConfig>::prepare_election_result_with_snapshot::<Accuracy>(
+				election_result,
+				voters,
+				targets,
+				desired_targets,
+			)?;
+		let round = Self::round();
+		Ok((RawSolution { solution, score, round }, size))
+	}
+
+	/// Checks if an execution of the offchain worker is permitted at the given block number, or
+	/// not.
+	///
+	/// This makes sure that
+	/// 1. we don't run on previous blocks in case of a re-org
+	/// 2. we don't run twice within a window of length `T::OffchainRepeat`.
+	///
+	/// Returns `Ok(())` if offchain worker limit is respected, `Err(reason)` otherwise. If `Ok()`
+	/// is returned, `now` is written in storage and will be used in further calls as the baseline.
+	pub fn ensure_offchain_repeat_frequency(now: T::BlockNumber) -> Result<(), MinerError> {
+		let threshold = T::OffchainRepeat::get();
+		let last_block = StorageValueRef::persistent(&OFFCHAIN_LAST_BLOCK);
+
+		let mutate_stat = last_block.mutate::<_, &'static str, _>(
+			|maybe_head: Result<Option<T::BlockNumber>, _>| {
+				match maybe_head {
+					Ok(Some(head)) if now < head => Err("fork."),
+					Ok(Some(head)) if now >= head && now <= head + threshold =>
+						Err("recently executed."),
+					Ok(Some(head)) if now > head + threshold => {
+						// we can run again now. Write the new head.
+						Ok(now)
+					},
+					_ => {
+						// value doesn't exists. Probably this node just booted up. Write, and run
+						Ok(now)
+					},
+				}
+			},
+		);
+
+		match mutate_stat {
+			// all good
+			Ok(_) => Ok(()),
+			// failed to write.
+			Err(MutateStorageError::ConcurrentModification(_)) =>
+				Err(MinerError::Lock("failed to write to offchain db (concurrent modification).")),
+			// fork etc.
+			Err(MutateStorageError::ValueFunctionFailed(why)) => Err(MinerError::Lock(why)),
+		}
+	}
+
+	/// Do the basics checks that MUST happen during the validation and pre-dispatch of an unsigned
+	/// transaction.
+	///
+	/// Can optionally also be called during dispatch, if needed.
+	///
+	/// NOTE: Ideally, these tests should move more and more outside of this and more to the miner's
+	/// code, so that we do less and less storage reads here.
+	pub fn unsigned_pre_dispatch_checks(
+		raw_solution: &RawSolution<SolutionOf<T::MinerConfig>>,
+	) -> DispatchResult {
+		// ensure solution is timely. Don't panic yet. This is a cheap check.
+		ensure!(Self::current_phase().is_unsigned_open(), Error::<T>::PreDispatchEarlySubmission);
+
+		// ensure round is current
+		ensure!(Self::round() == raw_solution.round, Error::<T>::OcwCallWrongEra);
+
+		// ensure correct number of winners.
+		ensure!(
+			Self::desired_targets().unwrap_or_default() ==
+				raw_solution.solution.unique_targets().len() as u32,
+			Error::<T>::PreDispatchWrongWinnerCount,
+		);
+
+		// ensure score is being improved. Panic henceforth.
+		ensure!(
+			Self::queued_solution().map_or(true, |q: ReadySolution<_>| is_score_better::<Perbill>(
+				raw_solution.score,
+				q.score,
+				T::SolutionImprovementThreshold::get()
+			)),
+			Error::<T>::PreDispatchWeakSubmission,
+		);
+
+		Ok(())
+	}
+}
+
+/// Configurations for a miner that comes with this pallet.
+///
+/// This is decoupled from [`crate::Config`] so that the miner can be used outside of the runtime,
+/// e.g. by an external tool that mines over a snapshot scraped from a remote node.
+pub trait MinerConfig {
+	/// The account id type.
+	type AccountId: IdentifierT;
+	/// The solution that the miner is mining.
+	type Solution: codec::Codec
+		+ Default
+		+ PartialEq
+		+ Eq
+		+ Clone
+		+ sp_std::fmt::Debug
+		+ Ord
+		+ NposSolution
+		+ TypeInfo;
+	/// Maximum length (bytes) that the mined solution should consume.
+	///
+	/// The miner will ensure that the total length of the solution will not exceed this value.
+	type MaxLength: Get<u32>;
+	/// Maximum weight that the miner should consume.
+	///
+	/// The miner will ensure that the total weight of the solution will not exceed this value,
+	/// based on [`MinerConfig::solution_weight`].
+	type MaxWeight: Get<Weight>;
+
+	/// Something that can compute the weight of a solution.
+	///
+	/// This weight estimate is then used to trim the solution, based on
+	/// [`MinerConfig::MaxWeight`].
+	fn solution_weight(voters: u32, targets: u32, active_voters: u32, degree: u32) -> Weight;
+}
+
+/// A base miner, generic over a [`MinerConfig`].
+///
+/// It does not read from any storage, and can therefore be used both by the offchain worker of
+/// this pallet and by external miners.
+pub struct Miner<T: MinerConfig>(sp_std::marker::PhantomData<T>);
+
+impl<T: MinerConfig> Miner<T> {
+	/// Mine a new npos solution, over the given snapshot, with the given solver `S`.
+	///
+	/// The returned solution is reduced and trimmed to fit into [`MinerConfig::MaxWeight`] and
+	/// [`MinerConfig::MaxLength`].
+	pub fn mine_solution_with_snapshot<S>(
+		voters: Vec<MinerVoterOf<T>>,
+		targets: Vec<T::AccountId>,
+		desired_targets: u32,
+	) -> Result<(SolutionOf<T>, ElectionScore, SolutionOrSnapshotSize), MinerError>
+	where
+		S: NposSolver<AccountId = T::AccountId>,
+	{
+		S::solve(desired_targets as usize, targets.clone(), voters.clone())
+			.map_err(|e| {
+				log_no_system!(error, "solver error: {:?}", e);
+				MinerError::Solver
+			})
+			.and_then(|e| {
+				Self::prepare_election_result_with_snapshot::<S::Accuracy>(
+					e,
+					voters,
+					targets,
+					desired_targets,
+				)
+			})
+	}
+
+	/// Convert a raw solution from [`sp_npos_elections::ElectionResult`] to a solution of type
+	/// [`MinerConfig::Solution`], computed over the given snapshot.
+	///
+	/// Will always reduce the solution as well, and trim it to fit into [`MinerConfig::MaxWeight`]
+	/// and [`MinerConfig::MaxLength`].
+	pub fn prepare_election_result_with_snapshot<Accuracy: PerThing128>(
+		election_result: ElectionResult<T::AccountId, Accuracy>,
+		voters: Vec<MinerVoterOf<T>>,
+		targets: Vec<T::AccountId>,
+		desired_targets: u32,
+	) -> Result<(SolutionOf<T>, ElectionScore, SolutionOrSnapshotSize), MinerError> {
+		// NOTE: This code path is generally not optimized as it is run offchain. Could use some at
+		// some point though.
+
 		// now make some helper closures.
 		let cache = helpers::generate_voter_cache::<T>(&voters);
 		let voter_index = helpers::voter_index_fn::<T>(&cache);
@@ -362,11 +536,11 @@ impl<T: Config> Pallet<T> {
 		Self::trim_assignments_weight(
 			desired_targets,
 			size,
-			T::MinerMaxWeight::get(),
+			T::MaxWeight::get(),
 			&mut index_assignments,
 		);
 		Self::trim_assignments_length(
-			T::MinerMaxLength::get(),
+			T::MaxLength::get(),
 			&mut index_assignments,
 			&encoded_size_of,
 		)?;
@@ -377,8 +551,7 @@ impl<T: Config> Pallet<T> {
 		// re-calc score.
 		let score = solution.clone().score(stake_of, voter_at, target_at)?;
 
-		let round = Self::round();
-		Ok((RawSolution { solution, score, round }, size))
+		Ok((solution, score, size))
 	}
 
 	/// Greedily reduce the size of the solution to fit into the block w.r.t. weight.
@@ -403,10 +576,10 @@ impl<T: Config> Pallet<T> {
 		assignments: &mut Vec<IndexAssignmentOf<T>>,
 	) {
 		let maximum_allowed_voters =
-			Self::maximum_voter_for_weight::<T::WeightInfo>(desired_targets, size, max_weight);
+			Self::maximum_voter_for_weight(desired_targets, size, max_weight);
 		let removing: usize =
 			assignments.len().saturating_sub(maximum_allowed_voters.saturated_into());
-		log!(
+		log_no_system!(
 			debug,
 			"from {} assignments, truncating to {} for weight, removing {}",
 			assignments.len(),
@@ -434,7 +607,7 @@ impl<T: Config> Pallet<T> {
 		max_allowed_length: u32,
 		assignments: &mut Vec<IndexAssignmentOf<T>>,
 		encoded_size_of: impl Fn(&[IndexAssignmentOf<T>]) -> Result<usize, sp_npos_elections::Error>,
-	) -> Result<(), MinerError<T>> {
+	) -> Result<(), MinerError> {
 		// Perform a binary search for the max subset of which can fit into the allowed
 		// length. Having discovered that, we can truncate efficiently.
 		let max_allowed_length: usize = max_allowed_length.saturated_into();
@@ -477,7 +650,7 @@ impl<T: Config> Pallet<T> {
 		// after this point, we never error.
 		// check before edit.
 
-		log!(
+		log_no_system!(
 			debug,
 			"from {} assignments, truncating to {} for length, removing {}",
 			assignments.len(),
@@ -492,7 +665,7 @@ impl<T: Config> Pallet<T> {
 	/// Find the maximum `len` that a solution can have in order to fit into the block weight.
 	///
 	/// This only returns a value between zero and `size.nominators`.
-	pub fn maximum_voter_for_weight<W: WeightInfo>(
+	pub fn maximum_voter_for_weight(
 		desired_winners: u32,
 		size: SolutionOrSnapshotSize,
 		max_weight: Weight,
@@ -506,7 +679,7 @@ impl<T: Config> Pallet<T> {
 
 		// helper closures.
 		let weight_with = |active_voters: u32| -> Weight {
-			W::submit_unsigned(size.voters, size.targets, active_voters, desired_winners)
+			T::solution_weight(size.voters, size.targets, active_voters, desired_winners)
 		};
 
 		let next_voters = |current_weight: Weight, voters: u32, step: u32| -> Result<u32, ()> {
@@ -560,178 +733,78 @@ impl<T: Config> Pallet<T> {
 		);
 		final_decision
 	}
-
-	/// Checks if an execution of the offchain worker is permitted at the given block number, or
-	/// not.
-	///
-	/// This makes sure that
-	/// 1. we don't run on previous blocks in case of a re-org
-	/// 2. we don't run twice within a window of length `T::OffchainRepeat`.
-	///
-	/// Returns `Ok(())` if offchain worker limit is respected, `Err(reason)` otherwise. If `Ok()`
-	/// is returned, `now` is written in storage and will be used in further calls as the baseline.
-	pub fn ensure_offchain_repeat_frequency(now: T::BlockNumber) -> Result<(), MinerError<T>> {
-		let threshold = T::OffchainRepeat::get();
-		let last_block = StorageValueRef::persistent(&OFFCHAIN_LAST_BLOCK);
-
-		let mutate_stat = last_block.mutate::<_, &'static str, _>(
-			|maybe_head: Result<Option<T::BlockNumber>, _>| {
-				match maybe_head {
-					Ok(Some(head)) if now < head => Err("fork."),
-					Ok(Some(head)) if now >= head && now <= head + threshold =>
-						Err("recently executed."),
-					Ok(Some(head)) if now > head + threshold => {
-						// we can run again now. Write the new head.
-						Ok(now)
-					},
-					_ => {
-						// value doesn't exists. Probably this node just booted up. Write, and run
-						Ok(now)
-					},
-				}
-			},
-		);
-
-		match mutate_stat {
-			// all good
-			Ok(_) => Ok(()),
-			// failed to write.
-			Err(MutateStorageError::ConcurrentModification(_)) =>
-				Err(MinerError::Lock("failed to write to offchain db (concurrent modification).")),
-			// fork etc.
-			Err(MutateStorageError::ValueFunctionFailed(why)) => Err(MinerError::Lock(why)),
-		}
-	}
-
-	/// Do the basics checks that MUST happen during the validation and pre-dispatch of an unsigned
-	/// transaction.
-	///
-	/// Can optionally also be called during dispatch, if needed.
-	///
-	/// NOTE: Ideally, these tests should move more and more outside of this and more to the miner's
-	/// code, so that we do less and less storage reads here.
-	pub fn unsigned_pre_dispatch_checks(
-		raw_solution: &RawSolution<SolutionOf<T>>,
-	) -> DispatchResult {
-		// ensure solution is timely. Don't panic yet. This is a cheap check.
-		ensure!(Self::current_phase().is_unsigned_open(), Error::<T>::PreDispatchEarlySubmission);
-
-		// ensure round is current
-		ensure!(Self::round() == raw_solution.round, Error::<T>::OcwCallWrongEra);
-
-		// ensure correct number of winners.
-		ensure!(
-			Self::desired_targets().unwrap_or_default() ==
-				raw_solution.solution.unique_targets().len() as u32,
-			Error::<T>::PreDispatchWrongWinnerCount,
-		);
-
-		// ensure score is being improved. Panic henceforth.
-		ensure!(
-			Self::queued_solution().map_or(true, |q: ReadySolution<_>| is_score_better::<Perbill>(
-				raw_solution.score,
-				q.score,
-				T::SolutionImprovementThreshold::get()
-			)),
-			Error::<T>::PreDispatchWeakSubmission,
-		);
-
-		Ok(())
-	}
 }
 
 #[cfg(test)]
 mod max_weight {
 	#![allow(unused_variables)]
 	use super::*;
-	use crate::mock::MultiPhase;
+	use crate::mock::TestNposSolution;
 
-	struct TestWeight;
-	impl crate::weights::WeightInfo for TestWeight {
-		fn elect_queued(a: u32, d: u32) -> Weight {
-			unreachable!()
-		}
-		fn create_snapshot_internal(v: u32, t: u32) -> Weight {
-			unreachable!()
-		}
-		fn on_initialize_nothing() -> Weight {
-			unreachable!()
-		}
-		fn on_initialize_open_signed() -> Weight {
-			unreachable!()
-		}
-		fn on_initialize_open_unsigned() -> Weight {
-			unreachable!()
-		}
-		fn finalize_signed_phase_accept_solution() -> Weight {
-			unreachable!()
-		}
-		fn finalize_signed_phase_reject_solution() -> Weight {
-			unreachable!()
-		}
-		fn submit(c: u32) -> Weight {
-			unreachable!()
-		}
-		fn submit_unsigned(v: u32, t: u32, a: u32, d: u32) -> Weight {
+	struct TestMinerConfig;
+	impl MinerConfig for TestMinerConfig {
+		type AccountId = u64;
+		type Solution = TestNposSolution;
+		type MaxLength = ();
+		type MaxWeight = ();
+
+		fn solution_weight(v: u32, t: u32, a: u32, d: u32) -> Weight {
 			(0 * v + 0 * t + 1000 * a + 0 * d) as Weight
 		}
-		fn feasibility_check(v: u32, _t: u32, a: u32, d: u32) -> Weight {
-			unreachable!()
-		}
 	}
 
 	#[test]
 	fn find_max_voter_binary_search_works() {
 		let w = SolutionOrSnapshotSize { voters: 10, targets: 0 };
 
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 0), 0);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 1), 0);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 999), 0);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 1000), 1);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 1001), 1);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 1990), 1);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 1999), 1);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 2000), 2);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 2001), 2);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 2010), 2);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 2990), 2);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 2999), 2);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 3000), 3);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 3333), 3);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 5500), 5);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 7777), 7);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 9999), 9);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 10_000), 10);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 10_999), 10);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 11_000), 10);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 22_000), 10);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 0), 0);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 1), 0);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 999), 0);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 1000), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 1001), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 1990), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 1999), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 2000), 2);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 2001), 2);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 2010), 2);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 2990), 2);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 2999), 2);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 3000), 3);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 3333), 3);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 5500), 5);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 7777), 7);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 9999), 9);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 10_000), 10);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 10_999), 10);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 11_000), 10);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 22_000), 10);
 
 		let w = SolutionOrSnapshotSize { voters: 1, targets: 0 };
 
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 0), 0);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 1), 0);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 999), 0);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 1000), 1);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 1001), 1);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 1990), 1);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 1999), 1);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 2000), 1);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 2001), 1);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 2010), 1);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 3333), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 0), 0);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 1), 0);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 999), 0);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 1000), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 1001), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 1990), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 1999), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 2000), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 2001), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 2010), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 3333), 1);
 
 		let w = SolutionOrSnapshotSize { voters: 2, targets: 0 };
 
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 0), 0);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 1), 0);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 999), 0);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 1000), 1);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 1001), 1);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 1999), 1);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 2000), 2);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 2001), 2);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 2010), 2);
-		assert_eq!(MultiPhase::maximum_voter_for_weight::<TestWeight>(0, w, 3333), 2);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 0), 0);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 1), 0);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 999), 0);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 1000), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 1001), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 1999), 1);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 2000), 2);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 2001), 2);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 2010), 2);
+		assert_eq!(Miner::<TestMinerConfig>::maximum_voter_for_weight(0, w, 3333), 2);
 	}
 }
 
@@ -745,7 +818,7 @@ mod tests {
 			TestNposSolution, TrimHelpers, UnsignedPhase,
 		},
 		CurrentPhase, InvalidTransaction, Phase, QueuedSolution, TransactionSource,
-		TransactionValidityError,
+		TransactionValidityError, WeightInfo,
 	};
 	use codec::Decode;
 	use frame_benchmarking::Zero;
@@ -1445,8 +1518,12 @@ mod tests {
 			let solution_clone = solution.clone();
 
 			// when
-			MultiPhase::trim_assignments_length(encoded_len, &mut assignments, encoded_size_of)
-				.unwrap();
+			Miner::<Runtime>::trim_assignments_length(
+				encoded_len,
+				&mut assignments,
+				encoded_size_of,
+			)
+			.unwrap();
 
 			// then
 			let solution = SolutionOf::<Runtime>::try_from(assignments.as_slice()).unwrap();
@@ -1466,7 +1543,7 @@ mod tests {
 			let solution_clone = solution.clone();
 
 			// when
-			MultiPhase::trim_assignments_length(
+			Miner::<Runtime>::trim_assignments_length(
 				encoded_len as u32 - 1,
 				&mut assignments,
 				encoded_size_of,
@@ -1499,8 +1576,12 @@ mod tests {
 				.unwrap();
 
 			// when
-			MultiPhase::trim_assignments_length(encoded_len - 1, &mut assignments, encoded_size_of)
-				.unwrap();
+			Miner::<Runtime>::trim_assignments_length(
+				encoded_len - 1,
+				&mut assignments,
+				encoded_size_of,
+			)
+			.unwrap();
 
 			// then
 			assert_eq!(assignments.len(), count - 1, "we must have removed exactly one assignment");
@@ -1527,11 +1608,11 @@ mod tests {
 			assert_eq!(min_solution_size, SolutionOf::<Runtime>::LIMIT);
 
 			// all of this should not panic.
-			MultiPhase::trim_assignments_length(0, &mut assignments, encoded_size_of.clone())
+			Miner::<Runtime>::trim_assignments_length(0, &mut assignments, encoded_size_of.clone())
 				.unwrap();
-			MultiPhase::trim_assignments_length(1, &mut assignments, encoded_size_of.clone())
+			Miner::<Runtime>::trim_assignments_length(1, &mut assignments, encoded_size_of.clone())
 				.unwrap();
-			MultiPhase::trim_assignments_length(
+			Miner::<Runtime>::trim_assignments_length(
 				min_solution_size as u32,
 				&mut assignments,
 				encoded_size_of,
@@ -1548,7 +1629,7 @@ mod tests {
 
 			// trim to min solution size.
 			let min_solution_size = SolutionOf::<Runtime>::LIMIT as u32;
-			MultiPhase::trim_assignments_length(
+			Miner::<Runtime>::trim_assignments_length(
 				min_solution_size,
 				&mut assignments,
 				encoded_size_of,
@@ -1568,16 +1649,62 @@ mod tests {
 
 			// how long would the default solution be?
 			let solution = MultiPhase::mine_solution::<<Runtime as Config>::Solver>().unwrap();
-			let max_length = <Runtime as Config>::MinerMaxLength::get();
+			let max_length = <Runtime as MinerConfig>::MaxLength::get();
 			let solution_size = solution.0.solution.encoded_size();
 			assert!(solution_size <= max_length as usize);
 
 			// now set the max size to less than the actual size and regenerate
-			<Runtime as Config>::MinerMaxLength::set(solution_size as u32 - 1);
+			<Runtime as MinerConfig>::MaxLength::set(solution_size as u32 - 1);
 			let solution = MultiPhase::mine_solution::<<Runtime as Config>::Solver>().unwrap();
-			let max_length = <Runtime as Config>::MinerMaxLength::get();
+			let max_length = <Runtime as MinerConfig>::MaxLength::get();
 			let solution_size = solution.0.solution.encoded_size();
 			assert!(solution_size <= max_length as usize);
 		});
 	}
+
+	#[test]
+	fn miner_trims_by_weight_and_length_given_a_snapshot() {
+		ExtBuilder::default().mock_weight_info(true).build_and_execute(|| {
+			roll_to(25);
+
+			let RoundSnapshot { voters, targets } = MultiPhase::snapshot().unwrap();
+			let desired_targets = MultiPhase::desired_targets().unwrap();
+
+			// the miner works on the given snapshot alone, and gives the same result as the pallet.
+			let (solution, score, size) = Miner::<Runtime>::mine_solution_with_snapshot::<
+				<Runtime as Config>::Solver,
+			>(voters.clone(), targets.clone(), desired_targets)
+			.unwrap();
+			let (raw, witness) =
+				MultiPhase::mine_solution::<<Runtime as Config>::Solver>().unwrap();
+			assert_eq!(raw.solution, solution);
+			assert_eq!(raw.score, score);
+			assert_eq!(witness, size);
+			assert_eq!(solution.voter_count(), 5);
+
+			// limit both the weight and the length.
+			<MinerMaxWeight>::set(<Runtime as MinerConfig>::solution_weight(
+				size.voters,
+				size.targets,
+				4,
+				desired_targets,
+			));
+			<Runtime as MinerConfig>::MaxLength::set(solution.encoded_size() as u32 - 1);
+
+			let (trimmed, _, _) = Miner::<Runtime>::mine_solution_with_snapshot::<
+				<Runtime as Config>::Solver,
+			>(voters, targets, desired_targets)
+			.unwrap();
+			assert!(trimmed.voter_count() <= 4);
+			assert!(trimmed.encoded_size() < solution.encoded_size());
+			assert!(
+				<Runtime as MinerConfig>::solution_weight(
+					size.voters,
+					size.targets,
+					trimmed.voter_count() as u32,
+					desired_targets,
+				) <= <Runtime as MinerConfig>::MaxWeight::get()
+			);
+		});
+	}
 }
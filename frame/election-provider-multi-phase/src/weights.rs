@@ -55,6 +55,8 @@ pub trait WeightInfo {
 	fn submit(c: u32, ) -> Weight;
 	fn submit_unsigned(v: u32, t: u32, a: u32, d: u32, ) -> Weight;
 	fn feasibility_check(v: u32, t: u32, a: u32, d: u32, ) -> Weight;
+	fn register_paged() -> Weight;
+	fn aggregate_paged_supports(v: u32, d: u32, ) -> Weight;
 }
 
 /// Weights for pallet_election_provider_multi_phase using the Substrate node and recommended hardware.
@@ -177,6 +179,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((1_844_000 as Weight).saturating_mul(d as Weight))
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 	}
+	// Storage: ElectionProviderMultiPhase CurrentPhase (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase PagedSignedSubmissions (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase CounterForPagedSignedSubmissions (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase MinimumUntrustedScore (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn register_paged() -> Weight {
+		(46_390_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: ElectionProviderMultiPhase DesiredTargets (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase MinimumUntrustedScore (r:1 w:0)
+	fn aggregate_paged_supports(v: u32, d: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((412_000 as Weight).saturating_mul(v as Weight))
+			// Standard Error: 9_000
+			.saturating_add((1_096_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -298,4 +320,24 @@ impl WeightInfo for () {
 			.saturating_add((1_844_000 as Weight).saturating_mul(d as Weight))
 			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 	}
+	// Storage: ElectionProviderMultiPhase CurrentPhase (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase PagedSignedSubmissions (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase CounterForPagedSignedSubmissions (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase MinimumUntrustedScore (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn register_paged() -> Weight {
+		(46_390_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: ElectionProviderMultiPhase DesiredTargets (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase MinimumUntrustedScore (r:1 w:0)
+	fn aggregate_paged_supports(v: u32, d: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((412_000 as Weight).saturating_mul(v as Weight))
+			// Standard Error: 9_000
+			.saturating_add((1_096_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+	}
 }
@@ -171,6 +171,9 @@ pub use sp_npos_elections::{
 	VoteWeight,
 };
 
+/// The index of a page, in a multi-page election.
+pub type PageIndex = u32;
+
 /// Types that are used by the data provider trait.
 pub mod data_provider {
 	/// Alias for the result type of the election data provider.
@@ -204,6 +207,28 @@ pub trait ElectionDataProvider<AccountId, BlockNumber> {
 		maybe_max_len: Option<usize>,
 	) -> data_provider::Result<Vec<(AccountId, VoteWeight, Vec<AccountId>)>>;
 
+	/// Same as [`Self::voters`], but only returns a single `page` of the voters.
+	///
+	/// Pages must be requested in order, starting from `0`. Each page continues from where the
+	/// previous one left off, and requesting page `0` again starts over. Once all voters have been
+	/// returned, any further page is empty.
+	///
+	/// The default implementation returns all voters in page `0`, and nothing afterwards. This is
+	/// only suitable for data providers that can return all of their voters in one go.
+	///
+	/// This should be implemented as a self-weighing function. The implementor should register its
+	/// appropriate weight at the end of execution with the system pallet directly.
+	fn voters_paged(
+		maybe_max_len: Option<usize>,
+		page: PageIndex,
+	) -> data_provider::Result<Vec<(AccountId, VoteWeight, Vec<AccountId>)>> {
+		if page == 0 {
+			Self::voters(maybe_max_len)
+		} else {
+			Ok(Default::default())
+		}
+	}
+
 	/// The number of targets to elect.
 	///
 	/// This should be implemented as a self-weighing function. The implementor should register its
@@ -315,6 +340,11 @@ pub trait SortedListProvider<AccountId> {
 	/// An iterator over the list, which can have `take` called on it.
 	fn iter() -> Box<dyn Iterator<Item = AccountId>>;
 
+	/// Same as [`Self::iter`], but starts right after `start`, which is excluded.
+	///
+	/// Returns an error if `start` is not in the list.
+	fn iter_from(start: &AccountId) -> Result<Box<dyn Iterator<Item = AccountId>>, Self::Error>;
+
 	/// The current count of ids in the list.
 	fn count() -> u32;
 
@@ -354,16 +384,19 @@ pub trait SortedListProvider<AccountId> {
 	}
 }
 
-/// Something that can provide the `VoteWeight` of an account. Similar to [`ElectionProvider`] and
+/// Something that can provide the `Score` of an account. Similar to [`ElectionProvider`] and
 /// [`ElectionDataProvider`], this should typically be implementing by whoever is supposed to *use*
 /// `SortedListProvider`.
-pub trait VoteWeightProvider<AccountId> {
-	/// Get the current `VoteWeight` of `who`.
-	fn vote_weight(who: &AccountId) -> VoteWeight;
+pub trait ScoreProvider<AccountId> {
+	/// The type of the score, e.g. [`VoteWeight`] when the list is used to sort voters.
+	type Score;
+
+	/// Get the current `Score` of `who`.
+	fn score(who: &AccountId) -> Self::Score;
 
-	/// For tests and benchmarks, set the `VoteWeight`.
+	/// For tests and benchmarks, set the `Score` of `who`.
 	#[cfg(any(feature = "runtime-benchmarks", test))]
-	fn set_vote_weight_of(_: &AccountId, _: VoteWeight) {}
+	fn set_score_of(_: &AccountId, _: Self::Score) {}
 }
 
 /// Something that can compute the result to an NPoS solution.
@@ -431,3 +464,22 @@ impl<
 		sp_npos_elections::phragmms(winners, targets, voters, Balancing::get())
 	}
 }
+
+/// A wrapper for [`sp_npos_elections::approval_voting()`] that implements [`NposSolver`]. See the
+/// documentation of [`sp_npos_elections::approval_voting()`] for more info.
+pub struct ApprovalVoting<AccountId, Accuracy>(sp_std::marker::PhantomData<(AccountId, Accuracy)>);
+
+impl<AccountId: IdentifierT, Accuracy: PerThing128> NposSolver
+	for ApprovalVoting<AccountId, Accuracy>
+{
+	type AccountId = AccountId;
+	type Accuracy = Accuracy;
+	type Error = sp_npos_elections::Error;
+	fn solve(
+		winners: usize,
+		targets: Vec<Self::AccountId>,
+		voters: Vec<(Self::AccountId, VoteWeight, Vec<Self::AccountId>)>,
+	) -> Result<ElectionResult<Self::AccountId, Self::Accuracy>, Self::Error> {
+		sp_npos_elections::approval_voting(winners, targets, voters)
+	}
+}
@@ -15,9 +15,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! An implementation of [`ElectionProvider`] that does an on-chain sequential phragmen.
+//! An implementation of [`ElectionProvider`] that uses an `NposSolver` to do the election.
 
-use crate::{ElectionDataProvider, ElectionProvider};
+use crate::{ElectionDataProvider, ElectionProvider, NposSolver};
 use frame_support::{traits::Get, weights::DispatchClass};
 use sp_npos_elections::*;
 use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, prelude::*};
@@ -29,6 +29,10 @@ pub enum Error {
 	NposElections(sp_npos_elections::Error),
 	/// Errors from the data provider.
 	DataProvider(&'static str),
+	/// The data provider returned more voters than the configured bound.
+	TooManyVoters,
+	/// The data provider returned more targets than the configured bound.
+	TooManyTargets,
 }
 
 impl From<sp_npos_elections::Error> for Error {
@@ -48,53 +52,95 @@ impl From<sp_npos_elections::Error> for Error {
 /// how much weight was consumed.
 ///
 /// Finally, this implementation does not impose any limits on the number of voters and targets that
-/// are provided.
-pub struct OnChainSequentialPhragmen<T: Config>(PhantomData<T>);
+/// are provided. See [`BoundedExecution`] for a version that does.
+pub struct UnboundedExecution<T: Config>(PhantomData<T>);
 
-/// Configuration trait of [`OnChainSequentialPhragmen`].
+/// An on-chain implementation of the election provider trait that refuses to run on more voters
+/// and targets than its configured bounds.
 ///
-/// Note that this is similar to a pallet traits, but [`OnChainSequentialPhragmen`] is not a pallet.
+/// Unlike [`UnboundedExecution`], the election is not executed at all if the data provider
+/// returns more than [`BoundedConfig::VotersBound`] voters or [`BoundedConfig::TargetsBound`]
+/// targets, in which case [`Error::TooManyVoters`] or [`Error::TooManyTargets`] is returned.
+pub struct BoundedExecution<T: BoundedConfig>(PhantomData<T>);
+
+/// Configuration trait of [`UnboundedExecution`] and [`BoundedExecution`].
+///
+/// Note that this is similar to a pallet traits, but neither of the two is a pallet.
 ///
-/// WARNING: the user of this pallet must ensure that the `Accuracy` type will work nicely with the
-/// normalization operation done inside `seq_phragmen`. See
+/// WARNING: the user of this pallet must ensure that the `Accuracy` of the `Solver` will work
+/// nicely with the normalization operation done inside it. See
 /// [`sp_npos_elections::Assignment::try_normalize`] for more info.
 pub trait Config: frame_system::Config {
-	/// The accuracy used to compute the election:
-	type Accuracy: PerThing128;
+	/// The solver used to compute the election, e.g. [`crate::SequentialPhragmen`],
+	/// [`crate::PhragMMS`] or [`crate::ApprovalVoting`].
+	type Solver: NposSolver<AccountId = Self::AccountId, Error = sp_npos_elections::Error>;
 	/// Something that provides the data for election.
 	type DataProvider: ElectionDataProvider<Self::AccountId, Self::BlockNumber>;
 }
 
-impl<T: Config> ElectionProvider<T::AccountId, T::BlockNumber> for OnChainSequentialPhragmen<T> {
-	type Error = Error;
-	type DataProvider = T::DataProvider;
+/// Configuration trait of [`BoundedExecution`].
+pub trait BoundedConfig: Config {
+	/// The maximum number of voters that the election is allowed to run on.
+	type VotersBound: Get<u32>;
+	/// The maximum number of targets that the election is allowed to run on.
+	type TargetsBound: Get<u32>;
+}
 
-	fn elect() -> Result<Supports<T::AccountId>, Self::Error> {
-		let voters = Self::DataProvider::voters(None).map_err(Error::DataProvider)?;
-		let targets = Self::DataProvider::targets(None).map_err(Error::DataProvider)?;
-		let desired_targets = Self::DataProvider::desired_targets().map_err(Error::DataProvider)?;
+/// Run the election with `T::Solver`, refusing to do so if the data provider returns more than
+/// `maybe_max_voters` voters or `maybe_max_targets` targets.
+fn elect_with<T: Config>(
+	maybe_max_voters: Option<usize>,
+	maybe_max_targets: Option<usize>,
+) -> Result<Supports<T::AccountId>, Error> {
+	// ask for one more item than the bound, so that we can tell if the bound has been exceeded.
+	let voters = T::DataProvider::voters(maybe_max_voters.map(|m| m.saturating_add(1)))
+		.map_err(Error::DataProvider)?;
+	if maybe_max_voters.map_or(false, |max| voters.len() > max) {
+		return Err(Error::TooManyVoters)
+	}
+
+	let targets = T::DataProvider::targets(maybe_max_targets.map(|m| m.saturating_add(1)))
+		.map_err(Error::DataProvider)?;
+	if maybe_max_targets.map_or(false, |max| targets.len() > max) {
+		return Err(Error::TooManyTargets)
+	}
+
+	let desired_targets = T::DataProvider::desired_targets().map_err(Error::DataProvider)?;
+
+	let stake_map: BTreeMap<T::AccountId, VoteWeight> = voters
+		.iter()
+		.map(|(validator, vote_weight, _)| (validator.clone(), *vote_weight))
+		.collect();
 
-		let stake_map: BTreeMap<T::AccountId, VoteWeight> = voters
-			.iter()
-			.map(|(validator, vote_weight, _)| (validator.clone(), *vote_weight))
-			.collect();
+	let stake_of =
+		|w: &T::AccountId| -> VoteWeight { stake_map.get(w).cloned().unwrap_or_default() };
 
-		let stake_of =
-			|w: &T::AccountId| -> VoteWeight { stake_map.get(w).cloned().unwrap_or_default() };
+	let ElectionResult { winners: _, assignments } =
+		T::Solver::solve(desired_targets as usize, targets, voters).map_err(Error::from)?;
 
-		let ElectionResult { winners: _, assignments } =
-			seq_phragmen::<_, T::Accuracy>(desired_targets as usize, targets, voters, None)
-				.map_err(Error::from)?;
+	let staked = assignment_ratio_to_staked_normalized(assignments, &stake_of)?;
 
-		let staked = assignment_ratio_to_staked_normalized(assignments, &stake_of)?;
+	let weight = T::BlockWeights::get().max_block;
+	frame_system::Pallet::<T>::register_extra_weight_unchecked(weight, DispatchClass::Mandatory);
 
-		let weight = T::BlockWeights::get().max_block;
-		frame_system::Pallet::<T>::register_extra_weight_unchecked(
-			weight,
-			DispatchClass::Mandatory,
-		);
+	Ok(to_supports(&staked))
+}
+
+impl<T: Config> ElectionProvider<T::AccountId, T::BlockNumber> for UnboundedExecution<T> {
+	type Error = Error;
+	type DataProvider = T::DataProvider;
+
+	fn elect() -> Result<Supports<T::AccountId>, Self::Error> {
+		elect_with::<T>(None, None)
+	}
+}
+
+impl<T: BoundedConfig> ElectionProvider<T::AccountId, T::BlockNumber> for BoundedExecution<T> {
+	type Error = Error;
+	type DataProvider = T::DataProvider;
 
-		Ok(to_supports(&staked))
+	fn elect() -> Result<Supports<T::AccountId>, Self::Error> {
+		elect_with::<T>(Some(T::VotersBound::get() as usize), Some(T::TargetsBound::get() as usize))
 	}
 }
 
@@ -148,11 +194,22 @@ mod tests {
 	}
 
 	impl Config for Runtime {
-		type Accuracy = Perbill;
+		type Solver = crate::SequentialPhragmen<AccountId, Perbill>;
 		type DataProvider = mock_data_provider::DataProvider;
 	}
 
-	type OnChainPhragmen = OnChainSequentialPhragmen<Runtime>;
+	frame_support::parameter_types! {
+		pub static VotersBound: u32 = 3;
+		pub static TargetsBound: u32 = 3;
+	}
+
+	impl BoundedConfig for Runtime {
+		type VotersBound = VotersBound;
+		type TargetsBound = TargetsBound;
+	}
+
+	type OnChainPhragmen = UnboundedExecution<Runtime>;
+	type BoundedOnChainPhragmen = BoundedExecution<Runtime>;
 
 	mod mock_data_provider {
 		use super::*;
@@ -193,4 +250,27 @@ mod tests {
 			);
 		})
 	}
+
+	#[test]
+	fn bounded_onchain_seq_phragmen_works() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			assert_eq!(BoundedOnChainPhragmen::elect().unwrap(), OnChainPhragmen::elect().unwrap());
+		})
+	}
+
+	#[test]
+	fn bounded_onchain_refuses_too_many_voters() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			VotersBound::set(2);
+			assert_eq!(BoundedOnChainPhragmen::elect().unwrap_err(), Error::TooManyVoters);
+		})
+	}
+
+	#[test]
+	fn bounded_onchain_refuses_too_many_targets() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			TargetsBound::set(2);
+			assert_eq!(BoundedOnChainPhragmen::elect().unwrap_err(), Error::TooManyTargets);
+		})
+	}
 }
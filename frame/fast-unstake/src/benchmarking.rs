@@ -0,0 +1,147 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarking for pallet-fast-unstake.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::{Pallet as FastUnstake, *};
+use frame_benchmarking::{account, benchmarks, whitelist_account};
+use frame_support::{
+	assert_ok,
+	traits::{Currency, EnsureOrigin, Get, Hooks},
+};
+use frame_system::RawOrigin;
+use pallet_staking::{Exposure, IndividualExposure};
+use sp_runtime::traits::Zero;
+use sp_staking::EraIndex;
+use sp_std::prelude::*;
+
+const USER_SEED: u32 = 0;
+/// The number of validators that are exposed in each era.
+const VALIDATORS_PER_ERA: u32 = 64;
+/// The number of nominators that back each of the validators.
+const NOMINATORS_PER_VALIDATOR: u32 = 16;
+
+type CurrencyOf<T> = <T as Config>::Currency;
+
+/// Create a bonded nominator that is not exposed in any era.
+fn create_unexposed_nominator<T: Config>() -> T::AccountId {
+	let who: T::AccountId = account("nominator_42", 0, USER_SEED);
+	let stake = T::Staking::minimum_bond().max(CurrencyOf::<T>::minimum_balance()) * 10u32.into();
+	CurrencyOf::<T>::make_free_balance_be(&who, stake * 2u32.into() + T::Deposit::get());
+
+	assert_ok!(T::Staking::bond(who.clone(), who.clone(), stake, who.clone()));
+	assert_ok!(T::Staking::nominate(who.clone(), vec![account("validator", 0, USER_SEED)]));
+	who
+}
+
+/// Set the current era to `current_era`, and populate the exposures of all eras up to it, none
+/// of which include the nominator created by [`create_unexposed_nominator`].
+fn setup_staking<T: Config + pallet_staking::Config>(current_era: EraIndex) {
+	for era in 0..=current_era {
+		for v in 0..VALIDATORS_PER_ERA {
+			let others = (0..NOMINATORS_PER_VALIDATOR)
+				.map(|n| IndividualExposure {
+					who: account("nominator", v * NOMINATORS_PER_VALIDATOR + n, USER_SEED),
+					value: Zero::zero(),
+				})
+				.collect::<Vec<_>>();
+			pallet_staking::Pallet::<T>::set_exposure(
+				era,
+				&account::<T::AccountId>("validator", v, USER_SEED),
+				Exposure { total: Zero::zero(), own: Zero::zero(), others },
+			);
+		}
+	}
+	pallet_staking::CurrentEra::<T>::put(current_era);
+}
+
+fn on_idle_full_block<T: Config>() {
+	let remaining_weight = <T as frame_system::Config>::BlockWeights::get().max_block;
+	FastUnstake::<T>::on_idle(Zero::zero(), remaining_weight);
+}
+
+benchmarks! {
+	where_clause { where T: pallet_staking::Config }
+
+	// on_idle, when all the eras have been checked and the stash is unstaked.
+	on_idle_unstake {
+		pallet_staking::CurrentEra::<T>::put(0);
+		ErasToCheckPerBlock::<T>::put(1);
+		let who = create_unexposed_nominator::<T>();
+		assert_ok!(FastUnstake::<T>::register_fast_unstake(RawOrigin::Signed(who.clone()).into()));
+
+		// the current era is 0, which is the only era to check.
+		on_idle_full_block::<T>();
+		assert_eq!(Head::<T>::get().map(|head| head.checked.into_inner()), Some(vec![0]));
+	}
+	: {
+		on_idle_full_block::<T>();
+	}
+	verify {
+		assert!(Head::<T>::get().is_none());
+		assert_eq!(T::Staking::total_stake(&who), None);
+	}
+
+	// on_idle, when `x` eras are checked and the stash was not exposed in any of them.
+	on_idle_check {
+		let x in 1 .. T::Staking::bonding_duration().saturating_add(1);
+
+		setup_staking::<T>(T::Staking::bonding_duration());
+		ErasToCheckPerBlock::<T>::put(x);
+		let who = create_unexposed_nominator::<T>();
+		assert_ok!(FastUnstake::<T>::register_fast_unstake(RawOrigin::Signed(who.clone()).into()));
+	}
+	: {
+		on_idle_full_block::<T>();
+	}
+	verify {
+		assert_eq!(Head::<T>::get().map(|head| head.checked.len() as u32), Some(x));
+	}
+
+	register_fast_unstake {
+		ErasToCheckPerBlock::<T>::put(1);
+		let who = create_unexposed_nominator::<T>();
+		whitelist_account!(who);
+	}
+	: _(RawOrigin::Signed(who.clone()))
+	verify {
+		assert!(Queue::<T>::contains_key(&who));
+	}
+
+	deregister {
+		ErasToCheckPerBlock::<T>::put(1);
+		let who = create_unexposed_nominator::<T>();
+		assert_ok!(FastUnstake::<T>::register_fast_unstake(RawOrigin::Signed(who.clone()).into()));
+		whitelist_account!(who);
+	}
+	: _(RawOrigin::Signed(who.clone()))
+	verify {
+		assert!(!Queue::<T>::contains_key(&who));
+	}
+
+	control {
+		let origin = <T as Config>::ControlOrigin::successful_origin();
+	}
+	: _<T::Origin>(origin, 128)
+	verify {
+		assert_eq!(ErasToCheckPerBlock::<T>::get(), 128);
+	}
+
+	impl_benchmark_test_suite!(FastUnstake, crate::mock::ExtBuilder::default().build(), crate::mock::Runtime)
+}
@@ -0,0 +1,355 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Fast Unstake Pallet
+//!
+//! A pallet that allows nominators who were not exposed in any of the last `BondingDuration`
+//! eras to unstake without waiting for the whole bonding period.
+//!
+//! ## Overview
+//!
+//! A nominator that calls `unbond` in the staking system has to wait `BondingDuration` eras
+//! before their funds are unlocked, because they might still be slashed for the misbehaviour of
+//! a validator they backed in any of those eras. A nominator who was not backing any validator in
+//! those eras, however, cannot be slashed, and can safely be unstaked immediately.
+//!
+//! Checking this is expensive, as it requires iterating over all of the exposures of each era.
+//! Therefore, a nominator registers for fast unstake with [`Call::register_fast_unstake`], which
+//! chills and fully unbonds them, reserves [`Config::Deposit`] from their stash and places them
+//! in the [`Queue`]. In `on_idle`, this pallet then takes one request at a time from the queue
+//! into [`Head`], and checks [`ErasToCheckPerBlock`] eras of it per block.
+//!
+//! - If the stash was not exposed in any of the eras, it is unstaked at once, and its deposit is
+//!   returned.
+//! - If the stash was exposed in any of the eras, the deposit is slashed, and the stash is left to
+//!   wait for the rest of the bonding period as a normal unbonding nominator.
+//!
+//! A stash that is still in the queue can leave it with [`Call::deregister`], which returns the
+//! deposit. The funds of the stash remain unbonding.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `register_fast_unstake` - Register the stash controlled by the caller for fast unstake.
+//! - `deregister` - Remove the stash controlled by the caller from the queue.
+//! - `control` - Set the number of eras to check per block. Only callable by
+//!   [`Config::ControlOrigin`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use frame_support::{
+	ensure,
+	pallet_prelude::*,
+	traits::{Currency, Get, OnUnbalanced, ReservableCurrency},
+	transactional, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchResult,
+};
+use sp_staking::{EraIndex, StakingInterface};
+use sp_std::{marker::PhantomData, prelude::*};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+pub(crate) const LOG_TARGET: &'static str = "runtime::fast-unstake";
+
+/// The balance type used by the currency system.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// The negative imbalance type of the currency system.
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+/// The maximum number of eras that are checked for a single request: the last
+/// `BondingDuration` eras, and the current one.
+pub struct MaxChecking<T: Config>(PhantomData<T>);
+impl<T: Config> Get<u32> for MaxChecking<T> {
+	fn get() -> u32 {
+		T::Staking::bonding_duration().saturating_add(1)
+	}
+}
+
+/// An unstake request that is currently being checked.
+#[derive(
+	Encode, Decode, EqNoBound, PartialEqNoBound, CloneNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct UnstakeRequest<T: Config> {
+	/// The stash that wishes to be unstaked.
+	pub stash: T::AccountId,
+	/// The eras that have already been checked, in which the stash was not exposed.
+	pub checked: BoundedVec<EraIndex, MaxChecking<T>>,
+	/// The deposit that was reserved from the stash upon registration.
+	pub deposit: BalanceOf<T>,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: weights::WeightInfo;
+
+		/// The currency used for the deposit of each request.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The interface to the staking system.
+		type Staking: StakingInterface<Balance = BalanceOf<Self>, AccountId = Self::AccountId>;
+
+		/// The amount that is reserved from the stash upon registration, and slashed if it turns
+		/// out that the stash was exposed in any of the checked eras.
+		#[pallet::constant]
+		type Deposit: Get<BalanceOf<Self>>;
+
+		/// Handler for the deposits that are slashed.
+		type SlashHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The origin that can set the number of eras to check per block.
+		type ControlOrigin: EnsureOrigin<Self::Origin>;
+	}
+
+	/// The request that is currently being checked, if any.
+	#[pallet::storage]
+	pub type Head<T: Config> = StorageValue<_, UnstakeRequest<T>, OptionQuery>;
+
+	/// The stashes waiting to be checked, along with the deposit they have reserved.
+	#[pallet::storage]
+	pub type Queue<T: Config> = CountedStorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>>;
+
+	/// The number of eras to check per block.
+	///
+	/// If set to zero, this pallet does nothing, and no new requests are accepted.
+	#[pallet::storage]
+	pub type ErasToCheckPerBlock<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A stash was unstaked, with the given result.
+		Unstaked { stash: T::AccountId, result: DispatchResult },
+		/// A stash was exposed in one of the checked eras, and its deposit was slashed.
+		Slashed { stash: T::AccountId, amount: BalanceOf<T> },
+		/// Some eras of a stash were checked, and it was not exposed in any of them.
+		Checking { stash: T::AccountId, eras: Vec<EraIndex> },
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq))]
+	pub enum Error<T> {
+		/// The caller is not a controller.
+		NotController,
+		/// The stash is already in the queue.
+		AlreadyQueued,
+		/// The stash has some funds that are already unbonding.
+		NotFullyBonded,
+		/// The stash is not in the queue.
+		NotQueued,
+		/// The stash is currently being checked, and cannot be removed from the queue.
+		AlreadyHead,
+		/// No eras are checked per block, so new requests are not accepted.
+		CallNotAllowed,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let eras_to_check = ErasToCheckPerBlock::<T>::get();
+			if eras_to_check.is_zero() {
+				return T::DbWeight::get().reads(1)
+			}
+
+			// only proceed if the worst case of processing a single request fits in this block.
+			let worst_case_weight =
+				T::WeightInfo::on_idle_check(eras_to_check).max(T::WeightInfo::on_idle_unstake());
+			if worst_case_weight > remaining_weight {
+				log::debug!(
+					target: LOG_TARGET,
+					"early exit because remaining weight is {}",
+					remaining_weight
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			Self::do_on_idle(eras_to_check)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register the stash controlled by the origin for fast unstake.
+		///
+		/// The dispatch origin must be signed by the controller of a bonded stash that has no
+		/// funds unbonding. The stash is chilled and fully unbonded, and [`Config::Deposit`] is
+		/// reserved from it.
+		///
+		/// If the stash turns out not to have been exposed in any of the eras that are checked,
+		/// it will be unstaked and its deposit returned. Otherwise, the deposit is slashed and the
+		/// funds of the stash remain unbonding.
+		#[pallet::weight(T::WeightInfo::register_fast_unstake())]
+		#[transactional]
+		pub fn register_fast_unstake(origin: OriginFor<T>) -> DispatchResult {
+			let controller = ensure_signed(origin)?;
+
+			ensure!(!ErasToCheckPerBlock::<T>::get().is_zero(), Error::<T>::CallNotAllowed);
+			let stash =
+				T::Staking::stash_by_ctrl(&controller).map_err(|_| Error::<T>::NotController)?;
+			ensure!(!Queue::<T>::contains_key(&stash), Error::<T>::AlreadyQueued);
+			ensure!(
+				Head::<T>::get().map_or(true, |head| head.stash != stash),
+				Error::<T>::AlreadyHead
+			);
+
+			let active = T::Staking::active_stake(&controller).unwrap_or_default();
+			let total = T::Staking::total_stake(&controller).unwrap_or_default();
+			ensure!(active == total, Error::<T>::NotFullyBonded);
+
+			T::Staking::chill(controller.clone())?;
+			T::Staking::unbond(controller, active)?;
+
+			let deposit = T::Deposit::get();
+			T::Currency::reserve(&stash, deposit)?;
+			Queue::<T>::insert(stash, deposit);
+			Ok(())
+		}
+
+		/// Remove the stash controlled by the origin from the queue, and return its deposit.
+		///
+		/// The funds of the stash remain unbonding. This is not possible once the stash is being
+		/// checked.
+		#[pallet::weight(T::WeightInfo::deregister())]
+		pub fn deregister(origin: OriginFor<T>) -> DispatchResult {
+			let controller = ensure_signed(origin)?;
+
+			let stash =
+				T::Staking::stash_by_ctrl(&controller).map_err(|_| Error::<T>::NotController)?;
+			ensure!(
+				Head::<T>::get().map_or(true, |head| head.stash != stash),
+				Error::<T>::AlreadyHead
+			);
+			let deposit = Queue::<T>::take(&stash).ok_or(Error::<T>::NotQueued)?;
+
+			let remaining = T::Currency::unreserve(&stash, deposit);
+			debug_assert!(remaining.is_zero());
+			Ok(())
+		}
+
+		/// Set the number of eras to check per block.
+		///
+		/// The dispatch origin must be [`Config::ControlOrigin`]. Setting this to zero pauses the
+		/// pallet.
+		#[pallet::weight(T::WeightInfo::control())]
+		pub fn control(origin: OriginFor<T>, eras_to_check: u32) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+			ErasToCheckPerBlock::<T>::put(eras_to_check);
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Process the request at the head of the queue, checking at most `eras_to_check` of its
+	/// eras.
+	///
+	/// Returns the weight consumed.
+	fn do_on_idle(eras_to_check: u32) -> Weight {
+		let request = Head::<T>::take().or_else(|| {
+			Queue::<T>::drain().next().map(|(stash, deposit)| UnstakeRequest {
+				stash,
+				deposit,
+				checked: Default::default(),
+			})
+		});
+		let UnstakeRequest { stash, mut checked, deposit } = match request {
+			Some(request) => request,
+			// nothing to do, read the head and the queue.
+			None => return T::DbWeight::get().reads(3),
+		};
+
+		// the eras in which the stash could have been exposed and still be slashed.
+		let current_era = T::Staking::current_era();
+		let earliest_era = current_era.saturating_sub(T::Staking::bonding_duration());
+		// eras that have become too old since they were checked are no longer relevant.
+		checked.retain(|era| *era >= earliest_era);
+
+		let unchecked_eras = (earliest_era..=current_era)
+			.rev()
+			.filter(|era| !checked.contains(era))
+			.take(eras_to_check as usize)
+			.collect::<Vec<_>>();
+
+		if unchecked_eras.is_empty() {
+			// all eras have been checked, and the stash was not exposed in any of them.
+			let result = T::Staking::force_unstake(stash.clone());
+			let remaining = T::Currency::unreserve(&stash, deposit);
+			debug_assert!(remaining.is_zero());
+			log::info!(target: LOG_TARGET, "unstaked {:?}, outcome: {:?}", stash, result);
+			Self::deposit_event(Event::<T>::Unstaked { stash, result });
+			return T::WeightInfo::on_idle_unstake()
+		}
+
+		let checked_count = unchecked_eras.len() as u32;
+		if unchecked_eras.iter().any(|era| T::Staking::is_exposed_in_era(&stash, era)) {
+			// the stash has to wait for the rest of the bonding duration, and loses its deposit.
+			let (imbalance, unslashed) = T::Currency::slash_reserved(&stash, deposit);
+			T::SlashHandler::on_unbalanced(imbalance);
+			let amount = deposit.saturating_sub(unslashed);
+			log::info!(target: LOG_TARGET, "slashed {:?} by {:?} for being exposed", stash, amount);
+			Self::deposit_event(Event::<T>::Slashed { stash, amount });
+		} else {
+			for era in unchecked_eras.iter() {
+				if checked.try_push(*era).is_err() {
+					// cannot happen, as at most `MaxChecking` distinct eras are retained.
+					log::warn!(
+						target: LOG_TARGET,
+						"checked eras of {:?} exceeded their bound",
+						stash
+					);
+				}
+			}
+			Head::<T>::put(UnstakeRequest { stash: stash.clone(), checked, deposit });
+			Self::deposit_event(Event::<T>::Checking { stash, eras: unchecked_eras });
+		}
+
+		T::WeightInfo::on_idle_check(checked_count)
+	}
+}
@@ -0,0 +1,323 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for the fast unstake pallet.
+
+use crate::{self as fast_unstake, *};
+use frame_election_provider_support::{onchain, SequentialPhragmen};
+use frame_support::{
+	assert_ok, parameter_types,
+	traits::{Currency, Hooks, OnUnbalanced},
+	weights::constants::WEIGHT_PER_SECOND,
+};
+use pallet_staking::{IndividualExposure, RewardDestination};
+use sp_runtime::traits::IdentityLookup;
+
+pub(crate) type AccountId = u64;
+type AccountIndex = u32;
+type BlockNumber = u64;
+pub(crate) type Balance = u64;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+frame_support::construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Staking: pallet_staking::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>},
+		FastUnstake: fast_unstake::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub BlockWeights: frame_system::limits::BlockWeights =
+		frame_system::limits::BlockWeights::simple_max(2 * WEIGHT_PER_SECOND);
+}
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = BlockWeights;
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Index = AccountIndex;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = sp_core::H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = sp_runtime::testing::Header;
+	type Event = Event;
+	type BlockHashCount = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+impl pallet_balances::Config for Runtime {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = Balance;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+impl pallet_timestamp::Config for Runtime {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+impl pallet_session::historical::Config for Runtime {
+	type FullIdentification = pallet_staking::Exposure<AccountId, Balance>;
+	type FullIdentificationOf = pallet_staking::ExposureOf<Runtime>;
+}
+
+sp_runtime::impl_opaque_keys! {
+	pub struct SessionKeys {
+		pub foo: sp_runtime::testing::UintAuthorityId,
+	}
+}
+
+pub struct TestSessionHandler;
+impl pallet_session::SessionHandler<AccountId> for TestSessionHandler {
+	const KEY_TYPE_IDS: &'static [sp_runtime::KeyTypeId] = &[];
+
+	fn on_genesis_session<Ks: sp_runtime::traits::OpaqueKeys>(_validators: &[(AccountId, Ks)]) {}
+
+	fn on_new_session<Ks: sp_runtime::traits::OpaqueKeys>(
+		_: bool,
+		_: &[(AccountId, Ks)],
+		_: &[(AccountId, Ks)],
+	) {
+	}
+
+	fn on_disabled(_: u32) {}
+}
+
+impl pallet_session::Config for Runtime {
+	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Runtime, Staking>;
+	type Keys = SessionKeys;
+	type ShouldEndSession = pallet_session::PeriodicSessions<(), ()>;
+	type NextSessionRotation = pallet_session::PeriodicSessions<(), ()>;
+	type SessionHandler = TestSessionHandler;
+	type Event = Event;
+	type ValidatorId = AccountId;
+	type ValidatorIdOf = pallet_staking::StashOf<Runtime>;
+	type WeightInfo = ();
+}
+pallet_staking_reward_curve::build! {
+	const I_NPOS: sp_runtime::curve::PiecewiseLinear<'static> = curve!(
+		min_inflation: 0_025_000,
+		max_inflation: 0_100_000,
+		ideal_stake: 0_500_000,
+		falloff: 0_050_000,
+		max_piece_count: 40,
+		test_precision: 0_005_000,
+	);
+}
+parameter_types! {
+	pub const RewardCurve: &'static sp_runtime::curve::PiecewiseLinear<'static> = &I_NPOS;
+	pub const MaxExposurePageSize: u32 = 64;
+	pub const MaxPayoutsPerCall: u32 = 16;
+	pub const MaxClaimerCommission: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(10);
+	pub const UnsignedPriority: u64 = 1 << 20;
+	pub const BondingDuration: sp_staking::EraIndex = 3;
+}
+
+pub type Extrinsic = sp_runtime::testing::TestXt<Call, ()>;
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	Call: From<C>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = Extrinsic;
+}
+
+impl onchain::Config for Runtime {
+	type Solver = SequentialPhragmen<AccountId, sp_runtime::Perbill>;
+	type DataProvider = Staking;
+}
+
+impl pallet_staking::Config for Runtime {
+	const MAX_NOMINATIONS: u32 = 16;
+	type Currency = Balances;
+	type UnixTime = pallet_timestamp::Pallet<Self>;
+	type CurrencyToVote = frame_support::traits::SaturatingCurrencyToVote;
+	type RewardRemainder = ();
+	type Event = Event;
+	type Slash = ();
+	type Reward = ();
+	type SessionsPerEra = ();
+	type SlashDeferDuration = ();
+	type SlashCancelOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type BondingDuration = BondingDuration;
+	type SessionInterface = Self;
+	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
+	type NextNewSession = Session;
+	type MaxExposurePageSize = MaxExposurePageSize;
+	type MaxPayoutsPerCall = MaxPayoutsPerCall;
+	type MaxClaimerCommission = MaxClaimerCommission;
+	type OffendingValidatorsThreshold = ();
+	type ElectionProvider = onchain::UnboundedExecution<Self>;
+	type GenesisElectionProvider = Self::ElectionProvider;
+	type SortedListProvider = pallet_staking::UseNominatorsMap<Self>;
+	type OnStakerSlash = ();
+	type BenchmarkingConfig = pallet_staking::TestBenchmarkingConfig;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub static DepositAmount: Balance = 7;
+}
+
+/// The account that receives the slashed deposits.
+pub(crate) const SLASH_RECIPIENT: AccountId = 999;
+
+pub struct DepositToSlashRecipient;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for DepositToSlashRecipient {
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Runtime>) {
+		Balances::resolve_creating(&SLASH_RECIPIENT, amount);
+	}
+}
+
+impl fast_unstake::Config for Runtime {
+	type Event = Event;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type Staking = Staking;
+	type Deposit = DepositAmount;
+	type SlashHandler = DepositToSlashRecipient;
+	type ControlOrigin = frame_system::EnsureRoot<Self::AccountId>;
+}
+
+/// The validator that is exposed in every era.
+pub(crate) const VALIDATOR: AccountId = 100;
+/// A nominator backing [`VALIDATOR`] in every era.
+pub(crate) const EXPOSED_NOMINATOR: AccountId = 200;
+/// The current era when the externalities are built.
+pub(crate) const CURRENT_ERA: sp_staking::EraIndex = 3;
+
+pub struct ExtBuilder {
+	// (stash, controller, stake) of the nominators that are bonded at genesis.
+	nominators: Vec<(AccountId, AccountId, Balance)>,
+	eras_to_check: u32,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self { nominators: vec![(1, 2, 100), (3, 4, 100), (5, 6, 100)], eras_to_check: 1 }
+	}
+}
+
+impl ExtBuilder {
+	pub(crate) fn eras_to_check(mut self, eras_to_check: u32) -> Self {
+		self.eras_to_check = eras_to_check;
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut storage =
+			frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+
+		let _ = pallet_balances::GenesisConfig::<Runtime> {
+			balances: self.nominators.iter().map(|(stash, _, stake)| (*stash, stake * 2)).collect(),
+		}
+		.assimilate_storage(&mut storage);
+
+		let mut ext = sp_io::TestExternalities::from(storage);
+
+		ext.execute_with(|| {
+			// for events to be deposited.
+			System::set_block_number(1);
+
+			for (stash, controller, stake) in self.nominators {
+				assert_ok!(Staking::bond(
+					Origin::signed(stash),
+					controller,
+					stake,
+					RewardDestination::Staked,
+				));
+				assert_ok!(Staking::nominate(Origin::signed(controller), vec![VALIDATOR]));
+			}
+
+			pallet_staking::CurrentEra::<Runtime>::put(CURRENT_ERA);
+			for era in 0..=CURRENT_ERA {
+				expose(era, EXPOSED_NOMINATOR);
+			}
+
+			ErasToCheckPerBlock::<Runtime>::put(self.eras_to_check);
+		});
+
+		ext
+	}
+
+	pub fn build_and_execute(self, test: impl FnOnce() -> ()) {
+		self.build().execute_with(test)
+	}
+}
+
+/// Add `nominator` to the exposure of [`VALIDATOR`] in `era`.
+pub(crate) fn expose(era: sp_staking::EraIndex, nominator: AccountId) {
+	let mut exposure = Staking::eras_stakers(era, VALIDATOR);
+	exposure.others.push(IndividualExposure { who: nominator, value: 1 });
+	exposure.total += 1;
+	Staking::set_exposure(era, &VALIDATOR, exposure);
+}
+
+/// Move to the next block, and give all of its weight to `on_idle`.
+pub(crate) fn next_block() {
+	System::set_block_number(System::block_number() + 1);
+	FastUnstake::on_idle(System::block_number(), BlockWeights::get().max_block);
+}
+
+pub(crate) fn fast_unstake_events_since_last_call() -> Vec<super::Event<Runtime>> {
+	let events = System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| if let Event::FastUnstake(inner) = e { Some(inner) } else { None })
+		.collect::<Vec<_>>();
+	let already_seen = FastUnstakeEvents::get();
+	FastUnstakeEvents::set(&(events.len() as u32));
+	events.into_iter().skip(already_seen as usize).collect()
+}
+
+parameter_types! {
+	storage FastUnstakeEvents: u32 = 0;
+}
@@ -0,0 +1,379 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{mock::*, Event};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, Hooks},
+};
+use pallet_staking::Exposure;
+use sp_runtime::traits::BadOrigin;
+
+fn checked(eras: Vec<EraIndex>) -> BoundedVec<EraIndex, MaxChecking<Runtime>> {
+	eras.try_into().unwrap()
+}
+
+/// Assert that the stash controlled by `controller` has all of its funds unbonding.
+fn assert_fully_unbonding(controller: AccountId) {
+	let ledger = pallet_staking::Ledger::<Runtime>::get(&controller).unwrap();
+	assert_eq!((ledger.total, ledger.active, ledger.unlocking.len()), (100, 0, 1));
+}
+
+mod register {
+	use super::*;
+
+	#[test]
+	fn register_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert!(pallet_staking::Nominators::<Runtime>::contains_key(&1));
+
+			// when
+			assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(2)));
+
+			// then the stash is queued with its deposit reserved
+			assert_eq!(Queue::<Runtime>::get(&1), Some(7));
+			assert_eq!(Queue::<Runtime>::count(), 1);
+			assert_eq!(Balances::reserved_balance(&1), 7);
+			assert_eq!(Balances::free_balance(&1), 193);
+			assert_eq!(Head::<Runtime>::get(), None);
+
+			// and it is chilled and fully unbonded
+			assert!(!pallet_staking::Nominators::<Runtime>::contains_key(&1));
+			assert_fully_unbonding(2);
+		});
+	}
+
+	#[test]
+	fn register_fails_if_paused() {
+		ExtBuilder::default().eras_to_check(0).build_and_execute(|| {
+			assert_noop!(
+				FastUnstake::register_fast_unstake(Origin::signed(2)),
+				Error::<Runtime>::CallNotAllowed
+			);
+		});
+	}
+
+	#[test]
+	fn register_fails_if_not_controller() {
+		ExtBuilder::default().build_and_execute(|| {
+			// the stash is not the controller
+			assert_noop!(
+				FastUnstake::register_fast_unstake(Origin::signed(1)),
+				Error::<Runtime>::NotController
+			);
+			assert_noop!(
+				FastUnstake::register_fast_unstake(Origin::signed(42)),
+				Error::<Runtime>::NotController
+			);
+		});
+	}
+
+	#[test]
+	fn register_fails_if_already_queued_or_head() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(2)));
+			assert_noop!(
+				FastUnstake::register_fast_unstake(Origin::signed(2)),
+				Error::<Runtime>::AlreadyQueued
+			);
+
+			// when the request starts being checked
+			next_block();
+			assert!(Head::<Runtime>::get().is_some());
+			assert_eq!(Queue::<Runtime>::count(), 0);
+
+			// then
+			assert_noop!(
+				FastUnstake::register_fast_unstake(Origin::signed(2)),
+				Error::<Runtime>::AlreadyHead
+			);
+		});
+	}
+
+	#[test]
+	fn register_fails_if_partially_unbonding() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(Staking::unbond(Origin::signed(2), 10));
+			assert_noop!(
+				FastUnstake::register_fast_unstake(Origin::signed(2)),
+				Error::<Runtime>::NotFullyBonded
+			);
+		});
+	}
+
+	#[test]
+	fn register_is_transactional() {
+		ExtBuilder::default().build_and_execute(|| {
+			// the stash cannot afford the deposit, after having already been unbonded.
+			DepositAmount::set(101);
+			assert_noop!(
+				FastUnstake::register_fast_unstake(Origin::signed(2)),
+				pallet_balances::Error::<Runtime>::LiquidityRestrictions
+			);
+			assert!(pallet_staking::Nominators::<Runtime>::contains_key(&1));
+			assert_eq!(pallet_staking::Ledger::<Runtime>::get(&2).unwrap().active, 100);
+		});
+	}
+}
+
+mod deregister {
+	use super::*;
+
+	#[test]
+	fn deregister_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(2)));
+
+			// when
+			assert_ok!(FastUnstake::deregister(Origin::signed(2)));
+
+			// then the deposit is returned, but the funds remain unbonding
+			assert_eq!(Queue::<Runtime>::count(), 0);
+			assert_eq!(Balances::reserved_balance(&1), 0);
+			assert_eq!(Balances::free_balance(&1), 200);
+			assert_fully_unbonding(2);
+		});
+	}
+
+	#[test]
+	fn deregister_fails_if_not_queued() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_noop!(FastUnstake::deregister(Origin::signed(2)), Error::<Runtime>::NotQueued);
+			assert_noop!(
+				FastUnstake::deregister(Origin::signed(1)),
+				Error::<Runtime>::NotController
+			);
+		});
+	}
+
+	#[test]
+	fn deregister_fails_if_head() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(2)));
+			next_block();
+
+			assert_noop!(FastUnstake::deregister(Origin::signed(2)), Error::<Runtime>::AlreadyHead);
+		});
+	}
+}
+
+#[test]
+fn control_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_noop!(FastUnstake::control(Origin::signed(2), 3), BadOrigin);
+
+		assert_ok!(FastUnstake::control(Origin::root(), 3));
+		assert_eq!(ErasToCheckPerBlock::<Runtime>::get(), 3);
+	});
+}
+
+mod on_idle {
+	use super::*;
+
+	#[test]
+	fn unstakes_after_checking_all_eras() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(2)));
+
+			// one era is checked per block, starting from the current one.
+			next_block();
+			assert_eq!(
+				Head::<Runtime>::get(),
+				Some(UnstakeRequest { stash: 1, checked: checked(vec![3]), deposit: 7 })
+			);
+			next_block();
+			next_block();
+			next_block();
+			assert_eq!(
+				Head::<Runtime>::get(),
+				Some(UnstakeRequest { stash: 1, checked: checked(vec![3, 2, 1, 0]), deposit: 7 })
+			);
+			assert_eq!(
+				fast_unstake_events_since_last_call(),
+				vec![
+					Event::Checking { stash: 1, eras: vec![3] },
+					Event::Checking { stash: 1, eras: vec![2] },
+					Event::Checking { stash: 1, eras: vec![1] },
+					Event::Checking { stash: 1, eras: vec![0] },
+				]
+			);
+
+			// when
+			next_block();
+
+			// then the stash is unstaked, and its deposit returned
+			assert_eq!(
+				fast_unstake_events_since_last_call(),
+				vec![Event::Unstaked { stash: 1, result: Ok(()) }]
+			);
+			assert_eq!(Head::<Runtime>::get(), None);
+			assert_eq!(pallet_staking::Ledger::<Runtime>::get(&2), None);
+			assert_eq!(pallet_staking::Bonded::<Runtime>::get(&1), None);
+			assert_eq!(Balances::reserved_balance(&1), 0);
+			assert_eq!(Balances::free_balance(&1), 200);
+			assert!(Balances::locks(&1).is_empty());
+
+			// nothing else happens
+			next_block();
+			assert_eq!(fast_unstake_events_since_last_call(), vec![]);
+		});
+	}
+
+	#[test]
+	fn checks_multiple_eras_per_block() {
+		ExtBuilder::default().eras_to_check(2).build_and_execute(|| {
+			assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(2)));
+
+			next_block();
+			next_block();
+			next_block();
+
+			assert_eq!(
+				fast_unstake_events_since_last_call(),
+				vec![
+					Event::Checking { stash: 1, eras: vec![3, 2] },
+					Event::Checking { stash: 1, eras: vec![1, 0] },
+					Event::Unstaked { stash: 1, result: Ok(()) },
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn processes_the_queue_one_request_at_a_time() {
+		ExtBuilder::default().eras_to_check(4).build_and_execute(|| {
+			assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(2)));
+			assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(4)));
+			assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(6)));
+			assert_eq!(Queue::<Runtime>::count(), 3);
+
+			for remaining in (0..3).rev() {
+				// the next request is checked in full..
+				next_block();
+				assert_eq!(Queue::<Runtime>::count(), remaining);
+				let head = Head::<Runtime>::get().unwrap();
+				assert_eq!(head.checked, checked(vec![3, 2, 1, 0]));
+
+				// ..and then unstaked.
+				next_block();
+				assert_eq!(Head::<Runtime>::get(), None);
+				assert_eq!(
+					fast_unstake_events_since_last_call(),
+					vec![
+						Event::Checking { stash: head.stash, eras: vec![3, 2, 1, 0] },
+						Event::Unstaked { stash: head.stash, result: Ok(()) },
+					]
+				);
+			}
+
+			for controller in [2, 4, 6] {
+				assert_eq!(pallet_staking::Ledger::<Runtime>::get(&controller), None);
+			}
+		});
+	}
+
+	#[test]
+	fn slashes_deposit_if_exposed_as_nominator() {
+		ExtBuilder::default().build_and_execute(|| {
+			expose(2, 1);
+			assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(2)));
+
+			next_block();
+			next_block();
+
+			// then the deposit is slashed, and the funds remain unbonding
+			assert_eq!(
+				fast_unstake_events_since_last_call(),
+				vec![
+					Event::Checking { stash: 1, eras: vec![3] },
+					Event::Slashed { stash: 1, amount: 7 },
+				]
+			);
+			assert_eq!(Head::<Runtime>::get(), None);
+			assert_eq!(Balances::reserved_balance(&1), 0);
+			assert_eq!(Balances::free_balance(&1), 193);
+			assert_eq!(Balances::free_balance(&SLASH_RECIPIENT), 7);
+			assert_fully_unbonding(2);
+		});
+	}
+
+	#[test]
+	fn slashes_deposit_if_exposed_as_validator() {
+		ExtBuilder::default().build_and_execute(|| {
+			Staking::set_exposure(3, &1, Exposure::default());
+			assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(2)));
+
+			next_block();
+
+			assert_eq!(
+				fast_unstake_events_since_last_call(),
+				vec![Event::Slashed { stash: 1, amount: 7 }]
+			);
+			assert_eq!(Balances::total_balance(&1), 193);
+		});
+	}
+
+	#[test]
+	fn checks_new_eras_if_era_changes() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(2)));
+			next_block();
+
+			// when a new era starts, era 0 is no longer relevant, and era 4 has to be checked.
+			pallet_staking::CurrentEra::<Runtime>::put(CURRENT_ERA + 1);
+			next_block();
+			next_block();
+			next_block();
+			assert_eq!(
+				Head::<Runtime>::get().map(|head| head.checked),
+				Some(checked(vec![3, 4, 2, 1]))
+			);
+
+			// then
+			next_block();
+			assert_eq!(
+				fast_unstake_events_since_last_call(),
+				vec![
+					Event::Checking { stash: 1, eras: vec![3] },
+					Event::Checking { stash: 1, eras: vec![4] },
+					Event::Checking { stash: 1, eras: vec![2] },
+					Event::Checking { stash: 1, eras: vec![1] },
+					Event::Unstaked { stash: 1, result: Ok(()) },
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn does_nothing_if_paused_or_out_of_weight() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(2)));
+
+			// not enough weight to process a request.
+			FastUnstake::on_idle(System::block_number(), 0);
+			assert_eq!(Queue::<Runtime>::count(), 1);
+
+			// paused.
+			assert_ok!(FastUnstake::control(Origin::root(), 0));
+			next_block();
+			assert_eq!(Queue::<Runtime>::count(), 1);
+			assert_eq!(Head::<Runtime>::get(), None);
+			assert_eq!(fast_unstake_events_since_last_call(), vec![]);
+		});
+	}
+}
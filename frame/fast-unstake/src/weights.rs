@@ -0,0 +1,188 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_fast_unstake
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2021-12-20, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+
+// Executed Command:
+// ./target/release/substrate
+// benchmark
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_fast_unstake
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/fast-unstake/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_fast_unstake.
+pub trait WeightInfo {
+	fn on_idle_unstake() -> Weight;
+	fn on_idle_check(x: u32, ) -> Weight;
+	fn register_fast_unstake() -> Weight;
+	fn deregister() -> Weight;
+	fn control() -> Weight;
+}
+
+/// Weights for pallet_fast_unstake using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: FastUnstake ErasToCheckPerBlock (r:1 w:0)
+	// Storage: FastUnstake Head (r:1 w:1)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Staking SlashingSpans (r:1 w:0)
+	// Storage: Staking Bonded (r:1 w:1)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Staking Validators (r:1 w:0)
+	// Storage: Staking Nominators (r:1 w:0)
+	// Storage: Staking Payee (r:0 w:1)
+	fn on_idle_unstake() -> Weight {
+		(73_442_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: FastUnstake ErasToCheckPerBlock (r:1 w:0)
+	// Storage: FastUnstake Head (r:1 w:1)
+	// Storage: FastUnstake CounterForQueue (r:1 w:0)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Staking ErasStakersOverview (r:65 w:0)
+	// Storage: Staking ErasStakersPaged (r:65 w:0)
+	fn on_idle_check(x: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 2_137_000
+			.saturating_add((5_672_126_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((65 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: FastUnstake ErasToCheckPerBlock (r:1 w:0)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: FastUnstake Queue (r:1 w:1)
+	// Storage: FastUnstake Head (r:1 w:0)
+	// Storage: Staking Nominators (r:1 w:1)
+	// Storage: Staking Validators (r:1 w:0)
+	// Storage: Staking CounterForNominators (r:1 w:1)
+	// Storage: BagsList ListNodes (r:2 w:2)
+	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: FastUnstake CounterForQueue (r:1 w:1)
+	fn register_fast_unstake() -> Weight {
+		(98_621_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Staking Ledger (r:1 w:0)
+	// Storage: FastUnstake Head (r:1 w:0)
+	// Storage: FastUnstake Queue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: FastUnstake CounterForQueue (r:1 w:1)
+	fn deregister() -> Weight {
+		(36_184_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: FastUnstake ErasToCheckPerBlock (r:0 w:1)
+	fn control() -> Weight {
+		(2_721_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: FastUnstake ErasToCheckPerBlock (r:1 w:0)
+	// Storage: FastUnstake Head (r:1 w:1)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Staking SlashingSpans (r:1 w:0)
+	// Storage: Staking Bonded (r:1 w:1)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Staking Validators (r:1 w:0)
+	// Storage: Staking Nominators (r:1 w:0)
+	// Storage: Staking Payee (r:0 w:1)
+	fn on_idle_unstake() -> Weight {
+		(73_442_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: FastUnstake ErasToCheckPerBlock (r:1 w:0)
+	// Storage: FastUnstake Head (r:1 w:1)
+	// Storage: FastUnstake CounterForQueue (r:1 w:0)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Staking ErasStakersOverview (r:65 w:0)
+	// Storage: Staking ErasStakersPaged (r:65 w:0)
+	fn on_idle_check(x: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 2_137_000
+			.saturating_add((5_672_126_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((65 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: FastUnstake ErasToCheckPerBlock (r:1 w:0)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: FastUnstake Queue (r:1 w:1)
+	// Storage: FastUnstake Head (r:1 w:0)
+	// Storage: Staking Nominators (r:1 w:1)
+	// Storage: Staking Validators (r:1 w:0)
+	// Storage: Staking CounterForNominators (r:1 w:1)
+	// Storage: BagsList ListNodes (r:2 w:2)
+	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: FastUnstake CounterForQueue (r:1 w:1)
+	fn register_fast_unstake() -> Weight {
+		(98_621_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Staking Ledger (r:1 w:0)
+	// Storage: FastUnstake Head (r:1 w:0)
+	// Storage: FastUnstake Queue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: FastUnstake CounterForQueue (r:1 w:1)
+	fn deregister() -> Weight {
+		(36_184_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: FastUnstake ErasToCheckPerBlock (r:0 w:1)
+	fn control() -> Weight {
+		(2_721_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
@@ -23,7 +23,10 @@ use super::*;
 
 use crate::Pallet as Identity;
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
-use frame_support::{ensure, traits::Get};
+use frame_support::{
+	ensure,
+	traits::{EnsureOrigin, Get},
+};
 use frame_system::RawOrigin;
 use sp_runtime::traits::Bounded;
 
@@ -44,14 +47,7 @@ fn add_registrars<T: Config>(r: u32) -> Result<(), &'static str> {
 			i.into(),
 			10u32.into(),
 		)?;
-		let fields =
-			IdentityFields(
-				IdentityField::Display |
-					IdentityField::Legal | IdentityField::Web |
-					IdentityField::Riot | IdentityField::Email |
-					IdentityField::PgpFingerprint |
-					IdentityField::Image | IdentityField::Twitter,
-			);
+		let fields = T::IdentityInformation::all_fields();
 		Identity::<T>::set_fields(RawOrigin::Signed(registrar.clone()).into(), i.into(), fields)?;
 	}
 
@@ -96,24 +92,52 @@ fn add_sub_accounts<T: Config>(
 	Ok(subs)
 }
 
-// This creates an `IdentityInfo` object with `num_fields` extra fields.
+// This creates an identity with `num_fields` extra fields.
 // All data is pre-populated with some arbitrary bytes.
-fn create_identity_info<T: Config>(num_fields: u32) -> IdentityInfo<T::MaxAdditionalFields> {
-	let data = Data::Raw(vec![0; 32].try_into().unwrap());
+fn create_identity_info<T: Config>(num_fields: u32) -> T::IdentityInformation {
+	T::IdentityInformation::create_identity_info(num_fields)
+}
+
+// Adds `authority` as a username authority with the longest possible suffix.
+fn setup_username_authority<T: Config>(authority: &T::AccountId) -> Result<(), &'static str> {
+	let origin = T::UsernameAuthorityOrigin::successful_origin();
+	let suffix = vec![b'a'; T::MaxSuffixLength::get() as usize];
+	Identity::<T>::add_username_authority(
+		origin,
+		T::Lookup::unlookup(authority.clone()),
+		suffix,
+		u32::MAX,
+	)
+	.map_err(|_| "Cannot add username authority")?;
+	Ok(())
+}
 
-	let info = IdentityInfo {
-		additional: vec![(data.clone(), data.clone()); num_fields as usize].try_into().unwrap(),
-		display: data.clone(),
-		legal: data.clone(),
-		web: data.clone(),
-		riot: data.clone(),
-		email: data.clone(),
-		pgp_fingerprint: Some([0; 20]),
-		image: data.clone(),
-		twitter: data.clone(),
-	};
-
-	return info
+// The longest name that, together with the longest suffix, fits in a username.
+fn create_name<T: Config>() -> Vec<u8> {
+	let len = T::MaxUsernameLength::get().saturating_sub(T::MaxSuffixLength::get() + 1).max(1);
+	vec![b'b'; len as usize]
+}
+
+// Grants a new username to `who` that it still has to accept, and returns it.
+fn queue_username<T: Config>(who: &T::AccountId) -> Result<Username<T>, &'static str> {
+	let authority: T::AccountId = account("authority", 0, SEED);
+	if !UsernameAuthorities::<T>::contains_key(&authority) {
+		setup_username_authority::<T>(&authority)?;
+	}
+	// make sure that the usernames granted to the same account differ.
+	let mut name = create_name::<T>();
+	let count = PendingUsernames::<T>::iter().count() + AccountOfUsername::<T>::iter().count();
+	name[0] = b'0' + (count % 10) as u8;
+	Identity::<T>::set_username_for(
+		RawOrigin::Signed(authority).into(),
+		T::Lookup::unlookup(who.clone()),
+		name,
+		None,
+	)?;
+	PendingUsernames::<T>::iter()
+		.find(|(_, (account, _))| account == who)
+		.map(|(username, _)| username)
+		.ok_or("Username not queued")
 }
 
 benchmarks! {
@@ -296,10 +320,7 @@ benchmarks! {
 		let r in 1 .. T::MaxRegistrars::get() - 1 => add_registrars::<T>(r)?;
 
 		Identity::<T>::add_registrar(RawOrigin::Root.into(), caller.clone())?;
-		let fields = IdentityFields(
-			IdentityField::Display | IdentityField::Legal | IdentityField::Web | IdentityField::Riot
-			| IdentityField::Email | IdentityField::PgpFingerprint | IdentityField::Image | IdentityField::Twitter
-		);
+		let fields = T::IdentityInformation::all_fields();
 		let registrars = Registrars::<T>::get();
 		ensure!(registrars[r as usize].as_ref().unwrap().fields == Default::default(), "fields already set.");
 	}: _(RawOrigin::Signed(caller), r, fields)
@@ -411,5 +432,71 @@ benchmarks! {
 		ensure!(!SuperOf::<T>::contains_key(&caller), "Sub not removed");
 	}
 
+	add_username_authority {
+		let authority: T::AccountId = account("authority", 0, SEED);
+		// Worst case: the authority already exists and its suffix is changed.
+		setup_username_authority::<T>(&authority)?;
+		let origin = T::UsernameAuthorityOrigin::successful_origin();
+		let suffix = vec![b'b'; T::MaxSuffixLength::get() as usize];
+	}: _<T::Origin>(origin, T::Lookup::unlookup(authority.clone()), suffix, 10)
+	verify {
+		let suffix: Suffix<T> = vec![b'b'; T::MaxSuffixLength::get() as usize].try_into().unwrap();
+		ensure!(AuthorityOfSuffix::<T>::get(&suffix) == Some(authority), "Authority not added");
+	}
+
+	remove_username_authority {
+		let authority: T::AccountId = account("authority", 0, SEED);
+		setup_username_authority::<T>(&authority)?;
+		let origin = T::UsernameAuthorityOrigin::successful_origin();
+	}: _<T::Origin>(origin, T::Lookup::unlookup(authority.clone()))
+	verify {
+		ensure!(!UsernameAuthorities::<T>::contains_key(&authority), "Authority not removed");
+	}
+
+	set_username_for {
+		let authority: T::AccountId = account("authority", 0, SEED);
+		setup_username_authority::<T>(&authority)?;
+		let who: T::AccountId = account("target", 0, SEED);
+		let name = create_name::<T>();
+	}: _(RawOrigin::Signed(authority), T::Lookup::unlookup(who.clone()), name, None)
+	verify {
+		ensure!(
+			PendingUsernames::<T>::iter_values().any(|(account, _)| account == who),
+			"Username not queued"
+		);
+	}
+
+	accept_username {
+		let caller: T::AccountId = whitelisted_caller();
+		let username = queue_username::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller.clone()), username.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::PrimaryUsernameSet { who: caller, username }.into());
+	}
+
+	remove_expired_approval {
+		let caller: T::AccountId = whitelisted_caller();
+		let who: T::AccountId = account("target", 0, SEED);
+		let username = queue_username::<T>(&who)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(
+			now + T::PendingUsernameExpiration::get() + 1u32.into(),
+		);
+	}: _(RawOrigin::Signed(caller), username.clone())
+	verify {
+		ensure!(!PendingUsernames::<T>::contains_key(&username), "Username not removed");
+	}
+
+	set_primary_username {
+		let caller: T::AccountId = whitelisted_caller();
+		let first = queue_username::<T>(&caller)?;
+		Identity::<T>::accept_username(RawOrigin::Signed(caller.clone()).into(), first)?;
+		let username = queue_username::<T>(&caller)?;
+		Identity::<T>::accept_username(RawOrigin::Signed(caller.clone()).into(), username.clone())?;
+	}: _(RawOrigin::Signed(caller.clone()), username.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::PrimaryUsernameSet { who: caller, username }.into());
+	}
+
 	impl_benchmark_test_suite!(Identity, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -39,6 +39,17 @@
 //!
 //! The number of registrars should be limited, and the deposit made sufficiently large, to ensure
 //! no state-bloat attack is viable.
+//!//!
+//! The fields of an identity are defined by the runtime through the `IdentityInformation` trait.
+//! `IdentityInfo` provides the set of fields that this pallet has always supported.
+//!
+//! ### Usernames
+//!
+//! A username authority, added by a privileged origin, can grant unique usernames of the form
+//! `name.suffix` to accounts, where `suffix` is specific to the authority. The receiving account
+//! either signs the username off-chain, or accepts it on-chain within a limited number of blocks.
+//! An account may own several usernames, one of which is its primary username. Usernames can be
+//! looked up by account and accounts by username.
 //!
 //! ## Interface
 //!
@@ -66,6 +77,16 @@
 //! #### For super-users
 //! * `add_registrar` - Add a new registrar to the system.
 //! * `kill_identity` - Forcibly remove the associated identity; the deposit is lost.
+//!//! * `add_username_authority` - Add an account that may grant usernames.
+//! * `remove_username_authority` - Remove a username authority.
+//!
+//! #### For username authorities
+//! * `set_username_for` - Grant a username to an account.
+//!
+//! #### For username owners
+//! * `accept_username` - Accept a username granted without a signature.
+//! * `set_primary_username` - Choose the primary username of an account.
+//! * `remove_expired_approval` - Remove a granted username that was not accepted in time.
 //!
 //! [`Call`]: ./enum.Call.html
 //! [`Config`]: ./trait.Config.html
@@ -73,19 +94,26 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
+mod legacy;
 #[cfg(test)]
 mod tests;
 mod types;
 pub mod weights;
 
-use frame_support::traits::{BalanceStatus, Currency, OnUnbalanced, ReservableCurrency};
-use sp_runtime::traits::{AppendZerosInput, Saturating, StaticLookup, Zero};
+use frame_support::{
+	traits::{BalanceStatus, Currency, OnUnbalanced, ReservableCurrency},
+	BoundedVec,
+};
+use sp_runtime::traits::{
+	AppendZerosInput, IdentifyAccount, Saturating, StaticLookup, Verify, Zero,
+};
 use sp_std::prelude::*;
 pub use weights::WeightInfo;
 
+pub use legacy::{IdentityField, IdentityFields, IdentityInfo};
 pub use pallet::*;
 pub use types::{
-	Data, IdentityField, IdentityFields, IdentityInfo, Judgement, RegistrarIndex, RegistrarInfo,
+	AuthorityProperties, Data, IdentityInformation, Judgement, RegistrarIndex, RegistrarInfo,
 	Registration,
 };
 
@@ -94,6 +122,19 @@ type BalanceOf<T> =
 type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::NegativeImbalance;
+type FieldsIdentifierOf<T> =
+	<<T as Config>::IdentityInformation as IdentityInformation>::FieldsIdentifier;
+type RegistrationOf<T> =
+	Registration<BalanceOf<T>, <T as Config>::MaxRegistrars, <T as Config>::IdentityInformation>;
+type RegistrarInfoOf<T> =
+	RegistrarInfo<BalanceOf<T>, <T as frame_system::Config>::AccountId, FieldsIdentifierOf<T>>;
+
+/// A full username, i.e. a name followed by a `.` and the suffix of the granting authority.
+pub type Username<T> = BoundedVec<u8, <T as Config>::MaxUsernameLength>;
+/// The suffix that an authority appends to the usernames that it grants.
+pub type Suffix<T> = BoundedVec<u8, <T as Config>::MaxSuffixLength>;
+/// The properties of a username authority.
+pub type AuthorityPropertiesOf<T> = AuthorityProperties<Suffix<T>>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -132,6 +173,9 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAdditionalFields: Get<u32>;
 
+		/// The information that may be stored as the identity of an account.
+		type IdentityInformation: IdentityInformation;
+
 		/// Maxmimum number of registrars allowed in the system. Needed to bound the complexity
 		/// of, e.g., updating judgements.
 		#[pallet::constant]
@@ -146,6 +190,28 @@ pub mod pallet {
 		/// The origin which may add or remove registrars. Root can always do this.
 		type RegistrarOrigin: EnsureOrigin<Self::Origin>;
 
+		/// A signature that an account can provide off-chain to accept a username.
+		type OffchainSignature: Verify<Signer = Self::SigningPublicKey> + Parameter;
+
+		/// The public key of an account that provides an `OffchainSignature`.
+		type SigningPublicKey: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// The origin which may add or remove username authorities.
+		type UsernameAuthorityOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The number of blocks within which a username granted without a signature must be
+		/// accepted by its account.
+		#[pallet::constant]
+		type PendingUsernameExpiration: Get<Self::BlockNumber>;
+
+		/// The maximum length of the suffix of a username authority.
+		#[pallet::constant]
+		type MaxSuffixLength: Get<u32>;
+
+		/// The maximum length of a username, including its suffix and the separating `.`.
+		#[pallet::constant]
+		type MaxUsernameLength: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -160,13 +226,8 @@ pub mod pallet {
 	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
 	#[pallet::storage]
 	#[pallet::getter(fn identity)]
-	pub(super) type IdentityOf<T: Config> = StorageMap<
-		_,
-		Twox64Concat,
-		T::AccountId,
-		Registration<BalanceOf<T>, T::MaxRegistrars, T::MaxAdditionalFields>,
-		OptionQuery,
-	>;
+	pub(super) type IdentityOf<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RegistrationOf<T>, OptionQuery>;
 
 	/// The super-identity of an alternative "sub" identity together with its name, within that
 	/// context. If the account is not some other account's sub-identity, then just `None`.
@@ -196,11 +257,46 @@ pub mod pallet {
 	/// The index into this can be cast to `RegistrarIndex` to get a valid value.
 	#[pallet::storage]
 	#[pallet::getter(fn registrars)]
-	pub(super) type Registrars<T: Config> = StorageValue<
-		_,
-		BoundedVec<Option<RegistrarInfo<BalanceOf<T>, T::AccountId>>, T::MaxRegistrars>,
-		ValueQuery,
-	>;
+	pub(super) type Registrars<T: Config> =
+		StorageValue<_, BoundedVec<Option<RegistrarInfoOf<T>>, T::MaxRegistrars>, ValueQuery>;
+
+	/// The accounts that may grant usernames, along with their suffix and remaining allocation.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	#[pallet::getter(fn username_authority)]
+	pub(super) type UsernameAuthorities<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, AuthorityPropertiesOf<T>, OptionQuery>;
+
+	/// The username authority that grants usernames with a given suffix, i.e. the reverse lookup
+	/// of the suffix in `UsernameAuthorities`.
+	#[pallet::storage]
+	#[pallet::getter(fn authority_of_suffix)]
+	pub(super) type AuthorityOfSuffix<T: Config> =
+		StorageMap<_, Blake2_128Concat, Suffix<T>, T::AccountId, OptionQuery>;
+
+	/// The account that owns a username.
+	///
+	/// An account may own several usernames, but each username has a single owner.
+	#[pallet::storage]
+	#[pallet::getter(fn account_of_username)]
+	pub(super) type AccountOfUsername<T: Config> =
+		StorageMap<_, Blake2_128Concat, Username<T>, T::AccountId, OptionQuery>;
+
+	/// The primary username of an account, i.e. the reverse lookup of `AccountOfUsername`.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	#[pallet::getter(fn username_of)]
+	pub(super) type UsernameOf<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, Username<T>, OptionQuery>;
+
+	/// Usernames that an authority granted without a signature of the receiving account, along
+	/// with that account and the block after which the username may no longer be accepted.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_username)]
+	pub(super) type PendingUsernames<T: Config> =
+		StorageMap<_, Blake2_128Concat, Username<T>, (T::AccountId, T::BlockNumber), OptionQuery>;
 
 	#[pallet::error]
 	pub enum Error<T> {
@@ -236,6 +332,25 @@ pub mod pallet {
 		NotSub,
 		/// Sub-account isn't owned by sender.
 		NotOwned,
+		/// The sender is not a username authority.
+		NotUsernameAuthority,
+		/// The username authority has no allocation left.
+		NoAllocation,
+		/// The suffix is empty, too long or contains invalid characters.
+		InvalidSuffix,
+		/// The username is empty, too long, contains invalid characters or does not belong to the
+		/// sender.
+		InvalidUsername,
+		/// The username is already taken or pending.
+		UsernameTaken,
+		/// The username does not exist.
+		NoUsername,
+		/// The signature does not match the account and the username.
+		InvalidSignature,
+		/// The pending username has not expired yet.
+		NotExpired,
+		/// The suffix is already used by another username authority.
+		SuffixInUse,
 	}
 
 	#[pallet::event]
@@ -262,6 +377,18 @@ pub mod pallet {
 		/// A sub-identity was cleared, and the given deposit repatriated from the
 		/// main identity account to the sub-identity account.
 		SubIdentityRevoked { sub: T::AccountId, main: T::AccountId, deposit: BalanceOf<T> },
+		/// A username authority was added or updated.
+		AuthorityAdded { authority: T::AccountId, suffix: Suffix<T>, allocation: u32 },
+		/// A username authority was removed.
+		AuthorityRemoved { authority: T::AccountId },
+		/// A username was granted to an account.
+		UsernameSet { who: T::AccountId, username: Username<T> },
+		/// A username was granted and must be accepted by the account before `expiration`.
+		UsernameQueued { who: T::AccountId, username: Username<T>, expiration: T::BlockNumber },
+		/// A pending username expired without being accepted.
+		PreapprovalExpired { whose: T::AccountId, username: Username<T> },
+		/// A username was set as the primary username of an account.
+		PrimaryUsernameSet { who: T::AccountId, username: Username<T> },
 	}
 
 	#[pallet::call]
@@ -330,10 +457,10 @@ pub mod pallet {
 		))]
 		pub fn set_identity(
 			origin: OriginFor<T>,
-			info: Box<IdentityInfo<T::MaxAdditionalFields>>,
+			info: Box<T::IdentityInformation>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
-			let extra_fields = info.additional.len() as u32;
+			let extra_fields = info.additional() as u32;
 			ensure!(extra_fields <= T::MaxAdditionalFields::get(), Error::<T>::TooManyFields);
 			let fd = <BalanceOf<T>>::from(extra_fields) * T::FieldDeposit::get();
 
@@ -491,9 +618,9 @@ pub mod pallet {
 			Self::deposit_event(Event::IdentityCleared { who: sender, deposit });
 
 			Ok(Some(T::WeightInfo::clear_identity(
-				id.judgements.len() as u32,      // R
-				sub_ids.len() as u32,            // S
-				id.info.additional.len() as u32, // X
+				id.judgements.len() as u32,  // R
+				sub_ids.len() as u32,        // S
+				id.info.additional() as u32, // X
 			))
 			.into())
 		}
@@ -554,7 +681,7 @@ pub mod pallet {
 			T::Currency::reserve(&sender, registrar.fee)?;
 
 			let judgements = id.judgements.len();
-			let extra_fields = id.info.additional.len();
+			let extra_fields = id.info.additional();
 			<IdentityOf<T>>::insert(&sender, id);
 
 			Self::deposit_event(Event::JudgementRequested {
@@ -607,7 +734,7 @@ pub mod pallet {
 			let err_amount = T::Currency::unreserve(&sender, fee);
 			debug_assert!(err_amount.is_zero());
 			let judgements = id.judgements.len();
-			let extra_fields = id.info.additional.len();
+			let extra_fields = id.info.additional();
 			<IdentityOf<T>>::insert(&sender, id);
 
 			Self::deposit_event(Event::JudgementUnrequested {
@@ -711,7 +838,7 @@ pub mod pallet {
 		pub fn set_fields(
 			origin: OriginFor<T>,
 			#[pallet::compact] index: RegistrarIndex,
-			fields: IdentityFields,
+			fields: FieldsIdentifierOf<T>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
@@ -794,7 +921,7 @@ pub mod pallet {
 			}
 
 			let judgements = id.judgements.len();
-			let extra_fields = id.info.additional.len();
+			let extra_fields = id.info.additional();
 			<IdentityOf<T>>::insert(&target, id);
 			Self::deposit_event(Event::JudgementGiven { target, registrar_index: reg_index });
 
@@ -847,9 +974,9 @@ pub mod pallet {
 			Self::deposit_event(Event::IdentityKilled { who: target, deposit });
 
 			Ok(Some(T::WeightInfo::kill_identity(
-				id.judgements.len() as u32,      // R
-				sub_ids.len() as u32,            // S
-				id.info.additional.len() as u32, // X
+				id.judgements.len() as u32,  // R
+				sub_ids.len() as u32,        // S
+				id.info.additional() as u32, // X
 			))
 			.into())
 		}
@@ -967,6 +1094,176 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Add an account that may grant usernames ending in `.suffix`, or update the suffix and
+		/// allocation of an existing one. Each suffix belongs to a single authority.
+		///
+		/// The dispatch origin for this call must be `T::UsernameAuthorityOrigin`.
+		///
+		/// - `authority`: the account that may grant usernames.
+		/// - `suffix`: the suffix of the granted usernames. It must only contain lowercase ASCII
+		///   letters and digits.
+		/// - `allocation`: the number of usernames that the authority may grant.
+		///
+		/// Emits `AuthorityAdded` if successful.
+		#[pallet::weight(T::WeightInfo::add_username_authority())]
+		pub fn add_username_authority(
+			origin: OriginFor<T>,
+			authority: <T::Lookup as StaticLookup>::Source,
+			suffix: Vec<u8>,
+			allocation: u32,
+		) -> DispatchResult {
+			T::UsernameAuthorityOrigin::ensure_origin(origin)?;
+			let authority = T::Lookup::lookup(authority)?;
+			ensure!(Self::is_valid_name(&suffix), Error::<T>::InvalidSuffix);
+			let suffix: Suffix<T> = suffix.try_into().map_err(|_| Error::<T>::InvalidSuffix)?;
+			if let Some(owner) = AuthorityOfSuffix::<T>::get(&suffix) {
+				ensure!(owner == authority, Error::<T>::SuffixInUse);
+			}
+
+			if let Some(old) = UsernameAuthorities::<T>::get(&authority) {
+				AuthorityOfSuffix::<T>::remove(&old.suffix);
+			}
+			AuthorityOfSuffix::<T>::insert(&suffix, &authority);
+			UsernameAuthorities::<T>::insert(
+				&authority,
+				AuthorityProperties { suffix: suffix.clone(), allocation },
+			);
+			Self::deposit_event(Event::AuthorityAdded { authority, suffix, allocation });
+			Ok(())
+		}
+
+		/// Remove a username authority. The usernames that it granted are not affected.
+		///
+		/// The dispatch origin for this call must be `T::UsernameAuthorityOrigin`.
+		///
+		/// Emits `AuthorityRemoved` if successful.
+		#[pallet::weight(T::WeightInfo::remove_username_authority())]
+		pub fn remove_username_authority(
+			origin: OriginFor<T>,
+			authority: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::UsernameAuthorityOrigin::ensure_origin(origin)?;
+			let authority = T::Lookup::lookup(authority)?;
+			let properties = UsernameAuthorities::<T>::take(&authority)
+				.ok_or(Error::<T>::NotUsernameAuthority)?;
+			AuthorityOfSuffix::<T>::remove(&properties.suffix);
+			Self::deposit_event(Event::AuthorityRemoved { authority });
+			Ok(())
+		}
+
+		/// Grant the username `username.suffix` to `who`, where `suffix` is the suffix of the
+		/// sender.
+		///
+		/// If `signature` is a valid signature of `who` over the full username, the username is
+		/// granted directly. Otherwise, `who` must accept it with `accept_username` within
+		/// `T::PendingUsernameExpiration` blocks.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be a username
+		/// authority with some allocation left, which this uses up.
+		///
+		/// - `who`: the account that receives the username.
+		/// - `username`: the username without the suffix. It must only contain lowercase ASCII
+		///   letters and digits.
+		/// - `signature`: the signature of `who` over the full username, if any.
+		///
+		/// Emits `UsernameSet` or `UsernameQueued` if successful.
+		#[pallet::weight(T::WeightInfo::set_username_for())]
+		pub fn set_username_for(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			username: Vec<u8>,
+			signature: Option<T::OffchainSignature>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			let mut authority =
+				UsernameAuthorities::<T>::get(&sender).ok_or(Error::<T>::NotUsernameAuthority)?;
+			authority.allocation =
+				authority.allocation.checked_sub(1).ok_or(Error::<T>::NoAllocation)?;
+
+			ensure!(Self::is_valid_name(&username), Error::<T>::InvalidUsername);
+			let username: Username<T> = username
+				.into_iter()
+				.chain(sp_std::iter::once(b'.'))
+				.chain(authority.suffix.iter().cloned())
+				.collect::<Vec<_>>()
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidUsername)?;
+			ensure!(
+				!AccountOfUsername::<T>::contains_key(&username) &&
+					!PendingUsernames::<T>::contains_key(&username),
+				Error::<T>::UsernameTaken
+			);
+
+			if let Some(signature) = &signature {
+				ensure!(signature.verify(&username[..], &who), Error::<T>::InvalidSignature);
+			}
+
+			UsernameAuthorities::<T>::insert(&sender, authority);
+			if signature.is_some() {
+				Self::insert_username(who, username);
+			} else {
+				let expiration = frame_system::Pallet::<T>::block_number()
+					.saturating_add(T::PendingUsernameExpiration::get());
+				PendingUsernames::<T>::insert(&username, (who.clone(), expiration));
+				Self::deposit_event(Event::UsernameQueued { who, username, expiration });
+			}
+			Ok(())
+		}
+
+		/// Accept a username that an authority granted to the sender without their signature.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the account
+		/// that the username was granted to.
+		///
+		/// Emits `UsernameSet` if successful.
+		#[pallet::weight(T::WeightInfo::accept_username())]
+		pub fn accept_username(origin: OriginFor<T>, username: Username<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (approved_for, _) =
+				PendingUsernames::<T>::get(&username).ok_or(Error::<T>::NoUsername)?;
+			ensure!(approved_for == who, Error::<T>::InvalidUsername);
+			PendingUsernames::<T>::remove(&username);
+			Self::insert_username(who, username);
+			Ok(())
+		}
+
+		/// Remove a pending username that was not accepted in time.
+		///
+		/// The dispatch origin for this call must be _Signed_. The call is free if successful.
+		///
+		/// Emits `PreapprovalExpired` if successful.
+		#[pallet::weight(T::WeightInfo::remove_expired_approval())]
+		pub fn remove_expired_approval(
+			origin: OriginFor<T>,
+			username: Username<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let (whose, expiration) =
+				PendingUsernames::<T>::get(&username).ok_or(Error::<T>::NoUsername)?;
+			ensure!(frame_system::Pallet::<T>::block_number() > expiration, Error::<T>::NotExpired);
+			PendingUsernames::<T>::remove(&username);
+			Self::deposit_event(Event::PreapprovalExpired { whose, username });
+			Ok(Pays::No.into())
+		}
+
+		/// Set one of the usernames of the sender as their primary username, i.e. the one that
+		/// is returned when looking up the username of the sender.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must own `username`.
+		///
+		/// Emits `PrimaryUsernameSet` if successful.
+		#[pallet::weight(T::WeightInfo::set_primary_username())]
+		pub fn set_primary_username(origin: OriginFor<T>, username: Username<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = AccountOfUsername::<T>::get(&username).ok_or(Error::<T>::NoUsername)?;
+			ensure!(owner == who, Error::<T>::InvalidUsername);
+			UsernameOf::<T>::insert(&who, &username);
+			Self::deposit_event(Event::PrimaryUsernameSet { who, username });
+			Ok(())
+		}
 	}
 }
 
@@ -979,4 +1276,27 @@ impl<T: Config> Pallet<T> {
 			.filter_map(|a| SuperOf::<T>::get(&a).map(|x| (a, x.1)))
 			.collect()
 	}
+
+	/// Check if the account has the given identity fields set. For [`IdentityInfo`], `fields` is
+	/// a bitmask of [`IdentityField`]s.
+	pub fn has_identity(who: &T::AccountId, fields: FieldsIdentifierOf<T>) -> bool {
+		IdentityOf::<T>::get(who)
+			.map_or(false, |registration| registration.info.has_identity(fields))
+	}
+
+	/// Whether `name` can be used as a suffix or as a username before its suffix, i.e. it is not
+	/// empty and only contains lowercase ASCII letters and digits.
+	fn is_valid_name(name: &[u8]) -> bool {
+		!name.is_empty() && name.iter().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+	}
+
+	/// Grant `username` to `who`, making it their primary username if they have none yet.
+	fn insert_username(who: T::AccountId, username: Username<T>) {
+		AccountOfUsername::<T>::insert(&username, &who);
+		Self::deposit_event(Event::UsernameSet { who: who.clone(), username: username.clone() });
+		if !UsernameOf::<T>::contains_key(&who) {
+			UsernameOf::<T>::insert(&who, &username);
+			Self::deposit_event(Event::PrimaryUsernameSet { who, username });
+		}
+	}
 }
@@ -27,7 +27,7 @@ use frame_support::{
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{BadOrigin, BlakeTwo256, IdentityLookup},
 };
 
@@ -97,6 +97,9 @@ parameter_types! {
 	pub const MaxSubAccounts: u32 = 2;
 	pub const MaxAdditionalFields: u32 = 2;
 	pub const MaxRegistrars: u32 = 20;
+	pub const PendingUsernameExpiration: u64 = 100;
+	pub const MaxSuffixLength: u32 = 7;
+	pub const MaxUsernameLength: u32 = 16;
 }
 ord_parameter_types! {
 	pub const One: u64 = 1;
@@ -113,9 +116,16 @@ impl pallet_identity::Config for Test {
 	type SubAccountDeposit = SubAccountDeposit;
 	type MaxSubAccounts = MaxSubAccounts;
 	type MaxAdditionalFields = MaxAdditionalFields;
+	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
 	type RegistrarOrigin = EnsureOneOrRoot;
 	type ForceOrigin = EnsureTwoOrRoot;
+	type OffchainSignature = TestSignature;
+	type SigningPublicKey = UintAuthorityId;
+	type UsernameAuthorityOrigin = EnsureOneOrRoot;
+	type PendingUsernameExpiration = PendingUsernameExpiration;
+	type MaxSuffixLength = MaxSuffixLength;
+	type MaxUsernameLength = MaxUsernameLength;
 	type WeightInfo = ();
 }
 
@@ -236,7 +246,7 @@ fn adding_registrar_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Identity::add_registrar(Origin::signed(1), 3));
 		assert_ok!(Identity::set_fee(Origin::signed(3), 0, 10));
-		let fields = IdentityFields(IdentityField::Display | IdentityField::Legal);
+		let fields = (IdentityField::Display | IdentityField::Legal).bits();
 		assert_ok!(Identity::set_fields(Origin::signed(3), 0, fields));
 		assert_eq!(
 			Identity::registrars(),
@@ -502,3 +512,209 @@ fn setting_account_id_should_work() {
 		assert_ok!(Identity::set_account_id(Origin::signed(4), 0, 3));
 	});
 }
+
+#[test]
+fn has_identity_checks_the_given_fields() {
+	new_test_ext().execute_with(|| {
+		assert!(!Identity::has_identity(&10, IdentityField::Display as u64));
+		assert_ok!(Identity::set_identity(Origin::signed(10), Box::new(ten())));
+		assert!(Identity::has_identity(&10, IdentityField::Display as u64));
+		assert!(Identity::has_identity(
+			&10,
+			(IdentityField::Display | IdentityField::Legal).bits()
+		));
+		assert!(!Identity::has_identity(&10, (IdentityField::Display | IdentityField::Web).bits()));
+	});
+}
+
+fn username(name: &[u8]) -> Username<Test> {
+	name.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn username_authorities_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Identity::add_username_authority(Origin::signed(3), 3, b"dot".to_vec(), 1),
+			BadOrigin
+		);
+		assert_noop!(
+			Identity::add_username_authority(Origin::signed(1), 3, b"Dot".to_vec(), 1),
+			Error::<Test>::InvalidSuffix
+		);
+		assert_noop!(
+			Identity::add_username_authority(Origin::signed(1), 3, b"toolongsuffix".to_vec(), 1),
+			Error::<Test>::InvalidSuffix
+		);
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 3, b"dot".to_vec(), 1));
+		assert_eq!(
+			Identity::username_authority(3),
+			Some(AuthorityProperties {
+				suffix: b"dot".to_vec().try_into().unwrap(),
+				allocation: 1
+			})
+		);
+
+		// the allocation is used up by granting a username.
+		assert_ok!(Identity::set_username_for(Origin::signed(3), 10, b"ten".to_vec(), None));
+		assert_eq!(Identity::username_authority(3).unwrap().allocation, 0);
+		assert_noop!(
+			Identity::set_username_for(Origin::signed(3), 20, b"twenty".to_vec(), None),
+			Error::<Test>::NoAllocation
+		);
+
+		assert_ok!(Identity::remove_username_authority(Origin::signed(1), 3));
+		assert_noop!(
+			Identity::remove_username_authority(Origin::signed(1), 3),
+			Error::<Test>::NotUsernameAuthority
+		);
+		assert_noop!(
+			Identity::set_username_for(Origin::signed(3), 20, b"twenty".to_vec(), None),
+			Error::<Test>::NotUsernameAuthority
+		);
+	});
+}
+
+#[test]
+fn username_authority_suffix_should_be_unique() {
+	new_test_ext().execute_with(|| {
+		let dot: Suffix<Test> = b"dot".to_vec().try_into().unwrap();
+		let ksm: Suffix<Test> = b"ksm".to_vec().try_into().unwrap();
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 3, b"dot".to_vec(), 1));
+		assert_eq!(Identity::authority_of_suffix(&dot), Some(3));
+
+		// another authority can't take the suffix...
+		assert_noop!(
+			Identity::add_username_authority(Origin::signed(1), 4, b"dot".to_vec(), 1),
+			Error::<Test>::SuffixInUse
+		);
+		// ...but the authority itself may update its allocation.
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 3, b"dot".to_vec(), 5));
+		assert_eq!(Identity::username_authority(3).unwrap().allocation, 5);
+
+		// changing the suffix frees the old one.
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 3, b"ksm".to_vec(), 5));
+		assert_eq!(Identity::authority_of_suffix(&dot), None);
+		assert_eq!(Identity::authority_of_suffix(&ksm), Some(3));
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 4, b"dot".to_vec(), 1));
+
+		// and so does removing the authority.
+		assert_ok!(Identity::remove_username_authority(Origin::signed(1), 3));
+		assert_eq!(Identity::authority_of_suffix(&ksm), None);
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 5, b"ksm".to_vec(), 1));
+	});
+}
+
+#[test]
+fn setting_username_with_signature_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 3, b"dot".to_vec(), 10));
+
+		for invalid in [&b""[..], b"Ten", b"te.n", b"thisnameistoolong"] {
+			assert_noop!(
+				Identity::set_username_for(Origin::signed(3), 10, invalid.to_vec(), None),
+				Error::<Test>::InvalidUsername
+			);
+		}
+
+		let bad_signature = TestSignature(20, b"ten.dot".to_vec());
+		assert_noop!(
+			Identity::set_username_for(Origin::signed(3), 10, b"ten".to_vec(), Some(bad_signature)),
+			Error::<Test>::InvalidSignature
+		);
+
+		let signature = TestSignature(10, b"ten.dot".to_vec());
+		assert_ok!(Identity::set_username_for(
+			Origin::signed(3),
+			10,
+			b"ten".to_vec(),
+			Some(signature)
+		));
+		assert_eq!(Identity::account_of_username(username(b"ten.dot")), Some(10));
+		assert_eq!(Identity::username_of(10), Some(username(b"ten.dot")));
+		assert_eq!(Identity::username_authority(3).unwrap().allocation, 9);
+
+		assert_noop!(
+			Identity::set_username_for(Origin::signed(3), 20, b"ten".to_vec(), None),
+			Error::<Test>::UsernameTaken
+		);
+
+		// a second username does not replace the primary one unless asked to.
+		let signature = TestSignature(10, b"tenth.dot".to_vec());
+		assert_ok!(Identity::set_username_for(
+			Origin::signed(3),
+			10,
+			b"tenth".to_vec(),
+			Some(signature)
+		));
+		assert_eq!(Identity::account_of_username(username(b"tenth.dot")), Some(10));
+		assert_eq!(Identity::username_of(10), Some(username(b"ten.dot")));
+
+		assert_noop!(
+			Identity::set_primary_username(Origin::signed(20), username(b"tenth.dot")),
+			Error::<Test>::InvalidUsername
+		);
+		assert_noop!(
+			Identity::set_primary_username(Origin::signed(10), username(b"eleven.dot")),
+			Error::<Test>::NoUsername
+		);
+		assert_ok!(Identity::set_primary_username(Origin::signed(10), username(b"tenth.dot")));
+		assert_eq!(Identity::username_of(10), Some(username(b"tenth.dot")));
+	});
+}
+
+#[test]
+fn accepting_pending_username_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 3, b"dot".to_vec(), 10));
+		assert_ok!(Identity::set_username_for(Origin::signed(3), 10, b"ten".to_vec(), None));
+		assert_eq!(Identity::pending_username(username(b"ten.dot")), Some((10, 101)));
+		assert_eq!(Identity::account_of_username(username(b"ten.dot")), None);
+
+		// pending usernames cannot be granted again.
+		assert_noop!(
+			Identity::set_username_for(Origin::signed(3), 20, b"ten".to_vec(), None),
+			Error::<Test>::UsernameTaken
+		);
+
+		assert_noop!(
+			Identity::accept_username(Origin::signed(20), username(b"ten.dot")),
+			Error::<Test>::InvalidUsername
+		);
+		assert_noop!(
+			Identity::accept_username(Origin::signed(10), username(b"eleven.dot")),
+			Error::<Test>::NoUsername
+		);
+		assert_ok!(Identity::accept_username(Origin::signed(10), username(b"ten.dot")));
+		assert_eq!(Identity::pending_username(username(b"ten.dot")), None);
+		assert_eq!(Identity::account_of_username(username(b"ten.dot")), Some(10));
+		assert_eq!(Identity::username_of(10), Some(username(b"ten.dot")));
+	});
+}
+
+#[test]
+fn expired_pending_username_can_be_removed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 3, b"dot".to_vec(), 10));
+		assert_ok!(Identity::set_username_for(Origin::signed(3), 10, b"ten".to_vec(), None));
+
+		System::set_block_number(101);
+		assert_noop!(
+			Identity::remove_expired_approval(Origin::signed(20), username(b"ten.dot")),
+			Error::<Test>::NotExpired
+		);
+
+		System::set_block_number(102);
+		assert_ok!(Identity::remove_expired_approval(Origin::signed(20), username(b"ten.dot")));
+		assert_eq!(Identity::pending_username(username(b"ten.dot")), None);
+		assert_noop!(
+			Identity::accept_username(Origin::signed(10), username(b"ten.dot")),
+			Error::<Test>::NoUsername
+		);
+
+		// the username can be granted again.
+		assert_ok!(Identity::set_username_for(Origin::signed(3), 20, b"ten".to_vec(), None));
+	});
+}
@@ -60,6 +60,12 @@ pub trait WeightInfo {
 	fn rename_sub(s: u32, ) -> Weight;
 	fn remove_sub(s: u32, ) -> Weight;
 	fn quit_sub(s: u32, ) -> Weight;
+	fn add_username_authority() -> Weight;
+	fn remove_username_authority() -> Weight;
+	fn set_username_for() -> Weight;
+	fn accept_username() -> Weight;
+	fn remove_expired_approval() -> Weight;
+	fn set_primary_username() -> Weight;
 }
 
 /// Weights for pallet_identity using the Substrate node and recommended hardware.
@@ -229,6 +235,49 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Storage: Identity AuthorityOfSuffix (r:1 w:2)
+	// Storage: Identity UsernameAuthorities (r:1 w:1)
+	fn add_username_authority() -> Weight {
+		(15_632_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Identity UsernameAuthorities (r:1 w:1)
+	// Storage: Identity AuthorityOfSuffix (r:0 w:1)
+	fn remove_username_authority() -> Weight {
+		(18_405_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Identity UsernameAuthorities (r:1 w:1)
+	// Storage: Identity AccountOfUsername (r:1 w:0)
+	// Storage: Identity PendingUsernames (r:1 w:1)
+	fn set_username_for() -> Weight {
+		(84_176_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Identity PendingUsernames (r:1 w:1)
+	// Storage: Identity UsernameOf (r:1 w:1)
+	// Storage: Identity AccountOfUsername (r:0 w:1)
+	fn accept_username() -> Weight {
+		(24_860_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Identity PendingUsernames (r:1 w:1)
+	fn remove_expired_approval() -> Weight {
+		(19_973_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Identity AccountOfUsername (r:1 w:0)
+	// Storage: Identity UsernameOf (r:0 w:1)
+	fn set_primary_username() -> Weight {
+		(20_527_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -397,4 +446,47 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	// Storage: Identity AuthorityOfSuffix (r:1 w:2)
+	// Storage: Identity UsernameAuthorities (r:1 w:1)
+	fn add_username_authority() -> Weight {
+		(15_632_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Identity UsernameAuthorities (r:1 w:1)
+	// Storage: Identity AuthorityOfSuffix (r:0 w:1)
+	fn remove_username_authority() -> Weight {
+		(18_405_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Identity UsernameAuthorities (r:1 w:1)
+	// Storage: Identity AccountOfUsername (r:1 w:0)
+	// Storage: Identity PendingUsernames (r:1 w:1)
+	fn set_username_for() -> Weight {
+		(84_176_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Identity PendingUsernames (r:1 w:1)
+	// Storage: Identity UsernameOf (r:1 w:1)
+	// Storage: Identity AccountOfUsername (r:0 w:1)
+	fn accept_username() -> Weight {
+		(24_860_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Identity PendingUsernames (r:1 w:1)
+	fn remove_expired_approval() -> Weight {
+		(19_973_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Identity AccountOfUsername (r:1 w:0)
+	// Storage: Identity UsernameOf (r:0 w:1)
+	fn set_primary_username() -> Weight {
+		(20_527_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 }
@@ -48,6 +48,26 @@ fn setup_multi<T: Config>(
 	return Ok((signatories, call_data))
 }
 
+/// Register a weighted multisig with `s` members of weight one and a threshold of two, returning
+/// its members and account.
+fn setup_weighted_multi<T: Config>(
+	s: u32,
+) -> Result<(Vec<(T::AccountId, u32)>, T::AccountId), &'static str> {
+	let (signatories, _) = setup_multi::<T>(s, 0)?;
+	let members: Vec<_> = signatories.into_iter().map(|m| (m, 1)).collect();
+	let creator: T::AccountId = account("creator", 0, SEED);
+	T::Currency::make_free_balance_be(&creator, BalanceOf::<T>::max_value());
+	Multisig::<T>::create_weighted_multisig(
+		RawOrigin::Signed(creator.clone()).into(),
+		members.clone(),
+		2,
+		0,
+	)?;
+	let multisig = Multisig::<T>::weighted_multi_account_id(&creator, 0, None);
+	T::Currency::make_free_balance_be(&multisig, BalanceOf::<T>::max_value());
+	Ok((members, multisig))
+}
+
 benchmarks! {
 	as_multi_threshold_1 {
 		// Transaction Length
@@ -300,5 +320,46 @@ benchmarks! {
 		assert!(!Calls::<T>::contains_key(call_hash));
 	}
 
+	create_weighted_multisig {
+		// Members, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get() as u32;
+		let (signatories, _) = setup_multi::<T>(s, 0)?;
+		let members: Vec<_> = signatories.into_iter().map(|m| (m, 1)).collect();
+		let caller: T::AccountId = account("caller", 0, SEED);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller.clone()), members, 2, 0)
+	verify {
+		let multisig = Multisig::<T>::weighted_multi_account_id(&caller, 0, None);
+		assert!(WeightedMultisigs::<T>::contains_key(multisig));
+	}
+
+	update_weighted_multisig {
+		// Members, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get() as u32;
+		let (members, multisig) = setup_weighted_multi::<T>(T::MaxSignatories::get().into())?;
+		let members: Vec<_> = members.into_iter().take(s as usize).collect();
+		// Whitelist multisig account from further DB operations.
+		let multisig_key = frame_system::Account::<T>::hashed_key_for(&multisig);
+		frame_benchmarking::benchmarking::add_to_whitelist(multisig_key.into());
+	}: _(RawOrigin::Signed(multisig.clone()), members, s)
+	verify {
+		let registration = WeightedMultisigs::<T>::get(&multisig).ok_or("not registered")?;
+		assert_eq!(registration.members.len() as u32, s);
+		assert_eq!(registration.depositor, multisig);
+	}
+
+	dissolve_weighted_multisig {
+		let (_, multisig) = setup_weighted_multi::<T>(T::MaxSignatories::get().into())?;
+		// Whitelist multisig account from further DB operations.
+		let multisig_key = frame_system::Account::<T>::hashed_key_for(&multisig);
+		frame_benchmarking::benchmarking::add_to_whitelist(multisig_key.into());
+	}: _(RawOrigin::Signed(multisig.clone()))
+	verify {
+		assert!(!WeightedMultisigs::<T>::contains_key(multisig));
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
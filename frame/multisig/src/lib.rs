@@ -31,6 +31,11 @@
 //! operation. This is useful for multisig wallets where cryptographic threshold signatures are
 //! not available or desired.
 //!
+//! Alternatively, a weighted multisig account may be registered. Its account ID does not depend on
+//! its members, each member's approval counts with a given weight, and the member list and the
+//! threshold weight can be changed by the multisig itself without moving any funds. Calls are
+//! approved and dispatched from it through the same flow as for the derived multisig accounts.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -39,6 +44,12 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `create_weighted_multisig` - Register a weighted multisig account.
+//! * `update_weighted_multisig` - Change the members and threshold of a weighted multisig.
+//! * `dissolve_weighted_multisig` - Remove the registration of a weighted multisig.
+//! * `as_weighted_multi` - Approve and if possible dispatch a call from a weighted multisig.
+//! * `approve_as_weighted_multi` - Approve a call from a weighted multisig.
+//! * `cancel_as_weighted_multi` - Cancel a call from a weighted multisig.
 //!
 //! [`Call`]: ./enum.Call.html
 //! [`Config`]: ./trait.Config.html
@@ -99,6 +110,29 @@ pub struct Multisig<BlockNumber, Balance, AccountId> {
 	approvals: Vec<AccountId>,
 }
 
+/// A registered multisig account, whose members and threshold may change over time.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct WeightedMultisig<Balance, AccountId> {
+	/// The members and the weight their approval counts with. Always sorted by account.
+	members: Vec<(AccountId, u32)>,
+	/// The total weight of approvals needed for a call to be dispatched.
+	threshold: u32,
+	/// The amount held in reserve of the `depositor`, to be returned once the multisig is
+	/// dissolved.
+	deposit: Balance,
+	/// The account who holds the deposit.
+	depositor: AccountId,
+}
+
+impl<Balance, AccountId: Ord> WeightedMultisig<Balance, AccountId> {
+	/// The weight of the approval of `who`, zero if it is not a member.
+	fn weight_of(&self, who: &AccountId) -> u32 {
+		self.members
+			.binary_search_by(|(member, _)| member.cmp(who))
+			.map_or(0, |i| self.members[i].1)
+	}
+}
+
 type OpaqueCall<T> = WrapperKeepOpaque<<T as Config>::Call>;
 
 type CallHash = [u8; 32];
@@ -137,7 +171,8 @@ pub mod pallet {
 		#[pallet::constant]
 		type DepositBase: Get<BalanceOf<Self>>;
 
-		/// The amount of currency needed per unit threshold when creating a multisig execution.
+		/// The amount of currency needed per unit threshold when creating a multisig execution, or
+		/// per member when registering a weighted multisig.
 		///
 		/// This is held for adding 32 bytes more into a pre-existing storage value.
 		#[pallet::constant]
@@ -170,6 +205,11 @@ pub mod pallet {
 	pub type Calls<T: Config> =
 		StorageMap<_, Identity, [u8; 32], (OpaqueCall<T>, T::AccountId, BalanceOf<T>)>;
 
+	/// The registered weighted multisig accounts.
+	#[pallet::storage]
+	pub type WeightedMultisigs<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, WeightedMultisig<BalanceOf<T>, T::AccountId>>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -200,6 +240,17 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// The account is not a registered weighted multisig.
+		NotRegistered,
+		/// The weighted multisig account is already registered.
+		AlreadyRegistered,
+		/// The sender is not a member of the weighted multisig.
+		NotMember,
+		/// A member of a weighted multisig was given a weight of zero.
+		ZeroWeight,
+		/// The threshold of a weighted multisig must exceed the weight of every single member and
+		/// be at most the total weight of all members.
+		InvalidThreshold,
 	}
 
 	#[pallet::event]
@@ -229,6 +280,12 @@ pub mod pallet {
 			multisig: T::AccountId,
 			call_hash: CallHash,
 		},
+		/// A weighted multisig account has been registered.
+		WeightedMultisigCreated { creator: T::AccountId, multisig: T::AccountId },
+		/// The members or threshold of a weighted multisig have been changed.
+		WeightedMultisigUpdated { multisig: T::AccountId },
+		/// A weighted multisig account has been dissolved.
+		WeightedMultisigDissolved { multisig: T::AccountId },
 	}
 
 	#[pallet::hooks]
@@ -481,23 +538,260 @@ pub mod pallet {
 
 			let id = Self::multi_account_id(&signatories, threshold);
 
-			let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
-			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
-			ensure!(m.depositor == who, Error::<T>::NotOwner);
+			Self::cancel(who, id, timepoint, call_hash)
+		}
+
+		/// Register a weighted multisig account, whose members and threshold may later be changed
+		/// by the multisig itself.
+		///
+		/// Payment: `DepositBase` plus `DepositFactor` for each member will be reserved. It is
+		/// returned once the multisig is dissolved, or moved to the multisig account itself once
+		/// it is updated.
+		///
+		/// The dispatch origin for this call must be _Signed_. The sender does not need to be a
+		/// member.
+		///
+		/// - `members`: The accounts who can approve calls from the multisig, together with the
+		/// weight their approval counts with. Must be sorted by account, with at least two
+		/// members.
+		/// - `threshold`: The total weight of approvals needed for a call to be dispatched. It
+		/// must exceed the weight of every single member and be at most the total weight of all
+		/// members.
+		/// - `index`: A disambiguation index, in case this is called multiple times in the same
+		/// transaction. Unless you're using `batch` you probably just want to use `0`.
+		///
+		/// The account ID of the multisig is given by `weighted_multi_account_id` and reported in
+		/// the `WeightedMultisigCreated` event.
+		///
+		/// # <weight>
+		/// - `O(S)` where `S` is the number of members.
+		/// - One balance-reserve operation.
+		/// - One insert of size `O(S)`.
+		/// - One event.
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::create_weighted_multisig(members.len() as u32))]
+		pub fn create_weighted_multisig(
+			origin: OriginFor<T>,
+			members: Vec<(T::AccountId, u32)>,
+			threshold: u32,
+			index: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_valid_members(&members, threshold)?;
+
+			let id = Self::weighted_multi_account_id(&who, index, None);
+			ensure!(!WeightedMultisigs::<T>::contains_key(&id), Error::<T>::AlreadyRegistered);
+
+			let deposit = Self::weighted_multisig_deposit(members.len());
+			T::Currency::reserve(&who, deposit)?;
+			WeightedMultisigs::<T>::insert(
+				&id,
+				WeightedMultisig { members, threshold, deposit, depositor: who.clone() },
+			);
+
+			Self::deposit_event(Event::WeightedMultisigCreated { creator: who, multisig: id });
+			Ok(())
+		}
 
-			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+		/// Change the members and threshold of a weighted multisig.
+		///
+		/// Approvals already given to open operations remain, but only count with the weight
+		/// their account has as a member at the time of the operation's final approval.
+		///
+		/// Payment: the deposit of the registration is returned to its holder, and `DepositBase`
+		/// plus `DepositFactor` for each new member is reserved from the multisig account instead.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the weighted multisig account
+		/// itself, i.e. this call is dispatched through `as_weighted_multi`.
+		///
+		/// - `members`: The new members with their weights. Must be sorted by account, with at
+		/// least two members.
+		/// - `threshold`: The new total weight of approvals needed for a call to be dispatched.
+		///
+		/// # <weight>
+		/// - `O(S)` where `S` is the number of members.
+		/// - One balance-reserve and one unreserve operation.
+		/// - One mutate of size `O(S)`.
+		/// - One event.
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::update_weighted_multisig(members.len() as u32))]
+		pub fn update_weighted_multisig(
+			origin: OriginFor<T>,
+			members: Vec<(T::AccountId, u32)>,
+			threshold: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_valid_members(&members, threshold)?;
+
+			WeightedMultisigs::<T>::try_mutate(&who, |maybe_multisig| -> DispatchResult {
+				let multisig = maybe_multisig.as_mut().ok_or(Error::<T>::NotRegistered)?;
+				// The multisig account takes over the deposit of its registration.
+				let deposit = Self::weighted_multisig_deposit(members.len());
+				T::Currency::reserve(&who, deposit)?;
+				let err_amount = T::Currency::unreserve(&multisig.depositor, multisig.deposit);
+				debug_assert!(err_amount.is_zero());
+				*multisig =
+					WeightedMultisig { members, threshold, deposit, depositor: who.clone() };
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::WeightedMultisigUpdated { multisig: who });
+			Ok(())
+		}
+
+		/// Remove the registration of a weighted multisig, returning its deposit.
+		///
+		/// WARNING: No calls can be dispatched from the account anymore. Any funds held in it
+		/// will be inaccessible. Open operations can still be cancelled by their depositors.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the weighted multisig account
+		/// itself, i.e. this call is dispatched through `as_weighted_multi`.
+		///
+		/// # <weight>
+		/// - `O(1)`.
+		/// - One balance-unreserve operation.
+		/// - One remove.
+		/// - One event.
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::dissolve_weighted_multisig())]
+		pub fn dissolve_weighted_multisig(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let multisig = WeightedMultisigs::<T>::take(&who).ok_or(Error::<T>::NotRegistered)?;
+
+			let err_amount = T::Currency::unreserve(&multisig.depositor, multisig.deposit);
 			debug_assert!(err_amount.is_zero());
-			<Multisigs<T>>::remove(&id, &call_hash);
-			Self::clear_call(&call_hash);
 
-			Self::deposit_event(Event::MultisigCancelled {
-				cancelling: who,
-				timepoint,
-				multisig: id,
-				call_hash,
-			});
+			Self::deposit_event(Event::WeightedMultisigDissolved { multisig: who });
 			Ok(())
 		}
+
+		/// Register approval for a dispatch to be made from a weighted multisig account if the
+		/// approvals of its members reach its threshold weight.
+		///
+		/// If they do, then dispatch the call.
+		///
+		/// Payment: `DepositBase` will be reserved if this is the first approval, plus
+		/// `DepositFactor` for each member of the multisig. It is returned once this dispatch
+		/// happens or is cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a member of the multisig.
+		///
+		/// - `multisig`: The weighted multisig account to dispatch the call from.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call`: The call to be executed.
+		///
+		/// NOTE: Unless this is the final approval, you will generally want to use
+		/// `approve_as_weighted_multi` instead, since it only requires a hash of the call.
+		///
+		/// On success, result is `Ok` and the result from the interior call, if it was executed,
+		/// may be found in the deposited `MultisigExecuted` event.
+		///
+		/// # <weight>
+		/// Same as `as_multi`, with `S` bounded by `MaxSignatories`, plus one read of the
+		/// registration.
+		/// # </weight>
+		#[pallet::weight({
+			let s = T::MaxSignatories::get() as u32;
+			let z = call.encoded_len() as u32;
+
+			T::WeightInfo::as_multi_create(s, z)
+			.max(T::WeightInfo::as_multi_create_store(s, z))
+			.max(T::WeightInfo::as_multi_approve(s, z))
+			.max(T::WeightInfo::as_multi_complete(s, z))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(*max_weight)
+		})]
+		pub fn as_weighted_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			maybe_timepoint: Option<Timepoint<T::BlockNumber>>,
+			call: OpaqueCall<T>,
+			store_call: bool,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate_weighted(
+				who,
+				multisig,
+				maybe_timepoint,
+				CallOrHash::Call(call, store_call),
+				max_weight,
+			)
+		}
+
+		/// Register approval for a dispatch to be made from a weighted multisig account if the
+		/// approvals of its members reach its threshold weight.
+		///
+		/// Payment: `DepositBase` will be reserved if this is the first approval, plus
+		/// `DepositFactor` for each member of the multisig. It is returned once this dispatch
+		/// happens or is cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a member of the multisig.
+		///
+		/// - `multisig`: The weighted multisig account to dispatch the call from.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// NOTE: If this is the final approval, you will want to use `as_weighted_multi` instead.
+		///
+		/// # <weight>
+		/// Same as `approve_as_multi`, with `S` bounded by `MaxSignatories`, plus one read of the
+		/// registration.
+		/// # </weight>
+		#[pallet::weight({
+			let s = T::MaxSignatories::get() as u32;
+
+			T::WeightInfo::approve_as_multi_create(s)
+				.max(T::WeightInfo::approve_as_multi_approve(s))
+				.max(T::WeightInfo::approve_as_multi_complete(s))
+				.saturating_add(T::DbWeight::get().reads(1))
+				.saturating_add(*max_weight)
+		})]
+		pub fn approve_as_weighted_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			maybe_timepoint: Option<Timepoint<T::BlockNumber>>,
+			call_hash: [u8; 32],
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate_weighted(
+				who,
+				multisig,
+				maybe_timepoint,
+				CallOrHash::Hash(call_hash),
+				max_weight,
+			)
+		}
+
+		/// Cancel a pre-existing, on-going operation of a weighted multisig. Any deposit reserved
+		/// previously for this operation will be unreserved on success.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the account who opened the
+		/// operation. The multisig does not need to be registered anymore.
+		///
+		/// - `multisig`: The weighted multisig account the call would be dispatched from.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// # <weight>
+		/// Same as `cancel_as_multi`, with `S` bounded by `MaxSignatories`.
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::cancel_as_multi(T::MaxSignatories::get() as u32))]
+		pub fn cancel_as_weighted_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			timepoint: Timepoint<T::BlockNumber>,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::cancel(who, multisig, timepoint, call_hash)
+		}
 	}
 }
 
@@ -511,6 +805,22 @@ impl<T: Config> Pallet<T> {
 		T::AccountId::decode(&mut &entropy[..]).unwrap_or_default()
 	}
 
+	/// Calculate the address of a weighted multisig account.
+	///
+	/// - `who`: The account who registered the multisig.
+	/// - `index`: The disambiguation index given at registration.
+	/// - `maybe_when`: The timepoint of the registration. None to use the current timepoint.
+	pub fn weighted_multi_account_id(
+		who: &T::AccountId,
+		index: u16,
+		maybe_when: Option<Timepoint<T::BlockNumber>>,
+	) -> T::AccountId {
+		let when = maybe_when.unwrap_or_else(Self::timepoint);
+		let entropy =
+			(b"modlpy/wghtmsig_", who, when.height, when.index, index).using_encoded(blake2_256);
+		T::AccountId::decode(&mut &entropy[..]).unwrap_or_default()
+	}
+
 	fn operate(
 		who: T::AccountId,
 		threshold: u16,
@@ -527,7 +837,68 @@ impl<T: Config> Pallet<T> {
 		let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
 
 		let id = Self::multi_account_id(&signatories, threshold);
+		let deposit = T::DepositBase::get() + T::DepositFactor::get() * threshold.into();
+
+		Self::approve(
+			who,
+			id,
+			threshold.into(),
+			|_| 1,
+			deposit,
+			other_signatories_len as u32,
+			maybe_timepoint,
+			call_or_hash,
+			max_weight,
+		)
+	}
 
+	fn operate_weighted(
+		who: T::AccountId,
+		id: T::AccountId,
+		maybe_timepoint: Option<Timepoint<T::BlockNumber>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let multisig = WeightedMultisigs::<T>::get(&id).ok_or(Error::<T>::NotRegistered)?;
+		ensure!(multisig.weight_of(&who) > 0, Error::<T>::NotMember);
+		let other_members_len = multisig.members.len().saturating_sub(1) as u32;
+		let deposit = Self::weighted_multisig_deposit(multisig.members.len());
+
+		Self::approve(
+			who,
+			id,
+			multisig.threshold,
+			|a| multisig.weight_of(a),
+			deposit,
+			other_members_len,
+			maybe_timepoint,
+			call_or_hash,
+			max_weight,
+		)
+		.map(|mut post_info| {
+			// Account for reading the registration.
+			post_info.actual_weight =
+				post_info.actual_weight.map(|w| w.saturating_add(T::DbWeight::get().reads(1)));
+			post_info
+		})
+	}
+
+	/// Register the approval of `who` for the operation of `id` identified by `call_or_hash`,
+	/// dispatching the call if the approvals reach `threshold`.
+	///
+	/// Each approval counts with `weight_of` its account. `deposit` is taken from `who` if this
+	/// opens the operation.
+	fn approve(
+		who: T::AccountId,
+		id: T::AccountId,
+		threshold: u32,
+		weight_of: impl Fn(&T::AccountId) -> u32,
+		deposit: BalanceOf<T>,
+		other_signatories_len: u32,
+		maybe_timepoint: Option<Timepoint<T::BlockNumber>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
 		// Threshold > 1; this means it's a multi-step operation. We extract the `call_hash`.
 		let (call_hash, call_len, maybe_call, store) = match call_or_hash {
 			CallOrHash::Call(call, should_store) => {
@@ -545,12 +916,13 @@ impl<T: Config> Pallet<T> {
 			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
 
 			// Ensure that either we have not yet signed or that it is at threshold.
-			let mut approvals = m.approvals.len() as u16;
+			let mut approvals =
+				m.approvals.iter().fold(0u32, |acc, a| acc.saturating_add(weight_of(a)));
 			// We only bother with the approval if we're below threshold.
 			let maybe_pos = m.approvals.binary_search(&who).err().filter(|_| approvals < threshold);
 			// Bump approvals if not yet voted and the vote is needed.
 			if maybe_pos.is_some() {
-				approvals += 1;
+				approvals = approvals.saturating_add(weight_of(&who));
 			}
 
 			// We only bother fetching/decoding call if we know that we're ready to execute.
@@ -580,11 +952,8 @@ impl<T: Config> Pallet<T> {
 				});
 				Ok(get_result_weight(result)
 					.map(|actual_weight| {
-						T::WeightInfo::as_multi_complete(
-							other_signatories_len as u32,
-							call_len as u32,
-						)
-						.saturating_add(actual_weight)
+						T::WeightInfo::as_multi_complete(other_signatories_len, call_len as u32)
+							.saturating_add(actual_weight)
 					})
 					.into())
 			} else {
@@ -621,12 +990,9 @@ impl<T: Config> Pallet<T> {
 				}
 
 				let final_weight = if stored {
-					T::WeightInfo::as_multi_approve_store(
-						other_signatories_len as u32,
-						call_len as u32,
-					)
+					T::WeightInfo::as_multi_approve_store(other_signatories_len, call_len as u32)
 				} else {
-					T::WeightInfo::as_multi_approve(other_signatories_len as u32, call_len as u32)
+					T::WeightInfo::as_multi_approve(other_signatories_len, call_len as u32)
 				};
 				// Call is not made, so the actual weight does not include call
 				Ok(Some(final_weight).into())
@@ -636,8 +1002,6 @@ impl<T: Config> Pallet<T> {
 			ensure!(maybe_timepoint.is_none(), Error::<T>::UnexpectedTimepoint);
 
 			// Just start the operation by recording it in storage.
-			let deposit = T::DepositBase::get() + T::DepositFactor::get() * threshold.into();
-
 			// Store the call if desired.
 			let stored = if let Some(data) = maybe_call.filter(|_| store) {
 				Self::store_call_and_reserve(who.clone(), &call_hash, data, deposit)?;
@@ -660,15 +1024,58 @@ impl<T: Config> Pallet<T> {
 			Self::deposit_event(Event::NewMultisig { approving: who, multisig: id, call_hash });
 
 			let final_weight = if stored {
-				T::WeightInfo::as_multi_create_store(other_signatories_len as u32, call_len as u32)
+				T::WeightInfo::as_multi_create_store(other_signatories_len, call_len as u32)
 			} else {
-				T::WeightInfo::as_multi_create(other_signatories_len as u32, call_len as u32)
+				T::WeightInfo::as_multi_create(other_signatories_len, call_len as u32)
 			};
 			// Call is not made, so the actual weight does not include call
 			Ok(Some(final_weight).into())
 		}
 	}
 
+	fn cancel(
+		who: T::AccountId,
+		id: T::AccountId,
+		timepoint: Timepoint<T::BlockNumber>,
+		call_hash: [u8; 32],
+	) -> DispatchResult {
+		let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+		ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+		ensure!(m.depositor == who, Error::<T>::NotOwner);
+
+		let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+		debug_assert!(err_amount.is_zero());
+		<Multisigs<T>>::remove(&id, &call_hash);
+		Self::clear_call(&call_hash);
+
+		Self::deposit_event(Event::MultisigCancelled {
+			cancelling: who,
+			timepoint,
+			multisig: id,
+			call_hash,
+		});
+		Ok(())
+	}
+
+	/// The deposit held for a weighted multisig with `members` members, or for an operation of it.
+	fn weighted_multisig_deposit(members: usize) -> BalanceOf<T> {
+		T::DepositBase::get() + T::DepositFactor::get() * (members as u32).into()
+	}
+
+	/// Check that the members of a weighted multisig are sorted, within bounds and have a
+	/// positive weight, and that `threshold` can be reached but not by a single member.
+	fn ensure_valid_members(members: &[(T::AccountId, u32)], threshold: u32) -> DispatchResult {
+		ensure!(members.len() >= 2, Error::<T>::TooFewSignatories);
+		ensure!(members.len() <= T::MaxSignatories::get() as usize, Error::<T>::TooManySignatories);
+		ensure!(members.windows(2).all(|w| w[0].0 < w[1].0), Error::<T>::SignatoriesOutOfOrder);
+		ensure!(members.iter().all(|(_, weight)| *weight > 0), Error::<T>::ZeroWeight);
+
+		let total = members.iter().fold(0u32, |acc, (_, weight)| acc.saturating_add(*weight));
+		let heaviest = members.iter().map(|(_, weight)| *weight).max().unwrap_or_default();
+		ensure!(threshold > heaviest && threshold <= total, Error::<T>::InvalidThreshold);
+		Ok(())
+	}
+
 	/// Place a call's encoded data in storage, reserving funds as appropriate.
 	///
 	/// We store `data` here because storing `call` would result in needing another `.encode`.
@@ -99,6 +99,8 @@ impl Contains<Call> for TestBaseCallFilter {
 	fn contains(c: &Call) -> bool {
 		match *c {
 			Call::Balances(_) => true,
+			// Needed for weighted multisigs to manage themselves
+			Call::Multisig(_) => true,
 			// Needed for benchmarking
 			Call::System(frame_system::Call::remark { .. }) => true,
 			_ => false,
@@ -947,3 +949,230 @@ fn multisig_handles_no_preimage_after_all_approve() {
 		assert_eq!(Balances::free_balance(6), 15);
 	});
 }
+
+#[test]
+fn weighted_multisig_registration_checks_members() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Multisig::create_weighted_multisig(Origin::signed(1), vec![(2, 1)], 1, 0),
+			Error::<Test>::TooFewSignatories,
+		);
+		assert_noop!(
+			Multisig::create_weighted_multisig(
+				Origin::signed(1),
+				vec![(1, 1), (2, 1), (3, 1), (4, 1)],
+				2,
+				0
+			),
+			Error::<Test>::TooManySignatories,
+		);
+		assert_noop!(
+			Multisig::create_weighted_multisig(Origin::signed(1), vec![(3, 1), (2, 1)], 2, 0),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_noop!(
+			Multisig::create_weighted_multisig(Origin::signed(1), vec![(2, 1), (2, 1)], 2, 0),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_noop!(
+			Multisig::create_weighted_multisig(Origin::signed(1), vec![(2, 0), (3, 2)], 2, 0),
+			Error::<Test>::ZeroWeight,
+		);
+		assert_noop!(
+			Multisig::create_weighted_multisig(Origin::signed(1), vec![(2, 1), (3, 2)], 2, 0),
+			Error::<Test>::InvalidThreshold,
+		);
+		assert_noop!(
+			Multisig::create_weighted_multisig(Origin::signed(1), vec![(2, 1), (3, 2)], 4, 0),
+			Error::<Test>::InvalidThreshold,
+		);
+
+		assert_ok!(Multisig::create_weighted_multisig(
+			Origin::signed(1),
+			vec![(2, 1), (3, 2)],
+			3,
+			0
+		));
+		let multi = Multisig::weighted_multi_account_id(&1, 0, None);
+		System::assert_last_event(
+			pallet_multisig::Event::WeightedMultisigCreated { creator: 1, multisig: multi }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_noop!(
+			Multisig::create_weighted_multisig(Origin::signed(1), vec![(2, 1), (3, 2)], 3, 0),
+			Error::<Test>::AlreadyRegistered,
+		);
+		assert_ok!(Multisig::create_weighted_multisig(
+			Origin::signed(1),
+			vec![(2, 1), (3, 2)],
+			3,
+			1
+		));
+		assert_ne!(Multisig::weighted_multi_account_id(&1, 1, None), multi);
+	});
+}
+
+#[test]
+fn weighted_multisig_counts_approval_weights() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_weighted_multisig(
+			Origin::signed(4),
+			vec![(1, 2), (2, 1), (3, 1)],
+			3,
+			0
+		));
+		let multi = Multisig::weighted_multi_account_id(&4, 0, None);
+		assert_ok!(Balances::transfer(Origin::signed(3), multi, 5));
+		assert_ok!(Balances::transfer(Origin::signed(4), multi, 5));
+
+		let call = call_transfer(6, 10);
+		let call_weight = call.get_dispatch_info().weight;
+		let data = call.encode();
+		let hash = blake2_256(&data);
+
+		assert_noop!(
+			Multisig::approve_as_weighted_multi(Origin::signed(5), multi, None, hash, 0),
+			Error::<Test>::NotMember,
+		);
+		assert_noop!(
+			Multisig::approve_as_weighted_multi(Origin::signed(1), 6, None, hash, 0),
+			Error::<Test>::NotRegistered,
+		);
+
+		assert_ok!(Multisig::approve_as_weighted_multi(Origin::signed(2), multi, None, hash, 0));
+		assert_eq!(Balances::reserved_balance(2), 4);
+		assert_ok!(Multisig::approve_as_weighted_multi(
+			Origin::signed(3),
+			multi,
+			Some(now()),
+			hash,
+			0
+		));
+		// Two approvals of weight one are not enough.
+		assert_eq!(Balances::free_balance(6), 0);
+
+		assert_ok!(Multisig::as_weighted_multi(
+			Origin::signed(1),
+			multi,
+			Some(now()),
+			OpaqueCall::from_encoded(data),
+			false,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 10);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigExecuted {
+				approving: 1,
+				timepoint: now(),
+				multisig: multi,
+				call_hash: hash,
+				result: Ok(()),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn weighted_multisig_can_be_cancelled() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_weighted_multisig(
+			Origin::signed(4),
+			vec![(1, 1), (2, 1)],
+			2,
+			0
+		));
+		let multi = Multisig::weighted_multi_account_id(&4, 0, None);
+		let hash = blake2_256(&call_transfer(6, 10).encode());
+
+		assert_ok!(Multisig::approve_as_weighted_multi(Origin::signed(1), multi, None, hash, 0));
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_noop!(
+			Multisig::cancel_as_weighted_multi(Origin::signed(2), multi, now(), hash),
+			Error::<Test>::NotOwner,
+		);
+		assert_ok!(Multisig::cancel_as_weighted_multi(Origin::signed(1), multi, now(), hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn weighted_multisig_manages_itself() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_weighted_multisig(
+			Origin::signed(1),
+			vec![(1, 1), (2, 1), (3, 1)],
+			2,
+			0
+		));
+		let multi = Multisig::weighted_multi_account_id(&1, 0, None);
+		assert_ok!(Balances::transfer(Origin::signed(3), multi, 5));
+		assert_ok!(Balances::transfer(Origin::signed(4), multi, 5));
+		assert_eq!(Balances::reserved_balance(1), 4);
+
+		// Only the multisig itself may manage its registration.
+		assert_noop!(
+			Multisig::update_weighted_multisig(Origin::signed(1), vec![(2, 1), (4, 1)], 2),
+			Error::<Test>::NotRegistered,
+		);
+		assert_noop!(
+			Multisig::dissolve_weighted_multisig(Origin::signed(1)),
+			Error::<Test>::NotRegistered,
+		);
+
+		let call = Call::Multisig(crate::Call::update_weighted_multisig {
+			members: vec![(2, 1), (4, 1)],
+			threshold: 2,
+		});
+		let call_weight = call.get_dispatch_info().weight;
+		let data = call.encode();
+		let hash = blake2_256(&data);
+		assert_ok!(Multisig::approve_as_weighted_multi(Origin::signed(1), multi, None, hash, 0));
+		assert_ok!(Multisig::as_weighted_multi(
+			Origin::signed(3),
+			multi,
+			Some(now()),
+			OpaqueCall::from_encoded(data),
+			false,
+			call_weight
+		));
+		System::assert_has_event(
+			pallet_multisig::Event::WeightedMultisigUpdated { multisig: multi }.into(),
+		);
+
+		// The account is unchanged, and now holds the deposit of its registration.
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(multi), 3);
+		let registration = WeightedMultisigs::<Test>::get(multi).unwrap();
+		assert_eq!(registration.members, vec![(2, 1), (4, 1)]);
+		assert_eq!(registration.depositor, multi);
+
+		let hash = blake2_256(&call_transfer(6, 5).encode());
+		assert_noop!(
+			Multisig::approve_as_weighted_multi(Origin::signed(1), multi, None, hash, 0),
+			Error::<Test>::NotMember,
+		);
+
+		let call = Call::Multisig(crate::Call::dissolve_weighted_multisig {});
+		let call_weight = call.get_dispatch_info().weight;
+		let data = call.encode();
+		let hash = blake2_256(&data);
+		assert_ok!(Multisig::approve_as_weighted_multi(Origin::signed(2), multi, None, hash, 0));
+		assert_ok!(Multisig::as_weighted_multi(
+			Origin::signed(4),
+			multi,
+			Some(now()),
+			OpaqueCall::from_encoded(data),
+			false,
+			call_weight
+		));
+		System::assert_has_event(
+			pallet_multisig::Event::WeightedMultisigDissolved { multisig: multi }.into(),
+		);
+		assert!(!WeightedMultisigs::<Test>::contains_key(multi));
+		assert_eq!(Balances::reserved_balance(multi), 0);
+		assert_eq!(Balances::free_balance(multi), 10);
+	});
+}
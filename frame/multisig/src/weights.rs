@@ -55,6 +55,9 @@ pub trait WeightInfo {
 	fn approve_as_multi_approve(s: u32, ) -> Weight;
 	fn approve_as_multi_complete(s: u32, ) -> Weight;
 	fn cancel_as_multi(s: u32, ) -> Weight;
+	fn create_weighted_multisig(s: u32, ) -> Weight;
+	fn update_weighted_multisig(s: u32, ) -> Weight;
+	fn dissolve_weighted_multisig() -> Weight;
 }
 
 /// Weights for pallet_multisig using the Substrate node and recommended hardware.
@@ -158,6 +161,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Multisig WeightedMultisigs (r:1 w:1)
+	fn create_weighted_multisig(s: u32, ) -> Weight {
+		(38_517_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((214_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Multisig WeightedMultisigs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn update_weighted_multisig(s: u32, ) -> Weight {
+		(52_940_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((397_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Multisig WeightedMultisigs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn dissolve_weighted_multisig() -> Weight {
+		(36_725_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -260,4 +288,29 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Multisig WeightedMultisigs (r:1 w:1)
+	fn create_weighted_multisig(s: u32, ) -> Weight {
+		(38_517_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((214_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Multisig WeightedMultisigs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn update_weighted_multisig(s: u32, ) -> Weight {
+		(52_940_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((397_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Multisig WeightedMultisigs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn dissolve_weighted_multisig() -> Weight {
+		(36_725_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
 }
@@ -0,0 +1,334 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks for the nomination pools coupled with the staking and bags list pallets.
+// This is separated into its own crate due to cyclic dependency issues.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+
+use frame_benchmarking::{account, benchmarks, whitelist_account};
+use frame_support::{assert_ok, ensure, traits::Currency};
+use frame_system::RawOrigin as Origin;
+use pallet_nomination_pools::{
+	BalanceOf, BondExtra, BondedPools, Call, Pallet as Pools, PoolMembers, PoolState,
+	RewardPools, SubPoolsStorage,
+};
+use sp_runtime::traits::{Bounded, Zero};
+use sp_staking::{EraIndex, StakingInterface};
+use sp_std::{vec, vec::Vec};
+
+// Ensure that the pallet can nominate the maximum number of validators.
+const MAX_SPANS: u32 = 100;
+const USER_SEED: u32 = 0;
+
+type CurrencyOf<T> = <T as pallet_nomination_pools::Config>::Currency;
+
+pub trait Config: pallet_nomination_pools::Config + pallet_staking::Config {}
+
+pub struct Pallet<T: Config>(Pools<T>);
+
+fn create_funded_user_with_balance<T: pallet_nomination_pools::Config>(
+	string: &'static str,
+	n: u32,
+	balance: BalanceOf<T>,
+) -> T::AccountId {
+	let user = account(string, n, USER_SEED);
+	let _ = CurrencyOf::<T>::make_free_balance_be(&user, balance);
+	user
+}
+
+/// The amount that is bonded by the depositor of every pool created in these benchmarks.
+fn min_create_bond<T: pallet_nomination_pools::Config>() -> BalanceOf<T> {
+	Pools::<T>::depositor_min_bond().max(CurrencyOf::<T>::minimum_balance())
+}
+
+/// Create a pool whose depositor holds all the pool roles, and bond `balance` into it.
+///
+/// Returns the depositor, and the id and bonded account of the pool.
+fn create_pool<T: pallet_nomination_pools::Config>(
+	n: u32,
+	balance: BalanceOf<T>,
+) -> (T::AccountId, u32, T::AccountId) {
+	let ed = CurrencyOf::<T>::minimum_balance();
+	let pool_creator =
+		create_funded_user_with_balance::<T>("pool_creator", n, ed + balance + balance);
+
+	assert_ok!(Pools::<T>::create(
+		Origin::Signed(pool_creator.clone()).into(),
+		balance,
+		pool_creator.clone(),
+		pool_creator.clone(),
+		pool_creator.clone(),
+	));
+
+	let pool_id = pallet_nomination_pools::LastPoolId::<T>::get();
+	(pool_creator, pool_id, Pools::<T>::create_bonded_account(pool_id))
+}
+
+/// Create a member of `pool_id`, bonding `balance`.
+fn create_member<T: pallet_nomination_pools::Config>(
+	n: u32,
+	pool_id: u32,
+	balance: BalanceOf<T>,
+) -> T::AccountId {
+	let ed = CurrencyOf::<T>::minimum_balance();
+	let member = create_funded_user_with_balance::<T>("member", n, ed + balance + balance);
+	assert_ok!(Pools::<T>::join(Origin::Signed(member.clone()).into(), balance, pool_id));
+	member
+}
+
+/// Deposit `amount` of rewards into the reward account of `pool_id`.
+fn deposit_rewards<T: pallet_nomination_pools::Config>(pool_id: u32, amount: BalanceOf<T>) {
+	let reward_account = Pools::<T>::create_reward_account(pool_id);
+	let balance = CurrencyOf::<T>::free_balance(&reward_account);
+	CurrencyOf::<T>::make_free_balance_be(&reward_account, balance + amount);
+}
+
+benchmarks! {
+	join {
+		let amount = min_create_bond::<T>();
+		let (_, pool_id, pool_account) = create_pool::<T>(0, amount);
+
+		let joiner = create_funded_user_with_balance::<T>("joiner", 0, amount * 2u32.into());
+		whitelist_account!(joiner);
+	}: _(Origin::Signed(joiner.clone()), amount, pool_id)
+	verify {
+		assert_eq!(CurrencyOf::<T>::free_balance(&joiner), amount);
+		assert_eq!(T::StakingInterface::active_stake(&pool_account), Some(amount * 2u32.into()));
+	}
+
+	bond_extra_transfer {
+		let amount = min_create_bond::<T>();
+		let (_, pool_id, pool_account) = create_pool::<T>(0, amount);
+		let member = create_member::<T>(0, pool_id, amount);
+		whitelist_account!(member);
+	}: bond_extra(Origin::Signed(member.clone()), BondExtra::FreeBalance(amount))
+	verify {
+		assert_eq!(T::StakingInterface::active_stake(&pool_account), Some(amount * 3u32.into()));
+	}
+
+	bond_extra_reward {
+		let amount = min_create_bond::<T>();
+		let (_, pool_id, pool_account) = create_pool::<T>(0, amount);
+		let member = create_member::<T>(0, pool_id, amount);
+		// the member earns half of these rewards.
+		deposit_rewards::<T>(pool_id, amount * 2u32.into());
+		whitelist_account!(member);
+	}: bond_extra(Origin::Signed(member.clone()), BondExtra::Rewards)
+	verify {
+		assert_eq!(T::StakingInterface::active_stake(&pool_account), Some(amount * 3u32.into()));
+	}
+
+	claim_payout {
+		let amount = min_create_bond::<T>();
+		let (_, pool_id, _) = create_pool::<T>(0, amount);
+		let member = create_member::<T>(0, pool_id, amount);
+		let original_balance = CurrencyOf::<T>::free_balance(&member);
+		deposit_rewards::<T>(pool_id, amount * 2u32.into());
+		whitelist_account!(member);
+	}: _(Origin::Signed(member.clone()))
+	verify {
+		assert_eq!(CurrencyOf::<T>::free_balance(&member), original_balance + amount);
+		assert_eq!(
+			RewardPools::<T>::get(pool_id).unwrap().total_rewards_claimed,
+			amount
+		);
+	}
+
+	unbond {
+		let amount = min_create_bond::<T>();
+		let (_, pool_id, pool_account) = create_pool::<T>(0, amount);
+		let member = create_member::<T>(0, pool_id, amount);
+		let member_points = PoolMembers::<T>::get(&member).unwrap().points;
+		whitelist_account!(member);
+	}: _(Origin::Signed(member.clone()), member.clone(), member_points)
+	verify {
+		assert_eq!(T::StakingInterface::active_stake(&pool_account), Some(amount));
+		assert!(PoolMembers::<T>::get(&member).unwrap().points.is_zero());
+		assert!(SubPoolsStorage::<T>::get(pool_id).is_some());
+	}
+
+	pool_withdraw_unbonded {
+		let s in 0 .. MAX_SPANS;
+
+		let amount = min_create_bond::<T>();
+		let (_, pool_id, pool_account) = create_pool::<T>(0, amount);
+		let member = create_member::<T>(0, pool_id, amount);
+		assert_ok!(Pools::<T>::unbond(Origin::Signed(member.clone()).into(), member.clone(), amount));
+		pallet_staking::benchmarking::add_slashing_spans::<T>(&pool_account, s);
+
+		// Sanity check that unbond worked
+		assert_eq!(T::StakingInterface::total_stake(&pool_account), Some(amount * 2u32.into()));
+		pallet_staking::CurrentEra::<T>::put(EraIndex::max_value());
+		whitelist_account!(pool_account);
+	}: _(Origin::Signed(pool_account.clone()), pool_id, s)
+	verify {
+		// The unlocking chunk was removed
+		assert_eq!(T::StakingInterface::total_stake(&pool_account), Some(amount));
+	}
+
+	withdraw_unbonded_update {
+		let s in 0 .. MAX_SPANS;
+
+		let amount = min_create_bond::<T>();
+		let (_, pool_id, pool_account) = create_pool::<T>(0, amount);
+		let member = create_member::<T>(0, pool_id, amount + amount);
+		assert_ok!(Pools::<T>::unbond(Origin::Signed(member.clone()).into(), member.clone(), amount));
+		pallet_staking::benchmarking::add_slashing_spans::<T>(&pool_account, s);
+		let original_balance = CurrencyOf::<T>::free_balance(&member);
+
+		pallet_staking::CurrentEra::<T>::put(EraIndex::max_value());
+		whitelist_account!(member);
+	}: withdraw_unbonded(Origin::Signed(member.clone()), member.clone(), s)
+	verify {
+		assert_eq!(CurrencyOf::<T>::free_balance(&member), original_balance + amount);
+		// the member still has some bonded funds.
+		assert!(PoolMembers::<T>::contains_key(&member));
+	}
+
+	withdraw_unbonded_kill {
+		let s in 0 .. MAX_SPANS;
+
+		let amount = min_create_bond::<T>();
+		let (depositor, pool_id, pool_account) = create_pool::<T>(0, amount);
+		// the depositor holds all the roles, and can set the pool to destroying.
+		assert_ok!(Pools::<T>::set_state(
+			Origin::Signed(depositor.clone()).into(),
+			pool_id,
+			PoolState::Destroying,
+		));
+		assert_ok!(Pools::<T>::unbond(
+			Origin::Signed(depositor.clone()).into(),
+			depositor.clone(),
+			amount,
+		));
+		pallet_staking::benchmarking::add_slashing_spans::<T>(&pool_account, s);
+
+		pallet_staking::CurrentEra::<T>::put(EraIndex::max_value());
+		whitelist_account!(depositor);
+	}: withdraw_unbonded(Origin::Signed(depositor.clone()), depositor.clone(), s)
+	verify {
+		assert!(!PoolMembers::<T>::contains_key(&depositor));
+		assert!(!BondedPools::<T>::contains_key(pool_id));
+		assert!(!RewardPools::<T>::contains_key(pool_id));
+		assert!(!SubPoolsStorage::<T>::contains_key(pool_id));
+		assert_eq!(T::StakingInterface::total_stake(&pool_account), None);
+	}
+
+	create {
+		let amount = min_create_bond::<T>();
+		let ed = CurrencyOf::<T>::minimum_balance();
+		let depositor = create_funded_user_with_balance::<T>("depositor", 0, amount + amount + ed);
+		let depositor_lookup = depositor.clone();
+		whitelist_account!(depositor);
+	}: _(
+			Origin::Signed(depositor.clone()),
+			amount,
+			depositor_lookup.clone(),
+			depositor_lookup.clone(),
+			depositor_lookup
+		)
+	verify {
+		let pool_id = pallet_nomination_pools::LastPoolId::<T>::get();
+		assert_eq!(BondedPools::<T>::count(), 1);
+		assert_eq!(BondedPools::<T>::get(pool_id).unwrap().points, amount);
+		assert_eq!(
+			T::StakingInterface::active_stake(&Pools::<T>::create_bonded_account(pool_id)),
+			Some(amount)
+		);
+	}
+
+	nominate {
+		let n in 1 .. T::MAX_NOMINATIONS;
+
+		let amount = min_create_bond::<T>();
+		let (depositor, pool_id, pool_account) = create_pool::<T>(0, amount);
+
+		// Create some validators to nominate.
+		let validators: Vec<_> = (0..n)
+			.map(|i| {
+				let validator: T::AccountId = account("stash", USER_SEED, i);
+				pallet_staking::Validators::<T>::insert(
+					validator.clone(),
+					pallet_staking::ValidatorPrefs::default(),
+				);
+				validator
+			})
+			.collect();
+		whitelist_account!(depositor);
+	}: _(Origin::Signed(depositor.clone()), pool_id, validators)
+	verify {
+		assert!(pallet_staking::Nominators::<T>::contains_key(&pool_account));
+	}
+
+	chill {
+		let amount = min_create_bond::<T>();
+		let (depositor, pool_id, pool_account) = create_pool::<T>(0, amount);
+		let validator: T::AccountId = account("stash", USER_SEED, 0);
+		pallet_staking::Validators::<T>::insert(
+			validator.clone(),
+			pallet_staking::ValidatorPrefs::default(),
+		);
+		assert_ok!(Pools::<T>::nominate(
+			Origin::Signed(depositor.clone()).into(),
+			pool_id,
+			vec![validator],
+		));
+		ensure!(
+			pallet_staking::Nominators::<T>::contains_key(&pool_account),
+			"pool should be nominating"
+		);
+		whitelist_account!(depositor);
+	}: _(Origin::Signed(depositor.clone()), pool_id)
+	verify {
+		assert!(!pallet_staking::Nominators::<T>::contains_key(&pool_account));
+	}
+
+	set_state {
+		let amount = min_create_bond::<T>();
+		let (depositor, pool_id, _) = create_pool::<T>(0, amount);
+		whitelist_account!(depositor);
+	}: _(Origin::Signed(depositor.clone()), pool_id, PoolState::Blocked)
+	verify {
+		assert_eq!(BondedPools::<T>::get(pool_id).unwrap().state, PoolState::Blocked);
+	}
+
+	set_configs {
+	}: _(
+		Origin::Root,
+		BalanceOf::<T>::max_value(),
+		BalanceOf::<T>::max_value(),
+		Some(u32::MAX),
+		Some(u32::MAX),
+		Some(u32::MAX)
+	) verify {
+		assert_eq!(pallet_nomination_pools::MinJoinBond::<T>::get(), BalanceOf::<T>::max_value());
+		assert_eq!(pallet_nomination_pools::MinCreateBond::<T>::get(), BalanceOf::<T>::max_value());
+		assert_eq!(pallet_nomination_pools::MaxPools::<T>::get(), Some(u32::MAX));
+		assert_eq!(pallet_nomination_pools::MaxPoolMembers::<T>::get(), Some(u32::MAX));
+		assert_eq!(pallet_nomination_pools::MaxPoolMembersPerPool::<T>::get(), Some(u32::MAX));
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Runtime
+	);
+}
@@ -0,0 +1,1808 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Nomination Pools for Staking Delegation
+//!
+//! A pallet that allows members to delegate their stake to nominating pools. A nomination pool
+//! acts as a nominator and nominates validators on behalf of its members.
+//!
+//! ## Overview
+//!
+//! Accounts that cannot meet the minimum nominator bond of the staking system on their own can
+//! pool their funds together. Each pool has a _bonded account_, which is registered as a
+//! nominator through [`sp_staking::StakingInterface`], and a _reward account_, which receives
+//! the staking rewards of the bonded account.
+//!
+//! ### Terminology
+//!
+//! - Bonded pool: Tracks the distribution of actively staked funds. See [`BondedPool`] and
+//!   [`BondedPoolInner`].
+//! - Reward pool: Tracks rewards earned by actively staked funds. See [`RewardPool`] and
+//!   [`RewardPools`].
+//! - Unbonding sub pools: Collection of pools at different phases of the unbonding lifecycle.
+//!   See [`SubPools`] and [`SubPoolsStorage`].
+//! - Members: Accounts that are members of pools. See [`PoolMember`] and [`PoolMembers`].
+//! - Roles: Administrative roles of each pool, capable of controlling nomination and the state of
+//!   the pool. See [`PoolRoles`].
+//! - Point: A unit of measure for a member's portion of a pool's funds. Points initially have a
+//!   ratio of 1 (as set by `POINTS_TO_BALANCE_INIT_RATIO`) to balance, but as slashing happens,
+//!   this can change.
+//! - Kick: The act of a pool administrator forcibly ejecting a member.
+//!
+//! ### Joining
+//!
+//! A member can join a pool in the `Open` state by transferring funds to the bonded account of
+//! the pool with [`Call::join`]. In return, the member is issued points proportional to the
+//! funds they contributed, relative to the pool's current points-to-balance ratio. An account can
+//! only be a member of a single pool at a time.
+//!
+//! ### Claiming rewards
+//!
+//! The reward pool keeps a _reward counter_: the total amount of rewards that have been earned
+//! per point held in the bonded pool. Each member records the value of the counter whenever they
+//! claim, so that their pending rewards are simply the growth of the counter since their last
+//! claim multiplied by their points. Rewards are claimed with [`Call::claim_payout`], and are
+//! implicitly claimed whenever a member's points change.
+//!
+//! ### Leaving
+//!
+//! A member leaves in two steps. First they call [`Call::unbond`], which unbonds their funds from
+//! the staking system and moves their points into the unbonding sub pool of the era in which the
+//! funds unlock. Once that era has been reached, [`Call::withdraw_unbonded`] transfers the funds
+//! back to the member. A member who has fully withdrawn is removed from the pool.
+//!
+//! Each unbonding sub pool has its own points, so a slash that is applied to the funds unlocking
+//! in a given era is spread proportionally across the members unbonding in that era. Sub pools
+//! older than [`Config::PostUnbondingPoolsWindow`] are merged into a single pool that is not
+//! associated with any era, bounding the number of sub pools a bonded pool can have.
+//!
+//! ### Slashing
+//!
+//! The bonded pool's balance is read from the staking system, so a slash of the active stake
+//! simply reduces the value of every point in the bonded pool. Slashes of unlocking funds are
+//! reported to this pallet through [`sp_staking::OnStakerSlash`], which updates the balance of
+//! the affected unbonding sub pools.
+//!
+//! ### Administration
+//!
+//! A pool is created by its _depositor_ with [`Call::create`]. The depositor's funds are the last
+//! to leave the pool: they can only fully unbond once the pool is `Destroying` and they are the
+//! only member left. Besides the depositor, each pool has:
+//!
+//! - a `root`, which can change the pool's state and the other roles,
+//! - a `nominator`, which can nominate validators through [`Call::nominate`] and [`Call::chill`],
+//! - a `state_toggler`, which can change the pool's state through [`Call::set_state`] and kick
+//!   members while the pool is `Blocked`.
+//!
+//! Once a pool is `Destroying`, any account can unbond and withdraw the funds of its members on
+//! their behalf, after which the depositor can withdraw and the pool is dissolved.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `join` - Join a pool by bonding some funds.
+//! - `bond_extra` - Bond more funds, either from the free balance or from pending rewards.
+//! - `claim_payout` - Claim the pending rewards of the caller.
+//! - `unbond` - Unbond some of the points of a member.
+//! - `pool_withdraw_unbonded` - Withdraw unlocked funds of a pool from the staking system.
+//! - `withdraw_unbonded` - Withdraw the unlocked funds of a member.
+//! - `create` - Create a new pool.
+//! - `nominate` - Nominate validators on behalf of a pool.
+//! - `chill` - Stop nominating on behalf of a pool.
+//! - `set_state` - Change the state of a pool.
+//! - `set_configs` - Update the global configuration of the pallet. Root only.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use frame_support::{
+	ensure,
+	pallet_prelude::*,
+	storage::bounded_btree_map::BoundedBTreeMap,
+	traits::{Currency, ExistenceRequirement, Get},
+	transactional, CloneNoBound, DefaultNoBound, PalletId, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{AccountIdConversion, CheckedAdd, CheckedSub, SaturatedConversion, Saturating, Zero},
+	FixedPointNumber, FixedU128,
+};
+use sp_staking::{EraIndex, OnStakerSlash, StakingInterface};
+use sp_std::{
+	collections::btree_map::BTreeMap,
+	marker::PhantomData,
+	ops::{Deref, DerefMut},
+	vec::Vec,
+};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// The balance type used by the currency system.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Type used to track the points of a pool's bonded and unbonding funds.
+pub type PoolId = u32;
+
+/// The ratio of points to balance that a pool starts with, when it has neither points nor
+/// balance.
+pub const POINTS_TO_BALANCE_INIT_RATIO: u32 = 1;
+
+/// Possible operations on the bonded funds of a pool.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum BondExtra<Balance> {
+	/// Take from the free balance.
+	FreeBalance(Balance),
+	/// Take the entire amount from the accumulated rewards.
+	Rewards,
+}
+
+/// The type of bonding that can happen to a pool.
+enum BondType {
+	/// Someone is bonding into the pool upon creation.
+	Create,
+	/// Someone is adding more funds later to this pool.
+	Later,
+}
+
+/// The type of account being created.
+#[derive(Encode, Decode)]
+enum AccountType {
+	Bonded,
+	Reward,
+}
+
+/// A member in a pool.
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
+#[cfg_attr(feature = "std", derive(DefaultNoBound))]
+#[scale_info(skip_type_params(T))]
+pub struct PoolMember<T: Config> {
+	/// The identifier of the pool to which `who` belongs.
+	pub pool_id: PoolId,
+	/// The quantity of points this member has in the bonded pool.
+	pub points: BalanceOf<T>,
+	/// The reward counter of the pool at the time of this member's last payout.
+	pub last_recorded_reward_counter: FixedU128,
+	/// The eras in which this member is unbonding, mapped from era index to the number of
+	/// points scheduled to unbond in the given era.
+	pub unbonding_eras: BoundedBTreeMap<EraIndex, BalanceOf<T>, T::MaxUnbonding>,
+}
+
+impl<T: Config> PoolMember<T> {
+	/// The pending rewards of this member, given the current reward counter of its pool.
+	fn pending_rewards(
+		&self,
+		current_reward_counter: FixedU128,
+	) -> Result<BalanceOf<T>, Error<T>> {
+		current_reward_counter
+			.saturating_sub(self.last_recorded_reward_counter)
+			.checked_mul_int(self.points.saturated_into::<u128>())
+			.map(|pending| pending.saturated_into())
+			.ok_or(Error::<T>::OverflowRisk)
+	}
+
+	/// Total points of this member, both active and unbonding.
+	fn total_points(&self) -> BalanceOf<T> {
+		self.active_points().saturating_add(self.unbonding_points())
+	}
+
+	/// Active points of the member.
+	fn active_points(&self) -> BalanceOf<T> {
+		self.points
+	}
+
+	/// Inactive points of the member, waiting to be withdrawn.
+	fn unbonding_points(&self) -> BalanceOf<T> {
+		self.unbonding_eras
+			.iter()
+			.fold(BalanceOf::<T>::zero(), |acc, (_, v)| acc.saturating_add(*v))
+	}
+
+	/// Move `points_dissolved` from the active points of this member, and add
+	/// `points_issued` to the unbonding points of `unbonding_era`.
+	fn try_unbond(
+		&mut self,
+		points_dissolved: BalanceOf<T>,
+		points_issued: BalanceOf<T>,
+		unbonding_era: EraIndex,
+	) -> Result<(), Error<T>> {
+		if let Some(new_points) = self.points.checked_sub(&points_dissolved) {
+			match self.unbonding_eras.get_mut(&unbonding_era) {
+				Some(already_unbonding_points) =>
+					*already_unbonding_points = already_unbonding_points.saturating_add(points_issued),
+				None => self
+					.unbonding_eras
+					.try_insert(unbonding_era, points_issued)
+					.map(|_| ())
+					.map_err(|_| Error::<T>::MaxUnbondingLimit)?,
+			}
+			self.points = new_points;
+			Ok(())
+		} else {
+			Err(Error::<T>::MinimumBondNotMet)
+		}
+	}
+
+	/// Remove and return all the unbonding eras of this member that are unlocked as of
+	/// `current_era`.
+	fn withdraw_unlocked(&mut self, current_era: EraIndex) -> BTreeMap<EraIndex, BalanceOf<T>> {
+		let mut unlocked = BTreeMap::new();
+		let unbonding_eras = sp_std::mem::take(&mut self.unbonding_eras).into_inner();
+		let remaining = unbonding_eras
+			.into_iter()
+			.filter_map(|(era, points)| {
+				if era <= current_era {
+					unlocked.insert(era, points);
+					None
+				} else {
+					Some((era, points))
+				}
+			})
+			.collect::<BTreeMap<_, _>>();
+		self.unbonding_eras =
+			BoundedBTreeMap::try_from(remaining).expect("a subset of a bounded map is bounded; qed");
+		unlocked
+	}
+}
+
+/// A pool's possible states.
+#[derive(Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, Clone, Copy)]
+pub enum PoolState {
+	/// The pool is open to be joined, and is working normally.
+	Open,
+	/// The pool is blocked. No one else can join.
+	Blocked,
+	/// The pool is in the process of being destroyed.
+	///
+	/// All members can now be permissionlessly unbonded, and the pool can never go back to any
+	/// other state other than being dissolved.
+	Destroying,
+}
+
+/// Pool administration roles.
+#[derive(Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, Clone)]
+pub struct PoolRoles<AccountId> {
+	/// Creates the pool and is the initial member. They can only leave the pool once all other
+	/// members have left. Once they fully leave, the pool is destroyed.
+	pub depositor: AccountId,
+	/// Can change the nominator, state-toggler, or itself and can perform any of the actions the
+	/// nominator or state-toggler can.
+	pub root: AccountId,
+	/// Can select which validators the pool nominates.
+	pub nominator: AccountId,
+	/// Can change the pool's state.
+	pub state_toggler: AccountId,
+}
+
+/// Pool permissions and state.
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct BondedPoolInner<T: Config> {
+	/// Total points of all the members in the pool who are actively bonded.
+	pub points: BalanceOf<T>,
+	/// The current state of the pool.
+	pub state: PoolState,
+	/// Count of members that belong to the pool.
+	pub member_counter: u32,
+	/// See [`PoolRoles`].
+	pub roles: PoolRoles<T::AccountId>,
+}
+
+/// A wrapper for bonded pools, with utility functions.
+///
+/// The main purpose of this is to wrap a [`BondedPoolInner`], with the account + id of the pool,
+/// for easier access.
+#[derive(RuntimeDebugNoBound, CloneNoBound)]
+#[cfg_attr(feature = "std", derive(PartialEqNoBound))]
+pub struct BondedPool<T: Config> {
+	/// The identifier of the pool.
+	id: PoolId,
+	/// The inner fields.
+	inner: BondedPoolInner<T>,
+}
+
+impl<T: Config> Deref for BondedPool<T> {
+	type Target = BondedPoolInner<T>;
+	fn deref(&self) -> &Self::Target {
+		&self.inner
+	}
+}
+
+impl<T: Config> DerefMut for BondedPool<T> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.inner
+	}
+}
+
+impl<T: Config> BondedPool<T> {
+	/// Create a new bonded pool with the given roles and identifier.
+	fn new(id: PoolId, roles: PoolRoles<T::AccountId>) -> Self {
+		Self {
+			id,
+			inner: BondedPoolInner {
+				roles,
+				state: PoolState::Open,
+				points: Zero::zero(),
+				member_counter: Zero::zero(),
+			},
+		}
+	}
+
+	/// Get [`Self`] from storage. Returns `None` if no entry for `pool_account` exists.
+	pub fn get(id: PoolId) -> Option<Self> {
+		BondedPools::<T>::get(id).map(|inner| Self { id, inner })
+	}
+
+	/// Get the bonded account id of this pool.
+	fn bonded_account(&self) -> T::AccountId {
+		Pallet::<T>::create_bonded_account(self.id)
+	}
+
+	/// Get the reward account id of this pool.
+	fn reward_account(&self) -> T::AccountId {
+		Pallet::<T>::create_reward_account(self.id)
+	}
+
+	/// Consume self and put into storage.
+	fn put(self) {
+		BondedPools::<T>::insert(self.id, self.inner);
+	}
+
+	/// Consume self and remove from storage.
+	fn remove(self) {
+		BondedPools::<T>::remove(self.id);
+	}
+
+	/// The actively bonded balance of this pool in the staking system.
+	fn active_balance(&self) -> BalanceOf<T> {
+		T::StakingInterface::active_stake(&self.bonded_account()).unwrap_or_default()
+	}
+
+	/// Get the amount of points to issue for some new funds that will be bonded in the pool.
+	fn balance_to_point(&self, new_funds: BalanceOf<T>) -> BalanceOf<T> {
+		Pallet::<T>::balance_to_point(self.active_balance(), self.points, new_funds)
+	}
+
+	/// Get the equivalent balance of `points` for this pool.
+	fn points_to_balance(&self, points: BalanceOf<T>) -> BalanceOf<T> {
+		Pallet::<T>::point_to_balance(self.active_balance(), self.points, points)
+	}
+
+	/// Issue points to [`Self`] for `new_funds`.
+	fn issue(&mut self, new_funds: BalanceOf<T>) -> BalanceOf<T> {
+		let points_to_issue = self.balance_to_point(new_funds);
+		self.points = self.points.saturating_add(points_to_issue);
+		points_to_issue
+	}
+
+	/// Dissolve some points from the pool i.e. unbond the given amount of points from this pool.
+	/// This is the opposite of issuing some funds into the pool.
+	///
+	/// Mutates self in place, but does not write anything to storage.
+	///
+	/// Returns the equivalent balance amount that actually needs to get unbonded.
+	fn dissolve(&mut self, points: BalanceOf<T>) -> BalanceOf<T> {
+		// NOTE: do not optimize by removing `balance`. it must be computed before mutating
+		// `self.point`.
+		let balance = self.points_to_balance(points);
+		self.points = self.points.saturating_sub(points);
+		balance
+	}
+
+	/// Increment the member counter. Ensures that the pool and system member limits are
+	/// respected.
+	fn try_inc_members(&mut self) -> Result<(), DispatchError> {
+		ensure!(
+			MaxPoolMembersPerPool::<T>::get()
+				.map_or(true, |max_per_pool| self.member_counter < max_per_pool),
+			Error::<T>::MaxPoolMembers
+		);
+		ensure!(
+			MaxPoolMembers::<T>::get().map_or(true, |max| PoolMembers::<T>::count() < max),
+			Error::<T>::MaxPoolMembers
+		);
+		self.member_counter = self.member_counter.checked_add(1).ok_or(Error::<T>::OverflowRisk)?;
+		Ok(())
+	}
+
+	/// Decrement the member counter.
+	fn dec_members(mut self) -> Self {
+		self.member_counter = self.member_counter.saturating_sub(1);
+		self
+	}
+
+	fn is_root(&self, who: &T::AccountId) -> bool {
+		*who == self.roles.root
+	}
+
+	fn can_nominate(&self, who: &T::AccountId) -> bool {
+		self.is_root(who) || *who == self.roles.nominator
+	}
+
+	fn can_kick(&self, who: &T::AccountId) -> bool {
+		(self.is_root(who) || *who == self.roles.state_toggler) &&
+			self.state == PoolState::Blocked
+	}
+
+	fn can_toggle_state(&self, who: &T::AccountId) -> bool {
+		(self.is_root(who) || *who == self.roles.state_toggler) && !self.is_destroying()
+	}
+
+	fn is_destroying(&self) -> bool {
+		matches!(self.state, PoolState::Destroying)
+	}
+
+	/// Whether or not the pool is ok to be in `PoolSate::Open`. If this returns an `Err`, then
+	/// the pool is unrecoverable and should be in the destroying state.
+	fn ok_to_be_open(&self) -> Result<(), DispatchError> {
+		ensure!(!self.is_destroying(), Error::<T>::CanNotChangeState);
+
+		// if the pool has points but no active balance, then every point has been slashed away,
+		// and newly issued points would be worthless.
+		ensure!(
+			self.points.is_zero() || !self.active_balance().is_zero(),
+			Error::<T>::OverflowRisk
+		);
+		Ok(())
+	}
+
+	/// Check that the pool can accept a member with `new_funds`.
+	fn ok_to_join(&self) -> Result<(), DispatchError> {
+		ensure!(self.state == PoolState::Open, Error::<T>::NotOpen);
+		self.ok_to_be_open()?;
+		Ok(())
+	}
+
+	/// Check that `caller` is allowed to unbond `unbonding_points` of `target_member`, the
+	/// membership of `target_account`.
+	fn ok_to_unbond_with(
+		&self,
+		caller: &T::AccountId,
+		target_account: &T::AccountId,
+		target_member: &PoolMember<T>,
+		unbonding_points: BalanceOf<T>,
+	) -> Result<(), DispatchError> {
+		ensure!(
+			!unbonding_points.is_zero() && unbonding_points <= target_member.active_points(),
+			Error::<T>::NotEnoughPointsToUnbond
+		);
+
+		let is_permissioned = caller == target_account;
+		let is_depositor = *target_account == self.roles.depositor;
+		let is_full_unbond = unbonding_points == target_member.active_points();
+
+		let balance_after_unbond = self.points_to_balance(
+			target_member.active_points().saturating_sub(unbonding_points),
+		);
+		let min_balance = if is_depositor {
+			Pallet::<T>::depositor_min_bond()
+		} else {
+			MinJoinBond::<T>::get()
+		};
+		// any partial unbonding is only ever allowed if this unbond is permissioned.
+		ensure!(
+			is_full_unbond || (is_permissioned && balance_after_unbond >= min_balance),
+			Error::<T>::MinimumBondNotMet
+		);
+
+		match (is_permissioned, is_depositor) {
+			// If the pool is blocked, then an admin with kicking permissions can remove a
+			// member. If the pool is being destroyed, anyone can remove a member.
+			(false, false) => ensure!(
+				self.can_kick(caller) || self.is_destroying(),
+				Error::<T>::NotKickerOrDestroying
+			),
+			// Any member who is not the depositor can always unbond themselves.
+			(true, false) => (),
+			// The depositor can only fully unbond once the pool is destroying and they are the
+			// only member left, and can never be unbonded by anyone else.
+			(true, true) =>
+				if is_full_unbond {
+					ensure!(self.is_destroying(), Error::<T>::NotDestroying);
+					ensure!(self.member_counter == 1, Error::<T>::NotOnlyPoolMember);
+				},
+			(false, true) => return Err(Error::<T>::DoesNotHavePermission.into()),
+		};
+		Ok(())
+	}
+
+	/// Check that `caller` is allowed to withdraw the unbonded funds of `target_account`.
+	///
+	/// # Warning
+	///
+	/// Returns `Ok(())` when the depositor withdraws as the last member, in which case the
+	/// caller is responsible for dissolving the pool.
+	fn ok_to_withdraw_unbonded_with(
+		&self,
+		caller: &T::AccountId,
+		target_account: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		// This isn't a depositor-specific check: anyone can withdraw their own funds, and
+		// anyone can withdraw the funds of the members of a destroying pool.
+		let is_permissioned = caller == target_account;
+		ensure!(is_permissioned || self.is_destroying(), Error::<T>::NotKickerOrDestroying);
+		Ok(())
+	}
+
+	/// Bond exactly `amount` from `who`'s funds into this pool.
+	///
+	/// If the bond type is `Create`, `StakingInterface::bond` is called, and `who` is allowed to
+	/// be killed. Otherwise, `StakingInterface::bond_extra` is called and `who` cannot be killed.
+	///
+	/// Returns `Ok(points_issues)`, `Err` otherwise.
+	fn try_bond_funds(
+		&mut self,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+		ty: BondType,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		// Cache the value
+		let bonded_account = self.bonded_account();
+		T::Currency::transfer(
+			who,
+			&bonded_account,
+			amount,
+			match ty {
+				BondType::Create => ExistenceRequirement::AllowDeath,
+				BondType::Later => ExistenceRequirement::KeepAlive,
+			},
+		)?;
+		// We must calculate the points issued *before* we bond who's funds, else points:balance
+		// ratio will be wrong.
+		let points_issued = self.issue(amount);
+
+		match ty {
+			BondType::Create => T::StakingInterface::bond(
+				bonded_account.clone(),
+				bonded_account,
+				amount,
+				self.reward_account(),
+			)?,
+			// The pool should always be created in such a way its in a state to bond extra, but
+			// if the active balance is slashed below the minimum bonded or the account cannot be
+			// found, we exit early.
+			BondType::Later => T::StakingInterface::bond_extra(bonded_account, amount)?,
+		}
+
+		Ok(points_issued)
+	}
+
+	/// The balance of the bonded account that is not locked by the staking system.
+	fn transferable_balance(&self) -> BalanceOf<T> {
+		let account = self.bonded_account();
+		T::Currency::free_balance(&account)
+			.saturating_sub(T::StakingInterface::total_stake(&account).unwrap_or_default())
+	}
+}
+
+/// A reward pool.
+///
+/// A reward pool is not so much a pool anymore, since it does not contain any shares or points.
+/// Rather, simply to fit nicely next to bonded pool and unbonding pools in terms of terminology.
+/// In reality, a reward pool is just a container for a few pool-dependent data related to the
+/// rewards.
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
+#[cfg_attr(feature = "std", derive(DefaultNoBound))]
+#[scale_info(skip_type_params(T))]
+pub struct RewardPool<T: Config> {
+	/// The last recorded value of the reward counter.
+	///
+	/// This is updated ONLY when the points in the bonded pool change, which means `join`,
+	/// `bond_extra` and `unbond`, all of which is done through `update_recorded`.
+	pub last_recorded_reward_counter: FixedU128,
+	/// The last recorded total payouts of the reward pool.
+	///
+	/// Payouts is essentially income of the pool.
+	///
+	/// Update criteria is same as that of `last_recorded_reward_counter`.
+	pub last_recorded_total_payouts: BalanceOf<T>,
+	/// Total amount that this pool has paid out so far to the members.
+	pub total_rewards_claimed: BalanceOf<T>,
+}
+
+impl<T: Config> RewardPool<T> {
+	/// Update the recorded values of the pool.
+	///
+	/// This must be called before the points of the bonded pool change, so that the rewards
+	/// earned so far are accounted for with the points that earned them.
+	fn update_records(&mut self, id: PoolId, bonded_points: BalanceOf<T>) -> Result<(), Error<T>> {
+		let balance = Self::current_balance(id);
+		self.last_recorded_reward_counter = self.current_reward_counter(id, bonded_points)?;
+		self.last_recorded_total_payouts = balance.saturating_add(self.total_rewards_claimed);
+		Ok(())
+	}
+
+	/// Get the current reward counter, based on the given `bonded_points` being the state of
+	/// the bonded pool at this time.
+	fn current_reward_counter(
+		&self,
+		id: PoolId,
+		bonded_points: BalanceOf<T>,
+	) -> Result<FixedU128, Error<T>> {
+		// rewards that arrive while the pool has no points cannot be attributed to anyone, and
+		// stay in the reward account.
+		if bonded_points.is_zero() {
+			return Ok(self.last_recorded_reward_counter)
+		}
+
+		let balance = Self::current_balance(id);
+		let payouts_since_last_record = balance
+			.saturating_add(self.total_rewards_claimed)
+			.saturating_sub(self.last_recorded_total_payouts);
+
+		FixedU128::checked_from_rational(
+			payouts_since_last_record.saturated_into::<u128>(),
+			bonded_points.saturated_into::<u128>(),
+		)
+		.and_then(|r| self.last_recorded_reward_counter.checked_add(&r))
+		.ok_or(Error::<T>::OverflowRisk)
+	}
+
+	/// Register some rewards that are claimed from the pool by the members.
+	fn register_claimed_reward(&mut self, reward: BalanceOf<T>) {
+		self.total_rewards_claimed = self.total_rewards_claimed.saturating_add(reward);
+	}
+
+	/// Get the current balance of the reward pool.
+	///
+	/// This is sum of all the rewards that are claimable by pool members.
+	fn current_balance(id: PoolId) -> BalanceOf<T> {
+		T::Currency::free_balance(&Pallet::<T>::create_reward_account(id))
+			.saturating_sub(T::Currency::minimum_balance())
+	}
+}
+
+/// An unbonding pool. This is always mapped with an era.
+#[derive(
+	Encode, Decode, CloneNoBound, DefaultNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct UnbondPool<T: Config> {
+	/// The points in this pool.
+	pub points: BalanceOf<T>,
+	/// The funds in the pool.
+	pub balance: BalanceOf<T>,
+}
+
+impl<T: Config> UnbondPool<T> {
+	fn balance_to_point(&self, new_funds: BalanceOf<T>) -> BalanceOf<T> {
+		Pallet::<T>::balance_to_point(self.balance, self.points, new_funds)
+	}
+
+	fn point_to_balance(&self, points: BalanceOf<T>) -> BalanceOf<T> {
+		Pallet::<T>::point_to_balance(self.balance, self.points, points)
+	}
+
+	/// Issue the equivalent points of `new_funds` into self.
+	///
+	/// Returns the actual amounts of points issued.
+	fn issue(&mut self, new_funds: BalanceOf<T>) -> BalanceOf<T> {
+		let new_points = self.balance_to_point(new_funds);
+		self.points = self.points.saturating_add(new_points);
+		self.balance = self.balance.saturating_add(new_funds);
+		new_points
+	}
+
+	/// Dissolve some points from the unbonding pool, reducing the balance of the pool
+	/// proportionally.
+	///
+	/// This is the opposite of `issue`.
+	///
+	/// Returns the actual amount of `Balance` that was removed from the pool.
+	fn dissolve(&mut self, points: BalanceOf<T>) -> BalanceOf<T> {
+		let balance_to_unbond = self.point_to_balance(points);
+		self.points = self.points.saturating_sub(points);
+		self.balance = self.balance.saturating_sub(balance_to_unbond);
+
+		balance_to_unbond
+	}
+}
+
+/// A group of unbonding pools belonging to a single bonded pool.
+#[derive(
+	Encode, Decode, CloneNoBound, DefaultNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct SubPools<T: Config> {
+	/// A general, era agnostic pool of funds that have fully unbonded. The pools
+	/// of `Self::with_era` will lazily be merged into into this pool if they are
+	/// older then `current_era - TotalUnbondingPools`.
+	pub no_era: UnbondPool<T>,
+	/// Map of era in which a pool becomes unbonded in => unbond pools.
+	pub with_era: BoundedBTreeMap<EraIndex, UnbondPool<T>, TotalUnbondingPools<T>>,
+}
+
+impl<T: Config> SubPools<T> {
+	/// Merge the oldest `with_era` unbond pools into the `no_era` unbond pool.
+	///
+	/// This is often used whilst getting the sub-pool from storage, thus it consumes and returns
+	/// `Self` for ergonomic purposes.
+	fn maybe_merge_pools(mut self, current_era: EraIndex) -> Self {
+		// Ex: if `TotalUnbondingPools` is 5 and current era is 10, we only want to retain pools
+		// 6..=10. Note that in the first few eras where `checked_sub` is `None`, we don't remove
+		// anything.
+		if let Some(newest_era_to_remove) =
+			current_era.checked_sub(T::PostUnbondingPoolsWindow::get())
+		{
+			let no_era = &mut self.no_era;
+			let mut with_era = sp_std::mem::take(&mut self.with_era).into_inner();
+			with_era.retain(|k, v| {
+				if *k > newest_era_to_remove {
+					// keep
+					true
+				} else {
+					// merge into the no-era pool
+					no_era.points = no_era.points.saturating_add(v.points);
+					no_era.balance = no_era.balance.saturating_add(v.balance);
+					false
+				}
+			});
+			self.with_era = BoundedBTreeMap::try_from(with_era)
+				.expect("a subset of a bounded map is bounded; qed");
+		}
+
+		self
+	}
+}
+
+/// The maximum amount of eras an unbonding pool can exist prior to being merged with the
+/// `no_era` pool. This is guaranteed to at least be equal to the staking `BondingDuration`. For
+/// improved UX [`Config::PostUnbondingPoolsWindow`] should be configured to a non-zero value.
+pub struct TotalUnbondingPools<T: Config>(PhantomData<T>);
+impl<T: Config> Get<u32> for TotalUnbondingPools<T> {
+	fn get() -> u32 {
+		// NOTE: this may be dangerous in the scenario bonding_duration gets decreased because
+		// we would no longer be able to decode `UnbondingPoolsWithEra`, which uses
+		// `TotalUnbondingPools` as the bound
+		T::StakingInterface::bonding_duration() + T::PostUnbondingPoolsWindow::get()
+	}
+}
+
+pub(crate) const LOG_TARGET: &'static str = "runtime::nomination-pools";
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: weights::WeightInfo;
+
+		/// The nominating balance.
+		type Currency: Currency<Self::AccountId>;
+
+		/// The nomination pool's pallet id, used to derive the bonded and reward accounts of
+		/// each pool.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The interface for nominating.
+		type StakingInterface: StakingInterface<
+			Balance = BalanceOf<Self>,
+			AccountId = Self::AccountId,
+		>;
+
+		/// The amount of eras a `SubPools::with_era` pool can exist before it gets merged into
+		/// the `SubPools::no_era` pool. In other words, this is the amount of eras a member will
+		/// be able to withdraw from an unbonding pool which is guaranteed to have the correct
+		/// ratio of points to balance; once the `with_era` pool is merged into the `no_era` pool,
+		/// the ratio can become skewed due to some slashed ratio getting merged in at some
+		/// point.
+		#[pallet::constant]
+		type PostUnbondingPoolsWindow: Get<u32>;
+
+		/// The maximum number of simultaneous unbonding chunks that can exist per member.
+		#[pallet::constant]
+		type MaxUnbonding: Get<u32>;
+	}
+
+	/// Minimum amount to bond to join a pool.
+	#[pallet::storage]
+	pub type MinJoinBond<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Minimum bond required to create a pool.
+	///
+	/// This is the amount that the depositor must put as their initial stake in the pool, as an
+	/// indication of "skin in the game".
+	///
+	/// This is the value that will always exist in the staking ledger of the pool bonded account
+	/// while all other accounts leave.
+	#[pallet::storage]
+	pub type MinCreateBond<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Maximum number of nomination pools that can exist. If `None`, then an unbounded number of
+	/// pools can exist.
+	#[pallet::storage]
+	pub type MaxPools<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+	/// Maximum number of members that can exist in the system. If `None`, then the count
+	/// members are not bound on a system wide basis.
+	#[pallet::storage]
+	pub type MaxPoolMembers<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+	/// Maximum number of members that may belong to pool. If `None`, then the count of
+	/// members is not bound on a per pool basis.
+	#[pallet::storage]
+	pub type MaxPoolMembersPerPool<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+	/// Active members.
+	#[pallet::storage]
+	pub type PoolMembers<T: Config> =
+		CountedStorageMap<_, Twox64Concat, T::AccountId, PoolMember<T>>;
+
+	/// Storage for bonded pools.
+	// To get or insert a pool see [`BondedPool::get`] and [`BondedPool::put`]
+	#[pallet::storage]
+	pub type BondedPools<T: Config> =
+		CountedStorageMap<_, Twox64Concat, PoolId, BondedPoolInner<T>>;
+
+	/// Reward pools. This is where there rewards for each pool accumulate. When a members payout
+	/// is claimed, the balance comes out fo the reward pool. Keyed by the bonded pools account.
+	#[pallet::storage]
+	pub type RewardPools<T: Config> = CountedStorageMap<_, Twox64Concat, PoolId, RewardPool<T>>;
+
+	/// Groups of unbonding pools. Each group of unbonding pools belongs to a bonded pool,
+	/// hence the name sub-pools. Keyed by the bonded pools account.
+	#[pallet::storage]
+	pub type SubPoolsStorage<T: Config> = CountedStorageMap<_, Twox64Concat, PoolId, SubPools<T>>;
+
+	/// Ever increasing number of all pools created so far.
+	#[pallet::storage]
+	pub type LastPoolId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// A reverse lookup from the pool's account id to its id.
+	///
+	/// This is only used for slashing. In all other instances, the pool id is used, and the
+	/// accounts are deterministically derived from it.
+	#[pallet::storage]
+	pub type ReversePoolIdLookup<T: Config> =
+		CountedStorageMap<_, Twox64Concat, T::AccountId, PoolId, OptionQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub min_join_bond: BalanceOf<T>,
+		pub min_create_bond: BalanceOf<T>,
+		pub max_pools: Option<u32>,
+		pub max_members_per_pool: Option<u32>,
+		pub max_members: Option<u32>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self {
+				min_join_bond: Zero::zero(),
+				min_create_bond: Zero::zero(),
+				max_pools: Some(16),
+				max_members_per_pool: Some(32),
+				max_members: Some(16 * 32),
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			MinJoinBond::<T>::put(self.min_join_bond);
+			MinCreateBond::<T>::put(self.min_create_bond);
+			if let Some(max_pools) = self.max_pools {
+				MaxPools::<T>::put(max_pools);
+			}
+			if let Some(max_members_per_pool) = self.max_members_per_pool {
+				MaxPoolMembersPerPool::<T>::put(max_members_per_pool);
+			}
+			if let Some(max_members) = self.max_members {
+				MaxPoolMembers::<T>::put(max_members);
+			}
+		}
+	}
+
+	/// Events of this pallet.
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pool has been created.
+		Created { depositor: T::AccountId, pool_id: PoolId },
+		/// A member has became bonded in a pool.
+		Bonded { member: T::AccountId, pool_id: PoolId, bonded: BalanceOf<T>, joined: bool },
+		/// A payout has been made to a member.
+		PaidOut { member: T::AccountId, pool_id: PoolId, payout: BalanceOf<T> },
+		/// A member has unbonded from their pool.
+		///
+		/// - `balance` is the corresponding balance of the number of points that has been
+		///   requested to be unbonded (the argument of the `unbond` transaction) from the bonded
+		///   pool.
+		/// - `points` is the number of points that are issued as a result of `balance` being
+		///   dissolved into the corresponding unbonding pool.
+		/// - `era` is the era in which the balance will be unbonded.
+		Unbonded {
+			member: T::AccountId,
+			pool_id: PoolId,
+			balance: BalanceOf<T>,
+			points: BalanceOf<T>,
+			era: EraIndex,
+		},
+		/// A member has withdrawn from their pool.
+		///
+		/// The given number of `points` have been dissolved in return of `balance`.
+		///
+		/// Similar to `Unbonded` event, in the absence of slashing, the ratio of point to balance
+		/// will be 1.
+		Withdrawn {
+			member: T::AccountId,
+			pool_id: PoolId,
+			balance: BalanceOf<T>,
+			points: BalanceOf<T>,
+		},
+		/// A pool has been destroyed.
+		Destroyed { pool_id: PoolId },
+		/// The state of a pool has changed
+		StateChanged { pool_id: PoolId, new_state: PoolState },
+		/// A member has been removed from a pool.
+		///
+		/// The removal can be voluntary (withdrawn all unbonded funds) or involuntary (kicked).
+		MemberRemoved { pool_id: PoolId, member: T::AccountId },
+		/// The active balance of pool `pool_id` has been slashed to `balance`.
+		PoolSlashed { pool_id: PoolId, balance: BalanceOf<T> },
+		/// The unbond pool at `era` of pool `pool_id` has been slashed to `balance`.
+		UnbondingPoolSlashed { pool_id: PoolId, era: EraIndex, balance: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// A (bonded) pool id does not exist.
+		PoolNotFound,
+		/// An account is not a member.
+		PoolMemberNotFound,
+		/// A reward pool does not exist. In all cases this is a system logic error.
+		RewardPoolNotFound,
+		/// A sub pool does not exist.
+		SubPoolsNotFound,
+		/// An account is already delegating in another pool. An account may only belong to one
+		/// pool at a time.
+		AccountBelongsToOtherPool,
+		/// The member is fully unbonded (and thus cannot access the bonded and reward pool
+		/// anymore to, for example, collect rewards).
+		FullyUnbonding,
+		/// The member cannot unbond further chunks due to reaching the limit.
+		MaxUnbondingLimit,
+		/// None of the funds can be withdrawn yet because the bonding duration has not passed.
+		CannotWithdrawAny,
+		/// The amount does not meet the minimum bond to either join or create a pool.
+		MinimumBondNotMet,
+		/// The transaction could not be executed due to overflow risk for the pool.
+		OverflowRisk,
+		/// A pool must be in [`PoolState::Destroying`] in order for the depositor to unbond or
+		/// for other members to be permissionlessly unbonded.
+		NotDestroying,
+		/// The depositor must be the only member in the bonded pool in order to unbond. And the
+		/// depositor must be the only member in the sub pools in order to withdraw unbonded.
+		NotOnlyPoolMember,
+		/// The caller does not have nominating permissions for the pool.
+		NotNominator,
+		/// Either a) the caller cannot make a valid kick or b) the pool is not destroying.
+		NotKickerOrDestroying,
+		/// The pool is not open to join
+		NotOpen,
+		/// The system is maxed out on pools.
+		MaxPools,
+		/// Too many members in the pool or system.
+		MaxPoolMembers,
+		/// The pools state cannot be changed.
+		CanNotChangeState,
+		/// The caller does not have adequate permissions.
+		DoesNotHavePermission,
+		/// The member does not have enough active points to unbond the requested amount.
+		NotEnoughPointsToUnbond,
+		/// Some error occurred that should never happen. This should be reported to the
+		/// maintainers.
+		DefensiveError,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Stake funds with a pool. The amount to bond is transferred from the member to the
+		/// pools account and immediately increases the pools bond.
+		///
+		/// # Note
+		///
+		/// * An account can only be a member of a single pool.
+		/// * An account cannot join the same pool multiple times.
+		/// * This call will *not* dust the member account, so the member must have at least
+		///   `existential deposit + amount` in their account.
+		/// * Only a pool with [`PoolState::Open`] can be joined
+		#[pallet::weight(T::WeightInfo::join())]
+		#[transactional]
+		pub fn join(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+			pool_id: PoolId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(amount >= MinJoinBond::<T>::get(), Error::<T>::MinimumBondNotMet);
+			// If a member already exists that means they already belong to a pool
+			ensure!(!PoolMembers::<T>::contains_key(&who), Error::<T>::AccountBelongsToOtherPool);
+
+			let mut bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			bonded_pool.ok_to_join()?;
+
+			let mut reward_pool =
+				RewardPools::<T>::get(pool_id).ok_or(Error::<T>::RewardPoolNotFound)?;
+			// IMPORTANT: reward pool records must be updated with the old points.
+			reward_pool.update_records(pool_id, bonded_pool.points)?;
+
+			bonded_pool.try_inc_members()?;
+			let points_issued = bonded_pool.try_bond_funds(&who, amount, BondType::Later)?;
+
+			PoolMembers::<T>::insert(
+				who.clone(),
+				PoolMember::<T> {
+					pool_id,
+					points: points_issued,
+					// we just updated `last_known_reward_counter` to the current one in
+					// `update_recorded`.
+					last_recorded_reward_counter: reward_pool.last_recorded_reward_counter,
+					unbonding_eras: Default::default(),
+				},
+			);
+
+			Self::deposit_event(Event::<T>::Bonded {
+				member: who,
+				pool_id,
+				bonded: amount,
+				joined: true,
+			});
+
+			bonded_pool.put();
+			RewardPools::<T>::insert(pool_id, reward_pool);
+
+			Ok(())
+		}
+
+		/// Bond `extra` more funds from `origin` into the pool to which they already belong.
+		///
+		/// Additional funds can come from either the free balance of the account, of from the
+		/// accumulated rewards, see [`BondExtra`].
+		///
+		/// Bonding extra funds implies an automatic payout of all pending rewards as well.
+		#[pallet::weight(
+			T::WeightInfo::bond_extra_transfer().max(T::WeightInfo::bond_extra_reward())
+		)]
+		#[transactional]
+		pub fn bond_extra(origin: OriginFor<T>, extra: BondExtra<BalanceOf<T>>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (mut member, mut bonded_pool, mut reward_pool) = Self::get_member_with_pools(&who)?;
+			bonded_pool.ok_to_be_open()?;
+
+			// IMPORTANT: reward pool records must be updated with the old points.
+			reward_pool.update_records(bonded_pool.id, bonded_pool.points)?;
+
+			let claimed =
+				Self::do_reward_payout(&who, &mut member, &mut bonded_pool, &mut reward_pool)?;
+
+			let (points_issued, bonded) = match extra {
+				BondExtra::FreeBalance(amount) =>
+					(bonded_pool.try_bond_funds(&who, amount, BondType::Later)?, amount),
+				BondExtra::Rewards =>
+					(bonded_pool.try_bond_funds(&who, claimed, BondType::Later)?, claimed),
+			};
+
+			member.points = member.points.saturating_add(points_issued);
+
+			Self::deposit_event(Event::<T>::Bonded {
+				member: who.clone(),
+				pool_id: member.pool_id,
+				bonded,
+				joined: false,
+			});
+			Self::put_member_with_pools(&who, member, bonded_pool, reward_pool);
+
+			Ok(())
+		}
+
+		/// A bonded member can use this to claim their payout based on the rewards that the pool
+		/// has accumulated since their last claimed payout (OR since joining if this is there
+		/// first time claiming rewards). The payout will be transferred to the member's account.
+		///
+		/// The member will earn rewards pro rata based on the members stake vs the sum of the
+		/// members in the pools stake. Rewards do not "expire".
+		#[pallet::weight(T::WeightInfo::claim_payout())]
+		pub fn claim_payout(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (mut member, mut bonded_pool, mut reward_pool) = Self::get_member_with_pools(&who)?;
+
+			let _ = Self::do_reward_payout(&who, &mut member, &mut bonded_pool, &mut reward_pool)?;
+
+			Self::put_member_with_pools(&who, member, bonded_pool, reward_pool);
+			Ok(())
+		}
+
+		/// Unbond up to `unbonding_points` of the `member_account`'s funds from the pool. It
+		/// implicitly collects the rewards one last time, since not doing so would mean some
+		/// rewards would be forfeited.
+		///
+		/// Under certain conditions, this call can be dispatched permissionlessly (i.e. by any
+		/// account).
+		///
+		/// # Conditions for a permissionless dispatch.
+		///
+		/// * The pool is blocked and the caller is either the root or state-toggler. This is
+		///   refereed to as a kick.
+		/// * The pool is destroying and the member is not the depositor.
+		///
+		/// # Conditions for permissioned dispatch (i.e. the caller is also the
+		/// `member_account`):
+		///
+		/// * The caller is not the depositor.
+		/// * The caller is the depositor, the pool is destroying and no other members are in the
+		///   pool.
+		///
+		/// ## Note
+		///
+		/// If there are too many unlocking chunks to unbond with the pool account,
+		/// [`Call::pool_withdraw_unbonded`] can be called to try and minimize unlocking chunks. If
+		/// there are too many unlocking chunks, the result of this call will likely be the
+		/// `NoMoreChunks` error from the staking system.
+		#[pallet::weight(T::WeightInfo::unbond())]
+		#[transactional]
+		pub fn unbond(
+			origin: OriginFor<T>,
+			member_account: T::AccountId,
+			#[pallet::compact] unbonding_points: BalanceOf<T>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let (mut member, mut bonded_pool, mut reward_pool) =
+				Self::get_member_with_pools(&member_account)?;
+
+			bonded_pool.ok_to_unbond_with(&caller, &member_account, &member, unbonding_points)?;
+
+			// Claim the the payout prior to unbonding. Once the user is unbonding their points no
+			// longer exist in the bonded pool and thus they can no longer claim their payouts. It
+			// is not strictly necessary to claim the rewards, but we do it here for UX.
+			reward_pool.update_records(bonded_pool.id, bonded_pool.points)?;
+			let _ = Self::do_reward_payout(
+				&member_account,
+				&mut member,
+				&mut bonded_pool,
+				&mut reward_pool,
+			)?;
+
+			let current_era = T::StakingInterface::current_era();
+			let unbond_era = T::StakingInterface::bonding_duration().saturating_add(current_era);
+
+			// Unbond in the actual underlying nominator.
+			let unbonding_balance = bonded_pool.dissolve(unbonding_points);
+			// The depositor leaving a destroying pool takes the last of the active stake with
+			// them, which the staking system only allows once the pool stops nominating.
+			if bonded_pool.points.is_zero() {
+				T::StakingInterface::chill(bonded_pool.bonded_account())?;
+			}
+			T::StakingInterface::unbond(bonded_pool.bonded_account(), unbonding_balance)?;
+
+			// Note that we lazily create the unbonding pools here if they don't already exist
+			let mut sub_pools = SubPoolsStorage::<T>::get(member.pool_id)
+				.unwrap_or_default()
+				.maybe_merge_pools(current_era);
+
+			// Update the unbond pool associated with the current era with the unbonded funds. Note
+			// that we lazily create the unbond pool if it does not yet exist.
+			if !sub_pools.with_era.contains_key(&unbond_era) {
+				sub_pools
+					.with_era
+					.try_insert(unbond_era, UnbondPool::default())
+					// The above call to `maybe_merge_pools` should ensure there is
+					// always enough space to insert.
+					.map_err(|_| Error::<T>::DefensiveError)?;
+			}
+
+			let points_unbonded = sub_pools
+				.with_era
+				.get_mut(&unbond_era)
+				// The above check ensures the pool exists.
+				.ok_or(Error::<T>::DefensiveError)?
+				.issue(unbonding_balance);
+
+			// Try and unbond in the member map.
+			member.try_unbond(unbonding_points, points_unbonded, unbond_era)?;
+
+			Self::deposit_event(Event::<T>::Unbonded {
+				member: member_account.clone(),
+				pool_id: member.pool_id,
+				points: points_unbonded,
+				balance: unbonding_balance,
+				era: unbond_era,
+			});
+
+			// Now that we know everything has worked write the items to storage.
+			SubPoolsStorage::<T>::insert(&member.pool_id, sub_pools);
+			Self::put_member_with_pools(&member_account, member, bonded_pool, reward_pool);
+			Ok(())
+		}
+
+		/// Call `withdraw_unbonded` for the pools account. This call can be made by any account.
+		///
+		/// This is useful if their are too many unlocking chunks to call `unbond`, and some
+		/// can be cleared by withdrawing. In the case there are too many unlocking chunks, the user
+		/// would probably see an error like `NoMoreChunks` emitted from the staking system when
+		/// they attempt to unbond.
+		#[pallet::weight(T::WeightInfo::pool_withdraw_unbonded(*num_slashing_spans))]
+		pub fn pool_withdraw_unbonded(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			num_slashing_spans: u32,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			let pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			// For now we only allow a pool to withdraw unbonded if its not destroying. If the pool
+			// is destroying then `withdraw_unbonded` can be used.
+			ensure!(pool.state != PoolState::Destroying, Error::<T>::NotDestroying);
+			T::StakingInterface::withdraw_unbonded(pool.bonded_account(), num_slashing_spans)?;
+			Ok(())
+		}
+
+		/// Withdraw unbonded funds from `member_account`. If no bonded funds can be unbonded, an
+		/// error is returned.
+		///
+		/// Under certain conditions, this call can be dispatched permissionlessly (i.e. by any
+		/// account).
+		///
+		/// # Conditions for a permissionless dispatch
+		///
+		/// * The pool is in destroy mode and the target is not the depositor.
+		///
+		/// # Conditions for permissioned dispatch
+		///
+		/// * The caller is the target and they are not the depositor.
+		///
+		/// # Note
+		///
+		/// If the target is the depositor, the pool will be destroyed.
+		#[pallet::weight(
+			T::WeightInfo::withdraw_unbonded_kill(*num_slashing_spans)
+		)]
+		#[transactional]
+		pub fn withdraw_unbonded(
+			origin: OriginFor<T>,
+			member_account: T::AccountId,
+			num_slashing_spans: u32,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let mut member =
+				PoolMembers::<T>::get(&member_account).ok_or(Error::<T>::PoolMemberNotFound)?;
+			let current_era = T::StakingInterface::current_era();
+
+			let bonded_pool = BondedPool::<T>::get(member.pool_id)
+				.ok_or_else(|| Error::<T>::PoolNotFound)?;
+			let mut sub_pools = SubPoolsStorage::<T>::get(member.pool_id)
+				.ok_or_else(|| Error::<T>::SubPoolsNotFound)?;
+
+			bonded_pool.ok_to_withdraw_unbonded_with(&caller, &member_account)?;
+
+			// NOTE: must do this after we have done the `ok_to_withdraw_unbonded_other_with` check.
+			let withdrawn_points = member.withdraw_unlocked(current_era);
+			ensure!(!withdrawn_points.is_empty(), Error::<T>::CannotWithdrawAny);
+
+			// Before calculating the `balance_to_unbond`, we call withdraw unbonded to ensure the
+			// `transferrable_balance` is correct.
+			let stash_killed = T::StakingInterface::withdraw_unbonded(
+				bonded_pool.bonded_account(),
+				num_slashing_spans,
+			)?;
+
+			// defensive-only: the depositor puts enough funds into the stash so that it will only
+			// be destroyed when they are leaving.
+			if stash_killed && member_account != bonded_pool.roles.depositor {
+				log::error!(
+					target: LOG_TARGET,
+					"pool {:?} stash was killed before its depositor left",
+					bonded_pool.id,
+				);
+			}
+
+			let mut sum_unlocked_points: BalanceOf<T> = Zero::zero();
+			let balance_to_unbond = withdrawn_points
+				.iter()
+				.fold(BalanceOf::<T>::zero(), |accumulator, (era, unlocked_points)| {
+					sum_unlocked_points = sum_unlocked_points.saturating_add(*unlocked_points);
+					if let Some(era_pool) = sub_pools.with_era.get_mut(era) {
+						let balance_to_unbond = era_pool.dissolve(*unlocked_points);
+						if era_pool.points.is_zero() {
+							sub_pools.with_era.remove(era);
+						}
+						accumulator.saturating_add(balance_to_unbond)
+					} else {
+						// A pool does not belong to this era, so it must have been merged to the
+						// era-less pool.
+						accumulator.saturating_add(sub_pools.no_era.dissolve(*unlocked_points))
+					}
+				})
+				// A call to this transaction may cause the pool's stash to get dusted. If this
+				// happens before the last member has withdrawn, then all subsequent withdraws will
+				// be 0. However the unbond pools do no get updated to reflect this. In the
+				// aforementioned scenario, this check ensures we don't try to withdraw funds that
+				// don't exist. This check is also defensive in cases where the unbond pool does not
+				// update its balance (e.g. a bug in the slashing hook.) We gracefully proceed in
+				// order to ensure members can leave the pool and it can be destroyed.
+				.min(bonded_pool.transferable_balance());
+
+			T::Currency::transfer(
+				&bonded_pool.bonded_account(),
+				&member_account,
+				balance_to_unbond,
+				ExistenceRequirement::AllowDeath,
+			)
+			.map_err(|_| Error::<T>::DefensiveError)?;
+
+			Self::deposit_event(Event::<T>::Withdrawn {
+				member: member_account.clone(),
+				pool_id: member.pool_id,
+				points: sum_unlocked_points,
+				balance: balance_to_unbond,
+			});
+
+			let post_info_weight = if member.total_points().is_zero() {
+				// member being reaped.
+				PoolMembers::<T>::remove(&member_account);
+				Self::deposit_event(Event::<T>::MemberRemoved {
+					pool_id: member.pool_id,
+					member: member_account.clone(),
+				});
+
+				if member_account == bonded_pool.roles.depositor {
+					Pallet::<T>::dissolve_pool(bonded_pool);
+					None
+				} else {
+					bonded_pool.dec_members().put();
+					SubPoolsStorage::<T>::insert(&member.pool_id, sub_pools);
+					Some(T::WeightInfo::withdraw_unbonded_update(num_slashing_spans))
+				}
+			} else {
+				// we certainly don't need to delete any pools, because no one is being removed.
+				SubPoolsStorage::<T>::insert(&member.pool_id, sub_pools);
+				PoolMembers::<T>::insert(&member_account, member);
+				Some(T::WeightInfo::withdraw_unbonded_update(num_slashing_spans))
+			};
+
+			Ok(post_info_weight.into())
+		}
+
+		/// Create a new delegation pool.
+		///
+		/// # Arguments
+		///
+		/// * `amount` - The amount of funds to delegate to the pool. This also acts of a sort of
+		///   deposit since the pools creator cannot fully unbond funds until the pool is being
+		///   destroyed.
+		/// * `root` - The account to set as [`PoolRoles::root`].
+		/// * `nominator` - The account to set as the [`PoolRoles::nominator`].
+		/// * `state_toggler` - The account to set as the [`PoolRoles::state_toggler`].
+		///
+		/// # Note
+		///
+		/// In addition to `amount`, the caller will transfer the existential deposit; so the caller
+		/// needs at have at least `amount + existential_deposit` transferrable.
+		#[pallet::weight(T::WeightInfo::create())]
+		#[transactional]
+		pub fn create(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+			root: T::AccountId,
+			nominator: T::AccountId,
+			state_toggler: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(amount >= Pallet::<T>::depositor_min_bond(), Error::<T>::MinimumBondNotMet);
+			ensure!(
+				MaxPools::<T>::get()
+					.map_or(true, |max_pools| BondedPools::<T>::count() < max_pools),
+				Error::<T>::MaxPools
+			);
+			ensure!(!PoolMembers::<T>::contains_key(&who), Error::<T>::AccountBelongsToOtherPool);
+
+			let pool_id = LastPoolId::<T>::try_mutate::<_, Error<T>, _>(|id| {
+				*id = id.checked_add(1).ok_or(Error::<T>::OverflowRisk)?;
+				Ok(*id)
+			})?;
+			let mut bonded_pool = BondedPool::<T>::new(
+				pool_id,
+				PoolRoles { root, nominator, state_toggler, depositor: who.clone() },
+			);
+
+			bonded_pool.try_inc_members()?;
+			let points = bonded_pool.try_bond_funds(&who, amount, BondType::Create)?;
+
+			T::Currency::transfer(
+				&who,
+				&bonded_pool.reward_account(),
+				T::Currency::minimum_balance(),
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			PoolMembers::<T>::insert(
+				who.clone(),
+				PoolMember::<T> {
+					pool_id,
+					points,
+					last_recorded_reward_counter: Zero::zero(),
+					unbonding_eras: Default::default(),
+				},
+			);
+			RewardPools::<T>::insert(
+				pool_id,
+				RewardPool::<T> {
+					last_recorded_reward_counter: Zero::zero(),
+					last_recorded_total_payouts: Zero::zero(),
+					total_rewards_claimed: Zero::zero(),
+				},
+			);
+			ReversePoolIdLookup::<T>::insert(bonded_pool.bonded_account(), pool_id);
+
+			Self::deposit_event(Event::<T>::Created { depositor: who.clone(), pool_id });
+
+			Self::deposit_event(Event::<T>::Bonded {
+				member: who,
+				pool_id,
+				bonded: amount,
+				joined: true,
+			});
+			bonded_pool.put();
+
+			Ok(())
+		}
+
+		/// Nominate on behalf of the pool.
+		///
+		/// The dispatch origin of this call must be signed by the pool nominator or the pool
+		/// root role.
+		///
+		/// This directly forward the call to the staking pallet, on behalf of the pool bonded
+		/// account.
+		#[pallet::weight(T::WeightInfo::nominate(validators.len() as u32))]
+		pub fn nominate(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			validators: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(bonded_pool.can_nominate(&who), Error::<T>::NotNominator);
+			T::StakingInterface::nominate(bonded_pool.bonded_account(), validators)
+		}
+
+		/// Chill on behalf of the pool.
+		///
+		/// The dispatch origin of this call must be signed by the pool nominator or the pool
+		/// root role, same as [`Call::nominate`].
+		#[pallet::weight(T::WeightInfo::chill())]
+		pub fn chill(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(bonded_pool.can_nominate(&who), Error::<T>::NotNominator);
+			T::StakingInterface::chill(bonded_pool.bonded_account())
+		}
+
+		/// Set a new state for the pool.
+		///
+		/// The dispatch origin of this call must be signed by the state toggler, or the root
+		/// role of the pool. A pool that is [`PoolState::Destroying`] can not change its state
+		/// anymore.
+		#[pallet::weight(T::WeightInfo::set_state())]
+		pub fn set_state(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			state: PoolState,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(bonded_pool.state != state, Error::<T>::CanNotChangeState);
+			ensure!(bonded_pool.can_toggle_state(&who), Error::<T>::CanNotChangeState);
+
+			bonded_pool.state = state;
+			Self::deposit_event(Event::<T>::StateChanged { pool_id, new_state: state });
+			bonded_pool.put();
+
+			Ok(())
+		}
+
+		/// Update configurations for the nomination pools. The origin for this call must be Root.
+		///
+		/// # Arguments
+		///
+		/// * `min_join_bond` - Set [`MinJoinBond`].
+		/// * `min_create_bond` - Set [`MinCreateBond`].
+		/// * `max_pools` - Set [`MaxPools`].
+		/// * `max_members` - Set [`MaxPoolMembers`].
+		/// * `max_members_per_pool` - Set [`MaxPoolMembersPerPool`].
+		#[pallet::weight(T::WeightInfo::set_configs())]
+		pub fn set_configs(
+			origin: OriginFor<T>,
+			min_join_bond: BalanceOf<T>,
+			min_create_bond: BalanceOf<T>,
+			max_pools: Option<u32>,
+			max_members: Option<u32>,
+			max_members_per_pool: Option<u32>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			MinJoinBond::<T>::set(min_join_bond);
+			MinCreateBond::<T>::set(min_create_bond);
+			MaxPools::<T>::set(max_pools);
+			MaxPoolMembers::<T>::set(max_members);
+			MaxPoolMembersPerPool::<T>::set(max_members_per_pool);
+			Ok(())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			assert!(
+				T::MaxUnbonding::get() >= T::StakingInterface::bonding_duration(),
+				"There must be at least `BondingDuration` unbonding chunks per member",
+			);
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns the pending rewards for the specified `member_account`.
+	///
+	/// In the case of error the function returns balance of zero.
+	pub fn pending_rewards(member_account: T::AccountId) -> BalanceOf<T> {
+		if let Some(member) = PoolMembers::<T>::get(member_account) {
+			if let Some(reward_pool) = RewardPools::<T>::get(member.pool_id) {
+				if let Some(bonded_pool) = BondedPools::<T>::get(member.pool_id) {
+					return reward_pool
+						.current_reward_counter(member.pool_id, bonded_pool.points)
+						.and_then(|current_reward_counter| {
+							member.pending_rewards(current_reward_counter)
+						})
+						.unwrap_or_default()
+				}
+			}
+		}
+
+		Zero::zero()
+	}
+
+	/// The amount of bond that MUST REMAIN IN BONDED in ALL POOLS.
+	///
+	/// It is the responsibility of the depositor to put these funds into the pool initially. Upon
+	/// unbond, they can never unbond to a value below this amount.
+	///
+	/// It is essentially `max { MinNominatorBond, MinCreateBond, MinJoinBond }`, where the former
+	/// is coming from the staking pallet and the latter two are configured in this pallet.
+	pub fn depositor_min_bond() -> BalanceOf<T> {
+		T::StakingInterface::minimum_bond()
+			.max(MinCreateBond::<T>::get())
+			.max(MinJoinBond::<T>::get())
+	}
+
+	/// Remove everything related to the given bonded pool.
+	///
+	/// All sub-pools are also deleted. All accounts are dusted and the leftover of the reward
+	/// account is returned to the depositor.
+	fn dissolve_pool(bonded_pool: BondedPool<T>) {
+		let reward_account = bonded_pool.reward_account();
+		let bonded_account = bonded_pool.bonded_account();
+
+		ReversePoolIdLookup::<T>::remove(&bonded_account);
+		RewardPools::<T>::remove(bonded_pool.id);
+		SubPoolsStorage::<T>::remove(bonded_pool.id);
+
+		// Return whatever is left in the reward account, including the existential deposit that
+		// was paid upon creation, to the depositor.
+		let _ = T::Currency::transfer(
+			&reward_account,
+			&bonded_pool.roles.depositor,
+			T::Currency::free_balance(&reward_account),
+			ExistenceRequirement::AllowDeath,
+		);
+
+		// Kill accounts from storage by making their balance go below ED. We assume that the
+		// accounts have no references that would prevent destruction once we get to this point.
+		T::Currency::make_free_balance_be(&reward_account, Zero::zero());
+		T::Currency::make_free_balance_be(&bonded_account, Zero::zero());
+
+		Self::deposit_event(Event::<T>::Destroyed { pool_id: bonded_pool.id });
+		bonded_pool.remove();
+	}
+
+	/// Create the main, bonded account of a pool with the given id.
+	pub fn create_bonded_account(id: PoolId) -> T::AccountId {
+		T::PalletId::get().into_sub_account((AccountType::Bonded, id))
+	}
+
+	/// Create the reward account of a pool with the given id.
+	pub fn create_reward_account(id: PoolId) -> T::AccountId {
+		T::PalletId::get().into_sub_account((AccountType::Reward, id))
+	}
+
+	/// Get the member with their associated bonded and reward pool.
+	fn get_member_with_pools(
+		who: &T::AccountId,
+	) -> Result<(PoolMember<T>, BondedPool<T>, RewardPool<T>), Error<T>> {
+		let member = PoolMembers::<T>::get(who).ok_or(Error::<T>::PoolMemberNotFound)?;
+		let bonded_pool =
+			BondedPool::<T>::get(member.pool_id).ok_or(Error::<T>::DefensiveError)?;
+		let reward_pool =
+			RewardPools::<T>::get(member.pool_id).ok_or(Error::<T>::DefensiveError)?;
+		Ok((member, bonded_pool, reward_pool))
+	}
+
+	/// Persist the member with their associated bonded and reward pool into storage, consuming
+	/// all of them.
+	fn put_member_with_pools(
+		member_account: &T::AccountId,
+		member: PoolMember<T>,
+		bonded_pool: BondedPool<T>,
+		reward_pool: RewardPool<T>,
+	) {
+		bonded_pool.put();
+		RewardPools::<T>::insert(member.pool_id, reward_pool);
+		PoolMembers::<T>::insert(member_account, member);
+	}
+
+	/// Calculate the equivalent point of `new_funds` in a pool with `current_balance` and
+	/// `current_points`.
+	fn balance_to_point(
+		current_balance: BalanceOf<T>,
+		current_points: BalanceOf<T>,
+		new_funds: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		match (current_balance.is_zero(), current_points.is_zero()) {
+			(_, true) => new_funds.saturating_mul(POINTS_TO_BALANCE_INIT_RATIO.into()),
+			(true, false) => {
+				// The pool was totally slashed.
+				// This is the equivalent of `(current_points / 1) * new_funds`.
+				new_funds.saturating_mul(current_points)
+			},
+			(false, false) => {
+				// Equivalent to (current_points / current_balance) * new_funds
+				multiply_by_rational(
+					current_points.saturated_into(),
+					new_funds.saturated_into(),
+					current_balance.saturated_into(),
+				)
+				.map(|points| points.saturated_into())
+				// If this happens the pool is in an invalid state, so we just dissolve it
+				.unwrap_or_else(|_| Zero::zero())
+			},
+		}
+	}
+
+	/// Calculate the equivalent balance of `points` in a pool with `current_balance` and
+	/// `current_points`.
+	fn point_to_balance(
+		current_balance: BalanceOf<T>,
+		current_points: BalanceOf<T>,
+		points: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		if current_balance.is_zero() || current_points.is_zero() || points.is_zero() {
+			// There is nothing to unbond
+			return Zero::zero()
+		}
+
+		// Equivalent of (current_balance / current_points) * points
+		multiply_by_rational(
+			current_balance.saturated_into(),
+			points.saturated_into(),
+			current_points.saturated_into(),
+		)
+		.map(|balance| balance.saturated_into())
+		.unwrap_or_else(|_| Zero::zero())
+	}
+
+	/// If the member has some rewards, transfer a payout from the reward pool to the member.
+	///
+	/// Returns the amount that was paid out.
+	// Emits events and potentially modifies pool state if any arithmetic saturates, but does
+	// not persist any of the mutable inputs to storage.
+	fn do_reward_payout(
+		member_account: &T::AccountId,
+		member: &mut PoolMember<T>,
+		bonded_pool: &mut BondedPool<T>,
+		reward_pool: &mut RewardPool<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		ensure!(!member.active_points().is_zero(), Error::<T>::FullyUnbonding);
+
+		let current_reward_counter =
+			reward_pool.current_reward_counter(bonded_pool.id, bonded_pool.points)?;
+		let pending_rewards = member.pending_rewards(current_reward_counter)?;
+
+		// record the current reward counter regardless of there being any rewards, since the
+		// counter may have grown by an amount smaller than one unit of balance per member point.
+		member.last_recorded_reward_counter = current_reward_counter;
+		if pending_rewards.is_zero() {
+			return Ok(pending_rewards)
+		}
+
+		reward_pool.register_claimed_reward(pending_rewards);
+
+		// Transfer payout to the member.
+		T::Currency::transfer(
+			&bonded_pool.reward_account(),
+			member_account,
+			pending_rewards,
+			ExistenceRequirement::KeepAlive,
+		)?;
+
+		Self::deposit_event(Event::<T>::PaidOut {
+			member: member_account.clone(),
+			pool_id: member.pool_id,
+			payout: pending_rewards,
+		});
+
+		Ok(pending_rewards)
+	}
+}
+
+impl<T: Config> OnStakerSlash<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn on_slash(
+		pool_account: &T::AccountId,
+		// Bonded balance is always read directly from staking, therefore we need not update
+		// anything here.
+		slashed_bonded: BalanceOf<T>,
+		slashed_unlocking: &BTreeMap<EraIndex, BalanceOf<T>>,
+	) {
+		if let Some(pool_id) = ReversePoolIdLookup::<T>::get(pool_account) {
+			let mut sub_pools = match SubPoolsStorage::<T>::get(pool_id) {
+				Some(sub_pools) => sub_pools,
+				None => {
+					Self::deposit_event(Event::<T>::PoolSlashed {
+						pool_id,
+						balance: slashed_bonded,
+					});
+					return
+				},
+			};
+			for (era, slashed_balance) in slashed_unlocking.iter() {
+				if let Some(pool) = sub_pools.with_era.get_mut(era) {
+					pool.balance = *slashed_balance;
+					Self::deposit_event(Event::<T>::UnbondingPoolSlashed {
+						era: *era,
+						pool_id,
+						balance: *slashed_balance,
+					});
+				}
+			}
+
+			Self::deposit_event(Event::<T>::PoolSlashed { pool_id, balance: slashed_bonded });
+			SubPoolsStorage::<T>::insert(pool_id, sub_pools);
+		}
+	}
+}
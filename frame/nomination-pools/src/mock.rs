@@ -0,0 +1,304 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{self as pools};
+use frame_support::{assert_ok, parameter_types, PalletId};
+use frame_system::RawOrigin;
+use sp_runtime::DispatchResult;
+
+pub type AccountId = u128;
+pub type Balance = u128;
+
+parameter_types! {
+	pub static CurrentEra: EraIndex = 0;
+	pub static BondingDuration: EraIndex = 3;
+	pub static MinimumBond: Balance = 10;
+	pub static BondedBalanceMap: BTreeMap<AccountId, Balance> = Default::default();
+	pub static UnbondingBalanceMap: BTreeMap<AccountId, Vec<(EraIndex, Balance)>> = Default::default();
+	pub static Nominations: BTreeMap<AccountId, Vec<AccountId>> = Default::default();
+}
+
+pub struct StakingMock;
+impl StakingMock {
+	pub(crate) fn set_bonded_balance(who: AccountId, bonded: Balance) {
+		BONDED_BALANCE_MAP.with(|m| m.borrow_mut().insert(who, bonded));
+	}
+}
+
+impl sp_staking::StakingInterface for StakingMock {
+	type Balance = Balance;
+	type AccountId = AccountId;
+
+	fn minimum_bond() -> Self::Balance {
+		MinimumBond::get()
+	}
+
+	fn current_era() -> EraIndex {
+		CurrentEra::get()
+	}
+
+	fn bonding_duration() -> EraIndex {
+		BondingDuration::get()
+	}
+
+	fn active_stake(who: &Self::AccountId) -> Option<Self::Balance> {
+		BondedBalanceMap::get().get(who).map(|v| *v)
+	}
+
+	fn total_stake(who: &Self::AccountId) -> Option<Self::Balance> {
+		let unbonding: Balance = UnbondingBalanceMap::get()
+			.get(who)
+			.map(|chunks| chunks.iter().map(|(_, v)| *v).sum())
+			.unwrap_or_default();
+		match (Self::active_stake(who), unbonding) {
+			(None, 0) => None,
+			(active, unbonding) => Some(active.unwrap_or_default() + unbonding),
+		}
+	}
+
+	fn bond_extra(who: Self::AccountId, extra: Self::Balance) -> DispatchResult {
+		BONDED_BALANCE_MAP.with(|m| *m.borrow_mut().get_mut(&who).unwrap() += extra);
+		Ok(())
+	}
+
+	fn unbond(who: Self::AccountId, amount: Self::Balance) -> DispatchResult {
+		BONDED_BALANCE_MAP.with(|m| *m.borrow_mut().get_mut(&who).unwrap() -= amount);
+		UNBONDING_BALANCE_MAP.with(|m| {
+			m.borrow_mut()
+				.entry(who)
+				.or_insert_with(Vec::new)
+				.push((CurrentEra::get() + BondingDuration::get(), amount))
+		});
+		Ok(())
+	}
+
+	fn chill(who: Self::AccountId) -> DispatchResult {
+		NOMINATIONS.with(|m| m.borrow_mut().remove(&who));
+		Ok(())
+	}
+
+	fn withdraw_unbonded(who: Self::AccountId, _: u32) -> Result<bool, DispatchError> {
+		let current_era = CurrentEra::get();
+		UNBONDING_BALANCE_MAP.with(|m| {
+			if let Some(chunks) = m.borrow_mut().get_mut(&who) {
+				chunks.retain(|(era, _)| *era > current_era);
+			}
+		});
+		Ok(Self::total_stake(&who).map_or(true, |total| total.is_zero()))
+	}
+
+	fn bond(
+		stash: Self::AccountId,
+		_: Self::AccountId,
+		value: Self::Balance,
+		_: Self::AccountId,
+	) -> DispatchResult {
+		StakingMock::set_bonded_balance(stash, value);
+		Ok(())
+	}
+
+	fn nominate(who: Self::AccountId, targets: Vec<Self::AccountId>) -> DispatchResult {
+		frame_support::ensure!(
+			BondedBalanceMap::get().contains_key(&who),
+			DispatchError::Other("not bonded")
+		);
+		NOMINATIONS.with(|m| m.borrow_mut().insert(who, targets));
+		Ok(())
+	}
+
+	fn stash_by_ctrl(controller: &Self::AccountId) -> Result<Self::AccountId, DispatchError> {
+		Ok(*controller)
+	}
+
+	fn force_unstake(_: Self::AccountId) -> DispatchResult {
+		unimplemented!("method currently not used in testing")
+	}
+
+	fn is_exposed_in_era(_: &Self::AccountId, _: &EraIndex) -> bool {
+		unimplemented!("method currently not used in testing")
+	}
+}
+
+impl frame_system::Config for Runtime {
+	type SS58Prefix = ();
+	type BaseCallFilter = frame_support::traits::Everything;
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Call = Call;
+	type Hash = sp_core::H256;
+	type Hashing = sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+	type Header = sp_runtime::testing::Header;
+	type Event = Event;
+	type BlockHashCount = ();
+	type DbWeight = ();
+	type BlockLength = ();
+	type BlockWeights = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type OnSetCode = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 5;
+}
+
+impl pallet_balances::Config for Runtime {
+	type MaxLocks = frame_support::traits::ConstU32<1024>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = Balance;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub static PostUnbondingPoolsWindow: u32 = 2;
+	pub const PoolsPalletId: PalletId = PalletId(*b"py/nopls");
+	pub const MaxUnbonding: u32 = 8;
+}
+
+impl pools::Config for Runtime {
+	type Event = Event;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type PalletId = PoolsPalletId;
+	type StakingInterface = StakingMock;
+	type PostUnbondingPoolsWindow = PostUnbondingPoolsWindow;
+	type MaxUnbonding = MaxUnbonding;
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+frame_support::construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Storage, Event<T>, Config},
+		Balances: pallet_balances::{Pallet, Call, Storage, Event<T>, Config<T>},
+		Pools: pools::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+#[derive(Default)]
+pub struct ExtBuilder {
+	members: Vec<(AccountId, Balance)>,
+}
+
+impl ExtBuilder {
+	// Add members to pool 1.
+	pub(crate) fn add_members(mut self, members: Vec<(AccountId, Balance)>) -> Self {
+		self.members = members;
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut storage =
+			frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+
+		let _ = crate::GenesisConfig::<Runtime> {
+			min_join_bond: 2,
+			min_create_bond: 2,
+			max_pools: Some(2),
+			max_members_per_pool: Some(3),
+			max_members: Some(4),
+		}
+		.assimilate_storage(&mut storage);
+
+		let mut ext = sp_io::TestExternalities::from(storage);
+
+		ext.execute_with(|| {
+			// for events to be deposited.
+			frame_system::Pallet::<Runtime>::set_block_number(1);
+
+			// make a pool
+			let amount_to_bond = Pools::depositor_min_bond();
+			Balances::make_free_balance_be(&10, amount_to_bond * 5);
+			assert_ok!(Pools::create(RawOrigin::Signed(10).into(), amount_to_bond, 900, 901, 902));
+
+			let last_pool = LastPoolId::<Runtime>::get();
+			for (account_id, bonded) in self.members {
+				Balances::make_free_balance_be(&account_id, bonded * 2);
+				assert_ok!(Pools::join(RawOrigin::Signed(account_id).into(), bonded, last_pool));
+			}
+		});
+
+		ext
+	}
+
+	pub fn build_and_execute(self, test: impl FnOnce() -> ()) {
+		self.build().execute_with(|| {
+			test();
+			post_checks();
+		})
+	}
+}
+
+pub(crate) fn unsafe_set_state(pool_id: PoolId, state: PoolState) -> Result<(), ()> {
+	BondedPools::<Runtime>::try_mutate(pool_id, |maybe_bonded_pool| {
+		maybe_bonded_pool.as_mut().ok_or(()).map(|bonded_pool| {
+			bonded_pool.state = state;
+		})
+	})
+}
+
+/// Sanity checks over the storage of this pallet, run after each test.
+fn post_checks() {
+	assert!(Pallet::<Runtime>::depositor_min_bond() >= MinimumBond::get());
+	assert_eq!(RewardPools::<Runtime>::count(), BondedPools::<Runtime>::count());
+	assert!(SubPoolsStorage::<Runtime>::count() <= BondedPools::<Runtime>::count());
+	assert_eq!(ReversePoolIdLookup::<Runtime>::count(), BondedPools::<Runtime>::count());
+
+	let mut pools_members = BTreeMap::<PoolId, u32>::new();
+	let mut pools_points = BTreeMap::<PoolId, Balance>::new();
+	PoolMembers::<Runtime>::iter().for_each(|(_, member)| {
+		assert!(BondedPools::<Runtime>::contains_key(member.pool_id));
+		*pools_members.entry(member.pool_id).or_default() += 1;
+		*pools_points.entry(member.pool_id).or_default() += member.points;
+	});
+	BondedPools::<Runtime>::iter().for_each(|(id, inner)| {
+		assert_eq!(pools_members.get(&id).copied().unwrap_or_default(), inner.member_counter);
+		assert_eq!(pools_points.get(&id).copied().unwrap_or_default(), inner.points);
+	});
+}
+
+pub(crate) fn pool_events_since_last_call() -> Vec<super::Event<Runtime>> {
+	let events = System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| if let Event::Pools(inner) = e { Some(inner) } else { None })
+		.collect::<Vec<_>>();
+	let already_seen = PoolsEvents::get();
+	PoolsEvents::set(&(events.len() as u32));
+	events.into_iter().skip(already_seen as usize).collect()
+}
+
+parameter_types! {
+	storage PoolsEvents: u32 = 0;
+}
@@ -0,0 +1,826 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{mock::*, Event};
+use frame_support::{assert_noop, assert_ok};
+
+macro_rules! unbonding_pools_with_era {
+	($($k:expr => $v:expr),* $(,)?) => {{
+		use sp_std::iter::{Iterator, IntoIterator};
+		let not_bounded: BTreeMap<_, _> = Iterator::collect(IntoIterator::into_iter([$(($k, $v),)*]));
+		BoundedBTreeMap::<EraIndex, UnbondPool<Runtime>, TotalUnbondingPools<Runtime>>::try_from(
+			not_bounded,
+		)
+		.unwrap()
+	}};
+}
+
+macro_rules! member_unbonding_eras {
+	($( $any:tt )*) => {{
+		let x: BoundedBTreeMap<EraIndex, Balance, MaxUnbonding> = bounded_btree_map!($( $any )*);
+		x
+	}};
+}
+
+macro_rules! bounded_btree_map {
+	($($k:expr => $v:expr),* $(,)?) => {{
+		use sp_std::iter::{Iterator, IntoIterator};
+		let not_bounded: BTreeMap<_, _> = Iterator::collect(IntoIterator::into_iter([$(($k, $v),)*]));
+		BoundedBTreeMap::try_from(not_bounded).unwrap()
+	}};
+}
+
+fn default_bonded_account() -> AccountId {
+	Pools::create_bonded_account(1)
+}
+
+fn default_reward_account() -> AccountId {
+	Pools::create_reward_account(1)
+}
+
+/// Deposit `amount` of rewards into the reward account of the default pool.
+fn deposit_rewards(amount: Balance) {
+	let reward_account = default_reward_account();
+	let balance = Balances::free_balance(&reward_account);
+	Balances::make_free_balance_be(&reward_account, balance + amount);
+}
+
+mod create {
+	use super::*;
+
+	#[test]
+	fn create_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// the pool of the builder
+			assert_eq!(LastPoolId::<Runtime>::get(), 1);
+			assert_eq!(
+				BondedPool::<Runtime>::get(1).unwrap(),
+				BondedPool {
+					id: 1,
+					inner: BondedPoolInner {
+						points: 10,
+						state: PoolState::Open,
+						member_counter: 1,
+						roles: PoolRoles {
+							depositor: 10,
+							root: 900,
+							nominator: 901,
+							state_toggler: 902,
+						},
+					},
+				}
+			);
+			assert_eq!(
+				PoolMembers::<Runtime>::get(10).unwrap(),
+				PoolMember::<Runtime> { pool_id: 1, points: 10, ..Default::default() }
+			);
+			assert_eq!(StakingMock::active_stake(&default_bonded_account()), Some(10));
+			assert_eq!(ReversePoolIdLookup::<Runtime>::get(default_bonded_account()), Some(1));
+			// the reward account is funded with the existential deposit.
+			assert_eq!(Balances::free_balance(&default_reward_account()), ExistentialDeposit::get());
+			assert_eq!(Balances::free_balance(&10), 50 - 10 - ExistentialDeposit::get());
+
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::Created { depositor: 10, pool_id: 1 },
+					Event::Bonded { member: 10, pool_id: 1, bonded: 10, joined: true },
+				]
+			);
+
+			// when
+			Balances::make_free_balance_be(&11, 100);
+			assert_ok!(Pools::create(Origin::signed(11), 20, 11, 11, 11));
+
+			// then
+			assert_eq!(LastPoolId::<Runtime>::get(), 2);
+			assert_eq!(BondedPool::<Runtime>::get(2).unwrap().points, 20);
+			assert_eq!(StakingMock::active_stake(&Pools::create_bonded_account(2)), Some(20));
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::Created { depositor: 11, pool_id: 2 },
+					Event::Bonded { member: 11, pool_id: 2, bonded: 20, joined: true },
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn create_errors_correctly() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_noop!(
+				Pools::create(Origin::signed(10), 20, 123, 456, 789),
+				Error::<Runtime>::AccountBelongsToOtherPool
+			);
+
+			Balances::make_free_balance_be(&11, 100);
+			// the staking minimum bond is the highest of the minimums.
+			assert_eq!(Pools::depositor_min_bond(), 10);
+			assert_noop!(
+				Pools::create(Origin::signed(11), 9, 123, 456, 789),
+				Error::<Runtime>::MinimumBondNotMet
+			);
+
+			// the system is limited to two pools.
+			assert_ok!(Pools::create(Origin::signed(11), 10, 11, 11, 11));
+			Balances::make_free_balance_be(&12, 100);
+			assert_noop!(
+				Pools::create(Origin::signed(12), 10, 12, 12, 12),
+				Error::<Runtime>::MaxPools
+			);
+		});
+	}
+}
+
+mod join {
+	use super::*;
+
+	#[test]
+	fn join_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			let _ = pool_events_since_last_call();
+			Balances::make_free_balance_be(&11, ExistentialDeposit::get() + 2);
+
+			// when
+			assert_ok!(Pools::join(Origin::signed(11), 2, 1));
+
+			// then
+			assert_eq!(
+				PoolMembers::<Runtime>::get(&11).unwrap(),
+				PoolMember::<Runtime> { pool_id: 1, points: 2, ..Default::default() }
+			);
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().points, 12);
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().member_counter, 2);
+			assert_eq!(StakingMock::active_stake(&default_bonded_account()), Some(12));
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![Event::Bonded { member: 11, pool_id: 1, bonded: 2, joined: true }]
+			);
+
+			// given the pool's active stake is slashed by half
+			StakingMock::set_bonded_balance(default_bonded_account(), 6);
+			Balances::make_free_balance_be(&12, ExistentialDeposit::get() + 12);
+
+			// when
+			assert_ok!(Pools::join(Origin::signed(12), 12, 1));
+
+			// then the new member is issued two points per unit of balance.
+			assert_eq!(PoolMembers::<Runtime>::get(&12).unwrap().points, 24);
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().points, 36);
+		});
+	}
+
+	#[test]
+	fn join_errors_correctly() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			assert_noop!(
+				Pools::join(Origin::signed(10), 420, 1),
+				Error::<Runtime>::AccountBelongsToOtherPool
+			);
+
+			assert_noop!(Pools::join(Origin::signed(11), 420, 123), Error::<Runtime>::PoolNotFound);
+
+			assert_noop!(
+				Pools::join(Origin::signed(11), 1, 1),
+				Error::<Runtime>::MinimumBondNotMet
+			);
+
+			// a blocked pool can not be joined.
+			unsafe_set_state(1, PoolState::Blocked).unwrap();
+			assert_noop!(Pools::join(Origin::signed(11), 10, 1), Error::<Runtime>::NotOpen);
+
+			// neither can a destroying one.
+			unsafe_set_state(1, PoolState::Destroying).unwrap();
+			assert_noop!(Pools::join(Origin::signed(11), 10, 1), Error::<Runtime>::NotOpen);
+
+			// a pool whose stake has been slashed away cannot be joined either.
+			unsafe_set_state(1, PoolState::Open).unwrap();
+			StakingMock::set_bonded_balance(default_bonded_account(), 0);
+			assert_noop!(Pools::join(Origin::signed(11), 10, 1), Error::<Runtime>::OverflowRisk);
+		});
+	}
+
+	#[test]
+	fn join_respects_member_limits() {
+		ExtBuilder::default().add_members(vec![(20, 20), (30, 30)]).build_and_execute(|| {
+			// the pool is limited to 3 members.
+			Balances::make_free_balance_be(&40, 100);
+			assert_noop!(Pools::join(Origin::signed(40), 10, 1), Error::<Runtime>::MaxPoolMembers);
+
+			// the system is limited to 4 members, one of which is in a second pool.
+			Balances::make_free_balance_be(&11, 100);
+			assert_ok!(Pools::create(Origin::signed(11), 10, 11, 11, 11));
+			assert_noop!(Pools::join(Origin::signed(40), 10, 2), Error::<Runtime>::MaxPoolMembers);
+
+			// unless the limits are lifted.
+			assert_ok!(Pools::set_configs(Origin::root(), 2, 2, Some(2), None, None));
+			assert_ok!(Pools::join(Origin::signed(40), 10, 1));
+		});
+	}
+}
+
+mod claim_payout {
+	use super::*;
+
+	#[test]
+	fn claim_payout_works() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			let _ = pool_events_since_last_call();
+			let (depositor_balance, member_balance) =
+				(Balances::free_balance(&10), Balances::free_balance(&40));
+
+			// given
+			deposit_rewards(100);
+			assert_eq!(Pools::pending_rewards(10), 20);
+			assert_eq!(Pools::pending_rewards(40), 80);
+
+			// when
+			assert_ok!(Pools::claim_payout(Origin::signed(10)));
+
+			// then
+			assert_eq!(Balances::free_balance(&10), depositor_balance + 20);
+			assert_eq!(Pools::pending_rewards(10), 0);
+			assert_eq!(Pools::pending_rewards(40), 80);
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![Event::PaidOut { member: 10, pool_id: 1, payout: 20 }]
+			);
+
+			// when
+			assert_ok!(Pools::claim_payout(Origin::signed(40)));
+
+			// then
+			assert_eq!(Balances::free_balance(&40), member_balance + 80);
+			assert_eq!(
+				RewardPools::<Runtime>::get(1).unwrap().total_rewards_claimed,
+				100
+			);
+			assert_eq!(
+				Balances::free_balance(&default_reward_account()),
+				ExistentialDeposit::get()
+			);
+
+			// claiming again is a no-op.
+			assert_ok!(Pools::claim_payout(Origin::signed(40)));
+			assert_eq!(Balances::free_balance(&40), member_balance + 80);
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![Event::PaidOut { member: 40, pool_id: 1, payout: 80 }]
+			);
+
+			assert_noop!(
+				Pools::claim_payout(Origin::signed(11)),
+				Error::<Runtime>::PoolMemberNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn rewards_are_not_shared_with_later_members() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given rewards that arrive while the depositor is the only member
+			deposit_rewards(100);
+
+			// when a new member joins
+			Balances::make_free_balance_be(&40, 100);
+			assert_ok!(Pools::join(Origin::signed(40), 40, 1));
+
+			// then they are not entitled to any of the existing rewards
+			assert_eq!(Pools::pending_rewards(10), 100);
+			assert_eq!(Pools::pending_rewards(40), 0);
+
+			// but they are to any that arrive afterwards
+			deposit_rewards(50);
+			assert_eq!(Pools::pending_rewards(10), 110);
+			assert_eq!(Pools::pending_rewards(40), 40);
+
+			assert_ok!(Pools::claim_payout(Origin::signed(10)));
+			assert_ok!(Pools::claim_payout(Origin::signed(40)));
+			assert_eq!(
+				Balances::free_balance(&default_reward_account()),
+				ExistentialDeposit::get()
+			);
+		});
+	}
+
+	#[test]
+	fn bond_extra_from_rewards_works() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			let _ = pool_events_since_last_call();
+			let member_balance = Balances::free_balance(&40);
+
+			// given
+			deposit_rewards(100);
+
+			// when
+			assert_ok!(Pools::bond_extra(Origin::signed(40), BondExtra::Rewards));
+
+			// then the rewards are bonded, not transferred.
+			assert_eq!(Balances::free_balance(&40), member_balance);
+			assert_eq!(PoolMembers::<Runtime>::get(40).unwrap().points, 120);
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().points, 130);
+			assert_eq!(StakingMock::active_stake(&default_bonded_account()), Some(130));
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::PaidOut { member: 40, pool_id: 1, payout: 80 },
+					Event::Bonded { member: 40, pool_id: 1, bonded: 80, joined: false },
+				]
+			);
+
+			// and the depositor's rewards are untouched.
+			assert_eq!(Pools::pending_rewards(10), 20);
+		});
+	}
+
+	#[test]
+	fn bond_extra_from_free_balance_works() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			let member_balance = Balances::free_balance(&40);
+			deposit_rewards(100);
+
+			// when
+			assert_ok!(Pools::bond_extra(Origin::signed(40), BondExtra::FreeBalance(10)));
+
+			// then the pending rewards are paid out as well.
+			assert_eq!(Balances::free_balance(&40), member_balance - 10 + 80);
+			assert_eq!(PoolMembers::<Runtime>::get(40).unwrap().points, 50);
+			assert_eq!(StakingMock::active_stake(&default_bonded_account()), Some(60));
+			assert_eq!(Pools::pending_rewards(40), 0);
+			assert_eq!(Pools::pending_rewards(10), 20);
+		});
+	}
+}
+
+mod unbond {
+	use super::*;
+
+	#[test]
+	fn unbond_works() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			let _ = pool_events_since_last_call();
+
+			// when
+			assert_ok!(Pools::unbond(Origin::signed(40), 40, 15));
+
+			// then
+			assert_eq!(
+				SubPoolsStorage::<Runtime>::get(1).unwrap().with_era,
+				unbonding_pools_with_era! { 3 => UnbondPool { points: 15, balance: 15 } }
+			);
+			let member = PoolMembers::<Runtime>::get(40).unwrap();
+			assert_eq!(member.points, 25);
+			assert_eq!(member.unbonding_eras, member_unbonding_eras!(3 => 15));
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().points, 35);
+			assert_eq!(StakingMock::active_stake(&default_bonded_account()), Some(35));
+			assert_eq!(StakingMock::total_stake(&default_bonded_account()), Some(50));
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![Event::Unbonded { member: 40, pool_id: 1, balance: 15, points: 15, era: 3 }]
+			);
+
+			// when unbonding the rest in a later era
+			CurrentEra::set(1);
+			assert_ok!(Pools::unbond(Origin::signed(40), 40, 25));
+
+			// then
+			assert_eq!(
+				SubPoolsStorage::<Runtime>::get(1).unwrap().with_era,
+				unbonding_pools_with_era! {
+					3 => UnbondPool { points: 15, balance: 15 },
+					4 => UnbondPool { points: 25, balance: 25 },
+				}
+			);
+			let member = PoolMembers::<Runtime>::get(40).unwrap();
+			assert_eq!(member.points, 0);
+			assert_eq!(member.unbonding_eras, member_unbonding_eras!(3 => 15, 4 => 25));
+
+			// and nothing is left to unbond.
+			assert_noop!(
+				Pools::unbond(Origin::signed(40), 40, 1),
+				Error::<Runtime>::NotEnoughPointsToUnbond
+			);
+			assert_noop!(Pools::claim_payout(Origin::signed(40)), Error::<Runtime>::FullyUnbonding);
+		});
+	}
+
+	#[test]
+	fn unbond_claims_pending_rewards() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			let member_balance = Balances::free_balance(&40);
+			deposit_rewards(100);
+
+			// when
+			assert_ok!(Pools::unbond(Origin::signed(40), 40, 40));
+
+			// then
+			assert_eq!(Balances::free_balance(&40), member_balance + 80);
+
+			// and further rewards go to the remaining points only.
+			deposit_rewards(10);
+			assert_eq!(Pools::pending_rewards(10), 30);
+		});
+	}
+
+	#[test]
+	fn unbond_permissions_are_respected() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			// a member cannot go below the minimum join bond with a partial unbond.
+			assert_ok!(Pools::set_configs(Origin::root(), 30, 2, Some(2), Some(4), Some(3)));
+			assert_noop!(
+				Pools::unbond(Origin::signed(40), 40, 20),
+				Error::<Runtime>::MinimumBondNotMet
+			);
+
+			// nobody else can unbond a member of an open pool.
+			assert_noop!(
+				Pools::unbond(Origin::signed(902), 40, 40),
+				Error::<Runtime>::NotKickerOrDestroying
+			);
+
+			// the depositor cannot unbond below the depositor minimum bond.
+			assert_noop!(
+				Pools::unbond(Origin::signed(10), 10, 5),
+				Error::<Runtime>::MinimumBondNotMet
+			);
+			// and can only fully unbond when the pool is destroying.
+			assert_noop!(
+				Pools::unbond(Origin::signed(10), 10, 10),
+				Error::<Runtime>::NotDestroying
+			);
+			unsafe_set_state(1, PoolState::Destroying).unwrap();
+			assert_noop!(
+				Pools::unbond(Origin::signed(10), 10, 10),
+				Error::<Runtime>::NotOnlyPoolMember
+			);
+			// and can never be unbonded by someone else.
+			assert_noop!(
+				Pools::unbond(Origin::signed(40), 10, 10),
+				Error::<Runtime>::DoesNotHavePermission
+			);
+
+			// while anyone can unbond the other members of a destroying pool.
+			assert_ok!(Pools::unbond(Origin::signed(420), 40, 40));
+		});
+	}
+
+	#[test]
+	fn kicking_works() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			// the state toggler cannot kick from an open pool.
+			assert_noop!(
+				Pools::unbond(Origin::signed(902), 40, 40),
+				Error::<Runtime>::NotKickerOrDestroying
+			);
+
+			// given
+			assert_ok!(Pools::set_state(Origin::signed(902), 1, PoolState::Blocked));
+
+			// a partial kick is not possible.
+			assert_noop!(
+				Pools::unbond(Origin::signed(902), 40, 20),
+				Error::<Runtime>::MinimumBondNotMet
+			);
+			// nor is a kick by a non-admin.
+			assert_noop!(
+				Pools::unbond(Origin::signed(420), 40, 40),
+				Error::<Runtime>::NotKickerOrDestroying
+			);
+
+			// when
+			assert_ok!(Pools::unbond(Origin::signed(902), 40, 40));
+
+			// then
+			assert_eq!(PoolMembers::<Runtime>::get(40).unwrap().points, 0);
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().points, 10);
+		});
+	}
+}
+
+mod withdraw_unbonded {
+	use super::*;
+
+	#[test]
+	fn withdraw_unbonded_works() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			let member_balance = Balances::free_balance(&40);
+			assert_ok!(Pools::unbond(Origin::signed(40), 40, 40));
+			let _ = pool_events_since_last_call();
+
+			// the funds cannot be withdrawn before the bonding duration has passed.
+			CurrentEra::set(2);
+			assert_noop!(
+				Pools::withdraw_unbonded(Origin::signed(40), 40, 0),
+				Error::<Runtime>::CannotWithdrawAny
+			);
+
+			// when
+			CurrentEra::set(3);
+			assert_ok!(Pools::withdraw_unbonded(Origin::signed(40), 40, 0));
+
+			// then
+			assert_eq!(Balances::free_balance(&40), member_balance + 40);
+			assert!(!PoolMembers::<Runtime>::contains_key(40));
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().member_counter, 1);
+			assert!(SubPoolsStorage::<Runtime>::get(1).unwrap().with_era.is_empty());
+			assert_eq!(StakingMock::total_stake(&default_bonded_account()), Some(10));
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::Withdrawn { member: 40, pool_id: 1, balance: 40, points: 40 },
+					Event::MemberRemoved { pool_id: 1, member: 40 },
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn partial_withdraw_unbonded_works() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			let member_balance = Balances::free_balance(&40);
+			assert_ok!(Pools::unbond(Origin::signed(40), 40, 10));
+			CurrentEra::set(1);
+			assert_ok!(Pools::unbond(Origin::signed(40), 40, 10));
+
+			// when
+			CurrentEra::set(3);
+			assert_ok!(Pools::withdraw_unbonded(Origin::signed(40), 40, 0));
+
+			// then only the first chunk is withdrawn.
+			assert_eq!(Balances::free_balance(&40), member_balance + 10);
+			let member = PoolMembers::<Runtime>::get(40).unwrap();
+			assert_eq!(member.points, 20);
+			assert_eq!(member.unbonding_eras, member_unbonding_eras!(4 => 10));
+			assert_eq!(
+				SubPoolsStorage::<Runtime>::get(1).unwrap().with_era,
+				unbonding_pools_with_era! { 4 => UnbondPool { points: 10, balance: 10 } }
+			);
+		});
+	}
+
+	#[test]
+	fn withdraw_unbonded_permissions_are_respected() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			assert_ok!(Pools::unbond(Origin::signed(40), 40, 40));
+			CurrentEra::set(3);
+
+			assert_noop!(
+				Pools::withdraw_unbonded(Origin::signed(420), 40, 0),
+				Error::<Runtime>::NotKickerOrDestroying
+			);
+			assert_noop!(
+				Pools::withdraw_unbonded(Origin::signed(420), 420, 0),
+				Error::<Runtime>::PoolMemberNotFound
+			);
+
+			// anyone can withdraw on behalf of the members of a destroying pool.
+			unsafe_set_state(1, PoolState::Destroying).unwrap();
+			assert_ok!(Pools::withdraw_unbonded(Origin::signed(420), 40, 0));
+		});
+	}
+
+	#[test]
+	fn depositor_leaving_destroys_the_pool() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			let depositor_balance = Balances::free_balance(&10);
+			assert_ok!(Pools::nominate(Origin::signed(901), 1, vec![21]));
+			assert_ok!(Pools::set_state(Origin::signed(900), 1, PoolState::Destroying));
+
+			// the remaining member is removed permissionlessly.
+			assert_ok!(Pools::unbond(Origin::signed(420), 40, 40));
+			CurrentEra::set(3);
+			assert_ok!(Pools::withdraw_unbonded(Origin::signed(420), 40, 0));
+
+			// when the depositor leaves
+			assert_ok!(Pools::unbond(Origin::signed(10), 10, 10));
+			// the pool stops nominating.
+			assert!(!Nominations::get().contains_key(&default_bonded_account()));
+			CurrentEra::set(6);
+			let _ = pool_events_since_last_call();
+			assert_ok!(Pools::withdraw_unbonded(Origin::signed(10), 10, 0));
+
+			// then the pool is gone
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::Withdrawn { member: 10, pool_id: 1, balance: 10, points: 10 },
+					Event::MemberRemoved { pool_id: 1, member: 10 },
+					Event::Destroyed { pool_id: 1 },
+				]
+			);
+			assert!(!BondedPools::<Runtime>::contains_key(1));
+			assert!(!RewardPools::<Runtime>::contains_key(1));
+			assert!(!SubPoolsStorage::<Runtime>::contains_key(1));
+			assert!(!PoolMembers::<Runtime>::contains_key(10));
+			assert_eq!(ReversePoolIdLookup::<Runtime>::get(default_bonded_account()), None);
+
+			// and the depositor got back their bond and the existential deposit of the reward
+			// account.
+			assert_eq!(
+				Balances::free_balance(&10),
+				depositor_balance + 10 + ExistentialDeposit::get()
+			);
+			assert_eq!(Balances::free_balance(&default_bonded_account()), 0);
+			assert_eq!(Balances::free_balance(&default_reward_account()), 0);
+		});
+	}
+
+	#[test]
+	fn old_sub_pools_are_merged() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			let member_balance = Balances::free_balance(&40);
+
+			// given a chunk unlocking in era 3
+			assert_ok!(Pools::unbond(Origin::signed(40), 40, 10));
+
+			// when unbonding once era 3 is out of the post unbonding window
+			CurrentEra::set(5);
+			assert_ok!(Pools::unbond(Origin::signed(40), 40, 10));
+
+			// then the era 3 pool is merged into the era-less pool.
+			let sub_pools = SubPoolsStorage::<Runtime>::get(1).unwrap();
+			assert_eq!(sub_pools.no_era, UnbondPool { points: 10, balance: 10 });
+			assert_eq!(
+				sub_pools.with_era,
+				unbonding_pools_with_era! { 8 => UnbondPool { points: 10, balance: 10 } }
+			);
+
+			// and the member can still withdraw from it.
+			assert_ok!(Pools::withdraw_unbonded(Origin::signed(40), 40, 0));
+			assert_eq!(Balances::free_balance(&40), member_balance + 10);
+			assert_eq!(
+				SubPoolsStorage::<Runtime>::get(1).unwrap().no_era,
+				UnbondPool { points: 0, balance: 0 }
+			);
+		});
+	}
+}
+
+mod slash {
+	use super::*;
+
+	#[test]
+	fn slash_is_shared_by_unbonding_members() {
+		ExtBuilder::default().add_members(vec![(20, 20), (40, 40)]).build_and_execute(|| {
+			let (balance_20, balance_40) = (Balances::free_balance(&20), Balances::free_balance(&40));
+			assert_ok!(Pools::unbond(Origin::signed(20), 20, 20));
+			assert_ok!(Pools::unbond(Origin::signed(40), 40, 40));
+			let _ = pool_events_since_last_call();
+
+			// given a slash of half of the unlocking funds
+			let bonded = default_bonded_account();
+			UnbondingBalanceMap::set(vec![(bonded, vec![(3, 30)])].into_iter().collect());
+			Balances::make_free_balance_be(&bonded, 40);
+
+			// when
+			Pools::on_slash(&bonded, 10, &vec![(3, 30)].into_iter().collect());
+
+			// then
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::UnbondingPoolSlashed { pool_id: 1, era: 3, balance: 30 },
+					Event::PoolSlashed { pool_id: 1, balance: 10 },
+				]
+			);
+			assert_eq!(
+				SubPoolsStorage::<Runtime>::get(1).unwrap().with_era,
+				unbonding_pools_with_era! { 3 => UnbondPool { points: 60, balance: 30 } }
+			);
+
+			// and each member loses half of their unbonding funds.
+			CurrentEra::set(3);
+			assert_ok!(Pools::withdraw_unbonded(Origin::signed(20), 20, 0));
+			assert_ok!(Pools::withdraw_unbonded(Origin::signed(40), 40, 0));
+			assert_eq!(Balances::free_balance(&20), balance_20 + 10);
+			assert_eq!(Balances::free_balance(&40), balance_40 + 20);
+		});
+	}
+
+	#[test]
+	fn slash_of_active_stake_is_shared_by_bonded_members() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			// given the active stake is slashed by half
+			StakingMock::set_bonded_balance(default_bonded_account(), 25);
+
+			// when
+			assert_ok!(Pools::unbond(Origin::signed(40), 40, 40));
+
+			// then
+			assert_eq!(
+				SubPoolsStorage::<Runtime>::get(1).unwrap().with_era,
+				unbonding_pools_with_era! { 3 => UnbondPool { points: 20, balance: 20 } }
+			);
+			assert_eq!(StakingMock::active_stake(&default_bonded_account()), Some(5));
+		});
+	}
+
+	#[test]
+	fn slash_of_unknown_account_is_ignored() {
+		ExtBuilder::default().build_and_execute(|| {
+			let _ = pool_events_since_last_call();
+			Pools::on_slash(&123, 10, &Default::default());
+			assert!(pool_events_since_last_call().is_empty());
+		});
+	}
+}
+
+mod admin {
+	use super::*;
+
+	#[test]
+	fn nominate_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_noop!(
+				Pools::nominate(Origin::signed(10), 1, vec![21]),
+				Error::<Runtime>::NotNominator
+			);
+			assert_noop!(
+				Pools::nominate(Origin::signed(902), 1, vec![21]),
+				Error::<Runtime>::NotNominator
+			);
+			assert_noop!(
+				Pools::nominate(Origin::signed(901), 123, vec![21]),
+				Error::<Runtime>::PoolNotFound
+			);
+
+			assert_ok!(Pools::nominate(Origin::signed(901), 1, vec![21]));
+			assert_eq!(Nominations::get().get(&default_bonded_account()), Some(&vec![21]));
+
+			// the root can nominate as well.
+			assert_ok!(Pools::nominate(Origin::signed(900), 1, vec![31]));
+			assert_eq!(Nominations::get().get(&default_bonded_account()), Some(&vec![31]));
+
+			assert_noop!(Pools::chill(Origin::signed(902), 1), Error::<Runtime>::NotNominator);
+			assert_ok!(Pools::chill(Origin::signed(901), 1));
+			assert_eq!(Nominations::get().get(&default_bonded_account()), None);
+		});
+	}
+
+	#[test]
+	fn set_state_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			let _ = pool_events_since_last_call();
+			assert_noop!(
+				Pools::set_state(Origin::signed(10), 1, PoolState::Blocked),
+				Error::<Runtime>::CanNotChangeState
+			);
+			assert_noop!(
+				Pools::set_state(Origin::signed(902), 1, PoolState::Open),
+				Error::<Runtime>::CanNotChangeState
+			);
+
+			assert_ok!(Pools::set_state(Origin::signed(902), 1, PoolState::Blocked));
+			assert_ok!(Pools::set_state(Origin::signed(900), 1, PoolState::Destroying));
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().state, PoolState::Destroying);
+
+			// a destroying pool cannot change its state anymore.
+			assert_noop!(
+				Pools::set_state(Origin::signed(900), 1, PoolState::Open),
+				Error::<Runtime>::CanNotChangeState
+			);
+
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::StateChanged { pool_id: 1, new_state: PoolState::Blocked },
+					Event::StateChanged { pool_id: 1, new_state: PoolState::Destroying },
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn set_configs_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_noop!(
+				Pools::set_configs(Origin::signed(10), 1, 2, Some(3), Some(4), Some(5)),
+				sp_runtime::DispatchError::BadOrigin
+			);
+
+			assert_ok!(Pools::set_configs(Origin::root(), 1, 2, Some(3), Some(4), Some(5)));
+
+			assert_eq!(MinJoinBond::<Runtime>::get(), 1);
+			assert_eq!(MinCreateBond::<Runtime>::get(), 2);
+			assert_eq!(MaxPools::<Runtime>::get(), Some(3));
+			assert_eq!(MaxPoolMembers::<Runtime>::get(), Some(4));
+			assert_eq!(MaxPoolMembersPerPool::<Runtime>::get(), Some(5));
+		});
+	}
+}
@@ -0,0 +1,472 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_nomination_pools
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2021-12-17, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+
+// Executed Command:
+// ./target/release/substrate
+// benchmark
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_nomination_pools
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/nomination-pools/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_nomination_pools.
+pub trait WeightInfo {
+	fn join() -> Weight;
+	fn bond_extra_transfer() -> Weight;
+	fn bond_extra_reward() -> Weight;
+	fn claim_payout() -> Weight;
+	fn unbond() -> Weight;
+	fn pool_withdraw_unbonded(s: u32, ) -> Weight;
+	fn withdraw_unbonded_update(s: u32, ) -> Weight;
+	fn withdraw_unbonded_kill(s: u32, ) -> Weight;
+	fn create() -> Weight;
+	fn nominate(n: u32, ) -> Weight;
+	fn chill() -> Weight;
+	fn set_state() -> Weight;
+	fn set_configs() -> Weight;
+}
+
+/// Weights for pallet_nomination_pools using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: NominationPools MinJoinBond (r:1 w:0)
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: NominationPools RewardPools (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NominationPools MaxPoolMembersPerPool (r:1 w:0)
+	// Storage: NominationPools MaxPoolMembers (r:1 w:0)
+	// Storage: NominationPools CounterForPoolMembers (r:1 w:1)
+	// Storage: Staking Bonded (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:3)
+	// Storage: BagsList ListBags (r:2 w:2)
+	fn join() -> Weight {
+		(124_351_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(17 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	// Storage: NominationPools RewardPools (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Staking Bonded (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:3)
+	// Storage: BagsList ListBags (r:2 w:2)
+	fn bond_extra_transfer() -> Weight {
+		(115_934_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	// Storage: NominationPools RewardPools (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Staking Bonded (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:3)
+	// Storage: BagsList ListBags (r:2 w:2)
+	fn bond_extra_reward() -> Weight {
+		(121_782_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	// Storage: NominationPools RewardPools (r:1 w:1)
+	// Storage: Staking Ledger (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn claim_payout() -> Weight {
+		(50_263_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	// Storage: NominationPools RewardPools (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Staking Bonded (r:1 w:0)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Staking Nominators (r:1 w:0)
+	// Storage: Staking MinNominatorBond (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:3)
+	// Storage: BagsList ListBags (r:2 w:2)
+	// Storage: NominationPools SubPoolsStorage (r:1 w:1)
+	// Storage: NominationPools CounterForSubPoolsStorage (r:1 w:1)
+	fn unbond() -> Weight {
+		(125_907_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+	}
+	// Storage: NominationPools BondedPools (r:1 w:0)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn pool_withdraw_unbonded(s: u32, ) -> Weight {
+		(42_016_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((52_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	// Storage: NominationPools SubPoolsStorage (r:1 w:1)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:2 w:1)
+	// Storage: NominationPools CounterForPoolMembers (r:1 w:1)
+	fn withdraw_unbonded_update(s: u32, ) -> Weight {
+		(81_738_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((61_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	// Storage: NominationPools SubPoolsStorage (r:1 w:1)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Staking SlashingSpans (r:1 w:0)
+	// Storage: Staking Validators (r:1 w:0)
+	// Storage: Staking Nominators (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: NominationPools CounterForPoolMembers (r:1 w:1)
+	// Storage: NominationPools ReversePoolIdLookup (r:1 w:1)
+	// Storage: NominationPools CounterForReversePoolIdLookup (r:1 w:1)
+	// Storage: NominationPools CounterForRewardPools (r:1 w:1)
+	// Storage: NominationPools CounterForSubPoolsStorage (r:1 w:1)
+	// Storage: NominationPools CounterForBondedPools (r:1 w:1)
+	// Storage: Staking Payee (r:0 w:1)
+	// Storage: NominationPools RewardPools (r:0 w:1)
+	fn withdraw_unbonded_kill(s: u32, ) -> Weight {
+		(136_218_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((28_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes(17 as Weight))
+	}
+	// Storage: Staking MinNominatorBond (r:1 w:0)
+	// Storage: NominationPools MinCreateBond (r:1 w:0)
+	// Storage: NominationPools MinJoinBond (r:1 w:0)
+	// Storage: NominationPools MaxPools (r:1 w:0)
+	// Storage: NominationPools CounterForBondedPools (r:1 w:1)
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: NominationPools LastPoolId (r:1 w:1)
+	// Storage: NominationPools MaxPoolMembersPerPool (r:1 w:0)
+	// Storage: NominationPools MaxPoolMembers (r:1 w:0)
+	// Storage: NominationPools CounterForPoolMembers (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Staking Bonded (r:1 w:1)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Staking HistoryDepth (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: NominationPools RewardPools (r:1 w:1)
+	// Storage: NominationPools CounterForRewardPools (r:1 w:1)
+	// Storage: NominationPools ReversePoolIdLookup (r:1 w:1)
+	// Storage: NominationPools CounterForReversePoolIdLookup (r:1 w:1)
+	// Storage: NominationPools BondedPools (r:0 w:1)
+	// Storage: Staking Payee (r:0 w:1)
+	fn create() -> Weight {
+		(131_466_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(22 as Weight))
+			.saturating_add(T::DbWeight::get().writes(16 as Weight))
+	}
+	// Storage: NominationPools BondedPools (r:1 w:0)
+	// Storage: Staking Ledger (r:1 w:0)
+	// Storage: Staking MinNominatorBond (r:1 w:0)
+	// Storage: Staking Nominators (r:1 w:1)
+	// Storage: Staking MaxNominatorsCount (r:1 w:0)
+	// Storage: Staking Validators (r:2 w:0)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Staking CounterForNominators (r:1 w:1)
+	// Storage: BagsList ListNodes (r:1 w:1)
+	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	fn nominate(n: u32, ) -> Weight {
+		(47_341_000 as Weight)
+			// Standard Error: 16_000
+			.saturating_add((2_145_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: NominationPools BondedPools (r:1 w:0)
+	// Storage: Staking Ledger (r:1 w:0)
+	// Storage: Staking Validators (r:1 w:0)
+	// Storage: Staking Nominators (r:1 w:1)
+	// Storage: Staking CounterForNominators (r:1 w:1)
+	// Storage: BagsList ListNodes (r:2 w:2)
+	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	fn chill() -> Weight {
+		(46_238_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	fn set_state() -> Weight {
+		(18_094_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: NominationPools MinJoinBond (r:0 w:1)
+	// Storage: NominationPools MaxPoolMembers (r:0 w:1)
+	// Storage: NominationPools MaxPoolMembersPerPool (r:0 w:1)
+	// Storage: NominationPools MinCreateBond (r:0 w:1)
+	// Storage: NominationPools MaxPools (r:0 w:1)
+	fn set_configs() -> Weight {
+		(7_012_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: NominationPools MinJoinBond (r:1 w:0)
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: NominationPools RewardPools (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NominationPools MaxPoolMembersPerPool (r:1 w:0)
+	// Storage: NominationPools MaxPoolMembers (r:1 w:0)
+	// Storage: NominationPools CounterForPoolMembers (r:1 w:1)
+	// Storage: Staking Bonded (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:3)
+	// Storage: BagsList ListBags (r:2 w:2)
+	fn join() -> Weight {
+		(124_351_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(17 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	// Storage: NominationPools RewardPools (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Staking Bonded (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:3)
+	// Storage: BagsList ListBags (r:2 w:2)
+	fn bond_extra_transfer() -> Weight {
+		(115_934_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	// Storage: NominationPools RewardPools (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Staking Bonded (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:3)
+	// Storage: BagsList ListBags (r:2 w:2)
+	fn bond_extra_reward() -> Weight {
+		(121_782_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	// Storage: NominationPools RewardPools (r:1 w:1)
+	// Storage: Staking Ledger (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn claim_payout() -> Weight {
+		(50_263_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	// Storage: NominationPools RewardPools (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Staking Bonded (r:1 w:0)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Staking Nominators (r:1 w:0)
+	// Storage: Staking MinNominatorBond (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:3)
+	// Storage: BagsList ListBags (r:2 w:2)
+	// Storage: NominationPools SubPoolsStorage (r:1 w:1)
+	// Storage: NominationPools CounterForSubPoolsStorage (r:1 w:1)
+	fn unbond() -> Weight {
+		(125_907_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
+	}
+	// Storage: NominationPools BondedPools (r:1 w:0)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn pool_withdraw_unbonded(s: u32, ) -> Weight {
+		(42_016_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((52_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	// Storage: NominationPools SubPoolsStorage (r:1 w:1)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:2 w:1)
+	// Storage: NominationPools CounterForPoolMembers (r:1 w:1)
+	fn withdraw_unbonded_update(s: u32, ) -> Weight {
+		(81_738_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((61_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	// Storage: NominationPools SubPoolsStorage (r:1 w:1)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Staking SlashingSpans (r:1 w:0)
+	// Storage: Staking Validators (r:1 w:0)
+	// Storage: Staking Nominators (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: NominationPools CounterForPoolMembers (r:1 w:1)
+	// Storage: NominationPools ReversePoolIdLookup (r:1 w:1)
+	// Storage: NominationPools CounterForReversePoolIdLookup (r:1 w:1)
+	// Storage: NominationPools CounterForRewardPools (r:1 w:1)
+	// Storage: NominationPools CounterForSubPoolsStorage (r:1 w:1)
+	// Storage: NominationPools CounterForBondedPools (r:1 w:1)
+	// Storage: Staking Payee (r:0 w:1)
+	// Storage: NominationPools RewardPools (r:0 w:1)
+	fn withdraw_unbonded_kill(s: u32, ) -> Weight {
+		(136_218_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((28_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(20 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(17 as Weight))
+	}
+	// Storage: Staking MinNominatorBond (r:1 w:0)
+	// Storage: NominationPools MinCreateBond (r:1 w:0)
+	// Storage: NominationPools MinJoinBond (r:1 w:0)
+	// Storage: NominationPools MaxPools (r:1 w:0)
+	// Storage: NominationPools CounterForBondedPools (r:1 w:1)
+	// Storage: NominationPools PoolMembers (r:1 w:1)
+	// Storage: NominationPools LastPoolId (r:1 w:1)
+	// Storage: NominationPools MaxPoolMembersPerPool (r:1 w:0)
+	// Storage: NominationPools MaxPoolMembers (r:1 w:0)
+	// Storage: NominationPools CounterForPoolMembers (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Staking Ledger (r:1 w:1)
+	// Storage: Staking Bonded (r:1 w:1)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Staking HistoryDepth (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: NominationPools RewardPools (r:1 w:1)
+	// Storage: NominationPools CounterForRewardPools (r:1 w:1)
+	// Storage: NominationPools ReversePoolIdLookup (r:1 w:1)
+	// Storage: NominationPools CounterForReversePoolIdLookup (r:1 w:1)
+	// Storage: NominationPools BondedPools (r:0 w:1)
+	// Storage: Staking Payee (r:0 w:1)
+	fn create() -> Weight {
+		(131_466_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(22 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(16 as Weight))
+	}
+	// Storage: NominationPools BondedPools (r:1 w:0)
+	// Storage: Staking Ledger (r:1 w:0)
+	// Storage: Staking MinNominatorBond (r:1 w:0)
+	// Storage: Staking Nominators (r:1 w:1)
+	// Storage: Staking MaxNominatorsCount (r:1 w:0)
+	// Storage: Staking Validators (r:2 w:0)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Staking CounterForNominators (r:1 w:1)
+	// Storage: BagsList ListNodes (r:1 w:1)
+	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	fn nominate(n: u32, ) -> Weight {
+		(47_341_000 as Weight)
+			// Standard Error: 16_000
+			.saturating_add((2_145_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: NominationPools BondedPools (r:1 w:0)
+	// Storage: Staking Ledger (r:1 w:0)
+	// Storage: Staking Validators (r:1 w:0)
+	// Storage: Staking Nominators (r:1 w:1)
+	// Storage: Staking CounterForNominators (r:1 w:1)
+	// Storage: BagsList ListNodes (r:2 w:2)
+	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	fn chill() -> Weight {
+		(46_238_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: NominationPools BondedPools (r:1 w:1)
+	fn set_state() -> Weight {
+		(18_094_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: NominationPools MinJoinBond (r:0 w:1)
+	// Storage: NominationPools MaxPoolMembers (r:0 w:1)
+	// Storage: NominationPools MaxPoolMembersPerPool (r:0 w:1)
+	// Storage: NominationPools MinCreateBond (r:0 w:1)
+	// Storage: NominationPools MaxPools (r:0 w:1)
+	fn set_configs() -> Weight {
+		(7_012_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+}
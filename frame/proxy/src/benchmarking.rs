@@ -45,6 +45,26 @@ fn add_proxies<T: Config>(n: u32, maybe_who: Option<T::AccountId>) -> Result<(),
 	Ok(())
 }
 
+/// Turn the proxy of `delegate` for `real` added by `add_proxies` into one which is used up after
+/// a single call.
+fn limit_proxy<T: Config>(real: T::AccountId, delegate: T::AccountId) -> Result<(), &'static str> {
+	Proxy::<T>::remove_proxy(
+		RawOrigin::Signed(real.clone()).into(),
+		delegate.clone(),
+		T::ProxyType::default(),
+		T::BlockNumber::zero(),
+	)?;
+	Proxy::<T>::add_limited_proxy(
+		RawOrigin::Signed(real).into(),
+		delegate,
+		T::ProxyType::default(),
+		T::BlockNumber::zero(),
+		None,
+		Some(1),
+	)?;
+	Ok(())
+}
+
 fn add_announcements<T: Config>(
 	n: u32,
 	maybe_who: Option<T::AccountId>,
@@ -83,10 +103,13 @@ benchmarks! {
 		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
 		// ... and "real" is the traditional caller. This is not a typo.
 		let real: T::AccountId = whitelisted_caller();
+		// Worst case: the proxy is used up by this call and gets removed.
+		limit_proxy::<T>(real.clone(), caller.clone())?;
 		let call: <T as Config>::Call = frame_system::Call::<T>::remark { remark: vec![] }.into();
-	}: _(RawOrigin::Signed(caller), real, Some(T::ProxyType::default()), Box::new(call))
+	}: _(RawOrigin::Signed(caller.clone()), real.clone(), Some(T::ProxyType::default()), Box::new(call))
 	verify {
-		assert_last_event::<T>(Event::ProxyExecuted { result: Ok(()) }.into())
+		assert_last_event::<T>(Event::ProxyExecuted { result: Ok(()) }.into());
+		assert!(Proxy::<T>::find_proxy(&real, &caller, None).is_err());
 	}
 
 	proxy_announced {
@@ -98,6 +121,8 @@ benchmarks! {
 		T::Currency::make_free_balance_be(&delegate, BalanceOf::<T>::max_value());
 		// ... and "real" is the traditional caller. This is not a typo.
 		let real: T::AccountId = whitelisted_caller();
+		// Worst case: the proxy is used up by this call and gets removed.
+		limit_proxy::<T>(real.clone(), delegate.clone())?;
 		let call: <T as Config>::Call = frame_system::Call::<T>::remark { remark: vec![] }.into();
 		Proxy::<T>::announce(
 			RawOrigin::Signed(delegate.clone()).into(),
@@ -105,9 +130,16 @@ benchmarks! {
 			T::CallHasher::hash_of(&call),
 		)?;
 		add_announcements::<T>(a, Some(delegate.clone()), None)?;
-	}: _(RawOrigin::Signed(caller), delegate, real, Some(T::ProxyType::default()), Box::new(call))
+	}: _(
+		RawOrigin::Signed(caller),
+		delegate.clone(),
+		real.clone(),
+		Some(T::ProxyType::default()),
+		Box::new(call)
+	)
 	verify {
-		assert_last_event::<T>(Event::ProxyExecuted { result: Ok(()) }.into())
+		assert_last_event::<T>(Event::ProxyExecuted { result: Ok(()) }.into());
+		assert!(Proxy::<T>::find_proxy(&real, &delegate, None).is_err());
 	}
 
 	remove_announcement {
@@ -182,6 +214,22 @@ benchmarks! {
 		assert_eq!(proxies.len() as u32, p + 1);
 	}
 
+	add_limited_proxy {
+		let p in 1 .. (T::MaxProxies::get() - 1).into() => add_proxies::<T>(p, None)?;
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		account("target", T::MaxProxies::get().into(), SEED),
+		T::ProxyType::default(),
+		T::BlockNumber::zero(),
+		Some(T::BlockNumber::max_value()),
+		Some(u32::max_value())
+	)
+	verify {
+		let (proxies, _) = Proxies::<T>::get(caller);
+		assert_eq!(proxies.len() as u32, p + 1);
+	}
+
 	remove_proxy {
 		let p in 1 .. (T::MaxProxies::get() - 1).into() => add_proxies::<T>(p, None)?;
 		let caller: T::AccountId = whitelisted_caller();
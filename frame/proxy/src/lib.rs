@@ -23,6 +23,10 @@
 //! wish to execute some duration prior to execution happens. In this case, the target account may
 //! reject the announcement and in doing so, veto the execution.
 //!
+//! A proxy may also be limited to a number of blocks or a number of calls, after which it may no
+//! longer be used. Expired proxies are removed, and their deposit returned, the next time the
+//! proxies of the delegating account are changed or used.
+//!
 //! - [`Config`]
 //! - [`Call`]
 
@@ -30,6 +34,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
+pub mod migration;
 mod tests;
 pub mod weights;
 
@@ -37,9 +42,12 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::DispatchError,
 	ensure,
-	traits::{Currency, Get, InstanceFilter, IsSubType, IsType, OriginTrait, ReservableCurrency},
+	traits::{
+		Currency, Get, InstanceFilter, IsSubType, IsType, OriginTrait, ReservableCurrency,
+		StorageVersion,
+	},
 	weights::GetDispatchInfo,
-	RuntimeDebug,
+	BoundedVec, RuntimeDebug,
 };
 use frame_system::{self as system};
 use scale_info::TypeInfo;
@@ -81,6 +89,26 @@ pub struct ProxyDefinition<AccountId, ProxyType, BlockNumber> {
 	/// The number of blocks that an announcement must be in place for before the corresponding
 	/// call may be dispatched. If zero, then no announcement is needed.
 	pub delay: BlockNumber,
+	/// The block from which the proxy may no longer be used. If `None`, then it never expires.
+	pub expires_at: Option<BlockNumber>,
+	/// The number of calls that may still be dispatched through the proxy. If `None`, then the
+	/// number of calls is unlimited.
+	pub max_calls: Option<u32>,
+}
+
+impl<AccountId, ProxyType, BlockNumber: PartialOrd>
+	ProxyDefinition<AccountId, ProxyType, BlockNumber>
+{
+	/// Whether the proxy has a limited lifetime or a limited number of calls.
+	pub fn is_limited(&self) -> bool {
+		self.expires_at.is_some() || self.max_calls.is_some()
+	}
+
+	/// Whether the proxy may no longer be used at block `now`, either because it expired or
+	/// because all of its calls were used up.
+	pub fn is_expired(&self, now: &BlockNumber) -> bool {
+		self.expires_at.as_ref().map_or(false, |e| now >= e) || self.max_calls == Some(0)
+	}
 }
 
 /// Details surrounding a specific instance of an announcement to make a call.
@@ -100,9 +128,13 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
 	#[pallet::generate_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configuration trait.
@@ -144,8 +176,10 @@ pub mod pallet {
 		/// The amount of currency needed per proxy added.
 		///
 		/// This is held for adding 32 bytes plus an instance of `ProxyType` more into a
-		/// pre-existing storage value. Thus, when configuring `ProxyDepositFactor` one should take
-		/// into account `32 + proxy_type.encode().len()` bytes of data.
+		/// pre-existing storage value, together with its delay and optional limits. Thus, when
+		/// configuring `ProxyDepositFactor` one should take into account
+		/// `32 + proxy_type.encode().len()` bytes of data plus the encoded size of a `BlockNumber`,
+		/// an `Option<BlockNumber>` and an `Option<u32>`.
 		#[pallet::constant]
 		type ProxyDepositFactor: Get<BalanceOf<Self>>;
 
@@ -213,6 +247,7 @@ pub mod pallet {
 			let def = Self::find_proxy(&real, &who, force_proxy_type)?;
 			ensure!(def.delay.is_zero(), Error::<T>::Unannounced);
 
+			Self::use_proxy(&real, &def);
 			Self::do_proxy(def, real, *call);
 
 			Ok(())
@@ -242,6 +277,41 @@ pub mod pallet {
 			Self::add_proxy_delegate(&who, delegate, proxy_type, delay)
 		}
 
+		/// Register a proxy account for the sender that is able to make calls on its behalf for a
+		/// limited time or a limited number of calls.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `proxy`: The account that the `caller` would like to make a proxy.
+		/// - `proxy_type`: The permissions allowed for this proxy account.
+		/// - `delay`: The announcement period required of the initial proxy. Will generally be
+		/// zero.
+		/// - `expires_at`: The block from which the proxy may no longer be used, if any.
+		/// - `max_calls`: The number of calls that may be dispatched through the proxy, if
+		/// limited.
+		///
+		/// Once expired or used up, the proxy is removed the next time the proxies of the sender
+		/// are changed or used, and the corresponding deposit is returned.
+		///
+		/// # <weight>
+		/// Weight is a function of the number of proxies the user has (P).
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::add_limited_proxy(T::MaxProxies::get().into()))]
+		pub fn add_limited_proxy(
+			origin: OriginFor<T>,
+			delegate: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: T::BlockNumber,
+			expires_at: Option<T::BlockNumber>,
+			max_calls: Option<u32>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::add_limited_proxy_delegate(
+				&who, delegate, proxy_type, delay, expires_at, max_calls,
+			)
+		}
+
 		/// Unregister a proxy account for the sender.
 		///
 		/// The dispatch origin for this call must be _Signed_.
@@ -318,8 +388,13 @@ pub mod pallet {
 			let anonymous = Self::anonymous_account(&who, &proxy_type, index, None);
 			ensure!(!Proxies::<T>::contains_key(&anonymous), Error::<T>::Duplicate);
 
-			let proxy_def =
-				ProxyDefinition { delegate: who.clone(), proxy_type: proxy_type.clone(), delay };
+			let proxy_def = ProxyDefinition {
+				delegate: who.clone(),
+				proxy_type: proxy_type.clone(),
+				delay,
+				expires_at: None,
+				max_calls: None,
+			};
 			let bounded_proxies: BoundedVec<_, T::MaxProxies> =
 				vec![proxy_def].try_into().map_err(|_| Error::<T>::TooMany)?;
 
@@ -406,11 +481,7 @@ pub mod pallet {
 			call_hash: CallHashOf<T>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			Proxies::<T>::get(&real)
-				.0
-				.into_iter()
-				.find(|x| &x.delegate == &who)
-				.ok_or(Error::<T>::NotProxy)?;
+			Self::find_proxy(&real, &who, None)?;
 
 			let announcement = Announcement {
 				real: real.clone(),
@@ -543,6 +614,7 @@ pub mod pallet {
 			})
 			.map_err(|_| Error::<T>::Unannounced)?;
 
+			Self::use_proxy(&real, &def);
 			Self::do_proxy(def, real, *call);
 
 			Ok(())
@@ -571,6 +643,13 @@ pub mod pallet {
 			proxy_type: T::ProxyType,
 			delay: T::BlockNumber,
 		},
+		/// A proxy was removed because it expired or all of its calls were used up.
+		ProxyExpired {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: T::BlockNumber,
+		},
 	}
 
 	/// Old name generated by `decl_event`.
@@ -595,6 +674,8 @@ pub mod pallet {
 		Unannounced,
 		/// Cannot add self as proxy.
 		NoSelfProxy,
+		/// The proxy would be expired or have no calls left as soon as it is added.
+		AlreadyExpired,
 	}
 
 	/// The set of account proxies. Maps the account which has delegated to the accounts
@@ -669,15 +750,46 @@ impl<T: Config> Pallet<T> {
 		delegatee: T::AccountId,
 		proxy_type: T::ProxyType,
 		delay: T::BlockNumber,
+	) -> DispatchResult {
+		Self::add_limited_proxy_delegate(delegator, delegatee, proxy_type, delay, None, None)
+	}
+
+	/// Register a proxy account for the delegator that is able to make calls on its behalf until
+	/// `expires_at` or for `max_calls` calls, whichever comes first.
+	///
+	/// Expired proxies of the delegator are removed along the way.
+	///
+	/// Parameters:
+	/// - `delegator`: The delegator account.
+	/// - `delegatee`: The account that the `delegator` would like to make a proxy.
+	/// - `proxy_type`: The permissions allowed for this proxy account.
+	/// - `delay`: The announcement period required of the initial proxy. Will generally be
+	/// zero.
+	/// - `expires_at`: The block from which the proxy may no longer be used, if any.
+	/// - `max_calls`: The number of calls that may be dispatched through the proxy, if limited.
+	pub fn add_limited_proxy_delegate(
+		delegator: &T::AccountId,
+		delegatee: T::AccountId,
+		proxy_type: T::ProxyType,
+		delay: T::BlockNumber,
+		expires_at: Option<T::BlockNumber>,
+		max_calls: Option<u32>,
 	) -> DispatchResult {
 		ensure!(delegator != &delegatee, Error::<T>::NoSelfProxy);
-		Proxies::<T>::try_mutate(delegator, |(ref mut proxies, ref mut deposit)| {
-			let proxy_def = ProxyDefinition {
-				delegate: delegatee.clone(),
-				proxy_type: proxy_type.clone(),
-				delay,
-			};
-			let i = proxies.binary_search(&proxy_def).err().ok_or(Error::<T>::Duplicate)?;
+		let now = system::Pallet::<T>::block_number();
+		let proxy_def = ProxyDefinition {
+			delegate: delegatee.clone(),
+			proxy_type: proxy_type.clone(),
+			delay,
+			expires_at,
+			max_calls,
+		};
+		ensure!(!proxy_def.is_expired(&now), Error::<T>::AlreadyExpired);
+		let expired = Proxies::<T>::try_mutate(delegator, |(ref mut proxies, ref mut deposit)| {
+			let expired = Self::prune_expired(proxies, &now);
+			let i = Self::search_proxy(proxies, &delegatee, &proxy_type, &delay)
+				.err()
+				.ok_or(Error::<T>::Duplicate)?;
 			proxies.try_insert(i, proxy_def).map_err(|_| Error::<T>::TooMany)?;
 			let new_deposit = Self::deposit(proxies.len() as u32);
 			if new_deposit > *deposit {
@@ -686,14 +798,16 @@ impl<T: Config> Pallet<T> {
 				T::Currency::unreserve(delegator, *deposit - new_deposit);
 			}
 			*deposit = new_deposit;
-			Self::deposit_event(Event::<T>::ProxyAdded {
-				delegator: delegator.clone(),
-				delegatee,
-				proxy_type,
-				delay,
-			});
-			Ok(())
-		})
+			Ok::<_, DispatchError>(expired)
+		})?;
+		Self::deposit_expired_events(delegator, expired);
+		Self::deposit_event(Event::<T>::ProxyAdded {
+			delegator: delegator.clone(),
+			delegatee,
+			proxy_type,
+			delay,
+		});
+		Ok(())
 	}
 
 	/// Unregister a proxy account for the delegator.
@@ -710,11 +824,14 @@ impl<T: Config> Pallet<T> {
 		proxy_type: T::ProxyType,
 		delay: T::BlockNumber,
 	) -> DispatchResult {
-		Proxies::<T>::try_mutate_exists(delegator, |x| {
+		let now = system::Pallet::<T>::block_number();
+		let expired = Proxies::<T>::try_mutate_exists(delegator, |x| {
 			let (mut proxies, old_deposit) = x.take().ok_or(Error::<T>::NotFound)?;
-			let proxy_def = ProxyDefinition { delegate: delegatee, proxy_type, delay };
-			let i = proxies.binary_search(&proxy_def).ok().ok_or(Error::<T>::NotFound)?;
+			let i = Self::search_proxy(&proxies, &delegatee, &proxy_type, &delay)
+				.ok()
+				.ok_or(Error::<T>::NotFound)?;
 			proxies.remove(i);
+			let expired = Self::prune_expired(&mut proxies, &now);
 			let new_deposit = Self::deposit(proxies.len() as u32);
 			if new_deposit > old_deposit {
 				T::Currency::reserve(delegator, new_deposit - old_deposit)?;
@@ -724,8 +841,10 @@ impl<T: Config> Pallet<T> {
 			if !proxies.is_empty() {
 				*x = Some((proxies, new_deposit))
 			}
-			Ok(())
-		})
+			Ok::<_, DispatchError>(expired)
+		})?;
+		Self::deposit_expired_events(delegator, expired);
+		Ok(())
 	}
 
 	pub fn deposit(num_proxies: u32) -> BalanceOf<T> {
@@ -776,14 +895,99 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Find the position of the proxy of `delegate` with the given `proxy_type` and `delay` in
+	/// the sorted `proxies`, regardless of its limits.
+	fn search_proxy(
+		proxies: &[ProxyDefinition<T::AccountId, T::ProxyType, T::BlockNumber>],
+		delegate: &T::AccountId,
+		proxy_type: &T::ProxyType,
+		delay: &T::BlockNumber,
+	) -> Result<usize, usize> {
+		proxies.binary_search_by(|x| {
+			(&x.delegate, &x.proxy_type, &x.delay).cmp(&(delegate, proxy_type, delay))
+		})
+	}
+
+	/// Remove the proxies which are expired at block `now`, returning them.
+	fn prune_expired(
+		proxies: &mut BoundedVec<
+			ProxyDefinition<T::AccountId, T::ProxyType, T::BlockNumber>,
+			T::MaxProxies,
+		>,
+		now: &T::BlockNumber,
+	) -> Vec<ProxyDefinition<T::AccountId, T::ProxyType, T::BlockNumber>> {
+		let mut expired = Vec::new();
+		proxies.retain(|x| {
+			if x.is_expired(now) {
+				expired.push(x.clone());
+				false
+			} else {
+				true
+			}
+		});
+		expired
+	}
+
+	fn deposit_expired_events(
+		delegator: &T::AccountId,
+		expired: Vec<ProxyDefinition<T::AccountId, T::ProxyType, T::BlockNumber>>,
+	) {
+		for def in expired {
+			Self::deposit_event(Event::<T>::ProxyExpired {
+				delegator: delegator.clone(),
+				delegatee: def.delegate,
+				proxy_type: def.proxy_type,
+				delay: def.delay,
+			});
+		}
+	}
+
+	/// Account for a call dispatched by `real` through the proxy `def`.
+	///
+	/// This is a no-op for proxies without a call limit. Otherwise the number of calls left is
+	/// decremented, and any proxy of `real` which expired (including `def`, once used up) is
+	/// removed and its deposit returned.
+	fn use_proxy(
+		real: &T::AccountId,
+		def: &ProxyDefinition<T::AccountId, T::ProxyType, T::BlockNumber>,
+	) {
+		if def.max_calls.is_none() {
+			return
+		}
+		let now = system::Pallet::<T>::block_number();
+		let expired = Proxies::<T>::mutate_exists(real, |x| {
+			let (mut proxies, old_deposit) = match x.take() {
+				Some(value) => value,
+				None => return Vec::new(),
+			};
+			if let Ok(i) = Self::search_proxy(&proxies, &def.delegate, &def.proxy_type, &def.delay)
+			{
+				proxies[i].max_calls = proxies[i].max_calls.map(|c| c.saturating_sub(1));
+			}
+			let expired = Self::prune_expired(&mut proxies, &now);
+			let new_deposit = Self::deposit(proxies.len() as u32);
+			// Pruning only ever shrinks the deposit.
+			T::Currency::unreserve(real, old_deposit.saturating_sub(new_deposit));
+			if !proxies.is_empty() {
+				*x = Some((proxies, new_deposit))
+			}
+			expired
+		});
+		Self::deposit_expired_events(real, expired);
+	}
+
+	/// Find the proxy of `real` for `delegate`, optionally of the given type, which may be used at
+	/// the current block.
 	pub fn find_proxy(
 		real: &T::AccountId,
 		delegate: &T::AccountId,
 		force_proxy_type: Option<T::ProxyType>,
 	) -> Result<ProxyDefinition<T::AccountId, T::ProxyType, T::BlockNumber>, DispatchError> {
+		let now = system::Pallet::<T>::block_number();
 		let f = |x: &ProxyDefinition<T::AccountId, T::ProxyType, T::BlockNumber>| -> bool {
 			&x.delegate == delegate &&
-				force_proxy_type.as_ref().map_or(true, |y| &x.proxy_type == y)
+				force_proxy_type.as_ref().map_or(true, |y| &x.proxy_type == y) &&
+				!x.is_expired(&now)
 		};
 		Ok(Proxies::<T>::get(real).0.into_iter().find(f).ok_or(Error::<T>::NotProxy)?)
 	}
@@ -802,9 +1006,15 @@ impl<T: Config> Pallet<T> {
 				// Proxy call cannot add or remove a proxy with more permissions than it already
 				// has.
 				Some(Call::add_proxy { ref proxy_type, .. }) |
+				Some(Call::add_limited_proxy { ref proxy_type, .. }) |
 				Some(Call::remove_proxy { ref proxy_type, .. })
 					if !def.proxy_type.is_superset(&proxy_type) =>
 					false,
+				// Proxy call cannot add a proxy if its own lifetime or number of calls is limited,
+				// since the new proxy could outlive it.
+				Some(Call::add_proxy { .. }) | Some(Call::add_limited_proxy { .. })
+					if def.is_limited() =>
+					false,
 				// Proxy call cannot remove all proxies or kill anonymous proxies unless it has full
 				// permissions.
 				Some(Call::remove_proxies { .. }) | Some(Call::kill_anonymous { .. })
@@ -0,0 +1,92 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the proxy pallet.
+
+use super::*;
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+
+pub mod v1 {
+	use super::*;
+	use codec::Decode;
+
+	#[derive(Decode)]
+	pub struct OldProxyDefinition<AccountId, ProxyType, BlockNumber> {
+		pub delegate: AccountId,
+		pub proxy_type: ProxyType,
+		pub delay: BlockNumber,
+	}
+
+	impl<AccountId, ProxyType, BlockNumber> OldProxyDefinition<AccountId, ProxyType, BlockNumber> {
+		fn migrate_to_v1(self) -> ProxyDefinition<AccountId, ProxyType, BlockNumber> {
+			ProxyDefinition {
+				delegate: self.delegate,
+				proxy_type: self.proxy_type,
+				delay: self.delay,
+				expires_at: None,
+				max_calls: None,
+			}
+		}
+	}
+
+	/// Migrate the pallet storage from `0` to `1`: proxy definitions gain an optional expiry
+	/// block and an optional call limit, both unset for existing proxies.
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T>::current_storage_version();
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version != 0 || current_version != 1 {
+				log::info!(
+					target: "runtime::proxy",
+					"Migration did not execute. This probably should be removed"
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Proxies::<T>::translate::<
+				(
+					BoundedVec<
+						OldProxyDefinition<T::AccountId, T::ProxyType, T::BlockNumber>,
+						T::MaxProxies,
+					>,
+					BalanceOf<T>,
+				),
+				_,
+			>(|_key, (proxies, deposit)| {
+				translated.saturating_inc();
+				// The order of the definitions only depends on their leading fields, which are
+				// unchanged, so the result is still sorted.
+				let proxies = proxies
+					.into_iter()
+					.map(OldProxyDefinition::migrate_to_v1)
+					.collect::<Vec<_>>()
+					.try_into()
+					.ok()?;
+				Some((proxies, deposit))
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::proxy", "Upgraded {} items, storage to version 1", translated);
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+	}
+}
@@ -565,3 +565,171 @@ fn anonymous_works() {
 		);
 	});
 }
+
+#[test]
+fn add_limited_proxy_checks_limits() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_noop!(
+			Proxy::add_limited_proxy(Origin::signed(1), 2, ProxyType::Any, 0, Some(5), None),
+			Error::<Test>::AlreadyExpired
+		);
+		assert_noop!(
+			Proxy::add_limited_proxy(Origin::signed(1), 2, ProxyType::Any, 0, None, Some(0)),
+			Error::<Test>::AlreadyExpired
+		);
+		assert_ok!(Proxy::add_limited_proxy(
+			Origin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			Some(6),
+			None
+		));
+		// The limits do not set proxies apart.
+		assert_noop!(
+			Proxy::add_limited_proxy(Origin::signed(1), 2, ProxyType::Any, 0, None, Some(1)),
+			Error::<Test>::Duplicate
+		);
+		assert_noop!(
+			Proxy::add_proxy(Origin::signed(1), 2, ProxyType::Any, 0),
+			Error::<Test>::Duplicate
+		);
+		assert_ok!(Proxy::remove_proxy(Origin::signed(1), 2, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn expired_proxy_cannot_be_used_and_is_removed_lazily() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_limited_proxy(
+			Origin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			Some(3),
+			None
+		));
+		assert_ok!(Proxy::add_proxy(Origin::signed(1), 3, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		let call = Box::new(call_transfer(6, 1));
+		System::set_block_number(2);
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call.clone()));
+		assert_eq!(Balances::free_balance(6), 1);
+
+		System::set_block_number(3);
+		assert_noop!(
+			Proxy::proxy(Origin::signed(2), 1, None, call.clone()),
+			Error::<Test>::NotProxy
+		);
+		assert_noop!(
+			Proxy::announce(Origin::signed(2), 1, [1; 32].into()),
+			Error::<Test>::NotProxy
+		);
+
+		// The next change to the proxies of the delegator removes it and returns its deposit.
+		assert_ok!(Proxy::add_proxy(Origin::signed(1), 4, ProxyType::Any, 0));
+		expect_events(vec![
+			ProxyEvent::ProxyExpired {
+				delegator: 1,
+				delegatee: 2,
+				proxy_type: ProxyType::Any,
+				delay: 0,
+			}
+			.into(),
+			ProxyEvent::ProxyAdded {
+				delegator: 1,
+				delegatee: 4,
+				proxy_type: ProxyType::Any,
+				delay: 0,
+			}
+			.into(),
+		]);
+		let delegates: Vec<_> = Proxies::<Test>::get(1).0.into_iter().map(|d| d.delegate).collect();
+		assert_eq!(delegates, vec![3, 4]);
+		assert_eq!(Balances::reserved_balance(1), 3);
+	});
+}
+
+#[test]
+fn call_limited_proxy_is_used_up() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_limited_proxy(
+			Origin::signed(1),
+			2,
+			ProxyType::Any,
+			1,
+			None,
+			Some(2)
+		));
+		assert_eq!(Balances::reserved_balance(1), 2);
+
+		let first = Box::new(call_transfer(6, 1));
+		let second = Box::new(call_transfer(6, 2));
+		assert_ok!(Proxy::announce(Origin::signed(2), 1, BlakeTwo256::hash_of(&first)));
+		assert_ok!(Proxy::announce(Origin::signed(2), 1, BlakeTwo256::hash_of(&second)));
+		System::set_block_number(2);
+
+		assert_ok!(Proxy::proxy_announced(Origin::signed(0), 2, 1, None, first.clone()));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Proxies::<Test>::get(1).0[0].max_calls, Some(1));
+
+		assert_ok!(Proxy::proxy_announced(Origin::signed(0), 2, 1, None, second.clone()));
+		expect_events(vec![
+			BalancesEvent::<Test>::Unreserved { who: 1, amount: 2 }.into(),
+			ProxyEvent::ProxyExpired {
+				delegator: 1,
+				delegatee: 2,
+				proxy_type: ProxyType::Any,
+				delay: 1,
+			}
+			.into(),
+			BalancesEvent::<Test>::Transfer { from: 1, to: 6, amount: 2 }.into(),
+			ProxyEvent::ProxyExecuted { result: Ok(()) }.into(),
+		]);
+		assert_eq!(Balances::free_balance(6), 3);
+		assert!(!Proxies::<Test>::contains_key(1));
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		assert_noop!(
+			Proxy::proxy_announced(Origin::signed(0), 2, 1, None, first),
+			Error::<Test>::NotProxy
+		);
+	});
+}
+
+#[test]
+fn limited_proxy_cannot_add_proxies() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_limited_proxy(
+			Origin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			Some(10),
+			None
+		));
+
+		let call =
+			Box::new(Call::Proxy(ProxyCall::new_call_variant_add_proxy(3, ProxyType::Any, 0)));
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call.clone()));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+
+		let call = Box::new(Call::Proxy(ProxyCall::new_call_variant_add_limited_proxy(
+			3,
+			ProxyType::Any,
+			0,
+			Some(5),
+			None,
+		)));
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call.clone()));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+		assert!(Proxy::find_proxy(&1, &3, None).is_err());
+	});
+}
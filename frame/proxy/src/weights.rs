@@ -51,6 +51,7 @@ pub trait WeightInfo {
 	fn reject_announcement(a: u32, p: u32, ) -> Weight;
 	fn announce(a: u32, p: u32, ) -> Weight;
 	fn add_proxy(p: u32, ) -> Weight;
+	fn add_limited_proxy(p: u32, ) -> Weight;
 	fn remove_proxy(p: u32, ) -> Weight;
 	fn remove_proxies(p: u32, ) -> Weight;
 	fn anonymous(p: u32, ) -> Weight;
@@ -60,24 +61,26 @@ pub trait WeightInfo {
 /// Weights for pallet_proxy using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn proxy(p: u32, ) -> Weight {
-		(23_213_000 as Weight)
+		(41_626_000 as Weight)
 			// Standard Error: 2_000
-			.saturating_add((153_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add((296_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
-	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:1)
 	// Storage: Proxy Announcements (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn proxy_announced(a: u32, p: u32, ) -> Weight {
-		(53_286_000 as Weight)
+		(64_104_000 as Weight)
 			// Standard Error: 2_000
-			.saturating_add((549_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add((551_000 as Weight).saturating_mul(a as Weight))
 			// Standard Error: 2_000
-			.saturating_add((138_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((284_000 as Weight).saturating_mul(p as Weight))
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Proxy Announcements (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
@@ -118,6 +121,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 	// Storage: Proxy Proxies (r:1 w:1)
+	fn add_limited_proxy(p: u32, ) -> Weight {
+		(36_912_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((226_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Proxy Proxies (r:1 w:1)
 	fn remove_proxy(p: u32, ) -> Weight {
 		(35_284_000 as Weight)
 			// Standard Error: 3_000
@@ -154,24 +165,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn proxy(p: u32, ) -> Weight {
-		(23_213_000 as Weight)
+		(41_626_000 as Weight)
 			// Standard Error: 2_000
-			.saturating_add((153_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add((296_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
-	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:1)
 	// Storage: Proxy Announcements (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn proxy_announced(a: u32, p: u32, ) -> Weight {
-		(53_286_000 as Weight)
+		(64_104_000 as Weight)
 			// Standard Error: 2_000
-			.saturating_add((549_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add((551_000 as Weight).saturating_mul(a as Weight))
 			// Standard Error: 2_000
-			.saturating_add((138_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((284_000 as Weight).saturating_mul(p as Weight))
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Proxy Announcements (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
@@ -212,6 +225,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
 	// Storage: Proxy Proxies (r:1 w:1)
+	fn add_limited_proxy(p: u32, ) -> Weight {
+		(36_912_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((226_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Proxy Proxies (r:1 w:1)
 	fn remove_proxy(p: u32, ) -> Weight {
 		(35_284_000 as Weight)
 			// Standard Error: 3_000
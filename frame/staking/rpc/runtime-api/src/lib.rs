@@ -0,0 +1,45 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the staking pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, HasCompact};
+use sp_std::vec::Vec;
+
+pub use pallet_staking::{
+	slashing::SlashingSpan, EraIndex, ExposurePage, PageIndex, UnappliedSlash,
+};
+
+sp_api::decl_runtime_apis! {
+	pub trait StakingApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec + HasCompact,
+	{
+		/// The slashes that were reported in `era` and are not applied yet.
+		fn pending_slashes(era: EraIndex) -> Vec<UnappliedSlash<AccountId, Balance>>;
+		/// The slashing spans of `stash`, most recent first.
+		fn slashing_spans(stash: AccountId) -> Vec<SlashingSpan>;
+		/// The page of the exposure of `validator` at `era` that contains `who`.
+		fn exposure_page_of(
+			era: EraIndex,
+			validator: AccountId,
+			who: AccountId,
+		) -> Option<(PageIndex, ExposurePage<AccountId, Balance>)>;
+	}
+}
@@ -0,0 +1,155 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the staking pallet.
+//!
+//! This exposes the slashes that are pending, the slashing spans of a stash and the exposure pages
+//! from which the nominators are slashed, without having to decode raw storage.
+
+use std::sync::Arc;
+
+use codec::{Codec, HasCompact};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use self::gen_client::Client as StakingClient;
+pub use pallet_staking_rpc_runtime_api::StakingApi as StakingRuntimeApi;
+use pallet_staking_rpc_runtime_api::{
+	EraIndex, ExposurePage, PageIndex, SlashingSpan, UnappliedSlash,
+};
+
+/// Staking RPC methods.
+///
+/// `Slash` is the type of a pending slash and `Page` the type of an exposure page, i.e.
+/// [`UnappliedSlash`] and [`ExposurePage`] of the account and balance types of the runtime.
+#[rpc]
+pub trait StakingApi<BlockHash, AccountId, Slash, Page> {
+	/// The slashes that were reported in `era` and are not applied yet.
+	#[rpc(name = "staking_pendingSlashes")]
+	fn pending_slashes(&self, era: EraIndex, at: Option<BlockHash>) -> Result<Vec<Slash>>;
+
+	/// The slashing spans of `stash`, most recent first.
+	#[rpc(name = "staking_slashingSpans")]
+	fn slashing_spans(&self, stash: AccountId, at: Option<BlockHash>) -> Result<Vec<SlashingSpan>>;
+
+	/// The page of the exposure of `validator` at `era` that contains `who`, along with its index.
+	#[rpc(name = "staking_exposurePageOf")]
+	fn exposure_page_of(
+		&self,
+		era: EraIndex,
+		validator: AccountId,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<Option<(PageIndex, Page)>>;
+}
+
+/// A struct that implements the [`StakingApi`].
+pub struct Staking<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Staking<C, B> {
+	/// Create new `Staking` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: message.into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+impl<C, Block, AccountId, Balance>
+	StakingApi<
+		<Block as BlockT>::Hash,
+		AccountId,
+		UnappliedSlash<AccountId, Balance>,
+		ExposurePage<AccountId, Balance>,
+	> for Staking<C, Block>
+where
+	Block: BlockT,
+	C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: StakingRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec,
+	Balance: Codec + HasCompact,
+{
+	fn pending_slashes(
+		&self,
+		era: EraIndex,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<UnappliedSlash<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.pending_slashes(&at, era)
+			.map_err(|e| runtime_error("Unable to query pending slashes.", e))
+	}
+
+	fn slashing_spans(
+		&self,
+		stash: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<SlashingSpan>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.slashing_spans(&at, stash)
+			.map_err(|e| runtime_error("Unable to query slashing spans.", e))
+	}
+
+	fn exposure_page_of(
+		&self,
+		era: EraIndex,
+		validator: AccountId,
+		who: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<(PageIndex, ExposurePage<AccountId, Balance>)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.exposure_page_of(&at, era, validator, who)
+			.map_err(|e| runtime_error("Unable to query exposure page.", e))
+	}
+}
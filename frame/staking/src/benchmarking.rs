@@ -46,9 +46,9 @@ const MAX_SLASHES: u32 = 1000;
 type MaxValidators<T> = <<T as Config>::BenchmarkingConfig as BenchmarkingConfig>::MaxValidators;
 type MaxNominators<T> = <<T as Config>::BenchmarkingConfig as BenchmarkingConfig>::MaxNominators;
 
-// Add slashing spans to a user account. Not relevant for actual use, only to benchmark
-// read and write operations.
-fn add_slashing_spans<T: Config>(who: &T::AccountId, spans: u32) {
+/// Add slashing spans to a user account. Not relevant for actual use, only to benchmark
+/// read and write operations.
+pub fn add_slashing_spans<T: Config>(who: &T::AccountId, spans: u32) {
 	if spans == 0 {
 		return
 	}
@@ -133,6 +133,20 @@ pub fn create_validator_with_nominators<T: Config>(
 	Ok((v_stash, nominators))
 }
 
+/// Make `claimer` the reward claimer of `validator` and all of its `nominators`, with the maximum
+/// commission.
+fn set_reward_claimers<T: Config>(
+	claimer: &T::AccountId,
+	validator: &T::AccountId,
+	nominators: &[(T::AccountId, T::AccountId)],
+) {
+	let commission = T::MaxClaimerCommission::get();
+	<RewardClaimers<T>>::insert(validator, (claimer.clone(), commission));
+	for (stash, _) in nominators {
+		<RewardClaimers<T>>::insert(stash, (claimer.clone(), commission));
+	}
+}
+
 struct ListScenario<T: Config> {
 	/// Stash that is expected to be moved.
 	origin_stash1: T::AccountId,
@@ -343,12 +357,25 @@ benchmarks! {
 		let stash = scenario.origin_stash1.clone();
 		assert!(T::SortedListProvider::contains(&stash));
 
-		let prefs = ValidatorPrefs::default();
+		// the stash was also a validator before, and raises its commission by the maximum
+		// allowed, which schedules the change.
+		let current_era = CurrentEra::<T>::get().unwrap_or(0);
+		CurrentEra::<T>::put(current_era);
+		LastCommission::<T>::insert(&stash, Perbill::zero());
+		let max_change = Perbill::from_percent(10);
+		MaxCommission::<T>::put(Perbill::one());
+		MaxCommissionChange::<T>::put(max_change);
+
+		let prefs = ValidatorPrefs { commission: max_change, ..Default::default() };
 		whitelist_account!(controller);
 	}: _(RawOrigin::Signed(controller), prefs)
 	verify {
 		assert!(Validators::<T>::contains_key(&stash));
 		assert!(!T::SortedListProvider::contains(&stash));
+		assert_eq!(
+			PendingCommission::<T>::get(&stash),
+			Some((max_change, current_era.saturating_add(2)))
+		);
 	}
 
 	kick {
@@ -471,6 +498,16 @@ benchmarks! {
 		assert_eq!(Payee::<T>::get(&stash), RewardDestination::Controller);
 	}
 
+	set_reward_claimer {
+		let (stash, controller) = create_stash_controller::<T>(USER_SEED, 100, Default::default())?;
+		let claimer: T::AccountId = account("claimer", USER_SEED, SEED);
+		let commission = T::MaxClaimerCommission::get();
+		whitelist_account!(controller);
+	}: _(RawOrigin::Signed(controller), Some((claimer.clone(), commission)))
+	verify {
+		assert_eq!(RewardClaimers::<T>::get(&stash), Some((claimer, commission)));
+	}
+
 	set_controller {
 		let (stash, _) = create_stash_controller::<T>(USER_SEED, 100, Default::default())?;
 		let new_controller = create_funded_user::<T>("new_controller", USER_SEED, 100);
@@ -547,10 +584,10 @@ benchmarks! {
 	}
 
 	payout_stakers_dead_controller {
-		let n in 1 .. T::MaxNominatorRewardedPerValidator::get() as u32;
+		let n in 1 .. T::MaxExposurePageSize::get() as u32;
 		let (validator, nominators) = create_validator_with_nominators::<T>(
 			n,
-			T::MaxNominatorRewardedPerValidator::get() as u32,
+			T::MaxExposurePageSize::get() as u32,
 			true,
 			RewardDestination::Controller,
 		)?;
@@ -559,15 +596,18 @@ benchmarks! {
 		// set the commission for this particular era as well.
 		<ErasValidatorPrefs<T>>::insert(current_era, validator.clone(), <Staking<T>>::validators(&validator));
 
-		let caller = whitelisted_caller();
+		// the caller is the reward claimer of every staker, which is the worst case.
+		let caller: T::AccountId = whitelisted_caller();
+		set_reward_claimers::<T>(&caller, &validator, &nominators);
 		let validator_controller = <Bonded<T>>::get(&validator).unwrap();
 		let balance_before = T::Currency::free_balance(&validator_controller);
 		for (_, controller) in &nominators {
 			let balance = T::Currency::free_balance(&controller);
 			ensure!(balance.is_zero(), "Controller has balance, but should be dead.");
 		}
-	}: payout_stakers(RawOrigin::Signed(caller), validator.clone(), current_era)
+	}: payout_stakers(RawOrigin::Signed(caller.clone()), validator.clone(), current_era, 0)
 	verify {
+		ensure!(!T::Currency::free_balance(&caller).is_zero(), "Claimer should be rewarded.");
 		let balance_after = T::Currency::free_balance(&validator_controller);
 		ensure!(
 			balance_before < balance_after,
@@ -580,10 +620,10 @@ benchmarks! {
 	}
 
 	payout_stakers_alive_staked {
-		let n in 1 .. T::MaxNominatorRewardedPerValidator::get() as u32;
+		let n in 1 .. T::MaxExposurePageSize::get() as u32;
 		let (validator, nominators) = create_validator_with_nominators::<T>(
 			n,
-			T::MaxNominatorRewardedPerValidator::get() as u32,
+			T::MaxExposurePageSize::get() as u32,
 			false,
 			RewardDestination::Staked,
 		)?;
@@ -592,15 +632,18 @@ benchmarks! {
 		// set the commission for this particular era as well.
 		<ErasValidatorPrefs<T>>::insert(current_era, validator.clone(), <Staking<T>>::validators(&validator));
 
-		let caller = whitelisted_caller();
+		// the caller is the reward claimer of every staker, which is the worst case.
+		let caller: T::AccountId = whitelisted_caller();
+		set_reward_claimers::<T>(&caller, &validator, &nominators);
 		let balance_before = T::Currency::free_balance(&validator);
 		let mut nominator_balances_before = Vec::new();
 		for (stash, _) in &nominators {
 			let balance = T::Currency::free_balance(&stash);
 			nominator_balances_before.push(balance);
 		}
-	}: payout_stakers(RawOrigin::Signed(caller), validator.clone(), current_era)
+	}: payout_stakers(RawOrigin::Signed(caller.clone()), validator.clone(), current_era, 0)
 	verify {
+		ensure!(!T::Currency::free_balance(&caller).is_zero(), "Claimer should be rewarded.");
 		let balance_after = T::Currency::free_balance(&validator);
 		ensure!(
 			balance_before < balance_after,
@@ -668,8 +711,7 @@ benchmarks! {
 		HistoryDepth::<T>::put(e);
 		CurrentEra::<T>::put(e);
 		for i in 0 .. e {
-			<ErasStakers<T>>::insert(i, T::AccountId::default(), Exposure::<T::AccountId, BalanceOf<T>>::default());
-			<ErasStakersClipped<T>>::insert(i, T::AccountId::default(), Exposure::<T::AccountId, BalanceOf<T>>::default());
+			<Staking<T>>::set_exposure(i, &T::AccountId::default(), Exposure::<T::AccountId, BalanceOf<T>>::default());
 			<ErasValidatorPrefs<T>>::insert(i, T::AccountId::default(), ValidatorPrefs::default());
 			<ErasValidatorReward<T>>::insert(i, BalanceOf::<T>::one());
 			<ErasRewardPoints<T>>::insert(i, EraRewardPoints::<T::AccountId>::default());
@@ -766,7 +808,7 @@ benchmarks! {
 		let caller: T::AccountId = whitelisted_caller();
 		let origin = RawOrigin::Signed(caller);
 		let calls: Vec<_> = payout_calls_arg.iter().map(|arg|
-			Call::<T>::payout_stakers { validator_stash: arg.0.clone(), era: arg.1 }.encode()
+			Call::<T>::payout_stakers { validator_stash: arg.0.clone(), era: arg.1, page: 0 }.encode()
 		).collect();
 	}: {
 		for call in calls {
@@ -796,7 +838,8 @@ benchmarks! {
 			&stash,
 			slash_amount,
 			&mut BalanceOf::<T>::zero(),
-			&mut NegativeImbalanceOf::<T>::zero()
+			&mut NegativeImbalanceOf::<T>::zero(),
+			EraIndex::zero(),
 		);
 	} verify {
 		let balance_after = T::Currency::free_balance(&stash);
@@ -843,7 +886,7 @@ benchmarks! {
 	}
 
 	set_staking_configs {
-		// This function always does the same thing... just write to 4 storage items.
+		// This function always does the same thing... just write to 8 storage items.
 	}: _(
 		RawOrigin::Root,
 		BalanceOf::<T>::max_value(),
@@ -851,7 +894,9 @@ benchmarks! {
 		Some(u32::MAX),
 		Some(u32::MAX),
 		Some(Percent::max_value()),
-		Perbill::max_value()
+		Perbill::max_value(),
+		Some(Perbill::max_value()),
+		Some(Perbill::max_value())
 	) verify {
 		assert_eq!(MinNominatorBond::<T>::get(), BalanceOf::<T>::max_value());
 		assert_eq!(MinValidatorBond::<T>::get(), BalanceOf::<T>::max_value());
@@ -859,6 +904,8 @@ benchmarks! {
 		assert_eq!(MaxValidatorsCount::<T>::get(), Some(u32::MAX));
 		assert_eq!(ChillThreshold::<T>::get(), Some(Percent::from_percent(100)));
 		assert_eq!(MinCommission::<T>::get(), Perbill::from_percent(100));
+		assert_eq!(MaxCommission::<T>::get(), Some(Perbill::from_percent(100)));
+		assert_eq!(MaxCommissionChange::<T>::get(), Some(Perbill::from_percent(100)));
 	}
 
 	chill_other {
@@ -882,6 +929,8 @@ benchmarks! {
 			Some(0),
 			Some(Percent::from_percent(0)),
 			Zero::zero(),
+			None,
+			None,
 		)?;
 
 		let caller = whitelisted_caller();
@@ -937,7 +986,7 @@ mod tests {
 
 			let (validator_stash, nominators) = create_validator_with_nominators::<Test>(
 				n,
-				<Test as Config>::MaxNominatorRewardedPerValidator::get() as u32,
+				<Test as Config>::MaxExposurePageSize::get() as u32,
 				false,
 				RewardDestination::Staked,
 			)
@@ -948,7 +997,12 @@ mod tests {
 			let current_era = CurrentEra::<Test>::get().unwrap();
 
 			let original_free_balance = Balances::free_balance(&validator_stash);
-			assert_ok!(Staking::payout_stakers(Origin::signed(1337), validator_stash, current_era));
+			assert_ok!(Staking::payout_stakers(
+				Origin::signed(1337),
+				validator_stash,
+				current_era,
+				0
+			));
 			let new_free_balance = Balances::free_balance(&validator_stash);
 
 			assert!(original_free_balance < new_free_balance);
@@ -962,7 +1016,7 @@ mod tests {
 
 			let (validator_stash, _nominators) = create_validator_with_nominators::<Test>(
 				n,
-				<Test as Config>::MaxNominatorRewardedPerValidator::get() as u32,
+				<Test as Config>::MaxExposurePageSize::get() as u32,
 				false,
 				RewardDestination::Staked,
 			)
@@ -88,6 +88,11 @@
 //! An account can become a validator candidate via the
 //! [`validate`](Call::validate) call.
 //!
+//! The commission that a validator can set is bounded by `MinCommission` and `MaxCommission`.
+//! If `MaxCommissionChange` is set, a validator can only increase its commission by that much at
+//! once, and the increase is only effective from the era after the next one. Until then, it is
+//! visible in `PendingCommission`, so that nominators can move their nominations in time.
+//!
 //! #### Nomination
 //!
 //! A **nominator** does not take any _direct_ role in maintaining the network, instead, it votes on
@@ -114,9 +119,16 @@
 //!
 //! Rewards must be claimed for each era before it gets too old by `$HISTORY_DEPTH` using the
 //! `payout_stakers` call. Any account can call `payout_stakers`, which pays the reward to the
-//! validator as well as its nominators. Only the [`Config::MaxNominatorRewardedPerValidator`]
-//! biggest stakers can claim their reward. This is to limit the i/o cost to mutate storage for each
-//! nominator's account.
+//! validator as well as its nominators. The exposure of a validator is split into pages of at most
+//! [`Config::MaxExposurePageSize`] nominators, and each page is paid out by a separate call to
+//! `payout_stakers`. This limits the i/o cost of a single payout while still allowing every
+//! nominator to receive their reward.
+//!
+//! Many pages, of different validators and eras, can be paid out at once with
+//! `payout_stakers_by_page`, up to [`Config::MaxPayoutsPerCall`]. A staker can also register a
+//! reward claimer with `set_reward_claimer`, e.g. a keeper that regularly compounds the rewards of
+//! many stakers. The claimer takes a share of the reward of that staker, at most
+//! [`Config::MaxClaimerCommission`], but only from the payouts that it triggers itself.
 //!
 //! Slashing can occur at any point in time, once misbehavior is reported. Once slashing is
 //! determined, a value is deducted from the balance of the validator and all the nominators who
@@ -338,6 +350,9 @@ pub type EraIndex = u32;
 /// Counter for the number of "reward" points earned by a given validator.
 pub type RewardPoint = u32;
 
+/// Counter for the index of a page of a validator's exposure.
+pub type PageIndex = u32;
+
 /// The balance type of this pallet.
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -522,45 +537,69 @@ where
 	/// of the slash in the case that the validator has less than `minimum_balance`
 	/// active funds. Returns the amount of funds actually slashed.
 	///
-	/// Slashes from `active` funds first, and then `unlocking`, starting with the
-	/// chunks that are closest to unlocking.
-	fn slash(&mut self, mut value: Balance, minimum_balance: Balance) -> Balance {
-		let pre_total = self.total;
-		let total = &mut self.total;
-		let active = &mut self.active;
-
-		let slash_out_of =
-			|total_remaining: &mut Balance, target: &mut Balance, value: &mut Balance| {
-				let mut slash_from_target = (*value).min(*target);
+	/// Chunks that unlock at or after `affected_from` were still bonded when the offence was
+	/// committed. If there are any, the slash is spread over `active` and those chunks in
+	/// proportion to their value. Otherwise the slash is taken from `active` first and then from
+	/// `unlocking`, starting with the chunks that are closest to unlocking.
+	fn slash(
+		&mut self,
+		slash_amount: Balance,
+		minimum_balance: Balance,
+		affected_from: EraIndex,
+	) -> Balance {
+		if slash_amount.is_zero() {
+			return Zero::zero()
+		}
 
-				if !slash_from_target.is_zero() {
-					*target -= slash_from_target;
+		let pre_total = self.total;
+		let mut remaining_slash = slash_amount;
+
+		let (maybe_proportional, slash_chunks_priority) =
+			match self.unlocking.iter().position(|chunk| chunk.era >= affected_from) {
+				Some(first_affected) => {
+					let affected_balance = self.unlocking[first_affected..]
+						.iter()
+						.fold(self.active, |sum, chunk| sum.saturating_add(chunk.value));
+					let ratio = Perbill::from_rational(slash_amount, affected_balance);
+					// Slash the affected chunks first, then fall back to the older ones if the
+					// affected funds are not enough.
+					let priority = (first_affected..self.unlocking.len())
+						.chain((0..first_affected).rev())
+						.collect::<Vec<_>>();
+					(Some(ratio), priority)
+				},
+				None => (None, (0..self.unlocking.len()).collect::<Vec<_>>()),
+			};
 
-					// Don't leave a dust balance in the staking system.
-					if *target <= minimum_balance {
-						slash_from_target += *target;
-						*value += sp_std::mem::replace(target, Zero::zero());
-					}
+		let total = &mut self.total;
+		let mut slash_out_of = |target: &mut Balance, remaining_slash: &mut Balance| {
+			let mut slash_from_target = match maybe_proportional {
+				Some(ratio) => ratio.mul_ceil(*target),
+				None => *remaining_slash,
+			}
+			.min(*target)
+			.min(*remaining_slash);
 
-					*total_remaining = total_remaining.saturating_sub(slash_from_target);
-					*value -= slash_from_target;
-				}
-			};
+			*target -= slash_from_target;
+			// Don't leave a dust balance in the staking system.
+			if *target <= minimum_balance {
+				slash_from_target += sp_std::mem::replace(target, Zero::zero());
+			}
 
-		slash_out_of(total, active, &mut value);
+			*total = total.saturating_sub(slash_from_target);
+			*remaining_slash = remaining_slash.saturating_sub(slash_from_target);
+		};
 
-		let i = self
-			.unlocking
-			.iter_mut()
-			.map(|chunk| {
-				slash_out_of(total, &mut chunk.value, &mut value);
-				chunk.value
-			})
-			.take_while(|value| value.is_zero()) // Take all fully-consumed chunks out.
-			.count();
+		slash_out_of(&mut self.active, &mut remaining_slash);
+		for i in slash_chunks_priority {
+			if remaining_slash.is_zero() {
+				break
+			}
+			slash_out_of(&mut self.unlocking[i].value, &mut remaining_slash);
+		}
 
 		// Kill all drained chunks.
-		let _ = self.unlocking.drain(..i);
+		self.unlocking.retain(|chunk| !chunk.value.is_zero());
 
 		pre_total.saturating_sub(*total)
 	}
@@ -584,6 +623,7 @@ pub struct Nominations<AccountId> {
 
 /// The amount of exposure (to slashing) than an individual nominator has.
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct IndividualExposure<AccountId, Balance: HasCompact> {
 	/// The stash account of the nominator in question.
 	pub who: AccountId,
@@ -607,20 +647,90 @@ pub struct Exposure<AccountId, Balance: HasCompact> {
 	pub others: Vec<IndividualExposure<AccountId, Balance>>,
 }
 
+impl<AccountId: Clone, Balance: HasCompact + AtLeast32BitUnsigned + Copy>
+	Exposure<AccountId, Balance>
+{
+	/// Split this exposure into its metadata and pages of at most `page_size` nominators.
+	///
+	/// Nominators are sorted by their exposed value, biggest first, so that the first page always
+	/// holds the biggest backers. There is always at least one page, even if it is empty.
+	pub fn into_pages(
+		mut self,
+		page_size: u32,
+	) -> (PagedExposureMetadata<Balance>, Vec<ExposurePage<AccountId, Balance>>) {
+		let page_size = page_size.max(1) as usize;
+		self.others.sort_by(|a, b| b.value.cmp(&a.value));
+
+		let nominator_count = self.others.len() as u32;
+		let mut pages = self
+			.others
+			.chunks(page_size)
+			.map(|chunk| ExposurePage {
+				page_total: chunk
+					.iter()
+					.fold(Zero::zero(), |acc: Balance, e| acc.saturating_add(e.value)),
+				others: chunk.to_vec(),
+			})
+			.collect::<Vec<_>>();
+		if pages.is_empty() {
+			pages.push(ExposurePage { page_total: Zero::zero(), others: Vec::new() });
+		}
+
+		let metadata = PagedExposureMetadata {
+			total: self.total,
+			own: self.own,
+			nominator_count,
+			page_count: pages.len() as PageIndex,
+		};
+		(metadata, pages)
+	}
+}
+
+/// The overview of a validator's exposure in an era, stored alongside its [`ExposurePage`]s.
+#[derive(
+	PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, RuntimeDebug, TypeInfo,
+)]
+pub struct PagedExposureMetadata<Balance: HasCompact> {
+	/// The total balance backing this validator.
+	#[codec(compact)]
+	pub total: Balance,
+	/// The validator's own stash that is exposed.
+	#[codec(compact)]
+	pub own: Balance,
+	/// Number of nominators backing this validator, across all pages.
+	pub nominator_count: u32,
+	/// Number of pages the nominators are split into.
+	pub page_count: PageIndex,
+}
+
+/// A page of the nominators backing a single validator in an era.
+#[derive(
+	PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, RuntimeDebug, TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExposurePage<AccountId, Balance: HasCompact> {
+	/// The sum of the stake of all nominators in this page.
+	#[codec(compact)]
+	pub page_total: Balance,
+	/// The portions of nominators stashes that are exposed.
+	pub others: Vec<IndividualExposure<AccountId, Balance>>,
+}
+
 /// A pending slash record. The value of the slash has been computed but not applied yet,
 /// rather deferred for several eras.
 #[derive(Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnappliedSlash<AccountId, Balance: HasCompact> {
 	/// The stash ID of the offending validator.
-	validator: AccountId,
+	pub validator: AccountId,
 	/// The validator's own slash.
-	own: Balance,
+	pub own: Balance,
 	/// All other slashed stakers and amounts.
-	others: Vec<(AccountId, Balance)>,
+	pub others: Vec<(AccountId, Balance)>,
 	/// Reporters of the offence; bounty payout recipients.
-	reporters: Vec<AccountId>,
+	pub reporters: Vec<AccountId>,
 	/// The amount of payout.
-	payout: Balance,
+	pub payout: Balance,
 }
 
 /// Means for interacting with a specialized version of the `session` trait.
@@ -731,6 +841,24 @@ impl Default for Forcing {
 	}
 }
 
+/// The progress of a paged voter snapshot, as requested through
+/// [`frame_election_provider_support::ElectionDataProvider::voters_paged`].
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum SnapshotStatus<AccountId> {
+	/// Paged snapshot is in progress, the `AccountId` was the last nominator processed.
+	Ongoing(AccountId),
+	/// All voters have been processed.
+	Consumed,
+	/// Waiting for the first page to be requested.
+	Waiting,
+}
+
+impl<AccountId> Default for SnapshotStatus<AccountId> {
+	fn default() -> Self {
+		SnapshotStatus::Waiting
+	}
+}
+
 // A value placed in storage that represents the current version of the Staking storage. This value
 // is used by the `on_runtime_upgrade` logic to determine whether we run storage migration logic.
 // This should match directly with the semantic versions of the Rust crate.
@@ -744,11 +872,12 @@ enum Releases {
 	V6_0_0, // removal of all storage associated with offchain phragmen.
 	V7_0_0, // keep track of number of nominators / validators in map
 	V8_0_0, // populate `SortedListProvider`.
+	V9_0_0, // paged era exposures.
 }
 
 impl Default for Releases {
 	fn default() -> Self {
-		Releases::V8_0_0
+		Releases::V9_0_0
 	}
 }
 
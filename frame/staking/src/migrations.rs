@@ -18,6 +18,93 @@
 
 use super::*;
 
+pub mod v9 {
+	use super::*;
+	use frame_support::{
+		generate_storage_alias, storage::migration::remove_storage_prefix, traits::Get,
+		weights::Weight, Twox64Concat,
+	};
+
+	generate_storage_alias!(
+		Staking,
+		ErasStakers<T: Config> => DoubleMap<
+			(Twox64Concat, EraIndex),
+			(Twox64Concat, T::AccountId),
+			Exposure<T::AccountId, BalanceOf<T>>
+		>
+	);
+
+	#[cfg(feature = "try-runtime")]
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		frame_support::ensure!(
+			StorageVersion::<T>::get() == Releases::V8_0_0,
+			"must upgrade linearly"
+		);
+
+		crate::log!(info, "staking paged exposure migration passes PRE migrate checks ✅",);
+		Ok(())
+	}
+
+	/// Migration of the era exposures to paged storage.
+	///
+	/// Every exposure in `ErasStakers` is split into [`ErasStakersOverview`] and pages of
+	/// [`ErasStakersPaged`], and the clipped exposures are removed altogether. This is run from
+	/// `on_runtime_upgrade` once the storage version is `V8_0_0`.
+	///
+	/// Exposures are only kept for the last `HistoryDepth` eras, so at most
+	/// `HistoryDepth * ValidatorCount` of them are migrated at once. Each costs one read and the
+	/// removal of the old entry, plus one write for its overview and one per page of at most
+	/// `MaxExposurePageSize` nominators. The returned weight accounts for all of them.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::<T>::get() == Releases::V8_0_0 {
+			log!(info, "migrating staking to Releases::V9_0_0");
+
+			let page_size = T::MaxExposurePageSize::get().max(1) as u64;
+			let mut migrated: u64 = 0;
+			let mut pages_written: u64 = 0;
+			for (era, stash, exposure) in ErasStakers::<T>::drain() {
+				let page_count =
+					((exposure.others.len() as u64 + page_size - 1) / page_size).max(1);
+				Pallet::<T>::set_exposure(era, &stash, exposure);
+				migrated += 1;
+				pages_written += page_count;
+			}
+			remove_storage_prefix(b"Staking", b"ErasStakersClipped", &[]);
+
+			StorageVersion::<T>::put(Releases::V9_0_0);
+			log!(
+				info,
+				"completed staking migration to Releases::V9_0_0 with {} exposures migrated",
+				migrated,
+			);
+
+			T::DbWeight::get().reads_writes(
+				migrated.saturating_add(1),
+				migrated.saturating_mul(2).saturating_add(pages_written).saturating_add(2),
+			)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		frame_support::ensure!(
+			ErasStakers::<T>::iter().next().is_none(),
+			"ErasStakers must be empty after migration"
+		);
+		for (era, stash, overview) in ErasStakersOverview::<T>::iter() {
+			frame_support::ensure!(
+				(0..overview.page_count.max(1))
+					.all(|page| ErasStakersPaged::<T>::contains_key((era, &stash, page))),
+				"missing exposure page after migration"
+			);
+		}
+		crate::log!(info, "staking paged exposure migration passes POST migrate checks ✅",);
+		Ok(())
+	}
+}
+
 pub mod v8 {
 	use frame_election_provider_support::SortedListProvider;
 	use frame_support::traits::Get;
@@ -17,9 +17,10 @@
 
 //! Implementations for the Staking FRAME Pallet.
 
+use codec::EncodeLike;
 use frame_election_provider_support::{
-	data_provider, ElectionDataProvider, ElectionProvider, SortedListProvider, Supports,
-	VoteWeight, VoteWeightProvider,
+	data_provider, ElectionDataProvider, ElectionProvider, ScoreProvider, SortedListProvider,
+	Supports, VoteWeight,
 };
 use frame_support::{
 	pallet_prelude::*,
@@ -29,22 +30,23 @@ use frame_support::{
 	},
 	weights::{Weight, WithPostDispatchInfo},
 };
-use frame_system::pallet_prelude::BlockNumberFor;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use pallet_session::historical;
 use sp_runtime::{
-	traits::{Bounded, Convert, SaturatedConversion, Saturating, Zero},
-	Perbill,
+	traits::{Bounded, Convert, SaturatedConversion, Saturating, StaticLookup, Zero},
+	DispatchError, Perbill,
 };
 use sp_staking::{
 	offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
-	SessionIndex,
+	SessionIndex, StakingInterface,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
 use crate::{
 	log, slashing, weights::WeightInfo, ActiveEraInfo, BalanceOf, EraIndex, EraPayout, Exposure,
-	ExposureOf, Forcing, IndividualExposure, Nominations, PositiveImbalanceOf, RewardDestination,
-	SessionInterface, StakingLedger, ValidatorPrefs,
+	ExposureOf, ExposurePage, Forcing, IndividualExposure, Nominations, PageIndex,
+	PositiveImbalanceOf, RewardDestination, SessionInterface, SnapshotStatus, StakingLedger,
+	UnappliedSlash, ValidatorPrefs,
 };
 
 use super::{pallet::*, STAKING_ID};
@@ -84,8 +86,10 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub(super) fn do_payout_stakers(
+		caller: &T::AccountId,
 		validator_stash: T::AccountId,
 		era: EraIndex,
+		page: PageIndex,
 	) -> DispatchResultWithPostInfo {
 		// Validate input data
 		let current_era = CurrentEra::<T>::get().ok_or_else(|| {
@@ -111,19 +115,42 @@ impl<T: Config> Pallet<T> {
 		})?;
 		let mut ledger = <Ledger<T>>::get(&controller).ok_or(Error::<T>::NotController)?;
 
+		// A validator without any exposure still has a single, empty page to claim.
+		let metadata = <ErasStakersOverview<T>>::get(&era, &ledger.stash).unwrap_or_default();
+		let page_count = metadata.page_count.max(1);
+		ensure!(
+			page < page_count,
+			Error::<T>::InvalidPage.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
+		);
+
 		ledger
 			.claimed_rewards
 			.retain(|&x| x >= current_era.saturating_sub(history_depth));
-		match ledger.claimed_rewards.binary_search(&era) {
+		let era_pos = match ledger.claimed_rewards.binary_search(&era) {
+			Ok(_) => Err(Error::<T>::AlreadyClaimed
+				.with_weight(T::WeightInfo::payout_stakers_alive_staked(0)))?,
+			Err(pos) => pos,
+		};
+
+		let mut claimed_pages = <ClaimedRewards<T>>::get(&era, &ledger.stash);
+		match claimed_pages.binary_search(&page) {
 			Ok(_) => Err(Error::<T>::AlreadyClaimed
 				.with_weight(T::WeightInfo::payout_stakers_alive_staked(0)))?,
-			Err(pos) => ledger.claimed_rewards.insert(pos, era),
+			Err(pos) => claimed_pages.insert(pos, page),
 		}
 
-		let exposure = <ErasStakersClipped<T>>::get(&era, &ledger.stash);
+		let exposure_page =
+			<ErasStakersPaged<T>>::get((&era, &ledger.stash, &page)).unwrap_or_default();
 
 		// Input data seems good, no errors allowed after this point
 
+		// Once every page is claimed, the era is recorded in the ledger like any fully paid era.
+		if claimed_pages.len() as PageIndex >= page_count {
+			ledger.claimed_rewards.insert(era_pos, era);
+			<ClaimedRewards<T>>::remove(&era, &ledger.stash);
+		} else {
+			<ClaimedRewards<T>>::insert(&era, &ledger.stash, &claimed_pages);
+		}
 		<Ledger<T>>::insert(&controller, &ledger);
 
 		// Get Era reward points. It has TOTAL and INDIVIDUAL
@@ -161,15 +188,26 @@ impl<T: Config> Pallet<T> {
 
 		let validator_leftover_payout = validator_total_payout - validator_commission_payout;
 		// Now let's calculate how this is split to the validator.
-		let validator_exposure_part = Perbill::from_rational(exposure.own, exposure.total);
+		let validator_exposure_part = Perbill::from_rational(metadata.own, metadata.total);
 		let validator_staking_payout = validator_exposure_part * validator_leftover_payout;
 
+		// The validator's own share is paid out pro rata to the nominator stake of each page.
+		let nominators_total = metadata.total.saturating_sub(metadata.own);
+		let page_stake_part = if nominators_total.is_zero() {
+			Perbill::one()
+		} else {
+			Perbill::from_rational(exposure_page.page_total, nominators_total)
+		};
+		let validator_page_payout =
+			page_stake_part * (validator_staking_payout + validator_commission_payout);
+
 		Self::deposit_event(Event::<T>::PayoutStarted(era, ledger.stash.clone()));
 
+		let validator_page_payout =
+			Self::pay_reward_claimer(caller, &ledger.stash, validator_page_payout);
+
 		// We can now make total validator payout:
-		if let Some(imbalance) =
-			Self::make_payout(&ledger.stash, validator_staking_payout + validator_commission_payout)
-		{
+		if let Some(imbalance) = Self::make_payout(&ledger.stash, validator_page_payout) {
 			Self::deposit_event(Event::<T>::Rewarded(ledger.stash, imbalance.peek()));
 		}
 
@@ -178,13 +216,14 @@ impl<T: Config> Pallet<T> {
 		// out, so we do not need to count their payout op.
 		let mut nominator_payout_count: u32 = 0;
 
-		// Lets now calculate how this is split to the nominators.
-		// Reward only the clipped exposures. Note this is not necessarily sorted.
-		for nominator in exposure.others.iter() {
-			let nominator_exposure_part = Perbill::from_rational(nominator.value, exposure.total);
+		// Lets now calculate how this is split to the nominators of this page.
+		for nominator in exposure_page.others.iter() {
+			let nominator_exposure_part = Perbill::from_rational(nominator.value, metadata.total);
 
 			let nominator_reward: BalanceOf<T> =
 				nominator_exposure_part * validator_leftover_payout;
+			let nominator_reward =
+				Self::pay_reward_claimer(caller, &nominator.who, nominator_reward);
 			// We can now make nominator payout:
 			if let Some(imbalance) = Self::make_payout(&nominator.who, nominator_reward) {
 				// Note: this logic does not count payouts for `RewardDestination::None`.
@@ -194,7 +233,7 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
-		debug_assert!(nominator_payout_count <= T::MaxNominatorRewardedPerValidator::get());
+		debug_assert!(nominator_payout_count <= T::MaxExposurePageSize::get());
 		Ok(Some(T::WeightInfo::payout_stakers_alive_staked(nominator_payout_count)).into())
 	}
 
@@ -218,6 +257,33 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Pay the reward claimer of `stash` its share of `reward`, if the claimer is `caller`.
+	///
+	/// Returns what is left of `reward` for `stash` itself.
+	fn pay_reward_claimer(
+		caller: &T::AccountId,
+		stash: &T::AccountId,
+		reward: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		let (claimer, commission) = match <RewardClaimers<T>>::get(stash) {
+			Some((claimer, commission)) if &claimer == caller => (claimer, commission),
+			_ => return reward,
+		};
+
+		let claimer_reward = commission * reward;
+		if claimer_reward.is_zero() {
+			return reward
+		}
+
+		// this can be zero if the claimer does not exist and the share is below the existential
+		// deposit, in which case the staker keeps the full reward.
+		let paid = T::Currency::deposit_creating(&claimer, claimer_reward).peek();
+		if !paid.is_zero() {
+			Self::deposit_event(Event::<T>::ClaimerRewarded(stash.clone(), claimer, paid));
+		}
+		reward.saturating_sub(paid)
+	}
+
 	/// Actually make a payment to a staker. This uses the currency's reward function
 	/// to pay the right payee for the given staker account.
 	fn make_payout(stash: &T::AccountId, amount: BalanceOf<T>) -> Option<PositiveImbalanceOf<T>> {
@@ -411,6 +477,9 @@ impl<T: Config> Pallet<T> {
 			Self::clear_era_information(old_era);
 		}
 
+		// The commission increases that are due take effect before the preferences are collected.
+		Self::apply_pending_commissions(new_planned_era);
+
 		// Set staking information for the new era.
 		Self::store_stakers_info(exposures, new_planned_era)
 	}
@@ -482,15 +551,7 @@ impl<T: Config> Pallet<T> {
 		let mut total_stake: BalanceOf<T> = Zero::zero();
 		exposures.into_iter().for_each(|(stash, exposure)| {
 			total_stake = total_stake.saturating_add(exposure.total);
-			<ErasStakers<T>>::insert(new_planned_era, &stash, &exposure);
-
-			let mut exposure_clipped = exposure;
-			let clipped_max_len = T::MaxNominatorRewardedPerValidator::get() as usize;
-			if exposure_clipped.others.len() > clipped_max_len {
-				exposure_clipped.others.sort_by(|a, b| a.value.cmp(&b.value).reverse());
-				exposure_clipped.others.truncate(clipped_max_len);
-			}
-			<ErasStakersClipped<T>>::insert(&new_planned_era, &stash, exposure_clipped);
+			Self::set_exposure(new_planned_era, &stash, exposure);
 		});
 
 		// Insert current era staking information
@@ -566,6 +627,8 @@ impl<T: Config> Pallet<T> {
 		<Ledger<T>>::remove(&controller);
 
 		<Payee<T>>::remove(stash);
+		<RewardClaimers<T>>::remove(stash);
+		<LastCommission<T>>::remove(stash);
 		Self::do_remove_validator(stash);
 		Self::do_remove_nominator(stash);
 
@@ -574,10 +637,75 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Store the exposure of `stash` at `era`, split into pages of `T::MaxExposurePageSize`.
+	pub fn set_exposure(
+		era: EraIndex,
+		stash: &T::AccountId,
+		exposure: Exposure<T::AccountId, BalanceOf<T>>,
+	) {
+		let (metadata, pages) = exposure.into_pages(T::MaxExposurePageSize::get());
+		<ErasStakersOverview<T>>::insert(era, stash, &metadata);
+		for (page, exposure_page) in pages.into_iter().enumerate() {
+			<ErasStakersPaged<T>>::insert((era, stash, page as PageIndex), exposure_page);
+		}
+	}
+
+	/// The full exposure of `stash` at `era`, gathered from all of its pages.
+	///
+	/// If the exposure hasn't been set or has been removed then an empty exposure is returned.
+	pub fn eras_stakers(
+		era: EraIndex,
+		stash: impl EncodeLike<T::AccountId> + Clone,
+	) -> Exposure<T::AccountId, BalanceOf<T>> {
+		let metadata = match <ErasStakersOverview<T>>::get(&era, stash.clone()) {
+			Some(metadata) => metadata,
+			None => return Default::default(),
+		};
+		let others = (0..metadata.page_count)
+			.filter_map(|page| <ErasStakersPaged<T>>::get((&era, stash.clone(), &page)))
+			.flat_map(|exposure_page| exposure_page.others)
+			.collect();
+		Exposure { total: metadata.total, own: metadata.own, others }
+	}
+
+	/// The slashes that were reported in `era`, and are deferred to be applied
+	/// `T::SlashDeferDuration` eras later.
+	pub fn pending_slashes(era: EraIndex) -> Vec<UnappliedSlash<T::AccountId, BalanceOf<T>>> {
+		<UnappliedSlashes<T>>::get(&era)
+	}
+
+	/// The slashing spans of `stash`, most recent first.
+	///
+	/// Empty if `stash` has never been slashed.
+	pub fn slashing_spans_of(stash: &T::AccountId) -> Vec<slashing::SlashingSpan> {
+		<SlashingSpans<T>>::get(stash)
+			.map(|spans| spans.iter().collect())
+			.unwrap_or_default()
+	}
+
+	/// The page of the exposure of `validator` at `era` that contains `who`, along with its index.
+	///
+	/// This is the page from which the slash of the nominator `who` is computed, if `validator`
+	/// is slashed for an offence committed in `era`. `None` if `who` was not exposed to
+	/// `validator` at `era`, or if the exposure is no longer stored.
+	pub fn exposure_page_of(
+		era: EraIndex,
+		validator: &T::AccountId,
+		who: &T::AccountId,
+	) -> Option<(PageIndex, ExposurePage<T::AccountId, BalanceOf<T>>)> {
+		let metadata = <ErasStakersOverview<T>>::get(&era, validator)?;
+		(0..metadata.page_count).find_map(|page| {
+			<ErasStakersPaged<T>>::get((&era, validator, &page))
+				.filter(|exposure_page| exposure_page.others.iter().any(|e| &e.who == who))
+				.map(|exposure_page| (page, exposure_page))
+		})
+	}
+
 	/// Clear all era information for given era.
 	pub(crate) fn clear_era_information(era_index: EraIndex) {
-		<ErasStakers<T>>::remove_prefix(era_index, None);
-		<ErasStakersClipped<T>>::remove_prefix(era_index, None);
+		<ErasStakersOverview<T>>::remove_prefix(era_index, None);
+		<ErasStakersPaged<T>>::remove_prefix((era_index,), None);
+		<ClaimedRewards<T>>::remove_prefix(era_index, None);
 		<ErasValidatorPrefs<T>>::remove_prefix(era_index, None);
 		<ErasValidatorReward<T>>::remove(era_index);
 		<ErasRewardPoints<T>>::remove(era_index);
@@ -594,7 +722,9 @@ impl<T: Config> Pallet<T> {
 				for era in (*earliest)..keep_from {
 					let era_slashes = <Self as Store>::UnappliedSlashes::take(&era);
 					for slash in era_slashes {
-						slashing::apply_slash::<T>(slash);
+						// Deferred slashes are stored by the era they were reported in, which is
+						// the closest bound on the offence era that is still known here.
+						slashing::apply_slash::<T>(slash, era);
 					}
 				}
 
@@ -639,7 +769,7 @@ impl<T: Config> Pallet<T> {
 		controller: T::AccountId,
 		exposure: Exposure<T::AccountId, BalanceOf<T>>,
 	) {
-		<ErasStakers<T>>::insert(&current_era, &controller, &exposure);
+		Self::set_exposure(current_era, &controller, exposure);
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -664,9 +794,24 @@ impl<T: Config> Pallet<T> {
 	pub fn get_npos_voters(
 		maybe_max_len: Option<usize>,
 	) -> Vec<(T::AccountId, VoteWeight, Vec<T::AccountId>)> {
+		Self::get_npos_voters_from(maybe_max_len, true, T::SortedListProvider::iter()).0
+	}
+
+	/// Same as [`Self::get_npos_voters`], but the nominators are taken from `nominators_iter` and
+	/// the validators' self votes are only included if `include_validators` is set.
+	///
+	/// Alongside the voters, returns the last nominator that was read from `nominators_iter`, if
+	/// any, and whether `nominators_iter` was exhausted. These are used to resume a paged voter
+	/// snapshot from where the previous page left off.
+	pub(crate) fn get_npos_voters_from(
+		maybe_max_len: Option<usize>,
+		include_validators: bool,
+		mut nominators_iter: Box<dyn Iterator<Item = T::AccountId>>,
+	) -> (Vec<(T::AccountId, VoteWeight, Vec<T::AccountId>)>, Option<T::AccountId>, bool) {
 		let max_allowed_len = {
 			let nominator_count = CounterForNominators::<T>::get() as usize;
-			let validator_count = CounterForValidators::<T>::get() as usize;
+			let validator_count =
+				if include_validators { CounterForValidators::<T>::get() as usize } else { 0 };
 			let all_voter_count = validator_count.saturating_add(nominator_count);
 			maybe_max_len.unwrap_or(all_voter_count).min(all_voter_count)
 		};
@@ -675,12 +820,14 @@ impl<T: Config> Pallet<T> {
 
 		// first, grab all validators in no particular order, capped by the maximum allowed length.
 		let mut validators_taken = 0u32;
-		for (validator, _) in <Validators<T>>::iter().take(max_allowed_len) {
-			// Append self vote.
-			let self_vote =
-				(validator.clone(), Self::weight_of(&validator), vec![validator.clone()]);
-			all_voters.push(self_vote);
-			validators_taken.saturating_inc();
+		if include_validators {
+			for (validator, _) in <Validators<T>>::iter().take(max_allowed_len) {
+				// Append self vote.
+				let self_vote =
+					(validator.clone(), Self::weight_of(&validator), vec![validator.clone()]);
+				all_voters.push(self_vote);
+				validators_taken.saturating_inc();
+			}
 		}
 
 		// .. and grab whatever we have left from nominators.
@@ -695,14 +842,19 @@ impl<T: Config> Pallet<T> {
 		// cache the total-issuance once in this function
 		let weight_of = Self::weight_of_fn();
 
-		let mut nominators_iter = T::SortedListProvider::iter();
+		let mut last_seen = None;
+		let mut exhausted = false;
 		while nominators_taken < nominators_quota && nominators_seen < nominators_quota * 2 {
 			let nominator = match nominators_iter.next() {
 				Some(nominator) => {
 					nominators_seen.saturating_inc();
+					last_seen = Some(nominator.clone());
 					nominator
 				},
-				None => break,
+				None => {
+					exhausted = true;
+					break
+				},
 			};
 
 			if let Some(Nominations { submitted_in, mut targets, suppressed: _ }) =
@@ -744,7 +896,7 @@ impl<T: Config> Pallet<T> {
 			validators_taken,
 			nominators_taken
 		);
-		all_voters
+		(all_voters, last_seen, exhausted)
 	}
 
 	/// Get the targets for an upcoming npos election.
@@ -822,6 +974,7 @@ impl<T: Config> Pallet<T> {
 		if !Validators::<T>::contains_key(who) {
 			CounterForValidators::<T>::mutate(|x| x.saturating_inc())
 		}
+		LastCommission::<T>::insert(who, prefs.commission);
 		Validators::<T>::insert(who, prefs);
 	}
 
@@ -836,6 +989,7 @@ impl<T: Config> Pallet<T> {
 	pub fn do_remove_validator(who: &T::AccountId) -> bool {
 		if Validators::<T>::contains_key(who) {
 			Validators::<T>::remove(who);
+			PendingCommission::<T>::remove(who);
 			CounterForValidators::<T>::mutate(|x| x.saturating_dec());
 			true
 		} else {
@@ -843,6 +997,72 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Throttle the commission increase requested by the validator `stash` in `prefs`, if
+	/// `MaxCommissionChange` is set.
+	///
+	/// The increase is limited to `MaxCommissionChange` above the commission that was last
+	/// effective, and scheduled in `PendingCommission` instead of being applied right away. The
+	/// returned preferences keep the currently effective commission in that case. Any other
+	/// change cancels the pending increase of `stash`, if any.
+	pub(crate) fn throttle_commission(
+		stash: &T::AccountId,
+		mut prefs: ValidatorPrefs,
+	) -> Result<ValidatorPrefs, DispatchError> {
+		let max_change = match MaxCommissionChange::<T>::get() {
+			Some(max_change) => max_change,
+			None => {
+				PendingCommission::<T>::remove(stash);
+				return Ok(prefs)
+			},
+		};
+
+		let current_era = Self::current_era().unwrap_or(0);
+		// validators that have not changed their preferences since `LastCommission` was introduced
+		// are only found in `Validators`.
+		let current_commission = LastCommission::<T>::get(stash).or_else(|| {
+			Validators::<T>::contains_key(stash).then(|| Self::validators(stash).commission)
+		});
+
+		match current_commission {
+			Some(current) if prefs.commission > current => {
+				ensure!(
+					prefs.commission.saturating_sub(current) <= max_change,
+					Error::<T>::CommissionChangeTooHigh
+				);
+				// the validator preferences of the next planned era are collected with the
+				// current commission, nominators have until then to react.
+				let effective_era = current_era.saturating_add(2);
+				PendingCommission::<T>::insert(stash, (prefs.commission, effective_era));
+				Self::deposit_event(Event::<T>::CommissionChangeScheduled(
+					stash.clone(),
+					prefs.commission,
+					effective_era,
+				));
+				prefs.commission = current;
+			},
+			_ => PendingCommission::<T>::remove(stash),
+		}
+
+		Ok(prefs)
+	}
+
+	/// Apply the pending commission increases that are effective at `era`.
+	///
+	/// Called when `era` is planned, before the preferences of its validators are collected.
+	pub(crate) fn apply_pending_commissions(era: EraIndex) {
+		let due = PendingCommission::<T>::iter()
+			.filter(|(_, (_, effective_era))| *effective_era <= era)
+			.collect::<Vec<_>>();
+		for (stash, (commission, _)) in due {
+			PendingCommission::<T>::remove(&stash);
+			// pending increases of validators that chilled are removed with them.
+			if Validators::<T>::contains_key(&stash) {
+				let prefs = ValidatorPrefs { commission, ..Self::validators(&stash) };
+				Self::do_add_validator(&stash, prefs);
+			}
+		}
+	}
+
 	/// Register some amount of weight directly with the system pallet.
 	///
 	/// This is always mandatory weight.
@@ -880,6 +1100,37 @@ impl<T: Config> ElectionDataProvider<T::AccountId, BlockNumberFor<T>> for Pallet
 		Ok(voters)
 	}
 
+	fn voters_paged(
+		maybe_max_len: Option<usize>,
+		page: PageIndex,
+	) -> data_provider::Result<Vec<(T::AccountId, VoteWeight, Vec<T::AccountId>)>> {
+		// the first page always restarts the snapshot and carries the validators' self votes.
+		let status =
+			if page == 0 { SnapshotStatus::Waiting } else { VoterSnapshotStatus::<T>::get() };
+
+		let nominators_iter = match status {
+			// no nominator has been read yet, start from the top of the list.
+			SnapshotStatus::Waiting => T::SortedListProvider::iter(),
+			SnapshotStatus::Ongoing(last) => T::SortedListProvider::iter_from(&last)
+				.map_err(|_| "last nominator of the previous page not found")?,
+			SnapshotStatus::Consumed => return Ok(Default::default()),
+		};
+
+		let (voters, last_seen, exhausted) =
+			Self::get_npos_voters_from(maybe_max_len, page == 0, nominators_iter);
+		debug_assert!(maybe_max_len.map_or(true, |max| voters.len() <= max));
+
+		let next_status = match (exhausted, last_seen) {
+			(true, _) => SnapshotStatus::Consumed,
+			(false, Some(last)) => SnapshotStatus::Ongoing(last),
+			// the page was filled up before any nominator was read.
+			(false, None) => SnapshotStatus::Waiting,
+		};
+		VoterSnapshotStatus::<T>::put(next_status);
+
+		Ok(voters)
+	}
+
 	fn targets(maybe_max_len: Option<usize>) -> data_provider::Result<Vec<T::AccountId>> {
 		let target_count = CounterForValidators::<T>::get();
 
@@ -1222,7 +1473,7 @@ where
 				unapplied.reporters = details.reporters.clone();
 				if slash_defer_duration == 0 {
 					// Apply right away.
-					slashing::apply_slash::<T>(unapplied);
+					slashing::apply_slash::<T>(unapplied, slash_era);
 					{
 						let slash_cost = (6, 5);
 						let reward_cost = (2, 2);
@@ -1247,13 +1498,15 @@ where
 	}
 }
 
-impl<T: Config> VoteWeightProvider<T::AccountId> for Pallet<T> {
-	fn vote_weight(who: &T::AccountId) -> VoteWeight {
+impl<T: Config> ScoreProvider<T::AccountId> for Pallet<T> {
+	type Score = VoteWeight;
+
+	fn score(who: &T::AccountId) -> Self::Score {
 		Self::weight_of(who)
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
-	fn set_vote_weight_of(who: &T::AccountId, weight: VoteWeight) {
+	fn set_score_of(who: &T::AccountId, weight: Self::Score) {
 		// this will clearly results in an inconsistent state, but it should not matter for a
 		// benchmark.
 		let active: BalanceOf<T> = weight.try_into().map_err(|_| ()).unwrap();
@@ -1283,6 +1536,16 @@ impl<T: Config> SortedListProvider<T::AccountId> for UseNominatorsMap<T> {
 	fn iter() -> Box<dyn Iterator<Item = T::AccountId>> {
 		Box::new(Nominators::<T>::iter().map(|(n, _)| n))
 	}
+	fn iter_from(
+		start: &T::AccountId,
+	) -> Result<Box<dyn Iterator<Item = T::AccountId>>, Self::Error> {
+		if Nominators::<T>::contains_key(start) {
+			let start_key = Nominators::<T>::hashed_key_for(start);
+			Ok(Box::new(Nominators::<T>::iter_from(start_key).map(|(n, _)| n)))
+		} else {
+			Err(())
+		}
+	}
 	fn count() -> u32 {
 		CounterForNominators::<T>::get()
 	}
@@ -1319,3 +1582,85 @@ impl<T: Config> SortedListProvider<T::AccountId> for UseNominatorsMap<T> {
 		}
 	}
 }
+
+impl<T: Config> StakingInterface for Pallet<T> {
+	type AccountId = T::AccountId;
+	type Balance = BalanceOf<T>;
+
+	fn minimum_bond() -> Self::Balance {
+		MinNominatorBond::<T>::get()
+	}
+
+	fn bonding_duration() -> EraIndex {
+		T::BondingDuration::get()
+	}
+
+	fn current_era() -> EraIndex {
+		Self::current_era().unwrap_or(Zero::zero())
+	}
+
+	fn active_stake(controller: &Self::AccountId) -> Option<Self::Balance> {
+		Self::ledger(controller).map(|l| l.active)
+	}
+
+	fn total_stake(controller: &Self::AccountId) -> Option<Self::Balance> {
+		Self::ledger(controller).map(|l| l.total)
+	}
+
+	fn bond(
+		stash: Self::AccountId,
+		controller: Self::AccountId,
+		value: Self::Balance,
+		payee: Self::AccountId,
+	) -> DispatchResult {
+		Self::bond(
+			RawOrigin::Signed(stash).into(),
+			T::Lookup::unlookup(controller),
+			value,
+			RewardDestination::Account(payee),
+		)
+	}
+
+	fn nominate(controller: Self::AccountId, targets: Vec<Self::AccountId>) -> DispatchResult {
+		let targets = targets.into_iter().map(T::Lookup::unlookup).collect::<Vec<_>>();
+		Self::nominate(RawOrigin::Signed(controller).into(), targets)
+	}
+
+	fn chill(controller: Self::AccountId) -> DispatchResult {
+		Self::chill(RawOrigin::Signed(controller).into())
+	}
+
+	fn bond_extra(stash: Self::AccountId, extra: Self::Balance) -> DispatchResult {
+		Self::bond_extra(RawOrigin::Signed(stash).into(), extra)
+	}
+
+	fn unbond(controller: Self::AccountId, value: Self::Balance) -> DispatchResult {
+		Self::unbond(RawOrigin::Signed(controller).into(), value)
+	}
+
+	fn withdraw_unbonded(
+		controller: Self::AccountId,
+		num_slashing_spans: u32,
+	) -> Result<bool, DispatchError> {
+		Self::withdraw_unbonded(RawOrigin::Signed(controller.clone()).into(), num_slashing_spans)
+			.map(|_| !Ledger::<T>::contains_key(&controller))
+			.map_err(|with_post| with_post.error)
+	}
+
+	fn stash_by_ctrl(controller: &Self::AccountId) -> Result<Self::AccountId, DispatchError> {
+		Self::ledger(controller)
+			.map(|l| l.stash)
+			.ok_or_else(|| Error::<T>::NotController.into())
+	}
+
+	fn force_unstake(stash: Self::AccountId) -> DispatchResult {
+		let num_slashing_spans = SlashingSpans::<T>::get(&stash).map_or(0, |s| s.iter().count());
+		Self::force_unstake(RawOrigin::Root.into(), stash, num_slashing_spans as u32)
+	}
+
+	fn is_exposed_in_era(who: &Self::AccountId, era: &EraIndex) -> bool {
+		ErasStakersOverview::<T>::iter_key_prefix(era).any(|validator| validator == *who) ||
+			ErasStakersPaged::<T>::iter_prefix_values((era,))
+				.any(|page| page.others.iter().any(|i| i.who == *who))
+	}
+}
@@ -40,9 +40,9 @@ pub use impls::*;
 
 use crate::{
 	log, migrations, slashing, weights::WeightInfo, ActiveEraInfo, BalanceOf, EraIndex, EraPayout,
-	EraRewardPoints, Exposure, Forcing, NegativeImbalanceOf, Nominations, PositiveImbalanceOf,
-	Releases, RewardDestination, SessionInterface, StakingLedger, UnappliedSlash, UnlockChunk,
-	ValidatorPrefs,
+	EraRewardPoints, ExposurePage, Forcing, NegativeImbalanceOf, Nominations, PageIndex,
+	PagedExposureMetadata, PositiveImbalanceOf, Releases, RewardDestination, SessionInterface,
+	SnapshotStatus, StakingLedger, UnappliedSlash, UnlockChunk, ValidatorPrefs,
 };
 
 pub const MAX_UNLOCKING_CHUNKS: usize = 32;
@@ -137,12 +137,23 @@ pub mod pallet {
 		/// guess.
 		type NextNewSession: EstimateNextNewSession<Self::BlockNumber>;
 
-		/// The maximum number of nominators rewarded for each validator.
+		/// The maximum number of nominators in a single page of a validator's exposure.
 		///
-		/// For each validator only the `$MaxNominatorRewardedPerValidator` biggest stakers can
-		/// claim their reward. This used to limit the i/o cost for the nominator payout.
+		/// The nominators of each validator are split into pages of this size, and each page is
+		/// paid out separately. This is used to limit the i/o cost for the nominator payout.
 		#[pallet::constant]
-		type MaxNominatorRewardedPerValidator: Get<u32>;
+		type MaxExposurePageSize: Get<u32>;
+
+		/// The maximum number of exposure pages that can be paid out by a single call to
+		/// [`Call::payout_stakers_by_page`].
+		#[pallet::constant]
+		type MaxPayoutsPerCall: Get<u32>;
+
+		/// The maximum share of its rewards that a staker can grant to its reward claimer.
+		///
+		/// See [`Call::set_reward_claimer`].
+		#[pallet::constant]
+		type MaxClaimerCommission: Get<Perbill>;
 
 		/// The fraction of the validator set that is safe to be offending.
 		/// After the threshold is reached a new era will be forced.
@@ -153,6 +164,10 @@ pub mod pallet {
 		/// the bags-list is not desired, [`impls::UseNominatorsMap`] is likely the desired option.
 		type SortedListProvider: SortedListProvider<Self::AccountId>;
 
+		/// A hook called when any staker is slashed. Mostly likely this can be a no-op unless
+		/// other pallets exist that are affected by slashing per-staker.
+		type OnStakerSlash: sp_staking::OnStakerSlash<Self::AccountId, BalanceOf<Self>>;
+
 		/// Some parameters of the benchmarking.
 		type BenchmarkingConfig: BenchmarkingConfig;
 
@@ -221,6 +236,37 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type MinCommission<T: Config> = StorageValue<_, Perbill, ValueQuery>;
 
+	/// The maximum amount of commission that validators can set.
+	///
+	/// When this value is not set, no limit is enforced.
+	#[pallet::storage]
+	pub type MaxCommission<T: Config> = StorageValue<_, Perbill, OptionQuery>;
+
+	/// The maximum amount by which a validator can increase its commission per era.
+	///
+	/// When this value is set, commission increases do not take effect immediately, but are
+	/// scheduled in [`PendingCommission`], leaving nominators time to react. When it is not set,
+	/// commission changes are neither limited nor delayed.
+	#[pallet::storage]
+	pub type MaxCommissionChange<T: Config> = StorageValue<_, Perbill, OptionQuery>;
+
+	/// Commission increases that have been requested by validators, but are not effective yet.
+	///
+	/// Maps a validator stash to its new commission and the first era whose validator preferences
+	/// include it.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_commission)]
+	pub type PendingCommission<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (Perbill, EraIndex)>;
+
+	/// The commission that is or was last effective for a validator.
+	///
+	/// It is kept when the validator chills, and used as the base from which commission increases
+	/// are limited by [`MaxCommissionChange`], so that chilling cannot be used to bypass the limit.
+	#[pallet::storage]
+	#[pallet::getter(fn last_commission)]
+	pub type LastCommission<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Perbill>;
+
 	/// Map from all (unlocked) "controller" accounts to the info regarding the staking.
 	#[pallet::storage]
 	#[pallet::getter(fn ledger)]
@@ -233,6 +279,15 @@ pub mod pallet {
 	pub type Payee<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, RewardDestination<T::AccountId>, ValueQuery>;
 
+	/// The account that claims the rewards of a staker on its behalf, and the share of these
+	/// rewards that it takes for doing so. Keyed by stash.
+	///
+	/// The share is only taken from payouts that are triggered by the claimer itself.
+	#[pallet::storage]
+	#[pallet::getter(fn reward_claimer)]
+	pub type RewardClaimers<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (T::AccountId, Perbill)>;
+
 	/// The map from (wannabe) validator stash key to the preferences of that validator.
 	///
 	/// When updating this storage item, you must also update the `CounterForValidators`.
@@ -293,48 +348,68 @@ pub mod pallet {
 	#[pallet::getter(fn eras_start_session_index)]
 	pub type ErasStartSessionIndex<T> = StorageMap<_, Twox64Concat, EraIndex, SessionIndex>;
 
-	/// Exposure of validator at era.
+	/// Summary of the exposure of a validator at era.
+	///
+	/// This holds the total and own stake of the validator, as well as the number of nominators
+	/// backing it and the number of pages they are split into in [`ErasStakersPaged`].
 	///
 	/// This is keyed first by the era index to allow bulk deletion and then the stash account.
 	///
 	/// Is it removed after `HISTORY_DEPTH` eras.
-	/// If stakers hasn't been set or has been removed then empty exposure is returned.
 	#[pallet::storage]
-	#[pallet::getter(fn eras_stakers)]
-	pub type ErasStakers<T: Config> = StorageDoubleMap<
+	#[pallet::getter(fn eras_stakers_overview)]
+	pub type ErasStakersOverview<T: Config> = StorageDoubleMap<
 		_,
 		Twox64Concat,
 		EraIndex,
 		Twox64Concat,
 		T::AccountId,
-		Exposure<T::AccountId, BalanceOf<T>>,
-		ValueQuery,
+		PagedExposureMetadata<BalanceOf<T>>,
+		OptionQuery,
 	>;
 
-	/// Clipped Exposure of validator at era.
+	/// Paged exposure of validator at era.
 	///
-	/// This is similar to [`ErasStakers`] but number of nominators exposed is reduced to the
-	/// `T::MaxNominatorRewardedPerValidator` biggest stakers.
-	/// (Note: the field `total` and `own` of the exposure remains unchanged).
-	/// This is used to limit the i/o cost for the nominator payout.
+	/// The nominators backing a validator are sorted by their stake and split into pages of at
+	/// most `T::MaxExposurePageSize` nominators. Each page is paid out separately, which limits the
+	/// i/o cost of a single nominator payout.
 	///
-	/// This is keyed fist by the era index to allow bulk deletion and then the stash account.
+	/// This is keyed first by the era index to allow bulk deletion, then the stash account and
+	/// finally the page.
 	///
 	/// Is it removed after `HISTORY_DEPTH` eras.
-	/// If stakers hasn't been set or has been removed then empty exposure is returned.
 	#[pallet::storage]
-	#[pallet::getter(fn eras_stakers_clipped)]
-	pub type ErasStakersClipped<T: Config> = StorageDoubleMap<
+	#[pallet::getter(fn eras_stakers_paged)]
+	pub type ErasStakersPaged<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, EraIndex>,
+			NMapKey<Twox64Concat, T::AccountId>,
+			NMapKey<Twox64Concat, PageIndex>,
+		),
+		ExposurePage<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The pages of a validator's exposure that have already been paid out at era.
+	///
+	/// Once all pages of an era are claimed, the era is recorded in the `claimed_rewards` of the
+	/// validator's ledger and the entry is removed.
+	///
+	/// Is it removed after `HISTORY_DEPTH` eras.
+	#[pallet::storage]
+	#[pallet::getter(fn claimed_rewards)]
+	pub type ClaimedRewards<T: Config> = StorageDoubleMap<
 		_,
 		Twox64Concat,
 		EraIndex,
 		Twox64Concat,
 		T::AccountId,
-		Exposure<T::AccountId, BalanceOf<T>>,
+		Vec<PageIndex>,
 		ValueQuery,
 	>;
 
-	/// Similar to `ErasStakers`, this holds the preferences of validators.
+	/// Similar to `ErasStakersOverview`, this holds the preferences of validators.
 	///
 	/// This is keyed first by the era index to allow bulk deletion and then the stash account.
 	///
@@ -479,6 +554,13 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type ChillThreshold<T: Config> = StorageValue<_, Percent, OptionQuery>;
 
+	/// The progress of the voter snapshot that is taken in pages by the election provider.
+	///
+	/// Reset whenever the first page is requested.
+	#[pallet::storage]
+	pub(crate) type VoterSnapshotStatus<T: Config> =
+		StorageValue<_, SnapshotStatus<T::AccountId>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub history_depth: u32,
@@ -612,6 +694,15 @@ pub mod pallet {
 		Chilled(T::AccountId),
 		/// The stakers' rewards are getting paid. \[era_index, validator_stash\]
 		PayoutStarted(EraIndex, T::AccountId),
+		/// A reward claimer has taken its share of the reward of a staker.
+		/// \[stash, claimer, amount\]
+		ClaimerRewarded(T::AccountId, T::AccountId, BalanceOf<T>),
+		/// A validator has scheduled an increase of its commission, which will take effect from
+		/// the given era. \[stash, commission, era_index\]
+		CommissionChangeScheduled(T::AccountId, Perbill, EraIndex),
+		/// A page of a batched payout could not be paid out and was skipped.
+		/// \[validator_stash, era_index, page, error\]
+		PayoutFailed(T::AccountId, EraIndex, PageIndex, DispatchError),
 	}
 
 	#[pallet::error]
@@ -668,24 +759,43 @@ pub mod pallet {
 		TooManyValidators,
 		/// Commission is too low. Must be at least `MinCommission`.
 		CommissionTooLow,
+		/// Commission is too high. Must be at most `MaxCommission`.
+		CommissionTooHigh,
+		/// Commission is increased by too much at once. Must be at most `MaxCommissionChange`.
+		CommissionChangeTooHigh,
+		/// The page does not exist in the exposure of this validator.
+		InvalidPage,
+		/// Too many payouts were requested in a single call. Must be at most `MaxPayoutsPerCall`.
+		TooManyPayouts,
+		/// The commission of the reward claimer is too high. Must be at most
+		/// `MaxClaimerCommission`.
+		ClaimerCommissionTooHigh,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_runtime_upgrade() -> Weight {
-			if StorageVersion::<T>::get() == Releases::V6_0_0 {
-				migrations::v7::migrate::<T>()
-			} else {
-				T::DbWeight::get().reads(1)
+			match StorageVersion::<T>::get() {
+				Releases::V6_0_0 => migrations::v7::migrate::<T>(),
+				Releases::V8_0_0 => migrations::v9::migrate::<T>(),
+				_ => T::DbWeight::get().reads(1),
 			}
 		}
 
 		#[cfg(feature = "try-runtime")]
 		fn pre_upgrade() -> Result<(), &'static str> {
-			if StorageVersion::<T>::get() == Releases::V6_0_0 {
-				migrations::v7::pre_migrate::<T>()
-			} else {
-				Ok(())
+			match StorageVersion::<T>::get() {
+				Releases::V6_0_0 => migrations::v7::pre_migrate::<T>(),
+				Releases::V8_0_0 => migrations::v9::pre_migrate::<T>(),
+				_ => Ok(()),
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			match StorageVersion::<T>::get() {
+				Releases::V9_0_0 => migrations::v9::post_migrate::<T>(),
+				_ => Ok(()),
 			}
 		}
 
@@ -967,6 +1077,12 @@ pub mod pallet {
 		///
 		/// Effects will be felt at the beginning of the next era.
 		///
+		/// The commission must be within `MinCommission` and `MaxCommission`. If
+		/// `MaxCommissionChange` is set, an existing validator can only increase its commission by
+		/// that much, and the increase only takes effect one era later than other changes. It is
+		/// recorded in `PendingCommission` in the meantime and `CommissionChangeScheduled` is
+		/// emitted. Calling this again without a commission increase cancels the pending one.
+		///
 		/// The dispatch origin for this call must be _Signed_ by the controller, not the stash.
 		#[pallet::weight(T::WeightInfo::validate())]
 		pub fn validate(origin: OriginFor<T>, prefs: ValidatorPrefs) -> DispatchResult {
@@ -979,6 +1095,9 @@ pub mod pallet {
 
 			// ensure their commission is correct.
 			ensure!(prefs.commission >= MinCommission::<T>::get(), Error::<T>::CommissionTooLow);
+			if let Some(max_commission) = MaxCommission::<T>::get() {
+				ensure!(prefs.commission <= max_commission, Error::<T>::CommissionTooHigh);
+			}
 
 			// Only check limits if they are not already a validator.
 			if !Validators::<T>::contains_key(stash) {
@@ -993,6 +1112,8 @@ pub mod pallet {
 				}
 			}
 
+			let prefs = Self::throttle_commission(stash, prefs)?;
+
 			Self::do_remove_nominator(stash);
 			Self::do_add_validator(stash, prefs);
 			Ok(())
@@ -1343,17 +1464,19 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Pay out all the stakers behind a single validator for a single era.
+		/// Pay out a single page of the stakers behind a validator for a single era.
 		///
-		/// - `validator_stash` is the stash account of the validator. Their nominators, up to
-		///   `T::MaxNominatorRewardedPerValidator`, will also receive their rewards.
+		/// - `validator_stash` is the stash account of the validator. The nominators in the given
+		///   page, up to `T::MaxExposurePageSize`, will also receive their rewards.
 		/// - `era` may be any era between `[current_era - history_depth; current_era]`.
+		/// - `page` is the index of the page of the validator's exposure to pay out. The validator
+		///   receives its share of the reward pro rata to the stake backing each page.
 		///
 		/// The origin of this call must be _Signed_. Any account can call this function, even if
 		/// it is not one of the stakers.
 		///
 		/// # <weight>
-		/// - Time complexity: at most O(MaxNominatorRewardedPerValidator).
+		/// - Time complexity: at most O(MaxExposurePageSize).
 		/// - Contains a limited number of reads and writes.
 		/// -----------
 		/// N is the Number of payouts for the validator (including the validator)
@@ -1364,16 +1487,100 @@ pub mod pallet {
 		///   NOTE: weights are assuming that payouts are made to alive stash account (Staked).
 		///   Paying even a dead controller is cheaper weight-wise. We don't do any refunds here.
 		/// # </weight>
-		#[pallet::weight(T::WeightInfo::payout_stakers_alive_staked(
-			T::MaxNominatorRewardedPerValidator::get()
-		))]
+		#[pallet::weight(T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get()))]
 		pub fn payout_stakers(
 			origin: OriginFor<T>,
 			validator_stash: T::AccountId,
 			era: EraIndex,
+			page: PageIndex,
 		) -> DispatchResultWithPostInfo {
-			ensure_signed(origin)?;
-			Self::do_payout_stakers(validator_stash, era)
+			let who = ensure_signed(origin)?;
+			Self::do_payout_stakers(&who, validator_stash, era, page)
+		}
+
+		/// Pay out the rewards of many exposure pages, possibly of different validators and eras,
+		/// at once.
+		///
+		/// - `payouts` is a list of `(validator_stash, era, page)`, each of which is paid out as in
+		///   [`Call::payout_stakers`]. It may contain at most `T::MaxPayoutsPerCall` items.
+		///
+		/// The payouts that fail, e.g. because they have already been claimed, are skipped with a
+		/// [`Event::PayoutFailed`] and do not prevent the other ones from being paid out. This
+		/// allows a permissionless keeper to compound the rewards of many stakers without having
+		/// to track each of them exactly.
+		///
+		/// The origin of this call must be _Signed_. Any account can call this function. If it is
+		/// the reward claimer of a staker (see [`Call::set_reward_claimer`]), it takes its share of
+		/// the reward of that staker.
+		///
+		/// # <weight>
+		/// - Time complexity: at most O(MaxPayoutsPerCall * MaxExposurePageSize).
+		/// - The unused weight of each payout is refunded.
+		/// # </weight>
+		#[pallet::weight(
+			T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get())
+				.saturating_mul(payouts.len() as Weight)
+		)]
+		pub fn payout_stakers_by_page(
+			origin: OriginFor<T>,
+			payouts: Vec<(T::AccountId, EraIndex, PageIndex)>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				payouts.len() <= T::MaxPayoutsPerCall::get() as usize,
+				Error::<T>::TooManyPayouts
+			);
+
+			let max_payout_weight =
+				T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get());
+			let actual_weight = payouts.into_iter().fold(0 as Weight, |acc, (stash, era, page)| {
+				let post_info = match Self::do_payout_stakers(&who, stash.clone(), era, page) {
+					Ok(post_info) => post_info,
+					Err(e) => {
+						Self::deposit_event(Event::<T>::PayoutFailed(stash, era, page, e.error));
+						e.post_info
+					},
+				};
+				acc.saturating_add(post_info.actual_weight.unwrap_or(max_payout_weight))
+			});
+
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Set the account that claims the rewards of this staker on its behalf, and the share of
+		/// the rewards that it takes for doing so.
+		///
+		/// The share is only taken from the payouts that the claimer itself triggers, through
+		/// [`Call::payout_stakers`] or [`Call::payout_stakers_by_page`]. The rest of the reward is
+		/// paid to the payee of the staker as usual, e.g. compounded into its bond if the payee is
+		/// [`RewardDestination::Staked`]. For a validator, the share is taken from its own payout,
+		/// commission included. Passing `None` removes the claimer.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the controller, not the stash.
+		///
+		/// # <weight>
+		/// - Independent of the arguments. Insignificant complexity.
+		/// - Contains a limited number of reads.
+		/// - Writes are limited to the `origin` account key.
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::set_reward_claimer())]
+		pub fn set_reward_claimer(
+			origin: OriginFor<T>,
+			claimer: Option<(T::AccountId, Perbill)>,
+		) -> DispatchResult {
+			let controller = ensure_signed(origin)?;
+			let ledger = Self::ledger(&controller).ok_or(Error::<T>::NotController)?;
+			match claimer {
+				Some((claimer, commission)) => {
+					ensure!(
+						commission <= T::MaxClaimerCommission::get(),
+						Error::<T>::ClaimerCommissionTooHigh
+					);
+					<RewardClaimers<T>>::insert(&ledger.stash, (claimer, commission));
+				},
+				None => <RewardClaimers<T>>::remove(&ledger.stash),
+			}
+			Ok(())
 		}
 
 		/// Rebond a portion of the stash scheduled to be unlocked.
@@ -1542,6 +1749,11 @@ pub mod pallet {
 		///   should be filled in order for the `chill_other` transaction to work.
 		/// * `min_commission`: The minimum amount of commission that each validators must maintain.
 		///   This is checked only upon calling `validate`. Existing validators are not affected.
+		/// * `max_commission`: The maximum amount of commission that each validators can set. When
+		///   set to `None`, no limit is enforced. This is checked only upon calling `validate`.
+		/// * `max_commission_change`: The maximum amount by which a validator can increase its
+		///   commission per era. When set, commission increases are also delayed by an era. When
+		///   set to `None`, no limit is enforced.
 		///
 		/// Origin must be Root to call this function.
 		///
@@ -1556,6 +1768,8 @@ pub mod pallet {
 			max_validator_count: Option<u32>,
 			chill_threshold: Option<Percent>,
 			min_commission: Perbill,
+			max_commission: Option<Perbill>,
+			max_commission_change: Option<Perbill>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
 			MinNominatorBond::<T>::set(min_nominator_bond);
@@ -1564,6 +1778,8 @@ pub mod pallet {
 			MaxValidatorsCount::<T>::set(max_validator_count);
 			ChillThreshold::<T>::set(chill_threshold);
 			MinCommission::<T>::set(min_commission);
+			MaxCommission::<T>::set(max_commission);
+			MaxCommissionChange::<T>::set(max_commission_change);
 			Ok(())
 		}
 
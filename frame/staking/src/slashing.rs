@@ -50,8 +50,8 @@
 //! Based on research at <https://research.web3.foundation/en/latest/polkadot/slashing/npos.html>
 
 use crate::{
-	BalanceOf, Config, EraIndex, Error, Exposure, NegativeImbalanceOf, Pallet, Perbill,
-	SessionInterface, Store, UnappliedSlash,
+	BalanceOf, Config, EraIndex, Error, Exposure, IndividualExposure, NegativeImbalanceOf, Pallet,
+	Perbill, SessionInterface, Store, UnappliedSlash,
 };
 use codec::{Decode, Encode};
 use frame_support::{
@@ -63,8 +63,8 @@ use sp_runtime::{
 	traits::{Saturating, Zero},
 	DispatchResult, RuntimeDebug,
 };
-use sp_staking::offence::DisableStrategy;
-use sp_std::vec::Vec;
+use sp_staking::{offence::DisableStrategy, OnStakerSlash};
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
 /// The proportion of the slashing reward to be paid out on the first slashing detection.
 /// This is f_1 in the paper.
@@ -73,13 +73,16 @@ const REWARD_F1: Perbill = Perbill::from_percent(50);
 /// The index of a slashing span - unique to each stash.
 pub type SpanIndex = u32;
 
-// A range of start..end eras for a slashing span.
-#[derive(Encode, Decode, TypeInfo)]
-#[cfg_attr(test, derive(Debug, PartialEq))]
-pub(crate) struct SlashingSpan {
-	pub(crate) index: SpanIndex,
-	pub(crate) start: EraIndex,
-	pub(crate) length: Option<EraIndex>, // the ongoing slashing span has indeterminate length.
+/// A range of start..end eras for a slashing span.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct SlashingSpan {
+	/// The index of this span, unique to the stash.
+	pub index: SpanIndex,
+	/// The first era of this span.
+	pub start: EraIndex,
+	/// The number of eras in this span. The ongoing slashing span has indeterminate length.
+	pub length: Option<EraIndex>,
 }
 
 impl SlashingSpan {
@@ -203,7 +206,9 @@ pub(crate) struct SlashParams<'a, T: 'a + Config> {
 	pub(crate) stash: &'a T::AccountId,
 	/// The proportion of the slash.
 	pub(crate) slash: Perbill,
-	/// The exposure of the stash and all nominators.
+	/// The exposure of the stash and all nominators, as reported with the offence.
+	///
+	/// The nominators are only read from here if the paged exposure of the slash era is gone.
 	pub(crate) exposure: &'a Exposure<T::AccountId, BalanceOf<T>>,
 	/// The era where the offence occurred.
 	pub(crate) slash_era: EraIndex,
@@ -367,6 +372,9 @@ fn add_offending_validator<T: Config>(stash: &T::AccountId, disable: bool) {
 
 /// Slash nominators. Accepts general parameters and the prior slash percentage of the validator.
 ///
+/// The nominators are read page by page from the paged exposure of the validator at the slash era.
+/// If that exposure is no longer in storage, the nominators of the reported exposure are used.
+///
 /// Returns the amount of reward to pay out.
 fn slash_nominators<T: Config>(
 	params: SlashParams<T>,
@@ -375,52 +383,95 @@ fn slash_nominators<T: Config>(
 ) -> BalanceOf<T> {
 	let mut reward_payout = Zero::zero();
 
-	nominators_slashed.reserve(params.exposure.others.len());
-	for nominator in &params.exposure.others {
-		let stash = &nominator.who;
-		let mut nom_slashed = Zero::zero();
+	match <Pallet<T>>::eras_stakers_overview(params.slash_era, params.stash) {
+		Some(overview) => {
+			nominators_slashed.reserve(overview.nominator_count as usize);
+			for page in 0..overview.page_count {
+				let exposure_page = match <Pallet<T> as Store>::ErasStakersPaged::get((
+					params.slash_era,
+					params.stash,
+					page,
+				)) {
+					Some(exposure_page) => exposure_page,
+					None => continue,
+				};
+				for nominator in &exposure_page.others {
+					slash_nominator::<T>(
+						&params,
+						prior_slash_p,
+						nominator,
+						&mut reward_payout,
+						nominators_slashed,
+					);
+				}
+			}
+		},
+		None => {
+			nominators_slashed.reserve(params.exposure.others.len());
+			for nominator in &params.exposure.others {
+				slash_nominator::<T>(
+					&params,
+					prior_slash_p,
+					nominator,
+					&mut reward_payout,
+					nominators_slashed,
+				);
+			}
+		},
+	}
 
-		// the era slash of a nominator always grows, if the validator
-		// had a new max slash for the era.
-		let era_slash = {
-			let own_slash_prior = prior_slash_p * nominator.value;
-			let own_slash_by_validator = params.slash * nominator.value;
-			let own_slash_difference = own_slash_by_validator.saturating_sub(own_slash_prior);
+	reward_payout
+}
 
-			let mut era_slash =
-				<Pallet<T> as Store>::NominatorSlashInEra::get(&params.slash_era, stash)
-					.unwrap_or_else(|| Zero::zero());
+/// Slash a single nominator of the offending validator, adding to the reward payout.
+fn slash_nominator<T: Config>(
+	params: &SlashParams<T>,
+	prior_slash_p: Perbill,
+	nominator: &IndividualExposure<T::AccountId, BalanceOf<T>>,
+	reward_payout: &mut BalanceOf<T>,
+	nominators_slashed: &mut Vec<(T::AccountId, BalanceOf<T>)>,
+) {
+	let stash = &nominator.who;
+	let mut nom_slashed = Zero::zero();
 
-			era_slash += own_slash_difference;
+	// the era slash of a nominator always grows, if the validator
+	// had a new max slash for the era.
+	let era_slash = {
+		let own_slash_prior = prior_slash_p * nominator.value;
+		let own_slash_by_validator = params.slash * nominator.value;
+		let own_slash_difference = own_slash_by_validator.saturating_sub(own_slash_prior);
 
-			<Pallet<T> as Store>::NominatorSlashInEra::insert(&params.slash_era, stash, &era_slash);
+		let mut era_slash =
+			<Pallet<T> as Store>::NominatorSlashInEra::get(&params.slash_era, stash)
+				.unwrap_or_else(|| Zero::zero());
 
-			era_slash
-		};
+		era_slash += own_slash_difference;
 
-		// compare the era slash against other eras in the same span.
-		{
-			let mut spans = fetch_spans::<T>(
-				stash,
-				params.window_start,
-				&mut reward_payout,
-				&mut nom_slashed,
-				params.reward_proportion,
-			);
-
-			let target_span = spans.compare_and_update_span_slash(params.slash_era, era_slash);
-
-			if target_span == Some(spans.span_index()) {
-				// End the span, but don't chill the nominator. its nomination
-				// on this validator will be ignored in the future.
-				spans.end_span(params.now);
-			}
-		}
+		<Pallet<T> as Store>::NominatorSlashInEra::insert(&params.slash_era, stash, &era_slash);
+
+		era_slash
+	};
 
-		nominators_slashed.push((stash.clone(), nom_slashed));
+	// compare the era slash against other eras in the same span.
+	{
+		let mut spans = fetch_spans::<T>(
+			stash,
+			params.window_start,
+			reward_payout,
+			&mut nom_slashed,
+			params.reward_proportion,
+		);
+
+		let target_span = spans.compare_and_update_span_slash(params.slash_era, era_slash);
+
+		if target_span == Some(spans.span_index()) {
+			// End the span, but don't chill the nominator. its nomination
+			// on this validator will be ignored in the future.
+			spans.end_span(params.now);
+		}
 	}
 
-	reward_payout
+	nominators_slashed.push((stash.clone(), nom_slashed));
 }
 
 // helper struct for managing a set of spans we are currently inspecting.
@@ -593,11 +644,15 @@ pub(crate) fn clear_stash_metadata<T: Config>(
 // apply the slash to a stash account, deducting any missing funds from the reward
 // payout, saturating at 0. this is mildly unfair but also an edge-case that
 // can only occur when overlapping locked funds have been slashed.
+//
+// funds that started unbonding in or after `slash_era` were still at stake at the time of
+// the offence, and are slashed proportionally together with the active funds.
 pub fn do_slash<T: Config>(
 	stash: &T::AccountId,
 	value: BalanceOf<T>,
 	reward_payout: &mut BalanceOf<T>,
 	slashed_imbalance: &mut NegativeImbalanceOf<T>,
+	slash_era: EraIndex,
 ) {
 	let controller = match <Pallet<T>>::bonded(stash) {
 		None => return, // defensive: should always exist.
@@ -609,9 +664,29 @@ pub fn do_slash<T: Config>(
 		None => return, // nothing to do.
 	};
 
-	let value = ledger.slash(value, T::Currency::minimum_balance());
+	// remember the eras that are currently unlocking, so that the slash hook is told about
+	// chunks that have been fully consumed by the slash as well.
+	let unlocking_eras = ledger.unlocking.iter().map(|chunk| chunk.era).collect::<Vec<_>>();
+	let value = ledger.slash(
+		value,
+		T::Currency::minimum_balance(),
+		slash_era.saturating_add(T::BondingDuration::get()),
+	);
 
 	if !value.is_zero() {
+		let slashed_unlocking = unlocking_eras
+			.into_iter()
+			.map(|era| {
+				let remaining = ledger
+					.unlocking
+					.iter()
+					.find(|chunk| chunk.era == era)
+					.map(|chunk| chunk.value)
+					.unwrap_or_else(Zero::zero);
+				(era, remaining)
+			})
+			.collect::<BTreeMap<_, _>>();
+
 		let (imbalance, missing) = T::Currency::slash(stash, value);
 		slashed_imbalance.subsume(imbalance);
 
@@ -621,14 +696,18 @@ pub fn do_slash<T: Config>(
 		}
 
 		<Pallet<T>>::update_ledger(&controller, &ledger);
+		T::OnStakerSlash::on_slash(stash, ledger.active, &slashed_unlocking);
 
 		// trigger the event
 		<Pallet<T>>::deposit_event(super::Event::<T>::Slashed(stash.clone(), value));
 	}
 }
 
-/// Apply a previously-unapplied slash.
-pub(crate) fn apply_slash<T: Config>(unapplied_slash: UnappliedSlash<T::AccountId, BalanceOf<T>>) {
+/// Apply a previously-unapplied slash for an offence committed in `slash_era`.
+pub(crate) fn apply_slash<T: Config>(
+	unapplied_slash: UnappliedSlash<T::AccountId, BalanceOf<T>>,
+	slash_era: EraIndex,
+) {
 	let mut slashed_imbalance = NegativeImbalanceOf::<T>::zero();
 	let mut reward_payout = unapplied_slash.payout;
 
@@ -637,10 +716,17 @@ pub(crate) fn apply_slash<T: Config>(unapplied_slash: UnappliedSlash<T::AccountI
 		unapplied_slash.own,
 		&mut reward_payout,
 		&mut slashed_imbalance,
+		slash_era,
 	);
 
 	for &(ref nominator, nominator_slash) in &unapplied_slash.others {
-		do_slash::<T>(&nominator, nominator_slash, &mut reward_payout, &mut slashed_imbalance);
+		do_slash::<T>(
+			&nominator,
+			nominator_slash,
+			&mut reward_payout,
+			&mut slashed_imbalance,
+			slash_era,
+		);
 	}
 
 	pay_reporters::<T>(reward_payout, slashed_imbalance, &unapplied_slash.reporters);
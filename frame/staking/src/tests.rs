@@ -437,8 +437,8 @@ fn less_than_needed_candidates_works() {
 
 			// But the exposure is updated in a simple way. No external votes exists.
 			// This is purely self-vote.
-			assert!(ErasStakers::<Test>::iter_prefix_values(active_era())
-				.all(|exposure| exposure.others.is_empty()));
+			assert!(ErasStakersOverview::<Test>::iter_prefix_values(active_era())
+				.all(|overview| overview.nominator_count == 0));
 		});
 }
 
@@ -535,7 +535,7 @@ fn nominating_and_rewards_should_work() {
 			assert_eq!(Balances::total_balance(&20), initial_balance_20 + total_payout_0 / 2);
 			initial_balance_20 = Balances::total_balance(&20);
 
-			assert_eq!(ErasStakers::<Test>::iter_prefix_values(active_era()).count(), 2);
+			assert_eq!(ErasStakersOverview::<Test>::iter_prefix_values(active_era()).count(), 2);
 			assert_eq!(
 				Staking::eras_stakers(active_era(), 11),
 				Exposure {
@@ -657,6 +657,47 @@ fn nominators_also_get_slashed_pro_rata() {
 	});
 }
 
+#[test]
+fn nominators_in_all_exposure_pages_get_slashed() {
+	ExtBuilder::default().build_and_execute(|| {
+		let max_page_size = <Test as Config>::MaxExposurePageSize::get();
+		for i in 0..=max_page_size {
+			let stash = 10_000 + i as AccountId;
+			let controller = 20_000 + i as AccountId;
+			let balance = 10_000 + i as Balance;
+			Balances::make_free_balance_be(&stash, balance);
+			assert_ok!(Staking::bond(
+				Origin::signed(stash),
+				controller,
+				balance,
+				RewardDestination::Stash
+			));
+			assert_ok!(Staking::nominate(Origin::signed(controller), vec![11]));
+		}
+		mock::start_active_era(1);
+		assert_eq!(ErasStakersOverview::<Test>::get(active_era(), 11).unwrap().page_count, 2);
+
+		// the reported exposure does not carry the nominators, they are read from the pages.
+		let exposure = Staking::eras_stakers(active_era(), 11);
+		on_offence_now(
+			&[OffenceDetails {
+				offender: (11, Exposure { others: vec![], ..exposure }),
+				reporters: vec![],
+			}],
+			&[Perbill::from_percent(10)],
+		);
+
+		for i in 0..=max_page_size {
+			let stash = 10_000 + i as AccountId;
+			let balance = 10_000 + i as Balance;
+			assert_eq!(
+				Balances::free_balance(&stash),
+				balance - Perbill::from_percent(10) * balance
+			);
+		}
+	});
+}
+
 #[test]
 fn double_staking_should_fail() {
 	// should test (in the same order):
@@ -1596,7 +1637,7 @@ fn reward_to_stake_works() {
 			let _ = Balances::make_free_balance_be(&20, 1000);
 
 			// Bypass logic and change current exposure
-			ErasStakers::<Test>::insert(0, 21, Exposure { total: 69, own: 69, others: vec![] });
+			Staking::set_exposure(0, &21, Exposure { total: 69, own: 69, others: vec![] });
 			<Ledger<Test>>::insert(
 				&20,
 				StakingLedger {
@@ -2008,10 +2049,9 @@ fn reward_validator_slashing_validator_does_not_overflow() {
 
 		// Check reward
 		ErasRewardPoints::<Test>::insert(0, reward);
-		ErasStakers::<Test>::insert(0, 11, &exposure);
-		ErasStakersClipped::<Test>::insert(0, 11, exposure);
+		Staking::set_exposure(0, &11, exposure);
 		ErasValidatorReward::<Test>::insert(0, stake);
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 0));
+		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 0, 0));
 		assert_eq!(Balances::total_balance(&11), stake * 2);
 
 		// Set staker
@@ -2022,9 +2062,9 @@ fn reward_validator_slashing_validator_does_not_overflow() {
 		// it is 0.
 		Staking::bond(Origin::signed(2), 20000, stake - 1, RewardDestination::default()).unwrap();
 		// Override exposure of 11
-		ErasStakers::<Test>::insert(
+		Staking::set_exposure(
 			0,
-			11,
+			&11,
 			Exposure {
 				total: stake,
 				own: 1,
@@ -2103,6 +2143,120 @@ fn unbonded_balance_is_not_slashable() {
 	})
 }
 
+#[test]
+fn ledger_slash_is_proportional_over_affected_chunks() {
+	let ledger = StakingLedger::<AccountId, Balance> {
+		stash: 11,
+		total: 1000,
+		active: 500,
+		unlocking: vec![
+			UnlockChunk { value: 100, era: 4 },
+			UnlockChunk { value: 200, era: 5 },
+			UnlockChunk { value: 200, era: 6 },
+		],
+		claimed_rewards: vec![],
+	};
+
+	// the chunks unlocking in era 5 and later were bonded at the time of the offence, so 10% of
+	// the affected 900 is taken from each of them and from the active funds.
+	let mut slashed = ledger.clone();
+	assert_eq!(slashed.slash(90, 10, 5), 90);
+	assert_eq!(slashed.total, 910);
+	assert_eq!(slashed.active, 450);
+	assert_eq!(
+		slashed.unlocking,
+		vec![
+			UnlockChunk { value: 100, era: 4 },
+			UnlockChunk { value: 180, era: 5 },
+			UnlockChunk { value: 180, era: 6 },
+		]
+	);
+
+	// when the affected funds are not enough, the remainder is taken from the older chunks,
+	// most recent first.
+	let mut slashed = ledger.clone();
+	assert_eq!(slashed.slash(950, 10, 5), 950);
+	assert_eq!(slashed.total, 50);
+	assert_eq!(slashed.active, 0);
+	assert_eq!(slashed.unlocking, vec![UnlockChunk { value: 50, era: 4 }]);
+
+	// a chunk left with dust is drained as well.
+	let mut slashed = ledger.clone();
+	assert_eq!(slashed.slash(990, 10, 5), 1000);
+	assert_eq!(slashed.total, 0);
+	assert!(slashed.unlocking.is_empty());
+
+	// without affected chunks, `active` is slashed first and then the chunks closest to
+	// unlocking.
+	let mut slashed = ledger;
+	assert_eq!(slashed.slash(600, 10, 7), 600);
+	assert_eq!(slashed.total, 400);
+	assert_eq!(slashed.active, 0);
+	assert_eq!(
+		slashed.unlocking,
+		vec![UnlockChunk { value: 200, era: 5 }, UnlockChunk { value: 200, era: 6 }]
+	);
+}
+
+#[test]
+fn unbonding_balance_is_slashed_proportionally() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+		assert_ok!(Staking::unbond(Origin::signed(10), 500));
+		assert_eq!(
+			Staking::ledger(&10).unwrap().unlocking,
+			vec![UnlockChunk { value: 500, era: 4 }]
+		);
+
+		// the funds were still at stake in era 1, so the slash of 10% of 1000 is spread over
+		// the active and unbonding funds alike.
+		on_offence_now(
+			&[OffenceDetails {
+				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				reporters: vec![],
+			}],
+			&[Perbill::from_percent(10)],
+		);
+
+		assert_eq!(Balances::free_balance(11), 900);
+		let ledger = Staking::ledger(&10).unwrap();
+		assert_eq!(ledger.total, 900);
+		assert_eq!(ledger.active, 450);
+		assert_eq!(ledger.unlocking, vec![UnlockChunk { value: 450, era: 4 }]);
+	});
+}
+
+#[test]
+fn paged_exposure_migration_runs_on_runtime_upgrade() {
+	ExtBuilder::default().build_and_execute(|| {
+		use frame_support::{
+			storage::migration::{get_storage_value, put_storage_value},
+			StorageHasher,
+		};
+
+		let exposure = Exposure {
+			total: 1500,
+			own: 1000,
+			others: vec![IndividualExposure { who: 101, value: 500 }],
+		};
+		let key =
+			[Twox64Concat::hash(&7u32.encode()), Twox64Concat::hash(&11u64.encode())].concat();
+		put_storage_value(b"Staking", b"ErasStakers", &key, exposure.clone());
+		StorageVersion::<Test>::put(Releases::V8_0_0);
+
+		<Staking as Hooks<u64>>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V9_0_0);
+		assert_eq!(Staking::eras_stakers(7, 11), exposure);
+		assert!(get_storage_value::<Exposure<AccountId, Balance>>(
+			b"Staking",
+			b"ErasStakers",
+			&key
+		)
+		.is_none());
+	});
+}
+
 #[test]
 fn era_is_always_same_length() {
 	// This ensures that the sessions is always of the same length if there is no forcing no
@@ -2724,6 +2878,44 @@ fn deferred_slashes_are_deferred() {
 	})
 }
 
+#[test]
+fn pending_slashes_and_slashing_spans_can_be_queried() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+		assert!(Staking::pending_slashes(1).is_empty());
+		assert!(Staking::slashing_spans_of(&11).is_empty());
+
+		// 101 is in the first and only page of the exposure of 11.
+		let (page, exposure_page) = Staking::exposure_page_of(1, &11, &101).unwrap();
+		assert_eq!(page, 0);
+		assert!(exposure_page.others.iter().any(|e| e.who == 101));
+		assert_eq!(Staking::exposure_page_of(1, &11, &1337), None);
+		assert_eq!(Staking::exposure_page_of(1, &1337, &101), None);
+
+		on_offence_now(
+			&[OffenceDetails {
+				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				reporters: vec![],
+			}],
+			&[Perbill::from_percent(10)],
+		);
+
+		// the slash is pending, and shows both the validator and its nominator.
+		let pending = Staking::pending_slashes(1);
+		assert_eq!(pending.len(), 1);
+		assert_eq!(pending[0].validator, 11);
+		assert_eq!(pending[0].own, 100);
+		assert!(pending[0].others.iter().any(|(who, _)| *who == 101));
+
+		// once applied, it is no longer pending.
+		mock::start_active_era(4);
+		assert!(Staking::pending_slashes(1).is_empty());
+		let spans = Staking::slashing_spans_of(&11);
+		assert!(!spans.is_empty());
+		assert_eq!(spans[0].length, None);
+	})
+}
+
 #[test]
 fn remove_deferred() {
 	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
@@ -3124,19 +3316,19 @@ fn claim_reward_at_the_last_era_and_no_double_claim_and_invalid_claim() {
 		// Last kept is 1:
 		assert!(current_era - Staking::history_depth() == 1);
 		assert_noop!(
-			Staking::payout_stakers(Origin::signed(1337), 11, 0),
+			Staking::payout_stakers(Origin::signed(1337), 11, 0, 0),
 			// Fail: Era out of history
 			Error::<Test>::InvalidEraToReward.with_weight(err_weight)
 		);
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 1));
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 2));
+		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 1, 0));
+		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 2, 0));
 		assert_noop!(
-			Staking::payout_stakers(Origin::signed(1337), 11, 2),
+			Staking::payout_stakers(Origin::signed(1337), 11, 2, 0),
 			// Fail: Double claim
 			Error::<Test>::AlreadyClaimed.with_weight(err_weight)
 		);
 		assert_noop!(
-			Staking::payout_stakers(Origin::signed(1337), 11, active_era),
+			Staking::payout_stakers(Origin::signed(1337), 11, active_era, 0),
 			// Fail: Era not finished yet
 			Error::<Test>::InvalidEraToReward.with_weight(err_weight)
 		);
@@ -3249,9 +3441,10 @@ fn six_session_delay() {
 }
 
 #[test]
-fn test_max_nominator_rewarded_per_validator_and_cant_steal_someone_else_reward() {
+fn nominators_over_max_exposure_page_size_are_rewarded_by_page() {
 	ExtBuilder::default().build_and_execute(|| {
-		for i in 0..=<Test as Config>::MaxNominatorRewardedPerValidator::get() {
+		let max_page_size = <Test as Config>::MaxExposurePageSize::get();
+		for i in 0..=max_page_size {
 			let stash = 10_000 + i as AccountId;
 			let controller = 20_000 + i as AccountId;
 			let balance = 10_000 + i as Balance;
@@ -3271,18 +3464,45 @@ fn test_max_nominator_rewarded_per_validator_and_cant_steal_someone_else_reward(
 		let _ = current_total_payout_for_duration(reward_time_per_era());
 
 		mock::start_active_era(2);
-		mock::make_all_reward_payment(1);
 
-		// Assert only nominators from 1 to Max are rewarded
-		for i in 0..=<Test as Config>::MaxNominatorRewardedPerValidator::get() {
+		// The nominators of 11 do not fit in a single page.
+		let overview = ErasStakersOverview::<Test>::get(1, 11).unwrap();
+		assert_eq!(overview.page_count, 2);
+		assert_eq!(
+			ErasStakersPaged::<Test>::get((1, 11, 0)).unwrap().others.len() as u32,
+			max_page_size
+		);
+
+		// Paying out the first page rewards only the biggest nominators.
+		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 1, 0));
+		for i in 0..=max_page_size {
 			let stash = 10_000 + i as AccountId;
 			let balance = 10_000 + i as Balance;
 			if stash == 10_000 {
-				assert!(Balances::free_balance(&stash) == balance);
+				assert_eq!(Balances::free_balance(&stash), balance);
 			} else {
 				assert!(Balances::free_balance(&stash) > balance);
 			}
 		}
+		assert_eq!(ClaimedRewards::<Test>::get(1, 11), vec![0]);
+		assert!(!Staking::ledger(10).unwrap().claimed_rewards.contains(&1));
+		assert_noop!(
+			Staking::payout_stakers(Origin::signed(1337), 11, 1, 0),
+			Error::<Test>::AlreadyClaimed
+				.with_weight(<Test as Config>::WeightInfo::payout_stakers_alive_staked(0))
+		);
+
+		// The second page rewards the rest, after which the era is fully claimed.
+		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 1, 1));
+		assert!(Balances::free_balance(&10_000) > 10_000);
+		assert!(ClaimedRewards::<Test>::get(1, 11).is_empty());
+		assert!(Staking::ledger(10).unwrap().claimed_rewards.contains(&1));
+
+		assert_noop!(
+			Staking::payout_stakers(Origin::signed(1337), 11, 1, 2),
+			Error::<Test>::InvalidPage
+				.with_weight(<Test as Config>::WeightInfo::payout_stakers_alive_staked(0))
+		);
 	});
 }
 
@@ -3307,8 +3527,8 @@ fn set_history_depth_works() {
 
 #[test]
 fn test_payout_stakers() {
-	// Here we will test validator can set `max_nominators_payout` and it works.
-	// We also test that `payout_extra_nominators` works.
+	// Here we will test that the exposure is paid out page by page, and that an era is only
+	// tracked as claimed once all of its pages are paid out.
 	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
 		let balance = 1000;
 		// Create a validator:
@@ -3326,20 +3546,30 @@ fn test_payout_stakers() {
 		let _ = current_total_payout_for_duration(reward_time_per_era());
 
 		mock::start_active_era(2);
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 1));
+		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 1, 0));
 
-		// Top 64 nominators of validator 11 automatically paid out, including the validator
-		// Validator payout goes to controller.
+		// Top 64 nominators of validator 11 are in the first page and paid out, including the
+		// validator. Validator payout goes to controller.
 		assert!(Balances::free_balance(&10) > balance);
 		for i in 36..100 {
 			assert!(Balances::free_balance(&(100 + i)) > balance + i as Balance);
 		}
-		// The bottom 36 do not
+		// The bottom 36 are in the second page and not paid yet
 		for i in 0..36 {
 			assert_eq!(Balances::free_balance(&(100 + i)), balance + i as Balance);
 		}
+		assert_eq!(ClaimedRewards::<Test>::get(1, 11), vec![0]);
+		assert!(Staking::ledger(&10).unwrap().claimed_rewards.is_empty());
 
-		// We track rewards in `claimed_rewards` vec
+		let validator_balance = Balances::free_balance(&10);
+		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 1, 1));
+		assert!(Balances::free_balance(&10) > validator_balance);
+		for i in 0..36 {
+			assert!(Balances::free_balance(&(100 + i)) > balance + i as Balance);
+		}
+		assert!(ClaimedRewards::<Test>::get(1, 11).is_empty());
+
+		// We track fully paid out rewards in `claimed_rewards` vec
 		assert_eq!(
 			Staking::ledger(&10),
 			Some(StakingLedger {
@@ -3358,7 +3588,8 @@ fn test_payout_stakers() {
 			let _ = current_total_payout_for_duration(reward_time_per_era());
 
 			mock::start_active_era(i);
-			assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, i - 1));
+			assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, i - 1, 0));
+			assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, i - 1, 1));
 		}
 
 		// We track rewards in `claimed_rewards` vec
@@ -3381,8 +3612,10 @@ fn test_payout_stakers() {
 		}
 
 		// We clean it up as history passes
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 15));
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 98));
+		for era in [15, 98] {
+			assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, era, 0));
+			assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, era, 1));
+		}
 		assert_eq!(
 			Staking::ledger(&10),
 			Some(StakingLedger {
@@ -3395,9 +3628,10 @@ fn test_payout_stakers() {
 		);
 
 		// Out of order claims works.
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 69));
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 23));
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 42));
+		for era in [69, 23, 42] {
+			assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, era, 1));
+			assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, era, 0));
+		}
 		assert_eq!(
 			Staking::ledger(&10),
 			Some(StakingLedger {
@@ -3437,14 +3671,19 @@ fn payout_stakers_handles_basic_errors() {
 
 		// Wrong Era, too big
 		assert_noop!(
-			Staking::payout_stakers(Origin::signed(1337), 11, 2),
+			Staking::payout_stakers(Origin::signed(1337), 11, 2, 0),
 			Error::<Test>::InvalidEraToReward.with_weight(err_weight)
 		);
 		// Wrong Staker
 		assert_noop!(
-			Staking::payout_stakers(Origin::signed(1337), 10, 1),
+			Staking::payout_stakers(Origin::signed(1337), 10, 1, 0),
 			Error::<Test>::NotStash.with_weight(err_weight)
 		);
+		// Wrong page, the 100 nominators only fill two pages
+		assert_noop!(
+			Staking::payout_stakers(Origin::signed(1337), 11, 1, 2),
+			Error::<Test>::InvalidPage.with_weight(err_weight)
+		);
 
 		for i in 3..100 {
 			Staking::reward_by_ids(vec![(11, 1)]);
@@ -3455,23 +3694,23 @@ fn payout_stakers_handles_basic_errors() {
 		// We are at era 99, with history depth of 84
 		// We should be able to payout era 15 through 98 (84 total eras), but not 14 or 99.
 		assert_noop!(
-			Staking::payout_stakers(Origin::signed(1337), 11, 14),
+			Staking::payout_stakers(Origin::signed(1337), 11, 14, 0),
 			Error::<Test>::InvalidEraToReward.with_weight(err_weight)
 		);
 		assert_noop!(
-			Staking::payout_stakers(Origin::signed(1337), 11, 99),
+			Staking::payout_stakers(Origin::signed(1337), 11, 99, 0),
 			Error::<Test>::InvalidEraToReward.with_weight(err_weight)
 		);
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 15));
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 98));
+		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 15, 0));
+		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 98, 0));
 
 		// Can't claim again
 		assert_noop!(
-			Staking::payout_stakers(Origin::signed(1337), 11, 15),
+			Staking::payout_stakers(Origin::signed(1337), 11, 15, 0),
 			Error::<Test>::AlreadyClaimed.with_weight(err_weight)
 		);
 		assert_noop!(
-			Staking::payout_stakers(Origin::signed(1337), 11, 98),
+			Staking::payout_stakers(Origin::signed(1337), 11, 98, 0),
 			Error::<Test>::AlreadyClaimed.with_weight(err_weight)
 		);
 	});
@@ -3482,7 +3721,7 @@ fn payout_stakers_handles_weight_refund() {
 	// Note: this test relies on the assumption that `payout_stakers_alive_staked` is solely used by
 	// `payout_stakers` to calculate the weight of each payout op.
 	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
-		let max_nom_rewarded = <Test as Config>::MaxNominatorRewardedPerValidator::get();
+		let max_nom_rewarded = <Test as Config>::MaxExposurePageSize::get();
 		// Make sure the configured value is meaningful for our use.
 		assert!(max_nom_rewarded >= 4);
 		let half_max_nom_rewarded = max_nom_rewarded / 2;
@@ -3518,8 +3757,11 @@ fn payout_stakers_handles_weight_refund() {
 		start_active_era(2);
 
 		// Collect payouts when there are no nominators
-		let call =
-			TestRuntimeCall::Staking(StakingCall::payout_stakers { validator_stash: 11, era: 1 });
+		let call = TestRuntimeCall::Staking(StakingCall::payout_stakers {
+			validator_stash: 11,
+			era: 1,
+			page: 0,
+		});
 		let info = call.get_dispatch_info();
 		let result = call.dispatch(Origin::signed(20));
 		assert_ok!(result);
@@ -3532,8 +3774,11 @@ fn payout_stakers_handles_weight_refund() {
 		start_active_era(3);
 
 		// Collect payouts for an era where the validator did not receive any points.
-		let call =
-			TestRuntimeCall::Staking(StakingCall::payout_stakers { validator_stash: 11, era: 2 });
+		let call = TestRuntimeCall::Staking(StakingCall::payout_stakers {
+			validator_stash: 11,
+			era: 2,
+			page: 0,
+		});
 		let info = call.get_dispatch_info();
 		let result = call.dispatch(Origin::signed(20));
 		assert_ok!(result);
@@ -3546,8 +3791,11 @@ fn payout_stakers_handles_weight_refund() {
 		start_active_era(4);
 
 		// Collect payouts when the validator has `half_max_nom_rewarded` nominators.
-		let call =
-			TestRuntimeCall::Staking(StakingCall::payout_stakers { validator_stash: 11, era: 3 });
+		let call = TestRuntimeCall::Staking(StakingCall::payout_stakers {
+			validator_stash: 11,
+			era: 3,
+			page: 0,
+		});
 		let info = call.get_dispatch_info();
 		let result = call.dispatch(Origin::signed(20));
 		assert_ok!(result);
@@ -3570,16 +3818,22 @@ fn payout_stakers_handles_weight_refund() {
 		start_active_era(6);
 
 		// Collect payouts when the validator had `half_max_nom_rewarded` nominators.
-		let call =
-			TestRuntimeCall::Staking(StakingCall::payout_stakers { validator_stash: 11, era: 5 });
+		let call = TestRuntimeCall::Staking(StakingCall::payout_stakers {
+			validator_stash: 11,
+			era: 5,
+			page: 0,
+		});
 		let info = call.get_dispatch_info();
 		let result = call.dispatch(Origin::signed(20));
 		assert_ok!(result);
 		assert_eq!(extract_actual_weight(&result, &info), max_nom_rewarded_weight);
 
 		// Try and collect payouts for an era that has already been collected.
-		let call =
-			TestRuntimeCall::Staking(StakingCall::payout_stakers { validator_stash: 11, era: 5 });
+		let call = TestRuntimeCall::Staking(StakingCall::payout_stakers {
+			validator_stash: 11,
+			era: 5,
+			page: 0,
+		});
 		let info = call.get_dispatch_info();
 		let result = call.dispatch(Origin::signed(20));
 		assert!(result.is_err());
@@ -3692,7 +3946,7 @@ fn payout_creates_controller() {
 		// compute and ensure the reward amount is greater than zero.
 		let _ = current_total_payout_for_duration(reward_time_per_era());
 		mock::start_active_era(2);
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 1));
+		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 1, 0));
 
 		// Controller is created
 		assert!(Balances::free_balance(1337) > 0);
@@ -3720,13 +3974,141 @@ fn payout_to_any_account_works() {
 		// compute and ensure the reward amount is greater than zero.
 		let _ = current_total_payout_for_duration(reward_time_per_era());
 		mock::start_active_era(2);
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 1));
+		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 1, 0));
 
 		// Payment is successful
 		assert!(Balances::free_balance(42) > 0);
 	})
 }
 
+#[test]
+fn payout_stakers_by_page_works() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		let balance = 1000;
+		bond_validator(11, 10, balance);
+		bond_nominator(1234, 1337, 100, vec![11]);
+
+		for era in 1..=2 {
+			mock::start_active_era(era);
+			Staking::reward_by_ids(vec![(11, 1)]);
+			// compute and ensure the reward amount is greater than zero.
+			let _ = current_total_payout_for_duration(reward_time_per_era());
+		}
+		mock::start_active_era(3);
+
+		// too many payouts at once.
+		let max_payouts = <Test as Config>::MaxPayoutsPerCall::get();
+		assert_noop!(
+			Staking::payout_stakers_by_page(
+				Origin::signed(99),
+				vec![(11, 1, 0); max_payouts as usize + 1]
+			),
+			Error::<Test>::TooManyPayouts
+		);
+
+		// any account can pay out both eras at once, the duplicate is skipped.
+		let controller_balance = Balances::free_balance(1337);
+		assert_ok!(Staking::payout_stakers_by_page(
+			Origin::signed(99),
+			vec![(11, 1, 0), (11, 2, 0), (11, 1, 0)]
+		));
+		assert_eq!(Staking::ledger(&10).unwrap().claimed_rewards, vec![1, 2]);
+		assert!(Balances::free_balance(1337) > controller_balance);
+		assert_eq!(
+			staking_events()
+				.into_iter()
+				.filter(|e| matches!(e, Event::PayoutStarted(_, 11)))
+				.count(),
+			2
+		);
+		assert_eq!(
+			*staking_events().last().unwrap(),
+			Event::PayoutFailed(11, 1, 0, Error::<Test>::AlreadyClaimed.into())
+		);
+	})
+}
+
+#[test]
+fn reward_claimer_takes_commission_only_when_claiming() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		let balance = 1000;
+		bond_validator(11, 10, balance);
+		bond_nominator(1234, 1337, 100, vec![11]);
+		assert_ok!(Staking::set_payee(Origin::signed(1337), RewardDestination::Staked));
+
+		// only the controller can set the claimer, and only up to the maximum commission.
+		assert_noop!(
+			Staking::set_reward_claimer(Origin::signed(1234), Some((42, Perbill::from_percent(5)))),
+			Error::<Test>::NotController
+		);
+		assert_noop!(
+			Staking::set_reward_claimer(
+				Origin::signed(1337),
+				Some((42, Perbill::from_percent(11)))
+			),
+			Error::<Test>::ClaimerCommissionTooHigh
+		);
+		assert_ok!(Staking::set_reward_claimer(
+			Origin::signed(1337),
+			Some((42, Perbill::from_percent(10)))
+		));
+		assert_eq!(Staking::reward_claimer(&1234), Some((42, Perbill::from_percent(10))));
+
+		for era in 1..=2 {
+			mock::start_active_era(era);
+			Staking::reward_by_ids(vec![(11, 1)]);
+			// compute and ensure the reward amount is greater than zero.
+			let _ = current_total_payout_for_duration(reward_time_per_era());
+		}
+		mock::start_active_era(3);
+
+		// someone else pays out, the nominator gets its full reward compounded.
+		let active_before = Staking::ledger(&1337).unwrap().active;
+		assert_ok!(Staking::payout_stakers(Origin::signed(99), 11, 1, 0));
+		assert!(Staking::ledger(&1337).unwrap().active > active_before);
+		assert_eq!(Balances::free_balance(42), 0);
+
+		// the claimer pays out, and takes its share of the nominator reward.
+		let active_before = Staking::ledger(&1337).unwrap().active;
+		assert_ok!(Staking::payout_stakers_by_page(Origin::signed(42), vec![(11, 2, 0)]));
+		let nominator_reward = Staking::ledger(&1337).unwrap().active - active_before;
+		let claimer_reward = Balances::free_balance(42);
+		assert!(claimer_reward > 0);
+		assert_eq!(claimer_reward, Perbill::from_percent(10) * (nominator_reward + claimer_reward));
+		assert!(staking_events().contains(&Event::ClaimerRewarded(1234, 42, claimer_reward)));
+
+		// the claimer can be removed.
+		assert_ok!(Staking::set_reward_claimer(Origin::signed(1337), None));
+		assert_eq!(Staking::reward_claimer(&1234), None);
+	})
+}
+
+#[test]
+fn reward_claimer_takes_commission_of_validator() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		bond_validator(11, 10, 1000);
+		assert_ok!(Staking::set_payee(Origin::signed(10), RewardDestination::Stash));
+		assert_ok!(Staking::set_reward_claimer(
+			Origin::signed(10),
+			Some((42, Perbill::from_percent(10)))
+		));
+
+		mock::start_active_era(1);
+		Staking::reward_by_ids(vec![(11, 1)]);
+		// compute and ensure the reward amount is greater than zero.
+		let _ = current_total_payout_for_duration(reward_time_per_era());
+		mock::start_active_era(2);
+
+		let balance_before = Balances::free_balance(11);
+		assert_ok!(Staking::payout_stakers(Origin::signed(42), 11, 1, 0));
+		let validator_reward = Balances::free_balance(11) - balance_before;
+		let claimer_reward = Balances::free_balance(42);
+		assert!(claimer_reward > 0);
+		assert_eq!(claimer_reward, Perbill::from_percent(10) * (validator_reward + claimer_reward));
+		assert!(staking_events().contains(&Event::ClaimerRewarded(11, 42, claimer_reward)));
+	})
+}
+
 #[test]
 fn session_buffering_with_offset() {
 	// similar to live-chains, have some offset for the first session
@@ -4073,6 +4455,71 @@ mod election_data_provider {
 			});
 	}
 
+	#[test]
+	fn voters_paged_continues_from_previous_page() {
+		ExtBuilder::default()
+			.nominate(true) // add nominator 101, who nominates [11, 21]
+			.add_staker(61, 60, 2_000, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.add_staker(71, 70, 2_000, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.add_staker(81, 80, 2_000, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.build_and_execute(|| {
+				assert_eq!(
+					<Test as Config>::SortedListProvider::iter().collect::<Vec<_>>(),
+					vec![61, 71, 81, 101]
+				);
+				assert_eq!(VoterSnapshotStatus::<Test>::get(), SnapshotStatus::Waiting);
+
+				let stashes_of = |page| {
+					Staking::voters_paged(Some(4), page)
+						.unwrap()
+						.into_iter()
+						.map(|(stash, _, _)| stash)
+						.collect::<Vec<_>>()
+				};
+
+				// the first page has all the validators, and then the top nominator.
+				let first = stashes_of(0);
+				assert_eq!(first.len(), 4);
+				assert!(<Validators<Test>>::iter().all(|(v, _)| first.contains(&v)));
+				assert_eq!(first[3], 61);
+				assert_eq!(VoterSnapshotStatus::<Test>::get(), SnapshotStatus::Ongoing(61));
+
+				// the second page has the rest of the nominators.
+				assert_eq!(stashes_of(1), vec![71, 81, 101]);
+				assert_eq!(VoterSnapshotStatus::<Test>::get(), SnapshotStatus::Consumed);
+
+				// and there is nothing left afterwards.
+				assert!(stashes_of(2).is_empty());
+
+				// requesting the first page again restarts the snapshot.
+				assert_eq!(stashes_of(0), first);
+				assert_eq!(VoterSnapshotStatus::<Test>::get(), SnapshotStatus::Ongoing(61));
+			});
+	}
+
+	#[test]
+	fn voters_paged_fails_if_last_nominator_is_gone() {
+		ExtBuilder::default()
+			.add_staker(61, 60, 2_000, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.add_staker(71, 70, 2_000, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.build_and_execute(|| {
+				// 3 validators and the first of the nominators.
+				assert_eq!(Staking::voters_paged(Some(4), 0).unwrap().len(), 4);
+				let last = match VoterSnapshotStatus::<Test>::get() {
+					SnapshotStatus::Ongoing(last) => last,
+					_ => panic!("snapshot should be ongoing"),
+				};
+
+				// the last nominator of the previous page chills in between pages.
+				assert_ok!(Staking::chill(Origin::signed(last - 1)));
+
+				assert_eq!(
+					Staking::voters_paged(Some(4), 1).unwrap_err(),
+					"last nominator of the previous page not found"
+				);
+			});
+	}
+
 	#[test]
 	fn only_iterates_max_2_times_nominators_quota() {
 		ExtBuilder::default()
@@ -4357,7 +4804,9 @@ fn chill_other_works() {
 				None,
 				None,
 				None,
-				Zero::zero()
+				Zero::zero(),
+				None,
+				None
 			));
 
 			// Still can't chill these users
@@ -4378,7 +4827,9 @@ fn chill_other_works() {
 				Some(10),
 				Some(10),
 				None,
-				Zero::zero()
+				Zero::zero(),
+				None,
+				None
 			));
 
 			// Still can't chill these users
@@ -4399,7 +4850,9 @@ fn chill_other_works() {
 				None,
 				None,
 				Some(Percent::from_percent(0)),
-				Zero::zero()
+				Zero::zero(),
+				None,
+				None
 			));
 
 			// Still can't chill these users
@@ -4420,7 +4873,9 @@ fn chill_other_works() {
 				Some(10),
 				Some(10),
 				Some(Percent::from_percent(75)),
-				Zero::zero()
+				Zero::zero(),
+				None,
+				None
 			));
 
 			// 16 people total because tests start with 2 active one
@@ -4466,6 +4921,8 @@ fn capped_stakers_works() {
 			Some(max),
 			Some(Percent::from_percent(0)),
 			Zero::zero(),
+			None,
+			None,
 		));
 
 		// can create `max - validator_count` validators
@@ -4536,6 +4993,8 @@ fn capped_stakers_works() {
 			None,
 			None,
 			Zero::zero(),
+			None,
+			None,
 		));
 		assert_ok!(Staking::nominate(Origin::signed(last_nominator), vec![1]));
 		assert_ok!(Staking::validate(Origin::signed(last_validator), ValidatorPrefs::default()));
@@ -4558,6 +5017,8 @@ fn min_commission_works() {
 			None,
 			None,
 			Perbill::from_percent(10),
+			None,
+			None,
 		));
 
 		// can't make it less than 10 now
@@ -4582,6 +5043,103 @@ fn min_commission_works() {
 	})
 }
 
+#[test]
+fn max_commission_and_commission_change_are_enforced() {
+	ExtBuilder::default().build_and_execute(|| {
+		let era = current_era();
+		assert_eq!(Staking::validators(&11).commission, Perbill::zero());
+
+		assert_ok!(Staking::set_staking_configs(
+			Origin::root(),
+			0,
+			0,
+			None,
+			None,
+			None,
+			Zero::zero(),
+			Some(Perbill::from_percent(50)),
+			Some(Perbill::from_percent(10)),
+		));
+
+		// can't make it more than 50.
+		assert_noop!(
+			Staking::validate(
+				Origin::signed(10),
+				ValidatorPrefs { commission: Perbill::from_percent(60), blocked: false }
+			),
+			Error::<Test>::CommissionTooHigh
+		);
+		// can't increase it by more than 10 at once.
+		assert_noop!(
+			Staking::validate(
+				Origin::signed(10),
+				ValidatorPrefs { commission: Perbill::from_percent(15), blocked: false }
+			),
+			Error::<Test>::CommissionChangeTooHigh
+		);
+
+		// the increase is scheduled, not applied.
+		assert_ok!(Staking::validate(
+			Origin::signed(10),
+			ValidatorPrefs { commission: Perbill::from_percent(10), blocked: false }
+		));
+		assert_eq!(Staking::validators(&11).commission, Perbill::zero());
+		assert_eq!(Staking::pending_commission(&11), Some((Perbill::from_percent(10), era + 2)));
+		assert_eq!(
+			*staking_events().last().unwrap(),
+			Event::CommissionChangeScheduled(11, Perbill::from_percent(10), era + 2)
+		);
+
+		// the next era still uses the old commission.
+		mock::start_active_era(era + 1);
+		assert_eq!(Staking::eras_validator_prefs(era + 1, &11).commission, Perbill::zero());
+		assert!(Staking::pending_commission(&11).is_some());
+
+		// and the one after uses the new one.
+		mock::start_active_era(era + 2);
+		assert_eq!(
+			Staking::eras_validator_prefs(era + 2, &11).commission,
+			Perbill::from_percent(10)
+		);
+		assert_eq!(Staking::validators(&11).commission, Perbill::from_percent(10));
+		assert_eq!(Staking::pending_commission(&11), None);
+
+		// an increase is cancelled by a subsequent decrease, which is applied right away.
+		assert_ok!(Staking::validate(
+			Origin::signed(10),
+			ValidatorPrefs { commission: Perbill::from_percent(20), blocked: false }
+		));
+		assert!(Staking::pending_commission(&11).is_some());
+		assert_ok!(Staking::validate(
+			Origin::signed(10),
+			ValidatorPrefs { commission: Perbill::from_percent(5), blocked: false }
+		));
+		assert_eq!(Staking::validators(&11).commission, Perbill::from_percent(5));
+		assert_eq!(Staking::pending_commission(&11), None);
+
+		// chilling does not allow to bypass the limit, even eras later.
+		assert_ok!(Staking::chill(Origin::signed(10)));
+		mock::start_active_era(era + 4);
+		assert_eq!(Staking::last_commission(&11), Some(Perbill::from_percent(5)));
+		assert_noop!(
+			Staking::validate(
+				Origin::signed(10),
+				ValidatorPrefs { commission: Perbill::from_percent(50), blocked: false }
+			),
+			Error::<Test>::CommissionChangeTooHigh
+		);
+		assert_ok!(Staking::validate(
+			Origin::signed(10),
+			ValidatorPrefs { commission: Perbill::from_percent(15), blocked: false }
+		));
+		assert_eq!(Staking::validators(&11).commission, Perbill::from_percent(5));
+		assert_eq!(
+			Staking::pending_commission(&11),
+			Some((Perbill::from_percent(15), era + 4 + 2))
+		);
+	})
+}
+
 mod sorted_list_provider {
 	use super::*;
 	use frame_election_provider_support::SortedListProvider;
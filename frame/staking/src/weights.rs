@@ -54,6 +54,7 @@ pub trait WeightInfo {
 	fn nominate(n: u32, ) -> Weight;
 	fn chill() -> Weight;
 	fn set_payee() -> Weight;
+	fn set_reward_claimer() -> Weight;
 	fn set_controller() -> Weight;
 	fn set_validator_count() -> Weight;
 	fn force_no_eras() -> Weight;
@@ -144,18 +145,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Staking Ledger (r:1 w:0)
 	// Storage: Staking MinValidatorBond (r:1 w:0)
 	// Storage: Staking MinCommission (r:1 w:0)
+	// Storage: Staking MaxCommission (r:1 w:0)
 	// Storage: Staking Validators (r:1 w:1)
 	// Storage: Staking MaxValidatorsCount (r:1 w:0)
+	// Storage: Staking MaxCommissionChange (r:1 w:0)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Staking LastCommission (r:1 w:1)
 	// Storage: Staking Nominators (r:1 w:1)
 	// Storage: Staking CounterForNominators (r:1 w:1)
 	// Storage: BagsList ListNodes (r:2 w:2)
 	// Storage: BagsList ListBags (r:1 w:1)
 	// Storage: BagsList CounterForListNodes (r:1 w:1)
 	// Storage: Staking CounterForValidators (r:1 w:1)
+	// Storage: Staking PendingCommission (r:0 w:1)
+	// Placeholder: the accesses of the commission limits are added by hand to the DB weight of
+	// the last benchmark.
 	fn validate() -> Weight {
 		(66_587_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(12 as Weight))
-			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
 	}
 	// Storage: Staking Ledger (r:1 w:0)
 	// Storage: Staking Nominators (r:1 w:1)
@@ -205,6 +213,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Staking Ledger (r:1 w:0)
+	// Storage: Staking RewardClaimers (r:0 w:1)
+	// Placeholder: not benchmarked yet, uses the weight of `set_payee`, which has the same storage
+	// access.
+	fn set_reward_claimer() -> Weight {
+		(11_757_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	// Storage: Staking Bonded (r:1 w:1)
 	// Storage: Staking Ledger (r:2 w:2)
 	fn set_controller() -> Weight {
@@ -273,18 +290,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Staking ErasValidatorReward (r:1 w:0)
 	// Storage: Staking Bonded (r:2 w:0)
 	// Storage: Staking Ledger (r:1 w:1)
-	// Storage: Staking ErasStakersClipped (r:1 w:0)
+	// Storage: Staking ErasStakersOverview (r:1 w:0)
+	// Storage: Staking ClaimedRewards (r:1 w:1)
+	// Storage: Staking ErasStakersPaged (r:1 w:0)
 	// Storage: Staking ErasRewardPoints (r:1 w:0)
 	// Storage: Staking ErasValidatorPrefs (r:1 w:0)
+	// Storage: Staking RewardClaimers (r:2 w:0)
 	// Storage: Staking Payee (r:2 w:0)
-	// Storage: System Account (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
+	// Placeholder: the accesses to `RewardClaimers` and the claimer's account are added by hand
+	// to the DB weight of the last benchmark.
 	fn payout_stakers_dead_controller(n: u32, ) -> Weight {
 		(103_452_000 as Weight)
 			// Standard Error: 19_000
 			.saturating_add((42_909_000 as Weight).saturating_mul(n as Weight))
-			.saturating_add(T::DbWeight::get().reads(10 as Weight))
-			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
 	}
 	// Storage: Staking CurrentEra (r:1 w:0)
@@ -292,19 +314,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Staking ErasValidatorReward (r:1 w:0)
 	// Storage: Staking Bonded (r:2 w:0)
 	// Storage: Staking Ledger (r:2 w:2)
-	// Storage: Staking ErasStakersClipped (r:1 w:0)
+	// Storage: Staking ErasStakersOverview (r:1 w:0)
+	// Storage: Staking ClaimedRewards (r:1 w:1)
+	// Storage: Staking ErasStakersPaged (r:1 w:0)
 	// Storage: Staking ErasRewardPoints (r:1 w:0)
 	// Storage: Staking ErasValidatorPrefs (r:1 w:0)
+	// Storage: Staking RewardClaimers (r:2 w:0)
 	// Storage: Staking Payee (r:2 w:0)
-	// Storage: System Account (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
 	// Storage: Balances Locks (r:2 w:2)
+	// Placeholder: the accesses to `RewardClaimers` and the claimer's account are added by hand
+	// to the DB weight of the last benchmark.
 	fn payout_stakers_alive_staked(n: u32, ) -> Weight {
 		(145_995_000 as Weight)
 			// Standard Error: 27_000
 			.saturating_add((55_974_000 as Weight).saturating_mul(n as Weight))
-			.saturating_add(T::DbWeight::get().reads(11 as Weight))
-			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(n as Weight)))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
 	}
 	// Storage: Staking Ledger (r:1 w:1)
@@ -322,11 +349,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: Staking CurrentEra (r:1 w:0)
 	// Storage: Staking HistoryDepth (r:1 w:1)
-	// Storage: Staking ErasStakersClipped (r:0 w:2)
+	// Storage: Staking ErasStakersOverview (r:0 w:2)
+	// Storage: Staking ClaimedRewards (r:0 w:2)
 	// Storage: Staking ErasValidatorPrefs (r:0 w:2)
 	// Storage: Staking ErasValidatorReward (r:0 w:1)
 	// Storage: Staking ErasRewardPoints (r:0 w:1)
-	// Storage: Staking ErasStakers (r:0 w:2)
+	// Storage: Staking ErasStakersPaged (r:0 w:2)
 	// Storage: Staking ErasTotalStake (r:0 w:1)
 	// Storage: Staking ErasStartSessionIndex (r:0 w:1)
 	fn set_history_depth(e: u32, ) -> Weight {
@@ -371,9 +399,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Staking MinimumValidatorCount (r:1 w:0)
 	// Storage: Staking CurrentEra (r:1 w:1)
 	// Storage: Staking HistoryDepth (r:1 w:0)
-	// Storage: Staking ErasStakersClipped (r:0 w:1)
+	// Storage: Staking ErasStakersOverview (r:0 w:1)
 	// Storage: Staking ErasValidatorPrefs (r:0 w:1)
-	// Storage: Staking ErasStakers (r:0 w:1)
+	// Storage: Staking ErasStakersPaged (r:0 w:1)
 	// Storage: Staking ErasTotalStake (r:0 w:1)
 	// Storage: Staking ErasStartSessionIndex (r:0 w:1)
 	fn new_era(v: u32, n: u32, ) -> Weight {
@@ -422,9 +450,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Staking ChillThreshold (r:0 w:1)
 	// Storage: Staking MaxNominatorsCount (r:0 w:1)
 	// Storage: Staking MinNominatorBond (r:0 w:1)
+	// Storage: Staking MaxCommission (r:0 w:1)
+	// Storage: Staking MaxCommissionChange (r:0 w:1)
+	// Placeholder: the writes of the commission limits are added by hand to the DB weight of the
+	// last benchmark.
 	fn set_staking_configs() -> Weight {
 		(6_187_000 as Weight)
-			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
 	// Storage: Staking Ledger (r:1 w:0)
 	// Storage: Staking ChillThreshold (r:1 w:0)
@@ -512,18 +544,25 @@ impl WeightInfo for () {
 	// Storage: Staking Ledger (r:1 w:0)
 	// Storage: Staking MinValidatorBond (r:1 w:0)
 	// Storage: Staking MinCommission (r:1 w:0)
+	// Storage: Staking MaxCommission (r:1 w:0)
 	// Storage: Staking Validators (r:1 w:1)
 	// Storage: Staking MaxValidatorsCount (r:1 w:0)
+	// Storage: Staking MaxCommissionChange (r:1 w:0)
+	// Storage: Staking CurrentEra (r:1 w:0)
+	// Storage: Staking LastCommission (r:1 w:1)
 	// Storage: Staking Nominators (r:1 w:1)
 	// Storage: Staking CounterForNominators (r:1 w:1)
 	// Storage: BagsList ListNodes (r:2 w:2)
 	// Storage: BagsList ListBags (r:1 w:1)
 	// Storage: BagsList CounterForListNodes (r:1 w:1)
 	// Storage: Staking CounterForValidators (r:1 w:1)
+	// Storage: Staking PendingCommission (r:0 w:1)
+	// Placeholder: the accesses of the commission limits are added by hand to the DB weight of
+	// the last benchmark.
 	fn validate() -> Weight {
 		(66_587_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
 	}
 	// Storage: Staking Ledger (r:1 w:0)
 	// Storage: Staking Nominators (r:1 w:1)
@@ -573,6 +612,15 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Staking Ledger (r:1 w:0)
+	// Storage: Staking RewardClaimers (r:0 w:1)
+	// Placeholder: not benchmarked yet, uses the weight of `set_payee`, which has the same storage
+	// access.
+	fn set_reward_claimer() -> Weight {
+		(11_757_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 	// Storage: Staking Bonded (r:1 w:1)
 	// Storage: Staking Ledger (r:2 w:2)
 	fn set_controller() -> Weight {
@@ -641,18 +689,23 @@ impl WeightInfo for () {
 	// Storage: Staking ErasValidatorReward (r:1 w:0)
 	// Storage: Staking Bonded (r:2 w:0)
 	// Storage: Staking Ledger (r:1 w:1)
-	// Storage: Staking ErasStakersClipped (r:1 w:0)
+	// Storage: Staking ErasStakersOverview (r:1 w:0)
+	// Storage: Staking ClaimedRewards (r:1 w:1)
+	// Storage: Staking ErasStakersPaged (r:1 w:0)
 	// Storage: Staking ErasRewardPoints (r:1 w:0)
 	// Storage: Staking ErasValidatorPrefs (r:1 w:0)
+	// Storage: Staking RewardClaimers (r:2 w:0)
 	// Storage: Staking Payee (r:2 w:0)
-	// Storage: System Account (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
+	// Placeholder: the accesses to `RewardClaimers` and the claimer's account are added by hand
+	// to the DB weight of the last benchmark.
 	fn payout_stakers_dead_controller(n: u32, ) -> Weight {
 		(103_452_000 as Weight)
 			// Standard Error: 19_000
 			.saturating_add((42_909_000 as Weight).saturating_mul(n as Weight))
-			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
-			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
 	}
 	// Storage: Staking CurrentEra (r:1 w:0)
@@ -660,19 +713,24 @@ impl WeightInfo for () {
 	// Storage: Staking ErasValidatorReward (r:1 w:0)
 	// Storage: Staking Bonded (r:2 w:0)
 	// Storage: Staking Ledger (r:2 w:2)
-	// Storage: Staking ErasStakersClipped (r:1 w:0)
+	// Storage: Staking ErasStakersOverview (r:1 w:0)
+	// Storage: Staking ClaimedRewards (r:1 w:1)
+	// Storage: Staking ErasStakersPaged (r:1 w:0)
 	// Storage: Staking ErasRewardPoints (r:1 w:0)
 	// Storage: Staking ErasValidatorPrefs (r:1 w:0)
+	// Storage: Staking RewardClaimers (r:2 w:0)
 	// Storage: Staking Payee (r:2 w:0)
-	// Storage: System Account (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
 	// Storage: Balances Locks (r:2 w:2)
+	// Placeholder: the accesses to `RewardClaimers` and the claimer's account are added by hand
+	// to the DB weight of the last benchmark.
 	fn payout_stakers_alive_staked(n: u32, ) -> Weight {
 		(145_995_000 as Weight)
 			// Standard Error: 27_000
 			.saturating_add((55_974_000 as Weight).saturating_mul(n as Weight))
-			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
-			.saturating_add(RocksDbWeight::get().reads((5 as Weight).saturating_mul(n as Weight)))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
 	}
 	// Storage: Staking Ledger (r:1 w:1)
@@ -690,11 +748,12 @@ impl WeightInfo for () {
 	}
 	// Storage: Staking CurrentEra (r:1 w:0)
 	// Storage: Staking HistoryDepth (r:1 w:1)
-	// Storage: Staking ErasStakersClipped (r:0 w:2)
+	// Storage: Staking ErasStakersOverview (r:0 w:2)
+	// Storage: Staking ClaimedRewards (r:0 w:2)
 	// Storage: Staking ErasValidatorPrefs (r:0 w:2)
 	// Storage: Staking ErasValidatorReward (r:0 w:1)
 	// Storage: Staking ErasRewardPoints (r:0 w:1)
-	// Storage: Staking ErasStakers (r:0 w:2)
+	// Storage: Staking ErasStakersPaged (r:0 w:2)
 	// Storage: Staking ErasTotalStake (r:0 w:1)
 	// Storage: Staking ErasStartSessionIndex (r:0 w:1)
 	fn set_history_depth(e: u32, ) -> Weight {
@@ -739,9 +798,9 @@ impl WeightInfo for () {
 	// Storage: Staking MinimumValidatorCount (r:1 w:0)
 	// Storage: Staking CurrentEra (r:1 w:1)
 	// Storage: Staking HistoryDepth (r:1 w:0)
-	// Storage: Staking ErasStakersClipped (r:0 w:1)
+	// Storage: Staking ErasStakersOverview (r:0 w:1)
 	// Storage: Staking ErasValidatorPrefs (r:0 w:1)
-	// Storage: Staking ErasStakers (r:0 w:1)
+	// Storage: Staking ErasStakersPaged (r:0 w:1)
 	// Storage: Staking ErasTotalStake (r:0 w:1)
 	// Storage: Staking ErasStartSessionIndex (r:0 w:1)
 	fn new_era(v: u32, n: u32, ) -> Weight {
@@ -790,9 +849,13 @@ impl WeightInfo for () {
 	// Storage: Staking ChillThreshold (r:0 w:1)
 	// Storage: Staking MaxNominatorsCount (r:0 w:1)
 	// Storage: Staking MinNominatorBond (r:0 w:1)
+	// Storage: Staking MaxCommission (r:0 w:1)
+	// Storage: Staking MaxCommissionChange (r:0 w:1)
+	// Placeholder: the writes of the commission limits are added by hand to the DB weight of the
+	// last benchmark.
 	fn set_staking_configs() -> Weight {
 		(6_187_000 as Weight)
-			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 	}
 	// Storage: Staking Ledger (r:1 w:0)
 	// Storage: Staking ChillThreshold (r:1 w:0)
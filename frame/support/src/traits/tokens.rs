@@ -24,8 +24,11 @@ pub mod imbalance;
 mod misc;
 pub mod nonfungible;
 pub mod nonfungibles;
+pub mod pay;
 pub use imbalance::Imbalance;
 pub use misc::{
-	AssetId, Balance, BalanceConversion, BalanceStatus, DepositConsequence, ExistenceRequirement,
-	WithdrawConsequence, WithdrawReasons,
+	AssetId, Balance, BalanceConversion, BalanceStatus, ConversionFromAssetBalance,
+	DepositConsequence, ExistenceRequirement, UnityAssetBalanceConversion, WithdrawConsequence,
+	WithdrawReasons,
 };
+pub use pay::{Pay, PayAssetFromAccount, PayFromAccount, PaymentStatus};
@@ -254,17 +254,25 @@ impl<
 	}
 }
 
+// Funds are held through the single-item view for the default hold reason of `F`.
 impl<
 		F: fungibles::InspectHold<AccountId>,
 		A: Get<<F as fungibles::Inspect<AccountId>>::AssetId>,
 		AccountId,
 	> InspectHold<AccountId> for ItemOf<F, A, AccountId>
+where
+	F::Reason: Default,
 {
 	fn balance_on_hold(who: &AccountId) -> Self::Balance {
-		<F as fungibles::InspectHold<AccountId>>::balance_on_hold(A::get(), who)
+		<F as fungibles::InspectHold<AccountId>>::total_balance_on_hold(A::get(), who)
 	}
 	fn can_hold(who: &AccountId, amount: Self::Balance) -> bool {
-		<F as fungibles::InspectHold<AccountId>>::can_hold(A::get(), who, amount)
+		<F as fungibles::InspectHold<AccountId>>::can_hold(
+			A::get(),
+			&Default::default(),
+			who,
+			amount,
+		)
 	}
 }
 
@@ -273,16 +281,24 @@ impl<
 		A: Get<<F as fungibles::Inspect<AccountId>>::AssetId>,
 		AccountId,
 	> MutateHold<AccountId> for ItemOf<F, A, AccountId>
+where
+	F::Reason: Default,
 {
 	fn hold(who: &AccountId, amount: Self::Balance) -> DispatchResult {
-		<F as fungibles::MutateHold<AccountId>>::hold(A::get(), who, amount)
+		<F as fungibles::MutateHold<AccountId>>::hold(A::get(), &Default::default(), who, amount)
 	}
 	fn release(
 		who: &AccountId,
 		amount: Self::Balance,
 		best_effort: bool,
 	) -> Result<Self::Balance, DispatchError> {
-		<F as fungibles::MutateHold<AccountId>>::release(A::get(), who, amount, best_effort)
+		<F as fungibles::MutateHold<AccountId>>::release(
+			A::get(),
+			&Default::default(),
+			who,
+			amount,
+			best_effort,
+		)
 	}
 	fn transfer_held(
 		source: &AccountId,
@@ -293,6 +309,7 @@ impl<
 	) -> Result<Self::Balance, DispatchError> {
 		<F as fungibles::MutateHold<AccountId>>::transfer_held(
 			A::get(),
+			&Default::default(),
 			source,
 			dest,
 			amount,
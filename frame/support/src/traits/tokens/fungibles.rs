@@ -22,6 +22,7 @@ use super::{
 	*,
 };
 use crate::dispatch::{DispatchError, DispatchResult};
+use scale_info::TypeInfo;
 use sp_runtime::traits::Saturating;
 use sp_std::vec::Vec;
 
@@ -169,35 +170,60 @@ pub trait Transfer<AccountId>: Inspect<AccountId> {
 }
 
 /// Trait for inspecting a set of named fungible assets which can be placed on hold.
+///
+/// Funds are held for a particular reason, and the funds held for each reason are accounted for
+/// separately.
 pub trait InspectHold<AccountId>: Inspect<AccountId> {
-	/// Amount of funds held in hold.
-	fn balance_on_hold(asset: Self::AssetId, who: &AccountId) -> Self::Balance;
+	/// An identifier for the reason for which funds are held.
+	type Reason: codec::Encode + TypeInfo + 'static;
 
-	/// Check to see if some `amount` of `asset` may be held on the account of `who`.
-	fn can_hold(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) -> bool;
+	/// Amount of funds held in hold for all reasons.
+	fn total_balance_on_hold(asset: Self::AssetId, who: &AccountId) -> Self::Balance;
+
+	/// Amount of funds held in hold for the given `reason`.
+	fn balance_on_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+	) -> Self::Balance;
+
+	/// Check to see if some `amount` of `asset` may be held on the account of `who` for the given
+	/// `reason`.
+	fn can_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> bool;
 }
 
 /// Trait for mutating a set of named fungible assets which can be placed on hold.
 pub trait MutateHold<AccountId>: InspectHold<AccountId> + Transfer<AccountId> {
-	/// Hold some funds in an account.
-	fn hold(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) -> DispatchResult;
+	/// Hold some funds in an account for the given `reason`.
+	fn hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
 
-	/// Release some funds in an account from being on hold.
+	/// Release some funds held in an account for the given `reason`.
 	///
 	/// If `best_effort` is `true`, then the amount actually released and returned as the inner
 	/// value of `Ok` may be smaller than the `amount` passed.
 	fn release(
 		asset: Self::AssetId,
+		reason: &Self::Reason,
 		who: &AccountId,
 		amount: Self::Balance,
 		best_effort: bool,
 	) -> Result<Self::Balance, DispatchError>;
 
-	/// Transfer held funds into a destination account.
+	/// Transfer funds held for the given `reason` into a destination account.
 	///
 	/// If `on_hold` is `true`, then the destination account must already exist and the assets
-	/// transferred will still be on hold in the destination account. If not, then the destination
-	/// account need not already exist, but must be creatable.
+	/// transferred will still be on hold in the destination account, for the same `reason`. If
+	/// not, then the destination account need not already exist, but must be creatable.
 	///
 	/// If `best_effort` is `true`, then an amount less than `amount` may be transferred without
 	/// error.
@@ -206,6 +232,7 @@ pub trait MutateHold<AccountId>: InspectHold<AccountId> + Transfer<AccountId> {
 	/// changed.
 	fn transfer_held(
 		asset: Self::AssetId,
+		reason: &Self::Reason,
 		source: &AccountId,
 		dest: &AccountId,
 		amount: Self::Balance,
@@ -216,7 +243,7 @@ pub trait MutateHold<AccountId>: InspectHold<AccountId> + Transfer<AccountId> {
 
 /// Trait for mutating one of several types of fungible assets which can be held.
 pub trait BalancedHold<AccountId>: Balanced<AccountId> + MutateHold<AccountId> {
-	/// Release and slash some funds in an account.
+	/// Release and slash some funds held in an account for the given `reason`.
 	///
 	/// The resulting imbalance is the first item of the tuple returned.
 	///
@@ -224,6 +251,7 @@ pub trait BalancedHold<AccountId>: Balanced<AccountId> + MutateHold<AccountId> {
 	/// then a non-zero second item will be returned.
 	fn slash_held(
 		asset: Self::AssetId,
+		reason: &Self::Reason,
 		who: &AccountId,
 		amount: Self::Balance,
 	) -> (CreditOf<AccountId, Self>, Self::Balance);
@@ -232,10 +260,11 @@ pub trait BalancedHold<AccountId>: Balanced<AccountId> + MutateHold<AccountId> {
 impl<AccountId, T: Balanced<AccountId> + MutateHold<AccountId>> BalancedHold<AccountId> for T {
 	fn slash_held(
 		asset: Self::AssetId,
+		reason: &Self::Reason,
 		who: &AccountId,
 		amount: Self::Balance,
 	) -> (CreditOf<AccountId, Self>, Self::Balance) {
-		let actual = match Self::release(asset, who, amount, true) {
+		let actual = match Self::release(asset, reason, who, amount, true) {
 			Ok(x) => x,
 			Err(_) => return (Imbalance::zero(asset), amount),
 		};
@@ -243,6 +272,45 @@ impl<AccountId, T: Balanced<AccountId> + MutateHold<AccountId>> BalancedHold<Acc
 	}
 }
 
+/// Trait for inspecting a set of named fungible assets which can be frozen.
+///
+/// Freezes are identified by an id and overlap: the amount which may not be withdrawn from an
+/// account is the largest of its freezes, rather than their sum.
+pub trait InspectFreeze<AccountId>: Inspect<AccountId> {
+	/// An identifier for a freeze.
+	type Id: codec::Encode + TypeInfo + 'static;
+
+	/// Amount of funds of `who` frozen under the given `id`.
+	fn balance_frozen(asset: Self::AssetId, id: &Self::Id, who: &AccountId) -> Self::Balance;
+
+	/// Check to see if a freeze with the given `id` may be placed on the account of `who`.
+	fn can_freeze(asset: Self::AssetId, id: &Self::Id, who: &AccountId) -> bool;
+}
+
+/// Trait for mutating a set of named fungible assets which can be frozen.
+pub trait MutateFreeze<AccountId>: InspectFreeze<AccountId> {
+	/// Set the freeze with the given `id` on the account of `who` to `amount`, replacing any
+	/// previous amount. An `amount` of zero removes the freeze.
+	fn set_freeze(
+		asset: Self::AssetId,
+		id: &Self::Id,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// Set the freeze with the given `id` on the account of `who` to the greater of `amount` and
+	/// its current amount.
+	fn extend_freeze(
+		asset: Self::AssetId,
+		id: &Self::Id,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// Remove the freeze with the given `id` from the account of `who`.
+	fn thaw(asset: Self::AssetId, id: &Self::Id, who: &AccountId) -> DispatchResult;
+}
+
 /// Trait for providing the ability to create new fungible assets.
 pub trait Create<AccountId>: Inspect<AccountId> {
 	/// Create a new fungible asset.
@@ -255,26 +323,28 @@ pub trait Create<AccountId>: Inspect<AccountId> {
 }
 
 /// Trait for providing the ability to destroy existing fungible assets.
+///
+/// Destruction happens in phases, so that an asset with an arbitrary number of accounts and
+/// approvals can be destroyed over several blocks: `start_destroy` first, then `destroy_accounts`
+/// and `destroy_approvals` until nothing remains, and finally `finish_destroy`.
 pub trait Destroy<AccountId>: Inspect<AccountId> {
-	/// The witness data needed to destroy an asset.
-	type DestroyWitness;
-
-	/// Provide the appropriate witness data needed to destroy an asset.
-	fn get_destroy_witness(id: &Self::AssetId) -> Option<Self::DestroyWitness>;
-
-	/// Destroy an existing fungible asset.
+	/// Start the destruction of an existing fungible asset.
 	/// * `id`: The `AssetId` to be destroyed.
-	/// * `witness`: Any witness data that needs to be provided to complete the operation
-	///   successfully.
 	/// * `maybe_check_owner`: An optional account id that can be used to authorize the destroy
 	///   command. If not provided, we will not do any authorization checks before destroying the
 	///   asset.
+	fn start_destroy(id: Self::AssetId, maybe_check_owner: Option<AccountId>) -> DispatchResult;
+
+	/// Destroy up to `max_items` of the accounts of an asset whose destruction has started.
 	///
-	/// If successful, this function will return the actual witness data from the destroyed asset.
-	/// This may be different than the witness data provided, and can be used to refund weight.
-	fn destroy(
-		id: Self::AssetId,
-		witness: Self::DestroyWitness,
-		maybe_check_owner: Option<AccountId>,
-	) -> Result<Self::DestroyWitness, DispatchError>;
+	/// Returns the number of destroyed accounts.
+	fn destroy_accounts(id: Self::AssetId, max_items: u32) -> Result<u32, DispatchError>;
+
+	/// Destroy up to `max_items` of the approvals of an asset whose destruction has started.
+	///
+	/// Returns the number of destroyed approvals.
+	fn destroy_approvals(id: Self::AssetId, max_items: u32) -> Result<u32, DispatchError>;
+
+	/// Complete the destruction of an asset whose accounts and approvals have all been destroyed.
+	fn finish_destroy(id: Self::AssetId) -> DispatchResult;
 }
@@ -30,8 +30,16 @@ use sp_runtime::{
 use sp_storage::Storage;
 
 use frame_support::{
-	assert_noop, assert_ok, pallet_prelude::GenesisBuild, parameter_types,
-	storage::StoragePrefixedMap, traits::SortedMembers, weights::Weight, PalletId,
+	assert_noop, assert_ok,
+	pallet_prelude::GenesisBuild,
+	parameter_types,
+	storage::StoragePrefixedMap,
+	traits::{
+		tokens::{PayFromAccount, UnityAssetBalanceConversion},
+		SortedMembers,
+	},
+	weights::Weight,
+	PalletId,
 };
 
 use super::*;
@@ -132,6 +140,8 @@ parameter_types! {
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 	pub const MaximumReasonLength: u32 = 16384;
 	pub const MaxApprovals: u32 = 100;
+	pub const PayoutPeriod: u64 = 10;
+	pub TreasuryAccount: u128 = Treasury::account_id();
 }
 impl pallet_treasury::Config for Test {
 	type PalletId = TreasuryPalletId;
@@ -148,6 +158,11 @@ impl pallet_treasury::Config for Test {
 	type WeightInfo = ();
 	type SpendFunds = ();
 	type MaxApprovals = MaxApprovals;
+	type SpendOrigin = frame_system::EnsureNever<u64>;
+	type AssetKind = ();
+	type Paymaster = PayFromAccount<Balances, TreasuryAccount, u128>;
+	type BalanceConverter = UnityAssetBalanceConversion;
+	type PayoutPeriod = PayoutPeriod;
 }
 parameter_types! {
 	pub const TipCountdown: u64 = 1;
@@ -25,7 +25,9 @@
 //! [`OnChargeAssetTransaction`] implementation analogously to [`pallet-transaction-payment`]. The
 //! included [`FungiblesAdapter`] (implementing [`OnChargeAssetTransaction`]) determines the fee
 //! amount by converting the fee calculated by [`pallet-transaction-payment`] into the desired
-//! asset.
+//! asset. Alternatively, the included [`AssetConversionAdapter`] pays the fee by swapping the
+//! desired asset for the native token at the current price of an asset conversion pool, such as
+//! the ones of `pallet-asset-conversion`.
 //!
 //! ## Integration
 
@@ -42,7 +44,7 @@ use frame_support::{
 	dispatch::DispatchResult,
 	traits::{
 		tokens::{
-			fungibles::{Balanced, CreditOf, Inspect},
+			fungibles::{Balanced, Inspect},
 			WithdrawConsequence,
 		},
 		IsType,
@@ -102,7 +104,7 @@ pub enum InitialPayment<T: Config> {
 	/// The initial fee was payed in the native currency.
 	Native(LiquidityInfoOf<T>),
 	/// The initial fee was payed in an asset.
-	Asset(CreditOf<T::AccountId, T::Fungibles>),
+	Asset(ChargeAssetLiquidityOf<T>),
 }
 
 pub use pallet::*;
@@ -113,6 +115,8 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_transaction_payment::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		/// The fungibles instance used to pay for transactions in assets.
 		type Fungibles: Balanced<Self::AccountId>;
 		/// The actual transaction charging logic that charges the fees.
@@ -122,6 +126,14 @@ pub mod pallet {
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
 	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The refund of a fee paid by swapping an asset could not be swapped back into the
+		/// asset, and was kept in the native currency instead.
+		AssetRefundFailed { who: T::AccountId, native_amount_kept: BalanceOf<T> },
+	}
 }
 
 /// Require the transactor pay for themselves and maybe include a tip to gain additional priority
@@ -143,7 +155,6 @@ where
 	AssetBalanceOf<T>: Send + Sync + FixedPointOperand,
 	BalanceOf<T>: Send + Sync + FixedPointOperand + IsType<ChargeAssetBalanceOf<T>>,
 	ChargeAssetIdOf<T>: Send + Sync,
-	CreditOf<T::AccountId, T::Fungibles>: IsType<ChargeAssetLiquidityOf<T>>,
 {
 	/// Utility constructor. Used only in client/factory code.
 	pub fn from(tip: BalanceOf<T>, asset_id: Option<ChargeAssetIdOf<T>>) -> Self {
@@ -172,7 +183,7 @@ where
 				fee.into(),
 				self.tip.into(),
 			)
-			.map(|i| (fee, InitialPayment::Asset(i.into())))
+			.map(|i| (fee, InitialPayment::Asset(i)))
 		} else {
 			<OnChargeTransactionOf<T> as OnChargeTransaction<T>>::withdraw_fee(
 				who, call, info, fee, self.tip,
@@ -200,7 +211,6 @@ where
 	AssetBalanceOf<T>: Send + Sync + FixedPointOperand,
 	BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand + IsType<ChargeAssetBalanceOf<T>>,
 	ChargeAssetIdOf<T>: Send + Sync,
-	CreditOf<T::AccountId, T::Fungibles>: IsType<ChargeAssetLiquidityOf<T>>,
 {
 	const IDENTIFIER: &'static str = "ChargeAssetTxPayment";
 	type AccountId = T::AccountId;
@@ -271,7 +281,7 @@ where
 					post_info,
 					actual_fee.into(),
 					tip.into(),
-					already_withdrawn.into(),
+					already_withdrawn,
 				)?;
 			},
 			InitialPayment::Nothing => {
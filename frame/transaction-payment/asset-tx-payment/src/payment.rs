@@ -25,10 +25,12 @@ use frame_support::{
 	},
 	unsigned::TransactionValidityError,
 };
+use pallet_asset_conversion::{NativeOrAssetId, Swap};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
 		AtLeast32BitUnsigned, DispatchInfoOf, MaybeSerializeDeserialize, One, PostDispatchInfoOf,
+		Saturating,
 	},
 	transaction_validity::InvalidTransaction,
 };
@@ -166,3 +168,95 @@ where
 		Ok(())
 	}
 }
+
+/// Implements the asset transaction for an asset conversion pallet (implementing [`Swap`]).
+///
+/// The fee is paid by swapping the asset used for the transaction for the exact fee in the native
+/// currency, which is then withdrawn through the [`OnChargeTransaction`] of
+/// [`pallet_transaction_payment`]. Any refund is swapped back into the asset. If that swap fails,
+/// the refund is kept in the native currency and [`Event::AssetRefundFailed`] is emitted. Note
+/// that the account paying the fee must be able to receive the native currency, and stay alive
+/// while it is withdrawn, so in general it must hold at least the existential deposit.
+pub struct AssetConversionAdapter<S>(PhantomData<S>);
+
+impl<T, S> OnChargeAssetTransaction<T> for AssetConversionAdapter<S>
+where
+	T: Config,
+	S: Swap<T::AccountId, BalanceOf<T>, NativeOrAssetId<AssetIdOf<T>>>,
+	AssetIdOf<T>: FullCodec + Copy + MaybeSerializeDeserialize + Debug + Default + Eq + TypeInfo,
+{
+	type Balance = BalanceOf<T>;
+	type AssetId = AssetIdOf<T>;
+	type LiquidityInfo = (LiquidityInfoOf<T>, AssetIdOf<T>, BalanceOf<T>);
+
+	/// Swap the asset for the predicted fee in the native currency, and withdraw it from the
+	/// transaction origin.
+	///
+	/// Note: The `fee` already includes the `tip`.
+	fn withdraw_fee(
+		who: &T::AccountId,
+		call: &T::Call,
+		info: &DispatchInfoOf<T::Call>,
+		asset_id: Self::AssetId,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		S::swap_tokens_for_exact_tokens(
+			who.clone(),
+			vec![NativeOrAssetId::Asset(asset_id), NativeOrAssetId::Native],
+			fee,
+			None,
+			who.clone(),
+			true,
+		)
+		.map_err(|_| TransactionValidityError::from(InvalidTransaction::Payment))?;
+
+		let already_withdrawn = <OnChargeTransactionOf<T> as OnChargeTransaction<T>>::withdraw_fee(
+			who, call, info, fee, tip,
+		)?;
+		Ok((already_withdrawn, asset_id, fee))
+	}
+
+	/// Correct the fee in the native currency, and swap the refund back into the asset used for
+	/// the transaction.
+	///
+	/// Note: The `corrected_fee` already includes the `tip`.
+	fn correct_and_deposit_fee(
+		who: &T::AccountId,
+		dispatch_info: &DispatchInfoOf<T::Call>,
+		post_info: &PostDispatchInfoOf<T::Call>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		(already_withdrawn, asset_id, fee): Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		<OnChargeTransactionOf<T> as OnChargeTransaction<T>>::correct_and_deposit_fee(
+			who,
+			dispatch_info,
+			post_info,
+			corrected_fee,
+			tip,
+			already_withdrawn,
+		)?;
+
+		let refund = fee.saturating_sub(corrected_fee);
+		if !refund.is_zero() {
+			// If the swap fails, e.g. because the refund is too small to be swapped, the account
+			// keeps the refund in the native currency.
+			let swapped = S::swap_exact_tokens_for_tokens(
+				who.clone(),
+				vec![NativeOrAssetId::Native, NativeOrAssetId::Asset(asset_id)],
+				refund,
+				None,
+				who.clone(),
+				true,
+			);
+			if swapped.is_err() {
+				Pallet::<T>::deposit_event(Event::<T>::AssetRefundFailed {
+					who: who.clone(),
+					native_amount_kept: refund,
+				});
+			}
+		}
+		Ok(())
+	}
+}
@@ -20,7 +20,10 @@ use frame_support::{
 	assert_ok,
 	pallet_prelude::*,
 	parameter_types,
-	traits::{fungibles::Mutate, FindAuthor},
+	traits::{
+		fungibles::{self, CreditOf, Mutate},
+		ConstU32, Currency, ExistenceRequirement, FindAuthor,
+	},
 	weights::{
 		DispatchClass, DispatchInfo, PostDispatchInfo, Weight, WeightToFeeCoefficient,
 		WeightToFeeCoefficients, WeightToFeePolynomial,
@@ -29,6 +32,7 @@ use frame_support::{
 };
 use frame_system as system;
 use frame_system::EnsureRoot;
+use pallet_asset_conversion::NativeOrAssetId;
 use pallet_balances::Call as BalancesCall;
 use pallet_transaction_payment::CurrencyAdapter;
 use smallvec::smallvec;
@@ -56,7 +60,7 @@ frame_support::construct_runtime!(
 		TransactionPayment: pallet_transaction_payment::{Pallet, Storage},
 		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 		Authorship: pallet_authorship::{Pallet, Call, Storage},
-		AssetTxPayment: pallet_asset_tx_payment::{Pallet},
+		AssetTxPayment: pallet_asset_tx_payment::{Pallet, Event<T>},
 	}
 );
 
@@ -160,6 +164,7 @@ parameter_types! {
 	pub const AssetDeposit: u64 = 2;
 	pub const MetadataDeposit: u64 = 0;
 	pub const StringLimit: u32 = 20;
+	pub const RemoveItemsLimit: u32 = 1000;
 }
 
 impl pallet_assets::Config for Runtime {
@@ -169,12 +174,18 @@ impl pallet_assets::Config for Runtime {
 	type Currency = Balances;
 	type ForceOrigin = EnsureRoot<AccountId>;
 	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetDeposit;
 	type MetadataDepositBase = MetadataDeposit;
 	type MetadataDepositPerByte = MetadataDeposit;
 	type ApprovalDeposit = MetadataDeposit;
 	type StringLimit = StringLimit;
 	type Freezer = ();
 	type Extra = ();
+	type RemoveItemsLimit = RemoveItemsLimit;
+	type HoldReason = ();
+	type FreezeId = ();
+	type MaxHolds = ConstU32<1>;
+	type MaxFreezes = ConstU32<1>;
 	type WeightInfo = ();
 }
 
@@ -207,6 +218,7 @@ impl HandleCredit<AccountId, Assets> for CreditToBlockAuthor {
 }
 
 impl Config for Runtime {
+	type Event = Event;
 	type Fungibles = Assets;
 	type OnChargeAssetTransaction = FungiblesAdapter<
 		pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>,
@@ -214,6 +226,73 @@ impl Config for Runtime {
 	>;
 }
 
+/// The account holding the liquidity of [`MockSwap`].
+const SWAP_POOL: AccountId = 999;
+
+parameter_types! {
+	pub static SwapIntoAssetFails: bool = false;
+}
+
+/// A [`Swap`] exchanging two units of an asset for one unit of the native currency with
+/// [`SWAP_POOL`]. Swaps into the asset fail while [`SwapIntoAssetFails`] is set.
+pub struct MockSwap;
+impl pallet_asset_conversion::Swap<AccountId, Balance, NativeOrAssetId<u32>> for MockSwap {
+	fn swap_exact_tokens_for_tokens(
+		sender: AccountId,
+		path: Vec<NativeOrAssetId<u32>>,
+		amount_in: Balance,
+		_amount_out_min: Option<Balance>,
+		send_to: AccountId,
+		keep_alive: bool,
+	) -> Result<Balance, DispatchError> {
+		let asset_id = match path[..] {
+			[NativeOrAssetId::Native, NativeOrAssetId::Asset(asset_id)] => asset_id,
+			_ => return Err(DispatchError::Other("unsupported path")),
+		};
+		if SwapIntoAssetFails::get() {
+			return Err(DispatchError::Other("swap failed"))
+		}
+		let amount_out = amount_in * 2;
+		let existence = if keep_alive {
+			ExistenceRequirement::KeepAlive
+		} else {
+			ExistenceRequirement::AllowDeath
+		};
+		<Balances as Currency<AccountId>>::transfer(&sender, &SWAP_POOL, amount_in, existence)?;
+		<Assets as fungibles::Transfer<AccountId>>::transfer(
+			asset_id, &SWAP_POOL, &send_to, amount_out, true,
+		)?;
+		Ok(amount_out)
+	}
+
+	fn swap_tokens_for_exact_tokens(
+		sender: AccountId,
+		path: Vec<NativeOrAssetId<u32>>,
+		amount_out: Balance,
+		_amount_in_max: Option<Balance>,
+		send_to: AccountId,
+		keep_alive: bool,
+	) -> Result<Balance, DispatchError> {
+		let asset_id = match path[..] {
+			[NativeOrAssetId::Asset(asset_id), NativeOrAssetId::Native] => asset_id,
+			_ => return Err(DispatchError::Other("unsupported path")),
+		};
+		let amount_in = amount_out * 2;
+		<Assets as fungibles::Transfer<AccountId>>::transfer(
+			asset_id, &sender, &SWAP_POOL, amount_in, keep_alive,
+		)?;
+		<Balances as Currency<AccountId>>::transfer(
+			&SWAP_POOL,
+			&send_to,
+			amount_out,
+			ExistenceRequirement::KeepAlive,
+		)?;
+		Ok(amount_in)
+	}
+}
+
+type SwapAdapter = AssetConversionAdapter<MockSwap>;
+
 pub struct ExtBuilder {
 	balance_factor: u64,
 	base_weight: u64,
@@ -746,3 +825,146 @@ fn post_dispatch_fee_is_zero_if_unsigned_pre_dispatch_fee_is_zero() {
 			assert_eq!(Assets::balance(asset_id, caller), balance);
 		});
 }
+
+/// Creates asset `1` and funds [`SWAP_POOL`] with it and with the native currency.
+fn setup_swap_pool() -> u32 {
+	let asset_id = 1;
+	assert_ok!(Assets::force_create(
+		Origin::root(),
+		asset_id,
+		42,   /* owner */
+		true, /* is_sufficient */
+		2     /* min_balance */
+	));
+	assert_ok!(Assets::mint_into(asset_id, &SWAP_POOL, 10_000));
+	Balances::make_free_balance_be(&SWAP_POOL, 10_000);
+	asset_id
+}
+
+#[test]
+fn asset_conversion_withdraw_swaps_asset_for_native_fee() {
+	ExtBuilder::default().balance_factor(100).build().execute_with(|| {
+		let asset_id = setup_swap_pool();
+		let caller = 1;
+		assert_ok!(Assets::mint_into(asset_id, &caller, 1_000));
+		let initial_native = Balances::free_balance(caller);
+
+		let fee = 30;
+		let _ = <SwapAdapter as OnChargeAssetTransaction<Runtime>>::withdraw_fee(
+			&caller,
+			CALL,
+			&info_from_weight(5),
+			asset_id,
+			fee,
+			0,
+		)
+		.unwrap();
+
+		// the asset paid for the native fee, which was withdrawn right away
+		assert_eq!(Assets::balance(asset_id, caller), 1_000 - 2 * fee);
+		assert_eq!(Balances::free_balance(caller), initial_native);
+		assert_eq!(Balances::free_balance(SWAP_POOL), 10_000 - fee);
+	});
+}
+
+#[test]
+fn asset_conversion_withdraw_fails_without_enough_asset() {
+	ExtBuilder::default().balance_factor(100).build().execute_with(|| {
+		let asset_id = setup_swap_pool();
+		let caller = 1;
+		assert_ok!(Assets::mint_into(asset_id, &caller, 10));
+
+		assert_eq!(
+			<SwapAdapter as OnChargeAssetTransaction<Runtime>>::withdraw_fee(
+				&caller,
+				CALL,
+				&info_from_weight(5),
+				asset_id,
+				30,
+				0,
+			)
+			.unwrap_err(),
+			TransactionValidityError::from(InvalidTransaction::Payment)
+		);
+		assert_eq!(Assets::balance(asset_id, caller), 10);
+	});
+}
+
+#[test]
+fn asset_conversion_refund_is_swapped_back_into_asset() {
+	ExtBuilder::default().balance_factor(100).build().execute_with(|| {
+		let asset_id = setup_swap_pool();
+		let caller = 1;
+		assert_ok!(Assets::mint_into(asset_id, &caller, 1_000));
+		let initial_native = Balances::free_balance(caller);
+
+		let (fee, corrected_fee) = (30, 20);
+		let paid = <SwapAdapter as OnChargeAssetTransaction<Runtime>>::withdraw_fee(
+			&caller,
+			CALL,
+			&info_from_weight(5),
+			asset_id,
+			fee,
+			0,
+		)
+		.unwrap();
+		assert_ok!(<SwapAdapter as OnChargeAssetTransaction<Runtime>>::correct_and_deposit_fee(
+			&caller,
+			&info_from_weight(5),
+			&default_post_info(),
+			corrected_fee,
+			0,
+			paid,
+		));
+
+		// the native refund was swapped back into the asset
+		assert_eq!(Assets::balance(asset_id, caller), 1_000 - 2 * fee + 2 * (fee - corrected_fee));
+		assert_eq!(Balances::free_balance(caller), initial_native);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			Event::AssetTxPayment(pallet_asset_tx_payment::Event::AssetRefundFailed { .. })
+		)));
+	});
+}
+
+#[test]
+fn asset_conversion_failed_refund_swap_keeps_native_refund() {
+	ExtBuilder::default().balance_factor(100).build().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = setup_swap_pool();
+		let caller = 1;
+		assert_ok!(Assets::mint_into(asset_id, &caller, 1_000));
+		let initial_native = Balances::free_balance(caller);
+
+		let (fee, corrected_fee) = (30, 20);
+		let paid = <SwapAdapter as OnChargeAssetTransaction<Runtime>>::withdraw_fee(
+			&caller,
+			CALL,
+			&info_from_weight(5),
+			asset_id,
+			fee,
+			0,
+		)
+		.unwrap();
+		SwapIntoAssetFails::set(true);
+		assert_ok!(<SwapAdapter as OnChargeAssetTransaction<Runtime>>::correct_and_deposit_fee(
+			&caller,
+			&info_from_weight(5),
+			&default_post_info(),
+			corrected_fee,
+			0,
+			paid,
+		));
+		SwapIntoAssetFails::set(false);
+
+		// the refund stays in the native currency, and the failure is reported
+		assert_eq!(Assets::balance(asset_id, caller), 1_000 - 2 * fee);
+		assert_eq!(Balances::free_balance(caller), initial_native + fee - corrected_fee);
+		System::assert_last_event(Event::AssetTxPayment(
+			pallet_asset_tx_payment::Event::AssetRefundFailed {
+				who: caller,
+				native_amount_kept: fee - corrected_fee,
+			},
+		));
+	});
+}
@@ -20,15 +20,44 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
+use frame_support::weights::Weight;
 use sp_runtime::traits::MaybeDisplay;
 
-pub use pallet_transaction_payment::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
+pub use pallet_transaction_payment::{
+	BlockFullness, FeeDetails, InclusionFee, RuntimeDispatchInfo,
+};
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait TransactionPaymentApi<Balance> where
 		Balance: Codec + MaybeDisplay,
 	{
 		fn query_info(uxt: Block::Extrinsic, len: u32) -> RuntimeDispatchInfo<Balance>;
 		fn query_fee_details(uxt: Block::Extrinsic, len: u32) -> FeeDetails<Balance>;
+		/// Query the fee details of `uxt` as they would be `blocks` blocks from now, assuming
+		/// each of those blocks ends with the given `fullness`.
+		fn query_fee_details_ahead(
+			uxt: Block::Extrinsic,
+			len: u32,
+			blocks: u32,
+			fullness: BlockFullness,
+		) -> FeeDetails<Balance>;
+	}
+
+	pub trait TransactionPaymentCallApi<Balance, Call> where
+		Balance: Codec + MaybeDisplay,
+		Call: Codec,
+	{
+		/// Query information of a dispatch class, weight, and fee of a given encoded `Call`.
+		fn query_call_info(call: Call, len: u32) -> RuntimeDispatchInfo<Balance>;
+
+		/// Query fee details of a given encoded `Call`.
+		fn query_call_fee_details(call: Call, len: u32) -> FeeDetails<Balance>;
+
+		/// Query the output of the current `WeightToFee` given some input.
+		fn query_weight_to_fee(weight: Weight) -> Balance;
+
+		/// Query the output of the current `TransactionByteFee` given some input.
+		fn query_length_to_fee(length: u32) -> Balance;
 	}
 }
@@ -0,0 +1,132 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for querying the fees of calls that are not part of an extrinsic.
+
+pub use self::gen_client::Client as TransactionPaymentCallClient;
+use crate::{fee_details_into_rpc, Error, TransactionPaymentCallRuntimeApi};
+use codec::{Codec, Decode};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, RuntimeDispatchInfo};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay},
+};
+use std::sync::Arc;
+
+/// Fee queries for calls that are not part of an extrinsic.
+///
+/// This is separate from [`crate::TransactionPaymentApi`] as it is additionally generic over the
+/// call type of the runtime.
+///
+/// Since a call alone does not have the length of the extrinsic it would be part of, `len` has to
+/// be provided by the caller.
+#[rpc]
+pub trait TransactionPaymentCallApi<BlockHash, ResponseType> {
+	#[rpc(name = "payment_queryCallInfo")]
+	fn query_call_info(
+		&self,
+		encoded_call: Bytes,
+		len: u32,
+		at: Option<BlockHash>,
+	) -> Result<ResponseType>;
+	#[rpc(name = "payment_queryCallFeeDetails")]
+	fn query_call_fee_details(
+		&self,
+		encoded_call: Bytes,
+		len: u32,
+		at: Option<BlockHash>,
+	) -> Result<FeeDetails<NumberOrHex>>;
+}
+
+/// A struct that implements the [`TransactionPaymentCallApi`] for a runtime whose call type is
+/// `Call`.
+pub struct TransactionPaymentCall<C, P, Call> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<(P, Call)>,
+}
+
+impl<C, P, Call> TransactionPaymentCall<C, P, Call> {
+	/// Create new `TransactionPaymentCall` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, Balance, Call>
+	TransactionPaymentCallApi<<Block as BlockT>::Hash, RuntimeDispatchInfo<Balance>>
+	for TransactionPaymentCall<C, Block, Call>
+where
+	Block: BlockT,
+	C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: TransactionPaymentCallRuntimeApi<Block, Balance, Call>,
+	Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex>,
+	Call: Codec + Send + Sync + 'static,
+{
+	fn query_call_info(
+		&self,
+		encoded_call: Bytes,
+		len: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<RuntimeDispatchInfo<Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		let call: Call = Decode::decode(&mut &*encoded_call).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::DecodeError.into()),
+			message: "Unable to query call info.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+		api.query_call_info(&at, call, len).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to query call info.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn query_call_fee_details(
+		&self,
+		encoded_call: Bytes,
+		len: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<FeeDetails<NumberOrHex>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		let call: Call = Decode::decode(&mut &*encoded_call).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::DecodeError.into()),
+			message: "Unable to query call fee details.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+		let fee_details = api.query_call_fee_details(&at, call, len).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to query call fee details.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+
+		fee_details_into_rpc(fee_details)
+	}
+}
@@ -21,9 +21,14 @@ pub use self::gen_client::Client as TransactionPaymentClient;
 use codec::{Codec, Decode};
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
-pub use pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi as TransactionPaymentRuntimeApi;
-use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
-use sp_api::ProvideRuntimeApi;
+use pallet_transaction_payment_rpc_runtime_api::{
+	BlockFullness, FeeDetails, InclusionFee, RuntimeDispatchInfo,
+};
+pub use pallet_transaction_payment_rpc_runtime_api::{
+	TransactionPaymentApi as TransactionPaymentRuntimeApi,
+	TransactionPaymentCallApi as TransactionPaymentCallRuntimeApi,
+};
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_core::Bytes;
 use sp_rpc::number::NumberOrHex;
@@ -33,6 +38,10 @@ use sp_runtime::{
 };
 use std::sync::Arc;
 
+mod call;
+
+pub use call::{TransactionPaymentCall, TransactionPaymentCallApi, TransactionPaymentCallClient};
+
 #[rpc]
 pub trait TransactionPaymentApi<BlockHash, ResponseType> {
 	#[rpc(name = "payment_queryInfo")]
@@ -43,8 +52,22 @@ pub trait TransactionPaymentApi<BlockHash, ResponseType> {
 		encoded_xt: Bytes,
 		at: Option<BlockHash>,
 	) -> Result<FeeDetails<NumberOrHex>>;
+	#[rpc(name = "payment_queryFeeDetailsAhead")]
+	fn query_fee_details_ahead(
+		&self,
+		encoded_xt: Bytes,
+		blocks: u32,
+		fullness: BlockFullness,
+		at: Option<BlockHash>,
+	) -> Result<FeeDetails<NumberOrHex>>;
 }
 
+/// The maximum number of blocks `payment_queryFeeDetailsAhead` is allowed to look ahead.
+///
+/// Each block is one multiplier update executed by the runtime, so this bounds the cost of a
+/// single call.
+pub const MAX_BLOCKS_AHEAD: u32 = 100_000;
+
 /// A struct that implements the [`TransactionPaymentApi`].
 pub struct TransactionPayment<C, P> {
 	client: Arc<C>,
@@ -64,6 +87,8 @@ pub enum Error {
 	DecodeError,
 	/// The call to runtime failed.
 	RuntimeError,
+	/// The runtime does not support the requested query.
+	UnsupportedRuntimeApi,
 }
 
 impl From<Error> for i64 {
@@ -71,6 +96,7 @@ impl From<Error> for i64 {
 		match e {
 			Error::RuntimeError => 1,
 			Error::DecodeError => 2,
+			Error::UnsupportedRuntimeApi => 3,
 		}
 	}
 }
@@ -130,25 +156,89 @@ where
 			data: Some(format!("{:?}", e).into()),
 		})?;
 
-		let try_into_rpc_balance = |value: Balance| {
-			value.try_into().map_err(|_| RpcError {
+		fee_details_into_rpc(fee_details)
+	}
+
+	fn query_fee_details_ahead(
+		&self,
+		encoded_xt: Bytes,
+		blocks: u32,
+		fullness: BlockFullness,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<FeeDetails<NumberOrHex>> {
+		if blocks > MAX_BLOCKS_AHEAD {
+			return Err(RpcError {
 				code: ErrorCode::InvalidParams,
-				message: format!("{} doesn't fit in NumberOrHex representation", value),
+				message: format!("Cannot look more than {} blocks ahead", MAX_BLOCKS_AHEAD),
 				data: None,
 			})
-		};
-
-		Ok(FeeDetails {
-			inclusion_fee: if let Some(inclusion_fee) = fee_details.inclusion_fee {
-				Some(InclusionFee {
-					base_fee: try_into_rpc_balance(inclusion_fee.base_fee)?,
-					len_fee: try_into_rpc_balance(inclusion_fee.len_fee)?,
-					adjusted_weight_fee: try_into_rpc_balance(inclusion_fee.adjusted_weight_fee)?,
-				})
-			} else {
-				None
-			},
-			tip: Default::default(),
-		})
+		}
+
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		// `query_fee_details_ahead` was only added in version 2 of the runtime API.
+		let api_version = api
+			.api_version::<dyn TransactionPaymentRuntimeApi<Block, Balance>>(&at)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to query fee details.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})?;
+		if !matches!(api_version, Some(version) if version >= 2) {
+			return Err(RpcError {
+				code: ErrorCode::ServerError(Error::UnsupportedRuntimeApi.into()),
+				message: "The runtime does not support querying fee details ahead.".into(),
+				data: Some(format!("{:?}", api_version).into()),
+			})
+		}
+
+		let encoded_len = encoded_xt.len() as u32;
+
+		let uxt: Block::Extrinsic = Decode::decode(&mut &*encoded_xt).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::DecodeError.into()),
+			message: "Unable to query fee details.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+		let fee_details = api
+			.query_fee_details_ahead(&at, uxt, encoded_len, blocks, fullness)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to query fee details.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})?;
+
+		fee_details_into_rpc(fee_details)
 	}
 }
+
+/// Convert the balances of `fee_details` into their RPC representation.
+fn fee_details_into_rpc<Balance>(
+	fee_details: FeeDetails<Balance>,
+) -> Result<FeeDetails<NumberOrHex>>
+where
+	Balance: MaybeDisplay + Copy + TryInto<NumberOrHex>,
+{
+	let try_into_rpc_balance = |value: Balance| {
+		value.try_into().map_err(|_| RpcError {
+			code: ErrorCode::InvalidParams,
+			message: format!("{} doesn't fit in NumberOrHex representation", value),
+			data: None,
+		})
+	};
+
+	Ok(FeeDetails {
+		inclusion_fee: if let Some(inclusion_fee) = fee_details.inclusion_fee {
+			Some(InclusionFee {
+				base_fee: try_into_rpc_balance(inclusion_fee.base_fee)?,
+				len_fee: try_into_rpc_balance(inclusion_fee.len_fee)?,
+				adjusted_weight_fee: try_into_rpc_balance(inclusion_fee.adjusted_weight_fee)?,
+			})
+		} else {
+			None
+		},
+		tip: Default::default(),
+	})
+}
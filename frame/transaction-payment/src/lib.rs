@@ -42,7 +42,9 @@
 //!   - The mapping between one unit of weight to one unit of fee via [`Config::WeightToFee`].
 //!   - A means of updating the fee for the next block, via defining a multiplier, based on the
 //!     final state of the chain at the end of the previous block. This can be configured via
-//!     [`Config::FeeMultiplierUpdate`]
+//!     [`Config::FeeMultiplierUpdate`]. [`TargetedFeeAdjustment`] only considers the weight of
+//!     normal transactions, while [`BlockFullnessFeeAdjustment`] can also consider the other
+//!     dispatch classes and the block length, each with its own target.
 //!   - How the fees are paid via [`Config::OnChargeTransaction`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -58,7 +60,7 @@ use sp_runtime::{
 	transaction_validity::{
 		TransactionPriority, TransactionValidity, TransactionValidityError, ValidTransaction,
 	},
-	FixedPointNumber, FixedPointOperand, FixedU128, Perquintill, RuntimeDebug,
+	FixedPointNumber, FixedPointOperand, FixedU128, PerThing, Perquintill, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
@@ -76,7 +78,7 @@ mod types;
 
 pub use pallet::*;
 pub use payment::*;
-pub use types::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
+pub use types::{BlockFullness, FeeDetails, InclusionFee, RuntimeDispatchInfo};
 
 /// Fee multiplier.
 pub type Multiplier = FixedU128;
@@ -138,6 +140,13 @@ pub trait MultiplierUpdate: Convert<Multiplier, Multiplier> {
 	fn target() -> Perquintill;
 	/// Variability factor
 	fn variability() -> Multiplier;
+	/// The multiplier that would follow `previous` if a block ended with the given `fullness`.
+	///
+	/// This allows predicting the multiplier without executing any blocks. By default, the
+	/// multiplier is predicted to stay the same.
+	fn next_with(previous: Multiplier, _fullness: &BlockFullness) -> Multiplier {
+		previous
+	}
 }
 
 impl MultiplierUpdate for () {
@@ -150,6 +159,9 @@ impl MultiplierUpdate for () {
 	fn variability() -> Multiplier {
 		Default::default()
 	}
+	fn next_with(_: Multiplier, _: &BlockFullness) -> Multiplier {
+		Default::default()
+	}
 }
 
 impl<T, S, V, M> MultiplierUpdate for TargetedFeeAdjustment<T, S, V, M>
@@ -168,6 +180,10 @@ where
 	fn variability() -> Multiplier {
 		V::get()
 	}
+	fn next_with(previous: Multiplier, fullness: &BlockFullness) -> Multiplier {
+		let (positive, diff) = fullness_diff(fullness.normal, S::get());
+		adjust_multiplier(previous, positive, diff, V::get(), M::get())
+	}
 }
 
 impl<T, S, V, M> Convert<Multiplier, Multiplier> for TargetedFeeAdjustment<T, S, V, M>
@@ -178,12 +194,6 @@ where
 	M: Get<Multiplier>,
 {
 	fn convert(previous: Multiplier) -> Multiplier {
-		// Defensive only. The multiplier in storage should always be at most positive. Nonetheless
-		// we recover here in case of errors, because any value below this would be stale and can
-		// never change.
-		let min_multiplier = M::get();
-		let previous = previous.max(min_multiplier);
-
 		let weights = T::BlockWeights::get();
 		// the computed ratio is only among the normal class.
 		let normal_max_weight = weights
@@ -195,7 +205,6 @@ where
 			*current_block_weight.get(DispatchClass::Normal).min(&normal_max_weight);
 
 		let s = S::get();
-		let v = V::get();
 
 		let target_weight = (s * normal_max_weight) as u128;
 		let block_weight = normal_block_weight as u128;
@@ -207,24 +216,131 @@ where
 		// defensive only, a test case assures that the maximum weight diff can fit in Multiplier
 		// without any saturation.
 		let diff = Multiplier::saturating_from_rational(diff_abs, normal_max_weight.max(1));
-		let diff_squared = diff.saturating_mul(diff);
 
-		let v_squared_2 = v.saturating_mul(v) / Multiplier::saturating_from_integer(2);
+		adjust_multiplier(previous, positive, diff, V::get(), M::get())
+	}
+}
 
-		let first_term = v.saturating_mul(diff);
-		let second_term = v_squared_2.saturating_mul(diff_squared);
+/// The targets used by [`BlockFullnessFeeAdjustment`], one per resource of a block.
+///
+/// Each target is a portion of the capacity of that resource, as described in
+/// [`BlockFullness`]. A resource without a target does not influence the multiplier.
+#[derive(Clone, Copy, Eq, PartialEq, Default, RuntimeDebug)]
+pub struct FullnessTargets {
+	/// Target fullness of the normal class weight.
+	pub normal: Option<Perquintill>,
+	/// Target fullness of the operational class weight.
+	pub operational: Option<Perquintill>,
+	/// Target fullness of the mandatory class weight.
+	pub mandatory: Option<Perquintill>,
+	/// Target fullness of the block length.
+	pub length: Option<Perquintill>,
+}
 
-		if positive {
-			let excess = first_term.saturating_add(second_term).saturating_mul(previous);
-			previous.saturating_add(excess).max(min_multiplier)
-		} else {
-			// Defensive-only: first_term > second_term. Safe subtraction.
-			let negative = first_term.saturating_sub(second_term).saturating_mul(previous);
-			previous.saturating_sub(negative).max(min_multiplier)
+/// A multiplier update similar to [`TargetedFeeAdjustment`], which takes into account the weight
+/// of every dispatch class as well as the length of the block.
+///
+/// Every resource that has a target in `S` is compared against it, and the resource that is the
+/// most congested relative to its own target drives the update, using the same formula as
+/// [`TargetedFeeAdjustment`]. Hence, the multiplier only decreases if all considered resources are
+/// below their targets.
+///
+/// `V` and `M` have the same meaning as in [`TargetedFeeAdjustment`].
+pub struct BlockFullnessFeeAdjustment<T, S, V, M>(sp_std::marker::PhantomData<(T, S, V, M)>);
+
+impl<T, S, V, M> MultiplierUpdate for BlockFullnessFeeAdjustment<T, S, V, M>
+where
+	T: frame_system::Config,
+	S: Get<FullnessTargets>,
+	V: Get<Multiplier>,
+	M: Get<Multiplier>,
+{
+	fn min() -> Multiplier {
+		M::get()
+	}
+	fn target() -> Perquintill {
+		S::get().normal.unwrap_or_default()
+	}
+	fn variability() -> Multiplier {
+		V::get()
+	}
+	fn next_with(previous: Multiplier, fullness: &BlockFullness) -> Multiplier {
+		let targets = S::get();
+		let most_congested = [
+			(targets.normal, fullness.normal),
+			(targets.operational, fullness.operational),
+			(targets.mandatory, fullness.mandatory),
+			(targets.length, fullness.length),
+		]
+		.into_iter()
+		.filter_map(|(target, ratio)| {
+			target.map(|t| ratio.deconstruct() as i128 - t.deconstruct() as i128)
+		})
+		.max();
+
+		match most_congested {
+			Some(diff) => {
+				let positive = diff >= 0;
+				let diff = Multiplier::saturating_from_rational(
+					diff.unsigned_abs(),
+					Perquintill::ACCURACY,
+				);
+				adjust_multiplier(previous, positive, diff, V::get(), M::get())
+			},
+			None => previous.max(M::get()),
 		}
 	}
 }
 
+impl<T, S, V, M> Convert<Multiplier, Multiplier> for BlockFullnessFeeAdjustment<T, S, V, M>
+where
+	T: frame_system::Config,
+	S: Get<FullnessTargets>,
+	V: Get<Multiplier>,
+	M: Get<Multiplier>,
+{
+	fn convert(previous: Multiplier) -> Multiplier {
+		Self::next_with(previous, &BlockFullness::current::<T>())
+	}
+}
+
+/// The signedness and absolute value of `ratio - target`, as a multiplier.
+fn fullness_diff(ratio: Perquintill, target: Perquintill) -> (bool, Multiplier) {
+	let positive = ratio >= target;
+	let diff_abs = ratio.max(target).saturating_sub(ratio.min(target));
+	(positive, Multiplier::saturating_from_rational(diff_abs.deconstruct(), Perquintill::ACCURACY))
+}
+
+/// Moves `previous` by `diff` using the formula described in [`TargetedFeeAdjustment`], never
+/// going below `min_multiplier`.
+fn adjust_multiplier(
+	previous: Multiplier,
+	positive: bool,
+	diff: Multiplier,
+	v: Multiplier,
+	min_multiplier: Multiplier,
+) -> Multiplier {
+	// Defensive only. The multiplier in storage should always be at most positive. Nonetheless
+	// we recover here in case of errors, because any value below this would be stale and can
+	// never change.
+	let previous = previous.max(min_multiplier);
+
+	let diff_squared = diff.saturating_mul(diff);
+	let v_squared_2 = v.saturating_mul(v) / Multiplier::saturating_from_integer(2);
+
+	let first_term = v.saturating_mul(diff);
+	let second_term = v_squared_2.saturating_mul(diff_squared);
+
+	if positive {
+		let excess = first_term.saturating_add(second_term).saturating_mul(previous);
+		previous.saturating_add(excess).max(min_multiplier)
+	} else {
+		// Defensive-only: first_term > second_term. Safe subtraction.
+		let negative = first_term.saturating_sub(second_term).saturating_mul(previous);
+		previous.saturating_sub(negative).max(min_multiplier)
+	}
+}
+
 /// Storage releases of the pallet.
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 enum Releases {
@@ -448,6 +564,78 @@ where
 		}
 	}
 
+	/// Query information of a dispatch class, weight, and fee of a given encoded `Call`.
+	///
+	/// The call is treated as if it was part of a signed extrinsic of length `len`, which allows
+	/// estimating its fee without constructing and signing such an extrinsic.
+	pub fn query_call_info(call: T::Call, len: u32) -> RuntimeDispatchInfo<BalanceOf<T>>
+	where
+		T::Call: Dispatchable<Info = DispatchInfo> + GetDispatchInfo,
+	{
+		let dispatch_info = <T::Call as GetDispatchInfo>::get_dispatch_info(&call);
+		let partial_fee = Self::compute_fee(len, &dispatch_info, 0u32.into());
+		let DispatchInfo { weight, class, .. } = dispatch_info;
+
+		RuntimeDispatchInfo { weight, class, partial_fee }
+	}
+
+	/// Query the detailed fee of a given encoded `Call`, treated as part of a signed extrinsic of
+	/// length `len`.
+	pub fn query_call_fee_details(call: T::Call, len: u32) -> FeeDetails<BalanceOf<T>>
+	where
+		T::Call: Dispatchable<Info = DispatchInfo> + GetDispatchInfo,
+	{
+		let dispatch_info = <T::Call as GetDispatchInfo>::get_dispatch_info(&call);
+		let tip = 0u32.into();
+
+		Self::compute_fee_details(len, &dispatch_info, tip)
+	}
+
+	/// Query the detailed fee of a given `call` as it would be `blocks` blocks from now, assuming
+	/// that each of those blocks ends with the given `fullness`.
+	///
+	/// The fee multiplier is the only part of the fee that changes over time, hence this is the
+	/// same as [`Self::query_fee_details`] with a multiplier predicted by
+	/// [`MultiplierUpdate::next_with`].
+	pub fn query_fee_details_ahead<Extrinsic: sp_runtime::traits::Extrinsic + GetDispatchInfo>(
+		unchecked_extrinsic: Extrinsic,
+		len: u32,
+		blocks: u32,
+		fullness: BlockFullness,
+	) -> FeeDetails<BalanceOf<T>>
+	where
+		T::Call: Dispatchable<Info = DispatchInfo>,
+	{
+		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
+
+		let tip = 0u32.into();
+
+		if unchecked_extrinsic.is_signed().unwrap_or(false) {
+			let multiplier = Self::next_fee_multiplier_ahead(blocks, &fullness);
+			Self::compute_fee_raw_with_multiplier(
+				len,
+				dispatch_info.weight,
+				tip,
+				dispatch_info.pays_fee,
+				dispatch_info.class,
+				multiplier,
+			)
+		} else {
+			// Unsigned extrinsics have no inclusion fee.
+			FeeDetails { inclusion_fee: None, tip }
+		}
+	}
+
+	/// The fee multiplier that will apply `blocks` blocks from now, assuming that each of those
+	/// blocks ends with the given `fullness`.
+	///
+	/// With `blocks == 0`, this is the multiplier of the next block.
+	pub fn next_fee_multiplier_ahead(blocks: u32, fullness: &BlockFullness) -> Multiplier {
+		(0..blocks).fold(Self::next_fee_multiplier(), |multiplier, _| {
+			T::FeeMultiplierUpdate::next_with(multiplier, fullness)
+		})
+	}
+
 	/// Compute the final fee value for a particular transaction.
 	pub fn compute_fee(len: u32, info: &DispatchInfoOf<T::Call>, tip: BalanceOf<T>) -> BalanceOf<T>
 	where
@@ -510,16 +698,30 @@ where
 		pays_fee: Pays,
 		class: DispatchClass,
 	) -> FeeDetails<BalanceOf<T>> {
-		if pays_fee == Pays::Yes {
-			let len = <BalanceOf<T>>::from(len);
-			let per_byte = T::TransactionByteFee::get();
+		Self::compute_fee_raw_with_multiplier(
+			len,
+			weight,
+			tip,
+			pays_fee,
+			class,
+			Self::next_fee_multiplier(),
+		)
+	}
 
+	fn compute_fee_raw_with_multiplier(
+		len: u32,
+		weight: Weight,
+		tip: BalanceOf<T>,
+		pays_fee: Pays,
+		class: DispatchClass,
+		multiplier: Multiplier,
+	) -> FeeDetails<BalanceOf<T>> {
+		if pays_fee == Pays::Yes {
 			// length fee. this is not adjusted.
-			let fixed_len_fee = per_byte.saturating_mul(len);
+			let fixed_len_fee = Self::length_to_fee(len);
 
 			// the adjustable part of the fee.
 			let unadjusted_weight_fee = Self::weight_to_fee(weight);
-			let multiplier = Self::next_fee_multiplier();
 			// final adjusted weight fee.
 			let adjusted_weight_fee = multiplier.saturating_mul_int(unadjusted_weight_fee);
 
@@ -537,7 +739,14 @@ where
 		}
 	}
 
-	fn weight_to_fee(weight: Weight) -> BalanceOf<T> {
+	/// Compute the length portion of a fee by invoking the configured `TransactionByteFee`.
+	pub fn length_to_fee(length: u32) -> BalanceOf<T> {
+		T::TransactionByteFee::get().saturating_mul(length.into())
+	}
+
+	/// Compute the unadjusted portion of the weight fee by invoking the configured `WeightToFee`
+	/// impl. Note that the input `weight` is capped by the maximum block weight before computation.
+	pub fn weight_to_fee(weight: Weight) -> BalanceOf<T> {
 		// cap the weight to the maximum defined in runtime, otherwise it will be the
 		// `Bounded` maximum of its data type, which is not desired.
 		let capped_weight = weight.min(T::BlockWeights::get().max_block);
@@ -839,6 +1048,21 @@ mod tests {
 		pub static TransactionByteFee: u64 = 1;
 		pub static WeightToFee: u64 = 1;
 		pub static OperationalFeeMultiplier: u8 = 5;
+		pub static Targets: FullnessTargets = FullnessTargets {
+			normal: Some(Perquintill::from_percent(25)),
+			..Default::default()
+		};
+		pub AdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(1, 100);
+		pub MinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 2);
+	}
+
+	type FullnessAdjustment =
+		BlockFullnessFeeAdjustment<Runtime, Targets, AdjustmentVariable, MinimumMultiplier>;
+	type NormalAdjustment =
+		TargetedFeeAdjustment<Runtime, TargetBlockFullness, AdjustmentVariable, MinimumMultiplier>;
+
+	parameter_types! {
+		pub const TargetBlockFullness: Perquintill = Perquintill::from_percent(25);
 	}
 
 	impl frame_system::Config for Runtime {
@@ -1210,6 +1434,49 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn query_call_info_and_fee_details_works() {
+		let call = Call::Balances(BalancesCall::transfer { dest: 2, value: 69 });
+		let info = call.get_dispatch_info();
+		let encoded_call = call.encode();
+		let len = encoded_call.len() as u32;
+
+		ExtBuilder::default().base_weight(5).weight_fee(2).build().execute_with(|| {
+			// all fees should be x1.5
+			<NextFeeMultiplier<Runtime>>::put(Multiplier::saturating_from_rational(3, 2));
+
+			assert_eq!(
+				TransactionPayment::query_call_info(call.clone(), len),
+				RuntimeDispatchInfo {
+					weight: info.weight,
+					class: info.class,
+					partial_fee: 5 * 2 /* base * weight_fee */
+						+ len as u64  /* len * 1 */
+						+ info.weight.min(BlockWeights::get().max_block) as u64 * 2 * 3 / 2 /* weight */
+				},
+			);
+
+			assert_eq!(
+				TransactionPayment::query_call_fee_details(call, len),
+				FeeDetails {
+					inclusion_fee: Some(InclusionFee {
+						base_fee: 5 * 2,     /* base * weight_fee */
+						len_fee: len as u64, /* len * 1 */
+						adjusted_weight_fee: info.weight.min(BlockWeights::get().max_block) as u64 *
+							2 * 3 / 2  /* weight * weight_fee * multiplier */
+					}),
+					tip: 0,
+				},
+			);
+
+			assert_eq!(
+				TransactionPayment::weight_to_fee(info.weight),
+				info.weight.min(BlockWeights::get().max_block) * 2
+			);
+			assert_eq!(TransactionPayment::length_to_fee(len), len as u64);
+		});
+	}
+
 	#[test]
 	fn compute_fee_works_without_multiplier() {
 		ExtBuilder::default()
@@ -1577,4 +1844,135 @@ mod tests {
 				assert_eq!(refund_based_fee, actual_fee);
 			});
 	}
+
+	#[test]
+	fn block_fullness_adjustment_matches_targeted_adjustment_for_normal_class() {
+		ExtBuilder::default().build().execute_with(|| {
+			let previous = Multiplier::one();
+			for weight in [0, 128, 256, 512, 1024] {
+				System::set_block_consumed_resources(weight, 0);
+				let fullness = BlockFullness::current::<Runtime>();
+				assert_eq!(fullness.normal, Perquintill::from_rational(weight, 1024));
+
+				let next = FullnessAdjustment::convert(previous);
+				assert_eq!(next, NormalAdjustment::next_with(previous, &fullness));
+				assert_eq!(next, NormalAdjustment::convert(previous));
+			}
+		});
+	}
+
+	#[test]
+	fn block_fullness_adjustment_follows_the_most_congested_resource() {
+		let previous = Multiplier::one();
+		let fullness = BlockFullness {
+			normal: Perquintill::from_percent(25),
+			operational: Perquintill::from_percent(60),
+			mandatory: Perquintill::zero(),
+			length: Perquintill::from_percent(75),
+		};
+
+		// only the normal class is considered, which is right at its target.
+		assert_eq!(FullnessAdjustment::next_with(previous, &fullness), previous);
+
+		// the length is way above its target.
+		Targets::set(FullnessTargets {
+			length: Some(Perquintill::from_percent(25)),
+			..Targets::get()
+		});
+		let by_length = FullnessAdjustment::next_with(previous, &fullness);
+		assert!(by_length > previous);
+
+		// operational is further above its target than the length is.
+		Targets::set(FullnessTargets {
+			operational: Some(Perquintill::from_percent(0)),
+			..Targets::get()
+		});
+		let by_operational = FullnessAdjustment::next_with(previous, &fullness);
+		assert!(by_operational > by_length);
+
+		// the multiplier only goes down if all resources are below their targets.
+		let empty = BlockFullness { length: Perquintill::from_percent(10), ..Default::default() };
+		Targets::set(FullnessTargets {
+			operational: Some(Perquintill::from_percent(25)),
+			..Targets::get()
+		});
+		assert!(FullnessAdjustment::next_with(previous, &empty) < previous);
+		let operational_above_target =
+			BlockFullness { operational: Perquintill::from_percent(30), ..empty };
+		assert!(FullnessAdjustment::next_with(previous, &operational_above_target) > previous);
+
+		// without any target, the multiplier stays the same, but never goes below the minimum.
+		Targets::set(FullnessTargets::default());
+		assert_eq!(FullnessAdjustment::next_with(previous, &fullness), previous);
+		assert_eq!(
+			FullnessAdjustment::next_with(Multiplier::zero(), &fullness),
+			MinimumMultiplier::get()
+		);
+
+		Targets::set(FullnessTargets {
+			normal: Some(Perquintill::from_percent(25)),
+			..Default::default()
+		});
+	}
+
+	#[test]
+	fn query_fee_details_ahead_works() {
+		let call = Call::Balances(BalancesCall::transfer { dest: 2, value: 69 });
+		let xt = TestXt::new(call.clone(), Some((111111, ())));
+		let len = xt.encode().len() as u32;
+		let unsigned_xt = TestXt::<_, ()>::new(call, None);
+		let fullness =
+			BlockFullness { normal: Perquintill::from_percent(40), ..Default::default() };
+
+		ExtBuilder::default().base_weight(5).weight_fee(2).build().execute_with(|| {
+			<NextFeeMultiplier<Runtime>>::put(Multiplier::saturating_from_rational(3, 2));
+
+			// looking zero blocks ahead is the same as querying the current fee details.
+			assert_eq!(
+				TransactionPayment::query_fee_details_ahead(xt.clone(), len, 0, fullness),
+				TransactionPayment::query_fee_details(xt.clone(), len),
+			);
+
+			// the mock uses `()` as the multiplier update, which always yields zero.
+			assert_eq!(
+				TransactionPayment::next_fee_multiplier_ahead(10, &fullness),
+				Multiplier::zero()
+			);
+			assert_eq!(
+				TransactionPayment::query_fee_details_ahead(xt.clone(), len, 10, fullness),
+				FeeDetails {
+					inclusion_fee: Some(InclusionFee {
+						base_fee: 5 * 2,
+						len_fee: len as u64,
+						adjusted_weight_fee: 0,
+					}),
+					tip: 0,
+				},
+			);
+
+			assert_eq!(
+				TransactionPayment::query_fee_details_ahead(unsigned_xt, len, 10, fullness),
+				FeeDetails { inclusion_fee: None, tip: 0 },
+			);
+		});
+	}
+
+	#[test]
+	fn next_with_can_predict_multiplier_updates() {
+		let fullness =
+			BlockFullness { normal: Perquintill::from_percent(50), ..Default::default() };
+
+		ExtBuilder::default().build().execute_with(|| {
+			let mut multiplier = Multiplier::saturating_from_rational(3, 2);
+			let predicted =
+				(0..10).fold(multiplier, |m, _| NormalAdjustment::next_with(m, &fullness));
+
+			System::set_block_consumed_resources(Perquintill::from_percent(50) * 1024, 0);
+			for _ in 0..10 {
+				multiplier = NormalAdjustment::convert(multiplier);
+			}
+			assert_eq!(multiplier, predicted);
+			assert!(predicted > Multiplier::saturating_from_rational(3, 2));
+		});
+	}
 }
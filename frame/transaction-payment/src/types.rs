@@ -21,10 +21,16 @@ use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
-use sp_runtime::traits::{AtLeast32BitUnsigned, Zero};
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Zero},
+	Perquintill,
+};
 use sp_std::prelude::*;
 
-use frame_support::weights::{DispatchClass, Weight};
+use frame_support::{
+	traits::Get,
+	weights::{DispatchClass, Weight},
+};
 
 /// The base fee and adjusted weight and length fees constitute the _inclusion fee_.
 #[derive(Encode, Decode, Clone, Eq, PartialEq)]
@@ -89,6 +95,61 @@ impl<Balance: AtLeast32BitUnsigned + Copy> FeeDetails<Balance> {
 	}
 }
 
+/// The fullness of a block, expressed per dispatch class for weight and over the whole block for
+/// length.
+///
+/// Each ratio is relative to the capacity available to that resource, i.e. `max_total` (or
+/// `max_block` if unlimited) of the class for weight, and the normal class length limit for
+/// length.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct BlockFullness {
+	/// Portion of the normal class weight capacity that was consumed.
+	pub normal: Perquintill,
+	/// Portion of the operational class weight capacity that was consumed.
+	pub operational: Perquintill,
+	/// Portion of the mandatory class weight capacity that was consumed.
+	pub mandatory: Perquintill,
+	/// Portion of the block length capacity that was consumed.
+	pub length: Perquintill,
+}
+
+impl BlockFullness {
+	/// The fullness of the block currently being built, as tracked by the system pallet.
+	pub fn current<T: frame_system::Config>() -> Self {
+		let weights = T::BlockWeights::get();
+		let consumed = <frame_system::Pallet<T>>::block_weight();
+		let ratio = |class: DispatchClass| {
+			let max = weights.get(class).max_total.unwrap_or(weights.max_block);
+			Perquintill::from_rational(*consumed.get(class), max.max(1))
+		};
+
+		// length is not tracked per class, so the normal class limit is the reference.
+		let max_len = *T::BlockLength::get().max.get(DispatchClass::Normal);
+		let length = Perquintill::from_rational(
+			<frame_system::Pallet<T>>::all_extrinsics_len() as u64,
+			max_len.max(1) as u64,
+		);
+
+		Self {
+			normal: ratio(DispatchClass::Normal),
+			operational: ratio(DispatchClass::Operational),
+			mandatory: ratio(DispatchClass::Mandatory),
+			length,
+		}
+	}
+
+	/// The fullness of the given dispatch class.
+	pub fn get(&self, class: DispatchClass) -> Perquintill {
+		match class {
+			DispatchClass::Normal => self.normal,
+			DispatchClass::Operational => self.operational,
+			DispatchClass::Mandatory => self.mandatory,
+		}
+	}
+}
+
 /// Information related to a dispatchable's class, weight, and fee that can be queried from the
 /// runtime.
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
@@ -22,7 +22,10 @@
 use super::{Pallet as Treasury, *};
 
 use frame_benchmarking::{account, benchmarks_instance_pallet};
-use frame_support::{ensure, traits::OnInitialize};
+use frame_support::{
+	ensure,
+	traits::{EnsureOrigin, OnInitialize},
+};
 use frame_system::RawOrigin;
 
 const SEED: u32 = 0;
@@ -51,6 +54,24 @@ fn create_approved_proposals<T: Config<I>, I: 'static>(n: u32) -> Result<(), &'s
 	Ok(())
 }
 
+// Create the arguments for the `spend` dispatchable.
+fn create_spend_arguments<T: Config<I>, I: 'static>(
+	seed: u32,
+) -> (T::AssetKind, AssetBalanceOf<T, I>, T::AccountId, <T::Lookup as StaticLookup>::Source)
+where
+	T::AssetKind: From<u32>,
+{
+	let asset_kind = T::AssetKind::from(seed);
+	T::BalanceConverter::ensure_successful(asset_kind.clone());
+	let beneficiary: T::AccountId = account("beneficiary", seed, SEED);
+	let beneficiary_lookup = T::Lookup::unlookup(beneficiary.clone());
+	(asset_kind, 100u32.into(), beneficiary, beneficiary_lookup)
+}
+
+fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::Event) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
 fn setup_pot_account<T: Config<I>, I: 'static>() {
 	let pot_account = Treasury::<T, I>::account_id();
 	let value = T::Currency::minimum_balance().saturating_mul(1_000_000_000u32.into());
@@ -58,6 +79,10 @@ fn setup_pot_account<T: Config<I>, I: 'static>() {
 }
 
 benchmarks_instance_pallet! {
+	where_clause {
+		where T::AssetKind: From<u32>
+	}
+
 	propose_spend {
 		let (caller, value, beneficiary_lookup) = setup_proposal::<T, _>(SEED);
 		// Whitelist caller account from further DB operations.
@@ -95,5 +120,88 @@ benchmarks_instance_pallet! {
 		Treasury::<T, _>::on_initialize(T::BlockNumber::zero());
 	}
 
+	spend {
+		let origin = T::SpendOrigin::successful_origin();
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_spend_arguments::<T, _>(SEED);
+	}: _<T::Origin>(origin, Box::new(asset_kind.clone()), amount, beneficiary_lookup, None)
+	verify {
+		let valid_from = frame_system::Pallet::<T>::block_number();
+		let expire_at = valid_from.saturating_add(T::PayoutPeriod::get());
+		assert_last_event::<T, I>(
+			Event::AssetSpendApproved {
+				index: 0,
+				asset_kind,
+				amount,
+				beneficiary,
+				valid_from,
+				expire_at,
+			}
+			.into(),
+		);
+	}
+
+	payout {
+		let origin = T::SpendOrigin::successful_origin();
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_spend_arguments::<T, _>(SEED);
+		Treasury::<T, _>::spend(
+			origin,
+			Box::new(asset_kind.clone()),
+			amount,
+			beneficiary_lookup,
+			None,
+		)?;
+		T::Paymaster::ensure_successful(&beneficiary, asset_kind, amount);
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), 0u32)
+	verify {
+		let id = match Spends::<T, I>::get(0).unwrap().status {
+			PaymentState::Attempted { id, .. } => {
+				assert_ne!(T::Paymaster::check_payment(id), PaymentStatus::Failure);
+				id
+			},
+			_ => panic!("No payout attempt made"),
+		};
+		assert_last_event::<T, I>(Event::Paid { index: 0, payment_id: id }.into());
+	}
+
+	check_status {
+		let origin = T::SpendOrigin::successful_origin();
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_spend_arguments::<T, _>(SEED);
+		Treasury::<T, _>::spend(
+			origin,
+			Box::new(asset_kind.clone()),
+			amount,
+			beneficiary_lookup,
+			None,
+		)?;
+		T::Paymaster::ensure_successful(&beneficiary, asset_kind, amount);
+		let caller: T::AccountId = account("caller", 0, SEED);
+		Treasury::<T, _>::payout(RawOrigin::Signed(caller.clone()).into(), 0u32)?;
+		match Spends::<T, I>::get(0).unwrap().status {
+			PaymentState::Attempted { id, .. } => {
+				T::Paymaster::ensure_concluded(id);
+			},
+			_ => panic!("No payout attempt made"),
+		};
+	}: _(RawOrigin::Signed(caller), 0u32)
+	verify {
+		if let Some(s) = Spends::<T, I>::get(0) {
+			assert!(!matches!(s.status, PaymentState::Attempted { .. }));
+		}
+	}
+
+	void_spend {
+		let origin = T::SpendOrigin::successful_origin();
+		let (asset_kind, amount, _, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
+		Treasury::<T, _>::spend(origin, Box::new(asset_kind), amount, beneficiary_lookup, None)?;
+		assert!(Spends::<T, I>::get(0).is_some());
+	}: _(RawOrigin::Root, 0u32)
+	verify {
+		assert!(Spends::<T, I>::get(0).is_none());
+	}
+
 	impl_benchmark_test_suite!(Treasury, crate::tests::new_test_ext(), crate::tests::Test);
 }
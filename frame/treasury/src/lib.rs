@@ -41,6 +41,9 @@
 //! - **Deposit:** Funds that a proposer must lock when making a proposal. The deposit will be
 //!   returned or slashed if the proposal is approved or rejected respectively.
 //! - **Pot:** Unspent funds accumulated by the treasury pallet.
+//! - **Spend:** An approved transfer of a specific amount of some asset kind to a beneficiary, paid
+//!   out through the `Paymaster`. A spend is valid from a given block and expires `PayoutPeriod`
+//!   blocks later if it has not been paid out by then.
 //!
 //! ## Interface
 //!
@@ -51,6 +54,13 @@
 //! - `reject_proposal` - Reject a proposal, slashing the deposit.
 //! - `approve_proposal` - Accept the proposal, returning the deposit.
 //!
+//! Asset spend protocol:
+//! - `spend` - Approve a spend of some asset kind to a beneficiary, up to the maximum amount
+//!   allowed for the origin by `SpendOrigin`. No deposit is required.
+//! - `payout` - Pay out an approved spend, or retry a failed payout.
+//! - `check_status` - Check the status of a payout, removing the spend once it is concluded.
+//! - `void_spend` - Void an approved spend that has not been paid out yet.
+//!
 //! ## GenesisConfig
 //!
 //! The Treasury pallet depends on the [`GenesisConfig`].
@@ -74,8 +84,10 @@ use sp_std::prelude::*;
 use frame_support::{
 	print,
 	traits::{
-		Currency, ExistenceRequirement::KeepAlive, Get, Imbalance, OnUnbalanced,
-		ReservableCurrency, WithdrawReasons,
+		tokens::{ConversionFromAssetBalance, Pay, PaymentStatus},
+		Currency,
+		ExistenceRequirement::KeepAlive,
+		Get, Imbalance, OnUnbalanced, ReservableCurrency, WithdrawReasons,
 	},
 	weights::Weight,
 	PalletId,
@@ -92,6 +104,8 @@ pub type PositiveImbalanceOf<T, I = ()> = <<T as Config<I>>::Currency as Currenc
 pub type NegativeImbalanceOf<T, I = ()> = <<T as Config<I>>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::NegativeImbalance;
+pub type AssetBalanceOf<T, I = ()> = <<T as Config<I>>::Paymaster as Pay>::Balance;
+pub type PaymentIdOf<T, I = ()> = <<T as Config<I>>::Paymaster as Pay>::Id;
 
 /// A trait to allow the Treasury Pallet to spend it's funds for other purposes.
 /// There is an expectation that the implementer of this trait will correctly manage
@@ -131,6 +145,39 @@ pub struct Proposal<AccountId, Balance> {
 	bond: Balance,
 }
 
+/// An index of an approved spend. Just a `u32`.
+pub type SpendIndex = u32;
+
+/// The state of the payment claim.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum PaymentState<Id> {
+	/// Pending claim.
+	Pending,
+	/// Payment attempted with a payment identifier.
+	Attempted { id: Id },
+	/// Payment failed.
+	Failed,
+}
+
+/// Info regarding an approved treasury spend.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct SpendStatus<AssetKind, AssetBalance, AccountId, BlockNumber, PaymentId> {
+	/// The kind of asset to be spent.
+	asset_kind: AssetKind,
+	/// The asset amount of the spend.
+	amount: AssetBalance,
+	/// The beneficiary of the spend.
+	beneficiary: AccountId,
+	/// The block number from which the spend can be claimed.
+	valid_from: BlockNumber,
+	/// The block number by which the spend has to be claimed.
+	expire_at: BlockNumber,
+	/// The status of the payout/claim.
+	status: PaymentState<PaymentId>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -192,6 +239,30 @@ pub mod pallet {
 		/// The maximum number of approvals that can wait in the spending queue.
 		#[pallet::constant]
 		type MaxApprovals: Get<u32>;
+
+		/// The origin required for approving spends from the treasury outside of the proposal
+		/// process. The `Success` value is the maximum amount, in the native currency, that this
+		/// origin is allowed to spend at a time.
+		type SpendOrigin: EnsureOrigin<Self::Origin, Success = BalanceOf<Self, I>>;
+
+		/// Type parameter representing the asset kinds to be spent from the treasury.
+		type AssetKind: Parameter + MaxEncodedLen;
+
+		/// Type for processing spends of [Self::AssetKind] in favor of a beneficiary.
+		type Paymaster: Pay<Beneficiary = Self::AccountId, AssetKind = Self::AssetKind>;
+
+		/// Type for converting the balance of an [Self::AssetKind] to the balance of the native
+		/// asset, solely for the purpose of asserting the result against the maximum allowed spend
+		/// amount of the [`Self::SpendOrigin`].
+		type BalanceConverter: ConversionFromAssetBalance<
+			<Self::Paymaster as Pay>::Balance,
+			Self::AssetKind,
+			BalanceOf<Self, I>,
+		>;
+
+		/// The period during which an approved treasury spend has to be claimed.
+		#[pallet::constant]
+		type PayoutPeriod: Get<Self::BlockNumber>;
 	}
 
 	/// Number of proposals that have been made.
@@ -216,6 +287,28 @@ pub mod pallet {
 	pub type Approvals<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, BoundedVec<ProposalIndex, T::MaxApprovals>, ValueQuery>;
 
+	/// The count of spends that have been made.
+	#[pallet::storage]
+	#[pallet::getter(fn spend_count)]
+	pub(crate) type SpendCount<T, I = ()> = StorageValue<_, SpendIndex, ValueQuery>;
+
+	/// Spends that have been approved and are being processed.
+	#[pallet::storage]
+	#[pallet::getter(fn spends)]
+	pub type Spends<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		SpendIndex,
+		SpendStatus<
+			T::AssetKind,
+			AssetBalanceOf<T, I>,
+			T::AccountId,
+			T::BlockNumber,
+			PaymentIdOf<T, I>,
+		>,
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig;
 
@@ -269,6 +362,24 @@ pub mod pallet {
 		Rollover { rollover_balance: BalanceOf<T, I> },
 		/// Some funds have been deposited.
 		Deposit { value: BalanceOf<T, I> },
+		/// A new asset spend proposal has been approved.
+		AssetSpendApproved {
+			index: SpendIndex,
+			asset_kind: T::AssetKind,
+			amount: AssetBalanceOf<T, I>,
+			beneficiary: T::AccountId,
+			valid_from: T::BlockNumber,
+			expire_at: T::BlockNumber,
+		},
+		/// An approved spend was voided.
+		AssetSpendVoided { index: SpendIndex },
+		/// A payment happened.
+		Paid { index: SpendIndex, payment_id: PaymentIdOf<T, I> },
+		/// A payment failed and can be retried.
+		PaymentFailed { index: SpendIndex, payment_id: PaymentIdOf<T, I> },
+		/// A spend was processed and removed from the storage. It might have been successfully
+		/// paid or it may have expired.
+		SpendProcessed { index: SpendIndex },
 	}
 
 	/// Old name generated by `decl_event`.
@@ -284,6 +395,23 @@ pub mod pallet {
 		InvalidIndex,
 		/// Too many approvals in the queue.
 		TooManyApprovals,
+		/// The spend origin is valid but the amount it is allowed to spend is lower than the
+		/// amount to be spent.
+		InsufficientPermission,
+		/// The balance of the asset kind is not convertible to the balance of the native asset.
+		FailedToConvertBalance,
+		/// The spend has expired and cannot be claimed.
+		SpendExpired,
+		/// The spend is not yet eligible for payout.
+		EarlyPayout,
+		/// The payment has already been attempted.
+		AlreadyAttempted,
+		/// There was some issue with the mechanism of payment.
+		PayoutError,
+		/// The payout was not yet attempted/claimed.
+		NotAttempted,
+		/// The payment has neither failed nor succeeded yet.
+		Inconclusive,
 	}
 
 	#[pallet::hooks]
@@ -388,6 +516,184 @@ pub mod pallet {
 				.map_err(|_| Error::<T, I>::TooManyApprovals)?;
 			Ok(())
 		}
+
+		/// Propose and approve a spend of treasury funds.
+		///
+		/// May only be called from `T::SpendOrigin`, and the amount, converted to the native
+		/// currency by `T::BalanceConverter`, must not exceed the maximum amount the origin is
+		/// allowed to spend.
+		///
+		/// The spend has to be claimed with `payout` after `valid_from` (or right away if it is
+		/// `None`) and before it expires `T::PayoutPeriod` blocks later.
+		///
+		/// - `asset_kind`: An indicator of the specific asset class to be spent.
+		/// - `amount`: The amount to be transferred from the treasury to the `beneficiary`.
+		/// - `beneficiary`: The beneficiary of the spend.
+		/// - `valid_from`: The block number from which the spend can be claimed.
+		///
+		/// # <weight>
+		/// - Complexity: O(1)
+		/// - DbReads: `SpendCount`
+		/// - DbWrites: `SpendCount`, `Spends`
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::spend())]
+		pub fn spend(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			#[pallet::compact] amount: AssetBalanceOf<T, I>,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+			valid_from: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let valid_from = valid_from.unwrap_or(now);
+			let expire_at = valid_from.saturating_add(T::PayoutPeriod::get());
+			ensure!(expire_at > now, Error::<T, I>::SpendExpired);
+
+			let native_amount =
+				T::BalanceConverter::from_asset_balance(amount, (*asset_kind).clone())
+					.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
+			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
+
+			let index = SpendCount::<T, I>::get();
+			Spends::<T, I>::insert(
+				index,
+				SpendStatus {
+					asset_kind: (*asset_kind).clone(),
+					amount,
+					beneficiary: beneficiary.clone(),
+					valid_from,
+					expire_at,
+					status: PaymentState::Pending,
+				},
+			);
+			SpendCount::<T, I>::put(index + 1);
+
+			Self::deposit_event(Event::AssetSpendApproved {
+				index,
+				asset_kind: *asset_kind,
+				amount,
+				beneficiary,
+				valid_from,
+				expire_at,
+			});
+			Ok(())
+		}
+
+		/// Claim a spend.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// The spend must not have expired and must be valid already. A spend whose payout failed
+		/// may be claimed again.
+		///
+		/// Emits [`Event::Paid`] if the payment was initiated; its outcome has to be checked with
+		/// `check_status`.
+		///
+		/// - `index`: The spend index.
+		///
+		/// # <weight>
+		/// - Complexity: O(1)
+		/// - DbReads: `Spends`
+		/// - DbWrites: `Spends`
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::payout())]
+		pub fn payout(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut spend = Spends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= spend.valid_from, Error::<T, I>::EarlyPayout);
+			ensure!(spend.expire_at > now, Error::<T, I>::SpendExpired);
+			ensure!(
+				matches!(spend.status, PaymentState::Pending | PaymentState::Failed),
+				Error::<T, I>::AlreadyAttempted
+			);
+
+			let id = T::Paymaster::pay(&spend.beneficiary, spend.asset_kind.clone(), spend.amount)
+				.map_err(|_| Error::<T, I>::PayoutError)?;
+
+			spend.status = PaymentState::Attempted { id };
+			Spends::<T, I>::insert(index, spend);
+
+			Self::deposit_event(Event::<T, I>::Paid { index, payment_id: id });
+			Ok(())
+		}
+
+		/// Check the status of the spend and remove it from the storage if processed.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// The spend is removed once its payment succeeded or once it has expired. A failed
+		/// payment is marked as such, so that it can be retried with `payout`. The call is free
+		/// of charge whenever it makes progress.
+		///
+		/// - `index`: The spend index.
+		///
+		/// # <weight>
+		/// - Complexity: O(1)
+		/// - DbReads: `Spends`
+		/// - DbWrites: `Spends`
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::check_status())]
+		pub fn check_status(origin: OriginFor<T>, index: SpendIndex) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let mut spend = Spends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let now = frame_system::Pallet::<T>::block_number();
+
+			if now >= spend.expire_at && !matches!(spend.status, PaymentState::Attempted { .. }) {
+				// Spend has expired and no further status update is expected.
+				Spends::<T, I>::remove(index);
+				Self::deposit_event(Event::<T, I>::SpendProcessed { index });
+				return Ok(Pays::No.into())
+			}
+
+			let payment_id = match spend.status {
+				PaymentState::Attempted { id } => id,
+				_ => return Err(Error::<T, I>::NotAttempted.into()),
+			};
+
+			match T::Paymaster::check_payment(payment_id) {
+				PaymentStatus::Failure => {
+					spend.status = PaymentState::Failed;
+					Spends::<T, I>::insert(index, spend);
+					Self::deposit_event(Event::<T, I>::PaymentFailed { index, payment_id });
+				},
+				PaymentStatus::Success | PaymentStatus::Unknown => {
+					Spends::<T, I>::remove(index);
+					Self::deposit_event(Event::<T, I>::SpendProcessed { index });
+				},
+				PaymentStatus::InProgress => return Err(Error::<T, I>::Inconclusive.into()),
+			}
+			Ok(Pays::No.into())
+		}
+
+		/// Void previously approved spend.
+		///
+		/// May only be called from `T::RejectOrigin`, and only as long as the payout has not been
+		/// attempted yet, or has failed.
+		///
+		/// - `index`: The spend index.
+		///
+		/// # <weight>
+		/// - Complexity: O(1)
+		/// - DbReads: `Spends`
+		/// - DbWrites: `Spends`
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::void_spend())]
+		pub fn void_spend(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+			T::RejectOrigin::ensure_origin(origin)?;
+			let spend = Spends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			ensure!(
+				matches!(spend.status, PaymentState::Pending | PaymentState::Failed),
+				Error::<T, I>::AlreadyAttempted
+			);
+
+			Spends::<T, I>::remove(index);
+			Self::deposit_event(Event::<T, I>::AssetSpendVoided { index });
+			Ok(())
+		}
 	}
 }
 
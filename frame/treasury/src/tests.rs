@@ -19,16 +19,19 @@
 
 #![cfg(test)]
 
-use std::cell::RefCell;
+use std::{cell::RefCell, collections::BTreeMap};
 
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BadOrigin, BlakeTwo256, IdentityLookup},
 };
 
 use frame_support::{
-	assert_noop, assert_ok, pallet_prelude::GenesisBuild, parameter_types, traits::OnInitialize,
+	assert_err_ignore_postinfo, assert_noop, assert_ok,
+	pallet_prelude::GenesisBuild,
+	parameter_types,
+	traits::{tokens::UnityAssetBalanceConversion, EnsureOrigin, OnInitialize},
 	PalletId,
 };
 
@@ -96,6 +99,76 @@ impl pallet_balances::Config for Test {
 }
 thread_local! {
 	static TEN_TO_FOURTEEN: RefCell<Vec<u128>> = RefCell::new(vec![10,11,12,13,14]);
+	static PAID: RefCell<BTreeMap<(u128, u32), u64>> = RefCell::new(BTreeMap::new());
+	static STATUS: RefCell<BTreeMap<u64, PaymentStatus>> = RefCell::new(BTreeMap::new());
+	static LAST_ID: RefCell<u64> = RefCell::new(0u64);
+}
+
+/// Paid balance for a given account and asset ids.
+fn paid(who: u128, asset_id: u32) -> u64 {
+	PAID.with(|p| p.borrow().get(&(who, asset_id)).cloned().unwrap_or(0))
+}
+
+/// Reduce paid balance for a given account and asset ids.
+fn unpay(who: u128, asset_id: u32, amount: u64) {
+	PAID.with(|p| p.borrow_mut().entry((who, asset_id)).or_default().saturating_reduce(amount))
+}
+
+/// Set status for a given payment id.
+fn set_status(id: u64, s: PaymentStatus) {
+	STATUS.with(|m| m.borrow_mut().insert(id, s));
+}
+
+pub struct TestPay;
+impl Pay for TestPay {
+	type Beneficiary = u128;
+	type Balance = u64;
+	type Id = u64;
+	type AssetKind = u32;
+	type Error = ();
+
+	fn pay(
+		who: &Self::Beneficiary,
+		asset_kind: Self::AssetKind,
+		amount: Self::Balance,
+	) -> Result<Self::Id, Self::Error> {
+		PAID.with(|paid| *paid.borrow_mut().entry((*who, asset_kind)).or_default() += amount);
+		Ok(LAST_ID.with(|lid| {
+			let x = *lid.borrow();
+			lid.replace(x + 1);
+			x
+		}))
+	}
+	fn check_payment(id: Self::Id) -> PaymentStatus {
+		STATUS.with(|s| s.borrow().get(&id).cloned().unwrap_or(PaymentStatus::Unknown))
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(_: &Self::Beneficiary, _: Self::AssetKind, _: Self::Balance) {}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_concluded(id: Self::Id) {
+		set_status(id, PaymentStatus::Failure)
+	}
+}
+
+/// Root may spend any amount; signed accounts 10 to 14 may spend up to ten times their
+/// distance from 10, and 10 itself up to 5.
+pub struct TestSpendOrigin;
+impl EnsureOrigin<Origin> for TestSpendOrigin {
+	type Success = u64;
+	fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+		Result::<frame_system::RawOrigin<_>, Origin>::from(o).and_then(|o| match o {
+			frame_system::RawOrigin::Root => Ok(u64::max_value()),
+			frame_system::RawOrigin::Signed(10) => Ok(5),
+			frame_system::RawOrigin::Signed(11) => Ok(10),
+			frame_system::RawOrigin::Signed(12) => Ok(20),
+			frame_system::RawOrigin::Signed(13) => Ok(50),
+			r => Err(Origin::from(r)),
+		})
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> Origin {
+		Origin::root()
+	}
 }
 parameter_types! {
 	pub const ProposalBond: Permill = Permill::from_percent(5);
@@ -107,6 +180,7 @@ parameter_types! {
 	pub const BountyCuratorDeposit: Permill = Permill::from_percent(50);
 	pub const BountyValueMinimum: u64 = 1;
 	pub const MaxApprovals: u32 = 100;
+	pub const PayoutPeriod: u64 = 5;
 }
 impl Config for Test {
 	type PalletId = TreasuryPalletId;
@@ -123,6 +197,11 @@ impl Config for Test {
 	type WeightInfo = ();
 	type SpendFunds = ();
 	type MaxApprovals = MaxApprovals;
+	type SpendOrigin = TestSpendOrigin;
+	type AssetKind = u32;
+	type Paymaster = TestPay;
+	type BalanceConverter = UnityAssetBalanceConversion;
+	type PayoutPeriod = PayoutPeriod;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -389,3 +468,173 @@ fn max_approvals_limited() {
 		);
 	});
 }
+
+#[test]
+fn spend_origin_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Treasury::spend(Origin::signed(11), Box::new(1), 1, 6, None));
+		assert_ok!(Treasury::spend(Origin::signed(11), Box::new(1), 10, 6, None));
+		assert_noop!(
+			Treasury::spend(Origin::signed(10), Box::new(1), 6, 6, None),
+			Error::<Test>::InsufficientPermission
+		);
+		assert_noop!(
+			Treasury::spend(Origin::signed(11), Box::new(1), 11, 6, None),
+			Error::<Test>::InsufficientPermission
+		);
+		assert_noop!(Treasury::spend(Origin::signed(1), Box::new(1), 1, 6, None), BadOrigin);
+		assert_ok!(Treasury::spend(Origin::root(), Box::new(1), 1_000, 6, None));
+
+		assert_eq!(SpendCount::<Test>::get(), 3);
+		assert!(Spends::<Test>::get(3).is_none());
+	});
+}
+
+#[test]
+fn spend_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend(Origin::signed(11), Box::new(1), 2, 6, None));
+
+		assert_eq!(SpendCount::<Test>::get(), 1);
+		assert_eq!(
+			Spends::<Test>::get(0).unwrap(),
+			SpendStatus {
+				asset_kind: 1,
+				amount: 2,
+				beneficiary: 6,
+				valid_from: 1,
+				expire_at: 6,
+				status: PaymentState::Pending,
+			}
+		);
+		System::assert_last_event(
+			treasury::Event::<Test>::AssetSpendApproved {
+				index: 0,
+				asset_kind: 1,
+				amount: 2,
+				beneficiary: 6,
+				valid_from: 1,
+				expire_at: 6,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn spend_expires() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		assert_noop!(
+			Treasury::spend(Origin::signed(11), Box::new(1), 2, 6, Some(4)),
+			Error::<Test>::SpendExpired
+		);
+		assert_ok!(Treasury::spend(Origin::signed(11), Box::new(1), 2, 6, Some(6)));
+
+		System::set_block_number(11);
+		assert_noop!(Treasury::payout(Origin::signed(1), 0), Error::<Test>::SpendExpired);
+	});
+}
+
+#[test]
+fn payout_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend(Origin::signed(11), Box::new(1), 2, 6, Some(2)));
+		assert_noop!(Treasury::payout(Origin::signed(1), 0), Error::<Test>::EarlyPayout);
+		assert_noop!(Treasury::payout(Origin::signed(1), 1), Error::<Test>::InvalidIndex);
+
+		System::set_block_number(2);
+		assert_ok!(Treasury::payout(Origin::signed(1), 0));
+		assert_eq!(paid(6, 1), 2);
+		let payment_id = LAST_ID.with(|id| *id.borrow()) - 1;
+		System::assert_last_event(treasury::Event::<Test>::Paid { index: 0, payment_id }.into());
+		assert_eq!(
+			Spends::<Test>::get(0).unwrap().status,
+			PaymentState::Attempted { id: payment_id }
+		);
+
+		assert_noop!(Treasury::payout(Origin::signed(1), 0), Error::<Test>::AlreadyAttempted);
+	});
+}
+
+#[test]
+fn check_status_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend(Origin::signed(11), Box::new(1), 2, 6, None));
+		assert_noop!(Treasury::check_status(Origin::signed(1), 0), Error::<Test>::NotAttempted);
+
+		assert_ok!(Treasury::payout(Origin::signed(1), 0));
+		let payment_id = LAST_ID.with(|id| *id.borrow()) - 1;
+
+		set_status(payment_id, PaymentStatus::InProgress);
+		assert_err_ignore_postinfo!(
+			Treasury::check_status(Origin::signed(1), 0),
+			Error::<Test>::Inconclusive
+		);
+
+		// a failed payment can be retried.
+		set_status(payment_id, PaymentStatus::Failure);
+		unpay(6, 1, 2);
+		assert_ok!(Treasury::check_status(Origin::signed(1), 0));
+		System::assert_last_event(
+			treasury::Event::<Test>::PaymentFailed { index: 0, payment_id }.into(),
+		);
+		assert_eq!(Spends::<Test>::get(0).unwrap().status, PaymentState::Failed);
+
+		assert_ok!(Treasury::payout(Origin::signed(1), 0));
+		assert_eq!(paid(6, 1), 2);
+		let payment_id = LAST_ID.with(|id| *id.borrow()) - 1;
+
+		set_status(payment_id, PaymentStatus::Success);
+		assert_ok!(Treasury::check_status(Origin::signed(1), 0));
+		System::assert_last_event(treasury::Event::<Test>::SpendProcessed { index: 0 }.into());
+		assert!(Spends::<Test>::get(0).is_none());
+	});
+}
+
+#[test]
+fn check_status_removes_expired_spend() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend(Origin::signed(11), Box::new(1), 2, 6, None));
+
+		System::set_block_number(7);
+		assert_ok!(Treasury::check_status(Origin::signed(1), 0));
+		System::assert_last_event(treasury::Event::<Test>::SpendProcessed { index: 0 }.into());
+		assert!(Spends::<Test>::get(0).is_none());
+	});
+}
+
+#[test]
+fn spend_at_expiry_block_is_expired() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend(Origin::signed(11), Box::new(1), 2, 6, None));
+
+		System::set_block_number(6);
+		assert_noop!(Treasury::payout(Origin::signed(1), 0), Error::<Test>::SpendExpired);
+		assert_ok!(Treasury::check_status(Origin::signed(1), 0));
+		System::assert_last_event(treasury::Event::<Test>::SpendProcessed { index: 0 }.into());
+		assert!(Spends::<Test>::get(0).is_none());
+	});
+}
+
+#[test]
+fn void_spend_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend(Origin::signed(11), Box::new(1), 2, 6, Some(10)));
+		assert_ok!(Treasury::spend(Origin::signed(11), Box::new(1), 2, 6, None));
+
+		assert_noop!(Treasury::void_spend(Origin::signed(1), 0), BadOrigin);
+		assert_ok!(Treasury::void_spend(Origin::root(), 0));
+		System::assert_last_event(treasury::Event::<Test>::AssetSpendVoided { index: 0 }.into());
+		assert!(Spends::<Test>::get(0).is_none());
+
+		assert_ok!(Treasury::payout(Origin::signed(1), 1));
+		assert_noop!(Treasury::void_spend(Origin::root(), 1), Error::<Test>::AlreadyAttempted);
+	});
+}
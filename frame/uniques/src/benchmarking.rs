@@ -126,6 +126,30 @@ fn add_instance_attribute<T: Config<I>, I: 'static>(
 	(key, caller, caller_lookup)
 }
 
+fn lock_sell_and_swap_instance<T: Config<I>, I: 'static>(instance: T::InstanceId) {
+	let class = T::ClassId::default();
+	let caller = Asset::<T, I>::get(class, instance).unwrap().owner;
+	let origin: T::Origin = SystemOrigin::Signed(caller).into();
+	let price = T::Currency::minimum_balance();
+	let duration = T::MaxDeadlineDuration::get();
+	assert!(Uniques::<T, I>::lock_instance_properties(origin.clone(), class, instance, true, true)
+		.is_ok());
+	assert!(Uniques::<T, I>::set_price(origin.clone(), class, instance, Some(price), None).is_ok());
+	assert!(
+		Uniques::<T, I>::create_swap(origin, class, instance, class, None, None, duration).is_ok()
+	);
+}
+
+fn add_holder_claims<T: Config<I>, I: 'static>(claims: u32) {
+	let class = T::ClassId::default();
+	for i in 0..claims {
+		HolderClaims::<T, I>::insert((&class, &class, &T::InstanceId::from(i as u16)), ());
+	}
+	Class::<T, I>::mutate(&class, |maybe_details| {
+		maybe_details.as_mut().map(|details| details.holder_claims = claims)
+	});
+}
+
 fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::Event) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::Event = generic_event.into();
@@ -156,6 +180,7 @@ benchmarks_instance_pallet! {
 		let n in 0 .. 1_000;
 		let m in 0 .. 1_000;
 		let a in 0 .. 1_000;
+		let c in 0 .. 1_000;
 
 		let (class, caller, caller_lookup) = create_class::<T, I>();
 		add_class_metadata::<T, I>();
@@ -168,6 +193,10 @@ benchmarks_instance_pallet! {
 		for i in 0..a {
 			add_instance_attribute::<T, I>((i as u16).into());
 		}
+		for i in 0..n {
+			lock_sell_and_swap_instance::<T, I>((i as u16).into());
+		}
+		add_holder_claims::<T, I>(c);
 		let witness = Class::<T, I>::get(class).unwrap().destroy_witness();
 	}: _(SystemOrigin::Signed(caller), class, witness)
 	verify {
@@ -379,5 +408,197 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::ApprovalCancelled { class, instance, owner: caller, delegate }.into());
 	}
 
+	set_mint_settings {
+		let (class, caller, _) = create_class::<T, I>();
+		let mint_settings = MintSettings {
+			mint_type: MintType::HolderOf(class),
+			price: Some(T::Currency::minimum_balance()),
+			start_block: Some(Zero::zero()),
+			end_block: Some(Bounded::max_value()),
+		};
+	}: _(SystemOrigin::Signed(caller), class, mint_settings)
+	verify {
+		assert_last_event::<T, I>(Event::MintSettingsSet { class, mint_settings }.into());
+	}
+
+	public_mint {
+		let (class, caller, _) = create_class::<T, I>();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value() / 2u32.into());
+		let minter: T::AccountId = account("minter", 0, SEED);
+		whitelist_account!(minter);
+		let minter_lookup = T::Lookup::unlookup(minter.clone());
+		let price = T::Currency::minimum_balance() * 100u32.into();
+		T::Currency::make_free_balance_be(&minter, price + price);
+		let held_instance: T::InstanceId = 0u16.into();
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Uniques::<T, I>::mint(origin, class, held_instance, minter_lookup)?;
+		let mint_settings = MintSettings {
+			mint_type: MintType::HolderOf(class),
+			price: Some(price),
+			start_block: None,
+			end_block: None,
+		};
+		let origin = SystemOrigin::Signed(caller).into();
+		Uniques::<T, I>::set_mint_settings(origin, class, mint_settings)?;
+		let instance: T::InstanceId = 1u16.into();
+	}: _(SystemOrigin::Signed(minter.clone()), class, instance, Some(held_instance))
+	verify {
+		assert_last_event::<T, I>(Event::Issued { class, instance, owner: minter }.into());
+	}
+
+	lock_instance_properties {
+		let (class, caller, _) = create_class::<T, I>();
+		let (instance, ..) = mint_instance::<T, I>(0);
+	}: _(SystemOrigin::Signed(caller), class, instance, true, true)
+	verify {
+		assert_last_event::<T, I>(Event::InstancePropertiesLocked {
+			class,
+			instance,
+			lock_metadata: true,
+			lock_attributes: true,
+		}.into());
+	}
+
+	set_royalty {
+		let (class, caller, _) = create_class::<T, I>();
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let percentage = Permill::from_percent(10);
+		let royalty = Royalty { beneficiary: beneficiary.clone(), percentage };
+	}: _(SystemOrigin::Signed(caller), class, Some(royalty))
+	verify {
+		assert_last_event::<T, I>(Event::RoyaltySet { class, beneficiary, percentage }.into());
+	}
+
+	set_price {
+		let (class, caller, _) = create_class::<T, I>();
+		let (instance, ..) = mint_instance::<T, I>(0);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
+		let price = T::Currency::minimum_balance();
+	}: _(SystemOrigin::Signed(caller), class, instance, Some(price), Some(buyer_lookup))
+	verify {
+		assert_last_event::<T, I>(Event::PriceSet {
+			class,
+			instance,
+			price,
+			whitelisted_buyer: Some(buyer),
+		}.into());
+	}
+
+	buy {
+		let (class, caller, _) = create_class::<T, I>();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value() / 2u32.into());
+		let (instance, ..) = mint_instance::<T, I>(0);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		whitelist_account!(buyer);
+		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
+		let price = T::Currency::minimum_balance() * 100u32.into();
+		T::Currency::make_free_balance_be(&buyer, price + price);
+		let royalty = Royalty {
+			beneficiary: account("beneficiary", 0, SEED),
+			percentage: Permill::from_percent(10),
+		};
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Uniques::<T, I>::set_royalty(origin, class, Some(royalty))?;
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Uniques::<T, I>::set_price(origin, class, instance, Some(price), Some(buyer_lookup))?;
+	}: _(SystemOrigin::Signed(buyer.clone()), class, instance, price)
+	verify {
+		assert_last_event::<T, I>(Event::Bought { class, instance, price, seller: caller, buyer }.into());
+	}
+
+	create_swap {
+		let (class, caller, _) = create_class::<T, I>();
+		let (offered_instance, ..) = mint_instance::<T, I>(0);
+		let (desired_instance, ..) = mint_instance::<T, I>(1);
+		let price = Some(PriceWithDirection {
+			amount: T::Currency::minimum_balance(),
+			direction: PriceDirection::Send,
+		});
+		let duration = T::MaxDeadlineDuration::get();
+		let deadline = frame_system::Pallet::<T>::block_number() + duration;
+	}: _(SystemOrigin::Signed(caller), class, offered_instance, class, Some(desired_instance), price, duration)
+	verify {
+		assert_last_event::<T, I>(Event::SwapCreated {
+			offered_class: class,
+			offered_instance,
+			desired_class: class,
+			desired_instance: Some(desired_instance),
+			price,
+			deadline,
+		}.into());
+	}
+
+	cancel_swap {
+		let (class, caller, _) = create_class::<T, I>();
+		let (offered_instance, ..) = mint_instance::<T, I>(0);
+		let (desired_instance, ..) = mint_instance::<T, I>(1);
+		let price = Some(PriceWithDirection {
+			amount: T::Currency::minimum_balance(),
+			direction: PriceDirection::Send,
+		});
+		let duration = T::MaxDeadlineDuration::get();
+		let deadline = frame_system::Pallet::<T>::block_number() + duration;
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Uniques::<T, I>::create_swap(
+			origin,
+			class,
+			offered_instance,
+			class,
+			Some(desired_instance),
+			price,
+			duration,
+		)?;
+	}: _(SystemOrigin::Signed(caller), class, offered_instance)
+	verify {
+		assert_last_event::<T, I>(Event::SwapCancelled {
+			offered_class: class,
+			offered_instance,
+			desired_class: class,
+			desired_instance: Some(desired_instance),
+			price,
+			deadline,
+		}.into());
+	}
+
+	claim_swap {
+		let (class, caller, _) = create_class::<T, I>();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value() / 2u32.into());
+		let (offered_instance, ..) = mint_instance::<T, I>(0);
+		let target: T::AccountId = account("target", 0, SEED);
+		whitelist_account!(target);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		T::Currency::make_free_balance_be(&target, amount + amount);
+		let desired_instance: T::InstanceId = 1u16.into();
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Uniques::<T, I>::mint(origin, class, desired_instance, target_lookup)?;
+		let price = Some(PriceWithDirection { amount, direction: PriceDirection::Send });
+		let duration = T::MaxDeadlineDuration::get();
+		let deadline = frame_system::Pallet::<T>::block_number() + duration;
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Uniques::<T, I>::create_swap(
+			origin,
+			class,
+			offered_instance,
+			class,
+			Some(desired_instance),
+			price,
+			duration,
+		)?;
+	}: _(SystemOrigin::Signed(target.clone()), class, desired_instance, class, offered_instance, price)
+	verify {
+		assert_last_event::<T, I>(Event::SwapClaimed {
+			sent_class: class,
+			sent_instance: desired_instance,
+			sent_instance_owner: target,
+			received_class: class,
+			received_instance: offered_instance,
+			received_instance_owner: caller,
+			price,
+			deadline,
+		}.into());
+	}
+
 	impl_benchmark_test_suite!(Uniques, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -39,10 +39,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		with_details(&class_details, &mut details)?;
 
 		Account::<T, I>::remove((&details.owner, &class, &instance));
+		// An approval given by the previous owner does not carry over to the new one.
+		details.approved = None;
 		Account::<T, I>::insert((&dest, &class, &instance), ());
 		let origin = details.owner;
 		details.owner = dest;
 		Asset::<T, I>::insert(&class, &instance, &details);
+		InstancePriceOf::<T, I>::remove(&class, &instance);
+		PendingSwapOf::<T, I>::remove(&class, &instance);
 
 		Self::deposit_event(Event::Transferred {
 			class,
@@ -77,6 +81,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				instances: 0,
 				instance_metadatas: 0,
 				attributes: 0,
+				holder_claims: 0,
 				is_frozen: false,
 			},
 		);
@@ -101,13 +106,23 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				Error::<T, I>::BadWitness
 			);
 			ensure!(class_details.attributes == witness.attributes, Error::<T, I>::BadWitness);
+			ensure!(
+				class_details.holder_claims == witness.holder_claims,
+				Error::<T, I>::BadWitness
+			);
 
 			for (instance, details) in Asset::<T, I>::drain_prefix(&class) {
 				Account::<T, I>::remove((&details.owner, &class, &instance));
+				InstanceLocksOf::<T, I>::remove(&class, &instance);
+				InstancePriceOf::<T, I>::remove(&class, &instance);
+				PendingSwapOf::<T, I>::remove(&class, &instance);
 			}
 			InstanceMetadataOf::<T, I>::remove_prefix(&class, None);
 			ClassMetadataOf::<T, I>::remove(&class);
 			Attribute::<T, I>::remove_prefix((&class,), None);
+			ClassMintSettings::<T, I>::remove(&class);
+			ClassRoyalty::<T, I>::remove(&class);
+			HolderClaims::<T, I>::remove_prefix((&class,), None);
 			T::Currency::unreserve(&class_details.owner, class_details.total_deposit);
 
 			Self::deposit_event(Event::Destroyed { class });
@@ -116,6 +131,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				instances: class_details.instances,
 				instance_metadatas: class_details.instance_metadatas,
 				attributes: class_details.attributes,
+				holder_claims: class_details.holder_claims,
 			})
 		})
 	}
@@ -178,8 +194,259 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Asset::<T, I>::remove(&class, &instance);
 		Account::<T, I>::remove((&owner, &class, &instance));
+		InstanceLocksOf::<T, I>::remove(&class, &instance);
+		InstancePriceOf::<T, I>::remove(&class, &instance);
+		PendingSwapOf::<T, I>::remove(&class, &instance);
 
 		Self::deposit_event(Event::Burned { class, instance, owner });
 		Ok(())
 	}
+
+	pub(super) fn do_public_mint(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		minter: T::AccountId,
+		owned_instance: Option<T::InstanceId>,
+	) -> DispatchResult {
+		let mint_settings = ClassMintSettings::<T, I>::get(&class);
+		match mint_settings.mint_type {
+			MintType::Issuer => return Err(Error::<T, I>::MintNotAllowed.into()),
+			MintType::Public => {},
+			MintType::HolderOf(held_class) => {
+				let held_instance = owned_instance.ok_or(Error::<T, I>::BadWitness)?;
+				let held_details = Asset::<T, I>::get(&held_class, &held_instance)
+					.ok_or(Error::<T, I>::Unknown)?;
+				ensure!(held_details.owner == minter, Error::<T, I>::NoPermission);
+				ensure!(
+					!HolderClaims::<T, I>::contains_key((&class, &held_class, &held_instance)),
+					Error::<T, I>::AlreadyClaimed
+				);
+				HolderClaims::<T, I>::insert((&class, &held_class, &held_instance), ());
+				Class::<T, I>::try_mutate(&class, |maybe_class_details| -> DispatchResult {
+					let class_details =
+						maybe_class_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+					class_details.holder_claims = class_details
+						.holder_claims
+						.checked_add(1)
+						.ok_or(ArithmeticError::Overflow)?;
+					Ok(())
+				})?;
+			},
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		if let Some(start_block) = mint_settings.start_block {
+			ensure!(start_block <= now, Error::<T, I>::MintNotStarted);
+		}
+		if let Some(end_block) = mint_settings.end_block {
+			ensure!(end_block >= now, Error::<T, I>::MintEnded);
+		}
+
+		Self::do_mint(class, instance, minter.clone(), |class_details| {
+			if let Some(price) = mint_settings.price {
+				T::Currency::transfer(&minter, &class_details.owner, price, KeepAlive)?;
+			}
+			Ok(())
+		})
+	}
+
+	pub(super) fn do_set_price(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		sender: T::AccountId,
+		price: Option<InstancePrice<T, I>>,
+		whitelisted_buyer: Option<T::AccountId>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(&class, &instance).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.owner == sender, Error::<T, I>::NoPermission);
+
+		if let Some(price) = price {
+			InstancePriceOf::<T, I>::insert(&class, &instance, (price, whitelisted_buyer.clone()));
+			Self::deposit_event(Event::PriceSet { class, instance, price, whitelisted_buyer });
+		} else {
+			InstancePriceOf::<T, I>::remove(&class, &instance);
+			Self::deposit_event(Event::PriceRemoved { class, instance });
+		}
+
+		Ok(())
+	}
+
+	pub(super) fn do_buy(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		buyer: T::AccountId,
+		bid_price: InstancePrice<T, I>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(&class, &instance).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.owner != buyer, Error::<T, I>::NoPermission);
+
+		let (price, whitelisted_buyer) =
+			InstancePriceOf::<T, I>::get(&class, &instance).ok_or(Error::<T, I>::NotForSale)?;
+		ensure!(bid_price >= price, Error::<T, I>::BidTooLow);
+		if let Some(only_buyer) = whitelisted_buyer {
+			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
+		}
+
+		let seller = details.owner;
+		let mut seller_amount = price;
+		if let Some(royalty) = ClassRoyalty::<T, I>::get(&class) {
+			let amount = royalty.percentage.mul_floor(price);
+			if !amount.is_zero() {
+				T::Currency::transfer(&buyer, &royalty.beneficiary, amount, KeepAlive)?;
+				seller_amount = price.saturating_sub(amount);
+				Self::deposit_event(Event::RoyaltyPaid {
+					class,
+					instance,
+					beneficiary: royalty.beneficiary,
+					amount,
+				});
+			}
+		}
+		T::Currency::transfer(&buyer, &seller, seller_amount, KeepAlive)?;
+
+		Self::do_transfer(class, instance, buyer.clone(), |_, _| Ok(()))?;
+
+		Self::deposit_event(Event::Bought { class, instance, price, seller, buyer });
+		Ok(())
+	}
+
+	pub(super) fn do_create_swap(
+		caller: T::AccountId,
+		offered_class: T::ClassId,
+		offered_instance: T::InstanceId,
+		desired_class: T::ClassId,
+		maybe_desired_instance: Option<T::InstanceId>,
+		maybe_price: Option<PriceWithDirection<InstancePrice<T, I>>>,
+		duration: <T as SystemConfig>::BlockNumber,
+	) -> DispatchResult {
+		ensure!(duration <= T::MaxDeadlineDuration::get(), Error::<T, I>::WrongDuration);
+
+		let details =
+			Asset::<T, I>::get(&offered_class, &offered_instance).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.owner == caller, Error::<T, I>::NoPermission);
+
+		match maybe_desired_instance {
+			Some(desired_instance) => ensure!(
+				Asset::<T, I>::contains_key(&desired_class, &desired_instance),
+				Error::<T, I>::Unknown
+			),
+			None => ensure!(Class::<T, I>::contains_key(&desired_class), Error::<T, I>::Unknown),
+		};
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let deadline = duration.saturating_add(now);
+
+		PendingSwapOf::<T, I>::insert(
+			&offered_class,
+			&offered_instance,
+			PendingSwap {
+				desired_class,
+				desired_instance: maybe_desired_instance,
+				price: maybe_price,
+				deadline,
+			},
+		);
+
+		Self::deposit_event(Event::SwapCreated {
+			offered_class,
+			offered_instance,
+			desired_class,
+			desired_instance: maybe_desired_instance,
+			price: maybe_price,
+			deadline,
+		});
+
+		Ok(())
+	}
+
+	pub(super) fn do_cancel_swap(
+		caller: T::AccountId,
+		offered_class: T::ClassId,
+		offered_instance: T::InstanceId,
+	) -> DispatchResult {
+		let swap = PendingSwapOf::<T, I>::get(&offered_class, &offered_instance)
+			.ok_or(Error::<T, I>::UnknownSwap)?;
+
+		let now = frame_system::Pallet::<T>::block_number();
+		if swap.deadline > now {
+			let details = Asset::<T, I>::get(&offered_class, &offered_instance)
+				.ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.owner == caller, Error::<T, I>::NoPermission);
+		}
+
+		PendingSwapOf::<T, I>::remove(&offered_class, &offered_instance);
+
+		Self::deposit_event(Event::SwapCancelled {
+			offered_class,
+			offered_instance,
+			desired_class: swap.desired_class,
+			desired_instance: swap.desired_instance,
+			price: swap.price,
+			deadline: swap.deadline,
+		});
+
+		Ok(())
+	}
+
+	pub(super) fn do_claim_swap(
+		caller: T::AccountId,
+		send_class: T::ClassId,
+		send_instance: T::InstanceId,
+		receive_class: T::ClassId,
+		receive_instance: T::InstanceId,
+		witness_price: Option<PriceWithDirection<InstancePrice<T, I>>>,
+	) -> DispatchResult {
+		let send_details =
+			Asset::<T, I>::get(&send_class, &send_instance).ok_or(Error::<T, I>::Unknown)?;
+		let receive_details =
+			Asset::<T, I>::get(&receive_class, &receive_instance).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(send_details.owner == caller, Error::<T, I>::NoPermission);
+
+		let swap = PendingSwapOf::<T, I>::get(&receive_class, &receive_instance)
+			.ok_or(Error::<T, I>::UnknownSwap)?;
+		ensure!(swap.desired_class == send_class, Error::<T, I>::UnknownSwap);
+		if let Some(desired_instance) = swap.desired_instance {
+			ensure!(desired_instance == send_instance, Error::<T, I>::UnknownSwap);
+		}
+		ensure!(swap.price == witness_price, Error::<T, I>::UnknownSwap);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now <= swap.deadline, Error::<T, I>::DeadlineExpired);
+
+		if let Some(ref price) = swap.price {
+			match price.direction {
+				PriceDirection::Send => T::Currency::transfer(
+					&send_details.owner,
+					&receive_details.owner,
+					price.amount,
+					KeepAlive,
+				)?,
+				PriceDirection::Receive => T::Currency::transfer(
+					&receive_details.owner,
+					&send_details.owner,
+					price.amount,
+					KeepAlive,
+				)?,
+			};
+		}
+
+		// This also removes the swap.
+		Self::do_transfer(send_class, send_instance, receive_details.owner.clone(), |_, _| Ok(()))?;
+		Self::do_transfer(receive_class, receive_instance, send_details.owner.clone(), |_, _| {
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::SwapClaimed {
+			sent_class: send_class,
+			sent_instance: send_instance,
+			sent_instance_owner: send_details.owner,
+			received_class: receive_class,
+			received_instance: receive_instance,
+			received_instance_owner: receive_details.owner,
+			price: swap.price,
+			deadline: swap.deadline,
+		});
+
+		Ok(())
+	}
 }
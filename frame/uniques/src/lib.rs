@@ -26,6 +26,7 @@
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
+#![recursion_limit = "256"]
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
@@ -41,11 +42,16 @@ mod types;
 pub use types::*;
 
 use codec::{Decode, Encode, HasCompact};
-use frame_support::traits::{BalanceStatus::Reserved, Currency, ReservableCurrency};
+use frame_support::{
+	traits::{
+		BalanceStatus::Reserved, Currency, ExistenceRequirement::KeepAlive, ReservableCurrency,
+	},
+	transactional,
+};
 use frame_system::Config as SystemConfig;
 use sp_runtime::{
 	traits::{Saturating, StaticLookup, Zero},
-	ArithmeticError, RuntimeDebug,
+	ArithmeticError, Permill, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
@@ -114,6 +120,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type ValueLimit: Get<u32>;
 
+		/// The maximum number of blocks for which a swap may be left open.
+		#[pallet::constant]
+		type MaxDeadlineDuration: Get<<Self as SystemConfig>::BlockNumber>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -188,6 +198,67 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	/// The conditions under which anyone other than the Issuer may mint instances of a class.
+	pub(super) type ClassMintSettings<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::ClassId, MintSettingsFor<T, I>, ValueQuery>;
+
+	#[pallet::storage]
+	/// The instances of a class which have already been used to mint instances of another class
+	/// restricted to its holders. First key is the minted class.
+	pub(super) type HolderClaims<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::ClassId>,
+			NMapKey<Blake2_128Concat, T::ClassId>,
+			NMapKey<Blake2_128Concat, T::InstanceId>,
+		),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The royalty paid out on every sale of an instance of a class.
+	pub(super) type ClassRoyalty<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::ClassId, Royalty<T::AccountId>, OptionQuery>;
+
+	#[pallet::storage]
+	/// The properties of an asset instance which have been locked.
+	pub(super) type InstanceLocksOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ClassId,
+		Blake2_128Concat,
+		T::InstanceId,
+		InstanceLocks,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The price for which an asset instance is for sale, together with the only account allowed
+	/// to buy it, if any.
+	pub(super) type InstancePriceOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ClassId,
+		Blake2_128Concat,
+		T::InstanceId,
+		(InstancePrice<T, I>, Option<T::AccountId>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The swaps proposed for asset instances, keyed by the offered instance.
+	pub(super) type PendingSwapOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ClassId,
+		Blake2_128Concat,
+		T::InstanceId,
+		PendingSwapFor<T, I>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -275,6 +346,72 @@ pub mod pallet {
 			maybe_instance: Option<T::InstanceId>,
 			key: BoundedVec<u8, T::KeyLimit>,
 		},
+		/// The mint settings of an asset `class` were changed.
+		MintSettingsSet { class: T::ClassId, mint_settings: MintSettingsFor<T, I> },
+		/// Some properties of an asset `instance` were locked against further changes.
+		InstancePropertiesLocked {
+			class: T::ClassId,
+			instance: T::InstanceId,
+			lock_metadata: bool,
+			lock_attributes: bool,
+		},
+		/// The royalty of an asset `class` was set.
+		RoyaltySet { class: T::ClassId, beneficiary: T::AccountId, percentage: Permill },
+		/// The royalty of an asset `class` was removed.
+		RoyaltyCleared { class: T::ClassId },
+		/// A royalty was paid for the sale of an asset `instance`.
+		RoyaltyPaid {
+			class: T::ClassId,
+			instance: T::InstanceId,
+			beneficiary: T::AccountId,
+			amount: InstancePrice<T, I>,
+		},
+		/// The price was set for an asset `instance`.
+		PriceSet {
+			class: T::ClassId,
+			instance: T::InstanceId,
+			price: InstancePrice<T, I>,
+			whitelisted_buyer: Option<T::AccountId>,
+		},
+		/// The price for an asset `instance` was removed.
+		PriceRemoved { class: T::ClassId, instance: T::InstanceId },
+		/// An asset `instance` was sold.
+		Bought {
+			class: T::ClassId,
+			instance: T::InstanceId,
+			price: InstancePrice<T, I>,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+		},
+		/// A swap of an asset `instance` for another was proposed.
+		SwapCreated {
+			offered_class: T::ClassId,
+			offered_instance: T::InstanceId,
+			desired_class: T::ClassId,
+			desired_instance: Option<T::InstanceId>,
+			price: Option<PriceWithDirection<InstancePrice<T, I>>>,
+			deadline: <T as SystemConfig>::BlockNumber,
+		},
+		/// A proposed swap was cancelled.
+		SwapCancelled {
+			offered_class: T::ClassId,
+			offered_instance: T::InstanceId,
+			desired_class: T::ClassId,
+			desired_instance: Option<T::InstanceId>,
+			price: Option<PriceWithDirection<InstancePrice<T, I>>>,
+			deadline: <T as SystemConfig>::BlockNumber,
+		},
+		/// A proposed swap was claimed and carried out.
+		SwapClaimed {
+			sent_class: T::ClassId,
+			sent_instance: T::InstanceId,
+			sent_instance_owner: T::AccountId,
+			received_class: T::ClassId,
+			received_instance: T::InstanceId,
+			received_instance_owner: T::AccountId,
+			price: Option<PriceWithDirection<InstancePrice<T, I>>>,
+			deadline: <T as SystemConfig>::BlockNumber,
+		},
 	}
 
 	#[pallet::error]
@@ -299,6 +436,26 @@ pub mod pallet {
 		NoDelegate,
 		/// No approval exists that would allow the transfer.
 		Unapproved,
+		/// Minting is not allowed for the origin by the mint settings of the class.
+		MintNotAllowed,
+		/// Minting of the class has not started yet.
+		MintNotStarted,
+		/// Minting of the class has already ended.
+		MintEnded,
+		/// The held instance has already been used to mint an instance of the class.
+		AlreadyClaimed,
+		/// The property of the instance is locked.
+		Locked,
+		/// The instance is not for sale.
+		NotForSale,
+		/// The provided bid is lower than the price of the instance.
+		BidTooLow,
+		/// No matching swap exists for the instance.
+		UnknownSwap,
+		/// The deadline of the swap has already passed.
+		DeadlineExpired,
+		/// The duration given for the swap exceeds `MaxDeadlineDuration`.
+		WrongDuration,
 	}
 
 	#[pallet::hooks]
@@ -395,14 +552,16 @@ pub mod pallet {
 		///
 		/// Emits `Destroyed` event when successful.
 		///
-		/// Weight: `O(n + m)` where:
+		/// Weight: `O(n + m + a + c)` where:
 		/// - `n = witness.instances`
 		/// - `m = witness.instance_metadatas`
 		/// - `a = witness.attributes`
+		/// - `c = witness.holder_claims`
 		#[pallet::weight(T::WeightInfo::destroy(
 			witness.instances,
  			witness.instance_metadatas,
 			witness.attributes,
+			witness.holder_claims,
  		))]
 		pub fn destroy(
 			origin: OriginFor<T>,
@@ -419,6 +578,7 @@ pub mod pallet {
 				details.instances,
 				details.instance_metadatas,
 				details.attributes,
+				details.holder_claims,
 			))
 			.into())
 		}
@@ -958,6 +1118,10 @@ pub mod pallet {
 					InstanceMetadataOf::<T, I>::get(class, instance).map(|v| v.is_frozen),
 			};
 			ensure!(!maybe_is_frozen.unwrap_or(false), Error::<T, I>::Frozen);
+			if let (Some(instance), Some(_)) = (maybe_instance, &maybe_check_owner) {
+				let locks = InstanceLocksOf::<T, I>::get(class, instance);
+				ensure!(!locks.attributes, Error::<T, I>::Locked);
+			}
 
 			let attribute = Attribute::<T, I>::get((class, maybe_instance, &key));
 			if attribute.is_none() {
@@ -1022,6 +1186,10 @@ pub mod pallet {
 					InstanceMetadataOf::<T, I>::get(class, instance).map(|v| v.is_frozen),
 			};
 			ensure!(!maybe_is_frozen.unwrap_or(false), Error::<T, I>::Frozen);
+			if let (Some(instance), Some(_)) = (maybe_instance, &maybe_check_owner) {
+				let locks = InstanceLocksOf::<T, I>::get(class, instance);
+				ensure!(!locks.attributes, Error::<T, I>::Locked);
+			}
 
 			if let Some((_, deposit)) = Attribute::<T, I>::take((class, maybe_instance, &key)) {
 				class_details.attributes.saturating_dec();
@@ -1068,6 +1236,11 @@ pub mod pallet {
 				ensure!(check_owner == &class_details.owner, Error::<T, I>::NoPermission);
 			}
 
+			if maybe_check_owner.is_some() {
+				let locks = InstanceLocksOf::<T, I>::get(class, instance);
+				ensure!(!locks.metadata, Error::<T, I>::Locked);
+			}
+
 			InstanceMetadataOf::<T, I>::try_mutate_exists(class, instance, |metadata| {
 				let was_frozen = metadata.as_ref().map_or(false, |m| m.is_frozen);
 				ensure!(maybe_check_owner.is_none() || !was_frozen, Error::<T, I>::Frozen);
@@ -1126,6 +1299,11 @@ pub mod pallet {
 				ensure!(check_owner == &class_details.owner, Error::<T, I>::NoPermission);
 			}
 
+			if maybe_check_owner.is_some() {
+				let locks = InstanceLocksOf::<T, I>::get(class, instance);
+				ensure!(!locks.metadata, Error::<T, I>::Locked);
+			}
+
 			InstanceMetadataOf::<T, I>::try_mutate_exists(class, instance, |metadata| {
 				let was_frozen = metadata.as_ref().map_or(false, |m| m.is_frozen);
 				ensure!(maybe_check_owner.is_none() || !was_frozen, Error::<T, I>::Frozen);
@@ -1239,5 +1417,292 @@ pub mod pallet {
 				Ok(())
 			})
 		}
+
+		/// Set the conditions under which accounts other than the Issuer may mint instances of
+		/// an asset class with `public_mint`.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the asset `class`.
+		///
+		/// - `class`: The identifier of the asset class whose mint settings to set.
+		/// - `mint_settings`: The new mint settings.
+		///
+		/// Emits `MintSettingsSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_mint_settings())]
+		pub fn set_mint_settings(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			mint_settings: MintSettingsFor<T, I>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+
+			let details = Class::<T, I>::get(&class).ok_or(Error::<T, I>::Unknown)?;
+			if let Some(check_owner) = &maybe_check_owner {
+				ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+			}
+
+			ClassMintSettings::<T, I>::insert(&class, &mint_settings);
+			Self::deposit_event(Event::MintSettingsSet { class, mint_settings });
+			Ok(())
+		}
+
+		/// Mint an asset instance of a particular class to the sender, as allowed by the mint
+		/// settings of the class.
+		///
+		/// Origin must be Signed. If the mint settings of the class set a price, it is paid by the
+		/// sender to the Owner of the asset `class`. Any instance deposit is reserved from the
+		/// Owner of the asset `class`, as with `mint`.
+		///
+		/// - `class`: The class of the asset to be minted.
+		/// - `instance`: The instance value of the asset to be minted.
+		/// - `owned_instance`: If minting is restricted to holders of another class, an instance of
+		///   that class owned by the sender and not yet used to mint an instance of `class`.
+		///
+		/// Emits `Issued` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::public_mint())]
+		#[transactional]
+		pub fn public_mint(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			#[pallet::compact] instance: T::InstanceId,
+			owned_instance: Option<T::InstanceId>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_public_mint(class, instance, origin, owned_instance)
+		}
+
+		/// Lock some properties of an asset instance against further changes by anyone other than
+		/// the `ForceOrigin`. Locks cannot be removed.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `class`.
+		///
+		/// - `class`: The class of the asset whose properties to lock.
+		/// - `instance`: The instance of the asset whose properties to lock.
+		/// - `lock_metadata`: Whether the metadata of the instance should be locked.
+		/// - `lock_attributes`: Whether the attributes of the instance should be locked.
+		///
+		/// Emits `InstancePropertiesLocked`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::lock_instance_properties())]
+		pub fn lock_instance_properties(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			#[pallet::compact] instance: T::InstanceId,
+			lock_metadata: bool,
+			lock_attributes: bool,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			let class_details = Class::<T, I>::get(&class).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(class_details.freezer == origin, Error::<T, I>::NoPermission);
+			ensure!(Asset::<T, I>::contains_key(&class, &instance), Error::<T, I>::Unknown);
+
+			InstanceLocksOf::<T, I>::mutate(&class, &instance, |locks| {
+				locks.metadata |= lock_metadata;
+				locks.attributes |= lock_attributes;
+			});
+
+			Self::deposit_event(Event::InstancePropertiesLocked {
+				class,
+				instance,
+				lock_metadata,
+				lock_attributes,
+			});
+			Ok(())
+		}
+
+		/// Set or remove the royalty paid out on every sale of an instance of an asset class.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the asset `class`.
+		///
+		/// - `class`: The identifier of the asset class whose royalty to set.
+		/// - `maybe_royalty`: The new royalty, or `None` to remove it.
+		///
+		/// Emits `RoyaltySet` or `RoyaltyCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_royalty())]
+		pub fn set_royalty(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			maybe_royalty: Option<Royalty<T::AccountId>>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+
+			let details = Class::<T, I>::get(&class).ok_or(Error::<T, I>::Unknown)?;
+			if let Some(check_owner) = &maybe_check_owner {
+				ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+			}
+
+			match maybe_royalty {
+				Some(royalty) => {
+					ClassRoyalty::<T, I>::insert(&class, &royalty);
+					Self::deposit_event(Event::RoyaltySet {
+						class,
+						beneficiary: royalty.beneficiary,
+						percentage: royalty.percentage,
+					});
+				},
+				None => {
+					ClassRoyalty::<T, I>::remove(&class);
+					Self::deposit_event(Event::RoyaltyCleared { class });
+				},
+			}
+			Ok(())
+		}
+
+		/// Set or remove the price for which an asset instance is for sale.
+		///
+		/// Origin must be Signed and must be the owner of the asset `instance`.
+		///
+		/// - `class`: The class of the asset to be sold.
+		/// - `instance`: The instance of the asset to be sold.
+		/// - `price`: The price of the instance, or `None` to take it off sale.
+		/// - `whitelisted_buyer`: If `Some`, the only account allowed to buy the instance.
+		///
+		/// Emits `PriceSet` or `PriceRemoved`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_price())]
+		pub fn set_price(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			#[pallet::compact] instance: T::InstanceId,
+			price: Option<InstancePrice<T, I>>,
+			whitelisted_buyer: Option<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let whitelisted_buyer = whitelisted_buyer.map(T::Lookup::lookup).transpose()?;
+			Self::do_set_price(class, instance, origin, price, whitelisted_buyer)
+		}
+
+		/// Buy an asset instance which is for sale.
+		///
+		/// Origin must be Signed and must not be the owner of the asset `instance`.
+		///
+		/// The price is paid to the owner of the instance, less any royalty of the asset `class`,
+		/// which is paid to its beneficiary.
+		///
+		/// - `class`: The class of the asset to be bought.
+		/// - `instance`: The instance of the asset to be bought.
+		/// - `bid_price`: The highest price the sender is willing to pay.
+		///
+		/// Emits `Bought`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::buy())]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			#[pallet::compact] instance: T::InstanceId,
+			bid_price: InstancePrice<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_buy(class, instance, origin, bid_price)
+		}
+
+		/// Propose to swap an asset instance for another one, possibly together with a payment.
+		///
+		/// Origin must be Signed and must be the owner of the asset `offered_instance`.
+		///
+		/// - `offered_class`: The class of the asset offered in the swap.
+		/// - `offered_instance`: The instance of the asset offered in the swap.
+		/// - `desired_class`: The class of the asset wanted in exchange.
+		/// - `maybe_desired_instance`: The instance of the asset wanted in exchange; any instance
+		///   of `desired_class` will do if `None`.
+		/// - `maybe_price`: The price to be paid on top of the exchange, and by which side.
+		/// - `duration`: The number of blocks for which the swap may be claimed. Must not exceed
+		///   `MaxDeadlineDuration`.
+		///
+		/// Emits `SwapCreated`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::create_swap())]
+		pub fn create_swap(
+			origin: OriginFor<T>,
+			#[pallet::compact] offered_class: T::ClassId,
+			#[pallet::compact] offered_instance: T::InstanceId,
+			#[pallet::compact] desired_class: T::ClassId,
+			maybe_desired_instance: Option<T::InstanceId>,
+			maybe_price: Option<PriceWithDirection<InstancePrice<T, I>>>,
+			duration: <T as SystemConfig>::BlockNumber,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_create_swap(
+				origin,
+				offered_class,
+				offered_instance,
+				desired_class,
+				maybe_desired_instance,
+				maybe_price,
+				duration,
+			)
+		}
+
+		/// Cancel a proposed swap.
+		///
+		/// Origin must be Signed and must be the owner of the asset `offered_instance`, unless the
+		/// deadline of the swap has passed, in which case anyone may cancel it.
+		///
+		/// - `offered_class`: The class of the asset offered in the swap.
+		/// - `offered_instance`: The instance of the asset offered in the swap.
+		///
+		/// Emits `SwapCancelled`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_swap())]
+		pub fn cancel_swap(
+			origin: OriginFor<T>,
+			#[pallet::compact] offered_class: T::ClassId,
+			#[pallet::compact] offered_instance: T::InstanceId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_cancel_swap(origin, offered_class, offered_instance)
+		}
+
+		/// Claim a proposed swap, exchanging an owned asset instance for the offered one.
+		///
+		/// Origin must be Signed and must be the owner of the asset `send_instance`.
+		///
+		/// - `send_class`: The class of the asset given in exchange.
+		/// - `send_instance`: The instance of the asset given in exchange.
+		/// - `receive_class`: The class of the asset offered in the swap.
+		/// - `receive_instance`: The instance of the asset offered in the swap.
+		/// - `witness_price`: The price of the swap, which must match the proposed one.
+		///
+		/// Emits `SwapClaimed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::claim_swap())]
+		#[transactional]
+		pub fn claim_swap(
+			origin: OriginFor<T>,
+			#[pallet::compact] send_class: T::ClassId,
+			#[pallet::compact] send_instance: T::InstanceId,
+			#[pallet::compact] receive_class: T::ClassId,
+			#[pallet::compact] receive_instance: T::InstanceId,
+			witness_price: Option<PriceWithDirection<InstancePrice<T, I>>>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_claim_swap(
+				origin,
+				send_class,
+				send_instance,
+				receive_class,
+				receive_instance,
+				witness_price,
+			)
+		}
 	}
 }
@@ -97,6 +97,7 @@ parameter_types! {
 	pub const MetadataDepositBase: u64 = 1;
 	pub const AttributeDepositBase: u64 = 1;
 	pub const MetadataDepositPerByte: u64 = 1;
+	pub const MaxDeadlineDuration: u64 = 10000;
 }
 
 impl Config for Test {
@@ -113,6 +114,7 @@ impl Config for Test {
 	type StringLimit = StringLimit;
 	type KeyLimit = KeyLimit;
 	type ValueLimit = ValueLimit;
+	type MaxDeadlineDuration = MaxDeadlineDuration;
 	type WeightInfo = ();
 }
 
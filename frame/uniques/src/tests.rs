@@ -581,3 +581,283 @@ fn cancel_approval_works_with_force() {
 		);
 	});
 }
+
+#[test]
+fn public_mint_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+
+		// Only the Issuer may mint by default.
+		assert_noop!(
+			Uniques::public_mint(Origin::signed(2), 0, 42, None),
+			Error::<Test>::MintNotAllowed
+		);
+
+		let settings = MintSettings {
+			mint_type: MintType::Public,
+			price: Some(10),
+			start_block: Some(2),
+			end_block: Some(3),
+		};
+		assert_noop!(
+			Uniques::set_mint_settings(Origin::signed(2), 0, settings),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::set_mint_settings(Origin::signed(1), 0, settings));
+
+		assert_noop!(
+			Uniques::public_mint(Origin::signed(2), 0, 42, None),
+			Error::<Test>::MintNotStarted
+		);
+		System::set_block_number(2);
+		assert_ok!(Uniques::public_mint(Origin::signed(2), 0, 42, None));
+		assert_eq!(assets(), vec![(2, 0, 42)]);
+		assert_eq!(Balances::total_balance(&1), 110);
+		assert_eq!(Balances::total_balance(&2), 90);
+
+		System::set_block_number(4);
+		assert_noop!(
+			Uniques::public_mint(Origin::signed(2), 0, 43, None),
+			Error::<Test>::MintEnded
+		);
+	});
+}
+
+#[test]
+fn holder_mint_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::force_create(Origin::root(), 1, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 2));
+
+		let settings = MintSettings { mint_type: MintType::HolderOf(0), ..Default::default() };
+		assert_ok!(Uniques::set_mint_settings(Origin::root(), 1, settings));
+
+		assert_noop!(
+			Uniques::public_mint(Origin::signed(2), 1, 1, None),
+			Error::<Test>::BadWitness
+		);
+		assert_noop!(
+			Uniques::public_mint(Origin::signed(3), 1, 1, Some(42)),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::public_mint(Origin::signed(2), 1, 1, Some(42)));
+		assert_noop!(
+			Uniques::public_mint(Origin::signed(2), 1, 2, Some(42)),
+			Error::<Test>::AlreadyClaimed
+		);
+
+		// The claim stays with the held instance after a transfer.
+		assert_ok!(Uniques::transfer(Origin::signed(2), 0, 42, 3));
+		assert_noop!(
+			Uniques::public_mint(Origin::signed(3), 1, 2, Some(42)),
+			Error::<Test>::AlreadyClaimed
+		);
+		assert_eq!(assets(), vec![(2, 1, 1), (3, 0, 42)]);
+	});
+}
+
+#[test]
+fn lock_instance_properties_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, false));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+
+		assert_noop!(
+			Uniques::lock_instance_properties(Origin::signed(2), 0, 42, true, true),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::lock_instance_properties(Origin::signed(1), 0, 42, true, false));
+		assert_noop!(
+			Uniques::set_metadata(Origin::signed(1), 0, 42, bvec![0u8; 20], false),
+			Error::<Test>::Locked
+		);
+		assert_ok!(Uniques::set_attribute(Origin::signed(1), 0, Some(42), bvec![0], bvec![0]));
+
+		// Locks are one-way.
+		assert_ok!(Uniques::lock_instance_properties(Origin::signed(1), 0, 42, false, true));
+		assert_noop!(
+			Uniques::set_metadata(Origin::signed(1), 0, 42, bvec![0u8; 20], false),
+			Error::<Test>::Locked
+		);
+		assert_noop!(
+			Uniques::set_attribute(Origin::signed(1), 0, Some(42), bvec![1], bvec![0]),
+			Error::<Test>::Locked
+		);
+		assert_noop!(
+			Uniques::clear_attribute(Origin::signed(1), 0, Some(42), bvec![0]),
+			Error::<Test>::Locked
+		);
+
+		// Class attributes and the force origin are unaffected.
+		assert_ok!(Uniques::set_attribute(Origin::signed(1), 0, None, bvec![0], bvec![0]));
+		assert_ok!(Uniques::set_metadata(Origin::root(), 0, 42, bvec![0u8; 20], false));
+	});
+}
+
+#[test]
+fn buy_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+		Balances::make_free_balance_be(&4, 100);
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 2));
+		let royalty = Royalty { beneficiary: 1, percentage: Permill::from_percent(10) };
+		assert_ok!(Uniques::set_royalty(Origin::signed(1), 0, Some(royalty)));
+
+		assert_noop!(Uniques::buy(Origin::signed(3), 0, 42, 50), Error::<Test>::NotForSale);
+		assert_noop!(
+			Uniques::set_price(Origin::signed(3), 0, 42, Some(50), None),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::set_price(Origin::signed(2), 0, 42, Some(50), Some(3)));
+
+		assert_noop!(Uniques::buy(Origin::signed(2), 0, 42, 50), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::buy(Origin::signed(4), 0, 42, 50), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::buy(Origin::signed(3), 0, 42, 49), Error::<Test>::BidTooLow);
+		assert_ok!(Uniques::buy(Origin::signed(3), 0, 42, 60));
+
+		assert_eq!(assets(), vec![(3, 0, 42)]);
+		assert_eq!(Balances::total_balance(&1), 105);
+		assert_eq!(Balances::total_balance(&2), 145);
+		assert_eq!(Balances::total_balance(&3), 50);
+		// The price is removed once the instance changes hands.
+		assert_noop!(Uniques::buy(Origin::signed(4), 0, 42, 50), Error::<Test>::NotForSale);
+
+		assert_ok!(Uniques::set_price(Origin::signed(3), 0, 42, Some(20), None));
+		assert_ok!(Uniques::set_price(Origin::signed(3), 0, 42, None, None));
+		assert_noop!(Uniques::buy(Origin::signed(4), 0, 42, 50), Error::<Test>::NotForSale);
+	});
+}
+
+#[test]
+fn buying_should_clear_approval() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::approve_transfer(Origin::signed(2), 0, 42, 4));
+		assert_ok!(Uniques::set_price(Origin::signed(2), 0, 42, Some(50), None));
+
+		assert_ok!(Uniques::buy(Origin::signed(3), 0, 42, 50));
+		assert_eq!(assets(), vec![(3, 0, 42)]);
+		// The delegate approved by the previous owner can no longer transfer the instance.
+		assert_noop!(Uniques::transfer(Origin::signed(4), 0, 42, 4), Error::<Test>::NoPermission);
+		assert_noop!(
+			Uniques::cancel_approval(Origin::signed(3), 0, 42, None),
+			Error::<Test>::NoDelegate
+		);
+	});
+}
+
+#[test]
+fn swaps_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 1, 1));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 2, 2));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 3, 2));
+
+		let price = Some(PriceWithDirection { amount: 10, direction: PriceDirection::Send });
+		assert_noop!(
+			Uniques::create_swap(Origin::signed(1), 0, 1, 0, None, price, 10001),
+			Error::<Test>::WrongDuration
+		);
+		assert_noop!(
+			Uniques::create_swap(Origin::signed(2), 0, 1, 0, None, price, 10),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::create_swap(Origin::signed(1), 0, 1, 0, Some(4), price, 10),
+			Error::<Test>::Unknown
+		);
+		assert_ok!(Uniques::create_swap(Origin::signed(1), 0, 1, 0, Some(2), price, 10));
+
+		assert_noop!(
+			Uniques::claim_swap(Origin::signed(2), 0, 3, 0, 1, price),
+			Error::<Test>::UnknownSwap
+		);
+		assert_noop!(
+			Uniques::claim_swap(Origin::signed(2), 0, 2, 0, 1, None),
+			Error::<Test>::UnknownSwap
+		);
+		assert_noop!(Uniques::cancel_swap(Origin::signed(2), 0, 1), Error::<Test>::NoPermission);
+
+		System::set_block_number(12);
+		assert_noop!(
+			Uniques::claim_swap(Origin::signed(2), 0, 2, 0, 1, price),
+			Error::<Test>::DeadlineExpired
+		);
+		// Anyone may cancel an expired swap.
+		assert_ok!(Uniques::cancel_swap(Origin::signed(2), 0, 1));
+		assert_noop!(
+			Uniques::claim_swap(Origin::signed(2), 0, 2, 0, 1, price),
+			Error::<Test>::UnknownSwap
+		);
+
+		assert_ok!(Uniques::create_swap(Origin::signed(1), 0, 1, 0, None, price, 10));
+		assert_ok!(Uniques::claim_swap(Origin::signed(2), 0, 3, 0, 1, price));
+		assert_eq!(assets(), vec![(1, 0, 3), (2, 0, 1), (2, 0, 2)]);
+		assert_eq!(Balances::total_balance(&1), 110);
+		assert_eq!(Balances::total_balance(&2), 90);
+		assert_noop!(
+			Uniques::claim_swap(Origin::signed(2), 0, 2, 0, 1, price),
+			Error::<Test>::UnknownSwap
+		);
+	});
+}
+
+#[test]
+fn destroy_should_clear_sales_and_swaps() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+		assert_ok!(Uniques::set_price(Origin::signed(1), 0, 42, Some(10), None));
+		assert_ok!(Uniques::create_swap(Origin::signed(1), 0, 42, 0, None, None, 10));
+		assert_ok!(Uniques::lock_instance_properties(Origin::signed(1), 0, 42, true, true));
+		let royalty = Royalty { beneficiary: 1, percentage: Permill::from_percent(10) };
+		assert_ok!(Uniques::set_royalty(Origin::signed(1), 0, Some(royalty)));
+
+		let w = Class::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Uniques::destroy(Origin::signed(1), 0, w));
+		assert!(!InstancePriceOf::<Test>::contains_key(0, 42));
+		assert!(!PendingSwapOf::<Test>::contains_key(0, 42));
+		assert!(!InstanceLocksOf::<Test>::contains_key(0, 42));
+		assert!(!ClassRoyalty::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn destroy_should_clear_holder_claims() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::force_create(Origin::root(), 1, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 2));
+		let settings = MintSettings { mint_type: MintType::HolderOf(0), ..Default::default() };
+		assert_ok!(Uniques::set_mint_settings(Origin::root(), 1, settings));
+		assert_ok!(Uniques::public_mint(Origin::signed(2), 1, 1, Some(42)));
+
+		// The claim outlives the instance minted with it.
+		assert_ok!(Uniques::burn(Origin::signed(2), 1, 1, None));
+		let w = Class::<Test>::get(1).unwrap().destroy_witness();
+		assert_eq!(w.instances, 0);
+		assert_eq!(w.holder_claims, 1);
+
+		let bad_witness = DestroyWitness { holder_claims: 0, ..w };
+		assert_noop!(
+			Uniques::destroy(Origin::signed(1), 1, bad_witness),
+			Error::<Test>::BadWitness
+		);
+		assert_ok!(Uniques::destroy(Origin::signed(1), 1, w));
+		assert!(!HolderClaims::<Test>::contains_key((1, 0, 42)));
+	});
+}
@@ -27,6 +27,15 @@ pub(super) type ClassDetailsFor<T, I> =
 	ClassDetails<<T as SystemConfig>::AccountId, DepositBalanceOf<T, I>>;
 pub(super) type InstanceDetailsFor<T, I> =
 	InstanceDetails<<T as SystemConfig>::AccountId, DepositBalanceOf<T, I>>;
+pub(super) type InstancePrice<T, I = ()> = DepositBalanceOf<T, I>;
+pub(super) type MintSettingsFor<T, I> =
+	MintSettings<InstancePrice<T, I>, <T as SystemConfig>::BlockNumber, <T as Config<I>>::ClassId>;
+pub(super) type PendingSwapFor<T, I> = PendingSwap<
+	<T as Config<I>>::ClassId,
+	<T as Config<I>>::InstanceId,
+	InstancePrice<T, I>,
+	<T as SystemConfig>::BlockNumber,
+>;
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct ClassDetails<AccountId, DepositBalance> {
@@ -49,6 +58,8 @@ pub struct ClassDetails<AccountId, DepositBalance> {
 	pub(super) instance_metadatas: u32,
 	/// The total number of attributes for this asset class.
 	pub(super) attributes: u32,
+	/// The total number of held instances claimed to mint instances of this class.
+	pub(super) holder_claims: u32,
 	/// Whether the asset is frozen for non-admin transfers.
 	pub(super) is_frozen: bool,
 }
@@ -65,6 +76,9 @@ pub struct DestroyWitness {
 	#[codec(compact)]
 	/// The total number of attributes for this asset class.
 	pub(super) attributes: u32,
+	/// The total number of held instances claimed to mint instances of this asset class.
+	#[codec(compact)]
+	pub(super) holder_claims: u32,
 }
 
 impl<AccountId, DepositBalance> ClassDetails<AccountId, DepositBalance> {
@@ -73,6 +87,7 @@ impl<AccountId, DepositBalance> ClassDetails<AccountId, DepositBalance> {
 			instances: self.instances,
 			instance_metadatas: self.instance_metadatas,
 			attributes: self.attributes,
+			holder_claims: self.holder_claims,
 		}
 	}
 }
@@ -120,3 +135,82 @@ pub struct InstanceMetadata<DepositBalance, StringLimit: Get<u32>> {
 	/// Whether the asset metadata may be changed by a non Force origin.
 	pub(super) is_frozen: bool,
 }
+
+/// Who may mint instances of an asset class.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum MintType<ClassId> {
+	/// Only the Issuer of the class may mint instances.
+	Issuer,
+	/// Anyone may mint an instance for themselves.
+	Public,
+	/// Only holders of an instance of the given class may mint, once per held instance.
+	HolderOf(ClassId),
+}
+
+impl<ClassId> Default for MintType<ClassId> {
+	fn default() -> Self {
+		Self::Issuer
+	}
+}
+
+/// The conditions under which instances of an asset class may be minted by non-Issuer accounts.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo)]
+pub struct MintSettings<Price, BlockNumber, ClassId> {
+	/// Who may mint instances.
+	pub mint_type: MintType<ClassId>,
+	/// The price to be paid to the class owner for each minted instance, if any.
+	pub price: Option<Price>,
+	/// The first block at which instances may be minted, if any.
+	pub start_block: Option<BlockNumber>,
+	/// The last block at which instances may be minted, if any.
+	pub end_block: Option<BlockNumber>,
+}
+
+/// The properties of an asset instance which have been locked against further changes.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo)]
+pub struct InstanceLocks {
+	/// Whether the metadata of the instance is locked.
+	pub metadata: bool,
+	/// Whether the attributes of the instance are locked.
+	pub attributes: bool,
+}
+
+/// The royalty taken from every sale of an instance of an asset class.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Royalty<AccountId> {
+	/// The account to which the royalty is paid.
+	pub beneficiary: AccountId,
+	/// The portion of the sale price paid as royalty.
+	pub percentage: Permill,
+}
+
+/// Which side of a swap pays the price.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum PriceDirection {
+	/// The account accepting the swap pays the price to the account which proposed it.
+	Send,
+	/// The account which proposed the swap pays the price to the account accepting it.
+	Receive,
+}
+
+/// A price to be paid in a swap, together with the side which pays it.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PriceWithDirection<Price> {
+	/// The amount to be paid.
+	pub amount: Price,
+	/// The side of the swap which pays `amount`.
+	pub direction: PriceDirection,
+}
+
+/// A proposed swap of one asset instance for another.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PendingSwap<ClassId, InstanceId, Price, BlockNumber> {
+	/// The class of the instance wanted in exchange.
+	pub(super) desired_class: ClassId,
+	/// The instance wanted in exchange; any instance of `desired_class` will do if `None`.
+	pub(super) desired_instance: Option<InstanceId>,
+	/// The price to be paid on top of the exchange, if any.
+	pub(super) price: Option<PriceWithDirection<Price>>,
+	/// The last block at which the swap may be claimed.
+	pub(super) deadline: BlockNumber,
+}
@@ -47,7 +47,7 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn create() -> Weight;
 	fn force_create() -> Weight;
-	fn destroy(n: u32, m: u32, a: u32, ) -> Weight;
+	fn destroy(n: u32, m: u32, a: u32, c: u32, ) -> Weight;
 	fn mint() -> Weight;
 	fn burn() -> Weight;
 	fn transfer() -> Weight;
@@ -67,6 +67,15 @@ pub trait WeightInfo {
 	fn clear_class_metadata() -> Weight;
 	fn approve_transfer() -> Weight;
 	fn cancel_approval() -> Weight;
+	fn set_mint_settings() -> Weight;
+	fn public_mint() -> Weight;
+	fn lock_instance_properties() -> Weight;
+	fn set_royalty() -> Weight;
+	fn set_price() -> Weight;
+	fn buy() -> Weight;
+	fn create_swap() -> Weight;
+	fn cancel_swap() -> Weight;
+	fn claim_swap() -> Weight;
 }
 
 /// Weights for pallet_uniques using the Substrate node and recommended hardware.
@@ -90,20 +99,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Uniques ClassMetadataOf (r:0 w:1)
 	// Storage: Uniques InstanceMetadataOf (r:0 w:1000)
 	// Storage: Uniques Account (r:0 w:20)
-	fn destroy(n: u32, m: u32, a: u32, ) -> Weight {
+	// Storage: Uniques InstanceLocksOf (r:0 w:20)
+	// Storage: Uniques InstancePriceOf (r:0 w:20)
+	// Storage: Uniques PendingSwapOf (r:0 w:20)
+	// Storage: Uniques ClassMintSettings (r:0 w:1)
+	// Storage: Uniques ClassRoyalty (r:0 w:1)
+	// Storage: Uniques HolderClaims (r:0 w:1000)
+	fn destroy(n: u32, m: u32, a: u32, c: u32, ) -> Weight {
 		(0 as Weight)
-			// Standard Error: 12_000
-			.saturating_add((16_171_000 as Weight).saturating_mul(n as Weight))
-			// Standard Error: 12_000
+			// Standard Error: 14_000
+			.saturating_add((19_342_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 14_000
 			.saturating_add((1_058_000 as Weight).saturating_mul(m as Weight))
-			// Standard Error: 12_000
+			// Standard Error: 14_000
 			.saturating_add((953_000 as Weight).saturating_mul(a as Weight))
+			// Standard Error: 14_000
+			.saturating_add((1_127_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(n as Weight)))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:1)
@@ -243,6 +261,83 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques ClassMintSettings (r:0 w:1)
+	fn set_mint_settings() -> Weight {
+		(23_512_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques ClassMintSettings (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques HolderClaims (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	fn public_mint() -> Weight {
+		(68_904_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques InstanceLocksOf (r:1 w:1)
+	fn lock_instance_properties() -> Weight {
+		(26_418_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques ClassRoyalty (r:0 w:1)
+	fn set_royalty() -> Weight {
+		(23_027_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques InstancePriceOf (r:0 w:1)
+	fn set_price() -> Weight {
+		(24_190_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques InstancePriceOf (r:1 w:1)
+	// Storage: Uniques ClassRoyalty (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques PendingSwapOf (r:0 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	fn buy() -> Weight {
+		(79_353_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Uniques Asset (r:2 w:0)
+	// Storage: Uniques PendingSwapOf (r:0 w:1)
+	fn create_swap() -> Weight {
+		(30_461_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques PendingSwapOf (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:0)
+	fn cancel_swap() -> Weight {
+		(29_835_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:2 w:2)
+	// Storage: Uniques PendingSwapOf (r:1 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques Class (r:2 w:0)
+	// Storage: Uniques InstancePriceOf (r:0 w:2)
+	// Storage: Uniques Account (r:0 w:4)
+	fn claim_swap() -> Weight {
+		(91_602_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -265,20 +360,29 @@ impl WeightInfo for () {
 	// Storage: Uniques ClassMetadataOf (r:0 w:1)
 	// Storage: Uniques InstanceMetadataOf (r:0 w:1000)
 	// Storage: Uniques Account (r:0 w:20)
-	fn destroy(n: u32, m: u32, a: u32, ) -> Weight {
+	// Storage: Uniques InstanceLocksOf (r:0 w:20)
+	// Storage: Uniques InstancePriceOf (r:0 w:20)
+	// Storage: Uniques PendingSwapOf (r:0 w:20)
+	// Storage: Uniques ClassMintSettings (r:0 w:1)
+	// Storage: Uniques ClassRoyalty (r:0 w:1)
+	// Storage: Uniques HolderClaims (r:0 w:1000)
+	fn destroy(n: u32, m: u32, a: u32, c: u32, ) -> Weight {
 		(0 as Weight)
-			// Standard Error: 12_000
-			.saturating_add((16_171_000 as Weight).saturating_mul(n as Weight))
-			// Standard Error: 12_000
+			// Standard Error: 14_000
+			.saturating_add((19_342_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 14_000
 			.saturating_add((1_058_000 as Weight).saturating_mul(m as Weight))
-			// Standard Error: 12_000
+			// Standard Error: 14_000
 			.saturating_add((953_000 as Weight).saturating_mul(a as Weight))
+			// Standard Error: 14_000
+			.saturating_add((1_127_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(n as Weight)))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:1)
@@ -418,4 +522,81 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques ClassMintSettings (r:0 w:1)
+	fn set_mint_settings() -> Weight {
+		(23_512_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques ClassMintSettings (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques HolderClaims (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	fn public_mint() -> Weight {
+		(68_904_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques InstanceLocksOf (r:1 w:1)
+	fn lock_instance_properties() -> Weight {
+		(26_418_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques ClassRoyalty (r:0 w:1)
+	fn set_royalty() -> Weight {
+		(23_027_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques InstancePriceOf (r:0 w:1)
+	fn set_price() -> Weight {
+		(24_190_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques InstancePriceOf (r:1 w:1)
+	// Storage: Uniques ClassRoyalty (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques PendingSwapOf (r:0 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	fn buy() -> Weight {
+		(79_353_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Uniques Asset (r:2 w:0)
+	// Storage: Uniques PendingSwapOf (r:0 w:1)
+	fn create_swap() -> Weight {
+		(30_461_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques PendingSwapOf (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:0)
+	fn cancel_swap() -> Weight {
+		(29_835_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:2 w:2)
+	// Storage: Uniques PendingSwapOf (r:1 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques Class (r:2 w:0)
+	// Storage: Uniques InstancePriceOf (r:0 w:2)
+	// Storage: Uniques Account (r:0 w:4)
+	fn claim_swap() -> Weight {
+		(91_602_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
 }
@@ -20,7 +20,7 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::UnfilteredDispatchable};
 use frame_system::{Pallet as System, RawOrigin};
 use sp_runtime::traits::{Bounded, CheckedDiv, CheckedMul};
 
@@ -75,6 +75,50 @@ fn add_vesting_schedules<T: Config>(
 	Ok(total_locked.into())
 }
 
+// A piecewise-linear curve with the maximum number of points, vesting one part per block.
+fn max_curve<T: Config>() -> VestingCurve<T::BlockNumber, T::MaxCurvePoints> {
+	let n = T::MaxCurvePoints::get();
+	let points = (1..=n)
+		.map(|i| CurvePoint { elapsed: i.into(), vested: Perbill::from_rational(i, n) })
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap();
+	VestingCurve::Piecewise(points)
+}
+
+fn add_curved_vesting_schedules<T: Config>(
+	target: <T::Lookup as StaticLookup>::Source,
+	n: u32,
+) -> Result<BalanceOf<T>, &'static str> {
+	let min_transfer = T::MinVestedTransfer::get();
+	let locked = min_transfer.checked_mul(&20u32.into()).unwrap();
+	let starting_block = 1u32;
+
+	let source: T::AccountId = account("source", 0, SEED);
+	let source_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(source.clone());
+	T::Currency::make_free_balance_be(&source, BalanceOf::<T>::max_value());
+
+	System::<T>::set_block_number(T::BlockNumber::zero());
+
+	let mut total_locked: BalanceOf<T> = Zero::zero();
+	for _ in 0..n {
+		total_locked += locked;
+
+		let schedule =
+			CurvedVestingInfo::new(locked, starting_block.into(), max_curve::<T>(), true);
+		assert_ok!(Vesting::<T>::do_curved_vested_transfer(
+			source_lookup.clone(),
+			target.clone(),
+			schedule
+		));
+
+		// Top up to guarantee we can always transfer another schedule.
+		T::Currency::make_free_balance_be(&source, BalanceOf::<T>::max_value());
+	}
+
+	Ok(total_locked)
+}
+
 benchmarks! {
 	vest_locked {
 		let l in 0 .. MaxLocksOf::<T>::get() - 1;
@@ -375,6 +419,123 @@ benchmarks! {
 		);
 	}
 
+	curved_vested_transfer {
+		let l in 0 .. MaxLocksOf::<T>::get() - 1;
+		let s in 0 .. T::MAX_VESTING_SCHEDULES - 1;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(target.clone());
+		// Give target existing locks
+		add_locks::<T>(&target, l as u8);
+		// Add linear and curved vesting schedules, which share the bound on their number.
+		let mut expected_balance = add_vesting_schedules::<T>(target_lookup.clone(), s / 2)?;
+		expected_balance += add_curved_vesting_schedules::<T>(target_lookup.clone(), s - s / 2)?;
+
+		let transfer_amount = T::MinVestedTransfer::get();
+		expected_balance += transfer_amount;
+
+		let vesting_schedule = CurvedVestingInfo::new(
+			transfer_amount,
+			1u32.into(),
+			max_curve::<T>(),
+			true,
+		);
+	}: _(RawOrigin::Signed(caller), target_lookup, vesting_schedule)
+	verify {
+		assert_eq!(
+			expected_balance,
+			T::Currency::free_balance(&target),
+			"Transfer didn't happen",
+		);
+		assert_eq!(
+			Vesting::<T>::vesting_balance(&target),
+			Some(expected_balance),
+			"Lock not correctly updated",
+		);
+	}
+
+	force_curved_vested_transfer {
+		let l in 0 .. MaxLocksOf::<T>::get() - 1;
+		let s in 0 .. T::MAX_VESTING_SCHEDULES - 1;
+
+		let source: T::AccountId = account("source", 0, SEED);
+		let source_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(source.clone());
+		T::Currency::make_free_balance_be(&source, BalanceOf::<T>::max_value());
+
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(target.clone());
+		// Give target existing locks
+		add_locks::<T>(&target, l as u8);
+		// Add linear and curved vesting schedules, which share the bound on their number.
+		let mut expected_balance = add_vesting_schedules::<T>(target_lookup.clone(), s / 2)?;
+		expected_balance += add_curved_vesting_schedules::<T>(target_lookup.clone(), s - s / 2)?;
+
+		let transfer_amount = T::MinVestedTransfer::get();
+		expected_balance += transfer_amount;
+
+		let vesting_schedule = CurvedVestingInfo::new(
+			transfer_amount,
+			1u32.into(),
+			max_curve::<T>(),
+			true,
+		);
+	}: _(RawOrigin::Root, source_lookup, target_lookup, vesting_schedule)
+	verify {
+		assert_eq!(
+			expected_balance,
+			T::Currency::free_balance(&target),
+			"Transfer didn't happen",
+		);
+		assert_eq!(
+			Vesting::<T>::vesting_balance(&target),
+			Some(expected_balance),
+			"Lock not correctly updated",
+		);
+	}
+
+	revoke_schedule {
+		let l in 0 .. MaxLocksOf::<T>::get() - 1;
+		let s in 1 .. T::MAX_VESTING_SCHEDULES;
+
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(target.clone());
+		// Give target existing locks, over funds that are not revoked.
+		T::Currency::make_free_balance_be(&target, 256u32.into());
+		add_locks::<T>(&target, l as u8);
+		// Add linear and curved vesting schedules, which share the bound on their number.
+		let mut expected_balance = add_vesting_schedules::<T>(target_lookup.clone(), s / 2)?;
+		expected_balance += add_curved_vesting_schedules::<T>(target_lookup.clone(), s - s / 2)?;
+
+		// Nothing is vested at block zero, so the whole schedule is revoked.
+		let revoked = T::MinVestedTransfer::get() * 20u32.into();
+		expected_balance -= revoked;
+		let destination = T::RevokedFundsDestination::get();
+		let destination_balance = T::Currency::free_balance(&destination);
+
+		let origin = T::RevokeOrigin::successful_origin();
+		let call = Call::<T>::revoke_schedule { target: target_lookup, schedule_index: 0 };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(
+			T::Currency::free_balance(&destination),
+			destination_balance + revoked,
+			"Revoked funds didn't go to the destination",
+		);
+		assert_eq!(
+			Vesting::<T>::vesting_balance(&target).unwrap_or_default(),
+			expected_balance,
+			"Lock not correctly updated",
+		);
+		assert_eq!(
+			Vesting::<T>::curved_vesting(&target).map_or(0, |schedules| schedules.len()),
+			(s - s / 2 - 1) as usize,
+			"Schedule count should reduce by 1"
+		);
+	}
+
 	impl_benchmark_test_suite!(
 		Vesting,
 		crate::mock::ExtBuilder::default().existential_deposit(256).build(),
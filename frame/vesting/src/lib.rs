@@ -32,6 +32,10 @@
 //! either `vest` (in typical case where the sender is calling on their own behalf) or `vest_other`
 //! in case the sender is calling on another account's behalf.
 //!
+//! Besides linear schedules, funds may be vested along a [`VestingCurve`]: linearly after a cliff,
+//! in equal steps, or along a piecewise-linear curve. Such a schedule may be marked as revocable,
+//! in which case `RevokeOrigin` can send whatever is not yet vested to `RevokedFundsDestination`.
+//!
 //! ## Interface
 //!
 //! This pallet implements the `VestingSchedule` trait.
@@ -41,6 +45,15 @@
 //! - `vest` - Update the lock, reducing it in line with the amount "vested" so far.
 //! - `vest_other` - Update the lock of another account, reducing it in line with the amount
 //!   "vested" so far.
+//! - `vested_transfer` - Transfer funds to an account along with a linear vesting schedule.
+//! - `curved_vested_transfer` - Transfer funds to an account along with a curved vesting schedule.
+//! - `merge_schedules` - Merge two linear vesting schedules of the sender into one.
+//! - `revoke_schedule` - Send the unvested funds of a revocable curved schedule to
+//!   `RevokedFundsDestination`.
+//!
+//! ### Runtime API
+//!
+//! [`VestingApi`] returns the vested and still locked amounts of an account at a given block.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -50,30 +63,34 @@ mod migrations;
 mod mock;
 #[cfg(test)]
 mod tests;
+mod vesting_curve;
 mod vesting_info;
 
 pub mod weights;
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	ensure,
 	pallet_prelude::*,
 	traits::{
-		Currency, ExistenceRequirement, Get, LockIdentifier, LockableCurrency, VestingSchedule,
-		WithdrawReasons,
+		Currency, EnsureOrigin, ExistenceRequirement, Get, LockIdentifier, LockableCurrency,
+		VestingSchedule, WithdrawReasons,
 	},
+	transactional, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
 use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
 pub use pallet::*;
 use scale_info::TypeInfo;
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
 	traits::{
 		AtLeast32BitUnsigned, Bounded, Convert, MaybeSerializeDeserialize, One, Saturating,
 		StaticLookup, Zero,
 	},
-	RuntimeDebug,
+	PerThing, Perbill, RuntimeDebug, SaturatedConversion,
 };
 use sp_std::{convert::TryInto, fmt::Debug, prelude::*};
+pub use vesting_curve::*;
 pub use vesting_info::*;
 pub use weights::WeightInfo;
 
@@ -81,6 +98,12 @@ type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 type MaxLocksOf<T> =
 	<<T as Config>::Currency as LockableCurrency<<T as frame_system::Config>::AccountId>>::MaxLocks;
+/// The curved vesting schedules of the pallet.
+pub type CurvedVestingInfoOf<T> = CurvedVestingInfo<
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config>::MaxCurvePoints,
+>;
 
 const VESTING_ID: LockIdentifier = *b"vesting ";
 
@@ -161,6 +184,17 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinVestedTransfer: Get<BalanceOf<Self>>;
 
+		/// The origin which may revoke the unvested funds of a revocable schedule.
+		type RevokeOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The account receiving the funds of revoked schedules.
+		#[pallet::constant]
+		type RevokedFundsDestination: Get<Self::AccountId>;
+
+		/// Maximum number of points of a piecewise-linear `VestingCurve`.
+		#[pallet::constant]
+		type MaxCurvePoints: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -217,6 +251,19 @@ pub mod pallet {
 		BoundedVec<VestingInfo<BalanceOf<T>, T::BlockNumber>, MaxVestingSchedulesGet<T>>,
 	>;
 
+	/// Vesting schedules of a given account that release their funds along a `VestingCurve`.
+	///
+	/// These are kept apart from `Vesting` and are never merged, but share its lock. An account
+	/// holds at most `MaxVestingSchedules` schedules across both.
+	#[pallet::storage]
+	#[pallet::getter(fn curved_vesting)]
+	pub type CurvedVesting<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<CurvedVestingInfoOf<T>, MaxVestingSchedulesGet<T>>,
+	>;
+
 	/// Storage version of the pallet.
 	///
 	/// New networks start with latest version, as determined by the genesis build.
@@ -282,6 +329,8 @@ pub mod pallet {
 		VestingUpdated { account: T::AccountId, unvested: BalanceOf<T> },
 		/// An \[account\] has become fully vested.
 		VestingCompleted { account: T::AccountId },
+		/// The unvested `amount` of a schedule of `account` was revoked.
+		VestingRevoked { account: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	/// Error for the vesting pallet.
@@ -298,6 +347,8 @@ pub mod pallet {
 		ScheduleIndexOutOfBounds,
 		/// Failed to create a new schedule because some parameter was invalid.
 		InvalidScheduleParams,
+		/// The schedule cannot be revoked.
+		NotRevocable,
 	}
 
 	#[pallet::call]
@@ -455,8 +506,135 @@ pub mod pallet {
 			let (schedules, locked_now) = Self::exec_action(schedules.to_vec(), merge_action)?;
 
 			Self::write_vesting(&who, schedules)?;
-			Self::write_lock(&who, locked_now);
+			Self::write_lock(&who, locked_now.saturating_add(Self::curved_locked_now(&who)));
+
+			Ok(())
+		}
+
+		/// Create a vested transfer that releases its funds along a `VestingCurve`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: The account receiving the vested funds.
+		/// - `schedule`: The curved vesting schedule attached to the transfer. If it is revocable,
+		///   `RevokeOrigin` may send its unvested funds to `RevokedFundsDestination` at any time.
+		///
+		/// NOTE: This will unlock all schedules through the current block.
+		///
+		/// # <weight>
+		/// - `O(1)`.
+		/// - DbWeight: 4 Reads, 4 Writes
+		///     - Reads: Vesting Storage, Curved Vesting Storage, Balances Locks, Target Account,
+		///       [Sender Account]
+		///     - Writes: Vesting Storage, Curved Vesting Storage, Balances Locks, Target Account,
+		///       [Sender Account]
+		/// # </weight>
+		#[pallet::weight(
+			T::WeightInfo::curved_vested_transfer(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn curved_vested_transfer(
+			origin: OriginFor<T>,
+			target: <T::Lookup as StaticLookup>::Source,
+			schedule: CurvedVestingInfoOf<T>,
+		) -> DispatchResult {
+			let transactor = ensure_signed(origin)?;
+			let transactor = <T::Lookup as StaticLookup>::unlookup(transactor);
+			Self::do_curved_vested_transfer(transactor, target, schedule)
+		}
+
+		/// Force a vested transfer that releases its funds along a `VestingCurve`.
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// - `source`: The account whose funds should be transferred.
+		/// - `target`: The account that should be transferred the vested funds.
+		/// - `schedule`: The curved vesting schedule attached to the transfer.
+		///
+		/// NOTE: This will unlock all schedules through the current block.
+		///
+		/// # <weight>
+		/// - `O(1)`.
+		/// - DbWeight: 5 Reads, 5 Writes
+		///     - Reads: Vesting Storage, Curved Vesting Storage, Balances Locks, Target Account,
+		///       Source Account
+		///     - Writes: Vesting Storage, Curved Vesting Storage, Balances Locks, Target Account,
+		///       Source Account
+		/// # </weight>
+		#[pallet::weight(
+			T::WeightInfo::force_curved_vested_transfer(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn force_curved_vested_transfer(
+			origin: OriginFor<T>,
+			source: <T::Lookup as StaticLookup>::Source,
+			target: <T::Lookup as StaticLookup>::Source,
+			schedule: CurvedVestingInfoOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::do_curved_vested_transfer(source, target, schedule)
+		}
+
+		/// Revoke a curved vesting schedule, sending the funds it still locks to
+		/// `RevokedFundsDestination`. The funds vested so far stay with the target.
+		///
+		/// The dispatch origin for this call must be `RevokeOrigin`.
+		///
+		/// - `target`: The account whose schedule is revoked.
+		/// - `schedule_index`: The index of the revocable schedule in the curved vesting schedules
+		///   of `target`.
+		///
+		/// Emits `VestingRevoked`.
+		///
+		/// NOTE: This will unlock all schedules through the current block.
+		///
+		/// NOTE: Only the vesting lock is lifted before the funds are moved. If another lock, such
+		/// as the one of staking, overlaps the revoked funds, the revocation fails as a whole and
+		/// can only succeed once that lock is lowered.
+		///
+		/// # <weight>
+		/// - `O(1)`.
+		/// - DbWeight: 5 Reads, 5 Writes
+		///     - Reads: Vesting Storage, Curved Vesting Storage, Balances Locks, Target Account,
+		///       Destination Account
+		///     - Writes: Vesting Storage, Curved Vesting Storage, Balances Locks, Target Account,
+		///       Destination Account
+		/// # </weight>
+		#[pallet::weight(
+			T::WeightInfo::revoke_schedule(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		#[transactional]
+		pub fn revoke_schedule(
+			origin: OriginFor<T>,
+			target: <T::Lookup as StaticLookup>::Source,
+			schedule_index: u32,
+		) -> DispatchResult {
+			T::RevokeOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(target)?;
+
+			let mut curved_schedules = Self::curved_vesting(&who).ok_or(Error::<T>::NotVesting)?;
+			let schedule_index = schedule_index as usize;
+			let schedule = curved_schedules
+				.get(schedule_index)
+				.ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+			ensure!(schedule.revocable(), Error::<T>::NotRevocable);
 
+			let now = <frame_system::Pallet<T>>::block_number();
+			let unvested = schedule.locked_at(now);
+			curved_schedules.remove(schedule_index);
+
+			// Lift the lock on the revoked funds before moving them.
+			let schedules = Self::vesting(&who).unwrap_or_default();
+			Self::update_vesting(&who, schedules.to_vec(), curved_schedules.to_vec())?;
+
+			// Fees may have been paid out of the locked funds, so they may not all be there.
+			let amount = unvested.min(T::Currency::free_balance(&who));
+			T::Currency::transfer(
+				&who,
+				&T::RevokedFundsDestination::get(),
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Self::deposit_event(Event::<T>::VestingRevoked { account: who, amount });
 			Ok(())
 		}
 	}
@@ -553,6 +731,33 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	// Execute a vested transfer from `source` to `target` with the given curved `schedule`.
+	fn do_curved_vested_transfer(
+		source: <T::Lookup as StaticLookup>::Source,
+		target: <T::Lookup as StaticLookup>::Source,
+		schedule: CurvedVestingInfoOf<T>,
+	) -> DispatchResult {
+		// Validate user inputs.
+		ensure!(schedule.locked() >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+		ensure!(schedule.is_valid(), Error::<T>::InvalidScheduleParams);
+		let target = T::Lookup::lookup(target)?;
+		let source = T::Lookup::lookup(source)?;
+
+		// Check we can add to this account prior to any storage writes.
+		ensure!(
+			Self::schedule_count(&target) < T::MAX_VESTING_SCHEDULES,
+			Error::<T>::AtMaxVestingSchedules
+		);
+		let locked = schedule.locked();
+		let mut curved_schedules = Self::curved_vesting(&target).unwrap_or_default();
+		ensure!(curved_schedules.try_push(schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
+
+		T::Currency::transfer(&source, &target, locked, ExistenceRequirement::AllowDeath)?;
+
+		let schedules = Self::vesting(&target).unwrap_or_default();
+		Self::update_vesting(&target, schedules.to_vec(), curved_schedules.to_vec())
+	}
+
 	/// Iterate through the schedules to track the current locked amount and
 	/// filter out completed and specified schedules.
 	///
@@ -586,6 +791,74 @@ impl<T: Config> Pallet<T> {
 		(filtered_schedules, total_locked_now)
 	}
 
+	/// Filter out the completed curved schedules and track the current locked amount of the rest.
+	///
+	/// Returns a tuple that consists of:
+	/// - Vec of curved vesting schedules, where completed schedules are removed.
+	/// - The amount locked at the current block number based on the given schedules.
+	fn report_curved_schedule_updates(
+		schedules: Vec<CurvedVestingInfoOf<T>>,
+	) -> (Vec<CurvedVestingInfoOf<T>>, BalanceOf<T>) {
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		let mut total_locked_now: BalanceOf<T> = Zero::zero();
+		let filtered_schedules = schedules
+			.into_iter()
+			.filter(|schedule| {
+				let locked_now = schedule.locked_at(now);
+				total_locked_now = total_locked_now.saturating_add(locked_now);
+				!locked_now.is_zero()
+			})
+			.collect::<Vec<_>>();
+
+		(filtered_schedules, total_locked_now)
+	}
+
+	/// The number of linear and curved schedules of `who`, which together may not exceed
+	/// `MaxVestingSchedules`.
+	fn schedule_count(who: &T::AccountId) -> u32 {
+		let linear = Vesting::<T>::decode_len(who).unwrap_or_default();
+		let curved = CurvedVesting::<T>::decode_len(who).unwrap_or_default();
+		linear.saturating_add(curved) as u32
+	}
+
+	/// The amount locked at the current block by the curved schedules of `who`.
+	fn curved_locked_now(who: &T::AccountId) -> BalanceOf<T> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		Self::curved_vesting(who).map_or(Zero::zero(), |schedules| {
+			schedules
+				.iter()
+				.fold(Zero::zero(), |total, schedule| schedule.locked_at(now).saturating_add(total))
+		})
+	}
+
+	/// The amounts of the funds under vesting of `who` that are vested and that are still locked
+	/// at block `at`, across all of its current schedules, or `None` if `who` is not vesting.
+	///
+	/// This is meant to be used by the [`VestingApi`] runtime API.
+	pub fn vesting_balances_at(
+		who: T::AccountId,
+		at: T::BlockNumber,
+	) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
+		let schedules = Self::vesting(&who);
+		let curved_schedules = Self::curved_vesting(&who);
+		if schedules.is_none() && curved_schedules.is_none() {
+			return None
+		}
+
+		let (mut total, mut locked): (BalanceOf<T>, BalanceOf<T>) = (Zero::zero(), Zero::zero());
+		for schedule in schedules.unwrap_or_default().iter() {
+			total = total.saturating_add(schedule.locked());
+			locked = locked.saturating_add(schedule.locked_at::<T::BlockNumberToBalance>(at));
+		}
+		for schedule in curved_schedules.unwrap_or_default().iter() {
+			total = total.saturating_add(schedule.locked());
+			locked = locked.saturating_add(schedule.locked_at(at));
+		}
+
+		Some((total.saturating_sub(locked), locked))
+	}
+
 	/// Write an accounts updated vesting lock to storage.
 	fn write_lock(who: &T::AccountId, total_locked_now: BalanceOf<T>) {
 		if total_locked_now.is_zero() {
@@ -620,15 +893,50 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Write an accounts updated curved vesting schedules to storage.
+	fn write_curved_vesting(
+		who: &T::AccountId,
+		schedules: Vec<CurvedVestingInfoOf<T>>,
+	) -> Result<(), DispatchError> {
+		let schedules: BoundedVec<CurvedVestingInfoOf<T>, MaxVestingSchedulesGet<T>> =
+			schedules.try_into().map_err(|_| Error::<T>::AtMaxVestingSchedules)?;
+
+		if schedules.len() == 0 {
+			CurvedVesting::<T>::remove(&who);
+		} else {
+			CurvedVesting::<T>::insert(who, schedules)
+		}
+
+		Ok(())
+	}
+
 	/// Unlock any vested funds of `who`.
 	fn do_vest(who: T::AccountId) -> DispatchResult {
-		let schedules = Self::vesting(&who).ok_or(Error::<T>::NotVesting)?;
+		let schedules = Self::vesting(&who);
+		let curved_schedules = Self::curved_vesting(&who);
+		ensure!(schedules.is_some() || curved_schedules.is_some(), Error::<T>::NotVesting);
+
+		Self::update_vesting(
+			&who,
+			schedules.unwrap_or_default().to_vec(),
+			curved_schedules.unwrap_or_default().to_vec(),
+		)
+	}
 
-		let (schedules, locked_now) =
-			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+	/// Write the given linear and curved schedules of `who`, without those that have completed,
+	/// and set the lock to what they lock at the current block.
+	fn update_vesting(
+		who: &T::AccountId,
+		schedules: Vec<VestingInfo<BalanceOf<T>, T::BlockNumber>>,
+		curved_schedules: Vec<CurvedVestingInfoOf<T>>,
+	) -> DispatchResult {
+		let (schedules, locked_now) = Self::exec_action(schedules, VestingAction::Passive)?;
+		let (curved_schedules, curved_locked_now) =
+			Self::report_curved_schedule_updates(curved_schedules);
 
-		Self::write_vesting(&who, schedules)?;
-		Self::write_lock(&who, locked_now);
+		Self::write_vesting(who, schedules)?;
+		Self::write_curved_vesting(who, curved_schedules)?;
+		Self::write_lock(who, locked_now.saturating_add(curved_locked_now));
 
 		Ok(())
 	}
@@ -687,15 +995,18 @@ where
 
 	/// Get the amount that is currently being vested and cannot be transferred out of this account.
 	fn vesting_balance(who: &T::AccountId) -> Option<BalanceOf<T>> {
-		if let Some(v) = Self::vesting(who) {
-			let now = <frame_system::Pallet<T>>::block_number();
-			let total_locked_now = v.iter().fold(Zero::zero(), |total, schedule| {
+		let schedules = Self::vesting(who);
+		if schedules.is_none() && !CurvedVesting::<T>::contains_key(who) {
+			return None
+		}
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let total_locked_now =
+			schedules.unwrap_or_default().iter().fold(Zero::zero(), |total, schedule| {
 				schedule.locked_at::<T::BlockNumberToBalance>(now).saturating_add(total)
 			});
-			Some(T::Currency::free_balance(who).min(total_locked_now))
-		} else {
-			None
-		}
+		let total_locked_now = total_locked_now.saturating_add(Self::curved_locked_now(who));
+		Some(T::Currency::free_balance(who).min(total_locked_now))
 	}
 
 	/// Adds a vesting schedule to a given account.
@@ -726,6 +1037,10 @@ where
 			return Err(Error::<T>::InvalidScheduleParams.into())
 		};
 
+		ensure!(
+			Self::schedule_count(who) < T::MAX_VESTING_SCHEDULES,
+			Error::<T>::AtMaxVestingSchedules
+		);
 		let mut schedules = Self::vesting(who).unwrap_or_default();
 
 		// NOTE: we must push the new schedule so that `exec_action`
@@ -736,7 +1051,7 @@ where
 			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
 
 		Self::write_vesting(&who, schedules)?;
-		Self::write_lock(who, locked_now);
+		Self::write_lock(who, locked_now.saturating_add(Self::curved_locked_now(who)));
 
 		Ok(())
 	}
@@ -755,7 +1070,7 @@ where
 		}
 
 		ensure!(
-			(Vesting::<T>::decode_len(who).unwrap_or_default() as u32) < T::MAX_VESTING_SCHEDULES,
+			Self::schedule_count(who) < T::MAX_VESTING_SCHEDULES,
 			Error::<T>::AtMaxVestingSchedules
 		);
 
@@ -770,7 +1085,20 @@ where
 		let (schedules, locked_now) = Self::exec_action(schedules.to_vec(), remove_action)?;
 
 		Self::write_vesting(&who, schedules)?;
-		Self::write_lock(who, locked_now);
+		Self::write_lock(who, locked_now.saturating_add(Self::curved_locked_now(who)));
 		Ok(())
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the vesting of an account at a given block.
+	pub trait VestingApi<AccountId, Balance, BlockNumber> where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// The amounts of the funds under vesting of `who` that are vested and that are still
+		/// locked at block `at`, or `None` if `who` is not vesting.
+		fn vesting_balances_at(who: AccountId, at: BlockNumber) -> Option<(Balance, Balance)>;
+	}
+}
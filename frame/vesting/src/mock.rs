@@ -16,6 +16,7 @@
 // limitations under the License.
 
 use frame_support::parameter_types;
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -87,13 +88,18 @@ impl pallet_balances::Config for Test {
 parameter_types! {
 	pub const MinVestedTransfer: u64 = 256 * 2;
 	pub static ExistentialDeposit: u64 = 0;
+	pub const RevokedFundsDestination: u64 = 100;
+	pub const MaxCurvePoints: u32 = 4;
 }
 impl Config for Test {
 	type BlockNumberToBalance = Identity;
 	type Currency = Balances;
 	type Event = Event;
 	const MAX_VESTING_SCHEDULES: u32 = 3;
+	type MaxCurvePoints = MaxCurvePoints;
 	type MinVestedTransfer = MinVestedTransfer;
+	type RevokeOrigin = EnsureRoot<u64>;
+	type RevokedFundsDestination = RevokedFundsDestination;
 	type WeightInfo = ();
 }
 
@@ -1155,3 +1155,275 @@ fn vested_transfer_less_than_existential_deposit_fails() {
 		);
 	});
 }
+
+#[test]
+fn curved_vesting_info_locked_at_works() {
+	// Nothing is released before the cliff, then everything vested so far is released at once.
+	let cliff = CurvedVestingInfoOf::<Test>::new(
+		1_000,
+		10,
+		VestingCurve::Linear { cliff: 5, duration: 20 },
+		false,
+	);
+	assert!(cliff.is_valid());
+	assert_eq!(cliff.locked_at(9), 1_000);
+	assert_eq!(cliff.locked_at(14), 1_000);
+	assert_eq!(cliff.locked_at(15), 750);
+	assert_eq!(cliff.locked_at(25), 250);
+	assert_eq!(cliff.ending_block(), 30);
+	assert_eq!(cliff.locked_at(30), 0);
+
+	// Funds are released in equal parts at the end of every period.
+	let stepped = CurvedVestingInfoOf::<Test>::new(
+		1_000,
+		10,
+		VestingCurve::Stepped { period: 10, steps: 4 },
+		false,
+	);
+	assert!(stepped.is_valid());
+	assert_eq!(stepped.locked_at(19), 1_000);
+	assert_eq!(stepped.locked_at(20), 750);
+	assert_eq!(stepped.locked_at(39), 500);
+	assert_eq!(stepped.ending_block(), 50);
+	assert_eq!(stepped.locked_at(50), 0);
+
+	// Funds are released along the lines between the points.
+	let points = vec![
+		(10, Perbill::from_percent(20)),
+		(20, Perbill::from_percent(20)),
+		(30, Perbill::from_percent(100)),
+	];
+	let piecewise = CurvedVestingInfoOf::<Test>::new(
+		1_000,
+		0,
+		VestingCurve::Piecewise(
+			points.into_iter().map(Into::into).collect::<Vec<_>>().try_into().unwrap(),
+		),
+		false,
+	);
+	assert!(piecewise.is_valid());
+	assert_eq!(piecewise.locked_at(5), 900);
+	assert_eq!(piecewise.locked_at(15), 800);
+	assert_eq!(piecewise.locked_at(25), 400);
+	assert_eq!(piecewise.ending_block(), 30);
+	assert_eq!(piecewise.locked_at(30), 0);
+}
+
+#[test]
+fn curved_vesting_info_validation_works() {
+	let is_valid = |locked: u64, curve: VestingCurve<u64, <Test as Config>::MaxCurvePoints>| {
+		CurvedVestingInfoOf::<Test>::new(locked, 10, curve, false).is_valid()
+	};
+	let piecewise = |points: Vec<(u64, Perbill)>| {
+		VestingCurve::Piecewise(
+			points.into_iter().map(Into::into).collect::<Vec<_>>().try_into().unwrap(),
+		)
+	};
+
+	// Nothing to vest.
+	assert!(!is_valid(0, VestingCurve::Linear { cliff: 0, duration: 20 }));
+	// The schedule never ends.
+	assert!(!is_valid(ED, VestingCurve::Linear { cliff: 0, duration: 0 }));
+	assert!(!is_valid(ED, VestingCurve::Stepped { period: 0, steps: 4 }));
+	assert!(!is_valid(ED, VestingCurve::Stepped { period: 10, steps: 0 }));
+	// The cliff comes after the end.
+	assert!(!is_valid(ED, VestingCurve::Linear { cliff: 21, duration: 20 }));
+	// A piecewise curve must vest everything at its last point,
+	assert!(!is_valid(ED, piecewise(vec![])));
+	assert!(!is_valid(ED, piecewise(vec![(10, Perbill::from_percent(50))])));
+	// its blocks must be strictly increasing,
+	assert!(!is_valid(
+		ED,
+		piecewise(vec![(10, Perbill::from_percent(50)), (10, Perbill::from_percent(100))])
+	));
+	// and it may not go down.
+	assert!(!is_valid(
+		ED,
+		piecewise(vec![
+			(10, Perbill::from_percent(50)),
+			(20, Perbill::from_percent(40)),
+			(30, Perbill::from_percent(100))
+		])
+	));
+	assert!(is_valid(
+		ED,
+		piecewise(vec![(0, Perbill::from_percent(50)), (10, Perbill::from_percent(100))])
+	));
+}
+
+#[test]
+fn curved_vested_transfer_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let user4_free_balance = Balances::free_balance(&4);
+		let sched = CurvedVestingInfoOf::<Test>::new(
+			ED * 20,
+			10,
+			VestingCurve::Linear { cliff: 10, duration: 20 },
+			false,
+		);
+		assert_ok!(Vesting::curved_vested_transfer(Some(3).into(), 4, sched.clone()));
+		assert_eq!(Vesting::curved_vesting(&4).unwrap(), vec![sched]);
+		assert_eq!(Vesting::vesting(&4), None);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 20));
+		assert_eq!(Balances::usable_balance(&4), user4_free_balance);
+
+		// Nothing is released before the cliff.
+		System::set_block_number(19);
+		assert_ok!(Vesting::vest(Some(4).into()));
+		assert_eq!(Balances::usable_balance(&4), user4_free_balance);
+
+		// At the cliff, half the funds are released at once.
+		System::set_block_number(20);
+		assert_ok!(Vesting::vest(Some(4).into()));
+		assert_eq!(Balances::usable_balance(&4), user4_free_balance + ED * 10);
+
+		// Once fully vested, the schedule is removed.
+		System::set_block_number(30);
+		assert_ok!(Vesting::vest(Some(4).into()));
+		assert_eq!(Balances::usable_balance(&4), user4_free_balance + ED * 20);
+		assert!(!CurvedVesting::<Test>::contains_key(&4));
+		assert_noop!(Vesting::vest(Some(4).into()), Error::<Test>::NotVesting);
+	});
+}
+
+#[test]
+fn curved_vested_transfer_checks_schedule() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let curve = VestingCurve::Stepped { period: 10, steps: 2 };
+		let too_low = CurvedVestingInfoOf::<Test>::new(
+			<Test as Config>::MinVestedTransfer::get() - 1,
+			10,
+			curve.clone(),
+			false,
+		);
+		assert_noop!(
+			Vesting::curved_vested_transfer(Some(3).into(), 4, too_low),
+			Error::<Test>::AmountLow,
+		);
+
+		let invalid = CurvedVestingInfoOf::<Test>::new(
+			ED * 2,
+			10,
+			VestingCurve::Linear { cliff: 30, duration: 20 },
+			false,
+		);
+		assert_noop!(
+			Vesting::curved_vested_transfer(Some(3).into(), 4, invalid),
+			Error::<Test>::InvalidScheduleParams,
+		);
+
+		let sched = CurvedVestingInfoOf::<Test>::new(ED * 2, 10, curve, true);
+		assert_noop!(
+			Vesting::force_curved_vested_transfer(Some(3).into(), 3, 4, sched.clone()),
+			BadOrigin,
+		);
+		for _ in 0..<Test as Config>::MAX_VESTING_SCHEDULES {
+			assert_ok!(Vesting::force_curved_vested_transfer(
+				RawOrigin::Root.into(),
+				3,
+				4,
+				sched.clone()
+			));
+		}
+		assert_noop!(
+			Vesting::curved_vested_transfer(Some(3).into(), 4, sched),
+			Error::<Test>::AtMaxVestingSchedules,
+		);
+	});
+}
+
+#[test]
+fn revoke_schedule_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let destination = <Test as Config>::RevokedFundsDestination::get();
+		let user4_free_balance = Balances::free_balance(&4);
+		let revocable = CurvedVestingInfoOf::<Test>::new(
+			ED * 20,
+			10,
+			VestingCurve::Stepped { period: 5, steps: 4 },
+			true,
+		);
+		let irrevocable = CurvedVestingInfoOf::<Test>::new(
+			ED * 20,
+			10,
+			VestingCurve::Linear { cliff: 0, duration: 20 },
+			false,
+		);
+		assert_ok!(Vesting::force_curved_vested_transfer(RawOrigin::Root.into(), 13, 4, revocable));
+		assert_ok!(Vesting::curved_vested_transfer(Some(13).into(), 4, irrevocable.clone()));
+
+		// Half way through both schedules.
+		System::set_block_number(20);
+
+		assert_noop!(Vesting::revoke_schedule(Some(4).into(), 4, 0), BadOrigin);
+		assert_noop!(
+			Vesting::revoke_schedule(RawOrigin::Root.into(), 4, 1),
+			Error::<Test>::NotRevocable,
+		);
+		assert_noop!(
+			Vesting::revoke_schedule(RawOrigin::Root.into(), 4, 2),
+			Error::<Test>::ScheduleIndexOutOfBounds,
+		);
+		assert_noop!(
+			Vesting::revoke_schedule(RawOrigin::Root.into(), 2, 0),
+			Error::<Test>::NotVesting,
+		);
+
+		// Only the funds that are not vested yet go to the destination.
+		assert_ok!(Vesting::revoke_schedule(RawOrigin::Root.into(), 4, 0));
+		System::assert_last_event(
+			Event::<Test>::VestingRevoked { account: 4, amount: ED * 10 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&destination), ED * 10);
+		assert_eq!(Balances::free_balance(&4), user4_free_balance + ED * 30);
+		assert_eq!(Vesting::curved_vesting(&4).unwrap(), vec![irrevocable]);
+		// The lock now only covers the remaining schedule.
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 10));
+		assert_eq!(Balances::usable_balance(&4), user4_free_balance + ED * 20);
+	});
+}
+
+#[test]
+fn revoke_schedule_fails_while_another_lock_overlaps() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let revocable = CurvedVestingInfoOf::<Test>::new(
+			ED * 20,
+			10,
+			VestingCurve::Stepped { period: 5, steps: 4 },
+			true,
+		);
+		assert_ok!(Vesting::force_curved_vested_transfer(RawOrigin::Root.into(), 13, 4, revocable));
+		// Another lock covers more than the funds outside of the schedule.
+		Balances::set_lock(*b"otherlck", &4, ED * 50, WithdrawReasons::all());
+
+		assert_noop!(
+			Vesting::revoke_schedule(RawOrigin::Root.into(), 4, 0),
+			pallet_balances::Error::<Test>::LiquidityRestrictions,
+		);
+
+		// Once the other lock is lowered, the revocation goes through.
+		Balances::set_lock(*b"otherlck", &4, ED * 40, WithdrawReasons::all());
+		assert_ok!(Vesting::revoke_schedule(RawOrigin::Root.into(), 4, 0));
+		assert_eq!(Balances::free_balance(&4), ED * 40);
+	});
+}
+
+#[test]
+fn vesting_balances_at_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		// Account 2 vests `ED * 20` linearly over 20 blocks from block 10.
+		let sched = CurvedVestingInfoOf::<Test>::new(
+			ED * 10,
+			10,
+			VestingCurve::Stepped { period: 10, steps: 2 },
+			false,
+		);
+		assert_ok!(Vesting::curved_vested_transfer(Some(3).into(), 2, sched));
+
+		assert_eq!(Vesting::vesting_balances_at(2, 10), Some((0, ED * 30)));
+		assert_eq!(Vesting::vesting_balances_at(2, 15), Some((ED * 5, ED * 25)));
+		assert_eq!(Vesting::vesting_balances_at(2, 20), Some((ED * 15, ED * 15)));
+		assert_eq!(Vesting::vesting_balances_at(2, 30), Some((ED * 30, 0)));
+		assert_eq!(Vesting::vesting_balances_at(4, 30), None);
+	});
+}
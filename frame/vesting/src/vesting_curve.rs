@@ -0,0 +1,223 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Module to enforce private fields on `CurvedVestingInfo`.
+
+use super::*;
+
+/// A point of a piecewise-linear `VestingCurve`.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct CurvePoint<BlockNumber> {
+	/// Number of blocks after the starting block of the schedule.
+	pub elapsed: BlockNumber,
+	/// Portion of the funds vested by then.
+	pub vested: Perbill,
+}
+
+impl<BlockNumber: MaxEncodedLen> MaxEncodedLen for CurvePoint<BlockNumber> {
+	fn max_encoded_len() -> usize {
+		// `Perbill` is encoded as its parts, a `u32`.
+		BlockNumber::max_encoded_len().saturating_add(u32::max_encoded_len())
+	}
+}
+
+impl<BlockNumber> From<(BlockNumber, Perbill)> for CurvePoint<BlockNumber> {
+	fn from((elapsed, vested): (BlockNumber, Perbill)) -> Self {
+		CurvePoint { elapsed, vested }
+	}
+}
+
+/// The shape along which the funds of a `CurvedVestingInfo` are released, relative to the
+/// starting block of the schedule.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	MaxEncodedLen,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+)]
+#[codec(mel_bound(BlockNumber: MaxEncodedLen, MaxPoints: Get<u32>))]
+#[scale_info(skip_type_params(MaxPoints))]
+pub enum VestingCurve<BlockNumber: Copy + Eq + Debug, MaxPoints: Get<u32>> {
+	/// Funds are released linearly over `duration` blocks, but nothing is released before `cliff`
+	/// blocks have passed. Everything that vested up to the cliff is released at once.
+	Linear { cliff: BlockNumber, duration: BlockNumber },
+	/// Funds are released in `steps` equal parts, one every `period` blocks.
+	Stepped { period: BlockNumber, steps: u32 },
+	/// Funds are released along the piecewise-linear curve through the given points. The curve
+	/// starts from nothing vested at the starting block.
+	Piecewise(BoundedVec<CurvePoint<BlockNumber>, MaxPoints>),
+}
+
+impl<BlockNumber, MaxPoints> VestingCurve<BlockNumber, MaxPoints>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy + Debug,
+	MaxPoints: Get<u32>,
+{
+	/// Validate the parameters of the curve: it must release all funds in a finite number of
+	/// blocks, and the points of a piecewise curve must be strictly increasing in blocks and
+	/// non-decreasing in the portion vested, with the last point vesting everything.
+	pub fn is_valid(&self) -> bool {
+		match self {
+			Self::Linear { cliff, duration } => !duration.is_zero() && cliff <= duration,
+			Self::Stepped { period, steps } => !period.is_zero() && *steps > 0,
+			Self::Piecewise(points) =>
+				points.last().map_or(false, |point| point.vested.is_one()) &&
+					points
+						.windows(2)
+						.all(|w| w[0].elapsed < w[1].elapsed && w[0].vested <= w[1].vested),
+		}
+	}
+
+	/// Number of blocks after the start at which all funds are released.
+	pub fn duration(&self) -> BlockNumber {
+		match self {
+			Self::Linear { duration, .. } => *duration,
+			Self::Stepped { period, steps } => (*period).saturating_mul((*steps).into()),
+			Self::Piecewise(points) => points.last().map_or(Zero::zero(), |point| point.elapsed),
+		}
+	}
+
+	/// The portion of the funds vested `elapsed` blocks after the start, as a numerator and a
+	/// denominator. The numerator never exceeds the denominator, which is never zero.
+	fn vested_ratio(&self, elapsed: BlockNumber) -> (u128, u128) {
+		let elapsed: u128 = elapsed.saturated_into();
+		match self {
+			Self::Linear { cliff, duration } => {
+				let duration: u128 = (*duration).saturated_into::<u128>().max(1);
+				if elapsed < (*cliff).saturated_into::<u128>() {
+					(0, duration)
+				} else {
+					(elapsed.min(duration), duration)
+				}
+			},
+			Self::Stepped { period, steps } => {
+				let period: u128 = (*period).saturated_into::<u128>().max(1);
+				let steps = (*steps as u128).max(1);
+				((elapsed / period).min(steps), steps)
+			},
+			Self::Piecewise(points) => {
+				let accuracy = Perbill::ACCURACY as u128;
+				let mut previous = (0u128, 0u128);
+				for point in points.iter() {
+					let (block, vested) = (
+						point.elapsed.saturated_into::<u128>(),
+						point.vested.deconstruct() as u128,
+					);
+					if elapsed < block {
+						// Interpolate between the previous point and this one.
+						let span = block - previous.0;
+						let gained = vested.saturating_sub(previous.1) * (elapsed - previous.0);
+						return (previous.1 * span + gained, accuracy * span)
+					}
+					previous = (block, vested);
+				}
+				(previous.1.min(accuracy), accuracy)
+			},
+		}
+	}
+}
+
+/// Struct to encode a vesting schedule that releases its funds along a `VestingCurve`.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	MaxEncodedLen,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+)]
+#[codec(mel_bound(Balance: MaxEncodedLen, BlockNumber: MaxEncodedLen, MaxPoints: Get<u32>))]
+#[scale_info(skip_type_params(MaxPoints))]
+pub struct CurvedVestingInfo<
+	Balance: Copy + Eq + Debug,
+	BlockNumber: Copy + Eq + Debug,
+	MaxPoints: Get<u32>,
+> {
+	/// Locked amount at schedule creation.
+	locked: Balance,
+	/// Starting block of the curve.
+	starting_block: BlockNumber,
+	/// The shape along which `locked` is released from `starting_block` on.
+	curve: VestingCurve<BlockNumber, MaxPoints>,
+	/// Whether `RevokeOrigin` may take back the funds that are not yet vested.
+	revocable: bool,
+}
+
+impl<Balance, BlockNumber, MaxPoints> CurvedVestingInfo<Balance, BlockNumber, MaxPoints>
+where
+	Balance: AtLeast32BitUnsigned + Copy + Debug,
+	BlockNumber: AtLeast32BitUnsigned + Copy + Debug,
+	MaxPoints: Get<u32>,
+{
+	/// Instantiate a new `CurvedVestingInfo`.
+	pub fn new(
+		locked: Balance,
+		starting_block: BlockNumber,
+		curve: VestingCurve<BlockNumber, MaxPoints>,
+		revocable: bool,
+	) -> CurvedVestingInfo<Balance, BlockNumber, MaxPoints> {
+		CurvedVestingInfo { locked, starting_block, curve, revocable }
+	}
+
+	/// Validate parameters for `CurvedVestingInfo`. Note that this does not check
+	/// against `MinVestedTransfer`.
+	pub fn is_valid(&self) -> bool {
+		!self.locked.is_zero() && self.curve.is_valid()
+	}
+
+	/// Locked amount at schedule creation.
+	pub fn locked(&self) -> Balance {
+		self.locked
+	}
+
+	/// Starting block of the curve.
+	pub fn starting_block(&self) -> BlockNumber {
+		self.starting_block
+	}
+
+	/// The shape along which the funds are released.
+	pub fn curve(&self) -> &VestingCurve<BlockNumber, MaxPoints> {
+		&self.curve
+	}
+
+	/// Whether the funds that are not yet vested may be revoked.
+	pub fn revocable(&self) -> bool {
+		self.revocable
+	}
+
+	/// Block number at which all funds are released.
+	pub fn ending_block(&self) -> BlockNumber {
+		self.starting_block.saturating_add(self.curve.duration())
+	}
+
+	/// Amount locked at block `n`.
+	pub fn locked_at(&self, n: BlockNumber) -> Balance {
+		// Number of blocks that count toward vesting;
+		// saturating to 0 when n < starting_block.
+		let elapsed = n.saturating_sub(self.starting_block);
+		let (numerator, denominator) = self.curve.vested_ratio(elapsed);
+		let vested = multiply_by_rational(self.locked.saturated_into(), numerator, denominator)
+			.map(Balance::saturated_from)
+			.unwrap_or_else(|_| Zero::zero());
+		self.locked.saturating_sub(vested)
+	}
+}
@@ -53,12 +53,16 @@ pub trait WeightInfo {
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight;
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
+	fn curved_vested_transfer(l: u32, s: u32, ) -> Weight;
+	fn force_curved_vested_transfer(l: u32, s: u32, ) -> Weight;
+	fn revoke_schedule(l: u32, s: u32, ) -> Weight;
 }
 
 /// Weights for pallet_vesting using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		(50_642_000 as Weight)
@@ -66,10 +70,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((144_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 3_000
 			.saturating_add((177_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		(50_830_000 as Weight)
@@ -77,10 +82,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((115_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 3_000
 			.saturating_add((112_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
@@ -89,10 +95,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((130_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 3_000
 			.saturating_add((162_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
@@ -101,10 +108,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((123_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 9_000
 			.saturating_add((118_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:0)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
@@ -113,10 +121,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((114_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 10_000
 			.saturating_add((23_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Balances Locks (r:1 w:1)
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
@@ -125,10 +134,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((121_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 12_000
 			.saturating_add((56_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
@@ -137,10 +147,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((123_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 5_000
 			.saturating_add((149_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
@@ -149,14 +160,54 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((137_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 4_000
 			.saturating_add((152_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	// Storage: Vesting CurvedVesting (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn curved_vested_transfer(l: u32, s: u32, ) -> Weight {
+		(96_418_000 as Weight)
+			// Standard Error: 5_000
+			.saturating_add((117_000 as Weight).saturating_mul(l as Weight))
+			// Standard Error: 10_000
+			.saturating_add((204_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Vesting CurvedVesting (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn force_curved_vested_transfer(l: u32, s: u32, ) -> Weight {
+		(94_857_000 as Weight)
+			// Standard Error: 6_000
+			.saturating_add((124_000 as Weight).saturating_mul(l as Weight))
+			// Standard Error: 12_000
+			.saturating_add((231_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Vesting CurvedVesting (r:1 w:1)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn revoke_schedule(l: u32, s: u32, ) -> Weight {
+		(98_260_000 as Weight)
+			// Standard Error: 5_000
+			.saturating_add((139_000 as Weight).saturating_mul(l as Weight))
+			// Standard Error: 11_000
+			.saturating_add((226_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		(50_642_000 as Weight)
@@ -164,10 +215,11 @@ impl WeightInfo for () {
 			.saturating_add((144_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 3_000
 			.saturating_add((177_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		(50_830_000 as Weight)
@@ -175,10 +227,11 @@ impl WeightInfo for () {
 			.saturating_add((115_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 3_000
 			.saturating_add((112_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
@@ -187,10 +240,11 @@ impl WeightInfo for () {
 			.saturating_add((130_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 3_000
 			.saturating_add((162_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 	}
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
@@ -199,10 +253,11 @@ impl WeightInfo for () {
 			.saturating_add((123_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 9_000
 			.saturating_add((118_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 	}
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:0)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
@@ -211,10 +266,11 @@ impl WeightInfo for () {
 			.saturating_add((114_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 10_000
 			.saturating_add((23_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Balances Locks (r:1 w:1)
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
@@ -223,10 +279,11 @@ impl WeightInfo for () {
 			.saturating_add((121_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 12_000
 			.saturating_add((56_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 	}
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
@@ -235,10 +292,11 @@ impl WeightInfo for () {
 			.saturating_add((123_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 5_000
 			.saturating_add((149_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Vesting CurvedVesting (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
@@ -247,7 +305,46 @@ impl WeightInfo for () {
 			.saturating_add((137_000 as Weight).saturating_mul(l as Weight))
 			// Standard Error: 4_000
 			.saturating_add((152_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
+	// Storage: Vesting CurvedVesting (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn curved_vested_transfer(l: u32, s: u32, ) -> Weight {
+		(96_418_000 as Weight)
+			// Standard Error: 5_000
+			.saturating_add((117_000 as Weight).saturating_mul(l as Weight))
+			// Standard Error: 10_000
+			.saturating_add((204_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Vesting CurvedVesting (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn force_curved_vested_transfer(l: u32, s: u32, ) -> Weight {
+		(94_857_000 as Weight)
+			// Standard Error: 6_000
+			.saturating_add((124_000 as Weight).saturating_mul(l as Weight))
+			// Standard Error: 12_000
+			.saturating_add((231_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Vesting CurvedVesting (r:1 w:1)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn revoke_schedule(l: u32, s: u32, ) -> Weight {
+		(98_260_000 as Weight)
+			// Standard Error: 5_000
+			.saturating_add((139_000 as Weight).saturating_mul(l as Weight))
+			// Standard Error: 11_000
+			.saturating_add((226_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
 }
@@ -0,0 +1,150 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of approval voting.
+//!
+//! Each candidate is scored by its approval stake, i.e. the sum of the stake of all the voters who
+//! approve of it, and the candidates with the highest approval stake are elected. Unlike
+//! [`crate::seq_phragmen`] and [`crate::phragmms()`], the selection of the winners does not try to
+//! balance the stake behind them in any way.
+
+use crate::{setup_inputs, ElectionResult, IdentifierT, PerThing128, VoteWeight};
+use sp_arithmetic::traits::Zero;
+use sp_std::{cmp::Reverse, prelude::*};
+
+/// Execute an approvals voting election.
+///
+/// This can be used interchangeably with [`crate::seq_phragmen`] and offers a similar API, namely:
+///
+/// - The resulting edge weight distribution is normalized (thus, safe to use for submission).
+/// - The accuracy can be configured via the generic type `P`.
+/// - The algorithm is a _best-effort_ to elect `to_elect`. If less candidates are provided, less
+///   winners are returned, without an error.
+///
+/// The winners are the `to_elect` candidates with the highest approval stake, ties being broken in
+/// favour of the candidate that comes first in `candidates`. The stake of each voter is then
+/// distributed equally among all of the winners that it approves of.
+///
+/// This can only fail if the normalization fails.
+pub fn approval_voting<AccountId: IdentifierT, P: PerThing128>(
+	to_elect: usize,
+	candidates: Vec<AccountId>,
+	voters: Vec<(AccountId, VoteWeight, Vec<AccountId>)>,
+) -> Result<ElectionResult<AccountId, P>, crate::Error> {
+	let (mut candidates, mut voters) = setup_inputs(candidates, voters);
+
+	// stable sort: equal candidates keep their initial order.
+	candidates.sort_by_key(|c| Reverse(c.borrow().approval_stake));
+	let winners = candidates
+		.into_iter()
+		.take(to_elect)
+		.map(|w_ptr| {
+			w_ptr.borrow_mut().elected = true;
+			w_ptr
+		})
+		.collect::<Vec<_>>();
+
+	for voter in &mut voters {
+		let elected_edges = voter.edges.iter().filter(|e| e.candidate.borrow().elected).count();
+		if elected_edges.is_zero() {
+			continue
+		}
+
+		let share = voter.budget / elected_edges as u128;
+		let mut remainder = voter.budget % elected_edges as u128;
+		for edge in voter.edges.iter_mut().filter(|e| e.candidate.borrow().elected) {
+			// the first edges take one more unit each, until the remainder is used up.
+			let extra = if remainder.is_zero() { 0 } else { 1 };
+			remainder -= extra;
+			edge.weight = share + extra;
+			edge.candidate.borrow_mut().backed_stake += edge.weight;
+		}
+	}
+
+	let mut assignments =
+		voters.into_iter().filter_map(|v| v.into_assignment()).collect::<Vec<_>>();
+	let _ = assignments
+		.iter_mut()
+		.map(|a| a.try_normalize())
+		.collect::<Result<(), _>>()
+		.map_err(|e| crate::Error::ArithmeticError(e))?;
+	let winners = winners
+		.into_iter()
+		.map(|w_ptr| (w_ptr.borrow().who.clone(), w_ptr.borrow().backed_stake))
+		.collect();
+
+	Ok(ElectionResult { winners, assignments })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Assignment;
+	use sp_runtime::Perbill;
+
+	#[test]
+	fn approval_voting_works() {
+		let candidates = vec![1, 2, 3, 4];
+		let voters = vec![(10, 10, vec![1, 2]), (20, 20, vec![1, 3]), (30, 30, vec![2, 3])];
+
+		// approval stakes are 1: 30, 2: 40, 3: 50, 4: 0.
+		let ElectionResult { winners, assignments } =
+			approval_voting::<_, Perbill>(2, candidates, voters).unwrap();
+
+		assert_eq!(winners, vec![(3, 35), (2, 25)]);
+		assert_eq!(
+			assignments,
+			vec![
+				Assignment { who: 10u64, distribution: vec![(2, Perbill::from_percent(100))] },
+				Assignment { who: 20u64, distribution: vec![(3, Perbill::from_percent(100))] },
+				Assignment {
+					who: 30u64,
+					distribution: vec![
+						(2, Perbill::from_percent(50)),
+						(3, Perbill::from_percent(50))
+					]
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn approval_voting_ties_are_broken_by_order() {
+		let candidates = vec![1, 2, 3];
+		let voters = vec![(10, 10, vec![1, 2, 3])];
+
+		let ElectionResult { winners, .. } =
+			approval_voting::<_, Perbill>(2, candidates, voters).unwrap();
+
+		assert_eq!(winners, vec![(1, 5), (2, 5)]);
+	}
+
+	#[test]
+	fn approval_voting_elects_less_if_not_enough_candidates() {
+		let candidates = vec![1, 2];
+		let voters = vec![(10, 10, vec![1]), (20, 20, vec![3])];
+
+		let ElectionResult { winners, assignments } =
+			approval_voting::<_, Perbill>(4, candidates, voters).unwrap();
+
+		assert_eq!(winners, vec![(1, 10), (2, 0)]);
+		assert_eq!(
+			assignments,
+			vec![Assignment { who: 10u64, distribution: vec![(1, Perbill::from_percent(100))] }]
+		);
+	}
+}
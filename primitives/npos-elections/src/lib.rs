@@ -87,6 +87,7 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod approval;
 mod assignments;
 pub mod balancing;
 pub mod helpers;
@@ -97,6 +98,7 @@ pub mod pjr;
 pub mod reduce;
 pub mod traits;
 
+pub use approval::*;
 pub use assignments::{Assignment, IndexAssignment, IndexAssignmentOf, StakedAssignment};
 pub use balancing::*;
 pub use helpers::*;
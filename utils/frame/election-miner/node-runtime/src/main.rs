@@ -0,0 +1,174 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mine an NPoS election solution for the node runtime, and submit it in the signed phase of the
+//! election.
+
+use codec::Encode;
+use election_miner::{build_ext, mine_solution, MinedSolution, DEFAULT_PALLET_NAME};
+use frame_support::traits::Get;
+use jsonrpsee::{rpc_params, types::traits::Client, ws_client::WsClientBuilder};
+use node_runtime::{Block, Runtime};
+use remote_externalities::rpc_api;
+use sp_core::{sr25519, Bytes, Pair, H256};
+use sp_runtime::{generic::Era, traits::Header as _, SaturatedConversion};
+use structopt::StructOpt;
+
+frame_support::parameter_types! {
+	/// Same number of balancing iterations as the offchain worker miner of the runtime at most
+	/// does.
+	pub const Balancing: Option<(usize, sp_npos_elections::ExtendedBalance)> =
+		Some((node_runtime::MINER_MAX_ITERATIONS as usize, 0));
+}
+
+/// The solver used to mine the solution.
+type Solver = frame_election_provider_support::SequentialPhragmen<
+	node_runtime::AccountId,
+	pallet_election_provider_multi_phase::SolutionAccuracyOf<Runtime>,
+	Balancing,
+>;
+
+#[derive(Debug, StructOpt)]
+struct Opt {
+	/// The ws uri of the node to connect to.
+	#[structopt(long, default_value = "ws://localhost:9944")]
+	uri: String,
+
+	/// The block hash at which the election state is scraped. If not provided, the latest
+	/// finalized head is used.
+	#[structopt(long, parse(try_from_str = parse_hash))]
+	at: Option<H256>,
+
+	/// The name of the election provider pallet in the runtime.
+	#[structopt(long, default_value = DEFAULT_PALLET_NAME)]
+	pallet: String,
+
+	/// The secret uri of the account that signs the submission, e.g. `//Alice`.
+	#[structopt(long)]
+	seed: String,
+
+	/// Only mine and check the solution, without submitting it.
+	#[structopt(long)]
+	dry_run: bool,
+}
+
+fn parse_hash(hash: &str) -> Result<H256, String> {
+	hash.trim_start_matches("0x")
+		.parse::<H256>()
+		.map_err(|e| format!("could not parse block hash: {:?}", e))
+}
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	env_logger::Builder::from_default_env()
+		.filter_level(log::LevelFilter::Info)
+		.format_module_path(true)
+		.format_level(true)
+		.init();
+
+	let Opt { uri, at, pallet, seed, dry_run } = Opt::from_args();
+	let signer = sr25519::Pair::from_string(&seed, None)
+		.map_err(|e| format!("invalid signer seed: {:?}", e))?;
+	let who: node_runtime::AccountId = signer.public().into();
+
+	let at = match at {
+		Some(at) => at,
+		None => rpc_api::get_finalized_head::<Block, _>(&uri).await?,
+	};
+	let number = *rpc_api::get_header::<Block, _>(&uri, at).await?.number();
+	let version = rpc_api::get_runtime_version::<Block, _>(&uri, Some(at)).await?;
+	if version.spec_version != node_runtime::VERSION.spec_version {
+		log::warn!(
+			"spec version of the remote node ({}) does not match the one of this miner ({})",
+			version.spec_version,
+			node_runtime::VERSION.spec_version,
+		);
+	}
+
+	// besides the election pallet, we need the nonce of the signer and the genesis hash.
+	let extra_keys = vec![
+		frame_system::Account::<Runtime>::hashed_key_for(&who),
+		frame_system::BlockHash::<Runtime>::hashed_key_for(0u32),
+	];
+	let mut ext = build_ext::<Block>(uri.clone(), Some(at), pallet, extra_keys).await?;
+
+	let (mined, nonce, genesis_hash) = ext.execute_with(|| {
+		mine_solution::<Runtime, Solver>().map(|mined| {
+			(
+				mined,
+				frame_system::Pallet::<Runtime>::account_nonce(&who),
+				frame_system::Pallet::<Runtime>::block_hash(0u32),
+			)
+		})
+	})?;
+	let MinedSolution { raw_solution, num_signed_submissions } = mined;
+	log::info!(
+		"mined a solution with {} voters and score {:?} at block #{}",
+		raw_solution.solution.voter_count(),
+		raw_solution.score,
+		number,
+	);
+
+	if dry_run {
+		return Ok(())
+	}
+
+	let call: node_runtime::Call = pallet_election_provider_multi_phase::Call::submit {
+		raw_solution: Box::new(raw_solution),
+		num_signed_submissions,
+	}
+	.into();
+
+	let period = node_runtime::BlockHashCount::get()
+		.checked_next_power_of_two()
+		.map(|c| c / 2)
+		.unwrap_or(2) as u64;
+	let extra: node_runtime::SignedExtra = (
+		frame_system::CheckSpecVersion::<Runtime>::new(),
+		frame_system::CheckTxVersion::<Runtime>::new(),
+		frame_system::CheckGenesis::<Runtime>::new(),
+		frame_system::CheckEra::<Runtime>::from(Era::mortal(period, number.saturated_into())),
+		frame_system::CheckNonce::<Runtime>::from(nonce),
+		frame_system::CheckWeight::<Runtime>::new(),
+		pallet_asset_tx_payment::ChargeAssetTxPayment::<Runtime>::from(0, None),
+	);
+	let raw_payload = node_runtime::SignedPayload::from_raw(
+		call.clone(),
+		extra.clone(),
+		(version.spec_version, version.transaction_version, genesis_hash, at, (), (), ()),
+	);
+	let signature = raw_payload.using_encoded(|payload| signer.sign(payload));
+	let extrinsic = node_runtime::UncheckedExtrinsic::new_signed(
+		call,
+		who.into(),
+		node_runtime::Signature::Sr25519(signature),
+		extra,
+	);
+
+	let client = WsClientBuilder::default()
+		.max_request_body_size(u32::MAX)
+		.build(&uri)
+		.await
+		.map_err(|e| format!("`WsClientBuilder` failed to build: {:?}", e))?;
+	let hash = client
+		.request::<H256>("author_submitExtrinsic", rpc_params![Bytes(extrinsic.encode())])
+		.await
+		.map_err(|e| format!("author_submitExtrinsic request failed: {:?}", e))?;
+	log::info!("submitted the solution in extrinsic {:?}", hash);
+
+	Ok(())
+}
@@ -0,0 +1,113 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support code for mining NPoS election solutions outside of the runtime, and submitting them in
+//! the signed phase of `pallet-election-provider-multi-phase`.
+//!
+//! The state of the election pallet is scraped from a remote node via `remote-externalities`, and
+//! the solution is computed with [`pallet_election_provider_multi_phase::Miner`]. This ensures that
+//! the solution is reduced, and trimmed to fit into the `MaxWeight` and `MaxLength` of the
+//! runtime's [`pallet_election_provider_multi_phase::MinerConfig`], exactly the same way as the
+//! offchain worker of the pallet would do it.
+//!
+//! Creating and signing the final extrinsic is runtime specific. Take a look at
+//! _utils/frame/election-miner/node-runtime_ for an example.
+
+use frame_election_provider_support::NposSolver;
+use pallet_election_provider_multi_phase::{
+	ElectionCompute, Miner, MinerConfig, RawSolution, SignedSubmissions, SolutionOf,
+};
+use remote_externalities::{Builder, Mode, OnlineConfig, TestExternalities};
+use sp_runtime::traits::Block as BlockT;
+
+const LOG_TARGET: &str = "election-miner";
+
+/// The name of the election provider pallet, as it appears in `construct_runtime!`.
+pub const DEFAULT_PALLET_NAME: &str = "ElectionProviderMultiPhase";
+
+/// A solution mined against a remote snapshot, along with the witness data needed to submit it.
+pub struct MinedSolution<T: pallet_election_provider_multi_phase::Config> {
+	/// The solution, ready to be submitted.
+	pub raw_solution: RawSolution<SolutionOf<T::MinerConfig>>,
+	/// The number of signed submissions that are already queued. Used as witness.
+	pub num_signed_submissions: u32,
+}
+
+/// Build externalities that contain the state of the election pallet at block `at` of the node
+/// at `uri`.
+///
+/// `extra_keys` are additional (hashed) storage keys to scrape, e.g. the account of the signer.
+pub async fn build_ext<B: BlockT + serde::de::DeserializeOwned>(
+	uri: String,
+	at: Option<B::Hash>,
+	pallet: String,
+	extra_keys: Vec<Vec<u8>>,
+) -> Result<TestExternalities, String> {
+	let mut builder = Builder::<B>::new().mode(Mode::Online(OnlineConfig {
+		transport: uri.into(),
+		at,
+		pallets: vec![pallet],
+		..Default::default()
+	}));
+	for key in extra_keys {
+		builder = builder.inject_hashed_key(&key);
+	}
+
+	builder
+		.build()
+		.await
+		.map_err(|e| format!("failed to scrape remote state: {}", e))
+}
+
+/// Mine a solution with the solver `S`, using the snapshot that is stored in the election pallet.
+///
+/// Must be called within externalities that contain the state of the election pallet, e.g. as
+/// returned by [`build_ext`]. The returned solution is checked to be feasible.
+pub fn mine_solution<T, S>() -> Result<MinedSolution<T>, String>
+where
+	T: pallet_election_provider_multi_phase::Config,
+	S: NposSolver<AccountId = <T::MinerConfig as MinerConfig>::AccountId>,
+{
+	use pallet_election_provider_multi_phase::Pallet as MultiPhase;
+
+	if !<MultiPhase<T>>::current_phase().is_signed() {
+		return Err("the election is not in the signed phase".into())
+	}
+
+	let snapshot = <MultiPhase<T>>::snapshot().ok_or("snapshot is not available")?;
+	let desired_targets = <MultiPhase<T>>::desired_targets().ok_or("snapshot is not available")?;
+
+	let (solution, score, size) = Miner::<T::MinerConfig>::mine_solution_with_snapshot::<S>(
+		snapshot.voters,
+		snapshot.targets,
+		desired_targets,
+	)
+	.map_err(|e| format!("failed to mine a solution: {:?}", e))?;
+	log::info!(
+		target: LOG_TARGET,
+		"mined a solution with score {:?} over a snapshot of {:?}",
+		score,
+		size
+	);
+
+	let raw_solution = RawSolution { solution, score, round: <MultiPhase<T>>::round() };
+	<MultiPhase<T>>::feasibility_check(raw_solution.clone(), ElectionCompute::Signed)
+		.map_err(|e| format!("mined solution is not feasible: {:?}", e))?;
+
+	let num_signed_submissions = SignedSubmissions::<T>::decode_len().unwrap_or_default() as u32;
+	Ok(MinedSolution { raw_solution, num_signed_submissions })
+}